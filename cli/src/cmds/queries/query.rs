@@ -209,7 +209,14 @@ fn build_endpoint(status: &Status, path: &str) -> Result<(reqwest::Client, Strin
     let query_configs = status.get_local_query_configs();
 
     let (_, query) = query_configs.get(0).expect("cannot find query configs");
+    // The local query server is accessed as root, which has no password on the local host.
+    let mut headers = reqwest::header::HeaderMap::new();
+    headers.insert(
+        reqwest::header::AUTHORIZATION,
+        reqwest::header::HeaderValue::from_static("Basic cm9vdDo="),
+    );
     let client = reqwest::Client::builder()
+        .default_headers(headers)
         .build()
         .expect("Cannot build query client");
 
@@ -13,10 +13,14 @@
 // limitations under the License.
 
 use std::alloc::Layout;
+use std::sync::atomic::AtomicBool;
 use std::sync::atomic::AtomicI64;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
+use common_exception::ErrorCode;
+use common_exception::Result;
+
 #[thread_local]
 static mut TRACKER: *mut ThreadTracker = std::ptr::null_mut();
 
@@ -100,6 +104,10 @@ impl ThreadTracker {
 
 pub struct MemoryTracker {
     memory_usage: AtomicI64,
+    // The memory limit in bytes, 0 means no limit.
+    memory_limit: AtomicI64,
+    // Set by the allocation which exceeds the memory limit.
+    limit_exceeded: AtomicBool,
     parent_memory_tracker: Option<Arc<MemoryTracker>>,
 }
 
@@ -108,12 +116,21 @@ impl MemoryTracker {
         Arc::new(MemoryTracker {
             parent_memory_tracker,
             memory_usage: AtomicI64::new(0),
+            memory_limit: AtomicI64::new(0),
+            limit_exceeded: AtomicBool::new(false),
         })
     }
 
     #[inline]
     pub fn alloc_memory(&self, size: i64) {
-        self.memory_usage.fetch_add(size, Ordering::Relaxed);
+        let memory_usage = self.memory_usage.fetch_add(size, Ordering::Relaxed) + size;
+
+        // The allocator can not fail the allocation, it marks the tracker and
+        // the owner of the tracker fails on its next check_memory_limit.
+        let memory_limit = self.get_memory_limit();
+        if memory_limit > 0 && memory_usage > memory_limit {
+            self.limit_exceeded.store(true, Ordering::Relaxed);
+        }
 
         if let Some(parent_memory_tracker) = &self.parent_memory_tracker {
             parent_memory_tracker.alloc_memory(size);
@@ -144,6 +161,29 @@ impl MemoryTracker {
     pub fn get_memory_usage(&self) -> i64 {
         self.memory_usage.load(Ordering::Relaxed)
     }
+
+    pub fn set_memory_limit(&self, limit: i64) {
+        self.memory_limit.store(limit, Ordering::Relaxed);
+    }
+
+    #[inline]
+    pub fn get_memory_limit(&self) -> i64 {
+        self.memory_limit.load(Ordering::Relaxed)
+    }
+
+    /// Fails if any allocation has exceeded the memory limit, even if the memory is freed since.
+    #[inline]
+    pub fn check_memory_limit(&self) -> Result<()> {
+        if self.limit_exceeded.load(Ordering::Relaxed) {
+            return Err(ErrorCode::QuotaExceeded(format!(
+                "Memory limit exceeded, usage {} bytes, limit {} bytes",
+                self.get_memory_usage(),
+                self.get_memory_limit()
+            )));
+        }
+
+        Ok(())
+    }
 }
 
 pub struct RuntimeTracker {
@@ -0,0 +1,56 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+#[test]
+fn test_memory_tracker_limit() -> Result<()> {
+    let tracker = MemoryTracker::create(None);
+
+    // No limit by default.
+    tracker.alloc_memory(1024);
+    assert_eq!(tracker.get_memory_limit(), 0);
+    tracker.check_memory_limit()?;
+
+    tracker.set_memory_limit(2048);
+    tracker.check_memory_limit()?;
+
+    tracker.alloc_memory(2048);
+    let result = tracker.check_memory_limit();
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().code(), ErrorCode::QuotaExceededCode());
+
+    // The exceeded limit is kept after the memory is freed.
+    tracker.dealloc_memory(2048);
+    assert!(tracker.check_memory_limit().is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_memory_tracker_parent() -> Result<()> {
+    let parent = MemoryTracker::create(None);
+    let child = MemoryTracker::create(Some(parent.clone()));
+
+    child.alloc_memory(4096);
+    assert_eq!(child.get_memory_usage(), 4096);
+    assert_eq!(parent.get_memory_usage(), 4096);
+
+    child.dealloc_memory(4096);
+    assert_eq!(parent.get_memory_usage(), 0);
+
+    Ok(())
+}
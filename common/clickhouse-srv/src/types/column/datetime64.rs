@@ -21,6 +21,9 @@ use crate::errors::Result;
 use crate::types::column::column_data::BoxColumnData;
 use crate::types::column::column_data::ColumnData;
 use crate::types::column::list::List;
+use crate::types::column::nullable::NullableColumnData;
+use crate::types::column::ArcColumnWrapper;
+use crate::types::column::ColumnWrapper;
 use crate::types::DateTimeType;
 use crate::types::SqlType;
 use crate::types::Value;
@@ -57,6 +60,34 @@ impl DateTime64ColumnData {
     }
 }
 
+/// Build the DateTime64 column from the ticks of 10^-precision seconds.
+pub fn column_from_ticks<W: ColumnWrapper>(ticks: Vec<i64>, precision: u32, tz: Tz) -> W::Wrapper {
+    let mut data = List::with_capacity(ticks.len());
+    for tick in ticks {
+        data.push(tick);
+    }
+
+    W::wrap(DateTime64ColumnData {
+        data,
+        params: (precision, tz),
+    })
+}
+
+/// Build the Nullable(DateTime64) column from the ticks of 10^-precision seconds.
+pub fn nullable_column_from_ticks<W: ColumnWrapper>(
+    ticks: Vec<Option<i64>>,
+    precision: u32,
+    tz: Tz,
+) -> W::Wrapper {
+    let nulls = ticks.iter().map(|t| t.is_none() as u8).collect();
+    let values = ticks.into_iter().map(|t| t.unwrap_or_default()).collect();
+
+    W::wrap(NullableColumnData {
+        inner: column_from_ticks::<ArcColumnWrapper>(values, precision, tz),
+        nulls,
+    })
+}
+
 impl ColumnData for DateTime64ColumnData {
     fn sql_type(&self) -> SqlType {
         let (precision, tz) = self.params;
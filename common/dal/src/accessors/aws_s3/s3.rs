@@ -14,24 +14,40 @@
 //
 
 use std::str::FromStr;
+use std::time::Instant;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
 use futures::Stream;
+use futures::StreamExt;
+use metrics::counter;
+use metrics::histogram;
 use rusoto_core::credential::DefaultCredentialsProvider;
 use rusoto_core::credential::StaticProvider;
 use rusoto_core::ByteStream;
 use rusoto_core::Client;
 use rusoto_core::HttpClient;
 use rusoto_core::Region;
+use rusoto_core::RusotoError;
+use rusoto_s3::Delete;
+use rusoto_s3::DeleteObjectRequest;
+use rusoto_s3::DeleteObjectsRequest;
+use rusoto_s3::GetObjectRequest;
+use rusoto_s3::HeadObjectRequest;
+use rusoto_s3::ListObjectsV2Request;
+use rusoto_s3::ObjectIdentifier;
 use rusoto_s3::PutObjectRequest;
 use rusoto_s3::S3Client;
 use rusoto_s3::S3 as RusotoS3;
 
 use crate::DataAccessor;
 use crate::InputStream;
+use crate::ObjectMeta;
 use crate::S3InputStream;
 
+// S3 deletes at most 1000 objects per request.
+const MAX_DELETE_OBJECTS: usize = 1000;
+
 pub struct S3 {
     client: S3Client,
     bucket: String,
@@ -174,4 +190,170 @@ impl DataAccessor for S3 {
         self.put_byte_stream(path, ByteStream::new_with_size(input_stream, stream_len))
             .await
     }
+
+    async fn read_range(&self, path: &str, offset: u64, len: u64) -> Result<Vec<u8>> {
+        if len == 0 {
+            return Ok(vec![]);
+        }
+
+        counter!(super::metrics::METRIC_S3_GETOBJECT_NUMBERS, 1);
+        let req = GetObjectRequest {
+            range: Some(format!("bytes={}-{}", offset, offset + len - 1)),
+            key: path.to_string(),
+            bucket: self.bucket.clone(),
+            ..Default::default()
+        };
+
+        let start = Instant::now();
+        let reply = self.client.get_object(req).await.map_err(|e| {
+            counter!(super::metrics::METRIC_S3_GETOBJECT_ERRORS, 1);
+            ErrorCode::DALTransportError(e.to_string())
+        })?;
+        histogram!(
+            super::metrics::METRIC_S3_GETOBJECT_USEDTIME,
+            start.elapsed()
+        );
+
+        let mut buffer = Vec::with_capacity(len as usize);
+        if let Some(mut body) = reply.body {
+            while let Some(bytes) = body.next().await {
+                buffer.extend_from_slice(&bytes?);
+            }
+        }
+        Ok(buffer)
+    }
+
+    async fn list(&self, prefix: &str) -> Result<Vec<ObjectMeta>> {
+        let mut objects = vec![];
+        let mut continuation_token = None;
+
+        loop {
+            counter!(super::metrics::METRIC_S3_LISTOBJECTS_NUMBERS, 1);
+            let req = ListObjectsV2Request {
+                bucket: self.bucket.clone(),
+                prefix: Some(prefix.to_string()),
+                continuation_token: continuation_token.take(),
+                ..Default::default()
+            };
+
+            let start = Instant::now();
+            let reply = self.client.list_objects_v2(req).await.map_err(|e| {
+                counter!(super::metrics::METRIC_S3_LISTOBJECTS_ERRORS, 1);
+                ErrorCode::DALTransportError(e.to_string())
+            })?;
+            histogram!(
+                super::metrics::METRIC_S3_LISTOBJECTS_USEDTIME,
+                start.elapsed()
+            );
+
+            for object in reply.contents.unwrap_or_default() {
+                if let Some(key) = object.key {
+                    objects.push(ObjectMeta {
+                        path: key,
+                        size: object.size.unwrap_or(0) as u64,
+                    });
+                }
+            }
+
+            match reply.next_continuation_token {
+                Some(token) if reply.is_truncated.unwrap_or(false) => {
+                    continuation_token = Some(token)
+                }
+                _ => break,
+            }
+        }
+
+        Ok(objects)
+    }
+
+    async fn stat(&self, path: &str) -> Result<ObjectMeta> {
+        counter!(super::metrics::METRIC_S3_HEADOBJECT_NUMBERS, 1);
+        let req = HeadObjectRequest {
+            key: path.to_string(),
+            bucket: self.bucket.clone(),
+            ..Default::default()
+        };
+
+        let start = Instant::now();
+        let reply = self.client.head_object(req).await.map_err(|e| match e {
+            // HEAD responses carry no body, a missing key comes back as a bare 404
+            RusotoError::Unknown(resp) if resp.status.as_u16() == 404 => {
+                ErrorCode::DALPathNotFound(format!("path {} not found", path))
+            }
+            e => {
+                counter!(super::metrics::METRIC_S3_HEADOBJECT_ERRORS, 1);
+                ErrorCode::DALTransportError(e.to_string())
+            }
+        })?;
+        histogram!(
+            super::metrics::METRIC_S3_HEADOBJECT_USEDTIME,
+            start.elapsed()
+        );
+
+        Ok(ObjectMeta {
+            path: path.to_string(),
+            size: reply.content_length.unwrap_or(0) as u64,
+        })
+    }
+
+    async fn delete(&self, path: &str) -> Result<()> {
+        counter!(super::metrics::METRIC_S3_DELETEOBJECT_NUMBERS, 1);
+        let req = DeleteObjectRequest {
+            key: path.to_string(),
+            bucket: self.bucket.clone(),
+            ..Default::default()
+        };
+
+        let start = Instant::now();
+        self.client.delete_object(req).await.map_err(|e| {
+            counter!(super::metrics::METRIC_S3_DELETEOBJECT_ERRORS, 1);
+            ErrorCode::DALTransportError(e.to_string())
+        })?;
+        histogram!(
+            super::metrics::METRIC_S3_DELETEOBJECT_USEDTIME,
+            start.elapsed()
+        );
+        Ok(())
+    }
+
+    async fn batch_delete(&self, paths: &[String]) -> Result<()> {
+        for chunk in paths.chunks(MAX_DELETE_OBJECTS) {
+            counter!(super::metrics::METRIC_S3_DELETEOBJECT_NUMBERS, 1);
+            let objects = chunk
+                .iter()
+                .map(|path| ObjectIdentifier {
+                    key: path.clone(),
+                    version_id: None,
+                })
+                .collect();
+            let req = DeleteObjectsRequest {
+                bucket: self.bucket.clone(),
+                delete: Delete {
+                    objects,
+                    quiet: Some(true),
+                },
+                ..Default::default()
+            };
+
+            let start = Instant::now();
+            let reply = self.client.delete_objects(req).await.map_err(|e| {
+                counter!(super::metrics::METRIC_S3_DELETEOBJECT_ERRORS, 1);
+                ErrorCode::DALTransportError(e.to_string())
+            })?;
+            histogram!(
+                super::metrics::METRIC_S3_DELETEOBJECT_USEDTIME,
+                start.elapsed()
+            );
+
+            if let Some(error) = reply.errors.and_then(|errors| errors.into_iter().next()) {
+                counter!(super::metrics::METRIC_S3_DELETEOBJECT_ERRORS, 1);
+                return Err(ErrorCode::DALTransportError(format!(
+                    "failed to delete {}, {}",
+                    error.key.unwrap_or_default(),
+                    error.message.unwrap_or_default()
+                )));
+            }
+        }
+        Ok(())
+    }
 }
@@ -15,6 +15,7 @@
 
 use std::sync::Arc;
 
+use azure_core_mirror::prelude::Range;
 use azure_core_mirror::HttpClient;
 use azure_storage_mirror::clients::StorageAccountClient;
 use azure_storage_mirror::core::prelude::*;
@@ -27,6 +28,7 @@ use crate::AzureBlobInputStream;
 use crate::Bytes;
 use crate::DataAccessor;
 use crate::InputStream;
+use crate::ObjectMeta;
 
 pub struct AzureBlobAccessor {
     client: Arc<StorageClient>,
@@ -160,4 +162,94 @@ impl DataAccessor for AzureBlobAccessor {
         }
         self.put_blob(path, data).await
     }
+
+    async fn read_range(&self, path: &str, offset: u64, len: u64) -> Result<Vec<u8>> {
+        if len == 0 {
+            return Ok(vec![]);
+        }
+
+        let blob = self
+            .client
+            .as_container_client(&self.container)
+            .as_blob_client(path);
+
+        match blob
+            .get()
+            .range(Range::new(offset, offset + len))
+            .execute()
+            .await
+        {
+            Err(e) => Err(ErrorCode::DALTransportError(format!(
+                "Failed on azure blob get operation with range {}-{}, {}",
+                offset,
+                offset + len,
+                e
+            ))),
+            Ok(blob_data) => Ok(blob_data.data),
+        }
+    }
+
+    async fn list(&self, prefix: &str) -> Result<Vec<ObjectMeta>> {
+        let container = self.client.as_container_client(&self.container);
+
+        let mut objects = vec![];
+        let mut next_marker: Option<NextMarker> = None;
+        loop {
+            let mut request = container.list_blobs().prefix(prefix.to_owned());
+            if let Some(marker) = next_marker.take() {
+                request = request.next_marker(marker);
+            }
+
+            let response = request.execute().await.map_err(|e| {
+                ErrorCode::DALTransportError(format!("Failed on azure blob list operation, {}", e))
+            })?;
+
+            for blob in response.blobs.blobs {
+                objects.push(ObjectMeta {
+                    path: blob.name,
+                    size: blob.properties.content_length,
+                });
+            }
+
+            match response.next_marker {
+                Some(marker) => next_marker = Some(marker),
+                None => break,
+            }
+        }
+
+        Ok(objects)
+    }
+
+    async fn stat(&self, path: &str) -> Result<ObjectMeta> {
+        let blob = self
+            .client
+            .as_container_client(&self.container)
+            .as_blob_client(path);
+
+        match blob.get_properties().execute().await {
+            Err(e) => Err(ErrorCode::DALTransportError(format!(
+                "Failed on azure blob get properties operation, {}",
+                e
+            ))),
+            Ok(response) => Ok(ObjectMeta {
+                path: path.to_string(),
+                size: response.blob.properties.content_length,
+            }),
+        }
+    }
+
+    async fn delete(&self, path: &str) -> Result<()> {
+        let blob = self
+            .client
+            .as_container_client(&self.container)
+            .as_blob_client(path);
+
+        match blob.delete().execute().await {
+            Err(e) => Err(ErrorCode::DALTransportError(format!(
+                "Failed on azure blob delete operation, {}",
+                e
+            ))),
+            Ok(_) => Ok(()),
+        }
+    }
 }
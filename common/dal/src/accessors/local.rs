@@ -29,6 +29,7 @@ use tokio::io::AsyncWriteExt;
 
 use crate::DataAccessor;
 use crate::InputStream;
+use crate::ObjectMeta;
 
 pub struct Local {
     root: PathBuf,
@@ -59,6 +60,16 @@ impl Local {
             )))
         }
     }
+
+    // path relative to the root, separated by '/'
+    fn relative_path(&self, path: &Path) -> String {
+        let relative = path.strip_prefix(&self.root).unwrap_or(path);
+        relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/")
+    }
 }
 
 #[async_trait::async_trait]
@@ -108,6 +119,69 @@ impl DataAccessor for Local {
         new_file.flush().await?;
         Ok(())
     }
+
+    async fn list(&self, prefix: &str) -> Result<Vec<ObjectMeta>> {
+        let prefix_path = self.prefix_with_root(prefix)?;
+
+        // The prefix is either a directory, or a partial name inside of its parent.
+        let dir = if prefix_path.is_dir() {
+            prefix_path.clone()
+        } else {
+            match prefix_path.parent() {
+                Some(parent) if parent.is_dir() => parent.to_path_buf(),
+                _ => return Ok(vec![]),
+            }
+        };
+
+        let prefix = prefix_path.to_string_lossy().to_string();
+        let mut objects = vec![];
+        let mut dirs = vec![dir];
+        while let Some(dir) = dirs.pop() {
+            let mut entries = tokio::fs::read_dir(&dir).await?;
+            while let Some(entry) = entries.next_entry().await? {
+                let path = entry.path();
+                let metadata = entry.metadata().await?;
+                if metadata.is_dir() {
+                    dirs.push(path);
+                } else if path.to_string_lossy().starts_with(&prefix) {
+                    objects.push(ObjectMeta {
+                        path: self.relative_path(&path),
+                        size: metadata.len(),
+                    });
+                }
+            }
+        }
+
+        objects.sort_by(|a, b| a.path.cmp(&b.path));
+        Ok(objects)
+    }
+
+    async fn stat(&self, path: &str) -> Result<ObjectMeta> {
+        let full_path = self.prefix_with_root(path)?;
+        match tokio::fs::metadata(&full_path).await {
+            Ok(metadata) if metadata.is_file() => Ok(ObjectMeta {
+                path: path.to_string(),
+                size: metadata.len(),
+            }),
+            Ok(_) => Err(ErrorCode::DALPathNotFound(format!(
+                "path {} is not a file",
+                path
+            ))),
+            Err(e) if e.kind() == ErrorKind::NotFound => Err(ErrorCode::DALPathNotFound(format!(
+                "path {} not found",
+                path
+            ))),
+            Err(e) => Err(ErrorCode::from(e)),
+        }
+    }
+
+    async fn delete(&self, path: &str) -> Result<()> {
+        let path = self.prefix_with_root(path)?;
+        match tokio::fs::remove_file(path).await {
+            Err(e) if e.kind() != ErrorKind::NotFound => Err(ErrorCode::from(e)),
+            _ => Ok(()),
+        }
+    }
 }
 
 // from cargo::util::path
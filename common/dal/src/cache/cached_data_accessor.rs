@@ -0,0 +1,145 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use async_compat::CompatExt;
+use common_base::tokio;
+use common_exception::Result;
+use futures::AsyncReadExt;
+use futures::Stream;
+
+use crate::DalCache;
+use crate::DataAccessor;
+use crate::InputStream;
+use crate::ObjectMeta;
+use crate::TeeInputStream;
+
+/// A data accessor which caches the objects it reads in the `DalCache`.
+///
+/// The objects must be immutable, e.g. the snapshots, segments and blocks of fuse tables.
+/// Objects under `memory_prefixes` are read as a whole and go to the memory tier,
+/// objects under `disk_prefixes` are read as streams and go to the disk tier.
+/// Other objects are not cached.
+pub struct CachedDataAccessor {
+    cache: Arc<DalCache>,
+    inner: Arc<dyn DataAccessor>,
+    memory_prefixes: Vec<String>,
+    disk_prefixes: Vec<String>,
+}
+
+impl CachedDataAccessor {
+    pub fn new(
+        cache: Arc<DalCache>,
+        inner: Arc<dyn DataAccessor>,
+        memory_prefixes: &[&str],
+        disk_prefixes: &[&str],
+    ) -> Self {
+        Self {
+            cache,
+            inner,
+            memory_prefixes: memory_prefixes.iter().map(|p| p.to_string()).collect(),
+            disk_prefixes: disk_prefixes.iter().map(|p| p.to_string()).collect(),
+        }
+    }
+
+    fn under_prefixes(path: &str, prefixes: &[String]) -> bool {
+        let path = Path::new(path.trim_start_matches('/'));
+        prefixes.iter().any(|prefix| path.starts_with(prefix))
+    }
+}
+
+#[async_trait::async_trait]
+impl DataAccessor for CachedDataAccessor {
+    fn get_input_stream(&self, path: &str, stream_len: Option<u64>) -> Result<InputStream> {
+        if !Self::under_prefixes(path, &self.disk_prefixes) {
+            return self.inner.get_input_stream(path, stream_len);
+        }
+
+        if let Some(file) = self.cache.get_disk(path) {
+            let tokio_file = tokio::fs::File::from_std(file);
+            return Ok(Box::new(tokio_file.compat()));
+        }
+
+        match self.cache.get_disk_fill(path) {
+            // The bytes the caller reads fill the disk tier, the object is downloaded once.
+            Some(fill) => {
+                let inner = self.inner.clone();
+                let cache = self.cache.clone();
+                let tee = TeeInputStream::create(path, inner, cache, fill)?;
+                Ok(Box::new(tee))
+            }
+            None => self.inner.get_input_stream(path, stream_len),
+        }
+    }
+
+    async fn put(&self, path: &str, content: Vec<u8>) -> Result<()> {
+        self.cache.remove(path);
+        self.inner.put(path, content).await
+    }
+
+    async fn put_stream(
+        &self,
+        path: &str,
+        input_stream: Box<
+            dyn Stream<Item = std::result::Result<bytes::Bytes, std::io::Error>>
+                + Send
+                + Unpin
+                + 'static,
+        >,
+        stream_len: usize,
+    ) -> Result<()> {
+        self.cache.remove(path);
+        self.inner.put_stream(path, input_stream, stream_len).await
+    }
+
+    async fn read(&self, location: &str) -> Result<Vec<u8>> {
+        if !Self::under_prefixes(location, &self.memory_prefixes) {
+            // Read through the input stream, the object may be in the disk tier.
+            let mut input_stream = self.get_input_stream(location, None)?;
+            let mut buffer = vec![];
+            input_stream.read_to_end(&mut buffer).await?;
+            return Ok(buffer);
+        }
+
+        if let Some(bytes) = self.cache.get_memory(location) {
+            return Ok(bytes.as_ref().clone());
+        }
+
+        let bytes = self.inner.read(location).await?;
+        self.cache.put_memory(location, Arc::new(bytes.clone()));
+        Ok(bytes)
+    }
+
+    async fn list(&self, prefix: &str) -> Result<Vec<ObjectMeta>> {
+        self.inner.list(prefix).await
+    }
+
+    async fn stat(&self, path: &str) -> Result<ObjectMeta> {
+        self.inner.stat(path).await
+    }
+
+    async fn delete(&self, path: &str) -> Result<()> {
+        self.cache.remove(path);
+        self.inner.delete(path).await
+    }
+
+    async fn batch_delete(&self, paths: &[String]) -> Result<()> {
+        for path in paths {
+            self.cache.remove(path);
+        }
+        self.inner.batch_delete(paths).await
+    }
+}
@@ -0,0 +1,274 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::borrow::Borrow;
+use std::collections::VecDeque;
+use std::path::Component;
+use std::path::Path;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use common_cache::Cache;
+use common_cache::DefaultHashBuilder;
+use common_cache::LruCache;
+use common_cache::LruDiskCache;
+use common_cache::Meter;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_infallible::Mutex;
+use common_tracing::tracing;
+use metrics::counter;
+
+use crate::DiskFill;
+
+pub static METRIC_DAL_CACHE_HITS: &str = "dal.cache_hits";
+pub static METRIC_DAL_CACHE_MISSES: &str = "dal.cache_misses";
+
+/// Measures the cached objects by their length in bytes.
+struct BytesMeter;
+
+impl<K> Meter<K, Arc<Vec<u8>>> for BytesMeter {
+    type Measure = usize;
+    fn measure<Q: ?Sized>(&self, _: &Q, v: &Arc<Vec<u8>>) -> usize
+    where K: Borrow<Q> {
+        v.len()
+    }
+}
+
+type MemoryCache = LruCache<String, Arc<Vec<u8>>, DefaultHashBuilder, BytesMeter>;
+
+#[derive(Default)]
+struct CacheMetrics {
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl CacheMetrics {
+    fn hit(&self, name: &'static str) {
+        self.hits.fetch_add(1, Ordering::Relaxed);
+        counter!(METRIC_DAL_CACHE_HITS, 1, "cache" => name);
+    }
+
+    fn miss(&self, name: &'static str) {
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        counter!(METRIC_DAL_CACHE_MISSES, 1, "cache" => name);
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DalCacheStatus {
+    pub name: String,
+    /// Capacity of the cache in bytes.
+    pub capacity: u64,
+    /// Size of the cached objects in bytes.
+    pub size: u64,
+    pub items: u64,
+    pub hits: u64,
+    pub misses: u64,
+}
+
+/// The local cache of immutable objects, shared by all the queries.
+///
+/// The memory tier holds small objects which are read as a whole (snapshots, segments),
+/// the disk tier holds the data blocks.
+pub struct DalCache {
+    memory_cache: Mutex<MemoryCache>,
+    memory_metrics: CacheMetrics,
+    disk_cache: Option<Mutex<LruDiskCache>>,
+    disk_metrics: CacheMetrics,
+    // Objects being assembled for the disk cache, the oldest first.
+    disk_fills: Mutex<VecDeque<Arc<DiskFill>>>,
+}
+
+impl DalCache {
+    const MEMORY: &'static str = "memory";
+    const DISK: &'static str = "disk";
+    // The bytes buffered by the pending disk fills, the oldest fills are dropped beyond it.
+    const MAX_DISK_FILL_BYTES: usize = 256 * 1024 * 1024;
+
+    /// Disk tier is disabled if `disk_capacity` is 0.
+    pub fn create(
+        memory_capacity: u64,
+        disk_path: &str,
+        disk_capacity: u64,
+    ) -> Result<Arc<DalCache>> {
+        let disk_cache = match disk_capacity {
+            0 => None,
+            _ => {
+                let disk_cache = LruDiskCache::new(disk_path, disk_capacity).map_err(|e| {
+                    ErrorCode::UnknownException(format!(
+                        "failed to create disk cache at {}, {}",
+                        disk_path, e
+                    ))
+                })?;
+                Some(Mutex::new(disk_cache))
+            }
+        };
+
+        Ok(Arc::new(DalCache {
+            memory_cache: Mutex::new(LruCache::with_meter(memory_capacity, BytesMeter)),
+            memory_metrics: CacheMetrics::default(),
+            disk_cache,
+            disk_metrics: CacheMetrics::default(),
+            disk_fills: Mutex::new(VecDeque::new()),
+        }))
+    }
+
+    pub fn get_memory(&self, path: &str) -> Option<Arc<Vec<u8>>> {
+        let mut memory_cache = self.memory_cache.lock();
+        match memory_cache.get(path) {
+            Some(bytes) => {
+                self.memory_metrics.hit(Self::MEMORY);
+                Some(bytes.clone())
+            }
+            None => {
+                self.memory_metrics.miss(Self::MEMORY);
+                None
+            }
+        }
+    }
+
+    pub fn put_memory(&self, path: &str, bytes: Arc<Vec<u8>>) {
+        let mut memory_cache = self.memory_cache.lock();
+        // An object larger than the capacity would evict everything, including itself.
+        if bytes.len() as u64 <= memory_cache.capacity() {
+            memory_cache.put(path.to_string(), bytes);
+        }
+    }
+
+    pub fn has_disk_tier(&self) -> bool {
+        self.disk_cache.is_some()
+    }
+
+    /// Opens the cached file of the object, if any.
+    pub fn get_disk(&self, path: &str) -> Option<std::fs::File> {
+        let disk_cache = self.disk_cache.as_ref()?;
+        let key = Self::disk_key(path)?;
+
+        let file = disk_cache.lock().get_file(key).ok();
+        match &file {
+            Some(_) => self.disk_metrics.hit(Self::DISK),
+            None => self.disk_metrics.miss(Self::DISK),
+        }
+        file
+    }
+
+    /// Returns the fill of the object, None if it is cached or can't be cached on disk.
+    ///
+    /// The input streams of an object share one fill, so it is buffered only once.
+    pub fn get_disk_fill(&self, path: &str) -> Option<Arc<DiskFill>> {
+        let disk_cache = self.disk_cache.as_ref()?;
+        let key = Self::disk_key(path)?;
+        if disk_cache.lock().contains_key(key) {
+            return None;
+        }
+
+        let mut disk_fills = self.disk_fills.lock();
+        if let Some(fill) = disk_fills.iter().find(|fill| fill.path() == key) {
+            return Some(fill.clone());
+        }
+
+        // Objects which are never read to the end must not pin their bytes forever.
+        while disk_fills.iter().map(|fill| fill.buffered()).sum::<usize>()
+            > Self::MAX_DISK_FILL_BYTES
+        {
+            match disk_fills.pop_front() {
+                Some(fill) => fill.cancel(),
+                None => break,
+            }
+        }
+
+        let fill = DiskFill::create(key);
+        disk_fills.push_back(fill.clone());
+        Some(fill)
+    }
+
+    /// Stores the completed object of the fill in the disk tier.
+    pub fn end_fill_disk(&self, fill: &DiskFill, bytes: &[u8]) {
+        if let Some(disk_cache) = &self.disk_cache {
+            let mut disk_cache = disk_cache.lock();
+            if disk_cache.can_store(bytes.len() as u64) {
+                if let Err(cause) = disk_cache.insert_bytes(fill.path(), bytes) {
+                    tracing::warn!("Failed to cache {} on disk, {}", fill.path(), cause);
+                }
+            }
+        }
+
+        let mut disk_fills = self.disk_fills.lock();
+        disk_fills.retain(|pending| !std::ptr::eq(pending.as_ref(), fill));
+    }
+
+    /// Removes the object from all the tiers.
+    pub fn remove(&self, path: &str) {
+        self.memory_cache.lock().pop(path);
+
+        if let (Some(disk_cache), Some(key)) = (&self.disk_cache, Self::disk_key(path)) {
+            if let Some(fill) = self.remove_disk_fill(key) {
+                fill.cancel();
+            }
+            if let Err(cause) = disk_cache.lock().remove(key) {
+                tracing::warn!("Failed to remove {} from disk cache, {}", path, cause);
+            }
+        }
+    }
+
+    fn remove_disk_fill(&self, key: &str) -> Option<Arc<DiskFill>> {
+        let mut disk_fills = self.disk_fills.lock();
+        let index = disk_fills.iter().position(|fill| fill.path() == key)?;
+        disk_fills.remove(index)
+    }
+
+    pub fn get_status(&self) -> Vec<DalCacheStatus> {
+        let mut status = vec![];
+
+        {
+            let memory_cache = self.memory_cache.lock();
+            status.push(DalCacheStatus {
+                name: Self::MEMORY.to_string(),
+                capacity: memory_cache.capacity(),
+                size: memory_cache.size(),
+                items: memory_cache.len() as u64,
+                hits: self.memory_metrics.hits.load(Ordering::Relaxed),
+                misses: self.memory_metrics.misses.load(Ordering::Relaxed),
+            });
+        }
+
+        if let Some(disk_cache) = &self.disk_cache {
+            let disk_cache = disk_cache.lock();
+            status.push(DalCacheStatus {
+                name: Self::DISK.to_string(),
+                capacity: disk_cache.capacity(),
+                size: disk_cache.size(),
+                items: disk_cache.len() as u64,
+                hits: self.disk_metrics.hits.load(Ordering::Relaxed),
+                misses: self.disk_metrics.misses.load(Ordering::Relaxed),
+            });
+        }
+
+        status
+    }
+
+    // The object path relative to the disk cache root, None if it can't be cached on disk.
+    fn disk_key(path: &str) -> Option<&str> {
+        let key = path.trim_start_matches('/');
+        let is_normal = Path::new(key)
+            .components()
+            .all(|c| matches!(c, Component::Normal(_)));
+        match !key.is_empty() && is_normal {
+            true => Some(key),
+            false => None,
+        }
+    }
+}
@@ -0,0 +1,289 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::io::SeekFrom;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::Context;
+use std::task::Poll;
+
+use common_base::tokio;
+use common_exception::Result;
+use common_infallible::Mutex;
+use futures::ready;
+use futures::AsyncRead;
+use futures::AsyncSeek;
+
+use crate::DalCache;
+use crate::DataAccessor;
+use crate::InputStream;
+
+/// An object being filled into the disk tier.
+///
+/// The object is assembled from the bytes its input streams read, wherever they seek to,
+/// and goes to the disk tier once all of its bytes are read. Readers usually skip a few
+/// bytes (e.g. the magic of parquet files), such small gaps are read once the last input
+/// stream of the object is dropped.
+pub struct DiskFill {
+    path: String,
+    state: Mutex<DiskFillState>,
+}
+
+#[derive(Default)]
+struct DiskFillState {
+    bytes: Vec<u8>,
+    // The sorted and disjoint ranges of `bytes` which are read.
+    ranges: Vec<(u64, u64)>,
+    // The length of the object, once an input stream reaches or seeks from its end.
+    len: Option<u64>,
+    // Completed or cancelled, the following reads are ignored.
+    done: bool,
+    // The input streams reading the object.
+    readers: usize,
+}
+
+impl DiskFill {
+    // The gaps are read only if they are this small, larger ones mean the object is read
+    // partially (e.g. a projection of the block columns) and it is not worth caching.
+    const MAX_GAP_BYTES: u64 = 64 * 1024;
+
+    pub fn create(path: &str) -> Arc<DiskFill> {
+        Arc::new(DiskFill {
+            path: path.to_string(),
+            state: Mutex::new(DiskFillState::default()),
+        })
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// The size of the bytes buffered so far.
+    pub fn buffered(&self) -> usize {
+        self.state.lock().bytes.len()
+    }
+
+    pub fn cancel(&self) {
+        let mut state = self.state.lock();
+        state.done = true;
+        state.bytes = vec![];
+    }
+
+    /// Records the bytes read at `offset`, returns the object once all of it is read.
+    pub fn write(&self, offset: u64, bytes: &[u8]) -> Option<Vec<u8>> {
+        let mut state = self.state.lock();
+        if state.done || bytes.is_empty() {
+            return None;
+        }
+
+        let (begin, end) = (offset as usize, offset as usize + bytes.len());
+        if state.bytes.len() < end {
+            state.bytes.resize(end, 0);
+        }
+        state.bytes[begin..end].copy_from_slice(bytes);
+        state.add_range(offset, end as u64);
+        state.take_completed()
+    }
+
+    pub fn attach(&self) {
+        self.state.lock().readers += 1;
+    }
+
+    /// Detaches an input stream, returns the gaps to read if it is the last one.
+    pub fn detach(&self) -> Option<Vec<(u64, u64)>> {
+        let mut state = self.state.lock();
+        state.readers -= 1;
+        if state.done || state.readers != 0 {
+            return None;
+        }
+
+        let gaps = state.gaps()?;
+        let gap_bytes: u64 = gaps.iter().map(|(begin, end)| end - begin).sum();
+        match gap_bytes <= Self::MAX_GAP_BYTES {
+            true => Some(gaps),
+            false => None,
+        }
+    }
+
+    /// Records the length of the object, returns the object if all of it is read.
+    pub fn set_len(&self, len: u64) -> Option<Vec<u8>> {
+        let mut state = self.state.lock();
+        if state.done {
+            return None;
+        }
+
+        state.len = Some(len);
+        state.take_completed()
+    }
+}
+
+impl DiskFillState {
+    fn add_range(&mut self, begin: u64, end: u64) {
+        self.ranges.push((begin, end));
+        self.ranges.sort_unstable();
+
+        let mut merged: Vec<(u64, u64)> = Vec::with_capacity(self.ranges.len());
+        for &(begin, end) in self.ranges.iter() {
+            match merged.last_mut() {
+                Some(last) if begin <= last.1 => last.1 = last.1.max(end),
+                _ => merged.push((begin, end)),
+            }
+        }
+        self.ranges = merged;
+    }
+
+    // The ranges not read yet, None if the length is unknown.
+    fn gaps(&self) -> Option<Vec<(u64, u64)>> {
+        let len = self.len?;
+        let mut gaps = vec![];
+        let mut pos = 0;
+        for &(begin, end) in self.ranges.iter() {
+            if pos < begin {
+                gaps.push((pos, begin));
+            }
+            pos = pos.max(end);
+        }
+        if pos < len {
+            gaps.push((pos, len));
+        }
+        Some(gaps)
+    }
+
+    fn take_completed(&mut self) -> Option<Vec<u8>> {
+        let completed = match self.len {
+            Some(0) => true,
+            Some(len) => self.ranges == [(0, len)],
+            None => false,
+        };
+
+        match completed {
+            true => {
+                self.done = true;
+                Some(std::mem::take(&mut self.bytes))
+            }
+            false => None,
+        }
+    }
+}
+
+/// Tees the bytes read from the inner stream into the disk fill of the object.
+pub struct TeeInputStream {
+    path: String,
+    inner: InputStream,
+    inner_accessor: Arc<dyn DataAccessor>,
+    cache: Arc<DalCache>,
+    fill: Arc<DiskFill>,
+    // The position of the inner stream.
+    pos: u64,
+}
+
+impl TeeInputStream {
+    pub fn create(
+        path: &str,
+        inner_accessor: Arc<dyn DataAccessor>,
+        cache: Arc<DalCache>,
+        fill: Arc<DiskFill>,
+    ) -> Result<Self> {
+        let inner = inner_accessor.get_input_stream(path, None)?;
+        fill.attach();
+        Ok(TeeInputStream {
+            path: path.to_string(),
+            inner,
+            inner_accessor,
+            cache,
+            fill,
+            pos: 0,
+        })
+    }
+
+    fn end_fill(&self, bytes: Option<Vec<u8>>) {
+        if let Some(bytes) = bytes {
+            // Writing the cached file is blocking, keep it off the reader.
+            let cache = self.cache.clone();
+            let fill = self.fill.clone();
+            tokio::task::spawn_blocking(move || cache.end_fill_disk(&fill, &bytes));
+        }
+    }
+}
+
+impl Drop for TeeInputStream {
+    fn drop(&mut self) {
+        let gaps = match self.fill.detach() {
+            None => return,
+            Some(gaps) => gaps,
+        };
+
+        let runtime = match tokio::runtime::Handle::try_current() {
+            Err(_) => return,
+            Ok(runtime) => runtime,
+        };
+
+        let path = self.path.clone();
+        let inner_accessor = self.inner_accessor.clone();
+        let cache = self.cache.clone();
+        let fill = self.fill.clone();
+        runtime.spawn(async move {
+            for (begin, end) in gaps {
+                let bytes = match inner_accessor.read_range(&path, begin, end - begin).await {
+                    Err(_) => return,
+                    Ok(bytes) => bytes,
+                };
+                if let Some(bytes) = fill.write(begin, &bytes) {
+                    let _ = tokio::task::spawn_blocking(move || cache.end_fill_disk(&fill, &bytes))
+                        .await;
+                    return;
+                }
+            }
+        });
+    }
+}
+
+impl AsyncRead for TeeInputStream {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<std::io::Result<usize>> {
+        let read = ready!(Pin::new(&mut self.inner).poll_read(cx, buf))?;
+
+        let completed = match read {
+            // The end of the object.
+            0 if !buf.is_empty() => self.fill.set_len(self.pos),
+            _ => self.fill.write(self.pos, &buf[..read]),
+        };
+        self.end_fill(completed);
+
+        self.pos += read as u64;
+        Poll::Ready(Ok(read))
+    }
+}
+
+impl AsyncSeek for TeeInputStream {
+    fn poll_seek(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        pos: SeekFrom,
+    ) -> Poll<std::io::Result<u64>> {
+        let new_pos = ready!(Pin::new(&mut self.inner).poll_seek(cx, pos))?;
+
+        if let SeekFrom::End(offset) = pos {
+            let len = new_pos as i64 - offset;
+            let completed = self.fill.set_len(len as u64);
+            self.end_fill(completed);
+        }
+
+        self.pos = new_pos;
+        Poll::Ready(Ok(new_pos))
+    }
+}
@@ -20,6 +20,9 @@ use common_infallible::RwLock;
 pub struct DalMetrics {
     pub read_bytes: usize,
     pub write_bytes: usize,
+    pub list_count: usize,
+    pub stat_count: usize,
+    pub delete_count: usize,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -46,6 +49,24 @@ impl DalContext {
         metrics.write_bytes += bytes;
     }
 
+    /// Increment list requests.
+    pub fn inc_list_count(&self) {
+        let mut metrics = self.metrics.write();
+        metrics.list_count += 1;
+    }
+
+    /// Increment stat requests.
+    pub fn inc_stat_count(&self) {
+        let mut metrics = self.metrics.write();
+        metrics.stat_count += 1;
+    }
+
+    /// Increment deleted objects.
+    pub fn inc_delete_count(&self, count: usize) {
+        let mut metrics = self.metrics.write();
+        metrics.delete_count += count;
+    }
+
     pub fn get_metrics(&self) -> DalMetrics {
         self.metrics.read().clone()
     }
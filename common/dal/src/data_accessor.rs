@@ -14,12 +14,14 @@
 
 use std::io::Read;
 use std::io::Seek;
+use std::io::SeekFrom;
 
 use common_exception::Result;
 use futures::stream::Stream;
 use futures::AsyncRead;
 use futures::AsyncReadExt;
 use futures::AsyncSeek;
+use futures::AsyncSeekExt;
 
 pub type Bytes = Vec<u8>;
 
@@ -33,6 +35,15 @@ pub trait SeekableReader: Read + Seek {}
 
 impl<T> SeekableReader for T where T: Read + Seek {}
 
+/// Metadata of an object in the storage.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ObjectMeta {
+    /// Path of the object, relative to the root of the accessor.
+    pub path: String,
+    /// Size of the object in bytes.
+    pub size: u64,
+}
+
 #[async_trait::async_trait]
 pub trait DataAccessor: Send + Sync {
     fn get_input_stream(&self, path: &str, stream_len: Option<u64>) -> Result<InputStream>;
@@ -57,4 +68,29 @@ pub trait DataAccessor: Send + Sync {
         input_stream.read_to_end(&mut buffer).await?;
         Ok(buffer)
     }
+
+    /// Reads at most `len` bytes of the object, starting from `offset`.
+    async fn read_range(&self, location: &str, offset: u64, len: u64) -> Result<Vec<u8>> {
+        let mut input_stream = self.get_input_stream(location, None)?;
+        input_stream.seek(SeekFrom::Start(offset)).await?;
+        let mut buffer = Vec::with_capacity(len as usize);
+        input_stream.take(len).read_to_end(&mut buffer).await?;
+        Ok(buffer)
+    }
+
+    /// Lists the objects whose path starts with `prefix`.
+    async fn list(&self, prefix: &str) -> Result<Vec<ObjectMeta>>;
+
+    /// Returns the metadata of the object, or `DALPathNotFound` if it does not exist.
+    async fn stat(&self, path: &str) -> Result<ObjectMeta>;
+
+    /// Deletes the object, deleting a non-existent object is not an error.
+    async fn delete(&self, path: &str) -> Result<()>;
+
+    async fn batch_delete(&self, paths: &[String]) -> Result<()> {
+        for path in paths {
+            self.delete(path).await?;
+        }
+        Ok(())
+    }
 }
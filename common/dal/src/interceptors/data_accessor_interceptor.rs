@@ -22,6 +22,7 @@ use crate::DalContext;
 use crate::DataAccessor;
 use crate::InputStream;
 use crate::InputStreamInterceptor;
+use crate::ObjectMeta;
 
 /// A interceptor for data accessor.
 pub struct DataAccessorInterceptor {
@@ -71,4 +72,47 @@ impl DataAccessor for DataAccessorInterceptor {
             .await
             .map(|_| self.ctx.inc_write_bytes(stream_len as usize))
     }
+
+    async fn read_range(
+        &self,
+        location: &str,
+        offset: u64,
+        len: u64,
+    ) -> common_exception::Result<Vec<u8>> {
+        self.inner
+            .read_range(location, offset, len)
+            .await
+            .map(|bytes| {
+                self.ctx.inc_read_bytes(bytes.len());
+                bytes
+            })
+    }
+
+    async fn list(&self, prefix: &str) -> common_exception::Result<Vec<ObjectMeta>> {
+        self.inner.list(prefix).await.map(|objects| {
+            self.ctx.inc_list_count();
+            objects
+        })
+    }
+
+    async fn stat(&self, path: &str) -> common_exception::Result<ObjectMeta> {
+        self.inner.stat(path).await.map(|meta| {
+            self.ctx.inc_stat_count();
+            meta
+        })
+    }
+
+    async fn delete(&self, path: &str) -> common_exception::Result<()> {
+        self.inner
+            .delete(path)
+            .await
+            .map(|_| self.ctx.inc_delete_count(1))
+    }
+
+    async fn batch_delete(&self, paths: &[String]) -> common_exception::Result<()> {
+        self.inner
+            .batch_delete(paths)
+            .await
+            .map(|_| self.ctx.inc_delete_count(paths.len()))
+    }
 }
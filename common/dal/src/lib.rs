@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod accessors;
+mod cache;
 mod context;
 mod data_accessor;
 mod in_memory_data;
@@ -24,12 +25,18 @@ pub use accessors::aws_s3::S3;
 pub use accessors::azure_blob::AzureBlobAccessor;
 pub use accessors::azure_blob::AzureBlobInputStream;
 pub use accessors::local::Local;
+pub use cache::CachedDataAccessor;
+pub use cache::DalCache;
+pub use cache::DalCacheStatus;
+pub use cache::DiskFill;
+pub use cache::TeeInputStream;
 pub use context::DalContext;
 pub use context::DalMetrics;
 pub use data_accessor::AsyncSeekableReader;
 pub use data_accessor::Bytes;
 pub use data_accessor::DataAccessor;
 pub use data_accessor::InputStream;
+pub use data_accessor::ObjectMeta;
 pub use data_accessor::SeekableReader;
 pub use in_memory_data::InMemoryData;
 pub use schemes::StorageScheme;
@@ -16,6 +16,8 @@
 use common_base::tokio;
 use common_dal::DataAccessor;
 use common_dal::Local;
+use common_dal::ObjectMeta;
+use common_exception::ErrorCode;
 use tempfile::TempDir;
 
 async fn local_read(loops: u32) -> common_exception::Result<()> {
@@ -42,3 +44,87 @@ async fn test_da_local_normal() -> common_exception::Result<()> {
     let read_fut = local_read(1000);
     read_fut.await
 }
+
+#[tokio::test]
+async fn test_da_local_list_stat_delete() -> common_exception::Result<()> {
+    let tmp_root_dir = TempDir::new().unwrap();
+    let root_path = tmp_root_dir.path().to_str().unwrap();
+    let local_da = Local::new(root_path);
+
+    local_da.put("stage/a.csv", vec![1; 10]).await?;
+    local_da.put("stage/b.csv", vec![2; 20]).await?;
+    local_da.put("stage/sub/c.csv", vec![3; 30]).await?;
+    local_da.put("other/d.csv", vec![4; 40]).await?;
+
+    // list a directory, recursively
+    let objects = local_da.list("stage").await?;
+    assert_eq!(objects, vec![
+        ObjectMeta {
+            path: "stage/a.csv".to_string(),
+            size: 10,
+        },
+        ObjectMeta {
+            path: "stage/b.csv".to_string(),
+            size: 20,
+        },
+        ObjectMeta {
+            path: "stage/sub/c.csv".to_string(),
+            size: 30,
+        },
+    ]);
+
+    // list by a partial name
+    let objects = local_da.list("stage/a").await?;
+    assert_eq!(objects.len(), 1);
+    assert_eq!(objects[0].path, "stage/a.csv");
+
+    // list everything
+    let objects = local_da.list("").await?;
+    assert_eq!(objects.len(), 4);
+
+    // list nothing
+    let objects = local_da.list("not_exist/").await?;
+    assert!(objects.is_empty());
+
+    // stat
+    let meta = local_da.stat("stage/sub/c.csv").await?;
+    assert_eq!(meta.size, 30);
+    let res = local_da.stat("stage/not_exist.csv").await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::DALPathNotFoundCode());
+
+    // delete, twice
+    local_da.delete("stage/a.csv").await?;
+    local_da.delete("stage/a.csv").await?;
+    assert!(local_da.stat("stage/a.csv").await.is_err());
+
+    // batch delete
+    local_da
+        .batch_delete(&["stage/b.csv".to_string(), "stage/sub/c.csv".to_string()])
+        .await?;
+    assert!(local_da.list("stage").await?.is_empty());
+    assert_eq!(local_da.list("").await?.len(), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_da_local_read_range() -> common_exception::Result<()> {
+    let tmp_root_dir = TempDir::new().unwrap();
+    let root_path = tmp_root_dir.path().to_str().unwrap();
+    let local_da = Local::new(root_path);
+
+    let content: Vec<u8> = (0..100).collect();
+    local_da.put("test_file", content.clone()).await?;
+
+    let bytes = local_da.read_range("test_file", 10, 20).await?;
+    assert_eq!(bytes, content[10..30].to_vec());
+
+    // range beyond the end of the object
+    let bytes = local_da.read_range("test_file", 90, 20).await?;
+    assert_eq!(bytes, content[90..].to_vec());
+
+    let bytes = local_da.read_range("test_file", 0, 0).await?;
+    assert!(bytes.is_empty());
+
+    Ok(())
+}
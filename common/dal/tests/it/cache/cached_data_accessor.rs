@@ -0,0 +1,195 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::SeekFrom;
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_base::tokio;
+use common_dal::CachedDataAccessor;
+use common_dal::DalCache;
+use common_dal::DalContext;
+use common_dal::DataAccessor;
+use common_dal::DataAccessorInterceptor;
+use common_dal::Local;
+use futures::AsyncReadExt;
+use futures::AsyncSeekExt;
+use tempfile::TempDir;
+
+struct TestFixture {
+    _data_dir: TempDir,
+    _cache_dir: TempDir,
+    // metrics of the accessor behind the cache
+    inner_ctx: Arc<DalContext>,
+    cache: Arc<DalCache>,
+    da: CachedDataAccessor,
+}
+
+impl TestFixture {
+    fn new(memory_capacity: u64, disk_capacity: u64) -> common_exception::Result<Self> {
+        let data_dir = TempDir::new().unwrap();
+        let cache_dir = TempDir::new().unwrap();
+
+        let inner_ctx = Arc::new(DalContext::create());
+        let local = Local::new(data_dir.path().to_str().unwrap());
+        let inner = DataAccessorInterceptor::new(inner_ctx.clone(), Arc::new(local));
+
+        let cache = DalCache::create(
+            memory_capacity,
+            cache_dir.path().to_str().unwrap(),
+            disk_capacity,
+        )?;
+        let da = CachedDataAccessor::new(cache.clone(), Arc::new(inner), &["_ss", "_sg"], &["_b"]);
+
+        Ok(Self {
+            _data_dir: data_dir,
+            _cache_dir: cache_dir,
+            inner_ctx,
+            cache,
+            da,
+        })
+    }
+}
+
+#[tokio::test]
+async fn test_cached_da_memory() -> common_exception::Result<()> {
+    let fixture = TestFixture::new(1024, 0)?;
+    let da = &fixture.da;
+
+    da.put("_ss/snapshot", vec![1; 100]).await?;
+
+    // miss, then hit
+    assert_eq!(da.read("_ss/snapshot").await?, vec![1; 100]);
+    assert_eq!(da.read("_ss/snapshot").await?, vec![1; 100]);
+    assert_eq!(fixture.inner_ctx.get_metrics().read_bytes, 100);
+
+    let status = fixture.cache.get_status();
+    assert_eq!(status.len(), 1);
+    assert_eq!(status[0].name, "memory");
+    assert_eq!(status[0].capacity, 1024);
+    assert_eq!(status[0].size, 100);
+    assert_eq!(status[0].items, 1);
+    assert_eq!(status[0].hits, 1);
+    assert_eq!(status[0].misses, 1);
+
+    // put invalidates the cached object
+    da.put("_ss/snapshot", vec![2; 10]).await?;
+    assert_eq!(da.read("_ss/snapshot").await?, vec![2; 10]);
+
+    // object larger than the capacity is not cached
+    da.put("_sg/segment", vec![3; 2048]).await?;
+    da.read("_sg/segment").await?;
+    da.read("_sg/segment").await?;
+    let status = fixture.cache.get_status();
+    assert_eq!(status[0].items, 1);
+    assert_eq!(status[0].size, 10);
+
+    // delete invalidates the cached object
+    da.delete("_ss/snapshot").await?;
+    assert!(da.read("_ss/snapshot").await.is_err());
+
+    // objects out of the cached prefixes are not cached
+    da.put("data/file.csv", vec![4; 10]).await?;
+    let read_bytes = fixture.inner_ctx.get_metrics().read_bytes;
+    da.read("data/file.csv").await?;
+    da.read("data/file.csv").await?;
+    assert_eq!(fixture.inner_ctx.get_metrics().read_bytes, read_bytes + 20);
+    assert_eq!(fixture.cache.get_status()[0].items, 0);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_cached_da_disk() -> common_exception::Result<()> {
+    let fixture = TestFixture::new(1024, 1024 * 1024)?;
+    let da = &fixture.da;
+
+    let content: Vec<u8> = (0..200).map(|_| rand::random::<u8>()).collect();
+    da.put("_b/block.parquet", content.clone()).await?;
+
+    // miss, the bytes read from the inner accessor are cached in background
+    let mut buffer = vec![];
+    let mut input_stream = da.get_input_stream("_b/block.parquet", None)?;
+    input_stream.read_to_end(&mut buffer).await?;
+    drop(input_stream);
+    assert_eq!(buffer, content);
+    wait_disk_items(&fixture, 1).await;
+
+    // hit, the object is read from the disk cache
+    let mut buffer = vec![];
+    let mut input_stream = da.get_input_stream("_b/block.parquet", None)?;
+    input_stream.read_to_end(&mut buffer).await?;
+    assert_eq!(buffer, content);
+
+    let status = fixture.cache.get_status();
+    assert_eq!(status[1].name, "disk");
+    assert_eq!(status[1].size, 200);
+    assert_eq!(status[1].hits, 1);
+    assert_eq!(status[1].misses, 1);
+
+    // the object is downloaded once, the hit is not read from the inner accessor
+    assert_eq!(fixture.inner_ctx.get_metrics().read_bytes, 200);
+
+    // delete removes the object from the disk cache
+    da.delete("_b/block.parquet").await?;
+    assert_eq!(fixture.cache.get_status()[1].items, 0);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_cached_da_disk_range_reads() -> common_exception::Result<()> {
+    let fixture = TestFixture::new(1024, 1024 * 1024)?;
+    let da = &fixture.da;
+
+    let content: Vec<u8> = (0..200).map(|_| rand::random::<u8>()).collect();
+    da.put("_b/block.parquet", content.clone()).await?;
+
+    // the ranges read by the caller, the magic at the head is skipped like parquet readers do
+    let mut input_stream = da.get_input_stream("_b/block.parquet", None)?;
+    let mut footer = vec![0; 50];
+    input_stream.seek(SeekFrom::End(-50)).await?;
+    input_stream.read_exact(&mut footer).await?;
+    assert_eq!(footer, content[150..]);
+
+    let mut columns = vec![0; 146];
+    input_stream.seek(SeekFrom::Start(4)).await?;
+    input_stream.read_exact(&mut columns).await?;
+    assert_eq!(columns, content[4..150]);
+    drop(input_stream);
+
+    // the gap is read once the stream is dropped
+    wait_disk_items(&fixture, 1).await;
+    assert_eq!(fixture.inner_ctx.get_metrics().read_bytes, 200);
+
+    let mut buffer = vec![];
+    let mut input_stream = da.get_input_stream("_b/block.parquet", None)?;
+    input_stream.read_to_end(&mut buffer).await?;
+    assert_eq!(buffer, content);
+    assert_eq!(fixture.cache.get_status()[1].hits, 1);
+
+    Ok(())
+}
+
+async fn wait_disk_items(fixture: &TestFixture, items: u64) {
+    let mut disk_items = 0;
+    for _ in 0..100 {
+        disk_items = fixture.cache.get_status()[1].items;
+        if disk_items == items {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    assert_eq!(disk_items, items);
+}
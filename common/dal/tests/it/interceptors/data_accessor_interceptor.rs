@@ -147,3 +147,48 @@ async fn test_dal_metrics_partial_read() -> common_exception::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_dal_metrics_read_range() -> common_exception::Result<()> {
+    // setup
+    let fixture = TestFixture::new();
+    let dal = &fixture.da_with_metric;
+
+    let len = 100;
+    fixture.gen_rand_content("test_path", len).await?;
+
+    // read 20 bytes
+    let bytes = dal.read_range("test_path", 10, 20).await?;
+    assert_eq!(20, bytes.len());
+
+    // check
+    let metrics = fixture.get_metrics();
+    assert_eq!(20, metrics.read_bytes);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_dal_metrics_list_stat_delete() -> common_exception::Result<()> {
+    // setup
+    let fixture = TestFixture::new();
+    let dal = &fixture.da_with_metric;
+
+    fixture.gen_rand_content("dir/test_path_1", 10).await?;
+    fixture.gen_rand_content("dir/test_path_2", 10).await?;
+    fixture.gen_rand_content("dir/test_path_3", 10).await?;
+
+    assert_eq!(3, dal.list("dir").await?.len());
+    assert_eq!(10, dal.stat("dir/test_path_1").await?.size);
+    dal.delete("dir/test_path_1").await?;
+    dal.batch_delete(&["dir/test_path_2".to_string(), "dir/test_path_3".to_string()])
+        .await?;
+
+    // check
+    let metrics = fixture.get_metrics();
+    assert_eq!(1, metrics.list_count);
+    assert_eq!(1, metrics.stat_count);
+    assert_eq!(3, metrics.delete_count);
+
+    Ok(())
+}
@@ -13,5 +13,6 @@
 // limitations under the License.
 
 mod accessors;
+mod cache;
 mod interceptors;
 mod schemes;
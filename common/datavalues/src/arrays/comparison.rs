@@ -114,6 +114,34 @@ pub trait ArrayCompare<Rhs>: Debug {
             self,
         )))
     }
+
+    fn ilike(&self, _rhs: Rhs) -> Result<DFBooleanArray> {
+        Err(ErrorCode::BadDataValueType(format!(
+            "Unsupported compare operation: ilike for {:?}",
+            self,
+        )))
+    }
+
+    fn nilike(&self, _rhs: Rhs) -> Result<DFBooleanArray> {
+        Err(ErrorCode::BadDataValueType(format!(
+            "Unsupported compare operation: nilike for {:?}",
+            self,
+        )))
+    }
+
+    fn regexp(&self, _rhs: Rhs) -> Result<DFBooleanArray> {
+        Err(ErrorCode::BadDataValueType(format!(
+            "Unsupported compare operation: regexp for {:?}",
+            self,
+        )))
+    }
+
+    fn nregexp(&self, _rhs: Rhs) -> Result<DFBooleanArray> {
+        Err(ErrorCode::BadDataValueType(format!(
+            "Unsupported compare operation: nregexp for {:?}",
+            self,
+        )))
+    }
 }
 
 impl<T> DFPrimitiveArray<T>
@@ -282,6 +310,38 @@ impl DFStringArray {
     fn nlike_scalar(&self, rhs: &[u8]) -> Result<DFBooleanArray> {
         self.a_like_binary_scalar(rhs, |x| !x)
     }
+
+    fn ilike(&self, rhs: &DFStringArray) -> Result<DFBooleanArray> {
+        self.a_ilike_binary(rhs, |x| x)
+    }
+
+    fn ilike_scalar(&self, rhs: &[u8]) -> Result<DFBooleanArray> {
+        self.a_ilike_binary_scalar(rhs, |x| x)
+    }
+
+    fn nilike(&self, rhs: &DFStringArray) -> Result<DFBooleanArray> {
+        self.a_ilike_binary(rhs, |x| !x)
+    }
+
+    fn nilike_scalar(&self, rhs: &[u8]) -> Result<DFBooleanArray> {
+        self.a_ilike_binary_scalar(rhs, |x| !x)
+    }
+
+    fn regexp(&self, rhs: &DFStringArray) -> Result<DFBooleanArray> {
+        self.a_regexp_binary(rhs, |x| x)
+    }
+
+    fn regexp_scalar(&self, rhs: &[u8]) -> Result<DFBooleanArray> {
+        self.a_regexp_binary_scalar(rhs, |x| x)
+    }
+
+    fn nregexp(&self, rhs: &DFStringArray) -> Result<DFBooleanArray> {
+        self.a_regexp_binary(rhs, |x| !x)
+    }
+
+    fn nregexp_scalar(&self, rhs: &[u8]) -> Result<DFBooleanArray> {
+        self.a_regexp_binary_scalar(rhs, |x| !x)
+    }
 }
 
 macro_rules! impl_like_string {
@@ -339,6 +399,87 @@ impl ArrayCompare<&DFStringArray> for DFStringArray {
     fn nlike(&self, rhs: &DFStringArray) -> Result<DFBooleanArray> {
         impl_like_string! {self, rhs, nlike, nlike_scalar}
     }
+
+    fn ilike(&self, rhs: &DFStringArray) -> Result<DFBooleanArray> {
+        impl_like_string! {self, rhs, ilike, ilike_scalar}
+    }
+
+    fn nilike(&self, rhs: &DFStringArray) -> Result<DFBooleanArray> {
+        impl_like_string! {self, rhs, nilike, nilike_scalar}
+    }
+
+    fn regexp(&self, rhs: &DFStringArray) -> Result<DFBooleanArray> {
+        impl_like_string! {self, rhs, regexp, regexp_scalar}
+    }
+
+    fn nregexp(&self, rhs: &DFStringArray) -> Result<DFBooleanArray> {
+        impl_like_string! {self, rhs, nregexp, nregexp_scalar}
+    }
+}
+
+impl DFDecimalArray {
+    /// Both sides must have the same scale, arrays with length 1 are broadcasted.
+    fn comparison(&self, rhs: &DFDecimalArray, op: Operator) -> Result<DFBooleanArray> {
+        let cmp = |a: i128, b: i128| match op {
+            Operator::Eq => a == b,
+            Operator::Neq => a != b,
+            Operator::Gt => a > b,
+            Operator::GtEq => a >= b,
+            Operator::Lt => a < b,
+            Operator::LtEq => a <= b,
+        };
+
+        let size = match (self.len(), rhs.len()) {
+            (a, b) if a == b => a,
+            (a, 1) => a,
+            (1, b) => b,
+            (a, b) => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Decimal comparison requires the arrays to have the same length ({} != {})",
+                    a, b
+                )))
+            }
+        };
+
+        let index_of = |len: usize, index: usize| if len == 1 { 0 } else { index };
+        Ok((0..size)
+            .map(|i| {
+                match (
+                    self.get(index_of(self.len(), i)),
+                    rhs.get(index_of(rhs.len(), i)),
+                ) {
+                    (Some(a), Some(b)) => Some(cmp(a, b)),
+                    _ => None,
+                }
+            })
+            .collect())
+    }
+}
+
+impl ArrayCompare<&DFDecimalArray> for DFDecimalArray {
+    fn eq(&self, rhs: &DFDecimalArray) -> Result<DFBooleanArray> {
+        self.comparison(rhs, Operator::Eq)
+    }
+
+    fn neq(&self, rhs: &DFDecimalArray) -> Result<DFBooleanArray> {
+        self.comparison(rhs, Operator::Neq)
+    }
+
+    fn gt(&self, rhs: &DFDecimalArray) -> Result<DFBooleanArray> {
+        self.comparison(rhs, Operator::Gt)
+    }
+
+    fn gt_eq(&self, rhs: &DFDecimalArray) -> Result<DFBooleanArray> {
+        self.comparison(rhs, Operator::GtEq)
+    }
+
+    fn lt(&self, rhs: &DFDecimalArray) -> Result<DFBooleanArray> {
+        self.comparison(rhs, Operator::Lt)
+    }
+
+    fn lt_eq(&self, rhs: &DFDecimalArray) -> Result<DFBooleanArray> {
+        self.comparison(rhs, Operator::LtEq)
+    }
 }
 
 impl ArrayCompare<&DFNullArray> for DFNullArray {}
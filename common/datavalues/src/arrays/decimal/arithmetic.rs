@@ -0,0 +1,146 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::prelude::*;
+
+fn overflow_error(op: &DataValueArithmeticOperator, data_type: &DataType) -> ErrorCode {
+    ErrorCode::Overflow(format!(
+        "Decimal overflow in operation {}, the result is out of range of {:?}",
+        op, data_type
+    ))
+}
+
+fn pow10(exponent: usize, op: &DataValueArithmeticOperator, data_type: &DataType) -> Result<i128> {
+    decimal_scale_multiplier(exponent).ok_or_else(|| overflow_error(op, data_type))
+}
+
+impl DFDecimalArray {
+    /// Apply the arithmetic operation with another decimal array, the scales of both sides can be different.
+    /// The result type follows `decimal_arithmetic_coercion`, arrays of length 1 are broadcasted.
+    pub fn arithmetic(&self, op: &DataValueArithmeticOperator, rhs: &Self) -> Result<Self> {
+        let data_type = decimal_arithmetic_coercion(op, self.data_type(), rhs.data_type())?;
+        let (precision, scale) = data_type.decimal_precision_scale().ok_or_else(|| {
+            ErrorCode::BadDataValueType(format!(
+                "Unexpected result type {:?} of decimal arithmetic",
+                data_type
+            ))
+        })?;
+
+        let (s1, s2) = (self.scale(), rhs.scale());
+        let result_type = &data_type;
+        let op_fn: Box<dyn Fn(i128, i128) -> Result<i128> + '_> = match op {
+            DataValueArithmeticOperator::Plus | DataValueArithmeticOperator::Minus => {
+                let is_plus = matches!(op, DataValueArithmeticOperator::Plus);
+                Box::new(move |a, b| {
+                    let a = rescale_decimal(a, s1, scale)?;
+                    let b = rescale_decimal(b, s2, scale)?;
+                    let value = if is_plus {
+                        a.checked_add(b)
+                    } else {
+                        a.checked_sub(b)
+                    };
+                    value.ok_or_else(|| overflow_error(op, result_type))
+                })
+            }
+            DataValueArithmeticOperator::Mul => Box::new(move |a, b| {
+                let value = a
+                    .checked_mul(b)
+                    .ok_or_else(|| overflow_error(op, result_type))?;
+                rescale_decimal(value, s1 + s2, scale)
+            }),
+            DataValueArithmeticOperator::Div => Box::new(move |a, b| {
+                if b == 0 {
+                    return Err(ErrorCode::BadArguments("Division by zero"));
+                }
+                if a == 0 {
+                    return Ok(0);
+                }
+
+                // a / 10^s1 / (b / 10^s2) * 10^scale = a * 10^(scale + s2 - s1) / b
+                match scale + s2 >= s1 {
+                    true => a
+                        .checked_mul(pow10(scale + s2 - s1, op, result_type)?)
+                        .map(|a| a / b)
+                        .ok_or_else(|| overflow_error(op, result_type)),
+                    false => Ok(a / b / pow10(s1 - scale - s2, op, result_type)?),
+                }
+            }),
+            DataValueArithmeticOperator::Modulo => Box::new(move |a, b| {
+                let a = rescale_decimal(a, s1, scale)?;
+                let b = rescale_decimal(b, s2, scale)?;
+                match b {
+                    0 => Err(ErrorCode::BadArguments("Division by zero")),
+                    _ => Ok(a % b),
+                }
+            }),
+            DataValueArithmeticOperator::IntDiv => {
+                return Err(ErrorCode::BadDataValueType(format!(
+                    "Unsupported decimal operation {}",
+                    op
+                )))
+            }
+        };
+
+        let size = match (self.len(), rhs.len()) {
+            (a, b) if a == b => a,
+            (a, 1) => a,
+            (1, b) => b,
+            (a, b) => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Decimal arithmetic requires the arrays to have the same length ({} != {})",
+                    a, b
+                )))
+            }
+        };
+
+        // arrays with length 1 are treated as constants
+        let index_of = |len: usize, index: usize| if len == 1 { 0 } else { index };
+        let values = (0..size)
+            .map(|i| {
+                match (
+                    self.get(index_of(self.len(), i)),
+                    rhs.get(index_of(rhs.len(), i)),
+                ) {
+                    (Some(a), Some(b)) => op_fn(a, b)
+                        .and_then(|v| check_decimal_precision(v, precision))
+                        .map(Some),
+                    _ => Ok(None),
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self::new_from_opt_iter(values.into_iter(), &data_type))
+    }
+
+    pub fn negative(&self) -> Result<Self> {
+        let values = self
+            .inner()
+            .iter()
+            .map(|v| match v {
+                Some(v) => v.checked_neg().map(Some).ok_or_else(|| {
+                    overflow_error(&DataValueArithmeticOperator::Minus, self.data_type())
+                }),
+                None => Ok(None),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self::new_from_opt_iter(
+            values.into_iter(),
+            self.data_type(),
+        ))
+    }
+}
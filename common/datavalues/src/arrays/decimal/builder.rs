@@ -0,0 +1,55 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_arrow::arrow::array::*;
+
+use crate::prelude::*;
+
+pub struct DecimalArrayBuilder {
+    builder: MutablePrimitiveArray<i128>,
+    data_type: DataType,
+}
+
+impl ArrayBuilder<i128, DFDecimalArray> for DecimalArrayBuilder {
+    /// Appends a value of type `T` into the builder
+    #[inline]
+    fn append_value(&mut self, v: i128) {
+        self.builder.push(Some(v))
+    }
+
+    /// Appends a null slot into the builder
+    #[inline]
+    fn append_null(&mut self) {
+        self.builder.push_null();
+    }
+
+    fn finish(&mut self) -> DFDecimalArray {
+        let array = self.builder.as_arc();
+        let array = array
+            .as_any()
+            .downcast_ref::<PrimitiveArray<i128>>()
+            .unwrap()
+            .clone();
+        DFDecimalArray::new(array.to(self.data_type.to_arrow()))
+    }
+}
+
+impl DecimalArrayBuilder {
+    pub fn with_capacity(capacity: usize, data_type: &DataType) -> Self {
+        DecimalArrayBuilder {
+            builder: MutablePrimitiveArray::<i128>::with_capacity(capacity),
+            data_type: data_type.clone(),
+        }
+    }
+}
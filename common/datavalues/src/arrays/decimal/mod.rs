@@ -0,0 +1,190 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_arrow::arrow::array::*;
+use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::compute::aggregate;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::prelude::*;
+
+mod arithmetic;
+mod builder;
+mod iterator;
+
+pub use builder::*;
+pub use iterator::*;
+
+/// DFDecimalArray wraps arrow's decimal array, values are stored unscaled as i128.
+#[derive(Debug, Clone)]
+pub struct DFDecimalArray {
+    pub(crate) array: PrimitiveArray<i128>,
+    data_type: DataType,
+}
+
+impl From<PrimitiveArray<i128>> for DFDecimalArray {
+    fn from(array: PrimitiveArray<i128>) -> Self {
+        Self::new(array)
+    }
+}
+
+impl DFDecimalArray {
+    pub fn new(array: PrimitiveArray<i128>) -> Self {
+        let data_type: DataType = array.data_type().into();
+        Self { array, data_type }
+    }
+
+    pub fn from_arrow_array(array: &dyn Array) -> Self {
+        Self::new(
+            array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<i128>>()
+                .unwrap()
+                .clone(),
+        )
+    }
+
+    pub fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    pub fn precision(&self) -> usize {
+        self.data_type.decimal_precision_scale().unwrap().0
+    }
+
+    pub fn scale(&self) -> usize {
+        self.data_type.decimal_precision_scale().unwrap().1
+    }
+
+    pub fn inner(&self) -> &PrimitiveArray<i128> {
+        &self.array
+    }
+
+    /// # Safety
+    /// Note this doesn't do any bound checking, for performance reason.
+    pub unsafe fn try_get(&self, index: usize) -> Result<DataValue> {
+        let v = match self.array.is_null(index) {
+            true => None,
+            false => Some(self.array.value_unchecked(index)),
+        };
+        Ok(self.to_data_value(v))
+    }
+
+    /// Wrap an unscaled value into a DataValue of this array's type.
+    pub fn to_data_value(&self, v: Option<i128>) -> DataValue {
+        DataValue::Decimal128(v, self.precision(), self.scale())
+    }
+
+    pub fn len(&self) -> usize {
+        self.array.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[inline]
+    pub fn null_count(&self) -> usize {
+        self.array.null_count()
+    }
+
+    #[inline]
+    pub fn is_null(&self, i: usize) -> bool {
+        self.array.is_null(i)
+    }
+
+    #[inline]
+    pub fn all_is_null(&self) -> bool {
+        self.null_count() == self.len()
+    }
+
+    #[inline]
+    /// Get the null count and the buffer of bits representing null values
+    pub fn null_bits(&self) -> (usize, Option<&Bitmap>) {
+        (self.array.null_count(), self.array.validity())
+    }
+
+    /// Take a view of top n elements
+    pub fn limit(&self, num_elements: usize) -> Self {
+        self.slice(0, num_elements)
+    }
+
+    pub fn slice(&self, offset: usize, length: usize) -> Self {
+        let array = self.array.slice(offset, length);
+        Self::new(array)
+    }
+
+    /// Unpack a array to the same physical type.
+    ///
+    /// # Safety
+    ///
+    /// This is unsafe as the data_type may be uncorrect and
+    /// is assumed to be correct in other unsafe code.
+    pub unsafe fn unpack(&self, array: &Series) -> Result<&Self> {
+        let array_trait = &**array;
+        if self.data_type() == array.data_type() {
+            let ca = &*(array_trait as *const dyn SeriesTrait as *const Self);
+            Ok(ca)
+        } else {
+            Err(ErrorCode::IllegalDataType(format!(
+                "cannot unpack array {:?} into matching type {:?}",
+                array,
+                self.data_type()
+            )))
+        }
+    }
+
+    #[inline]
+    pub fn get_array_ref(&self) -> ArrayRef {
+        Arc::new(self.array.clone()) as ArrayRef
+    }
+
+    pub fn get_array_memory_size(&self) -> usize {
+        aggregate::estimated_bytes_size(&self.array)
+    }
+
+    pub fn collect_values(&self) -> Vec<Option<i128>> {
+        self.array.iter().map(|v| v.copied()).collect()
+    }
+
+    /// Create a new DFDecimalArray with the given data type from an iterator.
+    pub fn new_from_opt_iter(it: impl Iterator<Item = Option<i128>>, data_type: &DataType) -> Self {
+        let array: PrimitiveArray<i128> = it.collect();
+        Self::new(array.to(data_type.to_arrow()))
+    }
+
+    pub fn new_from_iter(it: impl Iterator<Item = i128>, data_type: &DataType) -> Self {
+        Self::new_from_opt_iter(it.map(Some), data_type)
+    }
+
+    pub fn new_from_slice(v: &[i128], data_type: &DataType) -> Self {
+        Self::new_from_iter(v.iter().copied(), data_type)
+    }
+
+    pub fn new_from_opt_slice(v: &[Option<i128>], data_type: &DataType) -> Self {
+        Self::new_from_opt_iter(v.iter().copied(), data_type)
+    }
+
+    pub fn full(value: i128, length: usize, data_type: &DataType) -> Self {
+        Self::new_from_iter(std::iter::repeat(value).take(length), data_type)
+    }
+
+    pub fn full_null(length: usize, data_type: &DataType) -> Self {
+        Self::new_from_opt_iter(std::iter::repeat(None).take(length), data_type)
+    }
+}
@@ -351,6 +351,66 @@ impl ArrayAgg for DFStringArray {
     }
 }
 
+impl ArrayAgg for DFDecimalArray {
+    fn sum(&self) -> Result<DataValue> {
+        if self.all_is_null() {
+            return Ok(self.to_data_value(None));
+        }
+
+        let mut sum = 0_i128;
+        for value in self.inner().iter().flatten() {
+            sum = sum.checked_add(*value).ok_or_else(|| {
+                ErrorCode::Overflow(format!("Sum of {:?} is out of range", self.data_type()))
+            })?;
+        }
+        Ok(self.to_data_value(Some(sum)))
+    }
+
+    fn min(&self) -> Result<DataValue> {
+        let value = self.inner().iter().flatten().min();
+        Ok(self.to_data_value(value.copied()))
+    }
+
+    fn max(&self) -> Result<DataValue> {
+        let value = self.inner().iter().flatten().max();
+        Ok(self.to_data_value(value.copied()))
+    }
+
+    fn arg_min(&self) -> Result<DataValue> {
+        let value = self
+            .inner()
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, val)| val.map(|val| (idx, val)))
+            .reduce(|acc, (idx, val)| if acc.1 > val { (idx, val) } else { acc });
+
+        Ok(match value {
+            Some((index, value)) => DataValue::Struct(vec![
+                (index as u64).into(),
+                self.to_data_value(Some(*value)),
+            ]),
+            None => DataValue::Struct(vec![DataValue::UInt64(None), self.to_data_value(None)]),
+        })
+    }
+
+    fn arg_max(&self) -> Result<DataValue> {
+        let value = self
+            .inner()
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, val)| val.map(|val| (idx, val)))
+            .reduce(|acc, (idx, val)| if acc.1 < val { (idx, val) } else { acc });
+
+        Ok(match value {
+            Some((index, value)) => DataValue::Struct(vec![
+                (index as u64).into(),
+                self.to_data_value(Some(*value)),
+            ]),
+            None => DataValue::Struct(vec![DataValue::UInt64(None), self.to_data_value(None)]),
+        })
+    }
+}
+
 impl ArrayAgg for DFListArray {}
 
 impl ArrayAgg for DFNullArray {}
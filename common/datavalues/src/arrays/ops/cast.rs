@@ -21,6 +21,8 @@ use common_arrow::arrow::compute::cast;
 use common_arrow::arrow::compute::cast::CastOptions;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use num::NumCast;
+use num::ToPrimitive;
 
 use crate::prelude::*;
 use crate::series::IntoSeries;
@@ -47,11 +49,49 @@ fn cast_ca(ca: &dyn Array, data_type: &DataType) -> Result<Series> {
     Ok(array.into_series())
 }
 
+fn cast_to_decimal<T, F>(
+    iter: impl Iterator<Item = Option<T>>,
+    data_type: &DataType,
+    f: F,
+) -> Result<Series>
+where
+    F: Fn(T, usize) -> Result<i128>,
+{
+    let (precision, scale) = data_type.decimal_precision_scale().unwrap();
+    let values = iter
+        .map(|v| match v {
+            Some(v) => f(v, scale)
+                .and_then(|v| check_decimal_precision(v, precision))
+                .map(Some),
+            None => Ok(None),
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(DFDecimalArray::new_from_opt_iter(values.into_iter(), data_type).into_series())
+}
+
 impl<T> ArrayCast for DFPrimitiveArray<T>
 where T: DFPrimitiveType
 {
     fn cast_with_type(&self, data_type: &DataType) -> Result<Series> {
-        cast_ca(&self.array, data_type)
+        if !data_type.is_decimal() {
+            return cast_ca(&self.array, data_type);
+        }
+
+        cast_to_decimal(self.inner().iter(), data_type, |v: &T, scale| {
+            if T::FLOATING {
+                return decimal_from_f64(v.to_f64().unwrap_or_default(), scale);
+            }
+
+            v.to_i128()
+                .and_then(|v| v.checked_mul(decimal_scale_multiplier(scale)?))
+                .ok_or_else(|| {
+                    ErrorCode::Overflow(format!(
+                        "Cannot cast {:?} into {:?}, the value is out of range",
+                        v, data_type
+                    ))
+                })
+        })
     }
 }
 
@@ -74,6 +114,8 @@ impl ArrayCast for DFStringArray {
             });
 
             Ok(c.into_series())
+        } else if data_type.is_decimal() {
+            cast_to_decimal(self.inner().iter(), data_type, parse_decimal)
         } else {
             cast_ca(&self.array, data_type)
         }
@@ -103,7 +145,62 @@ impl ArrayCast for DFNullArray {
             DataType::Float64 => Ok(DFFloat64Array::full_null(self.len()).into_series()),
             DataType::String => Ok(DFStringArray::full_null(self.len()).into_series()),
             DataType::List(_) => Ok(DFListArray::full_null(self.len()).into_series()),
+            DataType::Decimal128(_, _) => {
+                Ok(DFDecimalArray::full_null(self.len(), data_type).into_series())
+            }
+
+            _ => Err(ErrorCode::BadDataValueType(format!(
+                "Unsupported cast_with_type from array: {:?} into data_type: {:?}",
+                self, data_type,
+            ))),
+        }
+    }
+}
 
+fn cast_from_decimal<T, F>(array: &DFDecimalArray, f: F) -> Series
+where
+    T: DFPrimitiveType,
+    F: Fn(i128) -> Option<T>,
+{
+    let iter = array.inner().iter().map(|v| v.and_then(|v| f(*v)));
+    DFPrimitiveArray::<T>::new_from_opt_iter(iter).into_series()
+}
+
+impl ArrayCast for DFDecimalArray {
+    fn cast_with_type(&self, data_type: &DataType) -> Result<Series> {
+        let scale = self.scale();
+        let multiplier = decimal_scale_multiplier(scale).unwrap();
+
+        match data_type {
+            DataType::Decimal128(_, to_scale) => {
+                cast_to_decimal(self.inner().iter(), data_type, |v: &i128, _| {
+                    rescale_decimal(*v, scale, *to_scale)
+                })
+            }
+            DataType::Float32 => Ok(cast_from_decimal::<f32, _>(self, |v| {
+                Some(decimal_to_f64(v, scale) as f32)
+            })),
+            DataType::Float64 => Ok(cast_from_decimal::<f64, _>(self, |v| {
+                Some(decimal_to_f64(v, scale))
+            })),
+            DataType::String => {
+                let array: DFStringArray = self
+                    .inner()
+                    .iter()
+                    .map(|v| v.map(|v| decimal_to_string(*v, scale)))
+                    .collect();
+                Ok(array.into_series())
+            }
+            DataType::Boolean => {
+                let array: DFBooleanArray =
+                    self.inner().iter().map(|v| v.map(|v| *v != 0)).collect();
+                Ok(array.into_series())
+            }
+            _ if data_type.is_integer() => {
+                // Integer casts truncate the fractional part.
+                let series = cast_from_decimal::<i64, _>(self, |v| NumCast::from(v / multiplier));
+                series.cast_with_type(data_type)
+            }
             _ => Err(ErrorCode::BadDataValueType(format!(
                 "Unsupported cast_with_type from array: {:?} into data_type: {:?}",
                 self, data_type,
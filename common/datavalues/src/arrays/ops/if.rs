@@ -154,6 +154,21 @@ impl ArrayIf for DFStringArray {
     }
 }
 
+impl ArrayIf for DFDecimalArray {
+    fn if_then_else(&self, rhs: &Self, predicate: &DFBooleanArray) -> Result<Self> {
+        // arrays with length 1 are treated as constants
+        let index_of = |len: usize, index: usize| if len == 1 { 0 } else { index };
+
+        let size = predicate.len().max(self.len()).max(rhs.len());
+        let iter = (0..size).map(|i| match predicate.get(index_of(predicate.len(), i)) {
+            Some(true) => self.get(index_of(self.len(), i)),
+            _ => rhs.get(index_of(rhs.len(), i)),
+        });
+
+        Ok(DFDecimalArray::new_from_opt_iter(iter, self.data_type()))
+    }
+}
+
 impl ArrayIf for DFNullArray {
     fn if_then_else(&self, _rhs: &Self, _predicate: &DFBooleanArray) -> Result<Self> {
         Ok(self.clone())
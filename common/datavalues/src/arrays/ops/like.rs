@@ -25,6 +25,25 @@ impl DFStringArray {
     /// QUOTE: (From arrow2::arrow::compute::like::a_like_binary)
     pub fn a_like_binary<F>(&self, rhs: &Self, op: F) -> Result<DFBooleanArray>
     where F: Fn(bool) -> bool {
+        self.a_regex_binary(rhs, like_regex, op)
+    }
+
+    pub fn a_ilike_binary<F>(&self, rhs: &Self, op: F) -> Result<DFBooleanArray>
+    where F: Fn(bool) -> bool {
+        self.a_regex_binary(rhs, ilike_regex, op)
+    }
+
+    pub fn a_regexp_binary<F>(&self, rhs: &Self, op: F) -> Result<DFBooleanArray>
+    where F: Fn(bool) -> bool {
+        self.a_regex_binary(rhs, regexp_regex, op)
+    }
+
+    /// Match every row with the regex built from the pattern in the same row, the built regexes are cached by pattern.
+    fn a_regex_binary<F, B>(&self, rhs: &Self, build: B, op: F) -> Result<DFBooleanArray>
+    where
+        F: Fn(bool) -> bool,
+        B: Fn(&[u8]) -> Result<BytesRegex>,
+    {
         if self.len() != rhs.len() {
             return Err(ErrorCode::BadArguments(
                 "Cannot perform comparison operation on arrays of different length".to_string(),
@@ -42,19 +61,7 @@ impl DFStringArray {
                     let pattern = if let Some(pattern) = map.get(rhs) {
                         pattern
                     } else {
-                        let pattern_str = simdutf8::basic::from_utf8(rhs).map_err(|e| {
-                            ErrorCode::BadArguments(format!(
-                                "Unable to convert the LIKE pattern to string: {}",
-                                e
-                            ))
-                        })?;
-                        let re_pattern = like_pattern_to_regex(pattern_str);
-                        let re = BytesRegex::new(&re_pattern).map_err(|e| {
-                            ErrorCode::BadArguments(format!(
-                                "Unable to build regex from LIKE pattern: {}",
-                                e
-                            ))
-                        })?;
+                        let re = build(rhs)?;
                         map.insert(rhs, re);
                         map.get(rhs).unwrap()
                     };
@@ -88,24 +95,62 @@ impl DFStringArray {
                 Bitmap::from_trusted_len_iter(arr.values_iter().map(|x| op(x.ends_with(ends_with))))
             }
             PatternType::PatternStr => {
-                let pattern = simdutf8::basic::from_utf8(rhs).map_err(|e| {
-                    ErrorCode::BadArguments(format!(
-                        "Unable to convert the LIKE pattern to string: {}",
-                        e
-                    ))
-                })?;
-                let re_pattern = like_pattern_to_regex(pattern);
-                let re = BytesRegex::new(&re_pattern).map_err(|e| {
-                    ErrorCode::BadArguments(format!(
-                        "Unable to build regex from LIKE pattern: {}",
-                        e
-                    ))
-                })?;
+                let re = like_regex(rhs)?;
                 Bitmap::from_trusted_len_iter(arr.values_iter().map(|x| op(re.is_match(x))))
             }
         };
         Ok(DFBooleanArray::from_arrow_data(values, validity.cloned()))
     }
+
+    pub fn a_ilike_binary_scalar<F>(&self, rhs: &[u8], op: F) -> Result<DFBooleanArray>
+    where F: Fn(bool) -> bool {
+        self.a_regex_binary_scalar(&ilike_regex(rhs)?, op)
+    }
+
+    pub fn a_regexp_binary_scalar<F>(&self, rhs: &[u8], op: F) -> Result<DFBooleanArray>
+    where F: Fn(bool) -> bool {
+        self.a_regex_binary_scalar(&regexp_regex(rhs)?, op)
+    }
+
+    fn a_regex_binary_scalar<F>(&self, re: &BytesRegex, op: F) -> Result<DFBooleanArray>
+    where F: Fn(bool) -> bool {
+        let arr = self.inner();
+        let values = Bitmap::from_trusted_len_iter(arr.values_iter().map(|x| op(re.is_match(x))));
+        Ok(DFBooleanArray::from_arrow_data(
+            values,
+            arr.validity().cloned(),
+        ))
+    }
+}
+
+fn build_regex(pattern: &[u8], operator: &str, to_regex: fn(&str) -> String) -> Result<BytesRegex> {
+    let pattern = simdutf8::basic::from_utf8(pattern).map_err(|e| {
+        ErrorCode::BadArguments(format!(
+            "Unable to convert the {} pattern to string: {}",
+            operator, e
+        ))
+    })?;
+
+    BytesRegex::new(&to_regex(pattern)).map_err(|e| {
+        ErrorCode::BadArguments(format!(
+            "Unable to build regex from {} pattern: {}",
+            operator, e
+        ))
+    })
+}
+
+fn like_regex(pattern: &[u8]) -> Result<BytesRegex> {
+    build_regex(pattern, "LIKE", like_pattern_to_regex)
+}
+
+fn ilike_regex(pattern: &[u8]) -> Result<BytesRegex> {
+    build_regex(pattern, "ILIKE", |pattern| {
+        format!("(?i){}", like_pattern_to_regex(pattern))
+    })
+}
+
+fn regexp_regex(pattern: &[u8]) -> Result<BytesRegex> {
+    build_regex(pattern, "REGEXP", |pattern| pattern.to_string())
 }
 
 fn is_like_pattern_escape(c: u8) -> bool {
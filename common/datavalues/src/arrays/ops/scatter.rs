@@ -19,6 +19,7 @@ use common_exception::Result;
 
 use crate::arrays::get_list_builder;
 use crate::arrays::BooleanArrayBuilder;
+use crate::arrays::DecimalArrayBuilder;
 use crate::arrays::PrimitiveArrayBuilder;
 use crate::arrays::StringArrayBuilder;
 use crate::prelude::*;
@@ -134,6 +135,49 @@ impl ArrayScatter for DFBooleanArray {
     }
 }
 
+impl ArrayScatter for DFDecimalArray {
+    unsafe fn scatter_unchecked(
+        &self,
+        indices: &mut dyn Iterator<Item = u64>,
+        scattered_size: usize,
+    ) -> Result<Vec<Self>>
+    where
+        Self: std::marker::Sized,
+    {
+        let array = self.inner();
+        let mut builders = Vec::with_capacity(scattered_size);
+
+        for _i in 0..scattered_size {
+            builders.push(DecimalArrayBuilder::with_capacity(
+                self.len(),
+                self.data_type(),
+            ));
+        }
+
+        match self.null_count() {
+            0 => {
+                indices.zip(0..self.len()).for_each(|(index, row)| {
+                    builders[index as usize].append_value(array.value(row));
+                });
+            }
+            _ => {
+                indices.zip(0..self.len()).for_each(|(index, row)| {
+                    if self.is_null(row) {
+                        builders[index as usize].append_null();
+                    } else {
+                        builders[index as usize].append_value(array.value(row));
+                    }
+                });
+            }
+        }
+
+        Ok(builders
+            .iter_mut()
+            .map(|builder| builder.finish())
+            .collect())
+    }
+}
+
 impl ArrayScatter for DFListArray {
     unsafe fn scatter_unchecked(
         &self,
@@ -20,6 +20,7 @@
 
 use std::fmt::Debug;
 
+use common_arrow::arrow::array::Array;
 use common_arrow::arrow::compute::take;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -247,6 +248,57 @@ impl ArrayTake for DFStringArray {
     }
 }
 
+impl ArrayTake for DFDecimalArray {
+    unsafe fn take_unchecked<I, INulls>(&self, indices: TakeIdx<I, INulls>) -> Result<Self>
+    where
+        Self: std::marker::Sized,
+        I: Iterator<Item = usize>,
+        INulls: Iterator<Item = Option<usize>>,
+    {
+        let decimal_array = self.inner();
+        match indices {
+            TakeIdx::Array(array) => {
+                if self.is_empty() {
+                    return Ok(Self::full_null(array.len(), self.data_type()));
+                }
+                let taked_array = take::take(decimal_array, array)?;
+                Ok(Self::from_arrow_array(taked_array.as_ref()))
+            }
+            TakeIdx::Iter(iter) => {
+                if self.is_empty() {
+                    return Ok(Self::full_null(iter.size_hint().0, self.data_type()));
+                }
+                let iter = iter.map(|idx| match decimal_array.is_null(idx) {
+                    true => None,
+                    false => Some(decimal_array.value_unchecked(idx)),
+                });
+                Ok(Self::new_from_opt_iter(iter, self.data_type()))
+            }
+            TakeIdx::IterNulls(iter) => {
+                if self.is_empty() {
+                    return Ok(Self::full_null(iter.size_hint().0, self.data_type()));
+                }
+                let iter = iter.map(|opt_idx| {
+                    opt_idx.and_then(|idx| match decimal_array.is_null(idx) {
+                        true => None,
+                        false => Some(decimal_array.value_unchecked(idx)),
+                    })
+                });
+                Ok(Self::new_from_opt_iter(iter, self.data_type()))
+            }
+        }
+    }
+
+    fn take<I, INulls>(&self, indices: TakeIdx<I, INulls>) -> Result<Self>
+    where
+        Self: std::marker::Sized,
+        I: Iterator<Item = usize>,
+        INulls: Iterator<Item = Option<usize>>,
+    {
+        unsafe { self.take_unchecked(indices) }
+    }
+}
+
 impl ArrayTake for DFNullArray {}
 impl ArrayTake for DFStructArray {}
 
@@ -72,6 +72,20 @@ where T: DFPrimitiveType
     }
 }
 
+impl TakeRandom for DFDecimalArray {
+    type Item = i128;
+
+    #[inline]
+    fn get(&self, index: usize) -> Option<Self::Item> {
+        unsafe { impl_take_random_get!(self, index) }
+    }
+
+    #[inline]
+    unsafe fn get_unchecked(&self, index: usize) -> Self::Item {
+        impl_take_random_get_unchecked!(self, index)
+    }
+}
+
 impl TakeRandom for DFBooleanArray {
     type Item = bool;
 
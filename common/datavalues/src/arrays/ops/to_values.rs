@@ -86,6 +86,18 @@ impl ToValues for DFBooleanArray {
     }
 }
 
+impl ToValues for DFDecimalArray {
+    fn to_values(&self) -> Result<Vec<DataValue>> {
+        let values = self
+            .inner()
+            .iter()
+            .map(|v| self.to_data_value(v.copied()))
+            .collect();
+
+        Ok(values)
+    }
+}
+
 impl ToValues for DFStringArray {
     fn to_values(&self) -> Result<Vec<DataValue>> {
         let mut values = Vec::with_capacity(self.len());
@@ -15,6 +15,7 @@ use std::fmt::Debug;
 use std::hash::Hash;
 use std::hash::Hasher;
 
+use common_arrow::arrow::compute::arity::unary;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
@@ -78,6 +79,21 @@ impl VecHash for DFFloat64Array {
     }
 }
 
+impl VecHash for DFDecimalArray {
+    fn vec_hash(&self, hasher: DFHasher) -> Result<DFUInt64Array> {
+        let array = unary(
+            self.inner(),
+            |v| {
+                let mut h = hasher.clone_initial();
+                v.hash(&mut h);
+                h.finish()
+            },
+            DataType::UInt64.to_arrow(),
+        );
+        Ok(DFUInt64Array::new(array))
+    }
+}
+
 impl VecHash for DFStringArray {
     fn vec_hash(&self, hasher: DFHasher) -> Result<DFUInt64Array> {
         Ok(self.apply_cast_numeric(|v| {
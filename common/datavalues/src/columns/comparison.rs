@@ -41,6 +41,10 @@ impl DataColumn {
             DataValueComparisonOperator::NotEq => apply_cmp! {self, rhs, neq},
             DataValueComparisonOperator::Like => apply_cmp! {self, rhs, like},
             DataValueComparisonOperator::NotLike => apply_cmp! {self, rhs, nlike},
+            DataValueComparisonOperator::ILike => apply_cmp! {self, rhs, ilike},
+            DataValueComparisonOperator::NotILike => apply_cmp! {self, rhs, nilike},
+            DataValueComparisonOperator::Regexp => apply_cmp! {self, rhs, regexp},
+            DataValueComparisonOperator::NotRegexp => apply_cmp! {self, rhs, nregexp},
         }
     }
 }
@@ -82,11 +82,12 @@ impl DataField {
             DataType::Date16 => Some("Date16"),
             DataType::Date32 => Some("Date32"),
             DataType::DateTime32(_) => Some("DateTime32"),
+            DataType::DateTime64(_, _) => Some("DateTime64"),
             _ => None,
         };
 
         let custom_metadata = match self.data_type() {
-            DataType::DateTime32(tz) => tz.clone(),
+            DataType::DateTime32(tz) | DataType::DateTime64(_, tz) => tz.clone(),
             _ => None,
         };
 
@@ -101,6 +102,13 @@ impl DataField {
             if let Some(m) = custom_metadata {
                 mp.insert("ARROW:extension:databend_metadata".to_string(), m);
             }
+
+            if let DataType::DateTime64(precision, _) = self.data_type() {
+                mp.insert(
+                    "ARROW:extension:databend_precision".to_string(),
+                    precision.to_string(),
+                );
+            }
             f = f.with_metadata(mp);
         }
 
@@ -118,6 +126,13 @@ impl From<&ArrowField> for DataField {
                     "Date16" => dt = DataType::Date16,
                     "Date32" => dt = DataType::Date32,
                     "DateTime32" => dt = DataType::DateTime32(metatada.cloned()),
+                    "DateTime64" => {
+                        let precision = m
+                            .get("ARROW:extension:databend_precision")
+                            .and_then(|p| p.parse::<u32>().ok())
+                            .unwrap_or(3);
+                        dt = DataType::DateTime64(precision, metatada.cloned())
+                    }
                     _ => {}
                 }
             }
@@ -34,6 +34,7 @@ pub enum DataGroupValue {
     Int16(i16),
     Int32(i32),
     Int64(i64),
+    Decimal128(i128, usize, usize),
     String(Vec<u8>),
     Boolean(bool),
 }
@@ -55,6 +56,9 @@ impl TryFrom<&DataValue> for DataGroupValue {
             DataValue::UInt32(Some(v)) => DataGroupValue::UInt32(*v),
             DataValue::UInt64(Some(v)) => DataGroupValue::UInt64(*v),
             DataValue::String(Some(v)) => DataGroupValue::String(v.clone()),
+            DataValue::Decimal128(Some(v), precision, scale) => {
+                DataGroupValue::Decimal128(*v, *precision, *scale)
+            }
 
             DataValue::Float32(None)
             | DataValue::Float64(None)
@@ -67,7 +71,8 @@ impl TryFrom<&DataValue> for DataGroupValue {
             | DataValue::UInt16(None)
             | DataValue::UInt32(None)
             | DataValue::UInt64(None)
-            | DataValue::String(None) => {
+            | DataValue::String(None)
+            | DataValue::Decimal128(None, _, _) => {
                 return Err(ErrorCode::BadDataValueType(format!(
                     "Cannot convert a DataValue holding NULL ({:?})",
                     value
@@ -99,6 +104,9 @@ impl From<&DataGroupValue> for DataValue {
             DataGroupValue::UInt32(v) => DataValue::UInt32(Some(*v)),
             DataGroupValue::UInt64(v) => DataValue::UInt64(Some(*v)),
             DataGroupValue::String(v) => DataValue::String(Some(v.to_vec())),
+            DataGroupValue::Decimal128(v, precision, scale) => {
+                DataValue::Decimal128(Some(*v), *precision, *scale)
+            }
         }
     }
 }
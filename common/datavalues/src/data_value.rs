@@ -53,6 +53,8 @@ pub enum DataValue {
     Float32(Option<f32>),
     Float64(Option<f64>),
     String(Option<Vec<u8>>),
+    /// Unscaled value with precision and scale.
+    Decimal128(Option<i128>, usize, usize),
 
     // Container struct.
     List(Option<Vec<DataValue>>, DataType),
@@ -77,6 +79,7 @@ impl DataValue {
                 | DataValue::Float32(None)
                 | DataValue::Float64(None)
                 | DataValue::String(None)
+                | DataValue::Decimal128(None, _, _)
                 | DataValue::Null
                 | DataValue::List(None, _)
         )
@@ -96,6 +99,7 @@ impl DataValue {
             DataValue::UInt64(_) => DataType::UInt64,
             DataValue::Float32(_) => DataType::Float32,
             DataValue::Float64(_) => DataType::Float64,
+            DataValue::Decimal128(_, precision, scale) => DataType::Decimal128(*precision, *scale),
             DataValue::List(_, data_type) => {
                 DataType::List(Box::new(DataField::new("item", data_type.clone(), true)))
             }
@@ -174,6 +178,13 @@ impl DataValue {
                 None => Ok(DFStringArray::full_null(size).into_series()),
                 Some(v) => Ok(DFStringArray::full(v.deref(), size).into_series()),
             },
+            DataValue::Decimal128(values, ..) => {
+                let data_type = self.data_type();
+                match values {
+                    None => Ok(DFDecimalArray::full_null(size, &data_type).into_series()),
+                    Some(v) => Ok(DFDecimalArray::full(*v, size, &data_type).into_series()),
+                }
+            }
             DataValue::List(values, data_type) => match data_type {
                 DataType::Int8 => build_list_series! {i8, values, size, data_type },
                 DataType::Int16 => build_list_series! {i16, values, size, data_type },
@@ -297,6 +308,7 @@ impl DataValue {
             DataValue::UInt64(v) => Ok(v.map_or(false, |v| v != 0)),
             DataValue::Float32(v) => Ok(v.map_or(false, |v| v != 0f32)),
             DataValue::Float64(v) => Ok(v.map_or(false, |v| v != 0f64)),
+            DataValue::Decimal128(v, ..) => Ok(v.map_or(false, |v| v != 0)),
             other => Result::Err(ErrorCode::BadDataValueType(format!(
                 "Unexpected type:{:?} to get boolean",
                 other.data_type()
@@ -316,6 +328,7 @@ impl DataValue {
             DataValue::UInt64(Some(v)) => Ok(*v as f64),
             DataValue::Float32(Some(v)) => Ok(*v as f64),
             DataValue::Float64(Some(v)) => Ok(*v),
+            DataValue::Decimal128(Some(v), _, scale) => Ok(decimal_to_f64(*v, *scale)),
             other => Result::Err(ErrorCode::BadDataValueType(format!(
                 "Unexpected type:{:?} to get f64 number",
                 other.data_type()
@@ -343,7 +356,11 @@ impl DataValue {
             DataType::Date16 => DataValue::UInt16(Some(0)),
             DataType::Date32 => DataValue::Int32(Some(0)),
             DataType::DateTime32(_) => DataValue::UInt32(Some(0)),
+            DataType::DateTime64(_, _) => DataValue::Int64(Some(0)),
             DataType::Interval(_) => DataValue::Int64(Some(0)),
+            DataType::Decimal128(precision, scale) => {
+                DataValue::Decimal128(Some(0), *precision, *scale)
+            }
             DataType::List(f) => DataValue::List(Some(vec![]), f.data_type().clone()),
             DataType::Struct(_) => DataValue::Struct(vec![]),
             DataType::String => DataValue::String(Some(vec![])),
@@ -362,6 +379,9 @@ impl DataValue {
             DataValue::Float32(Some(v)) => Ok(Vec::<u8>::from((*v).to_string())),
             DataValue::Float64(Some(v)) => Ok(Vec::<u8>::from((*v).to_string())),
             DataValue::String(Some(v)) => Ok(v.to_owned()),
+            DataValue::Decimal128(Some(v), _, scale) => {
+                Ok(Vec::<u8>::from(decimal_to_string(*v, *scale)))
+            }
             other => Result::Err(ErrorCode::BadDataValueType(format!(
                 "Unexpected type:{:?} to get string",
                 other.data_type()
@@ -388,6 +408,19 @@ typed_cast_from_data_value_to_std!(Float32, f32);
 typed_cast_from_data_value_to_std!(Float64, f64);
 typed_cast_from_data_value_to_std!(Boolean, bool);
 
+impl DFTryFrom<DataValue> for i128 {
+    fn try_from(value: DataValue) -> Result<Self> {
+        match value {
+            DataValue::Decimal128(Some(inner_value), ..) => Ok(inner_value),
+            _ => Err(ErrorCode::BadDataValueType(format!(
+                "DataValue Error:  Cannot convert {:?} to {}",
+                value,
+                std::any::type_name::<Self>()
+            ))),
+        }
+    }
+}
+
 impl DFTryFrom<DataValue> for Vec<u8> {
     fn try_from(value: DataValue) -> Result<Self> {
         match value {
@@ -456,10 +489,14 @@ impl From<&DataType> for DataValue {
             DataType::Date16 => DataValue::UInt16(None),
             DataType::Date32 => DataValue::Int32(None),
             DataType::DateTime32(_) => DataValue::UInt32(None),
+            DataType::DateTime64(_, _) => DataValue::Int64(None),
             DataType::List(f) => DataValue::List(None, f.data_type().clone()),
             DataType::Struct(_) => DataValue::Struct(vec![]),
             DataType::String => DataValue::String(None),
             DataType::Interval(_) => DataValue::Int64(None),
+            DataType::Decimal128(precision, scale) => {
+                DataValue::Decimal128(None, *precision, *scale)
+            }
         }
     }
 }
@@ -488,6 +525,9 @@ impl fmt::Display for DataValue {
             DataValue::UInt16(v) => format_data_value_with_option!(f, v),
             DataValue::UInt32(v) => format_data_value_with_option!(f, v),
             DataValue::UInt64(v) => format_data_value_with_option!(f, v),
+            DataValue::Decimal128(v, _, scale) => {
+                format_data_value_with_option!(f, v.map(|v| decimal_to_string(v, *scale)))
+            }
             DataValue::String(None) => write!(f, "NULL"),
             DataValue::String(Some(v)) => match std::str::from_utf8(v) {
                 Ok(v) => write!(f, "{}", v),
@@ -532,6 +572,9 @@ impl fmt::Debug for DataValue {
             DataValue::UInt64(v) => format_data_value_with_option!(f, v),
             DataValue::Float32(v) => format_data_value_with_option!(f, v),
             DataValue::Float64(v) => format_data_value_with_option!(f, v),
+            DataValue::Decimal128(v, _, scale) => {
+                format_data_value_with_option!(f, v.map(|v| decimal_to_string(v, *scale)))
+            }
             DataValue::String(None) => write!(f, "{}", self),
             DataValue::String(Some(_)) => write!(f, "{}", self),
             DataValue::List(_, _) => write!(f, "[{}]", self),
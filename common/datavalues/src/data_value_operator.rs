@@ -44,6 +44,10 @@ pub enum DataValueComparisonOperator {
     NotEq,
     Like,
     NotLike,
+    ILike,
+    NotILike,
+    Regexp,
+    NotRegexp,
 }
 
 impl std::fmt::Display for DataValueComparisonOperator {
@@ -57,6 +61,10 @@ impl std::fmt::Display for DataValueComparisonOperator {
             DataValueComparisonOperator::NotEq => "!=",
             DataValueComparisonOperator::Like => "LIKE",
             DataValueComparisonOperator::NotLike => "NOT LIKE",
+            DataValueComparisonOperator::ILike => "ILIKE",
+            DataValueComparisonOperator::NotILike => "NOT ILIKE",
+            DataValueComparisonOperator::Regexp => "REGEXP",
+            DataValueComparisonOperator::NotRegexp => "NOT REGEXP",
         };
         write!(f, "{}", display)
     }
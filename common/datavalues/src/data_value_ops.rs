@@ -102,6 +102,32 @@ impl DataValue {
             DataType::DateTime32(_) => {
                 try_build_array! {PrimitiveArrayBuilder, u32, UInt32, values}
             }
+            DataType::DateTime64(_, _) => {
+                try_build_array! {PrimitiveArrayBuilder, i64, Int64, values}
+            }
+            DataType::Decimal128(_, _) => {
+                let mut builder = DecimalArrayBuilder::with_capacity(values.len(), data_type);
+                for value in values.iter() {
+                    match value {
+                        DataValue::Decimal128(v, _, _) => builder.append_option(*v),
+                        _ => unreachable!(),
+                    }
+                }
+                Ok(builder.finish().into_series())
+            }
+            DataType::List(inner) => {
+                let inner_type = inner.data_type();
+                let mut builder = get_list_builder(inner_type, values.len() * 5, values.len());
+                for value in values.iter() {
+                    match value {
+                        DataValue::List(Some(v), _) => {
+                            builder.append_series(&DataValue::try_into_data_array(v, inner_type)?)
+                        }
+                        _ => builder.append_null(),
+                    }
+                }
+                Ok(builder.finish().into_series())
+            }
             other => Result::Err(ErrorCode::BadDataValueType(format!(
                 "Unexpected type:{} for DataValue List",
                 other
@@ -99,6 +99,15 @@ impl IntDiv for &Series {
     type Output = Result<Series>;
 
     fn int_div(self, rhs: Self) -> Self::Output {
+        let (lhs_type, rhs_type) = (self.data_type(), rhs.data_type());
+        if (lhs_type.is_decimal() || rhs_type.is_decimal())
+            && !(lhs_type.is_floating() || rhs_type.is_floating())
+        {
+            // the decimal division is exact, so we just truncate the quotient
+            let res = (self / rhs)?;
+            return res.cast_with_type(&DataType::Int64);
+        }
+
         let (lhs, rhs) = coerce_lhs_rhs(&DataValueArithmeticOperator::IntDiv, self, rhs)?;
         match &rhs
             .cast_with_type(&DataType::Float64)?
@@ -227,6 +236,33 @@ impl NumOpsDispatch for DFStringArray {
         Ok(out.into_series())
     }
 }
+impl NumOpsDispatch for DFDecimalArray {
+    fn subtract(&self, rhs: &Series) -> Result<Series> {
+        let out = self.arithmetic(&DataValueArithmeticOperator::Minus, rhs.decimal()?)?;
+        Ok(out.into_series())
+    }
+    fn add_to(&self, rhs: &Series) -> Result<Series> {
+        let out = self.arithmetic(&DataValueArithmeticOperator::Plus, rhs.decimal()?)?;
+        Ok(out.into_series())
+    }
+    fn multiply(&self, rhs: &Series) -> Result<Series> {
+        let out = self.arithmetic(&DataValueArithmeticOperator::Mul, rhs.decimal()?)?;
+        Ok(out.into_series())
+    }
+    fn divide(&self, rhs: &Series) -> Result<Series> {
+        let out = self.arithmetic(&DataValueArithmeticOperator::Div, rhs.decimal()?)?;
+        Ok(out.into_series())
+    }
+    fn remainder(&self, rhs: &Series, _dtype: &DataType) -> Result<Series> {
+        let out = self.arithmetic(&DataValueArithmeticOperator::Modulo, rhs.decimal()?)?;
+        Ok(out.into_series())
+    }
+
+    fn negative(&self) -> Result<Series> {
+        Ok(self.negative()?.into_series())
+    }
+}
+
 impl NumOpsDispatch for DFBooleanArray {}
 impl NumOpsDispatch for DFListArray {}
 impl NumOpsDispatch for DFNullArray {}
@@ -238,6 +274,10 @@ fn coerce_lhs_rhs(
     rhs: &Series,
 ) -> Result<(Series, Series)> {
     let dtype = numerical_arithmetic_coercion(op, lhs.data_type(), rhs.data_type())?;
+    if dtype.is_decimal() {
+        // decimal arithmetic is scale aware, so we only need to convert integers into decimals
+        return Ok((coerce_decimal(lhs)?, coerce_decimal(rhs)?));
+    }
 
     let mut left = lhs.clone();
     if lhs.data_type() != &dtype {
@@ -252,6 +292,21 @@ fn coerce_lhs_rhs(
     Ok((left, right))
 }
 
+fn coerce_decimal(series: &Series) -> Result<Series> {
+    match series.data_type().is_decimal() {
+        true => Ok(series.clone()),
+        false => match decimal_precision_scale_of(series.data_type()) {
+            Some((precision, scale)) => {
+                series.cast_with_type(&DataType::decimal(precision, scale)?)
+            }
+            None => Err(ErrorCode::BadDataValueType(format!(
+                "Can't coerce {:?} into decimal",
+                series.data_type()
+            ))),
+        },
+    }
+}
+
 fn coerce_lhs_rhs_no_op(lhs: &Series, rhs: &Series) -> Result<(Series, Series)> {
     let dtype = numerical_coercion(lhs.data_type(), rhs.data_type(), true)?;
 
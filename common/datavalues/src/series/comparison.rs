@@ -45,6 +45,7 @@ macro_rules! impl_compare {
             DataType::Float64 => $self.f64().unwrap().$method($rhs.f64().unwrap()),
             DataType::Date16 => $self.u16().unwrap().$method($rhs.u16().unwrap()),
             DataType::Date32 => $self.i32().unwrap().$method($rhs.i32().unwrap()),
+            DataType::Decimal128(_, _) => $self.decimal().unwrap().$method($rhs.decimal().unwrap()),
             _ => unimplemented!(),
         }
     }};
@@ -90,4 +91,24 @@ impl ArrayCompare<&Series> for Series {
     fn nlike(&self, rhs: &Series) -> Result<DFBooleanArray> {
         impl_compare!(self.as_ref(), rhs.as_ref(), nlike)
     }
+
+    /// Create a boolean mask by checking if lhs matches the rhs pattern case-insensitively.
+    fn ilike(&self, rhs: &Series) -> Result<DFBooleanArray> {
+        impl_compare!(self.as_ref(), rhs.as_ref(), ilike)
+    }
+
+    /// Create a boolean mask by checking if lhs does not match the rhs pattern case-insensitively.
+    fn nilike(&self, rhs: &Series) -> Result<DFBooleanArray> {
+        impl_compare!(self.as_ref(), rhs.as_ref(), nilike)
+    }
+
+    /// Create a boolean mask by checking if lhs matches the rhs regular expression.
+    fn regexp(&self, rhs: &Series) -> Result<DFBooleanArray> {
+        impl_compare!(self.as_ref(), rhs.as_ref(), regexp)
+    }
+
+    /// Create a boolean mask by checking if lhs does not match the rhs regular expression.
+    fn nregexp(&self, rhs: &Series) -> Result<DFBooleanArray> {
+        impl_compare!(self.as_ref(), rhs.as_ref(), nregexp)
+    }
 }
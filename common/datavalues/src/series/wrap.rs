@@ -300,6 +300,18 @@ macro_rules! impl_dyn_array {
                 }
             }
 
+            /// Unpack to DFArray of data_type decimal
+            fn decimal(&self) -> Result<&DFDecimalArray> {
+                if self.0.data_type().is_decimal() {
+                    unsafe { Ok(&*(self as *const dyn SeriesTrait as *const DFDecimalArray)) }
+                } else {
+                    Err(ErrorCode::IllegalDataType(format!(
+                        "cannot unpack Series of type {:?} into decimal",
+                        self.data_type(),
+                    )))
+                }
+            }
+
             /// Unpack to DFArray of data_type struct
             fn tuple(&self) -> Result<&DFStructArray> {
                 if matches!(self.0.data_type(), &DataType::Struct(_)) {
@@ -312,6 +324,18 @@ macro_rules! impl_dyn_array {
                 }
             }
 
+            /// Unpack to DFArray of data_type list
+            fn list(&self) -> Result<&DFListArray> {
+                if matches!(self.0.data_type(), &DataType::List(_)) {
+                    unsafe { Ok(&*(self as *const dyn SeriesTrait as *const DFListArray)) }
+                } else {
+                    Err(ErrorCode::IllegalDataType(format!(
+                        "cannot unpack Series of type {:?} into list",
+                        self.data_type(),
+                    )))
+                }
+            }
+
             fn take_iter(&self, iter: &mut dyn Iterator<Item = usize>) -> Result<Series> {
                 Ok(ArrayTake::take(&self.0, iter.into())?.into_series())
             }
@@ -353,4 +377,5 @@ impl_dyn_array!(DFInt64Array);
 impl_dyn_array!(DFListArray);
 impl_dyn_array!(DFBooleanArray);
 impl_dyn_array!(DFStringArray);
+impl_dyn_array!(DFDecimalArray);
 impl_dyn_array!(DFStructArray);
@@ -15,12 +15,16 @@
 use core::fmt;
 
 use common_arrow::arrow::datatypes::DataType as ArrowDataType;
+use common_arrow::arrow::datatypes::TimeUnit;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_macros::MallocSizeOf;
 
+use crate::parse_timezone;
 use crate::DataField;
 use crate::PhysicalDataType;
+use crate::DATETIME64_MAX_PRECISION;
+use crate::DECIMAL128_MAX_PRECISION;
 
 #[derive(
     serde::Serialize, serde::Deserialize, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, MallocSizeOf,
@@ -50,8 +54,19 @@ pub enum DataType {
     /// Option<String> indicates the timezone, if it's None, it's UTC
     DateTime32(Option<String>),
 
+    /// A 64-bit datetime representing the elapsed time since UNIX epoch (1970-01-01)
+    /// in 10^-precision seconds, it's physical type is Int64
+    /// The precision is at most 9 (nanoseconds), Option<String> indicates the timezone, if it's None, it's UTC
+    DateTime64(u32, Option<String>),
+
     Interval(IntervalUnit),
 
+    /// A fixed-point decimal with (precision, scale), the precision is at most 38,
+    /// it's physical type is Int128 which stores the unscaled value
+    Decimal128(usize, usize),
+    /// A fixed-point decimal with (precision, scale), the precision is at most 76,
+    /// it's physical type is Int128 too, the values out of the range of i128 are
+    /// reported as overflow until we have a 256-bit physical type
     List(Box<DataField>),
     Struct(Vec<DataField>),
     String,
@@ -89,6 +104,47 @@ impl DataType {
         self.clone().into()
     }
 
+    /// Create the decimal type which is able to hold the precision.
+    pub fn decimal(precision: usize, scale: usize) -> Result<DataType> {
+        if precision == 0 || precision > DECIMAL128_MAX_PRECISION || scale > precision {
+            return Err(ErrorCode::BadArguments(format!(
+                "Invalid decimal precision: {} and scale: {}, the precision must be in [1, {}] and the scale must not be greater than the precision",
+                precision, scale, DECIMAL128_MAX_PRECISION
+            )));
+        }
+
+        Ok(DataType::Decimal128(precision, scale))
+    }
+
+    /// Create the datetime64 type, the timezone must be a valid IANA timezone name.
+    pub fn datetime64(precision: u32, tz: Option<String>) -> Result<DataType> {
+        if precision > DATETIME64_MAX_PRECISION {
+            return Err(ErrorCode::BadArguments(format!(
+                "Invalid datetime64 precision: {}, the precision must be in [0, {}]",
+                precision, DATETIME64_MAX_PRECISION
+            )));
+        }
+
+        if let Some(tz) = &tz {
+            parse_timezone(tz)?;
+        }
+        Ok(DataType::DateTime64(precision, tz))
+    }
+
+    #[inline]
+    pub fn is_decimal(&self) -> bool {
+        matches!(self, DataType::Decimal128(_, _))
+    }
+
+    /// Returns the (precision, scale) of decimal types.
+    #[inline]
+    pub fn decimal_precision_scale(&self) -> Option<(usize, usize)> {
+        match self {
+            DataType::Decimal128(precision, scale) => Some((*precision, *scale)),
+            _ => None,
+        }
+    }
+
     #[inline]
     pub fn is_null(&self) -> bool {
         matches!(self, DataType::Null)
@@ -134,7 +190,10 @@ impl DataType {
     pub fn is_date_or_date_time(&self) -> bool {
         matches!(
             self,
-            DataType::Date16 | DataType::Date32 | DataType::DateTime32(_)
+            DataType::Date16
+                | DataType::Date32
+                | DataType::DateTime32(_)
+                | DataType::DateTime64(_, _)
         )
     }
 
@@ -200,6 +259,7 @@ impl DataType {
             Date32 => ArrowDataType::Int32,
             // we don't use DataType::Extension because extension types are not supported in parquet
             DateTime32(_) => ArrowDataType::UInt32,
+            DateTime64(_, _) => ArrowDataType::Int64,
             List(dt) => ArrowDataType::LargeList(Box::new(dt.to_arrow())),
             Struct(fs) => {
                 let arrows_fields = fs.iter().map(|f| f.to_arrow()).collect();
@@ -207,6 +267,7 @@ impl DataType {
             }
             String => ArrowDataType::LargeBinary,
             Interval(_) => ArrowDataType::Int64,
+            Decimal128(precision, scale) => ArrowDataType::Decimal(*precision, *scale),
         }
     }
 }
@@ -240,9 +301,15 @@ impl From<&ArrowDataType> for DataType {
             ArrowDataType::Binary | ArrowDataType::LargeBinary => DataType::String,
             ArrowDataType::Utf8 | ArrowDataType::LargeUtf8 => DataType::String,
 
-            ArrowDataType::Timestamp(_, tz) => DataType::DateTime32(tz.clone()),
+            ArrowDataType::Timestamp(unit, tz) => match unit {
+                TimeUnit::Second => DataType::DateTime32(tz.clone()),
+                TimeUnit::Millisecond => DataType::DateTime64(3, tz.clone()),
+                TimeUnit::Microsecond => DataType::DateTime64(6, tz.clone()),
+                TimeUnit::Nanosecond => DataType::DateTime64(9, tz.clone()),
+            },
             ArrowDataType::Date32 => DataType::Date16,
             ArrowDataType::Date64 => DataType::Date32,
+            ArrowDataType::Decimal(precision, scale) => DataType::Decimal128(*precision, *scale),
 
             ArrowDataType::Extension(name, _arrow_type, extra) => match name.as_str() {
                 "Date16" => DataType::Date16,
@@ -296,10 +363,20 @@ impl fmt::Debug for DataType {
                     write!(f, "DateTime32")
                 }
             }
+            Self::DateTime64(precision, arg1) => {
+                if let Some(tz) = arg1 {
+                    write!(f, "DateTime64({}, {:?})", precision, tz)
+                } else {
+                    write!(f, "DateTime64({})", precision)
+                }
+            }
             Self::List(arg0) => f.debug_tuple("List").field(arg0).finish(),
             Self::Struct(arg0) => f.debug_tuple("Struct").field(arg0).finish(),
             Self::String => write!(f, "String"),
             Self::Interval(unit) => write!(f, "Interval({})", unit),
+            Self::Decimal128(precision, scale) => {
+                write!(f, "Decimal128({}, {})", precision, scale)
+            }
         }
     }
 }
@@ -20,6 +20,7 @@ use common_exception::Result;
 use crate::prelude::DataType;
 use crate::DataField;
 use crate::DataValueArithmeticOperator;
+use crate::DECIMAL128_MAX_PRECISION;
 
 fn next_size(size: usize) -> usize {
     if size < 8_usize {
@@ -61,6 +62,47 @@ pub fn construct_numeric_type(
     }
 }
 
+/// Returns the (precision, scale) of the decimal which is able to hold all the values of the type.
+/// Only integer and decimal types can be converted to decimal losslessly.
+pub fn decimal_precision_scale_of(data_type: &DataType) -> Option<(usize, usize)> {
+    match data_type {
+        DataType::Int8 | DataType::UInt8 => Some((3, 0)),
+        DataType::Int16 | DataType::UInt16 => Some((5, 0)),
+        DataType::Int32 | DataType::UInt32 => Some((10, 0)),
+        DataType::Int64 => Some((19, 0)),
+        DataType::UInt64 => Some((20, 0)),
+        _ => data_type.decimal_precision_scale(),
+    }
+}
+
+/// Construct the decimal type, the precision is capped by the max precision of decimal.
+fn construct_decimal_type(precision: usize, scale: usize) -> Result<DataType> {
+    let precision = cmp::min(precision, DECIMAL128_MAX_PRECISION);
+    DataType::decimal(precision, cmp::min(scale, precision))
+}
+
+/// Coercion rule for decimal types: keep the max integral digits and the max scale of both sides.
+/// Decimal with floating types will be coerced to Float64.
+fn decimal_coercion(lhs_type: &DataType, rhs_type: &DataType) -> Result<DataType> {
+    if lhs_type.is_floating() || rhs_type.is_floating() {
+        return Ok(DataType::Float64);
+    }
+
+    match (
+        decimal_precision_scale_of(lhs_type),
+        decimal_precision_scale_of(rhs_type),
+    ) {
+        (Some((p1, s1)), Some((p2, s2))) => {
+            let scale = cmp::max(s1, s2);
+            construct_decimal_type(cmp::max(p1 - s1, p2 - s2) + scale, scale)
+        }
+        _ => Result::Err(ErrorCode::BadDataValueType(format!(
+            "Can't construct type from {} and {}",
+            lhs_type, rhs_type
+        ))),
+    }
+}
+
 /// Coercion rule for numerical types: The type that both lhs and rhs
 /// can be casted to for numerical calculation, while maintaining
 /// maximum precision
@@ -69,6 +111,10 @@ pub fn numerical_coercion(
     rhs_type: &DataType,
     allow_overflow: bool,
 ) -> Result<DataType> {
+    if lhs_type.is_decimal() || rhs_type.is_decimal() {
+        return decimal_coercion(lhs_type, rhs_type);
+    }
+
     let has_float = lhs_type.is_floating() || rhs_type.is_floating();
     let has_integer = lhs_type.is_integer() || rhs_type.is_integer();
     let has_signed = lhs_type.is_signed_numeric() || rhs_type.is_signed_numeric();
@@ -151,12 +197,57 @@ pub fn numerical_coercion(
     construct_numeric_type(has_signed, has_float, max_size)
 }
 
+/// Coercion rule for the result of decimal arithmetic, which follows:
+///   +, -: (max(p1 - s1, p2 - s2) + max(s1, s2) + 1, max(s1, s2))
+///   *   : (p1 + p2, s1 + s2)
+///   /   : (max(p1 - s1, p2 - s2) + max(s1, s2) + 4, max(s1, s2) + 4)
+///   %   : (max(p1 - s1, p2 - s2) + max(s1, s2), max(s1, s2))
+///   div : Int64
+pub fn decimal_arithmetic_coercion(
+    op: &DataValueArithmeticOperator,
+    lhs_type: &DataType,
+    rhs_type: &DataType,
+) -> Result<DataType> {
+    if lhs_type.is_floating() || rhs_type.is_floating() {
+        return Ok(DataType::Float64);
+    }
+
+    let (p1, s1, p2, s2) = match (
+        decimal_precision_scale_of(lhs_type),
+        decimal_precision_scale_of(rhs_type),
+    ) {
+        (Some((p1, s1)), Some((p2, s2))) => (p1, s1, p2, s2),
+        _ => {
+            return Result::Err(ErrorCode::BadDataValueType(format!(
+                "DataValue Error: Unsupported ({:?}) {} ({:?})",
+                lhs_type, op, rhs_type
+            )))
+        }
+    };
+
+    let scale = cmp::max(s1, s2);
+    let integral = cmp::max(p1 - s1, p2 - s2);
+    match op {
+        DataValueArithmeticOperator::Plus | DataValueArithmeticOperator::Minus => {
+            construct_decimal_type(integral + scale + 1, scale)
+        }
+        DataValueArithmeticOperator::Mul => construct_decimal_type(p1 + p2, s1 + s2),
+        DataValueArithmeticOperator::Div => construct_decimal_type(integral + scale + 4, scale + 4),
+        DataValueArithmeticOperator::Modulo => construct_decimal_type(integral + scale, scale),
+        DataValueArithmeticOperator::IntDiv => Ok(DataType::Int64),
+    }
+}
+
 #[inline]
 pub fn numerical_arithmetic_coercion(
     op: &DataValueArithmeticOperator,
     lhs_type: &DataType,
     rhs_type: &DataType,
 ) -> Result<DataType> {
+    if lhs_type.is_decimal() || rhs_type.is_decimal() {
+        return decimal_arithmetic_coercion(op, lhs_type, rhs_type);
+    }
+
     // error on any non-numeric type
     if !lhs_type.is_numeric() || !rhs_type.is_numeric() {
         return Result::Err(ErrorCode::BadDataValueType(format!(
@@ -228,6 +319,11 @@ pub fn datetime_arithmetic_coercion(
         DataValueArithmeticOperator::Minus => {
             if b.is_numeric() || b.is_interval() {
                 Ok(a)
+            } else if matches!(a, DataType::DateTime64(_, _))
+                || matches!(b, DataType::DateTime64(_, _))
+            {
+                // DateTime64 minus DateTime64 is the difference of ticks in the larger precision
+                Ok(DataType::Int64)
             } else {
                 // Date minus Date or DateTime minus DateTime
                 Ok(DataType::Int32)
@@ -272,6 +368,15 @@ pub fn numerical_unary_arithmetic_coercion(
     op: &DataValueArithmeticOperator,
     val_type: &DataType,
 ) -> Result<DataType> {
+    if val_type.is_decimal()
+        && matches!(
+            op,
+            DataValueArithmeticOperator::Plus | DataValueArithmeticOperator::Minus
+        )
+    {
+        return Ok(val_type.clone());
+    }
+
     // error on any non-numeric type
     if !val_type.is_numeric() {
         return Result::Err(ErrorCode::BadDataValueType(format!(
@@ -311,6 +416,22 @@ pub fn compare_coercion(lhs_type: &DataType, rhs_type: &DataType) -> Result<Data
         return numerical_coercion(lhs_type, rhs_type, true);
     }
 
+    // one of is decimal and other is number, decimal or string
+    {
+        if lhs_type.is_decimal() && (rhs_type.is_numeric() || rhs_type.is_decimal()) {
+            return numerical_coercion(lhs_type, rhs_type, true);
+        }
+        if rhs_type.is_decimal() && lhs_type.is_numeric() {
+            return numerical_coercion(lhs_type, rhs_type, true);
+        }
+        if lhs_type.is_decimal() && rhs_type == &DataType::String {
+            return Ok(lhs_type.clone());
+        }
+        if rhs_type.is_decimal() && lhs_type == &DataType::String {
+            return Ok(rhs_type.clone());
+        }
+    }
+
     //  one of is null
     {
         if rhs_type == &DataType::Null {
@@ -345,6 +466,16 @@ pub fn compare_coercion(lhs_type: &DataType, rhs_type: &DataType) -> Result<Data
 
     // one of is datetime and other is number or string
     if lhs_type.is_date_or_date_time() || rhs_type.is_date_or_date_time() {
+        // one of is datetime64, keeps the larger precision
+        match (lhs_type, rhs_type) {
+            (DataType::DateTime64(p1, tz), DataType::DateTime64(p2, _)) => {
+                return Ok(DataType::DateTime64(cmp::max(*p1, *p2), tz.clone()));
+            }
+            (DataType::DateTime64(_, _), _) => return Ok(lhs_type.clone()),
+            (_, DataType::DateTime64(_, _)) => return Ok(rhs_type.clone()),
+            _ => {}
+        }
+
         // one of is datetime
         if matches!(lhs_type, DataType::DateTime32(_))
             || matches!(rhs_type, DataType::DateTime32(_))
@@ -424,7 +555,8 @@ pub fn merge_types(lhs_type: &DataType, rhs_type: &DataType) -> Result<DataType>
             if lhs_type == rhs_type {
                 return Ok(lhs_type.clone());
             }
-            if lhs_type.is_numeric() && rhs_type.is_numeric() {
+            let is_numeric_or_decimal = |t: &DataType| t.is_numeric() || t.is_decimal();
+            if is_numeric_or_decimal(lhs_type) && is_numeric_or_decimal(rhs_type) {
                 numerical_coercion(lhs_type, rhs_type, false)
             } else {
                 Result::Err(ErrorCode::BadDataValueType(format!(
@@ -17,11 +17,18 @@ use chrono::DateTime;
 use chrono::Duration;
 use chrono::TimeZone;
 use chrono_tz::Tz;
+use common_exception::ErrorCode;
+use common_exception::Result;
 use num::cast::AsPrimitive;
 
+/// DateTime64 is able to store nanoseconds at most.
+pub const DATETIME64_MAX_PRECISION: u32 = 9;
+
 pub trait DateConverter {
     fn to_date(&self, tz: &Tz) -> Date<Tz>;
     fn to_date_time(&self, tz: &Tz) -> DateTime<Tz>;
+    /// Convert the ticks of 10^-precision seconds to datetime
+    fn to_date_time64(&self, precision: u32, tz: &Tz) -> DateTime<Tz>;
 }
 
 impl<T> DateConverter for T
@@ -36,4 +43,54 @@ where T: AsPrimitive<i64>
     fn to_date_time(&self, tz: &Tz) -> DateTime<Tz> {
         tz.timestamp_millis(self.as_() * 1000)
     }
+
+    fn to_date_time64(&self, precision: u32, tz: &Tz) -> DateTime<Tz> {
+        let ticks: i64 = self.as_();
+        let (seconds, nanos) = datetime64_split_ticks(ticks, precision);
+        tz.timestamp(seconds, nanos)
+    }
+}
+
+/// Split the ticks of DateTime64 into seconds and the nanoseconds fraction.
+#[inline]
+pub fn datetime64_split_ticks(ticks: i64, precision: u32) -> (i64, u32) {
+    let base = 10_i64.pow(precision);
+    let seconds = ticks.div_euclid(base);
+    let fraction = ticks.rem_euclid(base);
+    (
+        seconds,
+        (fraction * 10_i64.pow(DATETIME64_MAX_PRECISION - precision)) as u32,
+    )
+}
+
+/// Convert the datetime to the ticks of 10^-precision seconds.
+#[inline]
+pub fn datetime64_to_ticks<T: TimeZone>(dt: &DateTime<T>, precision: u32) -> i64 {
+    let fraction =
+        dt.timestamp_subsec_nanos() as i64 / 10_i64.pow(DATETIME64_MAX_PRECISION - precision);
+    dt.timestamp() * 10_i64.pow(precision) + fraction
+}
+
+/// Rescale the ticks of DateTime64 from one precision to another, the extra digits are truncated.
+#[inline]
+pub fn rescale_datetime64(ticks: i64, from_precision: u32, to_precision: u32) -> i64 {
+    if from_precision <= to_precision {
+        ticks * 10_i64.pow(to_precision - from_precision)
+    } else {
+        ticks.div_euclid(10_i64.pow(from_precision - to_precision))
+    }
+}
+
+/// Parse the IANA timezone name, such as 'UTC' and 'Asia/Shanghai'.
+pub fn parse_timezone(tz: &str) -> Result<Tz> {
+    tz.parse::<Tz>()
+        .map_err(|_| ErrorCode::BadArguments(format!("Unknown timezone: {}", tz)))
+}
+
+/// Returns the timezone of datetime types, it's UTC if absent.
+pub fn timezone_or_utc(tz: &Option<String>) -> Result<Tz> {
+    match tz {
+        Some(tz) => parse_timezone(tz),
+        None => Ok(Tz::UTC),
+    }
 }
@@ -0,0 +1,182 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+/// The max precision of Decimal128, every value of it fits into an i128.
+pub const DECIMAL128_MAX_PRECISION: usize = 38;
+
+/// Returns 10^scale, None if it overflows i128.
+#[inline]
+pub fn decimal_scale_multiplier(scale: usize) -> Option<i128> {
+    10_i128.checked_pow(scale as u32)
+}
+
+/// Check the unscaled value has no more digits than the precision.
+pub fn check_decimal_precision(value: i128, precision: usize) -> Result<i128> {
+    match decimal_scale_multiplier(precision) {
+        Some(max) if value.unsigned_abs() < max as u128 => Ok(value),
+        _ => Err(ErrorCode::Overflow(format!(
+            "Decimal value {} overflows precision {}",
+            value, precision
+        ))),
+    }
+}
+
+/// Rescale the unscaled value from `from_scale` to `to_scale`,
+/// the extra fractional digits are truncated when the scale decreases.
+pub fn rescale_decimal(value: i128, from_scale: usize, to_scale: usize) -> Result<i128> {
+    let overflow = || {
+        ErrorCode::Overflow(format!(
+            "Decimal value {} overflows when rescaling from {} to {}",
+            value, from_scale, to_scale
+        ))
+    };
+
+    if from_scale == to_scale {
+        return Ok(value);
+    }
+
+    if from_scale < to_scale {
+        let multiplier = decimal_scale_multiplier(to_scale - from_scale).ok_or_else(overflow)?;
+        value.checked_mul(multiplier).ok_or_else(overflow)
+    } else {
+        match decimal_scale_multiplier(from_scale - to_scale) {
+            Some(divisor) => Ok(value / divisor),
+            // the divisor is larger than any i128 value
+            None => Ok(0),
+        }
+    }
+}
+
+pub fn decimal_to_string(value: i128, scale: usize) -> String {
+    if scale == 0 {
+        return value.to_string();
+    }
+
+    let sign = if value < 0 { "-" } else { "" };
+    let digits = value.unsigned_abs().to_string();
+    if digits.len() > scale {
+        let (integral, fractional) = digits.split_at(digits.len() - scale);
+        format!("{}{}.{}", sign, integral, fractional)
+    } else {
+        format!("{}0.{:0>width$}", sign, digits, width = scale)
+    }
+}
+
+pub fn decimal_to_f64(value: i128, scale: usize) -> f64 {
+    value as f64 / 10_f64.powi(scale as i32)
+}
+
+pub fn decimal_from_f64(value: f64, scale: usize) -> Result<i128> {
+    let scaled = (value * 10_f64.powi(scale as i32)).round();
+    // i128::MAX is about 1.7e38
+    if !scaled.is_finite() || scaled.abs() >= 1.7e38 {
+        return Err(ErrorCode::Overflow(format!(
+            "Float value {} overflows decimal with scale {}",
+            value, scale
+        )));
+    }
+    Ok(scaled as i128)
+}
+
+/// Parse the text into the unscaled value, the fractional digits
+/// exceeding the scale are rounded half away from zero.
+pub fn parse_decimal(text: &[u8], scale: usize) -> Result<i128> {
+    let bad_bytes = || {
+        ErrorCode::BadBytes(format!(
+            "Incorrect decimal value: {}",
+            String::from_utf8_lossy(text)
+        ))
+    };
+    let overflow = || {
+        ErrorCode::Overflow(format!(
+            "Decimal value {} overflows",
+            String::from_utf8_lossy(text)
+        ))
+    };
+
+    let start = text
+        .iter()
+        .position(|c| !c.is_ascii_whitespace())
+        .unwrap_or(text.len());
+    let end = text
+        .iter()
+        .rposition(|c| !c.is_ascii_whitespace())
+        .map_or(start, |pos| pos + 1);
+
+    let mut bytes = &text[start..end];
+    let negative = match bytes.first() {
+        Some(b'-') => {
+            bytes = &bytes[1..];
+            true
+        }
+        Some(b'+') => {
+            bytes = &bytes[1..];
+            false
+        }
+        _ => false,
+    };
+
+    let mut value: i128 = 0;
+    let mut digits = 0;
+    let mut fractional_digits = None;
+    let mut round_up = false;
+    for c in bytes {
+        match c {
+            b'.' if fractional_digits.is_none() => fractional_digits = Some(0),
+            b'0'..=b'9' => {
+                digits += 1;
+                let digit = (c - b'0') as i128;
+                match fractional_digits.as_mut() {
+                    Some(n) if *n == scale => {
+                        // the first truncated digit decides the rounding
+                        round_up = digit >= 5;
+                        *n += 1;
+                    }
+                    Some(n) if *n > scale => {}
+                    Some(n) => {
+                        *n += 1;
+                        value = value
+                            .checked_mul(10)
+                            .and_then(|v| v.checked_add(digit))
+                            .ok_or_else(overflow)?;
+                    }
+                    None => {
+                        value = value
+                            .checked_mul(10)
+                            .and_then(|v| v.checked_add(digit))
+                            .ok_or_else(overflow)?;
+                    }
+                }
+            }
+            _ => return Err(bad_bytes()),
+        }
+    }
+
+    if digits == 0 {
+        return Err(bad_bytes());
+    }
+
+    let fractional_digits = fractional_digits.unwrap_or(0);
+    if fractional_digits < scale {
+        value = rescale_decimal(value, fractional_digits, scale)?;
+    }
+    if round_up {
+        value = value.checked_add(1).ok_or_else(overflow)?;
+    }
+
+    Ok(if negative { -value } else { value })
+}
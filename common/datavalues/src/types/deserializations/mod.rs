@@ -21,12 +21,14 @@ use crate::prelude::*;
 mod boolean;
 mod date;
 mod date_time;
+mod decimal;
 mod number;
 mod string;
 
 pub use boolean::*;
 pub use date::*;
 pub use date_time::*;
+pub use decimal::*;
 pub use number::*;
 pub use string::*;
 
@@ -66,9 +68,21 @@ impl DataType {
                         tz: tz.parse::<Tz>().unwrap(),
                     }))
                 }
+                DataType::DateTime64(precision, tz) => Ok(Box::new(DateTime64Deserializer {
+                    builder: PrimitiveArrayBuilder::<i64>::with_capacity(capacity),
+                    precision,
+                    tz: timezone_or_utc(&tz)?,
+                })),
                 DataType::String => Ok(Box::new(StringDeserializer {
                     builder: StringArrayBuilder::with_capacity(capacity),
                 })),
+                DataType::Decimal128(precision, scale) => {
+                    Ok(Box::new(DecimalDeserializer {
+                        builder: DecimalArrayBuilder::with_capacity(capacity, &data_type),
+                        precision,
+                        scale,
+                    }))
+                }
                 DataType::Interval(_) => Ok(Box::new(DateDeserializer::<i64> {
                     builder: PrimitiveArrayBuilder::<i64>::with_capacity(capacity),
                 })),
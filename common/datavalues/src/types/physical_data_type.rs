@@ -31,6 +31,7 @@ pub enum PhysicalDataType {
     Int64,
     Float32,
     Float64,
+    Decimal128(usize, usize),
     List(Box<DataField>),
     Struct(Vec<DataField>),
     String,
@@ -49,13 +50,14 @@ impl From<DataType> for PhysicalDataType {
             DataType::Int8 => Int8,
             DataType::Int16 => Int16,
             DataType::Int32 | DataType::Date32 => Int32,
-            DataType::Int64 => Int64,
+            DataType::Int64 | DataType::DateTime64(_, _) => Int64,
             DataType::Float32 => Float32,
             DataType::Float64 => Float64,
             DataType::List(x) => List(x),
             DataType::Struct(x) => Struct(x),
             DataType::String => String,
             DataType::Interval(_) => Int64,
+            DataType::Decimal128(precision, scale) => Decimal128(precision, scale),
         }
     }
 }
@@ -76,6 +78,7 @@ impl From<PhysicalDataType> for DataType {
             PhysicalDataType::Int64 => Int64,
             PhysicalDataType::Float32 => Float32,
             PhysicalDataType::Float64 => Float64,
+            PhysicalDataType::Decimal128(precision, scale) => Decimal128(precision, scale),
             PhysicalDataType::List(x) => List(x),
             PhysicalDataType::Struct(x) => Struct(x),
             PhysicalDataType::String => String,
@@ -13,22 +13,28 @@
 // limitations under the License.
 
 use std::marker::PhantomData;
-use std::ops::AddAssign;
 
-use chrono::Duration;
-use chrono::NaiveDateTime;
+use chrono_tz::Tz;
 use common_exception::*;
 
 use crate::prelude::*;
 
 pub struct DateTimeSerializer<T: DFPrimitiveType> {
     t: PhantomData<T>,
+    tz: Tz,
 }
 
 impl<T: DFPrimitiveType> Default for DateTimeSerializer<T> {
     fn default() -> Self {
+        Self::create(Tz::UTC)
+    }
+}
+
+impl<T: DFPrimitiveType> DateTimeSerializer<T> {
+    pub fn create(tz: Tz) -> Self {
         Self {
             t: Default::default(),
+            tz,
         }
     }
 }
@@ -39,9 +45,7 @@ impl<T: DFPrimitiveType> TypeSerializer for DateTimeSerializer<T> {
             return Ok("NULL".to_owned());
         }
 
-        let mut dt = NaiveDateTime::from_timestamp(0, 0);
-        let d = Duration::seconds(value.as_i64()?);
-        dt.add_assign(d);
+        let dt = value.as_i64()?.to_date_time(&self.tz);
         Ok(dt.format("%Y-%m-%d %H:%M:%S").to_string())
     }
 
@@ -53,9 +57,7 @@ impl<T: DFPrimitiveType> TypeSerializer for DateTimeSerializer<T> {
             .iter()
             .map(|x| {
                 x.map(|v| {
-                    let mut dt = NaiveDateTime::from_timestamp(0, 0);
-                    let d = Duration::seconds(v.to_i64().unwrap());
-                    dt.add_assign(d);
+                    let dt = v.to_i64().unwrap().to_date_time(&self.tz);
                     dt.format("%Y-%m-%d %H:%M:%S").to_string()
                 })
                 .unwrap_or_else(|| "NULL".to_owned())
@@ -64,3 +66,56 @@ impl<T: DFPrimitiveType> TypeSerializer for DateTimeSerializer<T> {
         Ok(result)
     }
 }
+
+pub struct DateTime64Serializer {
+    precision: u32,
+    tz: Tz,
+}
+
+impl DateTime64Serializer {
+    pub fn create(precision: u32, tz: Tz) -> Self {
+        Self { precision, tz }
+    }
+
+    fn to_string(&self, ticks: i64) -> String {
+        let dt = ticks.to_date_time64(self.precision, &self.tz);
+        match self.precision {
+            0 => dt.format("%Y-%m-%d %H:%M:%S").to_string(),
+            _ => {
+                // the fraction is always padded to the precision, such as '2021-08-30 10:47:42.100'
+                let fraction = dt.timestamp_subsec_nanos()
+                    / 10_u32.pow(DATETIME64_MAX_PRECISION - self.precision);
+                format!(
+                    "{}.{:0>width$}",
+                    dt.format("%Y-%m-%d %H:%M:%S"),
+                    fraction,
+                    width = self.precision as usize
+                )
+            }
+        }
+    }
+}
+
+impl TypeSerializer for DateTime64Serializer {
+    fn serialize_value(&self, value: &DataValue) -> Result<String> {
+        if value.is_null() {
+            return Ok("NULL".to_owned());
+        }
+
+        Ok(self.to_string(value.as_i64()?))
+    }
+
+    fn serialize_column(&self, column: &DataColumn) -> Result<Vec<String>> {
+        let array = column.to_array()?;
+        let array = array.i64()?;
+
+        let result: Vec<String> = array
+            .iter()
+            .map(|x| {
+                x.map(|v| self.to_string(*v))
+                    .unwrap_or_else(|| "NULL".to_owned())
+            })
+            .collect();
+        Ok(result)
+    }
+}
@@ -0,0 +1,42 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+
+use crate::prelude::*;
+
+pub struct DecimalSerializer {
+    pub scale: usize,
+}
+
+impl TypeSerializer for DecimalSerializer {
+    fn serialize_value(&self, value: &DataValue) -> Result<String> {
+        Ok(format!("{:?}", value))
+    }
+
+    fn serialize_column(&self, column: &DataColumn) -> Result<Vec<String>> {
+        let array = column.to_array()?;
+        let array: &DFDecimalArray = array.static_cast();
+
+        let result: Vec<String> = array
+            .inner()
+            .iter()
+            .map(|x| {
+                x.map(|v| decimal_to_string(*v, self.scale))
+                    .unwrap_or_else(|| "NULL".to_owned())
+            })
+            .collect();
+        Ok(result)
+    }
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use chrono_tz::Tz;
 use common_exception::Result;
 
 use crate::prelude::*;
@@ -19,6 +20,8 @@ use crate::prelude::*;
 mod boolean;
 mod date;
 mod date_time;
+mod decimal;
+mod list;
 mod nulls;
 mod number;
 mod string;
@@ -27,6 +30,8 @@ mod r#struct;
 pub use boolean::*;
 pub use date::*;
 pub use date_time::*;
+pub use decimal::*;
+pub use list::*;
 pub use nulls::*;
 pub use number::*;
 pub use r#struct::*;
@@ -54,11 +59,22 @@ impl DataType {
             DataType::Float64 => Box::new(NumberSerializer::<f64>::default()),
             DataType::Date16 => Box::new(DateSerializer::<u16>::default()),
             DataType::Date32 => Box::new(DateSerializer::<i32>::default()),
-            DataType::DateTime32(_) => Box::new(DateTimeSerializer::<u32>::default()),
+            DataType::DateTime32(tz) => {
+                let tz = timezone_or_utc(tz).unwrap_or(Tz::UTC);
+                Box::new(DateTimeSerializer::<u32>::create(tz))
+            }
+            DataType::DateTime64(precision, tz) => {
+                let tz = timezone_or_utc(tz).unwrap_or(Tz::UTC);
+                Box::new(DateTime64Serializer::create(*precision, tz))
+            }
             DataType::String => Box::new(StringSerializer {}),
+            DataType::Decimal128(_, scale) => Box::new(DecimalSerializer { scale: *scale }),
             DataType::Struct(fields) => Box::new(StructSerializer {
                 fields: fields.to_vec(),
             }),
+            DataType::List(inner) => Box::new(ListSerializer {
+                inner: inner.clone(),
+            }),
             _ => todo!(),
         }
     }
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use common_datavalues::prelude::*;
+use common_exception::Result;
 
 #[allow(dead_code)]
 struct Test<T> {
@@ -46,3 +47,76 @@ fn arithmetic_test() {
         assert_eq!(values.collect_values(), t.result);
     }
 }
+
+#[test]
+fn decimal_arithmetic_test() -> Result<()> {
+    struct DecimalTest {
+        name: &'static str,
+        op: DataValueArithmeticOperator,
+        result_type: DataType,
+        result: Vec<Option<i128>>,
+    }
+
+    // 1.50, 20.25, NULL
+    let lhs = DFDecimalArray::new_from_opt_slice(
+        &[Some(150), Some(2025), None],
+        &DataType::Decimal128(5, 2),
+    );
+    // 0.5, 1.5, 2.0
+    let rhs = DFDecimalArray::new_from_opt_slice(
+        &[Some(5), Some(15), Some(20)],
+        &DataType::Decimal128(3, 1),
+    );
+
+    let tests = vec![
+        DecimalTest {
+            name: "test_plus",
+            op: DataValueArithmeticOperator::Plus,
+            result_type: DataType::Decimal128(6, 2),
+            result: vec![Some(200), Some(2175), None],
+        },
+        DecimalTest {
+            name: "test_minus",
+            op: DataValueArithmeticOperator::Minus,
+            result_type: DataType::Decimal128(6, 2),
+            result: vec![Some(100), Some(1875), None],
+        },
+        DecimalTest {
+            name: "test_mul",
+            op: DataValueArithmeticOperator::Mul,
+            result_type: DataType::Decimal128(8, 3),
+            result: vec![Some(750), Some(30375), None],
+        },
+        DecimalTest {
+            name: "test_div",
+            op: DataValueArithmeticOperator::Div,
+            result_type: DataType::Decimal128(9, 6),
+            result: vec![Some(3000000), Some(13500000), None],
+        },
+    ];
+
+    for t in tests {
+        let result = lhs.arithmetic(&t.op, &rhs)?;
+        assert_eq!(result.data_type(), &t.result_type, "{}", t.name);
+        assert_eq!(result.collect_values(), t.result, "{}", t.name);
+    }
+
+    let zero = DFDecimalArray::new_from_slice(&[0], &DataType::Decimal128(3, 1));
+    let result = lhs.arithmetic(&DataValueArithmeticOperator::Div, &zero);
+    assert_eq!(
+        result.unwrap_err().message(),
+        "Division by zero".to_string()
+    );
+
+    // 0 / 0.1 with the max scale
+    let lhs = DFDecimalArray::new_from_slice(&[0], &DataType::Decimal128(38, 0));
+    let rhs = DFDecimalArray::new_from_slice(&[10_i128.pow(37)], &DataType::Decimal128(38, 38));
+    let result = lhs.arithmetic(&DataValueArithmeticOperator::Div, &rhs)?;
+    assert_eq!(result.collect_values(), vec![Some(0)]);
+
+    // every value of the decimal type must fit into an i128
+    assert_eq!(DataType::decimal(38, 2)?, DataType::Decimal128(38, 2));
+    assert!(DataType::decimal(39, 2).is_err());
+
+    Ok(())
+}
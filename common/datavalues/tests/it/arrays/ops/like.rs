@@ -57,6 +57,42 @@ fn test_a_like_binary_scalar() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_a_ilike_binary() -> Result<()> {
+    let strings = DFStringArray::new_from_slice(&["Hello", "HELLO", "hello", "World"]);
+    let patterns = DFStringArray::new_from_slice(&["h%", "%ll_", "HE_LO", "w%D"]);
+
+    let result1 = strings.a_ilike_binary(&patterns, |x| x).unwrap();
+    let vs1: Vec<_> = result1.into_no_null_iter().collect();
+    assert_eq!(vs1, [true, true, true, true]);
+
+    let result2 = strings
+        .a_ilike_binary_scalar("%LL%".as_bytes(), |x| !x)
+        .unwrap();
+    let vs2: Vec<_> = result2.into_no_null_iter().collect();
+    assert_eq!(vs2, [false, false, false, true]);
+
+    Ok(())
+}
+
+#[test]
+fn test_a_regexp_binary() -> Result<()> {
+    let strings = DFStringArray::new_from_slice(&["Hello", "Hello", "hello", "World"]);
+    let patterns = DFStringArray::new_from_slice(&["^H", "l+o$", "^H", "o.l"]);
+
+    let result1 = strings.a_regexp_binary(&patterns, |x| x).unwrap();
+    let vs1: Vec<_> = result1.into_no_null_iter().collect();
+    assert_eq!(vs1, [true, true, false, true]);
+
+    let result2 = strings
+        .a_regexp_binary_scalar("ll".as_bytes(), |x| !x)
+        .unwrap();
+    let vs2: Vec<_> = result2.into_no_null_iter().collect();
+    assert_eq!(vs2, [false, false, false, true]);
+
+    Ok(())
+}
+
 #[test]
 fn test_check_pattern_type() -> Result<()> {
     struct Test {
@@ -55,6 +55,26 @@ fn test_serializers() -> Result<()> {
                 "NULL".to_owned(),
             ],
         },
+        Test {
+            name: "datetime64",
+            data_type: DataType::DateTime64(3, None),
+            value: DataValue::Int64(Some(1630320462123)),
+            column: Series::new(vec![Some(1630320462123i64), Some(1637117572000i64), None]).into(),
+            val_str: "2021-08-30 10:47:42.123",
+            col_str: vec![
+                "2021-08-30 10:47:42.123".to_owned(),
+                "2021-11-17 02:52:52.000".to_owned(),
+                "NULL".to_owned(),
+            ],
+        },
+        Test {
+            name: "datetime32_timezone",
+            data_type: DataType::DateTime32(Some("Asia/Shanghai".to_string())),
+            value: DataValue::UInt32(Some(1630320462)),
+            column: Series::new(vec![Some(1630320462u32), None]).into(),
+            val_str: "2021-08-30 18:47:42",
+            col_str: vec!["2021-08-30 18:47:42".to_owned(), "NULL".to_owned()],
+        },
         Test {
             name: "date32",
             data_type: DataType::Date32,
@@ -67,6 +87,18 @@ fn test_serializers() -> Result<()> {
                 "NULL".to_owned(),
             ],
         },
+        Test {
+            name: "decimal",
+            data_type: DataType::Decimal128(10, 2),
+            value: DataValue::Decimal128(Some(-1205), 10, 2),
+            column: DFDecimalArray::new_from_opt_slice(
+                &[Some(12345i128), Some(5), None],
+                &DataType::Decimal128(10, 2),
+            )
+            .into(),
+            val_str: "-12.05",
+            col_str: vec!["123.45".to_owned(), "0.05".to_owned(), "NULL".to_owned()],
+        },
         Test {
             name: "string",
             data_type: DataType::String,
@@ -32,6 +32,7 @@ pub static ABORT_SESSION: u16 = 42;
 pub static ABORT_QUERY: u16 = 43;
 
 pub static UNKNOWN_USER: u16 = 3000;
+pub static PERMISSION_DENIED: u16 = 3003;
 
 #[derive(Clone)]
 pub enum ErrorCodeBacktrace {
@@ -255,6 +256,11 @@ build_exceptions! {
     UnknownUser(UNKNOWN_USER),
     UserAlreadyExists(3001),
     IllegalUserInfoFormat(3002),
+    PermissionDenied(PERMISSION_DENIED),
+    UnknownRole(3004),
+    RoleAlreadyExists(3005),
+    IllegalRoleInfoFormat(3006),
+    QuotaExceeded(3007),
 
     // meta-api error codes
     DatabaseAlreadyExists(4001),
@@ -265,6 +271,7 @@ build_exceptions! {
     MetaNodeInternalError(4007),
     TruncateTableFailedError(4008),
     CommitTableError(4009),
+    OCCRetryFailure(4010),
 
     // cluster error.
     ClusterUnknownNode(4058),
@@ -287,6 +294,7 @@ build_exceptions! {
     DALTransportError(7000),
     UnknownStorageSchemeName(7001),
     SecretKeyNotSet(7002),
+    DALPathNotFound(7003),
 
     // datasource error
     DuplicatedTableEngineProvider(8000),
@@ -178,6 +178,149 @@ where
     }
 }
 
+struct AggregateDecimalAvgState {
+    pub value: i128,
+    pub count: u64,
+}
+
+impl AggregateDecimalAvgState {
+    #[inline(always)]
+    fn add(&mut self, value: i128, count: u64) -> Result<()> {
+        self.value = self.value.checked_add(value).ok_or_else(|| {
+            ErrorCode::Overflow("Decimal overflow in AggregateDecimalAvgFunction")
+        })?;
+        self.count += count;
+        Ok(())
+    }
+}
+
+/// Average of decimals, the result has 4 more digits of scale than the argument like MySQL.
+#[derive(Clone)]
+pub struct AggregateDecimalAvgFunction {
+    display_name: String,
+    scale: usize,
+    return_type: DataType,
+}
+
+impl AggregateFunction for AggregateDecimalAvgFunction {
+    fn name(&self) -> &str {
+        "AggregateDecimalAvgFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(self.return_type.clone())
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(|| AggregateDecimalAvgState { value: 0, count: 0 });
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateDecimalAvgState>()
+    }
+
+    fn accumulate(&self, place: StateAddr, arrays: &[Series], _input_rows: usize) -> Result<()> {
+        let state = place.get::<AggregateDecimalAvgState>();
+        let value = arrays[0].sum()?;
+        let count = arrays[0].len() - arrays[0].null_count();
+        let opt_sum: Result<i128> = DFTryFrom::try_from(value);
+
+        if let Ok(v) = opt_sum {
+            state.add(v, count as u64)?;
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        arrays: &[Series],
+        _input_rows: usize,
+    ) -> Result<()> {
+        let array = arrays[0].decimal()?;
+
+        for (v, place) in array.inner().iter().zip(places.iter()) {
+            if let Some(v) = v {
+                let place = place.next(offset);
+                let state = place.get::<AggregateDecimalAvgState>();
+                state.add(*v, 1)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<AggregateDecimalAvgState>();
+        state.value.serialize_to_buf(writer)?;
+        state.count.serialize_to_buf(writer)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateDecimalAvgState>();
+        state.value = i128::deserialize(reader)?;
+        state.count = u64::deserialize(reader)?;
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<AggregateDecimalAvgState>();
+        let rhs = rhs.get::<AggregateDecimalAvgState>();
+        state.add(rhs.value, rhs.count)
+    }
+
+    fn merge_result(&self, place: StateAddr) -> Result<DataValue> {
+        let state = place.get::<AggregateDecimalAvgState>();
+
+        let value = match state.count {
+            0 => None,
+            count => {
+                let (_, result_scale) = self.return_type.decimal_precision_scale().unwrap();
+                let sum = rescale_decimal(state.value, self.scale, result_scale)?;
+                Some(sum / count as i128)
+            }
+        };
+
+        match self.return_type {
+            DataType::Decimal128(precision, scale) => {
+                Ok(DataValue::Decimal128(value, precision, scale))
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl fmt::Display for AggregateDecimalAvgFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+impl AggregateDecimalAvgFunction {
+    pub fn try_create(display_name: &str, data_type: &DataType) -> Result<AggregateFunctionRef> {
+        let (max_precision, scale) = match data_type {
+            DataType::Decimal128(_, scale) => (DECIMAL128_MAX_PRECISION, *scale),
+            _ => {
+                return Err(ErrorCode::BadDataValueType(format!(
+                    "AggregateDecimalAvgFunction does not support type '{:?}'",
+                    data_type
+                )))
+            }
+        };
+
+        Ok(Arc::new(Self {
+            display_name: display_name.to_string(),
+            scale,
+            return_type: DataType::decimal(max_precision, max_precision.min(scale + 4))?,
+        }))
+    }
+}
+
 pub fn try_create_aggregate_avg_function(
     display_name: &str,
     _params: Vec<DataValue>,
@@ -186,6 +329,10 @@ pub fn try_create_aggregate_avg_function(
     assert_unary_arguments(display_name, arguments.len())?;
 
     let data_type = arguments[0].data_type();
+    if data_type.is_decimal() {
+        return AggregateDecimalAvgFunction::try_create(display_name, data_type);
+    }
+
     with_match_primitive_type!(data_type, |$T| {
         AggregateAvgFunction::<$T, <$T as DFPrimitiveType>::LargestType>::try_create(
             display_name,
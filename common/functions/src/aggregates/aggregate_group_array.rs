@@ -0,0 +1,190 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::collections::HashSet;
+use std::convert::TryFrom;
+use std::fmt;
+use std::sync::Arc;
+
+use bytes::BytesMut;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+
+use super::StateAddr;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
+use crate::aggregates::aggregator_common::assert_unary_arguments;
+use crate::aggregates::AggregateFunction;
+
+pub struct AggregateGroupArrayState {
+    values: Vec<DataValue>,
+    // only for groupUniqArray
+    set: HashSet<DataGroupValue>,
+}
+
+impl AggregateGroupArrayState {
+    fn add(&mut self, value: DataValue, uniq: bool) -> Result<()> {
+        if value.is_null() {
+            return Ok(());
+        }
+
+        if !uniq || self.set.insert(DataGroupValue::try_from(&value)?) {
+            self.values.push(value);
+        }
+        Ok(())
+    }
+}
+
+/// groupArray(x) collects the values of x into an array, and groupUniqArray(x) collects the
+/// distinct values. The NULL values are left out.
+#[derive(Clone)]
+pub struct AggregateGroupArrayFunction<const UNIQ: bool> {
+    display_name: String,
+    arguments: Vec<DataField>,
+}
+
+impl<const UNIQ: bool> AggregateGroupArrayFunction<UNIQ> {
+    pub fn try_create(
+        display_name: &str,
+        _params: Vec<DataValue>,
+        arguments: Vec<DataField>,
+    ) -> Result<Arc<dyn AggregateFunction>> {
+        assert_unary_arguments(display_name, arguments.len())?;
+
+        let data_type = arguments[0].data_type();
+        if !data_type.is_numeric()
+            && data_type != &DataType::String
+            && data_type != &DataType::Boolean
+        {
+            return Err(ErrorCode::BadDataValueType(format!(
+                "{} does not support type '{:?}'",
+                display_name, data_type
+            )));
+        }
+
+        Ok(Arc::new(AggregateGroupArrayFunction::<UNIQ> {
+            display_name: display_name.to_string(),
+            arguments,
+        }))
+    }
+}
+
+impl<const UNIQ: bool> AggregateFunction for AggregateGroupArrayFunction<UNIQ> {
+    fn name(&self) -> &str {
+        "AggregateGroupArrayFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(DataType::List(Box::new(DataField::new(
+            "item",
+            self.arguments[0].data_type().clone(),
+            true,
+        ))))
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(|| AggregateGroupArrayState {
+            values: vec![],
+            set: HashSet::new(),
+        });
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateGroupArrayState>()
+    }
+
+    fn accumulate(&self, place: StateAddr, arrays: &[Series], _input_rows: usize) -> Result<()> {
+        let state = place.get::<AggregateGroupArrayState>();
+        for value in arrays[0].to_values()? {
+            state.add(value, UNIQ)?;
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        arrays: &[Series],
+        _input_rows: usize,
+    ) -> Result<()> {
+        for (row, place) in places.iter().enumerate() {
+            let place = place.next(offset);
+            let state = place.get::<AggregateGroupArrayState>();
+            state.add(arrays[0].try_get(row)?, UNIQ)?;
+        }
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<AggregateGroupArrayState>();
+        writer.write_uvarint(state.values.len() as u64)?;
+        for value in state.values.iter() {
+            value.serialize_to_buf(writer)?;
+        }
+        Ok(())
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateGroupArrayState>();
+        state.values.clear();
+        state.set.clear();
+
+        let size = reader.read_uvarint()?;
+        state.values.reserve(size as usize);
+        for _i in 0..size {
+            state.add(DataValue::deserialize(reader)?, UNIQ)?;
+        }
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<AggregateGroupArrayState>();
+        let rhs = rhs.get::<AggregateGroupArrayState>();
+        for value in rhs.values.iter() {
+            state.add(value.clone(), UNIQ)?;
+        }
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr) -> Result<DataValue> {
+        let state = place.get::<AggregateGroupArrayState>();
+        Ok(DataValue::List(
+            Some(state.values.clone()),
+            self.arguments[0].data_type().clone(),
+        ))
+    }
+}
+
+impl<const UNIQ: bool> fmt::Display for AggregateGroupArrayFunction<UNIQ> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+pub fn aggregate_group_array_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        AggregateGroupArrayFunction::<false>::try_create,
+    ))
+}
+
+pub fn aggregate_group_uniq_array_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(AggregateGroupArrayFunction::<true>::try_create))
+}
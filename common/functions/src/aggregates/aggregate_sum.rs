@@ -59,6 +59,20 @@ where
     }
 }
 
+impl AggregateSumState<i128> {
+    #[inline(always)]
+    fn checked_add(&mut self, other: i128) -> Result<()> {
+        let value = match self.value {
+            Some(a) => a
+                .checked_add(other)
+                .ok_or_else(|| ErrorCode::Overflow("Decimal overflow in AggregateSumFunction"))?,
+            None => other,
+        };
+        self.value = Some(value);
+        Ok(())
+    }
+}
+
 #[derive(Clone)]
 pub struct AggregateSumFunction<T, SumT> {
     display_name: String,
@@ -193,6 +207,122 @@ where
     }
 }
 
+/// Sum of decimals, the result keeps the scale and takes the max precision of the decimal type.
+#[derive(Clone)]
+pub struct AggregateDecimalSumFunction {
+    display_name: String,
+    return_type: DataType,
+}
+
+impl AggregateFunction for AggregateDecimalSumFunction {
+    fn name(&self) -> &str {
+        "AggregateDecimalSumFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(self.return_type.clone())
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(|| AggregateSumState::<i128> { value: None });
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateSumState<i128>>()
+    }
+
+    fn accumulate(&self, place: StateAddr, arrays: &[Series], _input_rows: usize) -> Result<()> {
+        let value = arrays[0].sum()?;
+        let opt_sum: Result<i128> = DFTryFrom::try_from(value);
+
+        if let Ok(s) = opt_sum {
+            let state = place.get::<AggregateSumState<i128>>();
+            state.checked_add(s)?;
+        }
+
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        arrays: &[Series],
+        _input_rows: usize,
+    ) -> Result<()> {
+        let darray = arrays[0].decimal()?;
+        for (c, place) in darray.inner().iter().zip(places.iter()) {
+            if let Some(v) = c {
+                let place = place.next(offset);
+                let state = place.get::<AggregateSumState<i128>>();
+                state.checked_add(*v)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<AggregateSumState<i128>>();
+        state.serialize(writer)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateSumState<i128>>();
+        state.deserialize(reader)
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let rhs = rhs.get::<AggregateSumState<i128>>();
+        if let Some(s) = &rhs.value {
+            let state = place.get::<AggregateSumState<i128>>();
+            state.checked_add(*s)?;
+        }
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr) -> Result<DataValue> {
+        let state = place.get::<AggregateSumState<i128>>();
+        match self.return_type {
+            DataType::Decimal128(precision, scale) => {
+                Ok(DataValue::Decimal128(state.value, precision, scale))
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl fmt::Display for AggregateDecimalSumFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+impl AggregateDecimalSumFunction {
+    pub fn try_create(display_name: &str, data_type: &DataType) -> Result<AggregateFunctionRef> {
+        let return_type = match data_type {
+            DataType::Decimal128(_, scale) => {
+                DataType::Decimal128(DECIMAL128_MAX_PRECISION, *scale)
+            }
+            _ => {
+                return Err(ErrorCode::BadDataValueType(format!(
+                    "AggregateDecimalSumFunction does not support type '{:?}'",
+                    data_type
+                )))
+            }
+        };
+
+        Ok(Arc::new(Self {
+            display_name: display_name.to_owned(),
+            return_type,
+        }))
+    }
+}
+
 pub fn try_create_aggregate_sum_function(
     display_name: &str,
     _params: Vec<DataValue>,
@@ -201,6 +331,10 @@ pub fn try_create_aggregate_sum_function(
     assert_unary_arguments(display_name, arguments.len())?;
 
     let data_type = arguments[0].data_type();
+    if data_type.is_decimal() {
+        return AggregateDecimalSumFunction::try_create(display_name, data_type);
+    }
+
     with_match_primitive_type!(data_type, |$T| {
         AggregateSumFunction::<$T, <$T as DFPrimitiveType>::LargestType>::try_create(
              display_name,
@@ -18,6 +18,8 @@ use crate::aggregates::aggregate_avg::aggregate_avg_function_desc;
 use crate::aggregates::aggregate_covariance::aggregate_covariance_population_desc;
 use crate::aggregates::aggregate_covariance::aggregate_covariance_sample_desc;
 use crate::aggregates::aggregate_function_factory::AggregateFunctionFactory;
+use crate::aggregates::aggregate_group_array::aggregate_group_array_function_desc;
+use crate::aggregates::aggregate_group_array::aggregate_group_uniq_array_function_desc;
 use crate::aggregates::aggregate_min_max::aggregate_max_function_desc;
 use crate::aggregates::aggregate_min_max::aggregate_min_function_desc;
 use crate::aggregates::aggregate_stddev_pop::aggregate_stddev_pop_function_desc;
@@ -46,6 +48,8 @@ impl Aggregators {
         factory.register("uniq", AggregateDistinctCombinator::uniq_desc());
         factory.register("covar_samp", aggregate_covariance_sample_desc());
         factory.register("covar_pop", aggregate_covariance_population_desc());
+        factory.register("groupArray", aggregate_group_array_function_desc());
+        factory.register("groupUniqArray", aggregate_group_uniq_array_function_desc());
     }
 
     pub fn register_combinator(factory: &mut AggregateFunctionFactory) {
@@ -20,6 +20,7 @@ mod aggregate_count;
 mod aggregate_function;
 mod aggregate_function_factory;
 mod aggregate_function_state;
+mod aggregate_group_array;
 mod aggregate_min_max;
 mod aggregate_window_funnel;
 
@@ -45,6 +46,7 @@ pub use aggregate_function_factory::AggregateFunctionFactory;
 pub use aggregate_function_state::get_layout_offsets;
 pub use aggregate_function_state::StateAddr;
 pub use aggregate_function_state::StateAddrs;
+pub use aggregate_group_array::AggregateGroupArrayFunction;
 pub use aggregate_min_max::AggregateMinMaxFunction;
 pub use aggregate_stddev_pop::AggregateStddevPopFunction;
 pub use aggregate_sum::AggregateSumFunction;
@@ -0,0 +1,42 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::scalars::function_factory::FunctionFactory;
+use crate::scalars::ArrayConcatFunction;
+use crate::scalars::ArrayDistinctFunction;
+use crate::scalars::ArrayElementFunction;
+use crate::scalars::ArrayFunction;
+use crate::scalars::ArrayHasFunction;
+use crate::scalars::ArrayIndexOfFunction;
+use crate::scalars::ArrayJoinFunction;
+use crate::scalars::ArrayLengthFunction;
+use crate::scalars::ArraySliceFunction;
+
+#[derive(Clone)]
+pub struct ArrayClassFunction;
+
+impl ArrayClassFunction {
+    pub fn register(factory: &mut FunctionFactory) {
+        factory.register("array", ArrayFunction::desc());
+        factory.register("arrayElement", ArrayElementFunction::desc());
+        factory.register("length", ArrayLengthFunction::desc());
+        factory.register("has", ArrayHasFunction::desc());
+        factory.register("indexOf", ArrayIndexOfFunction::desc());
+        factory.register("arrayConcat", ArrayConcatFunction::desc());
+        factory.register("arraySlice", ArraySliceFunction::desc());
+        factory.register("arrayDistinct", ArrayDistinctFunction::desc());
+        factory.register("arrayJoin", ArrayJoinFunction::desc());
+        factory.register("unnest", ArrayJoinFunction::desc());
+    }
+}
@@ -0,0 +1,107 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use super::array_utils::array_element_type;
+use super::array_utils::array_type;
+use super::array_utils::build_array_series;
+use super::array_utils::columns_to_series;
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+/// arrayConcat(arr1, arr2, ...) concatenates the arrays, the NULL arrays are taken as empty.
+#[derive(Clone)]
+pub struct ArrayConcatFunction {
+    display_name: String,
+}
+
+impl ArrayConcatFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ArrayConcatFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+
+    fn element_type(&self, args: &[DataType]) -> Result<DataType> {
+        let element_types = args
+            .iter()
+            .map(|arg| Ok(array_element_type(arg, &self.display_name)?.clone()))
+            .collect::<Result<Vec<_>>>()?;
+        aggregate_types(&element_types)
+    }
+}
+
+impl Function for ArrayConcatFunction {
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((1, usize::MAX))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        array_type(&self.element_type(args)?, &self.display_name)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let data_types = columns
+            .iter()
+            .map(|column| column.data_type().clone())
+            .collect::<Vec<_>>();
+        let element_type = self.element_type(&data_types)?;
+
+        let (series, rows) = columns_to_series(columns)?;
+        let lists = series
+            .iter()
+            .map(|s| Ok(s.list()?.into_iter().collect::<Vec<_>>()))
+            .collect::<Result<Vec<_>>>()?;
+
+        let elements = (0..rows)
+            .map(|row| {
+                let mut values = vec![];
+                for elements in lists.iter().filter_map(|list| list[row].as_ref()) {
+                    values.extend(elements.cast_with_type(&element_type)?.to_values()?);
+                }
+                Ok(Some(DataValue::try_into_data_array(
+                    &values,
+                    &element_type,
+                )?))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let column: DataColumn = build_array_series(&elements, &element_type)?.into();
+        Ok(column.resize_constant(input_rows))
+    }
+}
+
+impl fmt::Display for ArrayConcatFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}()", self.display_name)
+    }
+}
@@ -0,0 +1,100 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::convert::TryFrom;
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use super::array_utils::array_element_type;
+use super::array_utils::build_array_series;
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+/// arrayDistinct(arr) returns the distinct elements of the array in the order they first appear,
+/// the NULL elements are left out.
+#[derive(Clone)]
+pub struct ArrayDistinctFunction {
+    display_name: String,
+}
+
+impl ArrayDistinctFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ArrayDistinctFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+
+    fn distinct(elements: &Series) -> Result<Series> {
+        let mut set = HashSet::with_capacity(elements.len());
+        let mut indices = Vec::with_capacity(elements.len());
+        for (index, value) in elements.to_values()?.iter().enumerate() {
+            if !value.is_null() && set.insert(DataGroupValue::try_from(value)?) {
+                indices.push(index);
+            }
+        }
+        elements.take_iter(&mut indices.into_iter())
+    }
+}
+
+impl Function for ArrayDistinctFunction {
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn num_arguments(&self) -> usize {
+        1
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        array_element_type(&args[0], &self.display_name)?;
+        Ok(args[0].clone())
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let element_type = array_element_type(columns[0].data_type(), &self.display_name)?;
+
+        let series = columns[0].column().to_minimal_array()?;
+        let elements = series
+            .list()?
+            .into_iter()
+            .map(|elements| {
+                elements
+                    .map(|elements| Self::distinct(&elements))
+                    .transpose()
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let column: DataColumn = build_array_series(&elements, element_type)?.into();
+        Ok(column.resize_constant(input_rows))
+    }
+}
+
+impl fmt::Display for ArrayDistinctFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}()", self.display_name)
+    }
+}
@@ -0,0 +1,106 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::array_utils::array_element_type;
+use super::array_utils::columns_to_series;
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+/// arrayElement(arr, n) returns the n-th element of the array, the index starts from 1 and
+/// the negative index counts from the end. It returns NULL if the index is out of the array.
+/// The subscript `arr[n]` is evaluated by it.
+#[derive(Clone)]
+pub struct ArrayElementFunction {
+    display_name: String,
+}
+
+impl ArrayElementFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ArrayElementFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for ArrayElementFunction {
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn num_arguments(&self) -> usize {
+        2
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        if !args[1].is_integer() {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Illegal type {:?} of argument of function {}.Should be an integer",
+                args[1], self.display_name
+            )));
+        }
+        Ok(array_element_type(&args[0], &self.display_name)?.clone())
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let element_type = array_element_type(columns[0].data_type(), &self.display_name)?;
+
+        let (series, _) = columns_to_series(columns)?;
+        let indexes = series[1].cast_with_type(&DataType::Int64)?;
+        let values = series[0]
+            .list()?
+            .into_iter()
+            .zip(indexes.i64()?.into_iter())
+            .map(|(elements, index)| match (elements, index) {
+                (Some(elements), Some(index)) => {
+                    let len = elements.len() as i64;
+                    // The index 0 is out of the array too, as len + 0.
+                    let index = match *index {
+                        index if index > 0 => index - 1,
+                        index => len + index,
+                    };
+                    match index >= 0 && index < len {
+                        true => elements.try_get(index as usize),
+                        false => Ok(element_type.into()),
+                    }
+                }
+                _ => Ok(element_type.into()),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let column: DataColumn = DataValue::try_into_data_array(&values, element_type)?.into();
+        Ok(column.resize_constant(input_rows))
+    }
+}
+
+impl fmt::Display for ArrayElementFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}()", self.display_name)
+    }
+}
@@ -0,0 +1,96 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use super::array_utils::array_element_type;
+use super::array_utils::columns_to_series;
+use super::array_utils::find_element;
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+/// indexOf(arr, x) returns the index of the first element equal to x, the index starts from 1.
+/// It returns 0 if the array doesn't contain x.
+#[derive(Clone)]
+pub struct ArrayIndexOfFunction {
+    display_name: String,
+}
+
+impl ArrayIndexOfFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ArrayIndexOfFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for ArrayIndexOfFunction {
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn num_arguments(&self) -> usize {
+        2
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        let element_type = array_element_type(&args[0], &self.display_name)?;
+        merge_types(element_type, &args[1])?;
+        Ok(DataType::UInt64)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let element_type = array_element_type(columns[0].data_type(), &self.display_name)?;
+        let common_type = merge_types(element_type, columns[1].data_type())?;
+
+        let (series, _) = columns_to_series(columns)?;
+        let values = series[1].cast_with_type(&common_type)?;
+
+        let results = series[0]
+            .list()?
+            .into_iter()
+            .enumerate()
+            .map(|(row, elements)| {
+                let position = match elements {
+                    Some(elements) => find_element(&elements, &values.try_get(row)?, &common_type)?,
+                    None => None,
+                };
+                Ok(position.map_or(0, |position| position as u64 + 1))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let array = DFUInt64Array::new_from_slice(&results);
+        let column: DataColumn = array.into();
+        Ok(column.resize_constant(input_rows))
+    }
+}
+
+impl fmt::Display for ArrayIndexOfFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}()", self.display_name)
+    }
+}
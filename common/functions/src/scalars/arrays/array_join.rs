@@ -0,0 +1,97 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use super::array_utils::array_element_type;
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+/// arrayJoin(arr) expands each row to the rows of the elements of its array, the rows of
+/// the empty and NULL arrays are removed. It returns the flattened elements, and the other
+/// columns are replicated by `replicate_indices` in the expression executor.
+#[derive(Clone)]
+pub struct ArrayJoinFunction {
+    display_name: String,
+}
+
+impl ArrayJoinFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ArrayJoinFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().array_join_function())
+    }
+
+    /// Returns the source row of each expanded row, for the array column.
+    pub fn replicate_indices(column: &DataColumn) -> Result<Vec<usize>> {
+        let series = column.to_array()?;
+        let mut indices = vec![];
+        for (row, elements) in series.list()?.into_iter().enumerate() {
+            if let Some(elements) = elements {
+                indices.extend(std::iter::repeat(row).take(elements.len()));
+            }
+        }
+        Ok(indices)
+    }
+}
+
+impl Function for ArrayJoinFunction {
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn num_arguments(&self) -> usize {
+        1
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        Ok(array_element_type(&args[0], &self.display_name)?.clone())
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, _input_rows: usize) -> Result<DataColumn> {
+        let element_type = array_element_type(columns[0].data_type(), &self.display_name)?;
+
+        let series = columns[0].column().to_array()?;
+        let elements = series
+            .list()?
+            .into_iter()
+            .flatten()
+            .map(DataColumn::Array)
+            .collect::<Vec<_>>();
+
+        match elements.is_empty() {
+            true => Ok(DataValue::try_into_data_array(&[], element_type)?.into()),
+            false => DataColumnCommon::concat(&elements),
+        }
+    }
+}
+
+impl fmt::Display for ArrayJoinFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}()", self.display_name)
+    }
+}
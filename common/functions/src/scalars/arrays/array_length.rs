@@ -0,0 +1,86 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+use crate::scalars::LengthFunction;
+
+/// length(arr) returns the count of the elements in the array. For the other arguments,
+/// it returns the bytes of the string as the string function `length` does.
+#[derive(Clone)]
+pub struct ArrayLengthFunction {
+    display_name: String,
+}
+
+impl ArrayLengthFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ArrayLengthFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for ArrayLengthFunction {
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn num_arguments(&self) -> usize {
+        1
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        match &args[0] {
+            DataType::List(_) => Ok(DataType::UInt64),
+            _ => LengthFunction::try_create(&self.display_name)?.return_type(args),
+        }
+    }
+
+    fn nullable(&self, input_schema: &DataSchema) -> Result<bool> {
+        LengthFunction::try_create(&self.display_name)?.nullable(input_schema)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        if !matches!(columns[0].data_type(), DataType::List(_)) {
+            return LengthFunction::try_create(&self.display_name)?.eval(columns, input_rows);
+        }
+
+        let series = columns[0].column().to_minimal_array()?;
+        let array: DFUInt64Array = series
+            .list()?
+            .into_iter()
+            .map(|elements| elements.map(|elements| elements.len() as u64))
+            .collect();
+
+        let column: DataColumn = array.into();
+        Ok(column.resize_constant(input_rows))
+    }
+}
+
+impl fmt::Display for ArrayLengthFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}()", self.display_name)
+    }
+}
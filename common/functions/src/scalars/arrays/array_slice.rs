@@ -0,0 +1,138 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::array_utils::array_element_type;
+use super::array_utils::build_array_series;
+use super::array_utils::columns_to_series;
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+/// arraySlice(arr, offset[, length]) returns the slice of the array from the offset, the offset
+/// starts from 1 and the negative offset counts from the end. Without the length, the slice
+/// ends at the end of the array, and the negative length leaves the elements at the end out.
+#[derive(Clone)]
+pub struct ArraySliceFunction {
+    display_name: String,
+}
+
+impl ArraySliceFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ArraySliceFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+
+    /// Returns the 0-based start and end of the slice in the array of the len.
+    #[inline]
+    fn slice_range(len: i64, offset: i64, length: Option<i64>) -> (usize, usize) {
+        let start = match offset {
+            0 => return (0, 0),
+            offset if offset > 0 => (offset - 1).min(len),
+            offset => (len + offset).max(0),
+        };
+        let end = match length {
+            None => len,
+            Some(length) if length >= 0 => (start + length).min(len),
+            Some(length) => (len + length).max(start),
+        };
+        (start as usize, end as usize)
+    }
+}
+
+impl Function for ArraySliceFunction {
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((2, 3))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        array_element_type(&args[0], &self.display_name)?;
+        for arg in &args[1..] {
+            if !arg.is_integer() {
+                return Err(ErrorCode::IllegalDataType(format!(
+                    "Illegal type {:?} of argument of function {}.Should be an integer",
+                    arg, self.display_name
+                )));
+            }
+        }
+        Ok(args[0].clone())
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let element_type = array_element_type(columns[0].data_type(), &self.display_name)?;
+
+        let (series, _) = columns_to_series(columns)?;
+        let offsets = series[1].cast_with_type(&DataType::Int64)?;
+        let lengths = match series.get(2) {
+            Some(lengths) => Some(lengths.cast_with_type(&DataType::Int64)?),
+            None => None,
+        };
+
+        let elements = series[0]
+            .list()?
+            .into_iter()
+            .enumerate()
+            .map(|(row, elements)| {
+                let elements = match elements {
+                    Some(elements) => elements,
+                    None => return Ok(None),
+                };
+
+                let offset = offsets.try_get(row)?;
+                let length = match &lengths {
+                    Some(lengths) => Some(lengths.try_get(row)?),
+                    None => None,
+                };
+                if offset.is_null() || length.as_ref().map_or(false, |length| length.is_null()) {
+                    return Ok(None);
+                }
+
+                let (start, end) = Self::slice_range(
+                    elements.len() as i64,
+                    offset.as_i64()?,
+                    length.map(|length| length.as_i64()).transpose()?,
+                );
+                Ok(Some(elements.slice(start, end - start)))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let column: DataColumn = build_array_series(&elements, element_type)?.into();
+        Ok(column.resize_constant(input_rows))
+    }
+}
+
+impl fmt::Display for ArraySliceFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}()", self.display_name)
+    }
+}
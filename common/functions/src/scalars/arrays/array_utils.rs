@@ -0,0 +1,92 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+/// Returns the element type of the array argument of the function.
+pub fn array_element_type<'a>(data_type: &'a DataType, display_name: &str) -> Result<&'a DataType> {
+    match data_type {
+        DataType::List(inner) => Ok(inner.data_type()),
+        other => Err(ErrorCode::IllegalDataType(format!(
+            "Illegal type {:?} of argument of function {}.Should be an array",
+            other, display_name
+        ))),
+    }
+}
+
+/// Returns the array type of the elements, only numbers, strings and booleans can be the elements.
+pub fn array_type(element_type: &DataType, display_name: &str) -> Result<DataType> {
+    match element_type {
+        DataType::String | DataType::Boolean => {}
+        other if other.is_numeric() => {}
+        other => {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Unsupported array element type {:?} of function {}",
+                other, display_name
+            )))
+        }
+    }
+    Ok(DataType::List(Box::new(DataField::new(
+        "item",
+        element_type.clone(),
+        true,
+    ))))
+}
+
+/// Evaluates the columns to series of the same rows. If all the columns are constant,
+/// the series have only one row, and the result should be resized to the input rows.
+pub fn columns_to_series(columns: &DataColumnsWithField) -> Result<(Vec<Series>, usize)> {
+    let rows = columns
+        .iter()
+        .map(|column| match column.column() {
+            DataColumn::Array(array) => array.len(),
+            DataColumn::Constant(_, _) => 1,
+        })
+        .max()
+        .unwrap_or(1);
+
+    let series = columns
+        .iter()
+        .map(|column| match column.column() {
+            DataColumn::Array(array) => Ok(array.clone()),
+            DataColumn::Constant(value, _) => value.to_series_with_size(rows),
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok((series, rows))
+}
+
+/// Builds the array column from the elements of each row, the None rows are NULL arrays.
+pub fn build_array_series(rows: &[Option<Series>], element_type: &DataType) -> Result<Series> {
+    let mut builder = get_list_builder(element_type, rows.len() * 5, rows.len());
+    for row in rows.iter() {
+        match row {
+            Some(elements) => builder.append_series(&elements.cast_with_type(element_type)?),
+            None => builder.append_null(),
+        }
+    }
+    Ok(builder.finish().into_series())
+}
+
+/// Returns the 0-based position of the first element equal to the value, the elements and
+/// the value are compared in their common type.
+pub fn find_element(
+    elements: &Series,
+    value: &DataValue,
+    common_type: &DataType,
+) -> Result<Option<usize>> {
+    let elements = elements.cast_with_type(common_type)?.to_values()?;
+    Ok(elements.iter().position(|element| element == value))
+}
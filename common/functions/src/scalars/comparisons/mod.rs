@@ -16,18 +16,26 @@ mod comparison;
 mod comparison_eq;
 mod comparison_gt;
 mod comparison_gt_eq;
+mod comparison_ilike;
 mod comparison_like;
 mod comparison_lt;
 mod comparison_lt_eq;
 mod comparison_not_eq;
+mod comparison_not_ilike;
 mod comparison_not_like;
+mod comparison_not_regexp;
+mod comparison_regexp;
 
 pub use comparison::ComparisonFunction;
 pub use comparison_eq::ComparisonEqFunction;
 pub use comparison_gt::ComparisonGtFunction;
 pub use comparison_gt_eq::ComparisonGtEqFunction;
+pub use comparison_ilike::ComparisonILikeFunction;
 pub use comparison_like::ComparisonLikeFunction;
 pub use comparison_lt::ComparisonLtFunction;
 pub use comparison_lt_eq::ComparisonLtEqFunction;
 pub use comparison_not_eq::ComparisonNotEqFunction;
+pub use comparison_not_ilike::ComparisonNotILikeFunction;
 pub use comparison_not_like::ComparisonNotLikeFunction;
+pub use comparison_not_regexp::ComparisonNotRegexpFunction;
+pub use comparison_regexp::ComparisonRegexpFunction;
@@ -13,7 +13,13 @@
 // limitations under the License.
 
 use crate::scalars::function_factory::FunctionFactory;
+use crate::scalars::CoalesceFunction;
 use crate::scalars::IfFunction;
+use crate::scalars::IfNullFunction;
+use crate::scalars::InFunction;
+use crate::scalars::MultiIfFunction;
+use crate::scalars::NotInFunction;
+use crate::scalars::NullIfFunction;
 
 #[derive(Clone)]
 pub struct ConditionalFunction;
@@ -21,5 +27,11 @@ pub struct ConditionalFunction;
 impl ConditionalFunction {
     pub fn register(factory: &mut FunctionFactory) {
         factory.register("if", IfFunction::desc());
+        factory.register("multiIf", MultiIfFunction::desc());
+        factory.register("in", InFunction::desc());
+        factory.register("not in", NotInFunction::desc());
+        factory.register("coalesce", CoalesceFunction::desc());
+        factory.register("ifNull", IfNullFunction::desc());
+        factory.register("nullIf", NullIfFunction::desc());
     }
 }
@@ -0,0 +1,244 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::fmt;
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_infallible::Mutex;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+pub type InFunction = InBasicFunction<false>;
+pub type NotInFunction = InBasicFunction<true>;
+
+/// `in(expr, v1, v2, ...)` checks if `expr` is one of the constant values.
+/// `in(expr, subquery)` checks against the set built from a subquery result,
+/// which is a constant list column evaluated by the CreateSets transform.
+pub struct InBasicFunction<const NEGATED: bool> {
+    // The set built by the last eval, the values are the same for every block.
+    set: Mutex<Option<Arc<InSet>>>,
+}
+
+struct InSet {
+    values: Vec<DataValue>,
+    data_type: DataType,
+    keys: HashSet<Vec<u8>>,
+    // A NULL in the set makes the result of the rows without a match NULL.
+    has_null: bool,
+}
+
+impl<const NEGATED: bool> Clone for InBasicFunction<NEGATED> {
+    fn clone(&self) -> Self {
+        InBasicFunction {
+            set: Mutex::new(self.set.lock().clone()),
+        }
+    }
+}
+
+impl<const NEGATED: bool> InBasicFunction<NEGATED> {
+    pub fn try_create_func(_display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(InBasicFunction::<NEGATED> {
+            set: Mutex::new(None),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        let negative_name = match NEGATED {
+            true => "in",
+            false => "not in",
+        };
+
+        FunctionDescription::creator(Box::new(Self::try_create_func)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .negative_function(negative_name)
+                .bool_function(),
+        )
+    }
+
+    // The values to look up in, each column is either a constant value or a constant list.
+    fn set_values(columns: &[DataColumnWithField]) -> Result<Vec<DataValue>> {
+        let mut values = Vec::with_capacity(columns.len());
+        for column in columns {
+            match column.column() {
+                DataColumn::Constant(DataValue::Struct(_), _) => {
+                    return Err(ErrorCode::UnImplement(
+                        "IN subquery with multiple columns is not supported",
+                    ));
+                }
+                DataColumn::Constant(value, _) => values.push(value.clone()),
+                DataColumn::Array(_) => {
+                    return Err(ErrorCode::BadArguments(
+                        "The values of IN must be constants or a subquery",
+                    ));
+                }
+            }
+        }
+        Ok(values)
+    }
+
+    fn value_type(value: &DataValue) -> DataType {
+        match value {
+            DataValue::List(_, data_type) => data_type.clone(),
+            value => value.data_type(),
+        }
+    }
+
+    fn is_empty_list(value: &DataValue) -> bool {
+        match value {
+            DataValue::List(values, _) => values.as_ref().map_or(true, |v| v.is_empty()),
+            _ => false,
+        }
+    }
+
+    // Reuse the set of the last eval if it is built from the same values and type.
+    fn get_set(&self, values: Vec<DataValue>, data_type: DataType) -> Result<Arc<InSet>> {
+        let mut cached = self.set.lock();
+        if let Some(set) = cached.as_ref() {
+            if set.values == values && set.data_type == data_type {
+                return Ok(set.clone());
+            }
+        }
+
+        let mut keys = HashSet::new();
+        let mut has_null = false;
+        for value in values.iter() {
+            let series = match value {
+                DataValue::List(list, list_type) => {
+                    let list = list.clone().unwrap_or_default();
+                    DataValue::try_into_data_array(&list, list_type)?
+                }
+                value => value.to_series_with_size(1)?,
+            };
+            if series.data_type() == &DataType::Null {
+                has_null = has_null || !series.is_empty();
+                continue;
+            }
+            for key in Self::serialize_keys(&series, &data_type)? {
+                match key {
+                    Some(key) => {
+                        keys.insert(key);
+                    }
+                    None => has_null = true,
+                }
+            }
+        }
+
+        let set = Arc::new(InSet {
+            values,
+            data_type,
+            keys,
+            has_null,
+        });
+        *cached = Some(set.clone());
+        Ok(set)
+    }
+
+    // Serialize the non-null rows, so that values can be looked up in a hash set.
+    fn serialize_keys(series: &Series, data_type: &DataType) -> Result<Vec<Option<Vec<u8>>>> {
+        let series = match series.data_type() == data_type {
+            true => series.clone(),
+            false => series.cast_with_type(data_type)?,
+        };
+
+        let mut keys = vec![Vec::new(); series.len()];
+        series.serialize(&mut keys)?;
+
+        Ok(keys
+            .into_iter()
+            .enumerate()
+            .map(|(row, key)| match series.is_null(row) {
+                true => None,
+                false => Some(key),
+            })
+            .collect())
+    }
+}
+
+impl<const NEGATED: bool> Function for InBasicFunction<NEGATED> {
+    fn name(&self) -> &str {
+        match NEGATED {
+            true => "NotInFunction",
+            false => "InFunction",
+        }
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((2, usize::MAX))
+    }
+
+    fn return_type(&self, _args: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Boolean)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let values = Self::set_values(&columns[1..])?;
+
+        // Nothing is in an empty set, not even NULL.
+        if values.iter().all(Self::is_empty_list) {
+            return Ok(DataColumn::Constant(
+                DataValue::Boolean(Some(NEGATED)),
+                input_rows,
+            ));
+        }
+
+        let set_types = values
+            .iter()
+            .map(Self::value_type)
+            .filter(|t| t != &DataType::Null)
+            .collect::<Vec<_>>();
+
+        let expr_type = columns[0].data_type();
+        if set_types.is_empty() || expr_type == &DataType::Null {
+            return Ok(DataColumn::Constant(DataValue::Boolean(None), input_rows));
+        }
+
+        let set_type = aggregate_types(&set_types)?;
+        let data_type = compare_coercion(expr_type, &set_type)?;
+        let set = self.get_set(values, data_type.clone())?;
+
+        let input = columns[0].column().to_minimal_array()?;
+        let result: DFBooleanArray = Self::serialize_keys(&input, &data_type)?
+            .into_iter()
+            .map(|key| match key {
+                Some(key) if set.keys.contains(&key) => Some(!NEGATED),
+                Some(_) if set.has_null => None,
+                Some(_) => Some(NEGATED),
+                None => None,
+            })
+            .collect();
+
+        let result: DataColumn = result.into_series().into();
+        Ok(result.resize_constant(input_rows))
+    }
+}
+
+impl<const NEGATED: bool> fmt::Display for InBasicFunction<NEGATED> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match NEGATED {
+            true => write!(f, "NOT IN"),
+            false => write!(f, "IN"),
+        }
+    }
+}
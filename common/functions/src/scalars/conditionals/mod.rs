@@ -12,8 +12,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod coalesce;
 mod conditional;
 mod r#if;
+mod if_null;
+mod r#in;
+mod multi_if;
+mod null_if;
 
+pub use coalesce::CoalesceFunction;
 pub use conditional::ConditionalFunction;
+pub use if_null::IfNullFunction;
+pub use multi_if::MultiIfFunction;
+pub use null_if::NullIfFunction;
 pub use r#if::IfFunction;
+pub use r#in::InBasicFunction;
+pub use r#in::InFunction;
+pub use r#in::NotInFunction;
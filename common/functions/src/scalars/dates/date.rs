@@ -19,7 +19,15 @@ use super::interval_function::MonthsArithmeticFunction;
 use super::interval_function::SecondsArithmeticFunction;
 use super::now::NowFunction;
 use super::number_function::ToMondayFunction;
+use super::DateDiffFunction;
+use super::DateFormatFunction;
+use super::DateTruncFunction;
+use super::FormatDateTimeFunction;
+use super::FromUnixTimestampFunction;
+use super::ParseDateTimeBestEffortFunction;
+use super::ParseDateTimeBestEffortOrNullFunction;
 use super::RoundFunction;
+use super::StrToDateFunction;
 use super::ToDayOfMonthFunction;
 use super::ToDayOfWeekFunction;
 use super::ToDayOfYearFunction;
@@ -32,9 +40,11 @@ use super::ToStartOfMonthFunction;
 use super::ToStartOfQuarterFunction;
 use super::ToStartOfWeekFunction;
 use super::ToStartOfYearFunction;
+use super::ToUnixTimestampFunction;
 use super::ToYYYYMMDDFunction;
 use super::ToYYYYMMDDhhmmssFunction;
 use super::ToYYYYMMFunction;
+use super::ToYearFunction;
 use super::TodayFunction;
 use super::TomorrowFunction;
 use super::YesterdayFunction;
@@ -77,7 +87,7 @@ impl DateFunction {
         };
 
         FunctionDescription::creator(function_creator)
-            .features(FunctionFeatures::default().deterministic())
+            .features(FunctionFeatures::default().deterministic().monotonicity())
     }
 
     fn seconds_arithmetic_function_creator(factor: i64) -> FunctionDescription {
@@ -100,7 +110,7 @@ impl DateFunction {
         };
 
         FunctionDescription::creator(function_creator)
-            .features(FunctionFeatures::default().deterministic())
+            .features(FunctionFeatures::default().deterministic().monotonicity())
     }
 
     pub fn register(factory: &mut FunctionFactory) {
@@ -116,6 +126,7 @@ impl DateFunction {
         factory.register("toStartOfQuarter", ToStartOfQuarterFunction::desc());
         factory.register("toStartOfWeek", ToStartOfWeekFunction::desc());
         factory.register("toStartOfMonth", ToStartOfMonthFunction::desc());
+        factory.register("toYear", ToYearFunction::desc());
         factory.register("toMonth", ToMonthFunction::desc());
         factory.register("toDayOfYear", ToDayOfYearFunction::desc());
         factory.register("toDayOfMonth", ToDayOfMonthFunction::desc());
@@ -124,6 +135,26 @@ impl DateFunction {
         factory.register("toMinute", ToMinuteFunction::desc());
         factory.register("toSecond", ToSecondFunction::desc());
         factory.register("toMonday", ToMondayFunction::desc());
+        factory.register("toUnixTimestamp", ToUnixTimestampFunction::desc());
+        factory.register("unix_timestamp", ToUnixTimestampFunction::desc());
+        factory.register("fromUnixTimestamp", FromUnixTimestampFunction::desc());
+        factory.register("from_unixtime", FromUnixTimestampFunction::desc());
+
+        // formatters and parsers
+        factory.register("date_format", DateFormatFunction::desc());
+        factory.register("formatDateTime", FormatDateTimeFunction::desc());
+        factory.register("str_to_date", StrToDateFunction::desc());
+        factory.register(
+            "parseDateTimeBestEffort",
+            ParseDateTimeBestEffortFunction::desc(),
+        );
+        factory.register(
+            "parseDateTimeBestEffortOrNull",
+            ParseDateTimeBestEffortOrNullFunction::desc(),
+        );
+
+        factory.register("dateDiff", DateDiffFunction::desc());
+        factory.register("date_trunc", DateTruncFunction::desc());
 
         // rounders
         factory.register("toStartOfSecond", Self::round_function_creator(1));
@@ -140,7 +171,12 @@ impl DateFunction {
 
         //interval functions
         factory.register("addYears", Self::month_arithmetic_function_creator(12));
+        factory.register("addQuarters", Self::month_arithmetic_function_creator(3));
         factory.register("addMonths", Self::month_arithmetic_function_creator(1));
+        factory.register(
+            "addWeeks",
+            Self::seconds_arithmetic_function_creator(7 * 24 * 3600),
+        );
         factory.register(
             "addDays",
             Self::seconds_arithmetic_function_creator(24 * 3600),
@@ -153,10 +189,18 @@ impl DateFunction {
             "subtractYears",
             Self::month_arithmetic_function_creator(-12),
         );
+        factory.register(
+            "subtractQuarters",
+            Self::month_arithmetic_function_creator(-3),
+        );
         factory.register(
             "subtractMonths",
             Self::month_arithmetic_function_creator(-1),
         );
+        factory.register(
+            "subtractWeeks",
+            Self::seconds_arithmetic_function_creator(-(7 * 24 * 3600)),
+        );
         factory.register(
             "subtractDays",
             Self::seconds_arithmetic_function_creator(-(24 * 3600)),
@@ -0,0 +1,136 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::date_utils::constant_string_arg;
+use super::date_utils::series_to_datetimes;
+use super::date_utils::DateUnit;
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+use crate::scalars::Monotonicity;
+
+/// dateDiff('unit', start, end) returns the count of unit boundaries crossed from start to end,
+/// as ClickHouse does. With two arguments, dateDiff(end, start) returns the days from start to end,
+/// as MySQL DATEDIFF does.
+#[derive(Clone)]
+pub struct DateDiffFunction {
+    display_name: String,
+}
+
+impl DateDiffFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(DateDiffFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic().monotonicity())
+    }
+}
+
+impl Function for DateDiffFunction {
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((2, 3))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        for arg in &args[args.len() - 2..] {
+            if !arg.is_date_or_date_time() {
+                return Err(ErrorCode::IllegalDataType(format!(
+                    "Illegal type {:?} of argument of function {}.Should be a date16/data32 or a dateTime32/dateTime64",
+                    arg, self.display_name
+                )));
+            }
+        }
+        Ok(DataType::Int64)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, _input_rows: usize) -> Result<DataColumn> {
+        let (unit, start, end) = match columns.len() {
+            3 => {
+                let unit = constant_string_arg(&columns[0], &self.display_name, "unit")?;
+                (DateUnit::try_create(&unit)?, &columns[1], &columns[2])
+            }
+            _ => (DateUnit::Day, &columns[1], &columns[0]),
+        };
+
+        let starts = series_to_datetimes(
+            &start.column().to_array()?,
+            start.data_type(),
+            &self.display_name,
+        )?;
+        let ends = series_to_datetimes(
+            &end.column().to_array()?,
+            end.data_type(),
+            &self.display_name,
+        )?;
+
+        let array: DFInt64Array = starts
+            .iter()
+            .zip(ends.iter())
+            .map(|(start, end)| match (start, end) {
+                (Some(start), Some(end)) => Some(unit.relative_num(end) - unit.relative_num(start)),
+                _ => None,
+            })
+            .collect();
+        Ok(array.into())
+    }
+
+    fn get_monotonicity(&self, args: &[Monotonicity]) -> Result<Monotonicity> {
+        let (unit, start, end) = match args.len() {
+            3 => (Some(&args[0]), &args[1], &args[2]),
+            _ => (None, &args[1], &args[0]),
+        };
+
+        if unit.map_or(false, |unit| !unit.is_constant) {
+            return Ok(Monotonicity::default());
+        }
+
+        // The difference goes the same way as the end if the start is constant,
+        // and goes the opposite way of the start if the end is constant.
+        if start.is_constant {
+            return Ok(Monotonicity::clone_without_range(end));
+        }
+        if end.is_constant {
+            return Ok(Monotonicity::create(
+                start.is_monotonic,
+                !start.is_positive,
+                false,
+            ));
+        }
+        Ok(Monotonicity::default())
+    }
+}
+
+impl fmt::Display for DateDiffFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}()", self.display_name)
+    }
+}
@@ -0,0 +1,298 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::fmt::Write;
+use std::marker::PhantomData;
+
+use common_datavalues::chrono::DateTime;
+use common_datavalues::chrono::Datelike;
+use common_datavalues::prelude::*;
+use common_datavalues::Tz;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::date_utils::constant_string_arg;
+use super::date_utils::series_to_datetimes;
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+/// What a format specifier, the character following '%', is translated into.
+#[derive(Clone, Copy, Debug)]
+pub enum DateFormatSpecifier {
+    /// The equivalent chrono strftime specifiers.
+    Strftime(&'static str),
+    /// Day of the month with English suffix, such as 1st, 2nd and 3rd.
+    DayOfMonthWithSuffix,
+    /// Quarter of the year, from 1 to 4.
+    Quarter,
+}
+
+pub trait DateFormatDialect {
+    fn specifier(c: char) -> Option<DateFormatSpecifier>;
+}
+
+/// The format specifiers of MySQL `DATE_FORMAT` and `STR_TO_DATE`.
+#[derive(Clone)]
+pub struct MySQLDateFormat;
+
+impl DateFormatDialect for MySQLDateFormat {
+    fn specifier(c: char) -> Option<DateFormatSpecifier> {
+        let strftime = match c {
+            'a' => "%a",
+            'b' => "%b",
+            'c' => "%-m",
+            'D' => return Some(DateFormatSpecifier::DayOfMonthWithSuffix),
+            'd' => "%d",
+            'e' => "%-d",
+            'f' => "%6f",
+            'H' => "%H",
+            'h' | 'I' => "%I",
+            'i' => "%M",
+            'j' => "%j",
+            'k' => "%-H",
+            'l' => "%-I",
+            'M' => "%B",
+            'm' => "%m",
+            'p' => "%p",
+            'r' => "%I:%M:%S %p",
+            'S' | 's' => "%S",
+            'T' => "%H:%M:%S",
+            'U' => "%U",
+            'u' => "%W",
+            'v' => "%V",
+            'W' => "%A",
+            'w' => "%w",
+            'x' => "%G",
+            'Y' => "%Y",
+            'y' => "%y",
+            '%' => "%%",
+            _ => return None,
+        };
+        Some(DateFormatSpecifier::Strftime(strftime))
+    }
+}
+
+/// The format specifiers of ClickHouse `formatDateTime`.
+#[derive(Clone)]
+pub struct ClickHouseDateFormat;
+
+impl DateFormatDialect for ClickHouseDateFormat {
+    fn specifier(c: char) -> Option<DateFormatSpecifier> {
+        let strftime = match c {
+            'C' => "%C",
+            'd' => "%d",
+            'D' => "%m/%d/%y",
+            'e' => "%e",
+            'F' => "%Y-%m-%d",
+            'G' => "%G",
+            'g' => "%g",
+            'H' => "%H",
+            'I' => "%I",
+            'j' => "%j",
+            'm' => "%m",
+            'M' => "%M",
+            'n' => "%n",
+            'p' => "%p",
+            'Q' => return Some(DateFormatSpecifier::Quarter),
+            'R' => "%H:%M",
+            'S' => "%S",
+            't' => "%t",
+            'T' => "%H:%M:%S",
+            'u' => "%u",
+            'V' => "%V",
+            'w' => "%w",
+            'y' => "%y",
+            'Y' => "%Y",
+            '%' => "%%",
+            _ => return None,
+        };
+        Some(DateFormatSpecifier::Strftime(strftime))
+    }
+}
+
+enum DateFormatItem {
+    Strftime(String),
+    DayOfMonthWithSuffix,
+    Quarter,
+}
+
+/// A format string of some dialect, translated into chrono strftime items.
+pub struct DateFormatter {
+    items: Vec<DateFormatItem>,
+}
+
+impl DateFormatter {
+    pub fn try_create<T: DateFormatDialect>(format: &str) -> Result<DateFormatter> {
+        let mut items = vec![];
+        let mut strftime = String::new();
+        let mut chars = format.chars();
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                strftime.push(c);
+                continue;
+            }
+
+            // A trailing '%' is kept as it is.
+            let c = match chars.next() {
+                None => {
+                    strftime.push_str("%%");
+                    break;
+                }
+                Some(c) => c,
+            };
+
+            let item = match T::specifier(c) {
+                Some(DateFormatSpecifier::Strftime(s)) => {
+                    strftime.push_str(s);
+                    continue;
+                }
+                Some(DateFormatSpecifier::DayOfMonthWithSuffix) => {
+                    DateFormatItem::DayOfMonthWithSuffix
+                }
+                Some(DateFormatSpecifier::Quarter) => DateFormatItem::Quarter,
+                None => {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Unsupported format specifier '%{}' in format '{}'",
+                        c, format
+                    )))
+                }
+            };
+
+            if !strftime.is_empty() {
+                items.push(DateFormatItem::Strftime(std::mem::take(&mut strftime)));
+            }
+            items.push(item);
+        }
+
+        if !strftime.is_empty() {
+            items.push(DateFormatItem::Strftime(strftime));
+        }
+        Ok(DateFormatter { items })
+    }
+
+    pub fn format(&self, datetime: &DateTime<Tz>) -> String {
+        let mut result = String::new();
+        for item in &self.items {
+            // Writing to a String never fails.
+            let _ = match item {
+                DateFormatItem::Strftime(s) => write!(result, "{}", datetime.format(s)),
+                DateFormatItem::DayOfMonthWithSuffix => {
+                    let day = datetime.day();
+                    let suffix = match (day % 10, day / 10) {
+                        (1, d) if d != 1 => "st",
+                        (2, d) if d != 1 => "nd",
+                        (3, d) if d != 1 => "rd",
+                        _ => "th",
+                    };
+                    write!(result, "{}{}", day, suffix)
+                }
+                DateFormatItem::Quarter => write!(result, "{}", datetime.month0() / 3 + 1),
+            };
+        }
+        result
+    }
+
+    /// The chrono strftime format to parse strings with, all the specifiers must be parsable.
+    pub fn parse_format(&self) -> Result<String> {
+        let mut result = String::new();
+        for item in &self.items {
+            match item {
+                DateFormatItem::Strftime(s) => result.push_str(s),
+                DateFormatItem::DayOfMonthWithSuffix | DateFormatItem::Quarter => {
+                    return Err(ErrorCode::BadArguments(
+                        "Format specifiers '%D' and '%Q' are not supported for parsing",
+                    ))
+                }
+            }
+        }
+        Ok(result)
+    }
+}
+
+/// Formats a date or datetime with a constant format string, the format specifiers
+/// follow the dialect `T`.
+#[derive(Clone)]
+pub struct DateTimeFormatFunction<T> {
+    display_name: String,
+    t: PhantomData<T>,
+}
+
+impl<T> DateTimeFormatFunction<T>
+where T: DateFormatDialect + Clone + Sync + Send + 'static
+{
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(DateTimeFormatFunction::<T> {
+            display_name: display_name.to_string(),
+            t: PhantomData,
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl<T> Function for DateTimeFormatFunction<T>
+where T: DateFormatDialect + Clone + Sync + Send + 'static
+{
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn num_arguments(&self) -> usize {
+        2
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        if !args[0].is_date_or_date_time() {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Illegal type {:?} of argument of function {}.Should be a date16/data32 or a dateTime32/dateTime64",
+                args[0], self.display_name
+            )));
+        }
+        Ok(DataType::String)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let format = constant_string_arg(&columns[1], &self.display_name, "format")?;
+        let formatter = DateFormatter::try_create::<T>(&format)?;
+
+        let series = columns[0].column().to_minimal_array()?;
+        let datetimes = series_to_datetimes(&series, columns[0].data_type(), &self.display_name)?;
+        let array = DFStringArray::from_iter(
+            datetimes
+                .iter()
+                .map(|v| v.as_ref().map(|dt| formatter.format(dt))),
+        );
+
+        let column: DataColumn = array.into_series().into();
+        Ok(column.resize_constant(input_rows))
+    }
+}
+
+impl<T> fmt::Display for DateTimeFormatFunction<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}()", self.display_name)
+    }
+}
+
+pub type DateFormatFunction = DateTimeFormatFunction<MySQLDateFormat>;
+pub type FormatDateTimeFunction = DateTimeFormatFunction<ClickHouseDateFormat>;
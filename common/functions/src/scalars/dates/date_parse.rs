@@ -0,0 +1,243 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::chrono::DateTime;
+use common_datavalues::chrono::NaiveDate;
+use common_datavalues::chrono::NaiveDateTime;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::date_format::DateFormatter;
+use super::date_format::MySQLDateFormat;
+use super::date_utils::constant_string_arg;
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+// The layouts tried in order by parseDateTimeBestEffort, after RFC 3339 and RFC 2822.
+const DATETIME_FORMATS: &[&str] = &[
+    "%Y-%m-%d %H:%M:%S%.f",
+    "%Y-%m-%dT%H:%M:%S%.f",
+    "%Y/%m/%d %H:%M:%S%.f",
+    "%Y-%m-%d %H:%M",
+    "%Y-%m-%dT%H:%M",
+    "%Y/%m/%d %H:%M",
+    "%d/%m/%Y %H:%M:%S",
+    "%d.%m.%Y %H:%M:%S",
+    "%d %b %Y %H:%M:%S",
+    "%b %d %Y %H:%M:%S",
+    "%Y%m%d%H%M%S",
+];
+
+const DATE_FORMATS: &[&str] = &[
+    "%Y-%m-%d",
+    "%Y/%m/%d",
+    "%Y%m%d",
+    "%d/%m/%Y",
+    "%d.%m.%Y",
+    "%d-%m-%Y",
+    "%d %b %Y",
+    "%d %B %Y",
+    "%b %d %Y",
+    "%B %d %Y",
+    "%b %d, %Y",
+    "%B %d, %Y",
+];
+
+/// Seconds since 1970-01-01 00:00:00 UTC, if it is in the range of DateTime32.
+#[inline]
+fn to_datetime32(datetime: &NaiveDateTime) -> Option<u32> {
+    u32::try_from(datetime.timestamp()).ok()
+}
+
+fn parse_with_format(value: &str, format: &str) -> Option<u32> {
+    let datetime = NaiveDateTime::parse_from_str(value, format)
+        .or_else(|_| NaiveDate::parse_from_str(value, format).map(|d| d.and_hms(0, 0, 0)))
+        .ok()?;
+    to_datetime32(&datetime)
+}
+
+fn parse_best_effort(value: &str) -> Option<u32> {
+    let value = value.trim();
+
+    // A number of at most 10 digits is a unix timestamp, except the 'YYYYMMDD' dates.
+    if !value.is_empty()
+        && value.len() <= 10
+        && value.len() != 8
+        && value.bytes().all(|b| b.is_ascii_digit())
+    {
+        return value.parse::<u32>().ok();
+    }
+
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(value) {
+        return to_datetime32(&datetime.naive_utc());
+    }
+    if let Ok(datetime) = DateTime::parse_from_rfc2822(value) {
+        return to_datetime32(&datetime.naive_utc());
+    }
+
+    let datetime = DATETIME_FORMATS
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(value, format).ok())
+        .or_else(|| {
+            DATE_FORMATS
+                .iter()
+                .find_map(|format| NaiveDate::parse_from_str(value, format).ok())
+                .map(|d| d.and_hms(0, 0, 0))
+        })?;
+    to_datetime32(&datetime)
+}
+
+/// str_to_date(str, format) parses the string with the MySQL format specifiers,
+/// returns NULL if the string doesn't match the format.
+#[derive(Clone)]
+pub struct StrToDateFunction {
+    display_name: String,
+}
+
+impl StrToDateFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(StrToDateFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for StrToDateFunction {
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn num_arguments(&self) -> usize {
+        2
+    }
+
+    fn return_type(&self, _args: &[DataType]) -> Result<DataType> {
+        Ok(DataType::DateTime32(None))
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let format = constant_string_arg(&columns[1], &self.display_name, "format")?;
+        let format = DateFormatter::try_create::<MySQLDateFormat>(&format)?.parse_format()?;
+
+        let series = columns[0]
+            .column()
+            .to_minimal_array()?
+            .cast_with_type(&DataType::String)?;
+        let array: DFUInt32Array = series
+            .string()?
+            .into_iter()
+            .map(|v| {
+                v.and_then(|s| std::str::from_utf8(s).ok())
+                    .and_then(|s| parse_with_format(s, &format))
+            })
+            .collect();
+
+        let column: DataColumn = array.into_series().into();
+        Ok(column.resize_constant(input_rows))
+    }
+}
+
+impl fmt::Display for StrToDateFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}()", self.display_name)
+    }
+}
+
+/// parseDateTimeBestEffort(str) parses the string in the common datetime layouts,
+/// such as ISO 8601, RFC 2822 and unix timestamps.
+/// It throws on unparsable strings, or returns NULL for them if `OR_NULL` is true.
+#[derive(Clone)]
+pub struct ParseDateTimeBestEffortBasicFunction<const OR_NULL: bool> {
+    display_name: String,
+}
+
+impl<const OR_NULL: bool> ParseDateTimeBestEffortBasicFunction<OR_NULL> {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ParseDateTimeBestEffortBasicFunction::<OR_NULL> {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl<const OR_NULL: bool> Function for ParseDateTimeBestEffortBasicFunction<OR_NULL> {
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn num_arguments(&self) -> usize {
+        1
+    }
+
+    fn return_type(&self, _args: &[DataType]) -> Result<DataType> {
+        Ok(DataType::DateTime32(None))
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(OR_NULL)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let series = columns[0]
+            .column()
+            .to_minimal_array()?
+            .cast_with_type(&DataType::String)?;
+        let array = series
+            .string()?
+            .into_iter()
+            .map(|v| match v {
+                None => Ok(None),
+                Some(s) => {
+                    let s = String::from_utf8_lossy(s);
+                    match parse_best_effort(&s) {
+                        None if !OR_NULL => Err(ErrorCode::BadArguments(format!(
+                            "Cannot parse '{}' as DateTime in function {}",
+                            s, self.display_name
+                        ))),
+                        datetime => Ok(datetime),
+                    }
+                }
+            })
+            .collect::<Result<DFUInt32Array>>()?;
+
+        let column: DataColumn = array.into_series().into();
+        Ok(column.resize_constant(input_rows))
+    }
+}
+
+impl<const OR_NULL: bool> fmt::Display for ParseDateTimeBestEffortBasicFunction<OR_NULL> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}()", self.display_name)
+    }
+}
+
+pub type ParseDateTimeBestEffortFunction = ParseDateTimeBestEffortBasicFunction<false>;
+pub type ParseDateTimeBestEffortOrNullFunction = ParseDateTimeBestEffortBasicFunction<true>;
@@ -0,0 +1,153 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::chrono::Duration;
+use common_datavalues::chrono::NaiveDate;
+use common_datavalues::chrono::NaiveDateTime;
+use common_datavalues::chrono::Offset;
+use common_datavalues::chrono::TimeZone;
+use common_datavalues::prelude::*;
+use common_datavalues::Tz;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::date_utils::constant_string_arg;
+use super::date_utils::DateUnit;
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+use crate::scalars::Monotonicity;
+
+/// date_trunc('unit', date) truncates the date or datetime to the start of the unit,
+/// in the local time of its timezone. The result has the same type as the date,
+/// except that DateTime64 is truncated to a DateTime32.
+#[derive(Clone)]
+pub struct DateTruncFunction {
+    display_name: String,
+}
+
+impl DateTruncFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(DateTruncFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic().monotonicity())
+    }
+
+    #[inline]
+    fn truncate_days(unit: DateUnit, days: i64) -> i64 {
+        let epoch = NaiveDate::from_ymd(1970, 1, 1);
+        let date = (epoch + Duration::days(days)).and_hms(0, 0, 0);
+        unit.truncate(&date)
+            .date()
+            .signed_duration_since(epoch)
+            .num_days()
+    }
+
+    #[inline]
+    fn truncate_seconds(unit: DateUnit, seconds: i64, tz: &Tz) -> i64 {
+        let offset = tz
+            .offset_from_utc_datetime(&NaiveDateTime::from_timestamp(seconds, 0))
+            .fix()
+            .local_minus_utc() as i64;
+        let local = NaiveDateTime::from_timestamp(seconds + offset, 0);
+        unit.truncate(&local).timestamp() - offset
+    }
+}
+
+impl Function for DateTruncFunction {
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn num_arguments(&self) -> usize {
+        2
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        match &args[1] {
+            DataType::Date16 | DataType::Date32 | DataType::DateTime32(_) => Ok(args[1].clone()),
+            DataType::DateTime64(_, tz) => Ok(DataType::DateTime32(tz.clone())),
+            other => Err(ErrorCode::IllegalDataType(format!(
+                "Illegal type {:?} of argument of function {}.Should be a date16/data32 or a dateTime32/dateTime64",
+                other, self.display_name
+            ))),
+        }
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let unit = constant_string_arg(&columns[0], &self.display_name, "unit")?;
+        let unit = DateUnit::try_create(&unit)?;
+
+        let series = columns[1].column().to_minimal_array()?;
+        let array = match columns[1].data_type() {
+            DataType::Date16 => series
+                .u16()?
+                .apply(|v| Self::truncate_days(unit, v as i64) as u16)
+                .into_series(),
+            DataType::Date32 => series
+                .i32()?
+                .apply(|v| Self::truncate_days(unit, v as i64) as i32)
+                .into_series(),
+            DataType::DateTime32(tz) => {
+                let tz = timezone_or_utc(tz)?;
+                series
+                    .u32()?
+                    .apply(|v| Self::truncate_seconds(unit, v as i64, &tz) as u32)
+                    .into_series()
+            }
+            DataType::DateTime64(precision, tz) => {
+                let tz = timezone_or_utc(tz)?;
+                let scale = 10_i64.pow(*precision);
+                let array: DFUInt32Array = series.i64()?.apply_cast_numeric(|v| {
+                    Self::truncate_seconds(unit, v.div_euclid(scale), &tz) as u32
+                });
+                array.into_series()
+            }
+            other => {
+                return Err(ErrorCode::IllegalDataType(format!(
+                    "Illegal type {:?} of argument of function {}.Should be a date16/data32 or a dateTime32/dateTime64",
+                    other, self.display_name
+                )))
+            }
+        };
+
+        let column: DataColumn = array.into();
+        Ok(column.resize_constant(input_rows))
+    }
+
+    fn get_monotonicity(&self, args: &[Monotonicity]) -> Result<Monotonicity> {
+        // Truncating to a constant unit never changes the order of the dates.
+        if args[0].is_constant {
+            return Ok(Monotonicity::clone_without_range(&args[1]));
+        }
+        Ok(Monotonicity::default())
+    }
+}
+
+impl fmt::Display for DateTruncFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}()", self.display_name)
+    }
+}
@@ -0,0 +1,155 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::chrono::DateTime;
+use common_datavalues::chrono::Datelike;
+use common_datavalues::chrono::Duration;
+use common_datavalues::chrono::NaiveDate;
+use common_datavalues::chrono::NaiveDateTime;
+use common_datavalues::chrono::TimeZone;
+use common_datavalues::chrono::Timelike;
+use common_datavalues::prelude::*;
+use common_datavalues::Tz;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+/// The unit of functions like `dateDiff` and `date_trunc`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DateUnit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Quarter,
+    Year,
+}
+
+impl DateUnit {
+    pub fn try_create(unit: &str) -> Result<DateUnit> {
+        match unit.to_lowercase().as_str() {
+            "second" | "seconds" => Ok(DateUnit::Second),
+            "minute" | "minutes" => Ok(DateUnit::Minute),
+            "hour" | "hours" => Ok(DateUnit::Hour),
+            "day" | "days" => Ok(DateUnit::Day),
+            "week" | "weeks" => Ok(DateUnit::Week),
+            "month" | "months" => Ok(DateUnit::Month),
+            "quarter" | "quarters" => Ok(DateUnit::Quarter),
+            "year" | "years" => Ok(DateUnit::Year),
+            _ => Err(ErrorCode::BadArguments(format!(
+                "Unsupported date unit '{}', expect one of second, minute, hour, day, week, month, quarter and year",
+                unit
+            ))),
+        }
+    }
+
+    /// Truncates the datetime to the start of the unit it belongs to, weeks start on Monday.
+    pub fn truncate(&self, datetime: &NaiveDateTime) -> NaiveDateTime {
+        let date = datetime.date();
+        match self {
+            DateUnit::Second => date.and_hms(datetime.hour(), datetime.minute(), datetime.second()),
+            DateUnit::Minute => date.and_hms(datetime.hour(), datetime.minute(), 0),
+            DateUnit::Hour => date.and_hms(datetime.hour(), 0, 0),
+            DateUnit::Day => date.and_hms(0, 0, 0),
+            DateUnit::Week => {
+                let days = date.weekday().num_days_from_monday() as i64;
+                (date - Duration::days(days)).and_hms(0, 0, 0)
+            }
+            DateUnit::Month => NaiveDate::from_ymd(date.year(), date.month(), 1).and_hms(0, 0, 0),
+            DateUnit::Quarter => {
+                NaiveDate::from_ymd(date.year(), date.month0() / 3 * 3 + 1, 1).and_hms(0, 0, 0)
+            }
+            DateUnit::Year => NaiveDate::from_ymd(date.year(), 1, 1).and_hms(0, 0, 0),
+        }
+    }
+
+    /// The number of the unit the datetime belongs to, counting from 1970-01-01.
+    /// The difference of two numbers is the count of unit boundaries between the datetimes.
+    /// Units from day on are counted in the local time of the timezone.
+    pub fn relative_num(&self, datetime: &DateTime<Tz>) -> i64 {
+        let seconds = datetime.timestamp();
+        let local = datetime.naive_local();
+        let days = local.timestamp().div_euclid(24 * 3600);
+        match self {
+            DateUnit::Second => seconds,
+            DateUnit::Minute => seconds.div_euclid(60),
+            DateUnit::Hour => seconds.div_euclid(3600),
+            DateUnit::Day => days,
+            // 1970-01-01 is a Thursday, shift it so that weeks start on Monday.
+            DateUnit::Week => (days + 3).div_euclid(7),
+            DateUnit::Month => local.year() as i64 * 12 + local.month0() as i64,
+            DateUnit::Quarter => local.year() as i64 * 4 + (local.month0() / 3) as i64,
+            DateUnit::Year => local.year() as i64,
+        }
+    }
+}
+
+/// The value of a constant string argument, such as the unit or the format of a function.
+pub fn constant_string_arg(
+    column: &DataColumnWithField,
+    display_name: &str,
+    arg_name: &str,
+) -> Result<String> {
+    match column.column() {
+        DataColumn::Constant(DataValue::String(Some(value)), _) => {
+            Ok(String::from_utf8_lossy(value).into_owned())
+        }
+        _ => Err(ErrorCode::BadArguments(format!(
+            "The {} of function {} must be a constant string",
+            arg_name, display_name
+        ))),
+    }
+}
+
+/// Converts the values of a date or datetime series to datetimes in their timezone.
+/// Dates are the midnight of the day in UTC.
+pub fn series_to_datetimes(
+    series: &Series,
+    data_type: &DataType,
+    display_name: &str,
+) -> Result<Vec<Option<DateTime<Tz>>>> {
+    match data_type {
+        DataType::Date16 => Ok(series
+            .u16()?
+            .into_iter()
+            .map(|v| v.map(|d| Tz::UTC.timestamp(*d as i64 * 24 * 3600, 0_u32)))
+            .collect()),
+        DataType::Date32 => Ok(series
+            .i32()?
+            .into_iter()
+            .map(|v| v.map(|d| Tz::UTC.timestamp(*d as i64 * 24 * 3600, 0_u32)))
+            .collect()),
+        DataType::DateTime32(tz) => {
+            let tz = timezone_or_utc(tz)?;
+            Ok(series
+                .u32()?
+                .into_iter()
+                .map(|v| v.map(|t| tz.timestamp(*t as i64, 0_u32)))
+                .collect())
+        }
+        DataType::DateTime64(precision, tz) => {
+            let tz = timezone_or_utc(tz)?;
+            Ok(series
+                .i64()?
+                .into_iter()
+                .map(|v| v.map(|t| t.to_date_time64(*precision, &tz)))
+                .collect())
+        }
+        other => Err(ErrorCode::IllegalDataType(format!(
+            "Illegal type {:?} of argument of function {}.Should be a date16/data32 or a dateTime32/dateTime64",
+            other, display_name
+        ))),
+    }
+}
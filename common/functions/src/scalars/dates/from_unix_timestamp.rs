@@ -0,0 +1,112 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::chrono::TimeZone;
+use common_datavalues::prelude::*;
+use common_datavalues::Tz;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::date_format::DateFormatter;
+use super::date_format::MySQLDateFormat;
+use super::date_utils::constant_string_arg;
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+use crate::scalars::Monotonicity;
+
+/// fromUnixTimestamp(seconds) converts the seconds since 1970-01-01 00:00:00 UTC to a DateTime32.
+/// fromUnixTimestamp(seconds, format) formats it with the MySQL format specifiers instead,
+/// as MySQL FROM_UNIXTIME does.
+#[derive(Clone)]
+pub struct FromUnixTimestampFunction {
+    display_name: String,
+}
+
+impl FromUnixTimestampFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(FromUnixTimestampFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic().monotonicity())
+    }
+}
+
+impl Function for FromUnixTimestampFunction {
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((1, 2))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        if !args[0].is_integer() {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Illegal type {:?} of argument of function {}.Should be an integer",
+                args[0], self.display_name
+            )));
+        }
+
+        match args.len() {
+            1 => Ok(DataType::DateTime32(None)),
+            _ => Ok(DataType::String),
+        }
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let seconds = columns[0].column().cast_with_type(&DataType::UInt32)?;
+        if columns.len() == 1 {
+            return Ok(seconds);
+        }
+
+        let format = constant_string_arg(&columns[1], &self.display_name, "format")?;
+        let formatter = DateFormatter::try_create::<MySQLDateFormat>(&format)?;
+
+        let series = seconds.to_minimal_array()?;
+        let array: DFStringArray = series
+            .u32()?
+            .into_iter()
+            .map(|v| v.map(|t| formatter.format(&Tz::UTC.timestamp(*t as i64, 0_u32))))
+            .collect();
+
+        let column: DataColumn = array.into_series().into();
+        Ok(column.resize_constant(input_rows))
+    }
+
+    fn get_monotonicity(&self, args: &[Monotonicity]) -> Result<Monotonicity> {
+        // The formatted strings are not ordered as the timestamps.
+        if args.len() == 1 {
+            return Ok(Monotonicity::clone_without_range(&args[0]));
+        }
+        Ok(Monotonicity::default())
+    }
+}
+
+impl fmt::Display for FromUnixTimestampFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}()", self.display_name)
+    }
+}
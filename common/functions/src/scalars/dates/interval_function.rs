@@ -27,6 +27,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 
 use crate::scalars::Function;
+use crate::scalars::Monotonicity;
 
 pub trait IntegerTypedArithmetic {
     fn get_func(
@@ -122,6 +123,35 @@ where T: IntegerTypedArithmetic + Clone + Sync + Send + 'static
             self.factor,
         )
     }
+
+    fn get_monotonicity(&self, args: &[Monotonicity]) -> Result<Monotonicity> {
+        // The date is the first argument, unless the type of the constant argument tells.
+        let (variable, constant_is_date) = if args[1].is_constant {
+            (&args[0], Self::is_date_argument(&args[1]).unwrap_or(false))
+        } else if args[0].is_constant {
+            (&args[1], Self::is_date_argument(&args[0]).unwrap_or(true))
+        } else {
+            return Ok(Monotonicity::default());
+        };
+
+        // Adding or subtracting a constant interval keeps the order of the dates, while
+        // subtracting a variable interval from a constant date reverses the order.
+        let mut mono = Monotonicity::clone_without_range(variable);
+        if constant_is_date && matches!(self.op, DataValueArithmeticOperator::Minus) {
+            mono.is_positive = !mono.is_positive;
+        }
+        Ok(mono)
+    }
+}
+
+impl<T> IntegerTypedIntervalFunction<T>
+where T: IntegerTypedArithmetic + Clone + Sync + Send + 'static
+{
+    fn is_date_argument(mono: &Monotonicity) -> Option<bool> {
+        mono.left
+            .as_ref()
+            .map(|left| left.data_type().is_date_or_date_time())
+    }
 }
 
 impl<T> fmt::Display for IntegerTypedIntervalFunction<T>
@@ -193,12 +223,29 @@ impl IntervalFunctionFactory {
         let mut date_datetime_opt = None;
         columns.iter().for_each(|column| match column.data_type() {
             DataType::Interval(_) => interval_opt = Some(column),
-            DataType::Date16 | DataType::Date32 | DataType::DateTime32(_) => {
-                date_datetime_opt = Some(column)
-            }
+            DataType::Date16
+            | DataType::Date32
+            | DataType::DateTime32(_)
+            | DataType::DateTime64(_, _) => date_datetime_opt = Some(column),
             _ => {}
         });
 
+        // DateTime64 stores the ticks of sub-second, which can't be added to or subtracted from
+        // the integers and other dates directly.
+        let has_datetime64 = columns
+            .iter()
+            .any(|c| matches!(c.data_type(), DataType::DateTime64(_, _)));
+        if has_datetime64 && interval_opt.is_none() {
+            let (lhs, rhs) = (columns[0].data_type(), columns[1].data_type());
+            if lhs.is_integer() || rhs.is_integer() {
+                return Some(Self::integer_plus_minus_datetime64);
+            }
+            if lhs.is_date_or_date_time() && rhs.is_date_or_date_time() {
+                return Some(Self::datetime64_minus_datetime);
+            }
+            return None;
+        }
+
         if interval_opt.is_none() || date_datetime_opt.is_none() {
             return None;
         }
@@ -238,6 +285,8 @@ impl IntervalFunctionFactory {
     //   4. interval_month_plus_minus_date16 --------- Interval(YearMonth) +/-  Date16
     //   5. interval_month_plus_minus_date32 --------- Interval(YearMonth) +/-  Date32
     //   6. interval_month_plus_minus_datetime32 ----- Interval(YearMonth) +/-  DateTime32
+    //   7. interval_daytime_plus_minus_datetime64 --- Interval(DayTime)   +/-  DateTime64
+    //   8. interval_month_plus_minus_datetime64 ----- Interval(YearMonth) +/-  DateTime64
 
     fn get_interval_arithmetic_func(
         interval: &DataType,
@@ -248,12 +297,14 @@ impl IntervalFunctionFactory {
                 DataType::Date16 => Self::interval_month_plus_minus_date16,
                 DataType::Date32 => Self::interval_month_plus_minus_date32,
                 DataType::DateTime32(_) => Self::interval_month_plus_minus_datetime32,
+                DataType::DateTime64(_, _) => Self::interval_month_plus_minus_datetime64,
                 _ => unreachable!(),
             },
             DataType::Interval(IntervalUnit::DayTime) => match date_datetime {
                 DataType::Date16 => Self::interval_daytime_plus_minus_date16,
                 DataType::Date32 => Self::interval_daytime_plus_minus_date32,
                 DataType::DateTime32(_) => Self::interval_daytime_plus_minus_datetime32,
+                DataType::DateTime64(_, _) => Self::interval_daytime_plus_minus_datetime64,
                 _ => unreachable!(),
             },
             _ => unreachable!(),
@@ -333,6 +384,29 @@ impl IntervalFunctionFactory {
         Ok(res.into())
     }
 
+    fn interval_daytime_plus_minus_datetime64(
+        op: &DataValueArithmeticOperator,
+        a: &DataColumnWithField,
+        b: &DataColumnWithField,
+    ) -> Result<DataColumn> {
+        let (interval, datetime) = Self::validate_input(op, a, b)?;
+        let precision = Self::datetime64_precision(datetime.data_type());
+        let res = Self::interval_operation(
+            interval.column().to_array()?.i64()?,
+            datetime.column().to_array()?.i64()?,
+            |ms: &i64, ticks: &i64| {
+                let delta = rescale_datetime64(*ms, 3, precision);
+                let r = match op {
+                    DataValueArithmeticOperator::Plus => *ticks + delta,
+                    DataValueArithmeticOperator::Minus => *ticks - delta,
+                    _ => unreachable!(),
+                };
+                Ok(r)
+            },
+        )?;
+        Ok(res.into())
+    }
+
     fn interval_month_plus_minus_date16(
         op: &DataValueArithmeticOperator,
         a: &DataColumnWithField,
@@ -360,9 +434,113 @@ impl IntervalFunctionFactory {
         Self::month_i64_plus_minus_datetime32(op, interval, datetime, 1)
     }
 
+    fn interval_month_plus_minus_datetime64(
+        op: &DataValueArithmeticOperator,
+        a: &DataColumnWithField,
+        b: &DataColumnWithField,
+    ) -> Result<DataColumn> {
+        let (interval, datetime) = Self::validate_input(op, a, b)?;
+        Self::month_i64_plus_minus_datetime64(op, interval, datetime, 1)
+    }
+
     //  End of interval typed arithmetic functions.
     //////////////////////////////////////////////////////////////////////////////////
 
+    //////////////////////////////////////////////////////////////////////////////////
+    //  Starting from here is DateTime64 arithmetic functions, including:
+    //   1. integer_plus_minus_datetime64 ------- Integer    +/-  DateTime64, the integer is seconds
+    //   2. datetime64_minus_datetime ----------- DateTime64  -   Date|DateTime, or the reverse
+
+    fn integer_plus_minus_datetime64(
+        op: &DataValueArithmeticOperator,
+        a: &DataColumnWithField,
+        b: &DataColumnWithField,
+    ) -> Result<DataColumn> {
+        if matches!(op, DataValueArithmeticOperator::Minus) && a.data_type().is_integer() {
+            return Err(ErrorCode::IllegalDataType(
+                "Illegal operation integer minus DateTime64.",
+            ));
+        }
+
+        let (integer, datetime) = Self::validate_input(op, a, b)?;
+        let seconds = integer
+            .column()
+            .to_array()?
+            .cast_with_type(&DataType::Int64)?;
+        let seconds = DataColumnWithField::new(
+            seconds.into(),
+            DataField::new(integer.field().name(), DataType::Int64, false),
+        );
+        Self::time_secs_i64_plus_minus_datetime64(op, &seconds, datetime, 1)
+    }
+
+    fn datetime64_minus_datetime(
+        op: &DataValueArithmeticOperator,
+        a: &DataColumnWithField,
+        b: &DataColumnWithField,
+    ) -> Result<DataColumn> {
+        if !matches!(op, DataValueArithmeticOperator::Minus) {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Illegal operation {:?} between DateTime64 and date time.",
+                op
+            )));
+        }
+
+        // Both sides are rescaled to the larger precision.
+        let precision = std::cmp::max(
+            Self::datetime64_precision(a.data_type()),
+            Self::datetime64_precision(b.data_type()),
+        );
+        let lhs = Self::to_datetime64_ticks(a, precision)?;
+        let rhs = Self::to_datetime64_ticks(b, precision)?;
+        let res = Self::interval_operation(&lhs, &rhs, |l: &i64, r: &i64| Ok(*l - *r))?;
+        Ok(res.into())
+    }
+
+    // A private helper function to get the precision of DateTime64, the precision of
+    // other date|datetime types is 0.
+    fn datetime64_precision(data_type: &DataType) -> u32 {
+        match data_type {
+            DataType::DateTime64(precision, _) => *precision,
+            _ => 0,
+        }
+    }
+
+    // A private helper function to convert the date|datetime column to the ticks of DateTime64
+    fn to_datetime64_ticks(
+        column: &DataColumnWithField,
+        precision: u32,
+    ) -> Result<DFPrimitiveArray<i64>> {
+        let array = column.column().to_array()?;
+        let seconds_per_day = 24 * 3600_i64;
+        let base = 10_i64.pow(precision);
+        let ticks = match column.data_type() {
+            DataType::Date16 => array
+                .u16()?
+                .apply_cast_numeric(|v| v as i64 * seconds_per_day * base),
+            DataType::Date32 => array
+                .i32()?
+                .apply_cast_numeric(|v| v as i64 * seconds_per_day * base),
+            DataType::DateTime32(_) => array.u32()?.apply_cast_numeric(|v| v as i64 * base),
+            DataType::DateTime64(from, _) => {
+                let from = *from;
+                array
+                    .i64()?
+                    .apply_cast_numeric(|v| rescale_datetime64(v, from, precision))
+            }
+            other => {
+                return Err(ErrorCode::IllegalDataType(format!(
+                    "Illegal type {:?} to subtract with DateTime64.",
+                    other
+                )))
+            }
+        };
+        Ok(ticks)
+    }
+
+    //  End of DateTime64 arithmetic functions.
+    //////////////////////////////////////////////////////////////////////////////////
+
     //////////////////////////////////////////////////////////////////////////////////
     //  Starting from here is integer month arithmetic functions. It handles plus/minus
     //  operations between integer column and date|datetime column. The integer column
@@ -406,6 +584,17 @@ impl IntervalFunctionFactory {
                 DataType::Int64 => Self::month_i64_plus_minus_datetime32,
                 _ => unreachable!(),
             },
+            DataType::DateTime64(_, _) => match integer {
+                DataType::UInt8 => Self::month_u8_plus_minus_datetime64,
+                DataType::UInt16 => Self::month_u16_plus_minus_datetime64,
+                DataType::UInt32 => Self::month_u32_plus_minus_datetime64,
+                DataType::UInt64 => Self::month_u64_plus_minus_datetime64,
+                DataType::Int8 => Self::month_i8_plus_minus_datetime64,
+                DataType::Int16 => Self::month_i16_plus_minus_datetime64,
+                DataType::Int32 => Self::month_i32_plus_minus_datetime64,
+                DataType::Int64 => Self::month_i64_plus_minus_datetime64,
+                _ => unreachable!(),
+            },
             _ => unreachable!(),
         }
     }
@@ -439,6 +628,15 @@ impl IntervalFunctionFactory {
     crate::define_month_plus_minus_datetime32!(month_u16_plus_minus_datetime32, u16);
     crate::define_month_plus_minus_datetime32!(month_u8_plus_minus_datetime32, u8);
 
+    crate::define_month_plus_minus_datetime64!(month_i64_plus_minus_datetime64, i64);
+    crate::define_month_plus_minus_datetime64!(month_i32_plus_minus_datetime64, i32);
+    crate::define_month_plus_minus_datetime64!(month_i16_plus_minus_datetime64, i16);
+    crate::define_month_plus_minus_datetime64!(month_i8_plus_minus_datetime64, i8);
+    crate::define_month_plus_minus_datetime64!(month_u64_plus_minus_datetime64, u64);
+    crate::define_month_plus_minus_datetime64!(month_u32_plus_minus_datetime64, u32);
+    crate::define_month_plus_minus_datetime64!(month_u16_plus_minus_datetime64, u16);
+    crate::define_month_plus_minus_datetime64!(month_u8_plus_minus_datetime64, u8);
+
     //  End of months integer arithmetic functions
     //////////////////////////////////////////////////////////////////////////////////
 
@@ -485,6 +683,17 @@ impl IntervalFunctionFactory {
                 DataType::Int64 => Self::time_secs_i64_plus_minus_datetime32,
                 _ => unreachable!(),
             },
+            DataType::DateTime64(_, _) => match integer {
+                DataType::UInt8 => Self::time_secs_u8_plus_minus_datetime64,
+                DataType::UInt16 => Self::time_secs_u16_plus_minus_datetime64,
+                DataType::UInt32 => Self::time_secs_u32_plus_minus_datetime64,
+                DataType::UInt64 => Self::time_secs_u64_plus_minus_datetime64,
+                DataType::Int8 => Self::time_secs_i8_plus_minus_datetime64,
+                DataType::Int16 => Self::time_secs_i16_plus_minus_datetime64,
+                DataType::Int32 => Self::time_secs_i32_plus_minus_datetime64,
+                DataType::Int64 => Self::time_secs_i64_plus_minus_datetime64,
+                _ => unreachable!(),
+            },
             _ => unreachable!(),
         }
     }
@@ -518,11 +727,20 @@ impl IntervalFunctionFactory {
     crate::define_time_secs_plus_minus_datetime32!(time_secs_u16_plus_minus_datetime32, u16);
     crate::define_time_secs_plus_minus_datetime32!(time_secs_u8_plus_minus_datetime32, u8);
 
+    crate::define_time_secs_plus_minus_datetime64!(time_secs_i64_plus_minus_datetime64, i64);
+    crate::define_time_secs_plus_minus_datetime64!(time_secs_i32_plus_minus_datetime64, i32);
+    crate::define_time_secs_plus_minus_datetime64!(time_secs_i16_plus_minus_datetime64, i16);
+    crate::define_time_secs_plus_minus_datetime64!(time_secs_i8_plus_minus_datetime64, i8);
+    crate::define_time_secs_plus_minus_datetime64!(time_secs_u64_plus_minus_datetime64, u64);
+    crate::define_time_secs_plus_minus_datetime64!(time_secs_u32_plus_minus_datetime64, u32);
+    crate::define_time_secs_plus_minus_datetime64!(time_secs_u16_plus_minus_datetime64, u16);
+    crate::define_time_secs_plus_minus_datetime64!(time_secs_u8_plus_minus_datetime64, u8);
+
     // End of seconds integer arithmetic functions
     //////////////////////////////////////////////////////////////////////////////////
 
     // A private helper function for validate operator, returns a tuple of
-    // (interval|integer, date16|date32|datetime32)
+    // (interval|integer, date16|date32|datetime32|datetime64)
     fn validate_input<'a>(
         op: &DataValueArithmeticOperator,
         col0: &'a DataColumnWithField,
@@ -654,6 +872,41 @@ macro_rules! define_month_plus_minus_datetime32 {
     };
 }
 
+#[macro_export]
+macro_rules! define_month_plus_minus_datetime64 {
+    ($fn_name:ident, $type:ident) => {
+        fn $fn_name(
+            op: &DataValueArithmeticOperator,
+            a: &DataColumnWithField,
+            b: &DataColumnWithField,
+            mul: i64,
+        ) -> Result<DataColumn> {
+            let (interval_months, datetime64) = Self::validate_input(op, a, b)?;
+            let precision = Self::datetime64_precision(datetime64.data_type());
+            let base = 10_i64.pow(precision);
+
+            let res = Self::interval_operation(
+                interval_months.column().to_array()?.$type()?,
+                datetime64.column().to_array()?.i64()?,
+                |months: &$type, ticks: &i64| {
+                    let dt = Self::seconds_to_datetime(ticks.div_euclid(base))?;
+                    let new_dt = match op {
+                        DataValueArithmeticOperator::Plus => {
+                            Self::datetime_plus_signed_months(&dt, (*months as i64) * mul)?
+                        }
+                        DataValueArithmeticOperator::Minus => {
+                            Self::datetime_plus_signed_months(&dt, -(*months as i64) * mul)?
+                        }
+                        _ => unreachable!(),
+                    };
+                    Ok(new_dt.timestamp() * base + ticks.rem_euclid(base))
+                },
+            )?;
+            Ok(res.into())
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! define_month_plus_minus_date {
     ($fn_name:ident, $month_type:ident, $date_type:ident) => {
@@ -714,6 +967,33 @@ macro_rules! define_time_secs_plus_minus_datetime32 {
     };
 }
 
+#[macro_export]
+macro_rules! define_time_secs_plus_minus_datetime64 {
+    ($fn_name:ident, $type:ident) => {
+        fn $fn_name(
+            op: &DataValueArithmeticOperator,
+            interval: &DataColumnWithField,
+            datetime: &DataColumnWithField,
+            mul: i64,
+        ) -> Result<DataColumn> {
+            let base = 10_i64.pow(Self::datetime64_precision(datetime.data_type()));
+            let res = Self::interval_operation(
+                interval.column().to_array()?.$type()?,
+                datetime.column().to_array()?.i64()?,
+                |secs: &$type, ticks: &i64| {
+                    let r = match op {
+                        DataValueArithmeticOperator::Plus => *ticks + *secs as i64 * mul * base,
+                        DataValueArithmeticOperator::Minus => *ticks - *secs as i64 * mul * base,
+                        _ => unreachable!(),
+                    };
+                    Ok(r)
+                },
+            )?;
+            Ok(res.into())
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! define_time_secs_plus_minus_date {
     ($fn_name:ident, $seconds_type:ident, $date_type:ident) => {
@@ -13,6 +13,12 @@
 // limitations under the License.
 
 mod date;
+mod date_diff;
+mod date_format;
+mod date_parse;
+mod date_trunc;
+mod date_utils;
+mod from_unix_timestamp;
 mod interval_function;
 mod now;
 mod number_function;
@@ -21,6 +27,14 @@ mod simple_date;
 mod week_date;
 
 pub use date::DateFunction;
+pub use date_diff::DateDiffFunction;
+pub use date_format::DateFormatFunction;
+pub use date_format::FormatDateTimeFunction;
+pub use date_parse::ParseDateTimeBestEffortFunction;
+pub use date_parse::ParseDateTimeBestEffortOrNullFunction;
+pub use date_parse::StrToDateFunction;
+pub use date_trunc::DateTruncFunction;
+pub use from_unix_timestamp::FromUnixTimestampFunction;
 pub use interval_function::IntervalArithmeticFunction;
 pub use interval_function::IntervalFunctionFactory;
 pub use interval_function::MonthsArithmeticFunction;
@@ -37,9 +51,11 @@ pub use number_function::ToStartOfISOYearFunction;
 pub use number_function::ToStartOfMonthFunction;
 pub use number_function::ToStartOfQuarterFunction;
 pub use number_function::ToStartOfYearFunction;
+pub use number_function::ToUnixTimestampFunction;
 pub use number_function::ToYYYYMMDDFunction;
 pub use number_function::ToYYYYMMDDhhmmssFunction;
 pub use number_function::ToYYYYMMFunction;
+pub use number_function::ToYearFunction;
 pub use round_function::RoundFunction;
 pub use simple_date::TodayFunction;
 pub use simple_date::TomorrowFunction;
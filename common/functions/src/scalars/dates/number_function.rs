@@ -17,10 +17,11 @@ use std::marker::PhantomData;
 
 use common_datavalues::chrono::DateTime;
 use common_datavalues::chrono::Datelike;
+use common_datavalues::chrono::NaiveDate;
 use common_datavalues::chrono::TimeZone;
 use common_datavalues::chrono::Timelike;
-use common_datavalues::chrono::Utc;
 use common_datavalues::prelude::*;
+use common_datavalues::Tz;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
@@ -42,8 +43,8 @@ pub trait NumberResultFunction<R> {
     const IS_DETERMINISTIC: bool;
 
     fn return_type() -> Result<DataType>;
-    fn to_number(_value: DateTime<Utc>) -> R;
-    fn to_constant_value(_value: DateTime<Utc>) -> DataValue;
+    fn to_number(_value: DateTime<Tz>) -> R;
+    fn to_constant_value(_value: DateTime<Tz>) -> DataValue;
     // Used to check the monotonicity of the function.
     // For example, ToDayOfYear is monotonous only when the time range is the same year.
     // So we can use ToStartOfYearFunction to check whether the time range is in the same year.
@@ -64,11 +65,11 @@ impl NumberResultFunction<u32> for ToYYYYMM {
     fn return_type() -> Result<DataType> {
         Ok(DataType::UInt32)
     }
-    fn to_number(value: DateTime<Utc>) -> u32 {
+    fn to_number(value: DateTime<Tz>) -> u32 {
         value.year() as u32 * 100 + value.month()
     }
 
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
+    fn to_constant_value(value: DateTime<Tz>) -> DataValue {
         DataValue::UInt32(Some(Self::to_number(value)))
     }
 }
@@ -82,11 +83,11 @@ impl NumberResultFunction<u32> for ToYYYYMMDD {
     fn return_type() -> Result<DataType> {
         Ok(DataType::UInt32)
     }
-    fn to_number(value: DateTime<Utc>) -> u32 {
+    fn to_number(value: DateTime<Tz>) -> u32 {
         value.year() as u32 * 10000 + value.month() * 100 + value.day()
     }
 
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
+    fn to_constant_value(value: DateTime<Tz>) -> DataValue {
         DataValue::UInt32(Some(Self::to_number(value)))
     }
 }
@@ -101,7 +102,7 @@ impl NumberResultFunction<u64> for ToYYYYMMDDhhmmss {
         Ok(DataType::UInt64)
     }
 
-    fn to_number(value: DateTime<Utc>) -> u64 {
+    fn to_number(value: DateTime<Tz>) -> u64 {
         value.year() as u64 * 10000000000
             + value.month() as u64 * 100000000
             + value.day() as u64 * 1000000
@@ -110,7 +111,7 @@ impl NumberResultFunction<u64> for ToYYYYMMDDhhmmss {
             + value.second() as u64
     }
 
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
+    fn to_constant_value(value: DateTime<Tz>) -> DataValue {
         DataValue::UInt64(Some(Self::to_number(value)))
     }
 }
@@ -124,12 +125,12 @@ impl NumberResultFunction<u16> for ToStartOfYear {
     fn return_type() -> Result<DataType> {
         Ok(DataType::Date16)
     }
-    fn to_number(value: DateTime<Utc>) -> u16 {
-        let end: DateTime<Utc> = Utc.ymd(value.year(), 1, 1).and_hms(0, 0, 0);
+    fn to_number(value: DateTime<Tz>) -> u16 {
+        let end: DateTime<Tz> = value.timezone().ymd(value.year(), 1, 1).and_hms(0, 0, 0);
         get_day(end) as u16
     }
 
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
+    fn to_constant_value(value: DateTime<Tz>) -> DataValue {
         DataValue::UInt16(Some(Self::to_number(value) as u16))
     }
 }
@@ -143,17 +144,17 @@ impl NumberResultFunction<u16> for ToStartOfISOYear {
     fn return_type() -> Result<DataType> {
         Ok(DataType::Date16)
     }
-    fn to_number(value: DateTime<Utc>) -> u16 {
+    fn to_number(value: DateTime<Tz>) -> u16 {
         let week_day = value.weekday().num_days_from_monday();
         let iso_week = value.iso_week();
         let iso_week_num = iso_week.week();
         let sub_days = (iso_week_num - 1) * 7 + week_day;
         let result = value.timestamp_millis() - sub_days as i64 * 24 * 3600 * 1000;
-        let end: DateTime<Utc> = Utc.timestamp_millis(result);
+        let end: DateTime<Tz> = value.timezone().timestamp_millis(result);
         get_day(end) as u16
     }
 
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
+    fn to_constant_value(value: DateTime<Tz>) -> DataValue {
         DataValue::UInt16(Some(Self::to_number(value) as u16))
     }
 }
@@ -167,13 +168,16 @@ impl NumberResultFunction<u16> for ToStartOfQuarter {
     fn return_type() -> Result<DataType> {
         Ok(DataType::Date16)
     }
-    fn to_number(value: DateTime<Utc>) -> u16 {
+    fn to_number(value: DateTime<Tz>) -> u16 {
         let new_month = value.month0() / 3 * 3 + 1;
-        let date = Utc.ymd(value.year(), new_month, 1).and_hms(0, 0, 0);
+        let date = value
+            .timezone()
+            .ymd(value.year(), new_month, 1)
+            .and_hms(0, 0, 0);
         get_day(date) as u16
     }
 
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
+    fn to_constant_value(value: DateTime<Tz>) -> DataValue {
         DataValue::UInt16(Some(Self::to_number(value) as u16))
     }
 }
@@ -187,16 +191,37 @@ impl NumberResultFunction<u16> for ToStartOfMonth {
     fn return_type() -> Result<DataType> {
         Ok(DataType::Date16)
     }
-    fn to_number(value: DateTime<Utc>) -> u16 {
-        let date = Utc.ymd(value.year(), value.month(), 1).and_hms(0, 0, 0);
+    fn to_number(value: DateTime<Tz>) -> u16 {
+        let date = value
+            .timezone()
+            .ymd(value.year(), value.month(), 1)
+            .and_hms(0, 0, 0);
         get_day(date) as u16
     }
 
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
+    fn to_constant_value(value: DateTime<Tz>) -> DataValue {
         DataValue::UInt16(Some(Self::to_number(value) as u16))
     }
 }
 
+#[derive(Clone)]
+pub struct ToYear;
+
+impl NumberResultFunction<u16> for ToYear {
+    const IS_DETERMINISTIC: bool = true;
+
+    fn return_type() -> Result<DataType> {
+        Ok(DataType::UInt16)
+    }
+    fn to_number(value: DateTime<Tz>) -> u16 {
+        value.year() as u16
+    }
+
+    fn to_constant_value(value: DateTime<Tz>) -> DataValue {
+        DataValue::UInt16(Some(Self::to_number(value)))
+    }
+}
+
 #[derive(Clone)]
 pub struct ToMonth;
 
@@ -206,11 +231,11 @@ impl NumberResultFunction<u8> for ToMonth {
     fn return_type() -> Result<DataType> {
         Ok(DataType::UInt8)
     }
-    fn to_number(value: DateTime<Utc>) -> u8 {
+    fn to_number(value: DateTime<Tz>) -> u8 {
         value.month() as u8
     }
 
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
+    fn to_constant_value(value: DateTime<Tz>) -> DataValue {
         DataValue::UInt8(Some(Self::to_number(value)))
     }
 
@@ -230,11 +255,11 @@ impl NumberResultFunction<u16> for ToDayOfYear {
     fn return_type() -> Result<DataType> {
         Ok(DataType::UInt16)
     }
-    fn to_number(value: DateTime<Utc>) -> u16 {
+    fn to_number(value: DateTime<Tz>) -> u16 {
         value.ordinal() as u16
     }
 
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
+    fn to_constant_value(value: DateTime<Tz>) -> DataValue {
         DataValue::UInt16(Some(Self::to_number(value)))
     }
 
@@ -254,11 +279,11 @@ impl NumberResultFunction<u8> for ToDayOfMonth {
     fn return_type() -> Result<DataType> {
         Ok(DataType::UInt8)
     }
-    fn to_number(value: DateTime<Utc>) -> u8 {
+    fn to_number(value: DateTime<Tz>) -> u8 {
         value.day() as u8
     }
 
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
+    fn to_constant_value(value: DateTime<Tz>) -> DataValue {
         DataValue::UInt8(Some(Self::to_number(value)))
     }
 
@@ -278,11 +303,11 @@ impl NumberResultFunction<u8> for ToDayOfWeek {
     fn return_type() -> Result<DataType> {
         Ok(DataType::UInt8)
     }
-    fn to_number(value: DateTime<Utc>) -> u8 {
+    fn to_number(value: DateTime<Tz>) -> u8 {
         value.weekday().number_from_monday() as u8
     }
 
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
+    fn to_constant_value(value: DateTime<Tz>) -> DataValue {
         DataValue::UInt8(Some(Self::to_number(value)))
     }
 
@@ -301,11 +326,11 @@ impl NumberResultFunction<u8> for ToHour {
     fn return_type() -> Result<DataType> {
         Ok(DataType::UInt8)
     }
-    fn to_number(value: DateTime<Utc>) -> u8 {
+    fn to_number(value: DateTime<Tz>) -> u8 {
         value.hour() as u8
     }
 
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
+    fn to_constant_value(value: DateTime<Tz>) -> DataValue {
         DataValue::UInt8(Some(Self::to_number(value)))
     }
 
@@ -324,11 +349,11 @@ impl NumberResultFunction<u8> for ToMinute {
     fn return_type() -> Result<DataType> {
         Ok(DataType::UInt8)
     }
-    fn to_number(value: DateTime<Utc>) -> u8 {
+    fn to_number(value: DateTime<Tz>) -> u8 {
         value.minute() as u8
     }
 
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
+    fn to_constant_value(value: DateTime<Tz>) -> DataValue {
         DataValue::UInt8(Some(Self::to_number(value)))
     }
 
@@ -347,11 +372,11 @@ impl NumberResultFunction<u8> for ToSecond {
     fn return_type() -> Result<DataType> {
         Ok(DataType::UInt8)
     }
-    fn to_number(value: DateTime<Utc>) -> u8 {
+    fn to_number(value: DateTime<Tz>) -> u8 {
         value.second() as u8
     }
 
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
+    fn to_constant_value(value: DateTime<Tz>) -> DataValue {
         DataValue::UInt8(Some(Self::to_number(value)))
     }
 
@@ -370,16 +395,34 @@ impl NumberResultFunction<u16> for ToMonday {
     fn return_type() -> Result<DataType> {
         Ok(DataType::Date16)
     }
-    fn to_number(value: DateTime<Utc>) -> u16 {
+    fn to_number(value: DateTime<Tz>) -> u16 {
         let weekday = value.weekday();
         (get_day(value) - weekday.num_days_from_monday()) as u16
     }
 
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
+    fn to_constant_value(value: DateTime<Tz>) -> DataValue {
         DataValue::UInt16(Some(Self::to_number(value)))
     }
 }
 
+#[derive(Clone)]
+pub struct ToUnixTimestamp;
+
+impl NumberResultFunction<u32> for ToUnixTimestamp {
+    const IS_DETERMINISTIC: bool = true;
+
+    fn return_type() -> Result<DataType> {
+        Ok(DataType::UInt32)
+    }
+    fn to_number(value: DateTime<Tz>) -> u32 {
+        value.timestamp() as u32
+    }
+
+    fn to_constant_value(value: DateTime<Tz>) -> DataValue {
+        DataValue::UInt32(Some(Self::to_number(value)))
+    }
+}
+
 impl<T, R> NumberFunction<T, R>
 where
     T: NumberResultFunction<R> + Clone + Sync + Send + 'static,
@@ -432,7 +475,7 @@ where
         let number_array: DataColumn = match data_type {
             DataType::Date16 => {
                 if let DataColumn::Constant(v, _) = columns[0].column() {
-                    let date_time = Utc.timestamp(v.as_u64()? as i64 * 24 * 3600, 0_u32);
+                    let date_time = Tz::UTC.timestamp(v.as_u64()? as i64 * 24 * 3600, 0_u32);
                     let constant_result = T::to_constant_value(date_time);
                     Ok(DataColumn::Constant(constant_result, input_rows))
                 } else {
@@ -440,7 +483,7 @@ where
                         .to_array()?
                         .u16()?
                         .apply_cast_numeric(|v| {
-                            let date_time = Utc.timestamp(v as i64 * 24 * 3600, 0_u32);
+                            let date_time = Tz::UTC.timestamp(v as i64 * 24 * 3600, 0_u32);
                             T::to_number(date_time)
                         }
                         );
@@ -449,7 +492,7 @@ where
             }
             DataType::Date32 => {
                 if let DataColumn::Constant(v, _) = columns[0].column() {
-                    let date_time = Utc.timestamp(v.as_i64()? * 24 * 3600, 0_u32);
+                    let date_time = Tz::UTC.timestamp(v.as_i64()? * 24 * 3600, 0_u32);
                     let constant_result = T::to_constant_value(date_time);
                     Ok(DataColumn::Constant(constant_result, input_rows))
                 } else {
@@ -457,16 +500,17 @@ where
                         .to_array()?
                         .i32()?
                         .apply_cast_numeric(|v| {
-                            let date_time = Utc.timestamp(v as i64 * 24 * 3600, 0_u32);
+                            let date_time = Tz::UTC.timestamp(v as i64 * 24 * 3600, 0_u32);
                             T::to_number(date_time)
                         }
                         );
                     Ok(result.into())
                 }
             }
-            DataType::DateTime32(_) => {
+            DataType::DateTime32(tz) => {
+                let tz = timezone_or_utc(tz)?;
                 if let DataColumn::Constant(v, _) = columns[0].column() {
-                    let date_time = Utc.timestamp(v.as_u64()? as i64, 0_u32);
+                    let date_time = tz.timestamp(v.as_u64()? as i64, 0_u32);
                     let constant_result = T::to_constant_value(date_time);
                     Ok(DataColumn::Constant(constant_result, input_rows))
                 } else {
@@ -474,7 +518,25 @@ where
                         .to_array()?
                         .u32()?
                         .apply_cast_numeric(|v| {
-                            let date_time = Utc.timestamp(v as i64, 0_u32);
+                            let date_time = tz.timestamp(v as i64, 0_u32);
+                            T::to_number(date_time)
+                        }
+                        );
+                    Ok(result.into())
+                }
+            }
+            DataType::DateTime64(precision, tz) => {
+                let tz = timezone_or_utc(tz)?;
+                if let DataColumn::Constant(v, _) = columns[0].column() {
+                    let date_time = v.as_i64()?.to_date_time64(*precision, &tz);
+                    let constant_result = T::to_constant_value(date_time);
+                    Ok(DataColumn::Constant(constant_result, input_rows))
+                } else {
+                    let result = columns[0].column()
+                        .to_array()?
+                        .i64()?
+                        .apply_cast_numeric(|v| {
+                            let date_time = v.to_date_time64(*precision, &tz);
                             T::to_number(date_time)
                         }
                         );
@@ -482,7 +544,7 @@ where
                 }
             }
             other => Result::Err(ErrorCode::IllegalDataType(format!(
-                "Illegal type {:?} of argument of function {}.Should be a date16/data32 or a dateTime32",
+                "Illegal type {:?} of argument of function {}.Should be a date16/data32 or a dateTime32/dateTime64",
                 other,
                 self.name()))),
         }?;
@@ -519,9 +581,10 @@ impl<T, R> fmt::Display for NumberFunction<T, R> {
     }
 }
 
-fn get_day(date: DateTime<Utc>) -> u32 {
-    let start: DateTime<Utc> = Utc.ymd(1970, 1, 1).and_hms(0, 0, 0);
-    let duration = date.signed_duration_since(start);
+// Days since 1970-01-01 of the date in the timezone of the datetime.
+fn get_day(date: DateTime<Tz>) -> u32 {
+    let start = NaiveDate::from_ymd(1970, 1, 1);
+    let duration = date.naive_local().date().signed_duration_since(start);
     duration.num_days() as u32
 }
 
@@ -534,6 +597,7 @@ pub type ToStartOfYearFunction = NumberFunction<ToStartOfYear, u16>;
 pub type ToStartOfQuarterFunction = NumberFunction<ToStartOfQuarter, u16>;
 pub type ToStartOfMonthFunction = NumberFunction<ToStartOfMonth, u16>;
 
+pub type ToYearFunction = NumberFunction<ToYear, u16>;
 pub type ToMonthFunction = NumberFunction<ToMonth, u8>;
 pub type ToDayOfYearFunction = NumberFunction<ToDayOfYear, u16>;
 pub type ToDayOfMonthFunction = NumberFunction<ToDayOfMonth, u8>;
@@ -544,3 +608,5 @@ pub type ToMinuteFunction = NumberFunction<ToMinute, u8>;
 pub type ToSecondFunction = NumberFunction<ToSecond, u8>;
 
 pub type ToMondayFunction = NumberFunction<ToMonday, u16>;
+
+pub type ToUnixTimestampFunction = NumberFunction<ToUnixTimestamp, u32>;
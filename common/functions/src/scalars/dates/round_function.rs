@@ -14,7 +14,11 @@
 
 use std::fmt;
 
+use common_datavalues::chrono::NaiveDateTime;
+use common_datavalues::chrono::Offset;
+use common_datavalues::chrono::TimeZone;
 use common_datavalues::prelude::*;
+use common_datavalues::Tz;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
@@ -37,12 +41,16 @@ impl RoundFunction {
         Ok(Box::new(s))
     }
 
-    // TODO: (sundy-li)
-    // Consider about the timezones/offsets
-    // Currently: assuming timezone offset is a multiple of round.
+    // Round in the local time of the timezone, so that the result is aligned
+    // even if the timezone offset is not a multiple of round, such as '+05:30'.
     #[inline]
-    fn execute(&self, time: u32) -> u32 {
-        time / self.round * self.round
+    fn execute(&self, time: i64, tz: &Tz) -> u32 {
+        let offset = tz
+            .offset_from_utc_datetime(&NaiveDateTime::from_timestamp(time, 0))
+            .fix()
+            .local_minus_utc() as i64;
+        let round = self.round as i64;
+        ((time + offset).div_euclid(round) * round - offset) as u32
     }
 }
 
@@ -53,7 +61,9 @@ impl Function for RoundFunction {
 
     fn return_type(&self, args: &[DataType]) -> Result<DataType> {
         match args[0] {
-            DataType::DateTime32(_) => Ok(DataType::DateTime32(None)),
+            DataType::DateTime32(tz) | DataType::DateTime64(_, tz) => {
+                Ok(DataType::DateTime32(tz.clone()))
+            }
             _ => Err(ErrorCode::BadDataValueType(format!(
                 "Function {} must have a DateTime type as argument, but got {}",
                 self.display_name, args[0],
@@ -66,19 +76,35 @@ impl Function for RoundFunction {
     }
 
     fn eval(&self, columns: &DataColumnsWithField, _input_rows: usize) -> Result<DataColumn> {
+        // DateTime64 is rounded from its seconds, the sub-second part is dropped.
+        let (scale, tz) = match columns[0].data_type() {
+            DataType::DateTime64(precision, tz) => (10_i64.pow(*precision), timezone_or_utc(tz)?),
+            DataType::DateTime32(tz) => (1, timezone_or_utc(tz)?),
+            other => {
+                return Err(ErrorCode::BadDataValueType(format!(
+                    "Function {} must have a DateTime type as argument, but got {}",
+                    self.display_name, other,
+                )))
+            }
+        };
+
         match columns[0].column() {
             DataColumn::Array(array) => {
-                let array = array.u32()?;
-                let arr = array.apply(|x| self.execute(x));
+                let arr: DFUInt32Array = match columns[0].data_type() {
+                    DataType::DateTime64(_, _) => array
+                        .i64()?
+                        .apply_cast_numeric(|x| self.execute(x.div_euclid(scale), &tz)),
+                    _ => array.u32()?.apply(|x| self.execute(x as i64, &tz)),
+                };
                 Ok(DataColumn::Array(arr.into_series()))
             }
             DataColumn::Constant(v, rows) => {
                 if v.is_null() {
                     return Ok(DataColumn::Constant(DataValue::UInt32(None), *rows));
                 }
-                let value = v.as_u64()?;
+                let value = v.as_i64()?;
                 Ok(DataColumn::Constant(
-                    DataValue::UInt32(Some(self.execute(value as u32))),
+                    DataValue::UInt32(Some(self.execute(value.div_euclid(scale), &tz))),
                     *rows,
                 ))
             }
@@ -20,6 +20,8 @@ use common_datavalues::chrono::Date;
 use common_datavalues::chrono::NaiveDate;
 use common_datavalues::chrono::Utc;
 use common_datavalues::prelude::*;
+use common_datavalues::Tz;
+use common_exception::ErrorCode;
 use common_exception::Result;
 
 use crate::scalars::function_factory::FunctionDescription;
@@ -35,7 +37,8 @@ pub struct SimpleFunction<T> {
 pub trait NoArgDateFunction {
     const IS_DETERMINISTIC: bool;
 
-    fn execute() -> u16;
+    /// Returns the days since 1970-01-01 of the current date in the timezone.
+    fn execute(tz: &Tz) -> u16;
 }
 
 #[derive(Clone)]
@@ -44,11 +47,11 @@ pub struct Today;
 impl NoArgDateFunction for Today {
     const IS_DETERMINISTIC: bool = false;
 
-    fn execute() -> u16 {
-        let utc: Date<Utc> = Utc::now().date();
+    fn execute(tz: &Tz) -> u16 {
+        let today: Date<Tz> = Utc::now().with_timezone(tz).date();
         let epoch = NaiveDate::from_ymd(1970, 1, 1);
 
-        let duration = utc.naive_utc().sub(epoch);
+        let duration = today.naive_local().sub(epoch);
         duration.num_days() as u16
     }
 }
@@ -59,11 +62,11 @@ pub struct Yesterday;
 impl NoArgDateFunction for Yesterday {
     const IS_DETERMINISTIC: bool = false;
 
-    fn execute() -> u16 {
-        let utc: Date<Utc> = Utc::now().date();
+    fn execute(tz: &Tz) -> u16 {
+        let today: Date<Tz> = Utc::now().with_timezone(tz).date();
         let epoch = NaiveDate::from_ymd(1970, 1, 1);
 
-        let duration = utc.naive_utc().sub(epoch);
+        let duration = today.naive_local().sub(epoch);
         duration.num_days() as u16 - 1
     }
 }
@@ -74,11 +77,11 @@ pub struct Tomorrow;
 impl NoArgDateFunction for Tomorrow {
     const IS_DETERMINISTIC: bool = false;
 
-    fn execute() -> u16 {
-        let utc: Date<Utc> = Utc::now().date();
+    fn execute(tz: &Tz) -> u16 {
+        let today: Date<Tz> = Utc::now().with_timezone(tz).date();
         let epoch = NaiveDate::from_ymd(1970, 1, 1);
 
-        let duration = utc.naive_utc().sub(epoch);
+        let duration = today.naive_local().sub(epoch);
         duration.num_days() as u16 + 1
     }
 }
@@ -111,16 +114,34 @@ where T: NoArgDateFunction + Clone + Sync + Send + 'static
         self.display_name.as_str()
     }
 
-    fn return_type(&self, _args: &[DataType]) -> Result<DataType> {
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        if !args.is_empty() && args[0] != DataType::String {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Expected string timezone as the argument of function {}, but got {}",
+                self.display_name, args[0]
+            )));
+        }
         Ok(DataType::Date16)
     }
 
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((0, 1))
+    }
+
     fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
         Ok(false)
     }
 
-    fn eval(&self, _columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
-        let value = T::execute();
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let tz = match columns.len() {
+            0 => Tz::UTC,
+            _ => {
+                let tz = columns[0].column().try_get(0)?.as_string()?;
+                parse_timezone(&String::from_utf8_lossy(&tz))?
+            }
+        };
+
+        let value = T::execute(&tz);
         Ok(DataColumn::Constant(
             DataValue::UInt16(Some(value)),
             input_rows,
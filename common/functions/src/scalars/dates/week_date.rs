@@ -19,9 +19,10 @@ use std::ops::Sub;
 use common_datavalues::chrono::DateTime;
 use common_datavalues::chrono::Datelike;
 use common_datavalues::chrono::Duration;
+use common_datavalues::chrono::NaiveDate;
 use common_datavalues::chrono::TimeZone;
-use common_datavalues::chrono::Utc;
 use common_datavalues::prelude::*;
+use common_datavalues::Tz;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
@@ -41,8 +42,8 @@ pub trait WeekResultFunction<R> {
     const IS_DETERMINISTIC: bool;
 
     fn return_type() -> Result<DataType>;
-    fn to_number(_value: DateTime<Utc>, mode: Option<u64>) -> R;
-    fn to_constant_value(_value: DateTime<Utc>, mode: Option<u64>) -> DataValue;
+    fn to_number(_value: DateTime<Tz>, mode: Option<u64>) -> R;
+    fn to_constant_value(_value: DateTime<Tz>, mode: Option<u64>) -> DataValue;
     fn factor_function() -> Result<Box<dyn Function>> {
         Err(ErrorCode::UnknownException(
             "Always monotonous, has no factor function",
@@ -59,7 +60,7 @@ impl WeekResultFunction<u32> for ToStartOfWeek {
     fn return_type() -> Result<DataType> {
         Ok(DataType::Date16)
     }
-    fn to_number(value: DateTime<Utc>, mode: Option<u64>) -> u32 {
+    fn to_number(value: DateTime<Tz>, mode: Option<u64>) -> u32 {
         let week_mode = mode.unwrap_or(0);
         let mut weekday = value.weekday().number_from_sunday();
         if week_mode & 1 == 1 {
@@ -71,7 +72,7 @@ impl WeekResultFunction<u32> for ToStartOfWeek {
         get_day(result)
     }
 
-    fn to_constant_value(value: DateTime<Utc>, mode: Option<u64>) -> DataValue {
+    fn to_constant_value(value: DateTime<Tz>, mode: Option<u64>) -> DataValue {
         DataValue::UInt16(Some(Self::to_number(value, mode) as u16))
     }
 }
@@ -139,7 +140,7 @@ where
         let number_array: DataColumn = match data_type {
             DataType::Date16 => {
                 if let DataColumn::Constant(v, _) = columns[0].column() {
-                    let date_time = Utc.timestamp(v.as_u64()? as i64 * 24 * 3600, 0_u32);
+                    let date_time = Tz::UTC.timestamp(v.as_u64()? as i64 * 24 * 3600, 0_u32);
                     let constant_result = T::to_constant_value(date_time, mode);
                     Ok(DataColumn::Constant(constant_result, input_rows))
                 } else {
@@ -147,7 +148,7 @@ where
                         .to_array()?
                         .u16()?
                         .apply_cast_numeric(|v| {
-                            let date_time = Utc.timestamp(v as i64 * 24 * 3600, 0_u32);
+                            let date_time = Tz::UTC.timestamp(v as i64 * 24 * 3600, 0_u32);
                             T::to_number(date_time, mode)
                         }
                         );
@@ -156,7 +157,7 @@ where
             },
             DataType::Date32 => {
                 if let DataColumn::Constant(v, _) = columns[0].column() {
-                    let date_time = Utc.timestamp(v.as_i64()?  * 24 * 3600, 0_u32);
+                    let date_time = Tz::UTC.timestamp(v.as_i64()?  * 24 * 3600, 0_u32);
                     let constant_result = T::to_constant_value(date_time, mode);
                     Ok(DataColumn::Constant(constant_result, input_rows))
                 } else {
@@ -164,16 +165,17 @@ where
                         .to_array()?
                         .i32()?
                         .apply_cast_numeric(|v| {
-                            let date_time = Utc.timestamp(v as i64 * 24 * 3600, 0_u32);
+                            let date_time = Tz::UTC.timestamp(v as i64 * 24 * 3600, 0_u32);
                             T::to_number(date_time, mode)
                         }
                         );
                     Ok(result.into())
                 }
             },
-            DataType::DateTime32(_) => {
+            DataType::DateTime32(tz) => {
+                let tz = timezone_or_utc(tz)?;
                 if let DataColumn::Constant(v, _) = columns[0].column() {
-                    let date_time = Utc.timestamp(v.as_i64()?, 0_u32);
+                    let date_time = tz.timestamp(v.as_i64()?, 0_u32);
                     let constant_result = T::to_constant_value(date_time, mode);
                     Ok(DataColumn::Constant(constant_result, input_rows))
                 } else {
@@ -181,7 +183,25 @@ where
                         .to_array()?
                         .u32()?
                         .apply_cast_numeric(|v| {
-                            let date_time = Utc.timestamp(v as i64, 0_u32);
+                            let date_time = tz.timestamp(v as i64, 0_u32);
+                            T::to_number(date_time, mode)
+                        }
+                        );
+                    Ok(result.into())
+                }
+            },
+            DataType::DateTime64(precision, tz) => {
+                let tz = timezone_or_utc(tz)?;
+                if let DataColumn::Constant(v, _) = columns[0].column() {
+                    let date_time = v.as_i64()?.to_date_time64(*precision, &tz);
+                    let constant_result = T::to_constant_value(date_time, mode);
+                    Ok(DataColumn::Constant(constant_result, input_rows))
+                } else {
+                    let result = columns[0].column()
+                        .to_array()?
+                        .i64()?
+                        .apply_cast_numeric(|v| {
+                            let date_time = v.to_date_time64(*precision, &tz);
                             T::to_number(date_time, mode)
                         }
                         );
@@ -189,7 +209,7 @@ where
                 }
             },
             other => Result::Err(ErrorCode::IllegalDataType(format!(
-                "Illegal type {:?} of argument of function {}.Should be a date16/data32 or a dateTime32",
+                "Illegal type {:?} of argument of function {}.Should be a date16/data32 or a dateTime32/dateTime64",
                 other,
                 self.name()))),
         }?;
@@ -225,9 +245,9 @@ impl<T, R> fmt::Display for WeekFunction<T, R> {
     }
 }
 
-fn get_day(date: DateTime<Utc>) -> u32 {
-    let start: DateTime<Utc> = Utc.ymd(1970, 1, 1).and_hms(0, 0, 0);
-    let duration = date.signed_duration_since(start);
+fn get_day(date: DateTime<Tz>) -> u32 {
+    let start = NaiveDate::from_ymd(1970, 1, 1);
+    let duration = date.naive_local().date().signed_duration_since(start);
     duration.num_days() as u32
 }
 
@@ -22,15 +22,22 @@ use common_datavalues::chrono::NaiveDateTime;
 use common_datavalues::chrono::TimeZone;
 use common_datavalues::chrono::Utc;
 use common_datavalues::columns::DataColumn;
+use common_datavalues::datetime64_to_ticks;
 use common_datavalues::prelude::ArrayApply;
 use common_datavalues::prelude::DFInt32Array;
+use common_datavalues::prelude::DFInt64Array;
 use common_datavalues::prelude::DFStringArray;
 use common_datavalues::prelude::DFUInt16Array;
 use common_datavalues::prelude::DFUInt32Array;
 use common_datavalues::prelude::DataColumnsWithField;
+use common_datavalues::rescale_datetime64;
 use common_datavalues::series::IntoSeries;
+use common_datavalues::timezone_or_utc;
 use common_datavalues::DataSchema;
 use common_datavalues::DataType;
+use common_datavalues::DataValue;
+use common_datavalues::DateConverter;
+use common_datavalues::Tz;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
@@ -90,6 +97,7 @@ impl Function for CastFunction {
                match &self.cast_type {
                 Date32 => Ok(arr.apply_cast_numeric(|v| v as i32).into_series()),
                 DateTime32(_) => Ok(arr.apply_cast_numeric(|v|  Utc.timestamp(v as i64 * 24 * 3600, 0_u32).timestamp() as u32 ).into_series() ),
+                DateTime64(precision, _) => Ok(arr.apply_cast_numeric(|v| v as i64 * 24 * 3600 * 10_i64.pow(*precision)).into_series()),
                 String => Ok(DFStringArray::from_iter(arr.into_iter().map(|v| v.map(|x| datetime_to_string( Utc.timestamp(*x as i64 * 24 * 3600, 0_u32), DATE_FMT))) ).into_series()),
                 _ =>  Err(error)
                }
@@ -102,23 +110,48 @@ impl Function for CastFunction {
                match &self.cast_type {
                 Date32 => Ok(arr.apply_cast_numeric(|v| v as i32).into_series()),
                 DateTime32(_) => Ok(arr.apply_cast_numeric(|v|  Utc.timestamp(v as i64 * 24 * 3600, 0_u32).timestamp()  as u32).into_series() ),
+                DateTime64(precision, _) => Ok(arr.apply_cast_numeric(|v| v as i64 * 24 * 3600 * 10_i64.pow(*precision)).into_series()),
                 String => Ok(DFStringArray::from_iter(arr.into_iter().map(|v| v.map(|x| datetime_to_string( Utc.timestamp(*x as i64 * 24 * 3600, 0_u32), DATE_FMT))) ).into_series()),
                 _ =>  Err(error)
                }
             }),
 
-            (DataType::DateTime32(_), _) => with_match_primitive_type!(&self.cast_type, |$T| {
+            (DataType::DateTime32(tz), _) => with_match_primitive_type!(&self.cast_type, |$T| {
                 series.cast_with_type(&self.cast_type)
             }, {
                let arr = series.u32()?;
+               let tz = timezone_or_utc(tz)?;
                match &self.cast_type {
                 Date16 => Ok(arr.apply_cast_numeric(|v| (v as i64 / 24/ 3600) as u16).into_series()),
                 Date32 => Ok(arr.apply_cast_numeric(|v| (v as i64 / 24/ 3600) as i32).into_series()),
-                String => Ok(DFStringArray::from_iter(arr.into_iter().map(|v| v.map(|x| datetime_to_string( Utc.timestamp(*x as i64, 0_u32), TIME_FMT))) ).into_series()),
+                DateTime64(precision, _) => Ok(arr.apply_cast_numeric(|v| v as i64 * 10_i64.pow(*precision)).into_series()),
+                String => Ok(DFStringArray::from_iter(arr.into_iter().map(|v| v.map(|x| datetime_to_string( (*x).to_date_time(&tz), TIME_FMT))) ).into_series()),
                 _ =>  Err(error)
                }
             }),
 
+            (DataType::DateTime64(precision, tz), _) => {
+                with_match_primitive_type!(&self.cast_type, |$T| {
+                    series.cast_with_type(&self.cast_type)
+                }, {
+                   let arr = series.i64()?;
+                   let precision = *precision;
+                   let tz = timezone_or_utc(tz)?;
+                   match &self.cast_type {
+                    Date16 => Ok(arr.apply_cast_numeric(|v| local_days(&v.to_date_time64(precision, &tz)) as u16).into_series()),
+                    Date32 => Ok(arr.apply_cast_numeric(|v| local_days(&v.to_date_time64(precision, &tz)) as i32).into_series()),
+                    DateTime32(_) => Ok(arr.apply_cast_numeric(|v| v.div_euclid(10_i64.pow(precision)) as u32).into_series()),
+                    DateTime64(to_precision, _) => Ok(arr.apply_cast_numeric(|v| rescale_datetime64(v, precision, *to_precision)).into_series()),
+                    String => {
+                        let serializer = columns[0].data_type().create_serializer();
+                        let values = arr.into_iter().map(|v| v.map(|x| serializer.serialize_value(&DataValue::Int64(Some(*x)))).transpose()).collect::<Result<Vec<_>>>()?;
+                        Ok(DFStringArray::from_iter(values.into_iter()).into_series())
+                    },
+                    _ =>  Err(error)
+                   }
+                })
+            }
+
             // others to Date/DateTime
             (_, DataType::Date16) => with_match_primitive_type!(columns[0].data_type(), |$T| {
                 series.cast_with_type(&self.cast_type)
@@ -164,6 +197,23 @@ impl Function for CastFunction {
                 })
             }
 
+            (_, DataType::DateTime64(precision, tz)) => {
+                with_match_primitive_type!(columns[0].data_type(), |$T| {
+                    series.cast_with_type(&self.cast_type)
+                }, {
+                   match columns[0].data_type() {
+                    String => {
+                        let tz = timezone_or_utc(tz)?;
+                        let it = series.string()?.into_iter().map(|v| {
+                            v.and_then(|s| string_to_datetime64(s, &tz)).map(|t| datetime64_to_ticks(&t, *precision))
+                        });
+                        Ok(DFInt64Array::from_iter(it).into_series())
+                    },
+                    _ =>  Err(error)
+                   }
+                })
+            }
+
             _ => series.cast_with_type(&self.cast_type),
         }?;
 
@@ -183,7 +233,8 @@ impl fmt::Display for CastFunction {
 }
 
 #[inline]
-fn datetime_to_string(date: DateTime<Utc>, fmt: &str) -> String {
+fn datetime_to_string<T: TimeZone>(date: DateTime<T>, fmt: &str) -> String
+where T::Offset: fmt::Display {
     date.format(fmt).to_string()
 }
 
@@ -195,6 +246,18 @@ fn string_to_datetime(date_str: impl AsRef<[u8]>) -> Option<NaiveDateTime> {
     s.and_then(|c| NaiveDateTime::parse_from_str(c, "%Y-%m-%d %H:%M:%S").ok())
 }
 
+#[inline]
+fn string_to_datetime64(date_str: impl AsRef<[u8]>, tz: &Tz) -> Option<DateTime<Tz>> {
+    let s = std::str::from_utf8(date_str.as_ref()).ok();
+    s.and_then(|c| tz.datetime_from_str(c, "%Y-%m-%d %H:%M:%S%.f").ok())
+}
+
+/// Days since 1970-01-01 of the datetime in its own timezone.
+#[inline]
+fn local_days(dt: &DateTime<Tz>) -> i64 {
+    (dt.num_days_from_ce() - EPOCH_DAYS_FROM_CE) as i64
+}
+
 #[inline]
 fn string_to_date(date_str: impl AsRef<[u8]>) -> Option<NaiveDate> {
     let s = std::str::from_utf8(date_str.as_ref()).ok();
@@ -63,5 +63,9 @@ impl ToCastFunction {
             "toDateTime32",
             Self::cast_function_creator(DataType::DateTime32(None)),
         );
+        factory.register(
+            "toDateTime64",
+            Self::cast_function_creator(DataType::DateTime64(3, None)),
+        );
     }
 }
@@ -20,6 +20,7 @@ use common_exception::Result;
 use lazy_static::lazy_static;
 
 use crate::scalars::ArithmeticFunction;
+use crate::scalars::ArrayClassFunction;
 use crate::scalars::ComparisonFunction;
 use crate::scalars::ConditionalFunction;
 use crate::scalars::DateFunction;
@@ -43,6 +44,7 @@ pub struct FunctionFeatures {
     pub is_bool_func: bool,
     pub is_context_func: bool,
     pub maybe_monotonic: bool,
+    pub is_array_join_func: bool,
 }
 
 impl FunctionFeatures {
@@ -53,6 +55,7 @@ impl FunctionFeatures {
             is_bool_func: false,
             is_context_func: false,
             maybe_monotonic: false,
+            is_array_join_func: false,
         }
     }
 
@@ -80,6 +83,11 @@ impl FunctionFeatures {
         self.maybe_monotonic = true;
         self
     }
+
+    pub fn array_join_function(mut self) -> FunctionFeatures {
+        self.is_array_join_func = true;
+        self
+    }
 }
 
 pub struct FunctionDescription {
@@ -123,6 +131,7 @@ lazy_static! {
         OtherFunction::register(&mut function_factory);
         MathsFunction::register(&mut function_factory);
         TupleClassFunction::register(&mut function_factory);
+        ArrayClassFunction::register(&mut function_factory);
 
         Arc::new(function_factory)
     };
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod arithmetics;
+mod arrays;
 mod comparisons;
 mod conditionals;
 mod dates;
@@ -33,6 +34,7 @@ mod tuples;
 mod udfs;
 
 pub use arithmetics::*;
+pub use arrays::*;
 pub use comparisons::*;
 pub use conditionals::*;
 pub use dates::*;
@@ -59,7 +59,8 @@ impl Function for RunningDifferenceFunction {
             | DataType::Int64
             | DataType::UInt64
             | DataType::Date32
-            | DataType::DateTime32(_) => Ok(DataType::Int64),
+            | DataType::DateTime32(_)
+            | DataType::DateTime64(_, _) => Ok(DataType::Int64),
             DataType::Float32 | DataType::Float64 => Ok(DataType::Float64),
             _ => Result::Err(ErrorCode::IllegalDataType(
                 "Argument for function runningDifference must have numeric type",
@@ -81,7 +82,9 @@ impl Function for RunningDifferenceFunction {
             DataType::UInt32 | DataType::DateTime32(_) => {
                 compute_u32(columns[0].column(), input_rows)
             }
-            DataType::Int64 => compute_i64(columns[0].column(), input_rows),
+            DataType::Int64 | DataType::DateTime64(_, _) => {
+                compute_i64(columns[0].column(), input_rows)
+            }
             DataType::UInt64 => compute_u64(columns[0].column(), input_rows),
             DataType::Float32 => compute_f32(columns[0].column(), input_rows),
             DataType::Float64 => compute_f64(columns[0].column(), input_rows),
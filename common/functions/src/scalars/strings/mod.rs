@@ -28,15 +28,22 @@ mod insert;
 mod leftright;
 mod length;
 mod locate;
+mod lowerupper;
 mod oct;
 mod octet_length;
 mod ord;
 mod pad;
 mod quote;
+mod regexp;
+mod regexp_instr;
+mod regexp_like;
+mod regexp_replace;
+mod regexp_substr;
 mod repeat;
 mod replace;
 mod reverse;
 mod space;
+mod split_part;
 mod strcmp;
 mod string;
 mod string2number;
@@ -65,16 +72,23 @@ pub use length::LengthFunction;
 pub use locate::InstrFunction;
 pub use locate::LocateFunction;
 pub use locate::PositionFunction;
+pub use lowerupper::LowerFunction;
+pub use lowerupper::UpperFunction;
 pub use oct::OctFunction;
 pub use octet_length::OctetLengthFunction;
 pub use ord::OrdFunction;
 pub use pad::LeftPadFunction;
 pub use pad::RightPadFunction;
 pub use quote::QuoteFunction;
+pub use regexp_instr::RegexpInstrFunction;
+pub use regexp_like::RegexpLikeFunction;
+pub use regexp_replace::RegexpReplaceFunction;
+pub use regexp_substr::RegexpSubstrFunction;
 pub use repeat::RepeatFunction;
 pub use replace::ReplaceFunction;
 pub use reverse::ReverseFunction;
 pub use space::SpaceFunction;
+pub use split_part::SplitPartFunction;
 pub use strcmp::StrcmpFunction;
 pub use string::StringFunction;
 pub use string2number::NumberResultFunction;
@@ -0,0 +1,188 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use regex::bytes::Regex;
+use regex::bytes::RegexBuilder;
+
+/// The string values of an argument, a constant is not expanded to all rows.
+pub enum StringArg {
+    Constant(Option<Vec<u8>>),
+    Array(DFStringArray),
+}
+
+impl StringArg {
+    pub fn try_create(column: &DataColumnWithField) -> Result<StringArg> {
+        match column.column().cast_with_type(&DataType::String)? {
+            DataColumn::Constant(DataValue::String(value), _) => Ok(StringArg::Constant(value)),
+            DataColumn::Constant(_, _) => Ok(StringArg::Constant(None)),
+            DataColumn::Array(series) => Ok(StringArg::Array(series.string()?.clone())),
+        }
+    }
+
+    pub fn get(&self, row: usize) -> Option<&[u8]> {
+        match self {
+            StringArg::Constant(value) => value.as_deref(),
+            StringArg::Array(array) => array.get(row),
+        }
+    }
+}
+
+/// The integer values of an argument, a constant is not expanded to all rows.
+pub enum IntegerArg {
+    Constant(Option<i64>),
+    Array(DFInt64Array),
+}
+
+impl IntegerArg {
+    pub fn try_create(column: &DataColumnWithField) -> Result<IntegerArg> {
+        match column.column().cast_with_type(&DataType::Int64)? {
+            DataColumn::Constant(DataValue::Int64(value), _) => Ok(IntegerArg::Constant(value)),
+            DataColumn::Constant(_, _) => Ok(IntegerArg::Constant(None)),
+            DataColumn::Array(series) => Ok(IntegerArg::Array(series.i64()?.clone())),
+        }
+    }
+
+    /// The optional argument at `index`, which is `default` if absent.
+    pub fn try_create_optional(
+        columns: &DataColumnsWithField,
+        index: usize,
+        default: i64,
+    ) -> Result<IntegerArg> {
+        match columns.get(index) {
+            None => Ok(IntegerArg::Constant(Some(default))),
+            Some(column) => Self::try_create(column),
+        }
+    }
+
+    pub fn get(&self, row: usize) -> Option<i64> {
+        match self {
+            IntegerArg::Constant(value) => *value,
+            IntegerArg::Array(array) => array.get(row),
+        }
+    }
+}
+
+/// The regexes built from the patterns and match types of a block.
+/// A constant pattern with a constant match type is built only once,
+/// other patterns are built once for every distinct pattern.
+pub struct RegexCache {
+    patterns: StringArg,
+    match_types: StringArg,
+    constant: Option<Regex>,
+    regexes: HashMap<(Vec<u8>, Vec<u8>), Regex>,
+}
+
+impl RegexCache {
+    pub fn try_create(patterns: StringArg, match_types: StringArg) -> Result<RegexCache> {
+        let constant = match (&patterns, &match_types) {
+            (StringArg::Constant(Some(pattern)), StringArg::Constant(Some(match_type))) => {
+                Some(build_regex(pattern, match_type)?)
+            }
+            _ => None,
+        };
+
+        Ok(RegexCache {
+            patterns,
+            match_types,
+            constant,
+            regexes: HashMap::new(),
+        })
+    }
+
+    /// The regex of the row, or None if the pattern or the match type is null.
+    pub fn get(&mut self, row: usize) -> Result<Option<&Regex>> {
+        if self.constant.is_some() {
+            return Ok(self.constant.as_ref());
+        }
+
+        let (pattern, match_type) = match (self.patterns.get(row), self.match_types.get(row)) {
+            (Some(pattern), Some(match_type)) => (pattern.to_vec(), match_type.to_vec()),
+            _ => return Ok(None),
+        };
+
+        if !self
+            .regexes
+            .contains_key(&(pattern.clone(), match_type.clone()))
+        {
+            let regex = build_regex(&pattern, &match_type)?;
+            self.regexes
+                .insert((pattern.clone(), match_type.clone()), regex);
+        }
+        Ok(self.regexes.get(&(pattern, match_type)))
+    }
+}
+
+/// The match type is the same as MySQL:
+/// c: case sensitive, i: case insensitive, m: multiple-line mode, n: `.` matches line terminators,
+/// u: unix-only line endings. The rightmost one takes precedence if they are contradictory.
+pub fn build_regex(pattern: &[u8], match_type: &[u8]) -> Result<Regex> {
+    let pattern = std::str::from_utf8(pattern).map_err(|e| {
+        ErrorCode::BadArguments(format!(
+            "Unable to convert the regular expression to string: {}",
+            e
+        ))
+    })?;
+
+    let mut builder = RegexBuilder::new(pattern);
+    for c in match_type {
+        match c {
+            b'c' => builder.case_insensitive(false),
+            b'i' => builder.case_insensitive(true),
+            b'm' => builder.multi_line(true),
+            b'n' => builder.dot_matches_new_line(true),
+            // Only `\n` is the line terminator, which is the default.
+            b'u' => &mut builder,
+            _ => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Incorrect match type: {}",
+                    *c as char
+                )))
+            }
+        };
+    }
+
+    builder.build().map_err(|e| {
+        ErrorCode::BadArguments(format!(
+            "Unable to build regex from the regular expression: {}",
+            e
+        ))
+    })
+}
+
+/// The 1-based position must be in `[1, len + 1]`, returns the 0-based offset.
+pub fn check_position(position: i64, len: usize) -> Result<usize> {
+    if position < 1 || position as usize > len + 1 {
+        return Err(ErrorCode::BadArguments(format!(
+            "Index out of bounds in regular expression search: {}",
+            position
+        )));
+    }
+    Ok(position as usize - 1)
+}
+
+/// The 1-based occurrence must be positive.
+pub fn check_occurrence(occurrence: i64) -> Result<usize> {
+    if occurrence < 1 {
+        return Err(ErrorCode::BadArguments(format!(
+            "Incorrect occurrence in regular expression search: {}",
+            occurrence
+        )));
+    }
+    Ok(occurrence as usize)
+}
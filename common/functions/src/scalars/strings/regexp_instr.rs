@@ -0,0 +1,124 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use regex::bytes::Regex;
+
+use super::regexp::check_occurrence;
+use super::regexp::check_position;
+use super::regexp::IntegerArg;
+use super::regexp::RegexCache;
+use super::regexp::StringArg;
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+/// regexp_instr(expr, pat[, pos[, occurrence[, return_option[, match_type]]]]) returns the 1-based position
+/// of the `occurrence`th match starting from `pos`, or the position after it if `return_option` is 1.
+/// Returns 0 if there is no such match.
+#[derive(Clone)]
+pub struct RegexpInstrFunction {
+    display_name: String,
+}
+
+impl RegexpInstrFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(Self {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for RegexpInstrFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((2, 6))
+    }
+
+    fn return_type(&self, _args: &[DataType]) -> Result<DataType> {
+        Ok(DataType::UInt64)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let sources = StringArg::try_create(&columns[0])?;
+        let positions = IntegerArg::try_create_optional(columns, 2, 1)?;
+        let occurrences = IntegerArg::try_create_optional(columns, 3, 1)?;
+        let return_options = IntegerArg::try_create_optional(columns, 4, 0)?;
+        let match_types = match columns.get(5) {
+            None => StringArg::Constant(Some(vec![])),
+            Some(column) => StringArg::try_create(column)?,
+        };
+        let mut regexes = RegexCache::try_create(StringArg::try_create(&columns[1])?, match_types)?;
+
+        let mut values = Vec::with_capacity(input_rows);
+        for row in 0..input_rows {
+            values.push(
+                match (
+                    sources.get(row),
+                    regexes.get(row)?,
+                    positions.get(row),
+                    occurrences.get(row),
+                    return_options.get(row),
+                ) {
+                    (Some(s), Some(re), Some(pos), Some(occur), Some(opt)) => {
+                        Some(regexp_instr(s, re, pos, occur, opt)?)
+                    }
+                    _ => None,
+                },
+            );
+        }
+
+        let column: DataColumn = DFUInt64Array::new_from_opt_slice(&values).into();
+        Ok(column.resize_constant(input_rows))
+    }
+}
+
+impl fmt::Display for RegexpInstrFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+fn regexp_instr(s: &[u8], re: &Regex, pos: i64, occur: i64, opt: i64) -> Result<u64> {
+    let pos = check_position(pos, s.len())?;
+    let occur = check_occurrence(occur)?;
+    if opt != 0 && opt != 1 {
+        return Err(ErrorCode::BadArguments(format!(
+            "Incorrect return option in regular expression search: {}",
+            opt
+        )));
+    }
+
+    match re.find_iter(&s[pos..]).nth(occur - 1) {
+        None => Ok(0),
+        Some(m) if opt == 0 => Ok((pos + m.start() + 1) as u64),
+        Some(m) => Ok((pos + m.end() + 1) as u64),
+    }
+}
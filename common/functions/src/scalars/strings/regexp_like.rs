@@ -0,0 +1,87 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use super::regexp::RegexCache;
+use super::regexp::StringArg;
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+/// regexp_like(expr, pat[, match_type]) checks whether the string matches the regular expression.
+#[derive(Clone)]
+pub struct RegexpLikeFunction {
+    display_name: String,
+}
+
+impl RegexpLikeFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(Self {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for RegexpLikeFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((2, 3))
+    }
+
+    fn return_type(&self, _args: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Boolean)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let sources = StringArg::try_create(&columns[0])?;
+        let match_types = match columns.get(2) {
+            None => StringArg::Constant(Some(vec![])),
+            Some(column) => StringArg::try_create(column)?,
+        };
+        let mut regexes = RegexCache::try_create(StringArg::try_create(&columns[1])?, match_types)?;
+
+        let mut values = Vec::with_capacity(input_rows);
+        for row in 0..input_rows {
+            values.push(match (sources.get(row), regexes.get(row)?) {
+                (Some(source), Some(regex)) => Some(regex.is_match(source)),
+                _ => None,
+            });
+        }
+
+        let column: DataColumn = DFBooleanArray::new_from_opt_slice(&values).into();
+        Ok(column.resize_constant(input_rows))
+    }
+}
+
+impl fmt::Display for RegexpLikeFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
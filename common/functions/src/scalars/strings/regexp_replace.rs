@@ -0,0 +1,146 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use regex::bytes::Regex;
+
+use super::regexp::check_position;
+use super::regexp::IntegerArg;
+use super::regexp::RegexCache;
+use super::regexp::StringArg;
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+/// regexp_replace(expr, pat, repl[, pos[, occurrence[, match_type]]]) replaces the matches starting from `pos`
+/// with `repl`, which may refer to the capture groups like `$1`. Only the `occurrence`th match is replaced
+/// if `occurrence` is positive, otherwise all matches are replaced.
+#[derive(Clone)]
+pub struct RegexpReplaceFunction {
+    display_name: String,
+}
+
+impl RegexpReplaceFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(Self {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for RegexpReplaceFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((3, 6))
+    }
+
+    fn return_type(&self, _args: &[DataType]) -> Result<DataType> {
+        Ok(DataType::String)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let sources = StringArg::try_create(&columns[0])?;
+        let replaces = StringArg::try_create(&columns[2])?;
+        let positions = IntegerArg::try_create_optional(columns, 3, 1)?;
+        let occurrences = IntegerArg::try_create_optional(columns, 4, 0)?;
+        let match_types = match columns.get(5) {
+            None => StringArg::Constant(Some(vec![])),
+            Some(column) => StringArg::try_create(column)?,
+        };
+        let mut regexes = RegexCache::try_create(StringArg::try_create(&columns[1])?, match_types)?;
+
+        let mut buf = Vec::new();
+        let mut builder = StringArrayBuilder::with_capacity(input_rows);
+        for row in 0..input_rows {
+            match (
+                sources.get(row),
+                regexes.get(row)?,
+                replaces.get(row),
+                positions.get(row),
+                occurrences.get(row),
+            ) {
+                (Some(s), Some(re), Some(repl), Some(pos), Some(occur)) => {
+                    regexp_replace(s, re, repl, pos, occur, &mut buf)?;
+                    builder.append_value(&buf);
+                }
+                _ => builder.append_null(),
+            }
+        }
+
+        let column: DataColumn = builder.finish().into();
+        Ok(column.resize_constant(input_rows))
+    }
+}
+
+impl fmt::Display for RegexpReplaceFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+fn regexp_replace(
+    s: &[u8],
+    re: &Regex,
+    repl: &[u8],
+    pos: i64,
+    occur: i64,
+    buf: &mut Vec<u8>,
+) -> Result<()> {
+    let pos = check_position(pos, s.len())?;
+    if occur < 0 {
+        return Err(ErrorCode::BadArguments(format!(
+            "Incorrect occurrence in regular expression search: {}",
+            occur
+        )));
+    }
+
+    buf.clear();
+    let mut last = pos;
+    buf.extend_from_slice(&s[..pos]);
+
+    for (index, caps) in re.captures_iter(&s[pos..]).enumerate() {
+        if occur > 0 && index + 1 < occur as usize {
+            continue;
+        }
+
+        // Safe to unwrap because the group 0 is always the whole match.
+        let m = caps.get(0).unwrap();
+        buf.extend_from_slice(&s[last..pos + m.start()]);
+        caps.expand(repl, buf);
+        last = pos + m.end();
+
+        if occur > 0 {
+            break;
+        }
+    }
+
+    buf.extend_from_slice(&s[last..]);
+    Ok(())
+}
@@ -0,0 +1,120 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use super::regexp::IntegerArg;
+use super::regexp::StringArg;
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+/// split_part(str, delimiter, n) splits the string by the delimiter and returns the `n`th part,
+/// counting from the end if `n` is negative. Returns an empty string if there is no such part.
+#[derive(Clone)]
+pub struct SplitPartFunction {
+    display_name: String,
+}
+
+impl SplitPartFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(Self {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for SplitPartFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn num_arguments(&self) -> usize {
+        3
+    }
+
+    fn return_type(&self, _args: &[DataType]) -> Result<DataType> {
+        Ok(DataType::String)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let sources = StringArg::try_create(&columns[0])?;
+        let delimiters = StringArg::try_create(&columns[1])?;
+        let indexes = IntegerArg::try_create(&columns[2])?;
+
+        let mut builder = StringArrayBuilder::with_capacity(input_rows);
+        for row in 0..input_rows {
+            match (sources.get(row), delimiters.get(row), indexes.get(row)) {
+                (Some(s), Some(delimiter), Some(index)) => {
+                    builder.append_value(split_part(s, delimiter, index))
+                }
+                _ => builder.append_null(),
+            }
+        }
+
+        let column: DataColumn = builder.finish().into();
+        Ok(column.resize_constant(input_rows))
+    }
+}
+
+impl fmt::Display for SplitPartFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+fn split_part<'a>(s: &'a [u8], delimiter: &[u8], index: i64) -> &'a [u8] {
+    if delimiter.is_empty() {
+        return match index {
+            1 | -1 => s,
+            _ => &[],
+        };
+    }
+
+    let mut parts = Vec::new();
+    let mut start = 0;
+    let mut offset = 0;
+    while offset + delimiter.len() <= s.len() {
+        if &s[offset..offset + delimiter.len()] == delimiter {
+            parts.push(&s[start..offset]);
+            offset += delimiter.len();
+            start = offset;
+        } else {
+            offset += 1;
+        }
+    }
+    parts.push(&s[start..]);
+
+    let index = match index {
+        0 => return &[],
+        i if i > 0 => i as usize - 1,
+        i => match parts.len().checked_sub(i.unsigned_abs() as usize) {
+            Some(i) => i,
+            None => return &[],
+        },
+    };
+    parts.get(index).copied().unwrap_or(&[])
+}
@@ -31,25 +31,31 @@ use crate::scalars::InstrFunction;
 use crate::scalars::LTrimFunction;
 use crate::scalars::LeftFunction;
 use crate::scalars::LeftPadFunction;
-use crate::scalars::LengthFunction;
 use crate::scalars::LocateFunction;
+use crate::scalars::LowerFunction;
 use crate::scalars::OctFunction;
 use crate::scalars::OctetLengthFunction;
 use crate::scalars::OrdFunction;
 use crate::scalars::PositionFunction;
 use crate::scalars::QuoteFunction;
 use crate::scalars::RTrimFunction;
+use crate::scalars::RegexpInstrFunction;
+use crate::scalars::RegexpLikeFunction;
+use crate::scalars::RegexpReplaceFunction;
+use crate::scalars::RegexpSubstrFunction;
 use crate::scalars::RepeatFunction;
 use crate::scalars::ReplaceFunction;
 use crate::scalars::ReverseFunction;
 use crate::scalars::RightFunction;
 use crate::scalars::RightPadFunction;
 use crate::scalars::SpaceFunction;
+use crate::scalars::SplitPartFunction;
 use crate::scalars::StrcmpFunction;
 use crate::scalars::SubstringFunction;
 use crate::scalars::SubstringIndexFunction;
 use crate::scalars::TrimFunction;
 use crate::scalars::UnhexFunction;
+use crate::scalars::UpperFunction;
 
 #[derive(Clone)]
 pub struct StringFunction;
@@ -95,6 +101,14 @@ impl StringFunction {
         factory.register("bin", BinFunction::desc());
         factory.register("export_set", ExportSetFunction::desc());
         factory.register("find_in_set", FindInSetFunction::desc());
-        factory.register("length", LengthFunction::desc());
+        factory.register("lower", LowerFunction::desc());
+        factory.register("lcase", LowerFunction::desc());
+        factory.register("upper", UpperFunction::desc());
+        factory.register("ucase", UpperFunction::desc());
+        factory.register("regexp_like", RegexpLikeFunction::desc());
+        factory.register("regexp_instr", RegexpInstrFunction::desc());
+        factory.register("regexp_substr", RegexpSubstrFunction::desc());
+        factory.register("regexp_replace", RegexpReplaceFunction::desc());
+        factory.register("split_part", SplitPartFunction::desc());
     }
 }
@@ -208,6 +208,34 @@ fn test_aggregate_function() -> Result<()> {
             expect: DataValue::Float64(Some(-1.25000)),
             error: "",
         },
+        Test {
+            name: "groupArray-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "groupArray",
+            func_name: "groupArray",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::List(
+                Some(vec![4i64.into(), 3i64.into(), 2i64.into(), 1i64.into()]),
+                DataType::Int64,
+            ),
+            error: "",
+        },
+        Test {
+            name: "groupUniqArray-passed",
+            eval_nums: 2,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "groupUniqArray",
+            func_name: "groupUniqArray",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::List(
+                Some(vec![4i64.into(), 3i64.into(), 2i64.into(), 1i64.into()]),
+                DataType::Int64,
+            ),
+            error: "",
+        },
         Test {
             name: "windowFunnel-passed",
             eval_nums: 2,
@@ -0,0 +1,226 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::*;
+use pretty_assertions::assert_eq;
+
+struct Test {
+    name: &'static str,
+    func: Box<dyn Function>,
+    columns: Vec<DataColumnWithField>,
+    expect: Vec<&'static str>,
+    error: &'static str,
+}
+
+fn array_column(values: Vec<Option<Vec<DataValue>>>, data_type: DataType) -> DataColumnWithField {
+    let values = values
+        .into_iter()
+        .map(|v| DataValue::List(v, data_type.clone()))
+        .collect::<Vec<_>>();
+    let array_type = DataType::List(Box::new(DataField::new("item", data_type, true)));
+    DataColumnWithField::new(
+        DataValue::try_into_data_array(&values, &array_type)
+            .unwrap()
+            .into(),
+        DataField::new("array", array_type, false),
+    )
+}
+
+fn u8_array_column(values: Vec<Option<Vec<u8>>>) -> DataColumnWithField {
+    array_column(
+        values
+            .into_iter()
+            .map(|v| v.map(|v| v.into_iter().map(DataValue::from).collect()))
+            .collect(),
+        DataType::UInt8,
+    )
+}
+
+fn constant_column(value: DataValue) -> DataColumnWithField {
+    let data_type = value.data_type();
+    DataColumnWithField::new(
+        DataColumn::Constant(value, 1),
+        DataField::new("constant", data_type, false),
+    )
+}
+
+fn series_column(series: Series) -> DataColumnWithField {
+    let data_type = series.data_type().clone();
+    DataColumnWithField::new(series.into(), DataField::new("series", data_type, true))
+}
+
+#[test]
+fn test_array_functions() -> Result<()> {
+    let tests = vec![
+        Test {
+            name: "array",
+            func: ArrayFunction::try_create("array")?,
+            columns: vec![
+                series_column(Series::new(vec![1u8, 2])),
+                series_column(Series::new(vec![300u16, 400])),
+            ],
+            expect: vec!["[1, 300]", "[2, 400]"],
+            error: "",
+        },
+        Test {
+            name: "array-string",
+            func: ArrayFunction::try_create("array")?,
+            columns: vec![
+                series_column(Series::new(vec!["a", "b"])),
+                constant_column(DataValue::String(Some(b"c".to_vec()))),
+            ],
+            expect: vec!["[a, c]", "[b, c]"],
+            error: "",
+        },
+        Test {
+            name: "arrayElement",
+            func: ArrayElementFunction::try_create("arrayElement")?,
+            columns: vec![
+                u8_array_column(vec![Some(vec![1, 2, 3]), Some(vec![4]), None, Some(vec![5, 6])]),
+                series_column(Series::new(vec![2i64, 2, 1, -1])),
+            ],
+            expect: vec!["2", "NULL", "NULL", "6"],
+            error: "",
+        },
+        Test {
+            name: "length",
+            func: ArrayLengthFunction::try_create("length")?,
+            columns: vec![u8_array_column(vec![Some(vec![1, 2, 3]), Some(vec![])])],
+            expect: vec!["3", "0"],
+            error: "",
+        },
+        Test {
+            name: "length-string",
+            func: ArrayLengthFunction::try_create("length")?,
+            columns: vec![series_column(Series::new(vec!["databend", ""]))],
+            expect: vec!["8", "0"],
+            error: "",
+        },
+        Test {
+            name: "has",
+            func: ArrayHasFunction::try_create("has")?,
+            columns: vec![
+                u8_array_column(vec![Some(vec![1, 2, 3]), Some(vec![4]), None]),
+                constant_column(DataValue::UInt64(Some(2))),
+            ],
+            expect: vec!["true", "false", "false"],
+            error: "",
+        },
+        Test {
+            name: "indexOf",
+            func: ArrayIndexOfFunction::try_create("indexOf")?,
+            columns: vec![
+                u8_array_column(vec![Some(vec![1, 2, 3]), Some(vec![3, 3])]),
+                series_column(Series::new(vec![3i32, 1])),
+            ],
+            expect: vec!["3", "0"],
+            error: "",
+        },
+        Test {
+            name: "arrayConcat",
+            func: ArrayConcatFunction::try_create("arrayConcat")?,
+            columns: vec![
+                u8_array_column(vec![Some(vec![1, 2]), None]),
+                array_column(
+                    vec![Some(vec![DataValue::Int64(Some(-1))]), Some(vec![])],
+                    DataType::Int64,
+                ),
+            ],
+            expect: vec!["[1, 2, -1]", "[]"],
+            error: "",
+        },
+        Test {
+            name: "arraySlice",
+            func: ArraySliceFunction::try_create("arraySlice")?,
+            columns: vec![
+                u8_array_column(vec![Some(vec![1, 2, 3, 4, 5]); 4]),
+                series_column(Series::new(vec![2i64, -2, 1, 0])),
+                series_column(Series::new(vec![2i64, 5, -1, 2])),
+            ],
+            expect: vec!["[2, 3]", "[4, 5]", "[1, 2, 3, 4]", "[]"],
+            error: "",
+        },
+        Test {
+            name: "arrayDistinct",
+            func: ArrayDistinctFunction::try_create("arrayDistinct")?,
+            columns: vec![u8_array_column(vec![Some(vec![1, 2, 1, 3, 2]), None])],
+            expect: vec!["[1, 2, 3]", "NULL"],
+            error: "",
+        },
+        Test {
+            name: "arrayElement-not-array",
+            func: ArrayElementFunction::try_create("arrayElement")?,
+            columns: vec![
+                series_column(Series::new(vec![1u8])),
+                series_column(Series::new(vec![1i64])),
+            ],
+            expect: vec![],
+            error: "Code: 7, displayText = Illegal type UInt8 of argument of function arrayElement.Should be an array.",
+        },
+    ];
+
+    for t in tests {
+        let rows = t.columns.iter().map(|c| c.column().len()).max().unwrap();
+        match t.func.eval(&t.columns, rows) {
+            Ok(v) => {
+                assert_eq!(t.error, "", "{}", t.name);
+                let values = v
+                    .to_values()?
+                    .iter()
+                    .map(|v| v.to_string())
+                    .collect::<Vec<_>>();
+                assert_eq!(values, t.expect, "{}", t.name);
+            }
+            Err(e) => assert_eq!(t.error, e.to_string(), "{}", t.name),
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_array_join_function() -> Result<()> {
+    let column = u8_array_column(vec![Some(vec![1, 2]), None, Some(vec![]), Some(vec![3])]);
+
+    let func = ArrayJoinFunction::try_create("arrayJoin")?;
+    assert_eq!(
+        func.return_type(&[column.data_type().clone()])?,
+        DataType::UInt8
+    );
+
+    let v = func.eval(&[column.clone()], 4)?;
+    assert_eq!(format!("{:?}", v.to_values()?), "[1, 2, 3]");
+
+    let indices = ArrayJoinFunction::replicate_indices(column.column())?;
+    assert_eq!(indices, vec![0, 0, 3]);
+    Ok(())
+}
+
+#[test]
+fn test_length_function_on_strings() -> Result<()> {
+    // `length` is registered by the array functions, it still counts the bytes of strings.
+    let func = FunctionFactory::instance().get("length")?;
+    let column = series_column(Series::new(vec![Some("databend"), Some(""), None]));
+
+    assert_eq!(
+        func.return_type(&[column.data_type().clone()])?,
+        DataType::UInt64
+    );
+    assert!(!func.nullable(&DataSchema::empty())?);
+
+    let v = func.eval(&[column], 3)?;
+    assert_eq!(format!("{:?}", v.to_values()?), "[8, 0, NULL]");
+    Ok(())
+}
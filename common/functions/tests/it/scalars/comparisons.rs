@@ -141,6 +141,58 @@ fn test_comparison_function() -> Result<()> {
             expect: Series::new(vec![false, false, false, true]),
             error: "",
         },
+        Test {
+            name: "ilike-passed",
+            display: "ILIKE",
+            nullable: false,
+            func: ComparisonILikeFunction::try_create_func("")?,
+            arg_names: vec!["a", "b"],
+            columns: vec![
+                Series::new(vec!["abc", "abd", "abe", "abf"]).into(),
+                Series::new(vec!["A%", "_B_", "ABE", "a"]).into(),
+            ],
+            expect: Series::new(vec![true, true, true, false]),
+            error: "",
+        },
+        Test {
+            name: "not-ilike-passed",
+            display: "NOT ILIKE",
+            nullable: false,
+            func: ComparisonNotILikeFunction::try_create_func("")?,
+            arg_names: vec!["a", "b"],
+            columns: vec![
+                Series::new(vec!["abc", "abd", "abe", "abf"]).into(),
+                Series::new(vec!["A%", "_B_", "ABE", "a"]).into(),
+            ],
+            expect: Series::new(vec![false, false, false, true]),
+            error: "",
+        },
+        Test {
+            name: "regexp-passed",
+            display: "REGEXP",
+            nullable: false,
+            func: ComparisonRegexpFunction::try_create_func("")?,
+            arg_names: vec!["a", "b"],
+            columns: vec![
+                Series::new(vec!["abc", "abd", "abe", "abf"]).into(),
+                Series::new(vec!["^a", "b.$", "^b", "[ce]$"]).into(),
+            ],
+            expect: Series::new(vec![true, true, false, false]),
+            error: "",
+        },
+        Test {
+            name: "not-regexp-passed",
+            display: "NOT REGEXP",
+            nullable: false,
+            func: ComparisonNotRegexpFunction::try_create_func("")?,
+            arg_names: vec!["a", "b"],
+            columns: vec![
+                Series::new(vec!["abc", "abd", "abe", "abf"]).into(),
+                Series::new(vec!["^a", "b.$", "^b", "[ce]$"]).into(),
+            ],
+            expect: Series::new(vec![false, false, true, true]),
+            error: "",
+        },
     ];
 
     for t in tests {
@@ -87,3 +87,352 @@ fn test_if_function() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_multi_if_function() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        display: &'static str,
+        columns: Vec<DataColumn>,
+        expect_type: DataType,
+        expect: DataColumn,
+        error: &'static str,
+    }
+
+    let tests = vec![
+        Test {
+            name: "multi-if-passed",
+            display: "multiIf",
+            columns: vec![
+                Series::new(vec![true, false, false, true]).into(),
+                Series::new(vec![1i32, 2, 3, 4]).into(),
+                Series::new(vec![true, true, false, false]).into(),
+                DataColumn::Constant(DataValue::Int64(Some(10)), 4),
+                DataColumn::Constant(DataValue::Float64(Some(2.5)), 4),
+            ],
+            expect_type: DataType::Float64,
+            expect: Series::new(vec![1f64, 10f64, 2.5, 4f64]).into(),
+            error: "",
+        },
+        Test {
+            name: "multi-if-null-condition",
+            display: "multiIf",
+            columns: vec![
+                Series::new(vec![Some(true), None, Some(false)]).into(),
+                Series::new(vec![1i64, 2, 3]).into(),
+                Series::new(vec![4i64, 5, 6]).into(),
+            ],
+            expect_type: DataType::Int64,
+            expect: Series::new(vec![1i64, 5, 6]).into(),
+            error: "",
+        },
+        Test {
+            name: "multi-if-even-arguments",
+            display: "multiIf",
+            columns: vec![
+                Series::new(vec![true, false]).into(),
+                Series::new(vec![1i64, 2]).into(),
+                Series::new(vec![false, true]).into(),
+                Series::new(vec![3i64, 4]).into(),
+            ],
+            expect_type: DataType::Int64,
+            expect: Series::new(vec![1i64, 4]).into(),
+            error: "Code: 28, displayText = Function multiIf expects an odd number of arguments, but got 4.",
+        },
+    ];
+
+    for t in tests {
+        let func = MultiIfFunction::try_create_func("multiIf")?;
+        assert_eq!(t.display.to_string(), format!("{}", func));
+
+        let columns: Vec<DataColumnWithField> = t
+            .columns
+            .iter()
+            .map(|c| DataColumnWithField::new(c.clone(), DataField::new("a", c.data_type(), true)))
+            .collect();
+        let args: Vec<DataType> = t.columns.iter().map(|c| c.data_type()).collect();
+
+        match func.return_type(&args) {
+            Ok(return_type) => {
+                assert_eq!(t.expect_type, return_type, "case: {}", t.name);
+                let v = func.eval(&columns, t.columns[0].len())?;
+                assert_eq!(v.to_values()?, t.expect.to_values()?, "case: {}", t.name);
+            }
+            Err(e) => assert_eq!(t.error, e.to_string(), "case: {}", t.name),
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_in_function() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        display: &'static str,
+        func: Box<dyn Function>,
+        columns: Vec<DataColumn>,
+        expect: DataColumn,
+        error: &'static str,
+    }
+
+    let tests = vec![
+        Test {
+            name: "in-list-passed",
+            display: "IN",
+            func: InFunction::try_create_func("in")?,
+            columns: vec![
+                Series::new(vec![Some(1i32), Some(2), None, Some(4)]).into(),
+                DataColumn::Constant(DataValue::Int64(Some(1)), 4),
+                DataColumn::Constant(DataValue::Float64(Some(4.0)), 4),
+                DataColumn::Constant(DataValue::Null, 4),
+            ],
+            expect: Series::new(vec![Some(true), None, None, Some(true)]).into(),
+            error: "",
+        },
+        Test {
+            name: "not-in-list-passed",
+            display: "NOT IN",
+            func: NotInFunction::try_create_func("not in")?,
+            columns: vec![
+                Series::new(vec!["a", "b", "c"]).into(),
+                DataColumn::Constant(DataValue::String(Some(b"b".to_vec())), 3),
+            ],
+            expect: Series::new(vec![true, false, true]).into(),
+            error: "",
+        },
+        Test {
+            name: "in-null-list-passed",
+            display: "IN",
+            func: InFunction::try_create_func("in")?,
+            columns: vec![
+                Series::new(vec![1i64, 2]).into(),
+                DataColumn::Constant(DataValue::Int64(Some(2)), 2),
+                DataColumn::Constant(DataValue::Null, 2),
+            ],
+            expect: Series::new(vec![None, Some(true)]).into(),
+            error: "",
+        },
+        Test {
+            name: "not-in-null-list-passed",
+            display: "NOT IN",
+            func: NotInFunction::try_create_func("not in")?,
+            columns: vec![
+                Series::new(vec![1i64, 2]).into(),
+                DataColumn::Constant(DataValue::Int64(Some(2)), 2),
+                DataColumn::Constant(DataValue::Null, 2),
+            ],
+            expect: Series::new(vec![None, Some(false)]).into(),
+            error: "",
+        },
+        Test {
+            name: "not-in-subquery-with-null-passed",
+            display: "NOT IN",
+            func: NotInFunction::try_create_func("not in")?,
+            columns: vec![
+                Series::new(vec![1u8, 2]).into(),
+                DataColumn::Constant(
+                    DataValue::List(
+                        Some(vec![DataValue::UInt64(Some(2)), DataValue::UInt64(None)]),
+                        DataType::UInt64,
+                    ),
+                    2,
+                ),
+            ],
+            expect: Series::new(vec![None, Some(false)]).into(),
+            error: "",
+        },
+        Test {
+            name: "in-subquery-passed",
+            display: "IN",
+            func: InFunction::try_create_func("in")?,
+            columns: vec![
+                Series::new(vec![1u8, 2, 3]).into(),
+                DataColumn::Constant(
+                    DataValue::List(
+                        Some(vec![DataValue::UInt64(Some(2)), DataValue::UInt64(Some(3))]),
+                        DataType::UInt64,
+                    ),
+                    3,
+                ),
+            ],
+            expect: Series::new(vec![false, true, true]).into(),
+            error: "",
+        },
+        Test {
+            name: "in-empty-subquery-passed",
+            display: "IN",
+            func: InFunction::try_create_func("in")?,
+            columns: vec![
+                Series::new(vec![None, Some(1i32)]).into(),
+                DataColumn::Constant(DataValue::List(Some(vec![]), DataType::UInt64), 2),
+            ],
+            expect: Series::new(vec![false, false]).into(),
+            error: "",
+        },
+        Test {
+            name: "not-in-empty-subquery-passed",
+            display: "NOT IN",
+            func: NotInFunction::try_create_func("not in")?,
+            columns: vec![
+                DataColumn::Constant(DataValue::Null, 2),
+                DataColumn::Constant(DataValue::List(Some(vec![]), DataType::UInt64), 2),
+            ],
+            expect: Series::new(vec![true, true]).into(),
+            error: "",
+        },
+        Test {
+            name: "in-constant-passed",
+            display: "IN",
+            func: InFunction::try_create_func("in")?,
+            columns: vec![
+                DataColumn::Constant(DataValue::Int64(Some(2)), 3),
+                DataColumn::Constant(DataValue::Int64(Some(1)), 3),
+                DataColumn::Constant(DataValue::Int64(Some(2)), 3),
+            ],
+            expect: Series::new(vec![true, true, true]).into(),
+            error: "",
+        },
+        Test {
+            name: "in-column-values",
+            display: "IN",
+            func: InFunction::try_create_func("in")?,
+            columns: vec![
+                Series::new(vec![1i64, 2]).into(),
+                Series::new(vec![1i64, 2]).into(),
+            ],
+            expect: Series::new(vec![true, true]).into(),
+            error: "Code: 6, displayText = The values of IN must be constants or a subquery.",
+        },
+    ];
+
+    for t in tests {
+        let func = t.func;
+        assert_eq!(t.display.to_string(), format!("{}", func));
+
+        let columns: Vec<DataColumnWithField> = t
+            .columns
+            .iter()
+            .map(|c| DataColumnWithField::new(c.clone(), DataField::new("a", c.data_type(), true)))
+            .collect();
+
+        match func.eval(&columns, t.columns[0].len()) {
+            Ok(v) => {
+                assert_eq!(DataType::Boolean, v.data_type(), "case: {}", t.name);
+                assert_eq!(v.to_values()?, t.expect.to_values()?, "case: {}", t.name);
+            }
+            Err(e) => assert_eq!(t.error, e.to_string(), "case: {}", t.name),
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_in_function_with_changed_values() -> Result<()> {
+    let func = InFunction::try_create_func("in")?;
+    let input = DataColumnWithField::new(
+        Series::new(vec![1i64, 2, 3]).into(),
+        DataField::new("a", DataType::Int64, false),
+    );
+    let list = |values: Vec<i64>| {
+        let values = values
+            .into_iter()
+            .map(|v| DataValue::Int64(Some(v)))
+            .collect();
+        DataColumnWithField::new(
+            DataColumn::Constant(DataValue::List(Some(values), DataType::Int64), 3),
+            DataField::new("b", DataType::Int64, false),
+        )
+    };
+
+    // the set built for the first block is not reused for other values
+    let result = func.eval(&[input.clone(), list(vec![2, 3])], 3)?;
+    assert_eq!(
+        result.to_values()?,
+        DataColumn::from(Series::new(vec![false, true, true])).to_values()?
+    );
+    let result = func.eval(&[input.clone(), list(vec![2, 3])], 3)?;
+    assert_eq!(
+        result.to_values()?,
+        DataColumn::from(Series::new(vec![false, true, true])).to_values()?
+    );
+    let result = func.eval(&[input, list(vec![1])], 3)?;
+    assert_eq!(
+        result.to_values()?,
+        DataColumn::from(Series::new(vec![true, false, false])).to_values()?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_null_handling_functions() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        display: &'static str,
+        func: Box<dyn Function>,
+        columns: Vec<DataColumn>,
+        expect: DataColumn,
+    }
+
+    let tests = vec![
+        Test {
+            name: "coalesce-passed",
+            display: "coalesce",
+            func: CoalesceFunction::try_create_func("coalesce")?,
+            columns: vec![
+                Series::new(vec![Some(1i64), None, None]).into(),
+                Series::new(vec![Some(2i64), Some(3), None]).into(),
+                DataColumn::Constant(DataValue::Int64(Some(4)), 3),
+            ],
+            expect: Series::new(vec![1i64, 3, 4]).into(),
+        },
+        Test {
+            name: "coalesce-all-null",
+            display: "coalesce",
+            func: CoalesceFunction::try_create_func("coalesce")?,
+            columns: vec![
+                Series::new(vec![Some(1i64), None]).into(),
+                DataColumn::Constant(DataValue::Null, 2),
+            ],
+            expect: Series::new(vec![Some(1i64), None]).into(),
+        },
+        Test {
+            name: "if-null-passed",
+            display: "ifNull",
+            func: IfNullFunction::try_create_func("ifNull")?,
+            columns: vec![
+                Series::new(vec![Some(1i32), None, Some(3)]).into(),
+                DataColumn::Constant(DataValue::Int64(Some(0)), 3),
+            ],
+            expect: Series::new(vec![1i64, 0, 3]).into(),
+        },
+        Test {
+            name: "null-if-passed",
+            display: "nullIf",
+            func: NullIfFunction::try_create_func("nullIf")?,
+            columns: vec![
+                Series::new(vec![Some(1i64), Some(2), None]).into(),
+                DataColumn::Constant(DataValue::Int64(Some(2)), 3),
+            ],
+            expect: Series::new(vec![Some(1i64), None, None]).into(),
+        },
+    ];
+
+    for t in tests {
+        let func = t.func;
+        assert_eq!(t.display.to_string(), format!("{}", func));
+
+        let columns: Vec<DataColumnWithField> = t
+            .columns
+            .iter()
+            .map(|c| DataColumnWithField::new(c.clone(), DataField::new("a", c.data_type(), true)))
+            .collect();
+
+        let v = func.eval(&columns, t.columns[0].len())?;
+        assert_eq!(v.to_values()?, t.expect.to_values()?, "case: {}", t.name);
+    }
+
+    Ok(())
+}
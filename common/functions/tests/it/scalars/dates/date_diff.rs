@@ -0,0 +1,224 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::*;
+use pretty_assertions::assert_eq;
+
+struct Test {
+    name: &'static str,
+    func: Box<dyn Function>,
+    columns: Vec<DataColumnWithField>,
+    expect: DataColumn,
+    error: &'static str,
+}
+
+fn datetime_column(values: Vec<u32>, tz: Option<&str>) -> DataColumnWithField {
+    DataColumnWithField::new(
+        Series::new(values).into(),
+        DataField::new(
+            "datetime",
+            DataType::DateTime32(tz.map(|tz| tz.to_string())),
+            false,
+        ),
+    )
+}
+
+fn date16_column(values: Vec<u16>) -> DataColumnWithField {
+    DataColumnWithField::new(
+        Series::new(values).into(),
+        DataField::new("date16", DataType::Date16, false),
+    )
+}
+
+fn constant_string(value: &str) -> DataColumnWithField {
+    DataColumnWithField::new(
+        DataColumn::Constant(DataValue::String(Some(value.as_bytes().to_vec())), 1),
+        DataField::new("unit", DataType::String, false),
+    )
+}
+
+#[test]
+fn test_date_diff_function() -> Result<()> {
+    // 2021-10-19 08:09:05 UTC, and 2022-01-03 00:00:00 UTC
+    let start = 1634630945u32;
+    let end = 1641168000u32;
+
+    let mut tests = vec![];
+    for (unit, expect) in [
+        ("second", 6537055i64),
+        ("minute", 108951),
+        ("hour", 1816),
+        ("day", 76),
+        ("week", 11),
+        ("month", 3),
+        ("quarter", 1),
+        ("year", 1),
+    ] {
+        tests.push(Test {
+            name: "dateDiff-datetime",
+            func: DateDiffFunction::try_create("dateDiff")?,
+            columns: vec![
+                constant_string(unit),
+                datetime_column(vec![start, end], None),
+                datetime_column(vec![end, start], None),
+            ],
+            expect: Series::new(vec![expect, -expect]).into(),
+            error: "",
+        });
+    }
+
+    tests.push(Test {
+        name: "dateDiff-date16",
+        func: DateDiffFunction::try_create("dateDiff")?,
+        columns: vec![
+            constant_string("month"),
+            date16_column(vec![18919u16]),
+            date16_column(vec![18995u16]),
+        ],
+        expect: Series::new(vec![3i64]).into(),
+        error: "",
+    });
+
+    tests.push(Test {
+        name: "dateDiff-mysql",
+        func: DateDiffFunction::try_create("dateDiff")?,
+        columns: vec![
+            datetime_column(vec![end], None),
+            datetime_column(vec![start], None),
+        ],
+        expect: Series::new(vec![76i64]).into(),
+        error: "",
+    });
+
+    tests.push(Test {
+        name: "dateDiff-unknown-unit",
+        func: DateDiffFunction::try_create("dateDiff")?,
+        columns: vec![
+            constant_string("fortnight"),
+            datetime_column(vec![start], None),
+            datetime_column(vec![end], None),
+        ],
+        expect: Series::new(vec![0i64]).into(),
+        error: "Code: 6, displayText = Unsupported date unit 'fortnight', expect one of second, minute, hour, day, week, month, quarter and year.",
+    });
+
+    do_test(tests)
+}
+
+#[test]
+fn test_date_trunc_function() -> Result<()> {
+    // Tuesday, 2021-10-19 08:09:05 UTC
+    let datetime = 1634630945u32;
+
+    let mut tests = vec![];
+    for (unit, expect) in [
+        ("second", 1634630945u32),
+        ("minute", 1634630940),
+        ("hour", 1634630400),
+        ("day", 1634601600),
+        ("week", 1634515200),
+        ("month", 1633046400),
+        ("quarter", 1633046400),
+        ("year", 1609459200),
+    ] {
+        tests.push(Test {
+            name: "date_trunc-datetime",
+            func: DateTruncFunction::try_create("date_trunc")?,
+            columns: vec![
+                constant_string(unit),
+                datetime_column(vec![datetime, datetime], None),
+            ],
+            expect: Series::new(vec![expect, expect]).into(),
+            error: "",
+        });
+    }
+
+    tests.push(Test {
+        name: "date_trunc-timezone",
+        func: DateTruncFunction::try_create("date_trunc")?,
+        columns: vec![
+            constant_string("day"),
+            datetime_column(vec![datetime], Some("Asia/Shanghai")),
+        ],
+        expect: Series::new(vec![1634572800u32]).into(),
+        error: "",
+    });
+
+    tests.push(Test {
+        name: "date_trunc-date16",
+        func: DateTruncFunction::try_create("date_trunc")?,
+        columns: vec![constant_string("month"), date16_column(vec![18919u16])],
+        expect: Series::new(vec![18901u16]).into(),
+        error: "",
+    });
+
+    do_test(tests)
+}
+
+#[test]
+fn test_unix_timestamp_functions() -> Result<()> {
+    let timestamps = DataColumnWithField::new(
+        Series::new(vec![1634630945i64, 0]).into(),
+        DataField::new("timestamp", DataType::Int64, false),
+    );
+
+    let tests = vec![
+        Test {
+            name: "toUnixTimestamp-date16",
+            func: ToUnixTimestampFunction::try_create("toUnixTimestamp")?,
+            columns: vec![date16_column(vec![18919u16, 0])],
+            expect: Series::new(vec![1634601600u32, 0]).into(),
+            error: "",
+        },
+        Test {
+            name: "toUnixTimestamp-datetime",
+            func: ToUnixTimestampFunction::try_create("toUnixTimestamp")?,
+            columns: vec![datetime_column(vec![1634630945u32], Some("Asia/Shanghai"))],
+            expect: Series::new(vec![1634630945u32]).into(),
+            error: "",
+        },
+        Test {
+            name: "fromUnixTimestamp",
+            func: FromUnixTimestampFunction::try_create("fromUnixTimestamp")?,
+            columns: vec![timestamps.clone()],
+            expect: Series::new(vec![1634630945u32, 0]).into(),
+            error: "",
+        },
+        Test {
+            name: "fromUnixTimestamp-format",
+            func: FromUnixTimestampFunction::try_create("fromUnixTimestamp")?,
+            columns: vec![timestamps, constant_string("%Y-%m-%d %H:%i:%s")],
+            expect: Series::new(vec!["2021-10-19 08:09:05", "1970-01-01 00:00:00"]).into(),
+            error: "",
+        },
+    ];
+
+    do_test(tests)
+}
+
+fn do_test(tests: Vec<Test>) -> Result<()> {
+    for t in tests {
+        let rows = t.columns.iter().map(|c| c.column().len()).max().unwrap();
+        match t.func.eval(&t.columns, rows) {
+            Ok(v) => {
+                assert_eq!(t.error, "", "{}", t.name);
+                assert_eq!(&v, &t.expect, "{}", t.name);
+            }
+            Err(e) => assert_eq!(t.error, e.to_string(), "{}", t.name),
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,216 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::*;
+use pretty_assertions::assert_eq;
+
+struct Test {
+    name: &'static str,
+    func: Box<dyn Function>,
+    columns: Vec<DataColumnWithField>,
+    expect: DataColumn,
+    error: &'static str,
+}
+
+fn datetime_column(values: Vec<u32>) -> DataColumnWithField {
+    DataColumnWithField::new(
+        Series::new(values).into(),
+        DataField::new("datetime", DataType::DateTime32(None), false),
+    )
+}
+
+fn date16_column(values: Vec<u16>) -> DataColumnWithField {
+    DataColumnWithField::new(
+        Series::new(values).into(),
+        DataField::new("date16", DataType::Date16, false),
+    )
+}
+
+fn string_column(values: Vec<&str>) -> DataColumnWithField {
+    DataColumnWithField::new(
+        Series::new(values).into(),
+        DataField::new("string", DataType::String, false),
+    )
+}
+
+fn constant_string(value: &str) -> DataColumnWithField {
+    DataColumnWithField::new(
+        DataColumn::Constant(DataValue::String(Some(value.as_bytes().to_vec())), 1),
+        DataField::new("format", DataType::String, false),
+    )
+}
+
+#[test]
+fn test_format_datetime_functions() -> Result<()> {
+    // 2021-10-19 08:09:05 UTC, and 2021-03-01 23:00:00 UTC
+    let datetimes = vec![1634630945u32, 1614639600u32];
+
+    let tests = vec![
+        Test {
+            name: "date_format-mysql",
+            func: DateFormatFunction::try_create("date_format")?,
+            columns: vec![
+                datetime_column(datetimes.clone()),
+                constant_string("%Y-%m-%d %H:%i:%s %W %M %D %j %%"),
+            ],
+            expect: Series::new(vec![
+                "2021-10-19 08:09:05 Tuesday October 19th 292 %",
+                "2021-03-01 23:00:00 Monday March 1st 060 %",
+            ])
+            .into(),
+            error: "",
+        },
+        Test {
+            name: "date_format-mysql-unpadded",
+            func: DateFormatFunction::try_create("date_format")?,
+            columns: vec![
+                datetime_column(datetimes.clone()),
+                constant_string("%c/%e/%y %k %l%p %T %r"),
+            ],
+            expect: Series::new(vec![
+                "10/19/21 8 8AM 08:09:05 08:09:05 AM",
+                "3/1/21 23 11PM 23:00:00 11:00:00 PM",
+            ])
+            .into(),
+            error: "",
+        },
+        Test {
+            name: "date_format-date16",
+            func: DateFormatFunction::try_create("date_format")?,
+            columns: vec![
+                date16_column(vec![18919u16]),
+                constant_string("%d.%m.%Y %H:%i"),
+            ],
+            expect: Series::new(vec!["19.10.2021 00:00"]).into(),
+            error: "",
+        },
+        Test {
+            name: "date_format-unsupported-specifier",
+            func: DateFormatFunction::try_create("date_format")?,
+            columns: vec![datetime_column(datetimes.clone()), constant_string("%Y %Q")],
+            expect: Series::new(vec![""]).into(),
+            error: "Code: 6, displayText = Unsupported format specifier '%Q' in format '%Y %Q'.",
+        },
+        Test {
+            name: "formatDateTime-clickhouse",
+            func: FormatDateTimeFunction::try_create("formatDateTime")?,
+            columns: vec![
+                datetime_column(datetimes.clone()),
+                constant_string("%F %T %D %R Q%Q %u %j"),
+            ],
+            expect: Series::new(vec![
+                "2021-10-19 08:09:05 10/19/21 08:09 Q4 2 292",
+                "2021-03-01 23:00:00 03/01/21 23:00 Q1 1 060",
+            ])
+            .into(),
+            error: "",
+        },
+        Test {
+            name: "formatDateTime-minutes",
+            func: FormatDateTimeFunction::try_create("formatDateTime")?,
+            columns: vec![datetime_column(datetimes), constant_string("%H:%M:%S")],
+            expect: Series::new(vec!["08:09:05", "23:00:00"]).into(),
+            error: "",
+        },
+    ];
+
+    do_test(tests)
+}
+
+#[test]
+fn test_parse_datetime_functions() -> Result<()> {
+    let tests = vec![
+        Test {
+            name: "str_to_date-datetime",
+            func: StrToDateFunction::try_create("str_to_date")?,
+            columns: vec![
+                string_column(vec!["2021-10-19 08:09:05", "19/10/2021 08:09:05", "2021-10-19"]),
+                constant_string("%Y-%m-%d %H:%i:%s"),
+            ],
+            expect: DFUInt32Array::new_from_opt_slice(&[Some(1634630945u32), None, None])
+                .into(),
+            error: "",
+        },
+        Test {
+            name: "str_to_date-date",
+            func: StrToDateFunction::try_create("str_to_date")?,
+            columns: vec![
+                string_column(vec!["19 October 2021", "1 March 2021"]),
+                constant_string("%d %M %Y"),
+            ],
+            expect: Series::new(vec![1634601600u32, 1614556800u32]).into(),
+            error: "",
+        },
+        Test {
+            name: "parseDateTimeBestEffort",
+            func: ParseDateTimeBestEffortFunction::try_create("parseDateTimeBestEffort")?,
+            columns: vec![string_column(vec![
+                "2021-10-19 08:09:05",
+                "2021-10-19T10:09:05+02:00",
+                "Tue, 19 Oct 2021 08:09:05 +0000",
+                "20211019080905",
+                "1634630945",
+                "19/10/2021 08:09:05",
+                "2021-10-19",
+                "20211019",
+            ])],
+            expect: Series::new(vec![
+                1634630945u32,
+                1634630945u32,
+                1634630945u32,
+                1634630945u32,
+                1634630945u32,
+                1634630945u32,
+                1634601600u32,
+                1634601600u32,
+            ])
+            .into(),
+            error: "",
+        },
+        Test {
+            name: "parseDateTimeBestEffort-error",
+            func: ParseDateTimeBestEffortFunction::try_create("parseDateTimeBestEffort")?,
+            columns: vec![string_column(vec!["not a date"])],
+            expect: Series::new(vec![0u32]).into(),
+            error: "Code: 6, displayText = Cannot parse 'not a date' as DateTime in function parseDateTimeBestEffort.",
+        },
+        Test {
+            name: "parseDateTimeBestEffortOrNull",
+            func: ParseDateTimeBestEffortOrNullFunction::try_create(
+                "parseDateTimeBestEffortOrNull",
+            )?,
+            columns: vec![string_column(vec!["2021-10-19 08:09:05", "not a date"])],
+            expect: DFUInt32Array::new_from_opt_slice(&[Some(1634630945u32), None]).into(),
+            error: "",
+        },
+    ];
+
+    do_test(tests)
+}
+
+fn do_test(tests: Vec<Test>) -> Result<()> {
+    for t in tests {
+        let rows = t.columns[0].column().len();
+        match t.func.eval(&t.columns, rows) {
+            Ok(v) => {
+                assert_eq!(t.error, "", "{}", t.name);
+                assert_eq!(&v, &t.expect, "{}", t.name);
+            }
+            Err(e) => assert_eq!(t.error, e.to_string(), "{}", t.name),
+        }
+    }
+    Ok(())
+}
@@ -192,6 +192,26 @@ fn test_toyyyymmdd_function() -> Result<()> {
             expect: Series::new(vec![20210905u32]).into(),
             error: "",
         },
+        Test {
+            name: "test_toyyyymmdd_datetime64",
+            display: "d()",
+            arg_names: vec!["d"],
+            func: ToYYYYMMDDFunction::try_create("d")?,
+            columns: vec![Series::new(vec![1630785600123i64]).into()],
+            nullable: false,
+            expect: Series::new(vec![20210904u32]).into(),
+            error: "",
+        },
+        Test {
+            name: "test_toyyyymmdd_datetime_timezone",
+            display: "e()",
+            arg_names: vec!["e"],
+            func: ToYYYYMMDDFunction::try_create("e")?,
+            columns: vec![Series::new(vec![1630785600u32]).into()],
+            nullable: false,
+            expect: Series::new(vec![20210905u32]).into(),
+            error: "",
+        },
     ];
     do_test(tests)
 }
@@ -267,6 +287,57 @@ fn test_toyyyymmddhhmmss_function() -> Result<()> {
     do_test(tests)
 }
 
+#[test]
+fn test_toyear_function() -> Result<()> {
+    let tests = vec![
+        Test {
+            name: "test_toyear_date16",
+            display: "c()",
+            arg_names: vec!["c"],
+            func: ToYearFunction::try_create("c")?,
+            columns: vec![Series::new(vec![0u16]).into()],
+            nullable: false,
+            expect: Series::new(vec![1970u16]).into(),
+            error: "",
+        },
+        Test {
+            name: "test_toyear_date32",
+            display: "b()",
+            arg_names: vec!["b"],
+            func: ToYearFunction::try_create("b")?,
+            columns: vec![Series::new(vec![0i32]).into()],
+            nullable: false,
+            expect: Series::new(vec![1970u16]).into(),
+            error: "",
+        },
+        Test {
+            name: "test_toyear_datetime",
+            display: "a()",
+            arg_names: vec!["a"],
+            func: ToYearFunction::try_create("a")?,
+            columns: vec![Series::new(vec![1633081817u32]).into()],
+            nullable: false,
+            expect: Series::new(vec![2021u16]).into(),
+            error: "",
+        },
+        Test {
+            name: "test_toyear_datetime_constant",
+            display: "a()",
+            arg_names: vec!["a"],
+            func: ToYearFunction::try_create("a")?,
+            columns: vec![DataColumn::Constant(
+                DataValue::UInt32(Some(1633081817u32)),
+                1,
+            )],
+            nullable: false,
+            expect: Series::new(vec![2021u16]).into(),
+            error: "",
+        },
+    ];
+
+    do_test(tests)
+}
+
 #[test]
 fn test_tomonth_function() -> Result<()> {
     let tests = vec![
@@ -617,6 +688,26 @@ fn test_tohour_function() -> Result<()> {
             expect: Series::new(vec![10u8]).into(),
             error: "",
         },
+        Test {
+            name: "test_tohour_datetime64",
+            display: "d()",
+            arg_names: vec!["d"],
+            func: ToHourFunction::try_create("d")?,
+            columns: vec![Series::new(vec![1634551542123i64]).into()],
+            nullable: false,
+            expect: Series::new(vec![10u8]).into(),
+            error: "",
+        },
+        Test {
+            name: "test_tohour_datetime_timezone",
+            display: "e()",
+            arg_names: vec!["e"],
+            func: ToHourFunction::try_create("e")?,
+            columns: vec![Series::new(vec![1634551542u32]).into()],
+            nullable: false,
+            expect: Series::new(vec![18u8]).into(),
+            error: "",
+        },
     ];
 
     do_test(tests)
@@ -852,6 +943,12 @@ fn do_test(tests: Vec<Test>) -> Result<()> {
         DataField::new("a", DataType::DateTime32(None), false),
         DataField::new("b", DataType::Date32, false),
         DataField::new("c", DataType::Date16, false),
+        DataField::new("d", DataType::DateTime64(3, None), false),
+        DataField::new(
+            "e",
+            DataType::DateTime32(Some("Asia/Shanghai".to_string())),
+            false,
+        ),
     ]);
 
     for t in tests {
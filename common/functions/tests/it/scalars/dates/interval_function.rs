@@ -140,6 +140,7 @@ fn test_add_subtract_seconds() -> Result<()> {
 
     let schema = DataSchemaRefExt::create(vec![
         DataField::new("datetime32", DataType::DateTime32(None), false),
+        DataField::new("datetime64", DataType::DateTime64(3, None), false),
         DataField::new("u8", DataType::UInt8, false),
         DataField::new("u16", DataType::UInt16, false),
         DataField::new("u32", DataType::UInt32, false),
@@ -152,6 +153,7 @@ fn test_add_subtract_seconds() -> Result<()> {
 
     let blocks = DataBlock::create_by_array(schema.clone(), vec![
         Series::new(vec![dt_to_seconds("2020-02-29T23:59:59Z") as u32]),
+        Series::new(vec![dt_to_seconds("2020-02-29T23:59:59Z") * 1000 + 123]),
         Series::new(vec![1_u8]),
         Series::new(vec![1_u16]),
         Series::new(vec![1_u32]),
@@ -192,6 +194,27 @@ fn test_add_subtract_seconds() -> Result<()> {
             dt_to_seconds("2020-02-29T23:59:58Z") as u32,
         ]);
     }
+    {
+        let mut expects: Vec<i64> = Vec::new();
+        expects.reserve(8);
+        for c in ["u8", "u16", "u32", "u64", "i8", "i16", "i32", "i64"] {
+            let col = add_seconds.eval(&[column(c), column("datetime64")], 1)?;
+            let raw = col.to_array()?.i64()?.inner().values().as_slice().to_vec();
+            assert_eq!(raw.len(), 1);
+            assert_eq!(col.data_type(), DataType::Int64);
+            expects.push(raw[0]);
+        }
+        assert_eq!(expects, vec![
+            dt_to_seconds("2020-03-01T00:00:00Z") * 1000 + 123,
+            dt_to_seconds("2020-03-01T00:00:00Z") * 1000 + 123,
+            dt_to_seconds("2020-03-01T00:00:00Z") * 1000 + 123,
+            dt_to_seconds("2020-03-01T00:00:00Z") * 1000 + 123,
+            dt_to_seconds("2020-02-29T23:59:58Z") * 1000 + 123,
+            dt_to_seconds("2020-02-29T23:59:58Z") * 1000 + 123,
+            dt_to_seconds("2020-02-29T23:59:58Z") * 1000 + 123,
+            dt_to_seconds("2020-02-29T23:59:58Z") * 1000 + 123,
+        ]);
+    }
     let sub_seconds = SecondsArithmeticFunction::try_create(
         "subtractSeconds",
         DataValueArithmeticOperator::Minus,
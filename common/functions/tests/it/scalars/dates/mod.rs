@@ -13,5 +13,7 @@
 // limitations under the License.
 
 mod date;
+mod date_diff;
+mod date_format;
 mod date_function;
 mod interval_function;
@@ -0,0 +1,50 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::LowerFunction;
+use common_functions::scalars::UpperFunction;
+
+use super::run_tests;
+use super::Test;
+
+#[test]
+fn test_lower_upper_function() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::String, true)]);
+
+    let tests = vec![
+        Test {
+            name: "lower-passed",
+            display: "lower",
+            nullable: true,
+            arg_names: vec!["a"],
+            columns: vec![Series::new(vec![Some("Hello, World!"), None]).into()],
+            func: LowerFunction::try_create("lower")?,
+            expect: Series::new(vec![Some("hello, world!"), None]).into(),
+            error: "",
+        },
+        Test {
+            name: "upper-passed",
+            display: "upper",
+            nullable: true,
+            arg_names: vec!["a"],
+            columns: vec![Series::new(vec![Some("Hello, World!"), None]).into()],
+            func: UpperFunction::try_create("upper")?,
+            expect: Series::new(vec![Some("HELLO, WORLD!"), None]).into(),
+            error: "",
+        },
+    ];
+    run_tests(tests, schema)
+}
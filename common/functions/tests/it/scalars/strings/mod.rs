@@ -13,6 +13,8 @@
 // limitations under the License.
 
 mod locate;
+mod lowerupper;
+mod regexp;
 mod substring;
 mod trim;
 
@@ -0,0 +1,141 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::RegexpInstrFunction;
+use common_functions::scalars::RegexpLikeFunction;
+use common_functions::scalars::RegexpReplaceFunction;
+use common_functions::scalars::RegexpSubstrFunction;
+use common_functions::scalars::SplitPartFunction;
+
+use super::run_tests;
+use super::Test;
+
+#[test]
+fn test_regexp_functions() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("s", DataType::String, true),
+        DataField::new("p", DataType::String, false),
+        DataField::new("r", DataType::String, false),
+        DataField::new("t", DataType::String, false),
+        DataField::new("i", DataType::Int64, false),
+        DataField::new("j", DataType::Int64, false),
+    ]);
+
+    let tests = vec![
+        Test {
+            name: "regexp-like-passed",
+            display: "regexp_like",
+            nullable: true,
+            arg_names: vec!["s", "p"],
+            columns: vec![
+                Series::new(vec![Some("abc"), Some("ABC"), None, Some("xyz")]).into(),
+                DataColumn::Constant(DataValue::String(Some(b"^a.c$".to_vec())), 4),
+            ],
+            func: RegexpLikeFunction::try_create("regexp_like")?,
+            expect: Series::new(vec![Some(true), Some(false), None, Some(false)]).into(),
+            error: "",
+        },
+        Test {
+            name: "regexp-like-match-type-passed",
+            display: "regexp_like",
+            nullable: true,
+            arg_names: vec!["s", "p", "t"],
+            columns: vec![
+                Series::new(vec!["abc", "ABC"]).into(),
+                Series::new(vec!["^a", "^b"]).into(),
+                DataColumn::Constant(DataValue::String(Some(b"i".to_vec())), 2),
+            ],
+            func: RegexpLikeFunction::try_create("regexp_like")?,
+            expect: Series::new(vec![true, false]).into(),
+            error: "",
+        },
+        Test {
+            name: "regexp-instr-passed",
+            display: "regexp_instr",
+            nullable: true,
+            arg_names: vec!["s", "p", "i", "j"],
+            columns: vec![
+                Series::new(vec!["dog cat dog", "dog cat dog", "cat"]).into(),
+                DataColumn::Constant(DataValue::String(Some(b"dog".to_vec())), 3),
+                Series::new(vec![1i64, 2, 1]).into(),
+                DataColumn::Constant(DataValue::Int64(Some(1)), 3),
+            ],
+            func: RegexpInstrFunction::try_create("regexp_instr")?,
+            expect: Series::new(vec![1u64, 9, 0]).into(),
+            error: "",
+        },
+        Test {
+            name: "regexp-substr-passed",
+            display: "regexp_substr",
+            nullable: true,
+            arg_names: vec!["s", "p", "i", "j"],
+            columns: vec![
+                Series::new(vec!["abc def ghi", "abc"]).into(),
+                DataColumn::Constant(DataValue::String(Some(b"[a-z]+".to_vec())), 2),
+                DataColumn::Constant(DataValue::Int64(Some(1)), 2),
+                DataColumn::Constant(DataValue::Int64(Some(3)), 2),
+            ],
+            func: RegexpSubstrFunction::try_create("regexp_substr")?,
+            expect: Series::new(vec![Some("ghi"), None]).into(),
+            error: "",
+        },
+        Test {
+            name: "regexp-replace-passed",
+            display: "regexp_replace",
+            nullable: true,
+            arg_names: vec!["s", "p", "r"],
+            columns: vec![
+                Series::new(vec!["a1b22c333", "abc"]).into(),
+                DataColumn::Constant(DataValue::String(Some(b"([0-9]+)".to_vec())), 2),
+                DataColumn::Constant(DataValue::String(Some(b"<$1>".to_vec())), 2),
+            ],
+            func: RegexpReplaceFunction::try_create("regexp_replace")?,
+            expect: Series::new(vec!["a<1>b<22>c<333>", "abc"]).into(),
+            error: "",
+        },
+        Test {
+            name: "regexp-replace-occurrence-passed",
+            display: "regexp_replace",
+            nullable: true,
+            arg_names: vec!["s", "p", "r", "i", "j"],
+            columns: vec![
+                Series::new(vec!["abc def ghi"]).into(),
+                DataColumn::Constant(DataValue::String(Some(b"[a-z]+".to_vec())), 1),
+                DataColumn::Constant(DataValue::String(Some(b"X".to_vec())), 1),
+                DataColumn::Constant(DataValue::Int64(Some(1)), 1),
+                DataColumn::Constant(DataValue::Int64(Some(2)), 1),
+            ],
+            func: RegexpReplaceFunction::try_create("regexp_replace")?,
+            expect: DataColumn::Constant(DataValue::String(Some(b"abc X ghi".to_vec())), 1),
+            error: "",
+        },
+        Test {
+            name: "split-part-passed",
+            display: "split_part",
+            nullable: true,
+            arg_names: vec!["s", "p", "i"],
+            columns: vec![
+                Series::new(vec!["a,b,c", "a,b,c", "a,b,c", "a"]).into(),
+                DataColumn::Constant(DataValue::String(Some(b",".to_vec())), 4),
+                Series::new(vec![2i64, -1, 4, 1]).into(),
+            ],
+            func: SplitPartFunction::try_create("split_part")?,
+            expect: Series::new(vec!["b", "c", "", "a"]).into(),
+            error: "",
+        },
+    ];
+    run_tests(tests, schema)
+}
@@ -14,11 +14,14 @@
 //
 
 mod cluster;
+mod role;
 mod stage;
 mod user;
 
 pub use cluster::ClusterApi;
 pub use cluster::ClusterMgr;
+pub use role::RoleMgr;
+pub use role::RoleMgrApi;
 pub use stage::StageMgr;
 pub use stage::StageMgrApi;
 pub use user::user_api::UserMgrApi;
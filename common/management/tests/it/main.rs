@@ -13,5 +13,6 @@
 // limitations under the License.
 
 mod cluster;
+mod role;
 mod stage;
 mod user;
@@ -0,0 +1,146 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_exception::Result;
+use common_management::*;
+use common_meta_api::KVApi;
+use common_meta_embedded::MetaEmbedded;
+use common_meta_types::GrantObject;
+use common_meta_types::RoleInfo;
+use common_meta_types::SeqV;
+use common_meta_types::UserPrivilegeSet;
+use common_meta_types::UserPrivilegeType;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_add_role() -> Result<()> {
+    let (kv_api, role_api) = new_role_api().await?;
+
+    let role_info = RoleInfo::new("role1".to_string());
+    role_api.add_role(role_info.clone()).await?;
+    let value = kv_api.get_kv("__fd_roles/databend_query/role1").await?;
+
+    match value {
+        Some(SeqV {
+            seq: 1,
+            meta: _,
+            data: value,
+        }) => {
+            assert_eq!(value, serde_json::to_vec(&role_info)?);
+        }
+        catch => panic!("GetKVActionReply{:?}", catch),
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_already_exists_add_role() -> Result<()> {
+    let (_, role_api) = new_role_api().await?;
+
+    let role_info = RoleInfo::new("role1".to_string());
+    role_api.add_role(role_info.clone()).await?;
+
+    match role_api.add_role(role_info.clone()).await {
+        Ok(_) => panic!("Already exists add role must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 3005),
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_grant_and_revoke_role_privileges() -> Result<()> {
+    let (_, role_api) = new_role_api().await?;
+
+    let role_info = RoleInfo::new("role1".to_string());
+    role_api.add_role(role_info).await?;
+
+    let mut privileges = UserPrivilegeSet::empty();
+    privileges.set_privilege(UserPrivilegeType::Select);
+    role_api
+        .grant_role_privileges(
+            "role1".to_string(),
+            GrantObject::Database("db1".to_string()),
+            privileges,
+            None,
+        )
+        .await?;
+
+    let role_info = role_api.get_role("role1".to_string(), None).await?.data;
+    assert!(role_info.grants.verify_database_privilege(
+        "role1",
+        "%",
+        "db1",
+        UserPrivilegeType::Select
+    ));
+
+    role_api
+        .revoke_role_privileges(
+            "role1".to_string(),
+            GrantObject::Database("db1".to_string()),
+            privileges,
+            None,
+        )
+        .await?;
+
+    let role_info = role_api.get_role("role1".to_string(), None).await?.data;
+    assert!(!role_info.grants.verify_database_privilege(
+        "role1",
+        "%",
+        "db1",
+        UserPrivilegeType::Select
+    ));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_successfully_drop_role() -> Result<()> {
+    let (_, role_api) = new_role_api().await?;
+
+    let role_info = RoleInfo::new("role1".to_string());
+    role_api.add_role(role_info.clone()).await?;
+
+    let roles = role_api.get_roles().await?;
+    assert_eq!(roles.len(), 1);
+    assert_eq!(roles[0].data, role_info);
+
+    role_api.drop_role("role1".to_string(), None).await?;
+
+    let roles = role_api.get_roles().await?;
+    assert!(roles.is_empty());
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_unknown_role_drop_role() -> Result<()> {
+    let (_, role_api) = new_role_api().await?;
+
+    match role_api.drop_role("UNKNOWN_ROLE".to_string(), None).await {
+        Ok(_) => panic!("Unknown role drop role must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 3004),
+    }
+
+    Ok(())
+}
+
+async fn new_role_api() -> Result<(Arc<MetaEmbedded>, RoleMgr)> {
+    let test_api = Arc::new(MetaEmbedded::new_temp().await?);
+    let mgr = RoleMgr::new(test_api.clone(), "databend_query");
+    Ok((test_api, mgr))
+}
@@ -20,14 +20,18 @@ use common_base::tokio;
 use common_exception::ErrorCode;
 use common_management::*;
 use common_meta_api::KVApi;
+use common_meta_api::WatchKVStream;
 use common_meta_types::GetKVActionReply;
 use common_meta_types::MGetKVActionReply;
 use common_meta_types::MatchSeq;
 use common_meta_types::Operation;
 use common_meta_types::PrefixListReply;
 use common_meta_types::SeqV;
+use common_meta_types::TransactionReply;
+use common_meta_types::TransactionReq;
 use common_meta_types::UpsertKVAction;
 use common_meta_types::UpsertKVActionReply;
+use common_meta_types::WatchKVReq;
 use mockall::predicate::*;
 use mockall::*;
 
@@ -49,6 +53,10 @@ mock! {
         ) -> common_exception::Result<MGetKVActionReply>;
 
         async fn prefix_list_kv(&self, prefix: &str) -> common_exception::Result<PrefixListReply>;
+
+        async fn transaction(&self, req: TransactionReq) -> common_exception::Result<TransactionReply>;
+
+        async fn watch_kv(&self, req: WatchKVReq) -> common_exception::Result<WatchKVStream>;
         }
 }
 
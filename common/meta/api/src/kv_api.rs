@@ -19,8 +19,16 @@ use async_trait::async_trait;
 use common_meta_types::GetKVActionReply;
 use common_meta_types::MGetKVActionReply;
 use common_meta_types::PrefixListReply;
+use common_meta_types::TransactionReply;
+use common_meta_types::TransactionReq;
 use common_meta_types::UpsertKVAction;
 use common_meta_types::UpsertKVActionReply;
+use common_meta_types::WatchKVEvent;
+use common_meta_types::WatchKVReq;
+use futures::stream::BoxStream;
+
+/// A stream of the changes of the watched keys, in the order they are applied.
+pub type WatchKVStream = BoxStream<'static, common_exception::Result<WatchKVEvent>>;
 
 #[async_trait]
 pub trait KVApi: Send + Sync {
@@ -33,6 +41,13 @@ pub trait KVApi: Send + Sync {
     async fn mget_kv(&self, key: &[String]) -> common_exception::Result<MGetKVActionReply>;
 
     async fn prefix_list_kv(&self, prefix: &str) -> common_exception::Result<PrefixListReply>;
+
+    /// Updates several keys atomically, conditioned on the seq of keys.
+    async fn transaction(&self, req: TransactionReq) -> common_exception::Result<TransactionReply>;
+
+    /// Subscribes to the changes of a key or a key prefix.
+    /// Only the changes applied after the subscription are pushed.
+    async fn watch_kv(&self, req: WatchKVReq) -> common_exception::Result<WatchKVStream>;
 }
 
 #[async_trait]
@@ -55,4 +70,12 @@ impl KVApi for Arc<dyn KVApi> {
     async fn prefix_list_kv(&self, prefix: &str) -> common_exception::Result<PrefixListReply> {
         self.as_ref().prefix_list_kv(prefix).await
     }
+
+    async fn transaction(&self, req: TransactionReq) -> common_exception::Result<TransactionReply> {
+        self.as_ref().transaction(req).await
+    }
+
+    async fn watch_kv(&self, req: WatchKVReq) -> common_exception::Result<WatchKVStream> {
+        self.as_ref().watch_kv(req).await
+    }
 }
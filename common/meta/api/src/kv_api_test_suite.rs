@@ -20,8 +20,13 @@ use common_meta_types::KVMeta;
 use common_meta_types::MatchSeq;
 use common_meta_types::Operation;
 use common_meta_types::SeqV;
+use common_meta_types::TransactionReq;
+use common_meta_types::TxnCondition;
+use common_meta_types::TxnOp;
 use common_meta_types::UpsertKVAction;
+use common_meta_types::WatchKVReq;
 use common_tracing::tracing;
+use futures::StreamExt;
 
 use crate::KVApi;
 
@@ -503,6 +508,157 @@ impl KVApiTestSuite {
 
         Ok(())
     }
+
+    pub async fn kv_transaction<KV: KVApi>(&self, client: &KV) -> anyhow::Result<()> {
+        let res = client
+            .upsert_kv(UpsertKVAction::new(
+                "__txn/a",
+                MatchSeq::Any,
+                Operation::Update(b"a1".to_vec()),
+                None,
+            ))
+            .await?;
+        let seq_a = res.result.unwrap().seq;
+
+        let req = TransactionReq {
+            conditions: vec![
+                TxnCondition::new("__txn/a", MatchSeq::Exact(seq_a)),
+                // absent
+                TxnCondition::new("__txn/b", MatchSeq::Exact(0)),
+            ],
+            if_then: vec![
+                TxnOp::new("__txn/a", Operation::Update(b"a2".to_vec()), None),
+                TxnOp::new("__txn/b", Operation::Update(b"b1".to_vec()), None),
+            ],
+            else_then: vec![TxnOp::new(
+                "__txn/c",
+                Operation::Update(b"c1".to_vec()),
+                None,
+            )],
+        };
+
+        {
+            // conditions met, if_then is applied
+            let reply = client.transaction(req.clone()).await?;
+            assert!(reply.success);
+            let changes = reply
+                .responses
+                .into_iter()
+                .map(|ch| (ch.ident.clone().unwrap(), ch.unpack_data()))
+                .collect::<Vec<_>>();
+            assert_eq!(changes, vec![
+                (
+                    "__txn/a".to_string(),
+                    (Some(b"a1".to_vec()), Some(b"a2".to_vec()))
+                ),
+                ("__txn/b".to_string(), (None, Some(b"b1".to_vec()))),
+            ]);
+        }
+
+        {
+            // conditions not met, else_then is applied
+            let reply = client.transaction(req).await?;
+            assert!(!reply.success);
+            assert_eq!(reply.responses.len(), 1);
+            assert_eq!(reply.responses[0].ident, Some("__txn/c".to_string()));
+
+            let res = client
+                .mget_kv(&[
+                    "__txn/a".to_string(),
+                    "__txn/b".to_string(),
+                    "__txn/c".to_string(),
+                ])
+                .await?;
+            let values = res
+                .into_iter()
+                .map(|v| v.map(|v| v.data))
+                .collect::<Vec<_>>();
+            assert_eq!(values, vec![
+                Some(b"a2".to_vec()),
+                Some(b"b1".to_vec()),
+                Some(b"c1".to_vec())
+            ]);
+        }
+
+        {
+            // no condition, delete several keys
+            let reply = client
+                .transaction(TransactionReq {
+                    conditions: vec![],
+                    if_then: vec![
+                        TxnOp::new("__txn/a", Operation::Delete, None),
+                        TxnOp::new("__txn/b", Operation::Delete, None),
+                    ],
+                    else_then: vec![],
+                })
+                .await?;
+            assert!(reply.success);
+
+            let res = client.prefix_list_kv("__txn/").await?;
+            assert_eq!(res.into_iter().map(|(k, _)| k).collect::<Vec<_>>(), vec![
+                "__txn/c".to_string()
+            ]);
+        }
+
+        Ok(())
+    }
+
+    pub async fn kv_watch<KV: KVApi>(&self, client: &KV) -> anyhow::Result<()> {
+        let mut key_watcher = client
+            .watch_kv(WatchKVReq::Key("__watch/a".to_string()))
+            .await?;
+        let mut prefix_watcher = client
+            .watch_kv(WatchKVReq::Prefix("__watch/".to_string()))
+            .await?;
+
+        for (key, seq, value) in [
+            (
+                "__watch/a",
+                MatchSeq::Any,
+                Operation::Update(b"a1".to_vec()),
+            ),
+            ("other", MatchSeq::Any, Operation::Update(b"x".to_vec())),
+            (
+                "__watch/b",
+                MatchSeq::Any,
+                Operation::Update(b"b1".to_vec()),
+            ),
+            // seq mismatch, nothing changed
+            ("__watch/b", MatchSeq::Exact(100), Operation::Delete),
+            ("__watch/a", MatchSeq::Any, Operation::Delete),
+        ] {
+            client
+                .upsert_kv(UpsertKVAction::new(key, seq, value, None))
+                .await?;
+        }
+
+        {
+            // watch a key
+            let event = key_watcher.next().await.unwrap()?;
+            assert_eq!(Some("__watch/a".to_string()), event.ident);
+            assert_eq!((None, Some(b"a1".to_vec())), event.unpack_data());
+
+            let event = key_watcher.next().await.unwrap()?;
+            assert_eq!(Some("__watch/a".to_string()), event.ident);
+            assert_eq!((Some(b"a1".to_vec()), None), event.unpack_data());
+        }
+
+        {
+            // watch a prefix
+            let mut events = vec![];
+            for _ in 0..3 {
+                let event = prefix_watcher.next().await.unwrap()?;
+                events.push((event.ident.clone().unwrap(), event.unpack_data()));
+            }
+            assert_eq!(events, vec![
+                ("__watch/a".to_string(), (None, Some(b"a1".to_vec()))),
+                ("__watch/b".to_string(), (None, Some(b"b1".to_vec()))),
+                ("__watch/a".to_string(), (Some(b"a1".to_vec()), None)),
+            ]);
+        }
+
+        Ok(())
+    }
 }
 
 /// Test that write and read should be forwarded to leader
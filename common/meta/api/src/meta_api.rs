@@ -30,9 +30,13 @@ use common_meta_types::GetTableReq;
 use common_meta_types::ListDatabaseReq;
 use common_meta_types::ListTableReq;
 use common_meta_types::MetaId;
+use common_meta_types::RenameTableReply;
+use common_meta_types::RenameTableReq;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
+use common_meta_types::UpdateTableSchemaReply;
+use common_meta_types::UpdateTableSchemaReq;
 use common_meta_types::UpsertTableOptionReply;
 use common_meta_types::UpsertTableOptionReq;
 
@@ -54,6 +58,8 @@ pub trait MetaApi: Send + Sync {
 
     async fn drop_table(&self, req: DropTableReq) -> Result<DropTableReply>;
 
+    async fn rename_table(&self, req: RenameTableReq) -> Result<RenameTableReply>;
+
     async fn get_table(&self, req: GetTableReq) -> Result<Arc<TableInfo>>;
 
     async fn list_tables(&self, req: ListTableReq) -> Result<Vec<Arc<TableInfo>>>;
@@ -65,5 +71,10 @@ pub trait MetaApi: Send + Sync {
         req: UpsertTableOptionReq,
     ) -> Result<UpsertTableOptionReply>;
 
+    async fn update_table_schema(
+        &self,
+        req: UpdateTableSchemaReq,
+    ) -> Result<UpdateTableSchemaReply>;
+
     fn name(&self) -> String;
 }
@@ -27,9 +27,11 @@ use common_meta_types::GetDatabaseReq;
 use common_meta_types::GetTableReq;
 use common_meta_types::ListDatabaseReq;
 use common_meta_types::ListTableReq;
+use common_meta_types::RenameTableReq;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
+use common_meta_types::UpdateTableSchemaReq;
 use common_meta_types::UpsertTableOptionReq;
 use common_tracing::tracing;
 
@@ -430,6 +432,123 @@ impl MetaApiTestSuite {
 
         Ok(())
     }
+
+    pub async fn table_rename_and_update_schema<MT: MetaApi>(&self, mt: &MT) -> anyhow::Result<()> {
+        let db_name = "db1";
+
+        tracing::info!("--- prepare db and tables: tb1 tb2");
+        {
+            self.create_database(mt, db_name).await?;
+
+            let schema = Arc::new(DataSchema::new(vec![DataField::new(
+                "number",
+                DataType::UInt64,
+                false,
+            )]));
+
+            let mut req = CreateTableReq {
+                if_not_exists: false,
+                db: db_name.to_string(),
+                table: "tb1".to_string(),
+                table_meta: TableMeta {
+                    schema,
+                    engine: "JSON".to_string(),
+                    options: maplit::hashmap! {"opt-1".into() => "val-1".into()},
+                },
+            };
+            mt.create_table(req.clone()).await?;
+
+            req.table = "tb2".to_string();
+            mt.create_table(req).await?;
+        }
+
+        tracing::info!("--- update table schema");
+        {
+            let table = mt.get_table((db_name, "tb1").into()).await?;
+
+            let new_schema = Arc::new(DataSchema::new(vec![
+                DataField::new("number", DataType::UInt64, false),
+                DataField::new("name", DataType::String, true),
+            ]));
+            mt.update_table_schema(
+                UpdateTableSchemaReq::new(&table.ident, new_schema.clone())
+                    .with_option("opt-2", "val-2"),
+            )
+            .await?;
+
+            let got = mt.get_table((db_name, "tb1").into()).await?;
+            assert_eq!(new_schema, got.schema());
+            assert_eq!(got.options().get("opt-1"), Some(&"val-1".into()));
+            assert_eq!(got.options().get("opt-2"), Some(&"val-2".into()));
+            assert!(got.ident.version > table.ident.version);
+
+            tracing::info!("--- update table schema with a stale version");
+            {
+                let res = mt
+                    .update_table_schema(UpdateTableSchemaReq::new(&table.ident, table.schema()))
+                    .await;
+                let err = res.unwrap_err();
+                assert_eq!(ErrorCode::TableVersionMissMatch("").code(), err.code());
+
+                // table is not affected.
+                let got = mt.get_table((db_name, "tb1").into()).await?;
+                assert_eq!(new_schema, got.schema());
+            }
+        }
+
+        tracing::info!("--- rename table");
+        {
+            let table = mt.get_table((db_name, "tb1").into()).await?;
+
+            mt.rename_table(RenameTableReq {
+                db: db_name.to_string(),
+                table: "tb1".to_string(),
+                new_table: "tb3".to_string(),
+            })
+            .await?;
+
+            let res = mt.get_table((db_name, "tb1").into()).await;
+            assert_eq!(ErrorCode::UnknownTable("").code(), res.unwrap_err().code());
+
+            // renaming does not change the table version
+            let got = mt.get_table((db_name, "tb3").into()).await?;
+            assert_eq!(table.ident, got.ident);
+            assert_eq!(table.meta, got.meta);
+        }
+
+        tracing::info!("--- rename table to an existing name");
+        {
+            let res = mt
+                .rename_table(RenameTableReq {
+                    db: db_name.to_string(),
+                    table: "tb3".to_string(),
+                    new_table: "tb2".to_string(),
+                })
+                .await;
+            assert_eq!(
+                ErrorCode::TableAlreadyExists("").code(),
+                res.unwrap_err().code()
+            );
+
+            // both tables are not affected.
+            mt.get_table((db_name, "tb2").into()).await?;
+            mt.get_table((db_name, "tb3").into()).await?;
+        }
+
+        tracing::info!("--- rename an unknown table");
+        {
+            let res = mt
+                .rename_table(RenameTableReq {
+                    db: db_name.to_string(),
+                    table: "tb1".to_string(),
+                    new_table: "tb4".to_string(),
+                })
+                .await;
+            assert_eq!(ErrorCode::UnknownTable("").code(), res.unwrap_err().code());
+        }
+
+        Ok(())
+    }
 }
 
 impl MetaApiTestSuite {
@@ -15,12 +15,16 @@
 use async_trait::async_trait;
 use common_exception::Result;
 use common_meta_api::KVApi;
+use common_meta_api::WatchKVStream;
 pub use common_meta_sled_store::init_temp_sled_db;
 use common_meta_types::GetKVActionReply;
 use common_meta_types::MGetKVActionReply;
 use common_meta_types::PrefixListReply;
+use common_meta_types::TransactionReply;
+use common_meta_types::TransactionReq;
 use common_meta_types::UpsertKVAction;
 use common_meta_types::UpsertKVActionReply;
+use common_meta_types::WatchKVReq;
 
 use crate::MetaEmbedded;
 
@@ -48,4 +52,14 @@ impl KVApi for MetaEmbedded {
         let sm = self.inner.lock().await;
         sm.prefix_list_kv(prefix).await
     }
+
+    async fn transaction(&self, req: TransactionReq) -> Result<TransactionReply> {
+        let sm = self.inner.lock().await;
+        sm.transaction(req).await
+    }
+
+    async fn watch_kv(&self, req: WatchKVReq) -> Result<WatchKVStream> {
+        let sm = self.inner.lock().await;
+        sm.watch_kv(req).await
+    }
 }
@@ -31,9 +31,13 @@ use common_meta_types::GetTableReq;
 use common_meta_types::ListDatabaseReq;
 use common_meta_types::ListTableReq;
 use common_meta_types::MetaId;
+use common_meta_types::RenameTableReply;
+use common_meta_types::RenameTableReq;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
+use common_meta_types::UpdateTableSchemaReply;
+use common_meta_types::UpdateTableSchemaReq;
 use common_meta_types::UpsertTableOptionReply;
 use common_meta_types::UpsertTableOptionReq;
 
@@ -71,6 +75,11 @@ impl MetaApi for MetaEmbedded {
         sm.drop_table(req).await
     }
 
+    async fn rename_table(&self, req: RenameTableReq) -> Result<RenameTableReply> {
+        let sm = self.inner.lock().await;
+        sm.rename_table(req).await
+    }
+
     async fn get_table(&self, req: GetTableReq) -> Result<Arc<TableInfo>> {
         let sm = self.inner.lock().await;
         sm.get_table(req).await
@@ -94,6 +103,14 @@ impl MetaApi for MetaEmbedded {
         sm.upsert_table_option(req).await
     }
 
+    async fn update_table_schema(
+        &self,
+        req: UpdateTableSchemaReq,
+    ) -> Result<UpdateTableSchemaReply> {
+        let sm = self.inner.lock().await;
+        sm.update_table_schema(req).await
+    }
+
     fn name(&self) -> String {
         "meta-embedded".to_string()
     }
@@ -36,7 +36,13 @@ use common_meta_types::ListTableReq;
 use common_meta_types::MGetKVActionReply;
 use common_meta_types::MetaId;
 use common_meta_types::PrefixListReply;
+use common_meta_types::RenameTableReply;
+use common_meta_types::RenameTableReq;
 use common_meta_types::TableInfo;
+use common_meta_types::TransactionReply;
+use common_meta_types::TransactionReq;
+use common_meta_types::UpdateTableSchemaReply;
+use common_meta_types::UpdateTableSchemaReq;
 use common_meta_types::UpsertKVAction;
 use common_meta_types::UpsertKVActionReply;
 use common_meta_types::UpsertTableOptionReply;
@@ -60,15 +66,18 @@ pub enum MetaFlightAction {
 
     CreateTable(CreateTableReq),
     DropTable(DropTableReq),
+    RenameTable(RenameTableReq),
     GetTable(GetTableReq),
     GetTableExt(GetTableExtReq),
     ListTables(ListTableReq),
     CommitTable(UpsertTableOptionReq),
+    UpdateTableSchema(UpdateTableSchemaReq),
 
     UpsertKV(UpsertKVAction),
     GetKV(GetKVAction),
     MGetKV(MGetKVAction),
     PrefixListKV(PrefixListReq),
+    Transaction(TransactionReq),
 }
 
 /// Try convert tonic::Request<Action> to DoActionAction.
@@ -144,6 +153,10 @@ impl RequestFor for UpsertKVAction {
     type Reply = UpsertKVActionReply;
 }
 
+impl RequestFor for TransactionReq {
+    type Reply = TransactionReply;
+}
+
 // == database actions ==
 
 impl RequestFor for CreateDatabaseReq {
@@ -166,6 +179,10 @@ impl RequestFor for DropTableReq {
     type Reply = DropTableReply;
 }
 
+impl RequestFor for RenameTableReq {
+    type Reply = RenameTableReply;
+}
+
 impl RequestFor for GetTableReq {
     type Reply = Arc<TableInfo>;
 }
@@ -182,6 +199,10 @@ impl RequestFor for UpsertTableOptionReq {
     type Reply = UpsertTableOptionReply;
 }
 
+impl RequestFor for UpdateTableSchemaReq {
+    type Reply = UpdateTableSchemaReply;
+}
+
 impl RequestFor for ListTableReq {
     type Reply = Vec<Arc<TableInfo>>;
 }
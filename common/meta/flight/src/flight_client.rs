@@ -18,11 +18,15 @@ use std::time::Duration;
 use common_arrow::arrow_format::flight::data::Action;
 use common_arrow::arrow_format::flight::data::BasicAuth;
 use common_arrow::arrow_format::flight::data::HandshakeRequest;
+use common_arrow::arrow_format::flight::data::Ticket;
 use common_arrow::arrow_format::flight::service::flight_service_client::FlightServiceClient;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_flight_rpc::ConnectionFactory;
 use common_flight_rpc::FlightClientTlsConfig;
+use common_meta_api::WatchKVStream;
+use common_meta_types::WatchKVEvent;
+use common_meta_types::WatchKVReq;
 use common_tracing::tracing;
 use futures::stream;
 use futures::StreamExt;
@@ -142,6 +146,24 @@ impl MetaFlightClient {
             }
         }
     }
+
+    /// Subscribes to the changes of keys.
+    /// The request is sent as the ticket of `do_get`, and the changes are streamed back as flight data.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub(crate) async fn do_watch(&self, req: WatchKVReq) -> Result<WatchKVStream> {
+        let ticket = Ticket {
+            ticket: serde_json::to_vec(&req)?,
+        };
+        let req = common_tracing::inject_span_to_tonic_request(Request::new(ticket));
+
+        let stream = self.client.clone().do_get(req).await?.into_inner();
+        let stream = stream.map(|data| {
+            let data = data?;
+            let event = serde_json::from_slice::<WatchKVEvent>(&data.data_body)?;
+            Ok(event)
+        });
+        Ok(Box::pin(stream))
+    }
 }
 
 #[derive(Clone)]
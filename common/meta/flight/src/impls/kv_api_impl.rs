@@ -14,11 +14,15 @@
 
 use common_exception::Result;
 use common_meta_api::KVApi;
+use common_meta_api::WatchKVStream;
 use common_meta_types::GetKVActionReply;
 use common_meta_types::MGetKVActionReply;
 use common_meta_types::PrefixListReply;
+use common_meta_types::TransactionReply;
+use common_meta_types::TransactionReq;
 use common_meta_types::UpsertKVAction;
 use common_meta_types::UpsertKVActionReply;
+use common_meta_types::WatchKVReq;
 use common_tracing::tracing;
 
 use crate::GetKVAction;
@@ -54,4 +58,14 @@ impl KVApi for MetaFlightClient {
     async fn prefix_list_kv(&self, prefix: &str) -> common_exception::Result<PrefixListReply> {
         self.do_action(PrefixListReq(prefix.to_string())).await
     }
+
+    #[tracing::instrument(level = "debug", skip(self, req))]
+    async fn transaction(&self, req: TransactionReq) -> common_exception::Result<TransactionReply> {
+        self.do_action(req).await
+    }
+
+    #[tracing::instrument(level = "debug", skip(self))]
+    async fn watch_kv(&self, req: WatchKVReq) -> common_exception::Result<WatchKVStream> {
+        self.do_watch(req).await
+    }
 }
@@ -31,9 +31,13 @@ use common_meta_types::GetTableReq;
 use common_meta_types::ListDatabaseReq;
 use common_meta_types::ListTableReq;
 use common_meta_types::MetaId;
+use common_meta_types::RenameTableReply;
+use common_meta_types::RenameTableReq;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
+use common_meta_types::UpdateTableSchemaReply;
+use common_meta_types::UpdateTableSchemaReq;
 use common_meta_types::UpsertTableOptionReply;
 use common_meta_types::UpsertTableOptionReq;
 
@@ -72,6 +76,10 @@ impl MetaApi for MetaFlightClient {
         self.do_action(req).await
     }
 
+    async fn rename_table(&self, req: RenameTableReq) -> Result<RenameTableReply, ErrorCode> {
+        self.do_action(req).await
+    }
+
     async fn get_table(&self, req: GetTableReq) -> common_exception::Result<Arc<TableInfo>> {
         self.do_action(req).await
     }
@@ -98,6 +106,13 @@ impl MetaApi for MetaFlightClient {
         self.do_action(req).await
     }
 
+    async fn update_table_schema(
+        &self,
+        req: UpdateTableSchemaReq,
+    ) -> Result<UpdateTableSchemaReply, ErrorCode> {
+        self.do_action(req).await
+    }
+
     fn name(&self) -> String {
         "MetaFlightClient".to_string()
     }
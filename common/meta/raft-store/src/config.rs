@@ -25,6 +25,7 @@ pub const KVSRV_NO_SYNC: &str = "KVSRV_NO_SYNC";
 pub const KVSRV_SNAPSHOT_LOGS_SINCE_LAST: &str = "KVSRV_SNAPSHOT_LOGS_SINCE_LAST";
 pub const KVSRV_HEARTBEAT_INTERVAL: &str = "KVSRV_HEARTBEAT_INTERVAL";
 pub const KVSRV_INSTALL_SNAPSHOT_TIMEOUT: &str = "KVSRV_INSTALL_SNAPSHOT_TIMEOUT";
+pub const KVSRV_PURGE_EXPIRED_KV_INTERVAL: &str = "KVSRV_PURGE_EXPIRED_KV_INTERVAL";
 pub const KVSRV_BOOT: &str = "KVSRV_BOOT";
 pub const KVSRV_SINGLE: &str = "KVSRV_SINGLE";
 pub const KVSRV_ID: &str = "KVSRV_ID";
@@ -95,6 +96,15 @@ pub struct RaftConfig {
     )]
     pub install_snapshot_timeout: u64,
 
+    #[structopt(
+    long,
+    env = KVSRV_PURGE_EXPIRED_KV_INTERVAL,
+    default_value = "60000",
+    help = concat!("The interval in milli seconds at which a leader purges the expired entries of the generic kv.",
+    " 0 to disable purging.")
+    )]
+    pub purge_expired_kv_interval: u64,
+
     #[structopt(
         long,
         env = KVSRV_BOOT,
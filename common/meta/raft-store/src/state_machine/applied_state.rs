@@ -21,6 +21,7 @@ use common_meta_types::Change;
 use common_meta_types::DatabaseMeta;
 use common_meta_types::Node;
 use common_meta_types::TableMeta;
+use common_meta_types::TransactionReply;
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -48,6 +49,11 @@ pub enum AppliedState {
 
     KV(Change<Vec<u8>>),
 
+    Transaction(TransactionReply),
+
+    /// The removed expired entries, identified by the key.
+    PurgedKV(Vec<Change<Vec<u8>, String>>),
+
     #[try_into(ignore)]
     None,
 }
@@ -110,6 +116,8 @@ impl AppliedState {
             AppliedState::DatabaseMeta(ref ch) => ch.changed(),
             AppliedState::TableMeta(ref ch) => ch.changed(),
             AppliedState::KV(ref ch) => ch.changed(),
+            AppliedState::Transaction(ref reply) => reply.responses.iter().any(|ch| ch.changed()),
+            AppliedState::PurgedKV(ref purged) => !purged.is_empty(),
             AppliedState::None => false,
         }
     }
@@ -138,6 +146,10 @@ impl AppliedState {
             AppliedState::DatabaseMeta(Change { ref prev, .. }) => prev.is_none(),
             AppliedState::TableMeta(Change { ref prev, .. }) => prev.is_none(),
             AppliedState::KV(Change { ref prev, .. }) => prev.is_none(),
+            AppliedState::Transaction(ref reply) => {
+                reply.responses.iter().all(|ch| ch.prev.is_none())
+            }
+            AppliedState::PurgedKV(ref purged) => purged.is_empty(),
             AppliedState::None => true,
         }
     }
@@ -150,6 +162,10 @@ impl AppliedState {
             AppliedState::DatabaseMeta(Change { ref result, .. }) => result.is_none(),
             AppliedState::TableMeta(Change { ref result, .. }) => result.is_none(),
             AppliedState::KV(Change { ref result, .. }) => result.is_none(),
+            AppliedState::Transaction(ref reply) => {
+                reply.responses.iter().all(|ch| ch.result.is_none())
+            }
+            AppliedState::PurgedKV(_) => true,
             AppliedState::None => true,
         }
     }
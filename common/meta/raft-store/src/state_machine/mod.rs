@@ -22,6 +22,7 @@ pub use state_machine_meta::StateMachineMetaKey;
 pub use state_machine_meta::StateMachineMetaValue;
 pub use table_lookup::TableLookupKey;
 pub use table_lookup::TableLookupValue;
+pub use watcher::KVWatcher;
 
 pub mod applied_state;
 pub mod client_last_resp;
@@ -32,6 +33,7 @@ mod sm_meta_api_impl;
 pub mod snapshot;
 pub mod state_machine_meta;
 pub mod table_lookup;
+pub mod watcher;
 
 // will be accessed by other crate, can not cfg(test)
 pub mod testing;
@@ -45,6 +45,7 @@ use common_meta_types::NodeId;
 use common_meta_types::Operation;
 use common_meta_types::SeqV;
 use common_meta_types::TableMeta;
+use common_meta_types::TransactionReply;
 use common_tracing::tracing;
 use serde::Deserialize;
 use serde::Serialize;
@@ -63,6 +64,7 @@ use crate::sled_key_spaces::TableLookup;
 use crate::sled_key_spaces::Tables;
 use crate::state_machine::AppliedState;
 use crate::state_machine::ClientLastRespValue;
+use crate::state_machine::KVWatcher;
 use crate::state_machine::StateMachineMetaKey;
 use crate::state_machine::StateMachineMetaKey::Initialized;
 use crate::state_machine::StateMachineMetaKey::LastApplied;
@@ -101,6 +103,9 @@ pub struct StateMachine {
     /// - Store initialization state and last applied in keyspace `StateMachineMeta`.
     /// - Every other state is store in its own keyspace such as `Nodes`.
     pub sm_tree: SledTree,
+
+    /// Pushes the applied changes of the generic kv to the watchers.
+    pub kv_watcher: KVWatcher,
 }
 
 /// A key-value pair in a snapshot is a vec of two `Vec<u8>`.
@@ -156,6 +161,7 @@ impl StateMachine {
             _db: db,
 
             sm_tree,
+            kv_watcher: KVWatcher::default(),
         };
 
         let inited = {
@@ -253,6 +259,7 @@ impl StateMachine {
 
         let log_id = &entry.log_id;
 
+        // The second element tells if a command is applied, which is a change to be watched.
         let result = self.sm_tree.txn(true, move |txn_tree| {
             let txn_sm_meta = txn_tree.key_space::<StateMachineMeta>();
             txn_sm_meta.insert(&LastApplied, &StateMachineMetaValue::LogId(*log_id))?;
@@ -264,7 +271,7 @@ impl StateMachine {
                     if let Some(ref txid) = data.txid {
                         let (serial, resp) = self.txn_get_client_last_resp(&txid.client, &txn_tree);
                         if serial == txid.serial {
-                            return Ok(Some(resp));
+                            return Ok((Some(resp), false));
                         }
                     }
 
@@ -277,29 +284,60 @@ impl StateMachine {
                             &txn_tree,
                         );
                     }
-                    return Ok(Some(resp));
+                    return Ok((Some(resp), true));
                 }
                 EntryPayload::ConfigChange(ref mem) => {
                     txn_sm_meta.insert(
                         &LastMembership,
                         &StateMachineMetaValue::Membership(mem.membership.clone()),
                     )?;
-                    return Ok(Some(AppliedState::None));
+                    return Ok((Some(AppliedState::None), false));
                 }
                 EntryPayload::SnapshotPointer(_) => {}
             };
 
-            Ok(None)
+            Ok((None, false))
         })?;
 
+        let (result, applied) = result;
         let result = match result {
             Some(r) => r,
             None => AppliedState::None,
         };
 
+        if applied {
+            if let EntryPayload::Normal(ref norm) = entry.payload {
+                self.notify_watchers(&norm.data.cmd, &result);
+            }
+        }
+
         Ok(result)
     }
 
+    /// Pushes the change made by an applied `Cmd` to the kv watchers.
+    ///
+    /// It must be called after the transaction applying the `Cmd` is committed.
+    pub fn notify_watchers(&self, cmd: &Cmd, applied: &AppliedState) {
+        match (cmd, applied) {
+            (Cmd::UpsertKV { key, .. }, AppliedState::KV(change)) => {
+                self.kv_watcher.notify(key, change);
+            }
+            (
+                Cmd::Transaction(_),
+                AppliedState::Transaction(TransactionReply { responses, .. }),
+            )
+            | (Cmd::PurgeExpiredKV { .. }, AppliedState::PurgedKV(responses)) => {
+                for change in responses.iter() {
+                    if let Some(ref key) = change.ident {
+                        let change = Change::new(change.prev.clone(), change.result.clone());
+                        self.kv_watcher.notify(key, &change);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
     /// Apply a `Cmd` to state machine.
     ///
     /// Already applied log should be filtered out before passing into this function.
@@ -643,6 +681,86 @@ impl StateMachine {
                 Ok(Change::new(prev, result).into())
             }
 
+            Cmd::Transaction(ref req) => {
+                let sub_tree = txn_tree.key_space::<GenericKV>();
+
+                let mut success = true;
+                for cond in req.conditions.iter() {
+                    let prev = sub_tree.get(&cond.key).map_err(|e| {
+                        let e: ConflictableTransactionError<Infallible> = e.into();
+                        ErrorCode::from(e)
+                    })?;
+
+                    // If prev is timed out, treat it as a None.
+                    let prev = Self::unexpired_opt(prev);
+                    if cond.seq.match_seq(&prev).is_err() {
+                        success = false;
+                        break;
+                    }
+                }
+
+                let ops = if success {
+                    &req.if_then
+                } else {
+                    &req.else_then
+                };
+
+                let mut responses = Vec::with_capacity(ops.len());
+                for op in ops.iter() {
+                    let (prev, result) = self
+                        .sub_txn_tree_upsert(
+                            &sub_tree,
+                            &op.key,
+                            &MatchSeq::Any,
+                            op.value.clone(),
+                            op.value_meta.clone(),
+                        )
+                        .map_err(|e| {
+                            let e: ConflictableTransactionError<Infallible> = e.into();
+                            ErrorCode::from(e)
+                        })?;
+                    responses.push(Change::new_with_id(op.key.clone(), prev, result));
+                }
+
+                tracing::debug!("applied Transaction: success: {} {:?}", success, responses);
+                Ok(TransactionReply { success, responses }.into())
+            }
+
+            Cmd::PurgeExpiredKV { now } => {
+                // A transactional tree can not be iterated.
+                // The candidates are collected from the tree, which is the same on every raft node
+                // when this log is applied, and are checked again in the transaction.
+                let mut candidates = vec![];
+                for kv in self.kvs().range(..)? {
+                    let (key, seq_value) = kv?;
+                    if seq_value.get_expire_at() < *now {
+                        candidates.push(key);
+                    }
+                }
+
+                let sub_tree = txn_tree.key_space::<GenericKV>();
+                let mut purged = Vec::with_capacity(candidates.len());
+                for key in candidates.into_iter() {
+                    let prev = sub_tree.get(&key).map_err(|e| {
+                        let e: ConflictableTransactionError<Infallible> = e.into();
+                        ErrorCode::from(e)
+                    })?;
+
+                    if let Some(prev) = prev {
+                        if prev.get_expire_at() < *now {
+                            sub_tree.remove(&key).map_err(|e| {
+                                let e: ConflictableTransactionError<Infallible> = e.into();
+                                ErrorCode::from(e)
+                            })?;
+                            purged.push(Change::new_with_id(key, Some(prev), None));
+                        }
+                    }
+                }
+
+                tracing::debug!("applied PurgeExpiredKV: {} {:?}", now, purged);
+                Ok(AppliedState::PurgedKV(purged))
+            }
+
             Cmd::UpsertTableOptions(ref req) => {
                 let table_tree = txn_tree.key_space::<Tables>();
                 let prev = table_tree.get(&req.table_id).map_err(|e| {
@@ -696,6 +814,167 @@ impl StateMachine {
                     Some(sv),
                 )))
             }
+
+            Cmd::UpdateTableSchema(ref req) => {
+                let table_tree = txn_tree.key_space::<Tables>();
+                let prev = table_tree.get(&req.table_id).map_err(|e| {
+                    let e: ConflictableTransactionError<Infallible> = e.into();
+                    ErrorCode::from(e)
+                })?;
+
+                let prev = prev.ok_or_else(|| {
+                    ErrorCode::UnknownTableId(format!("table_id:{}", req.table_id))
+                })?;
+
+                if req.seq.match_seq(&prev).is_err() {
+                    let res = AppliedState::TableMeta(Change::new(Some(prev.clone()), Some(prev)));
+                    return Ok(res);
+                }
+
+                let meta = prev.meta.clone();
+                let mut table_meta = prev.data.clone();
+                table_meta.schema = req.schema.clone();
+                let opts = &mut table_meta.options;
+
+                for (k, opt_v) in &req.options {
+                    match opt_v {
+                        None => {
+                            opts.remove(k);
+                        }
+                        Some(v) => {
+                            opts.insert(k.to_string(), v.to_string());
+                        }
+                    }
+                }
+
+                let new_seq = self.txn_incr_seq(Tables::NAME, txn_tree).map_err(|e| {
+                    let e: ConflictableTransactionError<Infallible> = e.into();
+                    ErrorCode::from(e)
+                })?;
+                let sv = SeqV {
+                    seq: new_seq,
+                    meta,
+                    data: table_meta,
+                };
+
+                table_tree.insert(&req.table_id, &sv).map_err(|e| {
+                    let e: ConflictableTransactionError<Infallible> = e.into();
+                    ErrorCode::from(e)
+                })?;
+
+                Ok(AppliedState::TableMeta(Change::new_with_id(
+                    req.table_id,
+                    Some(prev),
+                    Some(sv),
+                )))
+            }
+
+            Cmd::RenameTable {
+                ref db_name,
+                ref table_name,
+                ref new_table_name,
+            } => {
+                let db_id = self.txn_get_database_id(db_name, txn_tree).map_err(|e| {
+                    let e: ConflictableTransactionError<Infallible> = e.into();
+                    ErrorCode::from(e)
+                })?;
+
+                let db_id = match db_id {
+                    Some(id) => id,
+                    None => return Ok(Change::<TableMeta>::new(None, None).into()),
+                };
+
+                let lookup_key = TableLookupKey {
+                    database_id: db_id,
+                    table_name: table_name.to_string(),
+                };
+                let new_lookup_key = TableLookupKey {
+                    database_id: db_id,
+                    table_name: new_table_name.to_string(),
+                };
+
+                let table_lookup_tree = txn_tree.key_space::<TableLookup>();
+                let seq_table_id = table_lookup_tree.get(&lookup_key).map_err(|e| {
+                    let e: ConflictableTransactionError<Infallible> = e.into();
+                    ErrorCode::from(e)
+                })?;
+
+                let table_id = match seq_table_id {
+                    Some(u) => u.data.0,
+                    None => return Ok(Change::<TableMeta>::new(None, None).into()),
+                };
+
+                // The returned Change describes the entry at the new name:
+                // an existing table there is returned unchanged.
+                let seq_new_table_id = table_lookup_tree.get(&new_lookup_key).map_err(|e| {
+                    let e: ConflictableTransactionError<Infallible> = e.into();
+                    ErrorCode::from(e)
+                })?;
+
+                if let Some(u) = seq_new_table_id {
+                    let existing_id = u.data.0;
+                    let existing = self
+                        .txn_get_table_meta_by_id(&existing_id, txn_tree)
+                        .map_err(|e| {
+                            let e: ConflictableTransactionError<Infallible> = e.into();
+                            ErrorCode::from(e)
+                        })?;
+
+                    return Ok(AppliedState::TableMeta(Change::nochange_with_id(
+                        existing_id,
+                        existing,
+                    )));
+                }
+
+                self.sub_txn_tree_upsert(
+                    &table_lookup_tree,
+                    &lookup_key,
+                    &MatchSeq::Any,
+                    Operation::Delete,
+                    None,
+                )
+                .map_err(|e| {
+                    let e: ConflictableTransactionError<Infallible> = e.into();
+                    ErrorCode::from(e)
+                })?;
+
+                self.sub_txn_tree_upsert(
+                    &table_lookup_tree,
+                    &new_lookup_key,
+                    &MatchSeq::Exact(0),
+                    Operation::Update(TableLookupValue(table_id)),
+                    None,
+                )
+                .map_err(|e| {
+                    let e: ConflictableTransactionError<Infallible> = e.into();
+                    ErrorCode::from(e)
+                })?;
+
+                // Renaming does not change the table itself, thus the table version is kept.
+                let result = self
+                    .txn_get_table_meta_by_id(&table_id, txn_tree)
+                    .map_err(|e| {
+                        let e: ConflictableTransactionError<Infallible> = e.into();
+                        ErrorCode::from(e)
+                    })?;
+
+                self.txn_incr_seq(SEQ_DATABASE_META_ID, txn_tree)
+                    .map_err(|e| {
+                        let e: ConflictableTransactionError<Infallible> = e.into();
+                        ErrorCode::from(e)
+                    })?;
+
+                tracing::debug!(
+                    "applied rename Table: {} => {} {:?}",
+                    table_name,
+                    new_table_name,
+                    result
+                );
+
+                Ok(AppliedState::TableMeta(Change::new_with_id(
+                    table_id, None, result,
+                )))
+            }
         }
     }
 
@@ -1009,18 +1288,14 @@ impl StateMachine {
     pub fn unexpired<V: Debug>(seq_value: SeqV<V>) -> Option<SeqV<V>> {
         // TODO(xp): log must be assigned with a ts.
 
-        // TODO(xp): background task to clean expired
-
-        // TODO(xp): Caveat: The cleanup must be consistent across raft nodes:
-        //           A conditional update, e.g. an upsert_kv() with MatchSeq::Eq(some_value),
+        // TODO(xp): Caveat: A conditional update, e.g. an upsert_kv() with MatchSeq::Eq(some_value),
         //           must be applied with the same timestamp on every raft node.
         //           Otherwise: node-1 could have applied a log with a ts that is smaller than value.expire_at,
         //           while node-2 may fail to apply the same log if it use a greater ts > value.expire_at.
-        //           Thus:
-        //           1. A raft log must have a field ts assigned by the leader. When applying, use this ts to
-        //              check against expire_at to decide whether to purge it.
-        //           2. A GET operation must not purge any expired entry. Since a GET is only applied to a node itself.
-        //           3. The background task can only be triggered by the raft leader, by submit a "clean expired" log.
+
+        // NOTE: An expired entry is only treated as absent here, a GET operation never purges it.
+        //       Expired entries are removed by the `Cmd::PurgeExpiredKV` log submitted by the raft leader,
+        //       which carries a ts assigned by the leader, thus the cleanup is consistent across raft nodes.
 
         // TODO(xp): maybe it needs a expiration queue for efficient cleaning up.
 
@@ -13,13 +13,18 @@
 // limitations under the License.
 
 use common_meta_api::KVApi;
+use common_meta_api::WatchKVStream;
 use common_meta_types::Cmd;
 use common_meta_types::GetKVActionReply;
 use common_meta_types::MGetKVActionReply;
 use common_meta_types::SeqV;
+use common_meta_types::TransactionReply;
+use common_meta_types::TransactionReq;
 use common_meta_types::UpsertKVAction;
 use common_meta_types::UpsertKVActionReply;
+use common_meta_types::WatchKVReq;
 use common_tracing::tracing;
+use futures::StreamExt;
 
 use crate::state_machine::AppliedState;
 use crate::state_machine::StateMachine;
@@ -41,6 +46,7 @@ impl KVApi for StateMachine {
             let r = self.apply_cmd(&cmd, &t).unwrap();
             Ok(r)
         })?;
+        self.notify_watchers(&cmd, &res);
 
         match res {
             AppliedState::KV(x) => Ok(x),
@@ -92,4 +98,26 @@ impl KVApi for StateMachine {
 
         Ok(x.collect())
     }
+
+    async fn transaction(&self, req: TransactionReq) -> common_exception::Result<TransactionReply> {
+        let cmd = Cmd::Transaction(req);
+
+        let res = self.sm_tree.txn(true, |t| {
+            let r = self.apply_cmd(&cmd, &t).unwrap();
+            Ok(r)
+        })?;
+        self.notify_watchers(&cmd, &res);
+
+        match res {
+            AppliedState::Transaction(x) => Ok(x),
+            _ => {
+                panic!("expect AppliedState::Transaction");
+            }
+        }
+    }
+
+    async fn watch_kv(&self, req: WatchKVReq) -> common_exception::Result<WatchKVStream> {
+        let rx = self.kv_watcher.subscribe(req);
+        Ok(Box::pin(rx.map(Ok)))
+    }
 }
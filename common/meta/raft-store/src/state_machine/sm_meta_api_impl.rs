@@ -34,9 +34,13 @@ use common_meta_types::GetTableReq;
 use common_meta_types::ListDatabaseReq;
 use common_meta_types::ListTableReq;
 use common_meta_types::MetaId;
+use common_meta_types::RenameTableReply;
+use common_meta_types::RenameTableReq;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
+use common_meta_types::UpdateTableSchemaReply;
+use common_meta_types::UpdateTableSchemaReq;
 use common_meta_types::UpsertTableOptionReply;
 use common_meta_types::UpsertTableOptionReq;
 use common_tracing::tracing;
@@ -195,6 +199,38 @@ impl MetaApi for StateMachine {
         Ok(DropTableReply {})
     }
 
+    async fn rename_table(&self, req: RenameTableReq) -> Result<RenameTableReply, ErrorCode> {
+        let cmd = Cmd::RenameTable {
+            db_name: req.db.clone(),
+            table_name: req.table.clone(),
+            new_table_name: req.new_table.clone(),
+        };
+
+        let res = self.sm_tree.txn(true, |t| {
+            let r = self.apply_cmd(&cmd, &t).unwrap();
+            Ok(r)
+        })?;
+
+        let ch: Change<TableMeta> = res.try_into().unwrap();
+        let (prev, result) = ch.unpack();
+
+        if result.is_none() {
+            return Err(ErrorCode::UnknownTable(format!(
+                "Unknown table: '{:}'",
+                req.table
+            )));
+        }
+
+        if prev.is_some() {
+            return Err(ErrorCode::TableAlreadyExists(format!(
+                "table exists: {}",
+                req.new_table
+            )));
+        }
+
+        Ok(RenameTableReply {})
+    }
+
     async fn get_table(&self, req: GetTableReq) -> Result<Arc<TableInfo>, ErrorCode> {
         let db = &req.db_name;
         let table_name = &req.table_name;
@@ -303,6 +339,29 @@ impl MetaApi for StateMachine {
         Ok(UpsertTableOptionReply {})
     }
 
+    async fn update_table_schema(
+        &self,
+        req: UpdateTableSchemaReq,
+    ) -> Result<UpdateTableSchemaReply, ErrorCode> {
+        let cmd = Cmd::UpdateTableSchema(req.clone());
+
+        let res = self.sm_tree.txn(true, |t| {
+            let r = self.apply_cmd(&cmd, &t).unwrap();
+            Ok(r)
+        })?;
+        if !res.changed() {
+            let ch: Change<TableMeta> = res.try_into().unwrap();
+            let (prev, _result) = ch.unwrap();
+
+            return Err(ErrorCode::TableVersionMissMatch(format!(
+                "targeting version {:?}, current version {}",
+                req.seq, prev.seq,
+            )));
+        }
+
+        Ok(UpdateTableSchemaReply {})
+    }
+
     fn name(&self) -> String {
         "StateMachine".to_string()
     }
@@ -0,0 +1,82 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_infallible::Mutex;
+use common_meta_types::Change;
+use common_meta_types::WatchKVEvent;
+use common_meta_types::WatchKVReq;
+use common_tracing::tracing;
+use futures::channel::mpsc;
+
+struct Subscriber {
+    req: WatchKVReq,
+    tx: mpsc::UnboundedSender<WatchKVEvent>,
+}
+
+/// Dispatches the changes of the generic kv to the subscribers, after they are applied to the state machine.
+///
+/// A subscriber is removed once its receiving end is dropped.
+/// It is shared by the state machines that replace each other, e.g., when installing a snapshot.
+#[derive(Clone, Default)]
+pub struct KVWatcher {
+    subscribers: Arc<Mutex<Vec<Subscriber>>>,
+}
+
+impl KVWatcher {
+    pub fn subscribe(&self, req: WatchKVReq) -> mpsc::UnboundedReceiver<WatchKVEvent> {
+        let (tx, rx) = mpsc::unbounded();
+        let mut subscribers = self.subscribers.lock();
+        subscribers.push(Subscriber { req, tx });
+        rx
+    }
+
+    /// Pushes a change of `key` to the subscribers watching it. Nothing is pushed if nothing is changed.
+    pub fn notify(&self, key: &str, change: &Change<Vec<u8>>) {
+        if !change.changed() {
+            return;
+        }
+
+        let mut subscribers = self.subscribers.lock();
+        subscribers.retain(|subscriber| {
+            if subscriber.tx.is_closed() {
+                return false;
+            }
+            if !subscriber.req.is_watching(key) {
+                return true;
+            }
+
+            let event =
+                Change::new_with_id(key.to_string(), change.prev.clone(), change.result.clone());
+            if let Err(e) = subscriber.tx.unbounded_send(event) {
+                tracing::debug!("remove closed kv watcher {:?}: {}", subscriber.req, e);
+                return false;
+            }
+            true
+        });
+    }
+
+    pub fn subscriber_count(&self) -> usize {
+        self.subscribers.lock().len()
+    }
+}
+
+impl std::fmt::Debug for KVWatcher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KVWatcher")
+            .field("subscribers", &self.subscriber_count())
+            .finish()
+    }
+}
@@ -614,6 +614,87 @@ async fn test_state_machine_apply_non_dup_generic_kv_value_meta() -> anyhow::Res
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_state_machine_apply_purge_expired_kv() -> anyhow::Result<()> {
+    // - Records expired before `now` are removed.
+    // - Records not yet expired or without expire_at are kept.
+
+    let (_log_guards, ut_span) = init_raft_store_ut!();
+    let _ent = ut_span.enter();
+
+    let tc = new_raft_test_context();
+    let sm = StateMachine::open(&tc.raft_config, 1).await?;
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+
+    let cases = vec![
+        ("purge_a", Some(now - 10)),
+        ("purge_b", Some(now + 1000)),
+        ("purge_c", None),
+        ("purge_d", Some(now - 5)),
+    ];
+
+    for (key, expire_at) in cases.iter() {
+        sm.sm_tree.txn(true, |t| {
+            Ok(sm
+                .apply_cmd(
+                    &Cmd::UpsertKV {
+                        key: key.to_string(),
+                        seq: MatchSeq::Any,
+                        value: Operation::Update(key.as_bytes().to_vec()),
+                        value_meta: expire_at.map(|x| KVMeta { expire_at: Some(x) }),
+                    },
+                    &t,
+                )
+                .unwrap())
+        })?;
+    }
+
+    tracing::info!("--- purge with a ts before any expiration does nothing");
+
+    let resp = sm.sm_tree.txn(true, |t| {
+        Ok(sm
+            .apply_cmd(&Cmd::PurgeExpiredKV { now: now - 20 }, &t)
+            .unwrap())
+    })?;
+    assert_eq!(AppliedState::PurgedKV(vec![]), resp);
+
+    tracing::info!("--- purge expired records");
+
+    let resp = sm.sm_tree.txn(true, |t| {
+        Ok(sm.apply_cmd(&Cmd::PurgeExpiredKV { now }, &t).unwrap())
+    })?;
+
+    let purged = match resp {
+        AppliedState::PurgedKV(purged) => purged,
+        _ => panic!("expect PurgedKV, got: {:?}", resp),
+    };
+
+    let keys = purged
+        .iter()
+        .map(|c| c.ident.clone().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(vec!["purge_a".to_string(), "purge_d".to_string()], keys);
+
+    for c in purged.iter() {
+        assert!(c.prev.is_some());
+        assert!(c.result.is_none());
+    }
+
+    tracing::info!("--- check records in the underlying tree");
+
+    let kvs = sm.kvs();
+    assert!(kvs.get(&"purge_a".to_string())?.is_none());
+    assert!(kvs.get(&"purge_b".to_string())?.is_some());
+    assert!(kvs.get(&"purge_c".to_string())?.is_some());
+    assert!(kvs.get(&"purge_d".to_string())?.is_none());
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_state_machine_apply_non_dup_generic_kv_delete() -> anyhow::Result<()> {
     let (_log_guards, ut_span) = init_raft_store_ut!();
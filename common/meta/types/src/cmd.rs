@@ -23,6 +23,8 @@ use crate::MatchSeq;
 use crate::Node;
 use crate::Operation;
 use crate::TableMeta;
+use crate::TransactionReq;
+use crate::UpdateTableSchemaReq;
 use crate::UpsertTableOptionReq;
 
 /// A Cmd describes what a user want to do to raft state machine
@@ -61,6 +63,22 @@ pub enum Cmd {
     /// Otherwise it returns the TableMeta before and after update.
     UpsertTableOptions(UpsertTableOptionReq),
 
+    /// Replace the schema of a table, and update, remove or insert table options in the same operation.
+    ///
+    /// Like `UpsertTableOptions`, it requires a present table and a matching seq,
+    /// and returns the TableMeta before and after update.
+    UpdateTableSchema(UpdateTableSchemaReq),
+
+    /// Rename a table in a database.
+    ///
+    /// The table id and version are unchanged, only the name lookup entry is moved.
+    /// It fails if the target name already exists.
+    RenameTable {
+        db_name: String,
+        table_name: String,
+        new_table_name: String,
+    },
+
     /// Update or insert a general purpose kv store
     UpsertKV {
         key: String,
@@ -77,6 +95,19 @@ pub enum Cmd {
         /// Meta data of a value.
         value_meta: Option<KVMeta>,
     },
+
+    /// Update several keys of the general purpose kv store atomically.
+    ///
+    /// The operations in `if_then` are applied if all the conditions are met,
+    /// otherwise the operations in `else_then` are applied.
+    Transaction(TransactionReq),
+
+    /// Remove the entries of the general purpose kv store that are expired before `now`.
+    ///
+    /// It is submitted periodically by the raft leader.
+    /// `now` is the timestamp in seconds assigned by the leader,
+    /// so that every raft node removes the same entries when applying it.
+    PurgeExpiredKV { now: u64 },
 }
 
 impl fmt::Display for Cmd {
@@ -119,6 +150,16 @@ impl fmt::Display for Cmd {
                     key, seq, value, value_meta
                 )
             }
+            Cmd::Transaction(req) => {
+                write!(
+                    f,
+                    "transaction: conditions: {:?}, if_then: {:?}, else_then: {:?}",
+                    req.conditions, req.if_then, req.else_then
+                )
+            }
+            Cmd::PurgeExpiredKV { now } => {
+                write!(f, "purge_expired_kv: now: {}", now)
+            }
             Cmd::UpsertTableOptions(req) => {
                 write!(
                     f,
@@ -126,6 +167,24 @@ impl fmt::Display for Cmd {
                     req.table_id, req.seq, req.options
                 )
             }
+            Cmd::UpdateTableSchema(req) => {
+                write!(
+                    f,
+                    "update-table-schema: table-id:{}({:?}) = {}, options: {:?}",
+                    req.table_id, req.seq, req.schema, req.options
+                )
+            }
+            Cmd::RenameTable {
+                db_name,
+                table_name,
+                new_table_name,
+            } => {
+                write!(
+                    f,
+                    "rename_table:{}-{}=>{}",
+                    db_name, table_name, new_table_name
+                )
+            }
         }
     }
 }
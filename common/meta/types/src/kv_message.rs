@@ -38,6 +38,26 @@ pub type GetKVActionReply = Option<SeqV<Vec<u8>>>;
 pub type MGetKVActionReply = Vec<Option<SeqV<Vec<u8>>>>;
 pub type PrefixListReply = Vec<(String, SeqV<Vec<u8>>)>;
 
+/// A change of a key, identified by the key, pushed to the watchers of the key.
+pub type WatchKVEvent = Change<Vec<u8>, String>;
+
+/// Subscribes to the changes of a single key or of all the keys with a prefix.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub enum WatchKVReq {
+    Key(String),
+    Prefix(String),
+}
+
+impl WatchKVReq {
+    /// Returns true if the changes of `key` should be pushed to the watcher.
+    pub fn is_watching(&self, key: &str) -> bool {
+        match self {
+            WatchKVReq::Key(k) => k == key,
+            WatchKVReq::Prefix(prefix) => key.starts_with(prefix.as_str()),
+        }
+    }
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
 pub struct UpsertKVAction {
     pub key: String,
@@ -61,3 +81,57 @@ impl UpsertKVAction {
         }
     }
 }
+
+/// A condition of a transaction: the current seq of `key` matches `seq`.
+///
+/// An absent or expired key has no seq, e.g., it matches `MatchSeq::Exact(0)`.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct TxnCondition {
+    pub key: String,
+    pub seq: MatchSeq,
+}
+
+impl TxnCondition {
+    pub fn new(key: &str, seq: MatchSeq) -> Self {
+        Self {
+            key: key.to_string(),
+            seq,
+        }
+    }
+}
+
+/// An operation of a transaction: updates or deletes `key`, regardless of its seq.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct TxnOp {
+    pub key: String,
+    pub value: Operation<Vec<u8>>,
+    pub value_meta: Option<KVMeta>,
+}
+
+impl TxnOp {
+    pub fn new(key: &str, value: Operation<Vec<u8>>, value_meta: Option<KVMeta>) -> Self {
+        Self {
+            key: key.to_string(),
+            value,
+            value_meta,
+        }
+    }
+}
+
+/// Updates several keys atomically:
+/// if all of the `conditions` are met, `if_then` is applied, otherwise `else_then` is applied.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Default)]
+pub struct TransactionReq {
+    pub conditions: Vec<TxnCondition>,
+    pub if_then: Vec<TxnOp>,
+    pub else_then: Vec<TxnOp>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct TransactionReply {
+    /// Whether all of the conditions are met, i.e., `if_then` is applied.
+    pub success: bool,
+
+    /// The change made by every applied operation, identified by the key, in the order of the operations.
+    pub responses: Vec<Change<Vec<u8>, String>>,
+}
@@ -25,6 +25,7 @@ mod match_seq;
 mod operation;
 mod raft_txid;
 mod raft_types;
+mod role_info;
 mod seq_num;
 mod seq_value;
 mod table;
@@ -58,8 +59,14 @@ pub use kv_message::ListKVReq;
 pub use kv_message::MGetKVActionReply;
 pub use kv_message::MGetKVReq;
 pub use kv_message::PrefixListReply;
+pub use kv_message::TransactionReply;
+pub use kv_message::TransactionReq;
+pub use kv_message::TxnCondition;
+pub use kv_message::TxnOp;
 pub use kv_message::UpsertKVAction;
 pub use kv_message::UpsertKVActionReply;
+pub use kv_message::WatchKVEvent;
+pub use kv_message::WatchKVReq;
 pub use log_entry::LogEntry;
 pub use match_seq::MatchSeq;
 pub use match_seq::MatchSeqExt;
@@ -71,6 +78,7 @@ pub use raft_types::LogId;
 pub use raft_types::LogIndex;
 pub use raft_types::NodeId;
 pub use raft_types::Term;
+pub use role_info::RoleInfo;
 pub use seq_num::SeqNum;
 pub use seq_value::IntoSeqV;
 pub use seq_value::KVMeta;
@@ -81,16 +89,21 @@ pub use table::DropTableReply;
 pub use table::DropTableReq;
 pub use table::GetTableReq;
 pub use table::ListTableReq;
+pub use table::RenameTableReply;
+pub use table::RenameTableReq;
 pub use table::TableIdent;
 pub use table::TableInfo;
 pub use table::TableMeta;
 pub use table::TableNameIndent;
+pub use table::UpdateTableSchemaReply;
+pub use table::UpdateTableSchemaReq;
 pub use table::UpsertTableOptionReply;
 pub use table::UpsertTableOptionReq;
 pub use user_auth::AuthType;
 pub use user_grant::GrantEntry;
 pub use user_grant::GrantObject;
 pub use user_grant::UserGrantSet;
+pub use user_identity::PrincipalIdentity;
 pub use user_identity::UserIdentity;
 pub use user_info::UserInfo;
 pub use user_privilege::UserPrivilegeSet;
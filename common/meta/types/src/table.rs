@@ -223,6 +223,51 @@ impl UpsertTableOptionReq {
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
 pub struct UpsertTableOptionReply {}
 
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct UpdateTableSchemaReq {
+    pub table_id: u64,
+    pub seq: MatchSeq,
+
+    /// The schema that replaces the current one.
+    pub schema: Arc<DataSchema>,
+
+    /// Options to add or remove along with the schema change,
+    /// e.g. the location of the data rewritten in the new schema.
+    ///
+    /// Some(String): add or update an option.
+    /// None: delete an option.
+    pub options: HashMap<String, Option<String>>,
+}
+
+impl UpdateTableSchemaReq {
+    pub fn new(table_ident: &TableIdent, schema: Arc<DataSchema>) -> UpdateTableSchemaReq {
+        UpdateTableSchemaReq {
+            table_id: table_ident.table_id,
+            seq: MatchSeq::Exact(table_ident.version),
+            schema,
+            options: HashMap::new(),
+        }
+    }
+
+    pub fn with_option(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.options.insert(key.into(), Some(value.into()));
+        self
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct UpdateTableSchemaReply {}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct RenameTableReq {
+    pub db: String,
+    pub table: String,
+    pub new_table: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct RenameTableReply {}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
 pub struct GetTableReq {
     pub inner: TableNameIndent,
@@ -14,6 +14,7 @@
 
 use std::fmt;
 
+use enumflags2::make_bitflags;
 use enumflags2::BitFlags;
 
 use crate::UserPrivilegeSet;
@@ -59,6 +60,14 @@ impl GrantEntry {
         }
     }
 
+    pub fn object(&self) -> &GrantObject {
+        &self.object
+    }
+
+    pub fn privileges(&self) -> &BitFlags<UserPrivilegeType> {
+        &self.privileges
+    }
+
     pub fn verify_global_privilege(
         &self,
         user: &str,
@@ -151,14 +160,66 @@ impl fmt::Display for GrantEntry {
     }
 }
 
-#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+// The version of the stored grants, bump it when ALL comes to cover new privileges.
+const USER_GRANT_SET_VERSION: u64 = 1;
+
+// ALL before the DROP and GRANT privileges were added.
+const LEGACY_ALL_PRIVILEGES: BitFlags<UserPrivilegeType> = make_bitflags!(
+    UserPrivilegeType::{Create
+        | Select
+        | Insert
+        | Set}
+);
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+#[serde(from = "StoredUserGrantSet")]
 pub struct UserGrantSet {
     grants: Vec<GrantEntry>,
+    version: u64,
+}
+
+// The grant set as stored, the grants stored without a version are migrated on load.
+#[derive(serde::Deserialize)]
+struct StoredUserGrantSet {
+    #[serde(default)]
+    grants: Vec<GrantEntry>,
+    #[serde(default)]
+    version: u64,
+}
+
+impl From<StoredUserGrantSet> for UserGrantSet {
+    fn from(stored: StoredUserGrantSet) -> Self {
+        let mut grants = stored.grants;
+        if stored.version < 1 {
+            // The legacy ALL covered every privilege of its time, keep it covering DROP and GRANT.
+            let all_privileges: BitFlags<UserPrivilegeType> =
+                UserPrivilegeSet::all_privileges().into();
+            for grant in grants.iter_mut() {
+                if grant.privileges.contains(LEGACY_ALL_PRIVILEGES) {
+                    grant.privileges |= all_privileges;
+                }
+            }
+        }
+
+        UserGrantSet {
+            grants,
+            version: USER_GRANT_SET_VERSION,
+        }
+    }
+}
+
+impl Default for UserGrantSet {
+    fn default() -> Self {
+        Self::empty()
+    }
 }
 
 impl UserGrantSet {
     pub fn empty() -> Self {
-        Self { grants: vec![] }
+        Self {
+            grants: vec![],
+            version: USER_GRANT_SET_VERSION,
+        }
     }
 
     pub fn entries(&self) -> &[GrantEntry] {
@@ -12,8 +12,26 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::fmt;
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
 pub struct UserIdentity {
     pub username: String,
     pub hostname: String,
 }
+
+/// The principal that privileges are granted to or revoked from.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub enum PrincipalIdentity {
+    User(UserIdentity),
+    Role(String),
+}
+
+impl fmt::Display for PrincipalIdentity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match self {
+            PrincipalIdentity::User(u) => write!(f, "'{}'@'{}'", u.username, u.hostname),
+            PrincipalIdentity::Role(r) => write!(f, "ROLE '{}'", r),
+        }
+    }
+}
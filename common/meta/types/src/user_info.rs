@@ -41,6 +41,10 @@ pub struct UserInfo {
 
     #[serde(default)]
     pub quota: UserQuota,
+
+    // The names of the roles granted to the user.
+    #[serde(default)]
+    pub roles: Vec<String>,
 }
 
 impl UserInfo {
@@ -56,6 +60,7 @@ impl UserInfo {
             auth_type,
             grants,
             quota,
+            roles: vec![],
         }
     }
 }
@@ -33,13 +33,19 @@ pub enum UserPrivilegeType {
     Insert = 1 << 3,
     // Privilege to SET variables.
     Set = 1 << 4,
+    // Privilege to drop databases and tables.
+    Drop = 1 << 5,
+    // Privilege to manage users and their grants.
+    Grant = 1 << 6,
 }
 
 const ALL_PRIVILEGES: BitFlags<UserPrivilegeType> = make_bitflags!(
     UserPrivilegeType::{Create
         | Select
         | Insert
-        | Set}
+        | Set
+        | Drop
+        | Grant}
 );
 
 impl std::fmt::Display for UserPrivilegeType {
@@ -50,6 +56,8 @@ impl std::fmt::Display for UserPrivilegeType {
             UserPrivilegeType::Select => "SELECT",
             UserPrivilegeType::Insert => "INSERT",
             UserPrivilegeType::Set => "SET",
+            UserPrivilegeType::Drop => "DROP",
+            UserPrivilegeType::Grant => "GRANT",
         })
     }
 }
@@ -102,3 +102,30 @@ fn test_user_grant_set() -> Result<()> {
     assert!(grants.verify_table_privilege("u1", "h3", "db1", "table1", UserPrivilegeType::Select));
     Ok(())
 }
+
+#[test]
+fn test_user_grant_set_migrate_legacy_all() -> Result<()> {
+    // Stored before the grants were versioned: ALL on *.* and SELECT on db1.*.
+    let legacy = r#"{"grants":[
+        {"user":"u1","host_pattern":"%","object":"Global","privileges":30},
+        {"user":"u1","host_pattern":"%","object":{"Database":"db1"},"privileges":4}
+    ]}"#;
+    let grants: UserGrantSet = serde_json::from_str(legacy)?;
+    assert!(grants.verify_global_privilege("u1", "h1", UserPrivilegeType::Drop));
+    assert!(grants.verify_global_privilege("u1", "h1", UserPrivilegeType::Grant));
+    assert!(!grants.entries()[1]
+        .privileges()
+        .contains(UserPrivilegeType::Drop));
+
+    // The migrated grants are stored with the version and not migrated again.
+    let mut grants = UserGrantSet::empty();
+    grants.grant_privileges(
+        "u1",
+        "%",
+        &GrantObject::Global,
+        make_bitflags!(UserPrivilegeType::{Create | Select | Insert | Set}).into(),
+    );
+    let grants: UserGrantSet = serde_json::from_str(&serde_json::to_string(&grants)?)?;
+    assert!(!grants.verify_global_privilege("u1", "h1", UserPrivilegeType::Drop));
+    Ok(())
+}
@@ -14,11 +14,13 @@
 
 mod plan_aggregator_final;
 mod plan_aggregator_partial;
+mod plan_alter_table;
 mod plan_broadcast;
 mod plan_builder;
 mod plan_copy;
 mod plan_database_create;
 mod plan_database_drop;
+mod plan_delete;
 mod plan_describe_stage;
 mod plan_describe_table;
 mod plan_display;
@@ -41,6 +43,7 @@ mod plan_filter;
 mod plan_grant_privilege;
 mod plan_having;
 mod plan_insert_into;
+mod plan_join;
 mod plan_kill;
 mod plan_limit;
 mod plan_limit_by;
@@ -51,6 +54,11 @@ mod plan_read_datasource;
 mod plan_remote;
 mod plan_revoke_privilege;
 mod plan_rewriter;
+mod plan_role_create;
+mod plan_role_drop;
+mod plan_role_grant;
+mod plan_role_revoke;
+mod plan_role_set;
 mod plan_select;
 mod plan_setting;
 mod plan_show_grants;
@@ -63,21 +71,29 @@ mod plan_subqueries_set;
 mod plan_table_create;
 mod plan_table_drop;
 mod plan_truncate_table;
+mod plan_update;
 mod plan_use_database;
 mod plan_user_alter;
 mod plan_user_create;
 mod plan_user_drop;
 mod plan_user_stage_create;
+mod plan_vacuum_table;
+mod plan_view_create;
+mod plan_view_drop;
 mod plan_visitor;
+mod plan_window;
 
 pub use plan_aggregator_final::AggregatorFinalPlan;
 pub use plan_aggregator_partial::AggregatorPartialPlan;
+pub use plan_alter_table::AlterTableOperation;
+pub use plan_alter_table::AlterTablePlan;
 pub use plan_broadcast::BroadcastPlan;
 pub use plan_builder::PlanBuilder;
 pub use plan_copy::CopyPlan;
 pub use plan_database_create::CreateDatabasePlan;
 pub use plan_database_create::DatabaseOptions;
 pub use plan_database_drop::DropDatabasePlan;
+pub use plan_delete::DeletePlan;
 pub use plan_describe_stage::DescribeStagePlan;
 pub use plan_describe_table::DescribeTablePlan;
 pub use plan_empty::EmptyPlan;
@@ -95,7 +111,10 @@ pub use plan_expression_common::expr_as_column_expr;
 pub use plan_expression_common::extract_aliases;
 pub use plan_expression_common::find_aggregate_exprs;
 pub use plan_expression_common::find_aggregate_exprs_in_expr;
+pub use plan_expression_common::find_array_join_exprs_in_expr;
 pub use plan_expression_common::find_columns_not_satisfy_exprs;
+pub use plan_expression_common::find_window_exprs;
+pub use plan_expression_common::find_window_exprs_in_expr;
 pub use plan_expression_common::rebase_expr;
 pub use plan_expression_common::rebase_expr_from_input;
 pub use plan_expression_common::resolve_aliases_to_exprs;
@@ -119,6 +138,8 @@ pub use plan_grant_privilege::GrantPrivilegePlan;
 pub use plan_having::HavingPlan;
 pub use plan_insert_into::InsertInputSource;
 pub use plan_insert_into::InsertPlan;
+pub use plan_join::JoinPlan;
+pub use plan_join::JoinType;
 pub use plan_kill::KillPlan;
 pub use plan_limit::LimitPlan;
 pub use plan_limit_by::LimitByPlan;
@@ -131,6 +152,11 @@ pub use plan_remote::RemotePlan;
 pub use plan_revoke_privilege::RevokePrivilegePlan;
 pub use plan_rewriter::PlanRewriter;
 pub use plan_rewriter::RewriteHelper;
+pub use plan_role_create::CreateRolePlan;
+pub use plan_role_drop::DropRolePlan;
+pub use plan_role_grant::GrantRolePlan;
+pub use plan_role_revoke::RevokeRolePlan;
+pub use plan_role_set::SetRolePlan;
 pub use plan_select::SelectPlan;
 pub use plan_setting::SettingPlan;
 pub use plan_setting::VarValue;
@@ -147,9 +173,20 @@ pub use plan_table_create::CreateTablePlan;
 pub use plan_table_create::TableOptions;
 pub use plan_table_drop::DropTablePlan;
 pub use plan_truncate_table::TruncateTablePlan;
+pub use plan_update::UpdatePlan;
 pub use plan_use_database::UseDatabasePlan;
 pub use plan_user_alter::AlterUserPlan;
 pub use plan_user_create::CreateUserPlan;
 pub use plan_user_drop::DropUserPlan;
 pub use plan_user_stage_create::CreateUserStagePlan;
+pub use plan_vacuum_table::VacuumTablePlan;
+pub use plan_vacuum_table::DEFAULT_VACUUM_RETAIN_HOURS;
+pub use plan_view_create::CreateViewPlan;
+pub use plan_view_drop::DropViewPlan;
 pub use plan_visitor::PlanVisitor;
+pub use plan_window::is_window_function;
+pub use plan_window::WindowFrame;
+pub use plan_window::WindowFrameBound;
+pub use plan_window::WindowFrameUnits;
+pub use plan_window::WindowPlan;
+pub use plan_window::WINDOW_FUNCTIONS;
@@ -20,6 +20,7 @@ use common_datavalues::DataSchema;
 use common_datavalues::DataSchemaRef;
 use common_datavalues::DataSchemaRefExt;
 use common_datavalues::DataType;
+use common_exception::ErrorCode;
 use common_exception::Result;
 
 use crate::col;
@@ -34,6 +35,8 @@ use crate::Expression;
 use crate::ExpressionPlan;
 use crate::FilterPlan;
 use crate::HavingPlan;
+use crate::JoinPlan;
+use crate::JoinType;
 use crate::LimitByPlan;
 use crate::LimitPlan;
 use crate::PlanNode;
@@ -41,6 +44,7 @@ use crate::ProjectionPlan;
 use crate::RewriteHelper;
 use crate::SelectPlan;
 use crate::SortPlan;
+use crate::WindowPlan;
 
 pub enum AggregateMode {
     Partial,
@@ -218,6 +222,41 @@ impl PlanBuilder {
         })))
     }
 
+    /// Apply a join, self is the left input and `right` is the right input.
+    pub fn join(
+        &self,
+        right: &PlanNode,
+        join_type: JoinType,
+        left_keys: &[Expression],
+        right_keys: &[Expression],
+        other_condition: Option<Expression>,
+    ) -> Result<Self> {
+        if left_keys.len() != right_keys.len() {
+            return Err(ErrorCode::LogicalError(
+                "Join keys of both sides must have the same length",
+            ));
+        }
+
+        for expr in left_keys.iter().chain(right_keys.iter()) {
+            validate_expression(expr)?;
+        }
+
+        if let Some(expr) = &other_condition {
+            validate_expression(expr)?;
+        }
+
+        let schema = JoinPlan::create_schema(join_type, &self.plan.schema(), &right.schema());
+        Ok(Self::from(&PlanNode::Join(JoinPlan {
+            join_type,
+            left_keys: left_keys.to_vec(),
+            right_keys: right_keys.to_vec(),
+            other_condition,
+            left: Arc::new(self.plan.clone()),
+            right: Arc::new(right.clone()),
+            schema,
+        })))
+    }
+
     pub fn sort(&self, exprs: &[Expression]) -> Result<Self> {
         Ok(Self::from(&PlanNode::Sort(SortPlan {
             order_by: exprs.to_vec(),
@@ -226,6 +265,21 @@ impl PlanBuilder {
         })))
     }
 
+    /// Apply a window function and append its result to the fields.
+    pub fn window(&self, window_func: Expression) -> Result<Self> {
+        validate_expression(&window_func)?;
+
+        let input_schema = self.plan.schema();
+        let mut fields = input_schema.fields().clone();
+        fields.push(window_func.to_data_field(&input_schema)?);
+
+        Ok(Self::from(&PlanNode::Window(WindowPlan {
+            window_func,
+            schema: DataSchemaRefExt::create(fields),
+            input: Arc::new(self.plan.clone()),
+        })))
+    }
+
     /// Apply a limit
     pub fn limit(&self, n: usize) -> Result<Self> {
         Ok(Self::from(&PlanNode::Limit(LimitPlan {
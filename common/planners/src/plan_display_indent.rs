@@ -24,6 +24,7 @@ use crate::DropDatabasePlan;
 use crate::DropTablePlan;
 use crate::Expression;
 use crate::ExpressionPlan;
+use crate::JoinPlan;
 use crate::LimitPlan;
 use crate::PlanNode;
 use crate::ProjectionPlan;
@@ -63,7 +64,9 @@ impl<'a> fmt::Display for PlanNodeIndentFormatDisplay<'a> {
             PlanNode::AggregatorFinal(plan) => Self::format_aggregator_final(f, plan),
             PlanNode::Filter(plan) => write!(f, "Filter: {:?}", plan.predicate),
             PlanNode::Having(plan) => write!(f, "Having: {:?}", plan.predicate),
+            PlanNode::Join(plan) => Self::format_join(f, plan),
             PlanNode::Sort(plan) => Self::format_sort(f, plan),
+            PlanNode::Window(plan) => write!(f, "Window: {:?}", plan.window_func),
             PlanNode::Limit(plan) => Self::format_limit(f, plan),
             PlanNode::SubQueryExpression(plan) => Self::format_subquery_expr(f, plan),
             PlanNode::ReadSource(plan) => Self::format_read_source(f, plan),
@@ -191,6 +194,19 @@ impl<'a> PlanNodeIndentFormatDisplay<'a> {
         }
     }
 
+    fn format_join(f: &mut Formatter, plan: &JoinPlan) -> fmt::Result {
+        write!(f, "Join: {}", plan.join_type)?;
+        if !plan.left_keys.is_empty() {
+            write!(f, ", keys=[{:?}] = [{:?}]", plan.left_keys, plan.right_keys)?;
+        }
+
+        if let Some(condition) = &plan.other_condition {
+            write!(f, ", condition=[{:?}]", condition)?;
+        }
+
+        fmt::Result::Ok(())
+    }
+
     fn format_subquery_expr(f: &mut Formatter, plan: &SubQueriesSetPlan) -> fmt::Result {
         let mut names = Vec::with_capacity(plan.expressions.len());
         for expression in &plan.expressions {
@@ -27,7 +27,9 @@ use common_functions::aggregates::AggregateFunctionRef;
 use common_functions::scalars::FunctionFactory;
 use lazy_static::lazy_static;
 
+use crate::is_window_function;
 use crate::PlanNode;
+use crate::WindowFrame;
 
 lazy_static! {
     static ref OP_SET: HashSet<&'static str> = ["database", "version", "current_user"]
@@ -96,6 +98,18 @@ pub enum Expression {
         args: Vec<Expression>,
     },
 
+    /// WindowFunction evaluated over the rows of a partition, such as
+    /// `rank() OVER (PARTITION BY a ORDER BY b)`.
+    WindowFunction {
+        op: String,
+        params: Vec<DataValue>,
+        args: Vec<Expression>,
+        partition_by: Vec<Expression>,
+        /// `Expression::Sort` expressions
+        order_by: Vec<Expression>,
+        window_frame: Option<WindowFrame>,
+    },
+
     /// A sort expression, that can be used to sort values.
     Sort {
         /// The expression to sort on
@@ -211,6 +225,7 @@ impl Expression {
                     false => format!("{}({})", prefix, args_column_name.join(", ")),
                 }
             }
+            Expression::WindowFunction { .. } => format!("{:?}", self),
             Expression::Sort { expr, .. } => expr.column_name(),
             Expression::Cast { expr, data_type } => {
                 format!("cast({} as {:?})", expr.column_name(), data_type)
@@ -265,6 +280,15 @@ impl Expression {
                 let f = self.to_aggregate_function(input_schema)?;
                 f.nullable(input_schema)
             }
+            Expression::WindowFunction { op, .. } => match op.to_lowercase().as_str() {
+                "row_number" | "rank" | "dense_rank" => Ok(false),
+                // The offset of lag/lead or the frame may fall outside of the partition.
+                "lag" | "lead" | "first_value" | "last_value" => Ok(true),
+                _ => {
+                    let f = self.to_aggregate_function(input_schema)?;
+                    f.nullable(input_schema)
+                }
+            },
             Expression::Wildcard => Result::Err(ErrorCode::IllegalDataType(
                 "Wildcard expressions are not valid to get return nullable",
             )),
@@ -343,6 +367,20 @@ impl Expression {
                 let func = self.to_aggregate_function(input_schema)?;
                 func.return_type()
             }
+            Expression::WindowFunction { op, args, .. } => match op.to_lowercase().as_str() {
+                "row_number" | "rank" | "dense_rank" => Ok(DataType::UInt64),
+                "lag" | "lead" | "first_value" | "last_value" => match args.first() {
+                    Some(arg) => arg.to_data_type(input_schema),
+                    None => Err(ErrorCode::BadArguments(format!(
+                        "Window function {} expects at least one argument",
+                        op
+                    ))),
+                },
+                _ => {
+                    let func = self.to_aggregate_function(input_schema)?;
+                    func.return_type()
+                }
+            },
             Expression::Wildcard => Result::Err(ErrorCode::IllegalDataType(
                 "Wildcard expressions are not valid to get return type",
             )),
@@ -370,6 +408,15 @@ impl Expression {
                 }
                 AggregateFunctionFactory::instance().get(&func_name, params.clone(), fields)
             }
+            Expression::WindowFunction {
+                op, params, args, ..
+            } if !is_window_function(op) => {
+                let mut fields = Vec::with_capacity(args.len());
+                for arg in args.iter() {
+                    fields.push(arg.to_data_field(schema)?);
+                }
+                AggregateFunctionFactory::instance().get(op, params.clone(), fields)
+            }
             _ => Err(ErrorCode::LogicalError(
                 "Expression must be aggregated function",
             )),
@@ -465,6 +512,56 @@ impl fmt::Debug for Expression {
                 Ok(())
             }
 
+            Expression::WindowFunction {
+                op,
+                params,
+                args,
+                partition_by,
+                order_by,
+                window_frame,
+            } => {
+                let args_column_name = args.iter().map(Expression::column_name).collect::<Vec<_>>();
+                let params_name = params
+                    .iter()
+                    .map(|v| DataValue::custom_display(v, true))
+                    .collect::<Vec<_>>();
+
+                if params.is_empty() {
+                    write!(f, "{}", op)?;
+                } else {
+                    write!(f, "{}({})", op, params_name.join(", "))?;
+                };
+                write!(f, "({}) OVER (", args_column_name.join(", "))?;
+
+                let mut clauses = vec![];
+                if !partition_by.is_empty() {
+                    let partition_by_name = partition_by
+                        .iter()
+                        .map(Expression::column_name)
+                        .collect::<Vec<_>>();
+                    clauses.push(format!("PARTITION BY {}", partition_by_name.join(", ")));
+                }
+
+                if !order_by.is_empty() {
+                    let order_by_name = order_by
+                        .iter()
+                        .map(|expr| match expr {
+                            Expression::Sort { expr, asc, .. } => match asc {
+                                true => expr.column_name(),
+                                false => format!("{} DESC", expr.column_name()),
+                            },
+                            _ => expr.column_name(),
+                        })
+                        .collect::<Vec<_>>();
+                    clauses.push(format!("ORDER BY {}", order_by_name.join(", ")));
+                }
+
+                if let Some(window_frame) = window_frame {
+                    clauses.push(window_frame.to_string());
+                }
+
+                write!(f, "{})", clauses.join(" "))
+            }
             Expression::Sort { expr, .. } => write!(f, "{:?}", expr),
             Expression::Wildcard => write!(f, "*"),
             Expression::Cast { expr, data_type } => {
@@ -95,6 +95,14 @@ impl ActionFunction {
         }
     }
 
+    /// Whether the function expands the rows by the elements of its array, as arrayJoin.
+    pub fn is_array_join(&self) -> bool {
+        !self.is_aggregated
+            && FunctionFactory::instance()
+                .get_features(&self.func_name)
+                .map_or(false, |features| features.is_array_join_func)
+    }
+
     pub fn to_aggregate_function(&self) -> Result<AggregateFunctionRef> {
         if !self.is_aggregated {
             return Err(ErrorCode::LogicalError(
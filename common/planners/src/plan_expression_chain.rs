@@ -195,6 +195,12 @@ impl ExpressionChain {
 
                 self.actions.push(ExpressionAction::Function(function));
             }
+            Expression::WindowFunction { .. } => {
+                return Err(ErrorCode::LogicalError(format!(
+                    "Window function {:?} must be evaluated by a WindowPlan",
+                    expr
+                )));
+            }
             Expression::Sort { expr, .. } => {
                 self.add_expr(expr)?;
             }
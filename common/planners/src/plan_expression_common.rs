@@ -17,6 +17,7 @@ use std::collections::HashMap;
 use common_datavalues::DataSchemaRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_functions::scalars::FunctionFactory;
 
 use crate::Expression;
 use crate::ExpressionVisitor;
@@ -49,6 +50,30 @@ pub fn find_aggregate_exprs_in_expr(expr: &Expression) -> Vec<Expression> {
     })
 }
 
+/// Collect all deeply nested `Expression::WindowFunction`. They are returned in order of
+/// occurrence (depth first), with duplicates omitted.
+pub fn find_window_exprs(exprs: &[Expression]) -> Vec<Expression> {
+    find_exprs_in_exprs(exprs, &|nest_exprs| {
+        matches!(nest_exprs, Expression::WindowFunction { .. })
+    })
+}
+
+pub fn find_window_exprs_in_expr(expr: &Expression) -> Vec<Expression> {
+    find_exprs_in_expr(expr, &|nest_exprs| {
+        matches!(nest_exprs, Expression::WindowFunction { .. })
+    })
+}
+
+/// Collect all deeply nested scalar functions that expand the rows, as `arrayJoin`.
+pub fn find_array_join_exprs_in_expr(expr: &Expression) -> Vec<Expression> {
+    find_exprs_in_expr(expr, &|nest_exprs| match nest_exprs {
+        Expression::ScalarFunction { op, .. } => FunctionFactory::instance()
+            .get_features(op)
+            .map_or(false, |features| features.is_array_join_func),
+        _ => false,
+    })
+}
+
 /// Collect all arguments from aggregation function and append to this exprs
 /// [ColumnExpr(b), Aggr(sum(a, b))] ---> [ColumnExpr(b), ColumnExpr(a)]
 
@@ -303,6 +328,31 @@ where F: Fn(&Expression) -> Result<Option<Expression>> {
                     .collect::<Result<Vec<Expression>>>()?,
             }),
 
+            Expression::WindowFunction {
+                op,
+                params,
+                args,
+                partition_by,
+                order_by,
+                window_frame,
+            } => Ok(Expression::WindowFunction {
+                op: op.clone(),
+                params: params.clone(),
+                args: args
+                    .iter()
+                    .map(|e| clone_with_replacement(e, replacement_fn))
+                    .collect::<Result<Vec<Expression>>>()?,
+                partition_by: partition_by
+                    .iter()
+                    .map(|e| clone_with_replacement(e, replacement_fn))
+                    .collect::<Result<Vec<Expression>>>()?,
+                order_by: order_by
+                    .iter()
+                    .map(|e| clone_with_replacement(e, replacement_fn))
+                    .collect::<Result<Vec<Expression>>>()?,
+                window_frame: *window_frame,
+            }),
+
             Expression::Sort {
                 expr: nested_expr,
                 asc,
@@ -106,6 +106,30 @@ impl Expression {
                     args: new_args,
                 }
             }
+            Expression::WindowFunction {
+                op,
+                params,
+                args,
+                partition_by,
+                order_by,
+                window_frame,
+            } => {
+                let rewrite_exprs = |exprs: Vec<Expression>, rewriter: &mut R| {
+                    exprs
+                        .into_iter()
+                        .map(|expr| expr.rewrite(rewriter))
+                        .collect::<Result<Vec<_>>>()
+                };
+
+                Expression::WindowFunction {
+                    op,
+                    params,
+                    args: rewrite_exprs(args, rewriter)?,
+                    partition_by: rewrite_exprs(partition_by, rewriter)?,
+                    order_by: rewrite_exprs(order_by, rewriter)?,
+                    window_frame,
+                }
+            }
             Expression::Cast { expr, data_type } => {
                 let expr = expr.rewrite(rewriter)?;
                 Expression::Cast {
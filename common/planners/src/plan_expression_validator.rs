@@ -86,6 +86,39 @@ fn validate_function_arg(func: Box<dyn Function>, args: &[Expression]) -> Result
     }
 }
 
+fn validate_window_function_arg(op: &str, args: &[Expression]) -> Result<()> {
+    let (start, end) = match op.to_lowercase().as_str() {
+        "row_number" | "rank" | "dense_rank" => (0, 0),
+        "lag" | "lead" => (1, 3),
+        "first_value" | "last_value" => (1, 1),
+        // Aggregate functions are validated by AggregateFunctionFactory
+        _ => return Ok(()),
+    };
+
+    if args.len() < start || args.len() > end {
+        return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+            "{} expect to have [{}, {}] arguments, but got {}",
+            op,
+            start,
+            end,
+            args.len()
+        )));
+    }
+
+    // The offset and the default value of lag/lead must be constant
+    match args
+        .iter()
+        .skip(1)
+        .find(|arg| !matches!(arg, Expression::Literal { .. }))
+    {
+        None => Ok(()),
+        Some(arg) => Err(ErrorCode::BadArguments(format!(
+            "{} expect constant offset and default value, but got {:?}",
+            op, arg
+        ))),
+    }
+}
+
 // Can works before expression,filter,having in PlanBuilder
 pub fn validate_expression(expr: &Expression) -> Result<()> {
     let validator = ExpressionValidator::new(&|expr: &Expression| match expr {
@@ -93,6 +126,7 @@ pub fn validate_expression(expr: &Expression) -> Result<()> {
             let func = FunctionFactory::instance().get(op)?;
             validate_function_arg(func, args)
         }
+        Expression::WindowFunction { op, args, .. } => validate_window_function_arg(op, args),
 
         // Currently no need to check  UnaryExpression and BinaryExpression
         // todo: AggregateFunction validation after generic AggregateFunctions
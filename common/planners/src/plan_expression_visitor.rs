@@ -62,6 +62,18 @@ pub trait ExpressionVisitor: Sized {
                 }
                 Ok(visitor)
             }
+            Expression::WindowFunction {
+                args,
+                partition_by,
+                order_by,
+                ..
+            } => {
+                let mut visitor = self;
+                for arg in args.iter().chain(partition_by).chain(order_by) {
+                    visitor = arg.accept(visitor)?;
+                }
+                Ok(visitor)
+            }
             Expression::Cast { expr, .. } => expr.accept(self),
             Expression::Sort { expr, .. } => expr.accept(self),
             _ => Ok(self),
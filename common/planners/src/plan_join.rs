@@ -0,0 +1,118 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use common_datavalues::DataField;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataSchemaRefExt;
+
+use crate::Expression;
+use crate::PlanNode;
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq)]
+pub enum JoinType {
+    Inner,
+    Left,
+    Right,
+    Full,
+    Cross,
+}
+
+impl JoinType {
+    /// The unmatched rows of the left side are kept in the output.
+    pub fn preserves_left(&self) -> bool {
+        matches!(self, JoinType::Left | JoinType::Full)
+    }
+
+    /// The unmatched rows of the right side are kept in the output.
+    pub fn preserves_right(&self) -> bool {
+        matches!(self, JoinType::Right | JoinType::Full)
+    }
+}
+
+impl fmt::Display for JoinType {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            JoinType::Inner => write!(f, "INNER"),
+            JoinType::Left => write!(f, "LEFT OUTER"),
+            JoinType::Right => write!(f, "RIGHT OUTER"),
+            JoinType::Full => write!(f, "FULL OUTER"),
+            JoinType::Cross => write!(f, "CROSS"),
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
+pub struct JoinPlan {
+    pub join_type: JoinType,
+    /// The equi-join keys evaluated on the left input.
+    pub left_keys: Vec<Expression>,
+    /// The equi-join keys evaluated on the right input, paired with left_keys.
+    pub right_keys: Vec<Expression>,
+    /// The non-equi part of the join condition, evaluated on the joined row.
+    pub other_condition: Option<Expression>,
+    pub left: Arc<PlanNode>,
+    pub right: Arc<PlanNode>,
+    /// Output schema: left fields followed by right fields.
+    pub schema: DataSchemaRef,
+}
+
+impl JoinPlan {
+    pub fn create_schema(
+        join_type: JoinType,
+        left: &DataSchemaRef,
+        right: &DataSchemaRef,
+    ) -> DataSchemaRef {
+        let mut fields = Vec::with_capacity(left.fields().len() + right.fields().len());
+        let left_nullable = join_type.preserves_right();
+        let right_nullable = join_type.preserves_left();
+
+        for field in left.fields() {
+            let nullable = field.is_nullable() || left_nullable;
+            fields.push(DataField::new(
+                field.name(),
+                field.data_type().clone(),
+                nullable,
+            ));
+        }
+
+        for field in right.fields() {
+            let nullable = field.is_nullable() || right_nullable;
+            fields.push(DataField::new(
+                field.name(),
+                field.data_type().clone(),
+                nullable,
+            ));
+        }
+
+        DataSchemaRefExt::create(fields)
+    }
+
+    pub fn schema(&self) -> DataSchemaRef {
+        self.schema.clone()
+    }
+
+    pub fn get_inputs(&self) -> Vec<Arc<PlanNode>> {
+        vec![self.left.clone(), self.right.clone()]
+    }
+
+    pub fn set_inputs(&mut self, inputs: Vec<&PlanNode>) {
+        assert_eq!(inputs.len(), 2);
+        self.left = Arc::new(inputs[0].clone());
+        self.right = Arc::new(inputs[1].clone());
+    }
+}
@@ -21,23 +21,31 @@ use crate::plan_subqueries_set::SubQueriesSetPlan;
 use crate::plan_user_stage_create::CreateUserStagePlan;
 use crate::AggregatorFinalPlan;
 use crate::AggregatorPartialPlan;
+use crate::AlterTablePlan;
 use crate::AlterUserPlan;
 use crate::CopyPlan;
 use crate::CreateDatabasePlan;
+use crate::CreateRolePlan;
 use crate::CreateTablePlan;
 use crate::CreateUserPlan;
+use crate::CreateViewPlan;
+use crate::DeletePlan;
 use crate::DescribeStagePlan;
 use crate::DescribeTablePlan;
 use crate::DropDatabasePlan;
+use crate::DropRolePlan;
 use crate::DropTablePlan;
 use crate::DropUserPlan;
+use crate::DropViewPlan;
 use crate::EmptyPlan;
 use crate::ExplainPlan;
 use crate::ExpressionPlan;
 use crate::FilterPlan;
 use crate::GrantPrivilegePlan;
+use crate::GrantRolePlan;
 use crate::HavingPlan;
 use crate::InsertPlan;
+use crate::JoinPlan;
 use crate::KillPlan;
 use crate::LimitByPlan;
 use crate::LimitPlan;
@@ -45,7 +53,9 @@ use crate::ProjectionPlan;
 use crate::ReadDataSourcePlan;
 use crate::RemotePlan;
 use crate::RevokePrivilegePlan;
+use crate::RevokeRolePlan;
 use crate::SelectPlan;
+use crate::SetRolePlan;
 use crate::SettingPlan;
 use crate::ShowCreateTablePlan;
 use crate::ShowGrantsPlan;
@@ -53,7 +63,10 @@ use crate::SinkPlan;
 use crate::SortPlan;
 use crate::StagePlan;
 use crate::TruncateTablePlan;
+use crate::UpdatePlan;
 use crate::UseDatabasePlan;
+use crate::VacuumTablePlan;
+use crate::WindowPlan;
 
 #[allow(clippy::large_enum_variant)]
 #[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
@@ -67,8 +80,10 @@ pub enum PlanNode {
     AggregatorPartial(AggregatorPartialPlan),
     AggregatorFinal(AggregatorFinalPlan),
     Filter(FilterPlan),
+    Join(JoinPlan),
     Having(HavingPlan),
     Sort(SortPlan),
+    Window(WindowPlan),
     Limit(LimitPlan),
     LimitBy(LimitByPlan),
     ReadSource(ReadDataSourcePlan),
@@ -81,7 +96,13 @@ pub enum PlanNode {
     DescribeTable(DescribeTablePlan),
     DescribeStage(DescribeStagePlan),
     DropTable(DropTablePlan),
+    AlterTable(AlterTablePlan),
+    CreateView(CreateViewPlan),
+    DropView(DropViewPlan),
     TruncateTable(TruncateTablePlan),
+    Delete(DeletePlan),
+    Update(UpdatePlan),
+    VacuumTable(VacuumTablePlan),
     UseDatabase(UseDatabasePlan),
     SetVariable(SettingPlan),
     Insert(InsertPlan),
@@ -96,6 +117,11 @@ pub enum PlanNode {
     RevokePrivilege(RevokePrivilegePlan),
     CreateUserStage(CreateUserStagePlan),
     ShowGrants(ShowGrantsPlan),
+    CreateRole(CreateRolePlan),
+    DropRole(DropRolePlan),
+    GrantRole(GrantRolePlan),
+    RevokeRole(RevokeRolePlan),
+    SetRole(SetRolePlan),
 }
 
 impl PlanNode {
@@ -111,6 +137,7 @@ impl PlanNode {
             PlanNode::AggregatorPartial(v) => v.schema(),
             PlanNode::AggregatorFinal(v) => v.schema(),
             PlanNode::Filter(v) => v.schema(),
+            PlanNode::Join(v) => v.schema(),
             PlanNode::Having(v) => v.schema(),
             PlanNode::Limit(v) => v.schema(),
             PlanNode::LimitBy(v) => v.schema(),
@@ -123,9 +150,16 @@ impl PlanNode {
             PlanNode::DropTable(v) => v.schema(),
             PlanNode::DescribeTable(v) => v.schema(),
             PlanNode::DescribeStage(v) => v.schema(),
+            PlanNode::AlterTable(v) => v.schema(),
+            PlanNode::CreateView(v) => v.schema(),
+            PlanNode::DropView(v) => v.schema(),
             PlanNode::TruncateTable(v) => v.schema(),
+            PlanNode::Delete(v) => v.schema(),
+            PlanNode::Update(v) => v.schema(),
+            PlanNode::VacuumTable(v) => v.schema(),
             PlanNode::SetVariable(v) => v.schema(),
             PlanNode::Sort(v) => v.schema(),
+            PlanNode::Window(v) => v.schema(),
             PlanNode::UseDatabase(v) => v.schema(),
             PlanNode::Insert(v) => v.schema(),
             PlanNode::ShowCreateTable(v) => v.schema(),
@@ -140,6 +174,11 @@ impl PlanNode {
             PlanNode::Copy(v) => v.schema(),
             PlanNode::CreateUserStage(v) => v.schema(),
             PlanNode::ShowGrants(v) => v.schema(),
+            PlanNode::CreateRole(v) => v.schema(),
+            PlanNode::DropRole(v) => v.schema(),
+            PlanNode::GrantRole(v) => v.schema(),
+            PlanNode::RevokeRole(v) => v.schema(),
+            PlanNode::SetRole(v) => v.schema(),
         }
     }
 
@@ -154,6 +193,7 @@ impl PlanNode {
             PlanNode::AggregatorPartial(_) => "AggregatorPartialPlan",
             PlanNode::AggregatorFinal(_) => "AggregatorFinalPlan",
             PlanNode::Filter(_) => "FilterPlan",
+            PlanNode::Join(_) => "JoinPlan",
             PlanNode::Having(_) => "HavingPlan",
             PlanNode::Limit(_) => "LimitPlan",
             PlanNode::LimitBy(_) => "LimitByPlan",
@@ -166,9 +206,16 @@ impl PlanNode {
             PlanNode::DescribeTable(_) => "DescribeTablePlan",
             PlanNode::DescribeStage(_) => "DescribeStagePlan",
             PlanNode::DropTable(_) => "DropTablePlan",
+            PlanNode::AlterTable(_) => "AlterTablePlan",
+            PlanNode::CreateView(_) => "CreateViewPlan",
+            PlanNode::DropView(_) => "DropViewPlan",
             PlanNode::TruncateTable(_) => "TruncateTablePlan",
+            PlanNode::Delete(_) => "DeletePlan",
+            PlanNode::Update(_) => "UpdatePlan",
+            PlanNode::VacuumTable(_) => "VacuumTablePlan",
             PlanNode::SetVariable(_) => "SetVariablePlan",
             PlanNode::Sort(_) => "SortPlan",
+            PlanNode::Window(_) => "WindowPlan",
             PlanNode::UseDatabase(_) => "UseDatabasePlan",
             PlanNode::Insert(_) => "InsertPlan",
             PlanNode::ShowCreateTable(_) => "ShowCreateTablePlan",
@@ -183,6 +230,11 @@ impl PlanNode {
             PlanNode::Copy(_) => "CopyPlan",
             PlanNode::CreateUserStage(_) => "CreateUserStagePlan",
             PlanNode::ShowGrants(_) => "ShowGrantsPlan",
+            PlanNode::CreateRole(_) => "CreateRolePlan",
+            PlanNode::DropRole(_) => "DropRolePlan",
+            PlanNode::GrantRole(_) => "GrantRolePlan",
+            PlanNode::RevokeRole(_) => "RevokeRolePlan",
+            PlanNode::SetRole(_) => "SetRolePlan",
         }
     }
 
@@ -195,11 +247,13 @@ impl PlanNode {
             PlanNode::AggregatorPartial(v) => vec![v.input.clone()],
             PlanNode::AggregatorFinal(v) => vec![v.input.clone()],
             PlanNode::Filter(v) => vec![v.input.clone()],
+            PlanNode::Join(v) => v.get_inputs(),
             PlanNode::Having(v) => vec![v.input.clone()],
             PlanNode::Limit(v) => vec![v.input.clone()],
             PlanNode::Explain(v) => vec![v.input.clone()],
             PlanNode::Select(v) => vec![v.input.clone()],
             PlanNode::Sort(v) => vec![v.input.clone()],
+            PlanNode::Window(v) => vec![v.input.clone()],
             PlanNode::SubQueryExpression(v) => v.get_inputs(),
             PlanNode::Sink(v) => vec![v.input.clone()],
 
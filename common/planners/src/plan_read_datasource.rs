@@ -43,6 +43,9 @@ pub struct ReadDataSourcePlan {
 
     pub tbl_args: Option<Vec<Expression>>,
     pub push_downs: Option<Extras>,
+
+    /// The database and the name of the table read by a query, None for a table function.
+    pub source_table: Option<(String, String)>,
 }
 
 impl ReadDataSourcePlan {
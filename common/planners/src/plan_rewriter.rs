@@ -25,17 +25,23 @@ use crate::plan_broadcast::BroadcastPlan;
 use crate::plan_subqueries_set::SubQueriesSetPlan;
 use crate::AggregatorFinalPlan;
 use crate::AggregatorPartialPlan;
+use crate::AlterTablePlan;
 use crate::AlterUserPlan;
 use crate::CopyPlan;
 use crate::CreateDatabasePlan;
+use crate::CreateRolePlan;
 use crate::CreateTablePlan;
 use crate::CreateUserPlan;
 use crate::CreateUserStagePlan;
+use crate::CreateViewPlan;
+use crate::DeletePlan;
 use crate::DescribeStagePlan;
 use crate::DescribeTablePlan;
 use crate::DropDatabasePlan;
+use crate::DropRolePlan;
 use crate::DropTablePlan;
 use crate::DropUserPlan;
+use crate::DropViewPlan;
 use crate::EmptyPlan;
 use crate::ExplainPlan;
 use crate::Expression;
@@ -43,8 +49,10 @@ use crate::ExpressionPlan;
 use crate::Expressions;
 use crate::FilterPlan;
 use crate::GrantPrivilegePlan;
+use crate::GrantRolePlan;
 use crate::HavingPlan;
 use crate::InsertPlan;
+use crate::JoinPlan;
 use crate::KillPlan;
 use crate::LimitByPlan;
 use crate::LimitPlan;
@@ -54,7 +62,9 @@ use crate::ProjectionPlan;
 use crate::ReadDataSourcePlan;
 use crate::RemotePlan;
 use crate::RevokePrivilegePlan;
+use crate::RevokeRolePlan;
 use crate::SelectPlan;
+use crate::SetRolePlan;
 use crate::SettingPlan;
 use crate::ShowCreateTablePlan;
 use crate::ShowGrantsPlan;
@@ -62,7 +72,10 @@ use crate::SinkPlan;
 use crate::SortPlan;
 use crate::StagePlan;
 use crate::TruncateTablePlan;
+use crate::UpdatePlan;
 use crate::UseDatabasePlan;
+use crate::VacuumTablePlan;
+use crate::WindowPlan;
 
 /// `PlanRewriter` is a visitor that can help to rewrite `PlanNode`
 /// By default, a `PlanRewriter` will traverse the plan tree in pre-order and return rewritten plan tree.
@@ -91,7 +104,9 @@ pub trait PlanRewriter {
             PlanNode::Empty(plan) => self.rewrite_empty(plan),
             PlanNode::Projection(plan) => self.rewrite_projection(plan),
             PlanNode::Filter(plan) => self.rewrite_filter(plan),
+            PlanNode::Join(plan) => self.rewrite_join(plan),
             PlanNode::Sort(plan) => self.rewrite_sort(plan),
+            PlanNode::Window(plan) => self.rewrite_window(plan),
             PlanNode::Limit(plan) => self.rewrite_limit(plan),
             PlanNode::LimitBy(plan) => self.rewrite_limit_by(plan),
             PlanNode::ReadSource(plan) => self.rewrite_read_data_source(plan),
@@ -114,7 +129,13 @@ pub trait PlanRewriter {
             PlanNode::Copy(plan) => self.rewrite_copy(plan),
             PlanNode::ShowCreateTable(plan) => self.rewrite_show_create_table(plan),
             PlanNode::SubQueryExpression(plan) => self.rewrite_sub_queries_sets(plan),
+            PlanNode::AlterTable(plan) => self.rewrite_alter_table(plan),
+            PlanNode::CreateView(plan) => self.rewrite_create_view(plan),
+            PlanNode::DropView(plan) => self.rewrite_drop_view(plan),
             PlanNode::TruncateTable(plan) => self.rewrite_truncate_table(plan),
+            PlanNode::Delete(plan) => self.rewrite_delete(plan),
+            PlanNode::Update(plan) => self.rewrite_update(plan),
+            PlanNode::VacuumTable(plan) => self.rewrite_vacuum_table(plan),
             PlanNode::Kill(plan) => self.rewrite_kill(plan),
             PlanNode::CreateUser(plan) => self.create_user(plan),
             PlanNode::AlterUser(plan) => self.alter_user(plan),
@@ -124,6 +145,11 @@ pub trait PlanRewriter {
             PlanNode::CreateUserStage(plan) => self.rewrite_create_stage(plan),
             PlanNode::Sink(plan) => self.rewrite_sink(plan),
             PlanNode::ShowGrants(plan) => self.rewrite_show_grants(plan),
+            PlanNode::CreateRole(plan) => self.rewrite_create_role(plan),
+            PlanNode::DropRole(plan) => self.rewrite_drop_role(plan),
+            PlanNode::GrantRole(plan) => self.rewrite_grant_role(plan),
+            PlanNode::RevokeRole(plan) => self.rewrite_revoke_role(plan),
+            PlanNode::SetRole(plan) => self.rewrite_set_role(plan),
         }
     }
 
@@ -162,6 +188,21 @@ pub trait PlanRewriter {
                 params: params.clone(),
                 args: self.rewrite_exprs(schema, args)?,
             }),
+            Expression::WindowFunction {
+                op,
+                params,
+                args,
+                partition_by,
+                order_by,
+                window_frame,
+            } => Ok(Expression::WindowFunction {
+                op: op.clone(),
+                params: params.clone(),
+                args: self.rewrite_exprs(schema, args)?,
+                partition_by: self.rewrite_exprs(schema, partition_by)?,
+                order_by: self.rewrite_exprs(schema, order_by)?,
+                window_frame: *window_frame,
+            }),
             Expression::Sort {
                 expr,
                 asc,
@@ -271,6 +312,22 @@ pub trait PlanRewriter {
         PlanBuilder::from(&new_input).filter(new_predicate)?.build()
     }
 
+    fn rewrite_join(&mut self, plan: &JoinPlan) -> Result<PlanNode> {
+        let new_left = self.rewrite_plan_node(plan.left.as_ref())?;
+        let new_right = self.rewrite_plan_node(plan.right.as_ref())?;
+        let left_keys = self.rewrite_exprs(&new_left.schema(), &plan.left_keys)?;
+        let right_keys = self.rewrite_exprs(&new_right.schema(), &plan.right_keys)?;
+        PlanBuilder::from(&new_left)
+            .join(
+                &new_right,
+                plan.join_type,
+                &left_keys,
+                &right_keys,
+                plan.other_condition.clone(),
+            )?
+            .build()
+    }
+
     fn rewrite_having(&mut self, plan: &HavingPlan) -> Result<PlanNode> {
         let new_input = self.rewrite_plan_node(plan.input.as_ref())?;
         let new_predicate = self.rewrite_expr(&new_input.schema(), &plan.predicate)?;
@@ -283,6 +340,14 @@ pub trait PlanRewriter {
         PlanBuilder::from(&new_input).sort(&new_order_by)?.build()
     }
 
+    fn rewrite_window(&mut self, plan: &WindowPlan) -> Result<PlanNode> {
+        let new_input = self.rewrite_plan_node(plan.input.as_ref())?;
+        let new_window_func = self.rewrite_expr(&new_input.schema(), &plan.window_func)?;
+        PlanBuilder::from(&new_input)
+            .window(new_window_func)?
+            .build()
+    }
+
     fn rewrite_limit(&mut self, plan: &LimitPlan) -> Result<PlanNode> {
         let new_input = self.rewrite_plan_node(plan.input.as_ref())?;
         PlanBuilder::from(&new_input)
@@ -358,10 +423,34 @@ pub trait PlanRewriter {
         Ok(PlanNode::ShowCreateTable(plan.clone()))
     }
 
+    fn rewrite_alter_table(&mut self, plan: &AlterTablePlan) -> Result<PlanNode> {
+        Ok(PlanNode::AlterTable(plan.clone()))
+    }
+
+    fn rewrite_create_view(&mut self, plan: &CreateViewPlan) -> Result<PlanNode> {
+        Ok(PlanNode::CreateView(plan.clone()))
+    }
+
+    fn rewrite_drop_view(&mut self, plan: &DropViewPlan) -> Result<PlanNode> {
+        Ok(PlanNode::DropView(plan.clone()))
+    }
+
     fn rewrite_truncate_table(&mut self, plan: &TruncateTablePlan) -> Result<PlanNode> {
         Ok(PlanNode::TruncateTable(plan.clone()))
     }
 
+    fn rewrite_delete(&mut self, plan: &DeletePlan) -> Result<PlanNode> {
+        Ok(PlanNode::Delete(plan.clone()))
+    }
+
+    fn rewrite_update(&mut self, plan: &UpdatePlan) -> Result<PlanNode> {
+        Ok(PlanNode::Update(plan.clone()))
+    }
+
+    fn rewrite_vacuum_table(&mut self, plan: &VacuumTablePlan) -> Result<PlanNode> {
+        Ok(PlanNode::VacuumTable(plan.clone()))
+    }
+
     fn rewrite_kill(&mut self, plan: &KillPlan) -> Result<PlanNode> {
         Ok(PlanNode::Kill(plan.clone()))
     }
@@ -394,6 +483,26 @@ pub trait PlanRewriter {
         Ok(PlanNode::ShowGrants(plan.clone()))
     }
 
+    fn rewrite_create_role(&mut self, plan: &CreateRolePlan) -> Result<PlanNode> {
+        Ok(PlanNode::CreateRole(plan.clone()))
+    }
+
+    fn rewrite_drop_role(&mut self, plan: &DropRolePlan) -> Result<PlanNode> {
+        Ok(PlanNode::DropRole(plan.clone()))
+    }
+
+    fn rewrite_grant_role(&mut self, plan: &GrantRolePlan) -> Result<PlanNode> {
+        Ok(PlanNode::GrantRole(plan.clone()))
+    }
+
+    fn rewrite_revoke_role(&mut self, plan: &RevokeRolePlan) -> Result<PlanNode> {
+        Ok(PlanNode::RevokeRole(plan.clone()))
+    }
+
+    fn rewrite_set_role(&mut self, plan: &SetRolePlan) -> Result<PlanNode> {
+        Ok(PlanNode::SetRole(plan.clone()))
+    }
+
     fn rewrite_sink(&mut self, plan: &SinkPlan) -> Result<PlanNode> {
         Ok(PlanNode::Sink(plan.clone()))
     }
@@ -570,6 +679,7 @@ impl RewriteHelper {
             | Expression::Literal { .. }
             | Expression::Subquery { .. }
             | Expression::ScalarSubquery { .. }
+            | Expression::WindowFunction { .. }
             | Expression::Sort { .. } => Ok(expr.clone()),
         }
     }
@@ -632,6 +742,17 @@ impl RewriteHelper {
             }
             Expression::ScalarFunction { args, .. } => args.clone(),
             Expression::AggregateFunction { args, .. } => args.clone(),
+            Expression::WindowFunction {
+                args,
+                partition_by,
+                order_by,
+                ..
+            } => {
+                let mut v = args.clone();
+                v.extend_from_slice(partition_by);
+                v.extend_from_slice(order_by);
+                v
+            }
             Expression::Wildcard => vec![],
             Expression::Sort { expr, .. } => vec![expr.as_ref().clone()],
             Expression::Cast { expr, .. } => vec![expr.as_ref().clone()],
@@ -670,6 +791,19 @@ impl RewriteHelper {
                 }
                 v
             }
+            Expression::WindowFunction {
+                args,
+                partition_by,
+                order_by,
+                ..
+            } => {
+                let mut v = vec![];
+                for arg in args.iter().chain(partition_by).chain(order_by) {
+                    let mut col = Self::expression_plan_columns(arg)?;
+                    v.append(&mut col);
+                }
+                v
+            }
             Expression::Wildcard => vec![],
             Expression::Sort { expr, .. } => Self::expression_plan_columns(expr)?,
             Expression::Cast { expr, .. } => Self::expression_plan_columns(expr)?,
@@ -742,6 +876,25 @@ impl RewriteHelper {
                 params: params.clone(),
                 args: expressions.to_vec(),
             },
+            Expression::WindowFunction {
+                op,
+                params,
+                args,
+                partition_by,
+                window_frame,
+                ..
+            } => {
+                let (new_args, others) = expressions.split_at(args.len());
+                let (new_partition_by, new_order_by) = others.split_at(partition_by.len());
+                Expression::WindowFunction {
+                    op: op.clone(),
+                    params: params.clone(),
+                    args: new_args.to_vec(),
+                    partition_by: new_partition_by.to_vec(),
+                    order_by: new_order_by.to_vec(),
+                    window_frame: *window_frame,
+                }
+            }
             other => other.clone(),
         }
     }
@@ -18,25 +18,33 @@ use crate::plan_broadcast::BroadcastPlan;
 use crate::plan_subqueries_set::SubQueriesSetPlan;
 use crate::AggregatorFinalPlan;
 use crate::AggregatorPartialPlan;
+use crate::AlterTablePlan;
 use crate::AlterUserPlan;
 use crate::CopyPlan;
 use crate::CreateDatabasePlan;
+use crate::CreateRolePlan;
 use crate::CreateTablePlan;
 use crate::CreateUserPlan;
 use crate::CreateUserStagePlan;
+use crate::CreateViewPlan;
+use crate::DeletePlan;
 use crate::DescribeStagePlan;
 use crate::DescribeTablePlan;
 use crate::DropDatabasePlan;
+use crate::DropRolePlan;
 use crate::DropTablePlan;
 use crate::DropUserPlan;
+use crate::DropViewPlan;
 use crate::EmptyPlan;
 use crate::ExplainPlan;
 use crate::Expression;
 use crate::ExpressionPlan;
 use crate::FilterPlan;
 use crate::GrantPrivilegePlan;
+use crate::GrantRolePlan;
 use crate::HavingPlan;
 use crate::InsertPlan;
+use crate::JoinPlan;
 use crate::KillPlan;
 use crate::LimitByPlan;
 use crate::LimitPlan;
@@ -45,7 +53,9 @@ use crate::ProjectionPlan;
 use crate::ReadDataSourcePlan;
 use crate::RemotePlan;
 use crate::RevokePrivilegePlan;
+use crate::RevokeRolePlan;
 use crate::SelectPlan;
+use crate::SetRolePlan;
 use crate::SettingPlan;
 use crate::ShowCreateTablePlan;
 use crate::ShowGrantsPlan;
@@ -53,7 +63,10 @@ use crate::SinkPlan;
 use crate::SortPlan;
 use crate::StagePlan;
 use crate::TruncateTablePlan;
+use crate::UpdatePlan;
 use crate::UseDatabasePlan;
+use crate::VacuumTablePlan;
+use crate::WindowPlan;
 
 /// `PlanVisitor` implements visitor pattern(reference [syn](https://docs.rs/syn/1.0.72/syn/visit/trait.Visit.html)) for `PlanNode`.
 ///
@@ -104,7 +117,9 @@ pub trait PlanVisitor {
             PlanNode::Empty(plan) => self.visit_empty(plan),
             PlanNode::Projection(plan) => self.visit_projection(plan),
             PlanNode::Filter(plan) => self.visit_filter(plan),
+            PlanNode::Join(plan) => self.visit_join(plan),
             PlanNode::Sort(plan) => self.visit_sort(plan),
+            PlanNode::Window(plan) => self.visit_window(plan),
             PlanNode::Limit(plan) => self.visit_limit(plan),
             PlanNode::LimitBy(plan) => self.visit_limit_by(plan),
             PlanNode::ReadSource(plan) => self.visit_read_data_source(plan),
@@ -116,7 +131,13 @@ pub trait PlanVisitor {
             PlanNode::DropTable(plan) => self.visit_drop_table(plan),
             PlanNode::DescribeTable(plan) => self.visit_describe_table(plan),
             PlanNode::DescribeStage(plan) => self.visit_describe_stage(plan),
+            PlanNode::AlterTable(plan) => self.visit_alter_table(plan),
+            PlanNode::CreateView(plan) => self.visit_create_view(plan),
+            PlanNode::DropView(plan) => self.visit_drop_view(plan),
             PlanNode::TruncateTable(plan) => self.visit_truncate_table(plan),
+            PlanNode::Delete(plan) => self.visit_delete(plan),
+            PlanNode::Update(plan) => self.visit_update(plan),
+            PlanNode::VacuumTable(plan) => self.visit_vacuum_table(plan),
             PlanNode::UseDatabase(plan) => self.visit_use_database(plan),
             PlanNode::SetVariable(plan) => self.visit_set_variable(plan),
             PlanNode::Stage(plan) => self.visit_stage(plan),
@@ -137,6 +158,11 @@ pub trait PlanVisitor {
             PlanNode::Sink(plan) => self.visit_append(plan),
             PlanNode::CreateUserStage(plan) => self.visit_create_stage(plan),
             PlanNode::ShowGrants(plan) => self.visit_show_grants(plan),
+            PlanNode::CreateRole(plan) => self.visit_create_role(plan),
+            PlanNode::DropRole(plan) => self.visit_drop_role(plan),
+            PlanNode::GrantRole(plan) => self.visit_grant_role(plan),
+            PlanNode::RevokeRole(plan) => self.visit_revoke_role(plan),
+            PlanNode::SetRole(plan) => self.visit_set_role(plan),
         }
     }
 
@@ -214,6 +240,11 @@ pub trait PlanVisitor {
         self.visit_expr(&plan.predicate)
     }
 
+    fn visit_join(&mut self, plan: &JoinPlan) -> Result<()> {
+        self.visit_plan_node(plan.left.as_ref())?;
+        self.visit_plan_node(plan.right.as_ref())
+    }
+
     fn visit_having(&mut self, plan: &HavingPlan) -> Result<()> {
         self.visit_plan_node(plan.input.as_ref())?;
         self.visit_expr(&plan.predicate)
@@ -224,6 +255,11 @@ pub trait PlanVisitor {
         self.visit_exprs(&plan.order_by)
     }
 
+    fn visit_window(&mut self, plan: &WindowPlan) -> Result<()> {
+        self.visit_plan_node(plan.input.as_ref())?;
+        self.visit_expr(&plan.window_func)
+    }
+
     fn visit_limit(&mut self, plan: &LimitPlan) -> Result<()> {
         self.visit_plan_node(plan.input.as_ref())
     }
@@ -308,10 +344,34 @@ pub trait PlanVisitor {
         Ok(())
     }
 
+    fn visit_alter_table(&mut self, _: &AlterTablePlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_create_view(&mut self, _: &CreateViewPlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_drop_view(&mut self, _: &DropViewPlan) -> Result<()> {
+        Ok(())
+    }
+
     fn visit_truncate_table(&mut self, _: &TruncateTablePlan) -> Result<()> {
         Ok(())
     }
 
+    fn visit_delete(&mut self, _: &DeletePlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_update(&mut self, _: &UpdatePlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_vacuum_table(&mut self, _: &VacuumTablePlan) -> Result<()> {
+        Ok(())
+    }
+
     fn visit_kill_query(&mut self, _: &KillPlan) -> Result<()> {
         Ok(())
     }
@@ -326,4 +386,24 @@ pub trait PlanVisitor {
     fn visit_show_grants(&mut self, _: &ShowGrantsPlan) -> Result<()> {
         Ok(())
     }
+
+    fn visit_create_role(&mut self, _: &CreateRolePlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_drop_role(&mut self, _: &DropRolePlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_grant_role(&mut self, _: &GrantRolePlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_revoke_role(&mut self, _: &RevokeRolePlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_set_role(&mut self, _: &SetRolePlan) -> Result<()> {
+        Ok(())
+    }
 }
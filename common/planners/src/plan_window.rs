@@ -0,0 +1,157 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::sync::Arc;
+
+use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::Expression;
+use crate::PlanNode;
+
+/// Functions that can only be evaluated over a window, every aggregate function
+/// can be used as a window function too.
+pub const WINDOW_FUNCTIONS: [&str; 7] = [
+    "row_number",
+    "rank",
+    "dense_rank",
+    "lag",
+    "lead",
+    "first_value",
+    "last_value",
+];
+
+pub fn is_window_function(name: &str) -> bool {
+    WINDOW_FUNCTIONS
+        .iter()
+        .any(|f| f.eq_ignore_ascii_case(name))
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq)]
+pub enum WindowFrameUnits {
+    Rows,
+    Range,
+}
+
+impl fmt::Display for WindowFrameUnits {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            WindowFrameUnits::Rows => write!(f, "ROWS"),
+            WindowFrameUnits::Range => write!(f, "RANGE"),
+        }
+    }
+}
+
+/// `None` offsets mean `UNBOUNDED`.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq)]
+pub enum WindowFrameBound {
+    CurrentRow,
+    Preceding(Option<u64>),
+    Following(Option<u64>),
+}
+
+impl fmt::Display for WindowFrameBound {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            WindowFrameBound::CurrentRow => write!(f, "CURRENT ROW"),
+            WindowFrameBound::Preceding(None) => write!(f, "UNBOUNDED PRECEDING"),
+            WindowFrameBound::Preceding(Some(n)) => write!(f, "{} PRECEDING", n),
+            WindowFrameBound::Following(None) => write!(f, "UNBOUNDED FOLLOWING"),
+            WindowFrameBound::Following(Some(n)) => write!(f, "{} FOLLOWING", n),
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq)]
+pub struct WindowFrame {
+    pub units: WindowFrameUnits,
+    pub start_bound: WindowFrameBound,
+    pub end_bound: WindowFrameBound,
+}
+
+impl WindowFrame {
+    pub fn try_create(
+        units: WindowFrameUnits,
+        start_bound: WindowFrameBound,
+        end_bound: Option<WindowFrameBound>,
+    ) -> Result<WindowFrame> {
+        let end_bound = end_bound.unwrap_or(WindowFrameBound::CurrentRow);
+
+        if let WindowFrameBound::Following(None) = start_bound {
+            return Err(ErrorCode::SyntaxException(
+                "Window frame start cannot be UNBOUNDED FOLLOWING",
+            ));
+        }
+
+        if let WindowFrameBound::Preceding(None) = end_bound {
+            return Err(ErrorCode::SyntaxException(
+                "Window frame end cannot be UNBOUNDED PRECEDING",
+            ));
+        }
+
+        if units == WindowFrameUnits::Range {
+            let with_offset = |bound: &WindowFrameBound| {
+                matches!(
+                    bound,
+                    WindowFrameBound::Preceding(Some(_)) | WindowFrameBound::Following(Some(_))
+                )
+            };
+
+            if with_offset(&start_bound) || with_offset(&end_bound) {
+                return Err(ErrorCode::UnImplement(
+                    "RANGE window frame with offset PRECEDING or FOLLOWING is unsupported",
+                ));
+            }
+        }
+
+        Ok(WindowFrame {
+            units,
+            start_bound,
+            end_bound,
+        })
+    }
+}
+
+impl fmt::Display for WindowFrame {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} BETWEEN {} AND {}",
+            self.units, self.start_bound, self.end_bound
+        )
+    }
+}
+
+/// Evaluate one window function over the input and append its result as a new column.
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
+pub struct WindowPlan {
+    /// The `Expression::WindowFunction` to evaluate
+    pub window_func: Expression,
+    /// The logical plan
+    pub input: Arc<PlanNode>,
+    /// Output data schema
+    pub schema: DataSchemaRef,
+}
+
+impl WindowPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        self.schema.clone()
+    }
+
+    pub fn set_input(&mut self, node: &PlanNode) {
+        self.input = Arc::new(node.clone());
+    }
+}
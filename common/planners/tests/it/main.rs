@@ -25,4 +25,5 @@ mod plan_limit;
 mod plan_projection;
 mod plan_rewriter;
 mod plan_select;
+mod plan_window;
 mod test;
@@ -0,0 +1,202 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::*;
+use pretty_assertions::assert_eq;
+
+use crate::test::Test;
+
+#[test]
+fn test_window_plan() -> Result<()> {
+    let source = Test::create().generate_source_plan_for_test(10000)?;
+    let window_func = Expression::WindowFunction {
+        op: "sum".to_string(),
+        params: vec![],
+        args: vec![col("number")],
+        partition_by: vec![modular(col("number"), lit(3u8))],
+        order_by: vec![sort("number", false, false)],
+        window_frame: Some(WindowFrame::try_create(
+            WindowFrameUnits::Rows,
+            WindowFrameBound::Preceding(Some(1)),
+            Some(WindowFrameBound::Following(None)),
+        )?),
+    };
+
+    let plan = PlanBuilder::from(&source)
+        .expression(&[modular(col("number"), lit(3u8))], "Before Window")?
+        .window(window_func)?
+        .build()?;
+
+    let expect = "\
+    Window: sum(number) OVER (PARTITION BY (number % 3) ORDER BY number DESC ROWS BETWEEN 1 PRECEDING AND UNBOUNDED FOLLOWING)\
+    \n  Expression: (number % 3):UInt8 (Before Window)\
+    \n    ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 10000, read_bytes: 80000]";
+    assert_eq!(expect, format!("{:?}", plan));
+
+    let schema = plan.schema();
+    assert_eq!(3, schema.fields().len());
+    assert_eq!(
+        "sum(number) OVER (PARTITION BY (number % 3) ORDER BY number DESC ROWS BETWEEN 1 PRECEDING AND UNBOUNDED FOLLOWING)",
+        schema.field(2).name()
+    );
+    assert_eq!(&DataType::UInt64, schema.field(2).data_type());
+    Ok(())
+}
+
+#[test]
+fn test_window_function_type() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::Int32, false)]);
+
+    let window_function = |op: &str, args: Vec<Expression>| Expression::WindowFunction {
+        op: op.to_string(),
+        params: vec![],
+        args,
+        partition_by: vec![],
+        order_by: vec![sort("a", true, false)],
+        window_frame: None,
+    };
+
+    let cases = vec![
+        (
+            "row_number",
+            vec![],
+            "row_number() OVER (ORDER BY a)",
+            DataType::UInt64,
+            false,
+        ),
+        (
+            "rank",
+            vec![],
+            "rank() OVER (ORDER BY a)",
+            DataType::UInt64,
+            false,
+        ),
+        (
+            "dense_rank",
+            vec![],
+            "dense_rank() OVER (ORDER BY a)",
+            DataType::UInt64,
+            false,
+        ),
+        (
+            "lag",
+            vec![col("a"), lit(2u64)],
+            "lag(a, 2) OVER (ORDER BY a)",
+            DataType::Int32,
+            true,
+        ),
+        (
+            "first_value",
+            vec![col("a")],
+            "first_value(a) OVER (ORDER BY a)",
+            DataType::Int32,
+            true,
+        ),
+        (
+            "count",
+            vec![col("a")],
+            "count(a) OVER (ORDER BY a)",
+            DataType::UInt64,
+            false,
+        ),
+    ];
+
+    for (op, args, name, data_type, nullable) in cases {
+        let field = window_function(op, args).to_data_field(&schema)?;
+        assert_eq!(name, field.name(), "{}", op);
+        assert_eq!(&data_type, field.data_type(), "{}", op);
+        assert_eq!(nullable, field.is_nullable(), "{}", op);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_window_frame() -> Result<()> {
+    let frame = WindowFrame::try_create(
+        WindowFrameUnits::Range,
+        WindowFrameBound::Preceding(None),
+        None,
+    )?;
+    assert_eq!(
+        "RANGE BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW",
+        frame.to_string()
+    );
+
+    let cases = vec![
+        (
+            WindowFrameUnits::Rows,
+            WindowFrameBound::Following(None),
+            None,
+            ErrorCode::SyntaxException("Window frame start cannot be UNBOUNDED FOLLOWING"),
+        ),
+        (
+            WindowFrameUnits::Rows,
+            WindowFrameBound::CurrentRow,
+            Some(WindowFrameBound::Preceding(None)),
+            ErrorCode::SyntaxException("Window frame end cannot be UNBOUNDED PRECEDING"),
+        ),
+        (
+            WindowFrameUnits::Range,
+            WindowFrameBound::Preceding(Some(1)),
+            None,
+            ErrorCode::UnImplement(
+                "RANGE window frame with offset PRECEDING or FOLLOWING is unsupported",
+            ),
+        ),
+    ];
+
+    for (units, start_bound, end_bound, error) in cases {
+        let result = WindowFrame::try_create(units, start_bound, end_bound);
+        let actual = result.err().unwrap();
+        assert_eq!(error.code(), actual.code());
+        assert_eq!(error.message(), actual.message());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_window_function_validate() -> Result<()> {
+    let window_function = |op: &str, args: Vec<Expression>| Expression::WindowFunction {
+        op: op.to_string(),
+        params: vec![],
+        args,
+        partition_by: vec![],
+        order_by: vec![],
+        window_frame: None,
+    };
+
+    let result = validate_expression(&window_function("rank", vec![col("a")]));
+    assert_eq!(
+        "rank expect to have [0, 0] arguments, but got 1",
+        result.err().unwrap().message()
+    );
+
+    let result = validate_expression(&window_function("lead", vec![col("a"), col("b")]));
+    assert_eq!(
+        "lead expect constant offset and default value, but got b",
+        result.err().unwrap().message()
+    );
+
+    validate_expression(&window_function("lag", vec![
+        col("a"),
+        lit(1u64),
+        lit(0u64),
+    ]))?;
+    Ok(())
+}
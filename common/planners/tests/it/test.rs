@@ -51,6 +51,7 @@ impl Test {
             ),
             tbl_args: None,
             push_downs: None,
+            source_table: None,
         }))
     }
 
@@ -26,6 +26,8 @@ use common_datablocks::DataBlock;
 use common_datavalues::prelude::DataColumn;
 use common_datavalues::series::IntoSeries;
 use common_datavalues::DataSchemaRef;
+use common_datavalues::DataType;
+use common_datavalues::DataValue;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use futures::StreamExt;
@@ -40,6 +42,7 @@ pub struct ParquetSource {
     block_schema: DataSchemaRef,
     arrow_table_schema: ArrowSchema,
     projection: Vec<usize>,
+    default_values: Option<Vec<DataValue>>,
     row_group: usize,
     row_groups: usize,
     metadata: Option<FileMetaData>,
@@ -59,11 +62,33 @@ impl ParquetSource {
             block_schema,
             arrow_table_schema: table_schema.to_arrow(),
             projection,
+            default_values: None,
             row_group: 0,
             row_groups: 0,
             metadata: None,
         }
     }
+
+    /// Values of the table columns, used to fill the projected columns which do not
+    /// exist in the file, e.g. columns added to the table after the file is written.
+    ///
+    /// Without them, the default values of the column data types are used.
+    pub fn with_default_values(mut self, default_values: Vec<DataValue>) -> Self {
+        self.default_values = Some(default_values);
+        self
+    }
+
+    fn default_column(&self, idx: usize, num_rows: usize) -> DataColumn {
+        let value = match &self.default_values {
+            Some(values) => values[idx].clone(),
+            None => {
+                let field = &self.arrow_table_schema.fields()[idx];
+                let data_type: DataType = field.data_type().into();
+                DataValue::new_from_data_type(&data_type, field.is_nullable())
+            }
+        };
+        DataColumn::Constant(value, num_rows)
+    }
 }
 
 #[async_trait]
@@ -88,11 +113,16 @@ impl Source for ParquetSource {
         if self.row_group >= self.row_groups {
             return Ok(None);
         }
-        let col_num = self.projection.len();
         let row_group = self.row_group;
-        let cols = self
+        let file_col_num = metadata.row_groups[row_group].columns().len();
+        let num_rows = metadata.row_groups[row_group].num_rows() as usize;
+        let (present, missing): (Vec<usize>, Vec<usize>) = self
             .projection
-            .clone()
+            .iter()
+            .copied()
+            .partition(|idx| *idx < file_col_num);
+        let col_num = present.len();
+        let cols = present
             .into_iter()
             .map(|idx| (metadata.row_groups[row_group].column(idx).clone(), idx));
 
@@ -120,8 +150,26 @@ impl Source for ParquetSource {
 
         // TODO configuration of the buffer size
         let buffer_size = 10;
-        let n = std::cmp::min(buffer_size, col_num);
-        let data_cols = stream.buffered(n).try_collect().await?;
+        let n = std::cmp::max(std::cmp::min(buffer_size, col_num), 1);
+        let data_cols: Vec<DataColumn> = stream.buffered(n).try_collect().await?;
+
+        let data_cols = if missing.is_empty() {
+            data_cols
+        } else {
+            // new columns are only appended to the table schema, thus the columns missing in
+            // the file are those after its last column
+            let mut data_cols = data_cols.into_iter();
+            self.projection
+                .iter()
+                .map(|idx| {
+                    if *idx < file_col_num {
+                        data_cols.next().unwrap()
+                    } else {
+                        self.default_column(*idx, num_rows)
+                    }
+                })
+                .collect()
+        };
 
         self.row_group += 1;
         let block = DataBlock::create(self.block_schema.clone(), data_cols);
@@ -0,0 +1,69 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::task::Context;
+use std::task::Poll;
+
+use common_base::RuntimeTracker;
+use common_datablocks::DataBlock;
+use common_exception::Result;
+use futures::Stream;
+use pin_project_lite::pin_project;
+
+use crate::SendableDataBlockStream;
+
+pin_project! {
+    /// Stops the stream with a QuotaExceeded error once an allocation
+    /// of the runtime has exceeded its memory limit.
+    pub struct MemoryLimitStream {
+        #[pin]
+        input: SendableDataBlockStream,
+        tracker: Arc<RuntimeTracker>,
+    }
+}
+
+impl MemoryLimitStream {
+    pub fn try_create(
+        input: SendableDataBlockStream,
+        tracker: Arc<RuntimeTracker>,
+    ) -> Result<Self> {
+        Ok(Self { input, tracker })
+    }
+}
+
+impl Stream for MemoryLimitStream {
+    type Item = Result<DataBlock>;
+
+    fn poll_next(
+        self: std::pin::Pin<&mut Self>,
+        ctx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let memory_tracker = this.tracker.get_memory_tracker();
+
+        if let Err(cause) = memory_tracker.check_memory_limit() {
+            return Poll::Ready(Some(Err(cause)));
+        }
+
+        // The input may have allocated a lot to produce the block, e.g. aggregation or sort.
+        match this.input.poll_next(ctx) {
+            Poll::Ready(Some(Ok(block))) => match memory_tracker.check_memory_limit() {
+                Ok(_) => Poll::Ready(Some(Ok(block))),
+                Err(cause) => Poll::Ready(Some(Err(cause))),
+            },
+            other => other,
+        }
+    }
+}
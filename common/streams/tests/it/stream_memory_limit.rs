@@ -0,0 +1,57 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_base::*;
+use common_datablocks::*;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_streams::*;
+use futures::TryStreamExt;
+
+#[tokio::test]
+async fn test_memory_limit_stream() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::Int64, false)]);
+    let block = DataBlock::create_by_array(schema.clone(), vec![Series::new(vec![1i64, 2, 3])]);
+
+    // Under the limit.
+    {
+        let input = DataBlockStream::create(schema.clone(), None, vec![block.clone()]);
+        let tracker = RuntimeTracker::create();
+        tracker.get_memory_tracker().set_memory_limit(1024);
+
+        let stream = MemoryLimitStream::try_create(Box::pin(input), tracker)?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        assert_eq!(result.len(), 1);
+    }
+
+    // Over the limit.
+    {
+        let input = DataBlockStream::create(schema, None, vec![block]);
+        let tracker = RuntimeTracker::create();
+        tracker.get_memory_tracker().set_memory_limit(1024);
+        tracker.get_memory_tracker().alloc_memory(2048);
+
+        let stream = MemoryLimitStream::try_create(Box::pin(input), tracker.clone())?;
+        let result = stream.try_collect::<Vec<_>>().await;
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().code(), ErrorCode::QuotaExceededCode());
+        tracker.get_memory_tracker().dealloc_memory(2048);
+    }
+
+    Ok(())
+}
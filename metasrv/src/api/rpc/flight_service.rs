@@ -31,14 +31,18 @@ use common_arrow::arrow_format::flight::data::PutResult;
 use common_arrow::arrow_format::flight::data::SchemaResult;
 use common_arrow::arrow_format::flight::data::Ticket;
 use common_arrow::arrow_format::flight::service::flight_service_server::FlightService;
+use common_base::tokio;
 use common_flight_rpc::FlightClaim;
 use common_flight_rpc::FlightToken;
+use common_meta_api::KVApi;
 use common_meta_flight::MetaFlightAction;
+use common_meta_types::WatchKVReq;
 use common_tracing::tracing;
 use futures::Stream;
 use futures::StreamExt;
 use prost::Message;
 use serde::Serialize;
+use tokio_stream::wrappers::ReceiverStream;
 use tonic::metadata::MetadataMap;
 use tonic::Request;
 use tonic::Response;
@@ -148,11 +152,45 @@ impl FlightService for MetaFlightImpl {
 
     type DoGetStream =
         Pin<Box<dyn Stream<Item = Result<FlightData, tonic::Status>> + Send + Sync + 'static>>;
+
+    /// Watches the changes of keys: the ticket is a `WatchKVReq` and every change is streamed back as a `FlightData`.
+    #[tracing::instrument(level = "debug", skip(self, request))]
     async fn do_get(
         &self,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<Self::DoGetStream>, Status> {
-        todo!()
+        // Check token.
+        let _claim = self.check_token(request.metadata())?;
+
+        common_tracing::extract_remote_span_as_parent(&request);
+
+        let ticket = request.into_inner();
+        let req: WatchKVReq = serde_json::from_slice(&ticket.ticket)
+            .map_err(|e| Status::invalid_argument(e.to_string()))?;
+        tracing::info!("Receive do_get watch: {:?}", req);
+
+        let mut events = self.action_handler.meta_node.watch_kv(req).await?;
+
+        // The response stream has to be Sync, thus the events are forwarded through a channel.
+        let (tx, rx) = tokio::sync::mpsc::channel(64);
+        tokio::spawn(async move {
+            while let Some(event) = events.next().await {
+                let data = event
+                    .and_then(|event| Ok(serde_json::to_vec(&event)?))
+                    .map(|data_body| FlightData {
+                        data_body,
+                        ..FlightData::default()
+                    })
+                    .map_err(Status::from);
+
+                if tx.send(data).await.is_err() {
+                    tracing::debug!("watcher is closed by the client");
+                    break;
+                }
+            }
+        });
+
+        Ok(Response::new(Box::pin(ReceiverStream::new(rx))))
     }
 
     type DoPutStream = FlightStream<PutResult>;
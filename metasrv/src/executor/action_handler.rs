@@ -62,6 +62,7 @@ impl ActionHandler {
             MetaFlightAction::PrefixListKV(a) => {
                 s.serialize(self.meta_node.prefix_list_kv(&a.0).await?)
             }
+            MetaFlightAction::Transaction(a) => s.serialize(self.meta_node.transaction(a).await?),
 
             // database
             MetaFlightAction::CreateDatabase(a) => s.serialize(self.handle(a).await?),
@@ -72,10 +73,12 @@ impl ActionHandler {
             // table
             MetaFlightAction::CreateTable(a) => s.serialize(self.handle(a).await?),
             MetaFlightAction::DropTable(a) => s.serialize(self.handle(a).await?),
+            MetaFlightAction::RenameTable(a) => s.serialize(self.handle(a).await?),
             MetaFlightAction::GetTable(a) => s.serialize(self.handle(a).await?),
             MetaFlightAction::ListTables(a) => s.serialize(self.handle(a).await?),
             MetaFlightAction::GetTableExt(a) => s.serialize(self.handle(a).await?),
             MetaFlightAction::CommitTable(a) => s.serialize(self.handle(a).await?),
+            MetaFlightAction::UpdateTableSchema(a) => s.serialize(self.handle(a).await?),
         }
     }
 }
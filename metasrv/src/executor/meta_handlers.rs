@@ -24,6 +24,8 @@ use common_meta_types::Cmd::CreateDatabase;
 use common_meta_types::Cmd::CreateTable;
 use common_meta_types::Cmd::DropDatabase;
 use common_meta_types::Cmd::DropTable;
+use common_meta_types::Cmd::RenameTable;
+use common_meta_types::Cmd::UpdateTableSchema;
 use common_meta_types::Cmd::UpsertTableOptions;
 use common_meta_types::CreateDatabaseReply;
 use common_meta_types::CreateDatabaseReq;
@@ -41,9 +43,13 @@ use common_meta_types::ListDatabaseReq;
 use common_meta_types::ListTableReq;
 use common_meta_types::LogEntry;
 use common_meta_types::OkOrExist;
+use common_meta_types::RenameTableReply;
+use common_meta_types::RenameTableReq;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
+use common_meta_types::UpdateTableSchemaReply;
+use common_meta_types::UpdateTableSchemaReq;
 use common_meta_types::UpsertTableOptionReply;
 use common_meta_types::UpsertTableOptionReq;
 use common_tracing::tracing;
@@ -211,6 +217,45 @@ impl RequestHandler<DropTableReq> for ActionHandler {
     }
 }
 
+#[async_trait::async_trait]
+impl RequestHandler<RenameTableReq> for ActionHandler {
+    async fn handle(&self, req: RenameTableReq) -> common_exception::Result<RenameTableReply> {
+        let cr = LogEntry {
+            txid: None,
+            cmd: RenameTable {
+                db_name: req.db.clone(),
+                table_name: req.table.clone(),
+                new_table_name: req.new_table.clone(),
+            },
+        };
+
+        let res = self
+            .meta_node
+            .write(cr)
+            .await
+            .map_err(|e| ErrorCode::MetaNodeInternalError(e.to_string()))?;
+
+        let ch: Change<TableMeta> = res.try_into().unwrap();
+        let (prev, result) = ch.unpack();
+
+        if result.is_none() {
+            return Err(ErrorCode::UnknownTable(format!(
+                "Unknown table: '{:}'",
+                req.table
+            )));
+        }
+
+        if prev.is_some() {
+            return Err(ErrorCode::TableAlreadyExists(format!(
+                "table exists: {}",
+                req.new_table
+            )));
+        }
+
+        Ok(RenameTableReply {})
+    }
+}
+
 #[async_trait::async_trait]
 impl RequestHandler<GetTableReq> for ActionHandler {
     async fn handle(&self, req: GetTableReq) -> common_exception::Result<Arc<TableInfo>> {
@@ -289,3 +334,34 @@ impl RequestHandler<UpsertTableOptionReq> for ActionHandler {
         Ok(UpsertTableOptionReply {})
     }
 }
+
+#[async_trait::async_trait]
+impl RequestHandler<UpdateTableSchemaReq> for ActionHandler {
+    async fn handle(
+        &self,
+        req: UpdateTableSchemaReq,
+    ) -> common_exception::Result<UpdateTableSchemaReply> {
+        let cr = LogEntry {
+            txid: None,
+            cmd: UpdateTableSchema(req.clone()),
+        };
+
+        let res = self
+            .meta_node
+            .write(cr)
+            .await
+            .map_err(|e| ErrorCode::MetaNodeInternalError(e.to_string()))?;
+
+        if !res.changed() {
+            let ch: Change<TableMeta> = res.try_into().unwrap();
+            let (prev, _result) = ch.unwrap();
+
+            return Err(ErrorCode::TableVersionMissMatch(format!(
+                "targeting version {:?}, current version {}",
+                req.seq, prev.seq,
+            )));
+        }
+
+        Ok(UpdateTableSchemaReply {})
+    }
+}
@@ -15,6 +15,7 @@
 use async_trait::async_trait;
 use common_exception::ErrorCode;
 use common_meta_api::KVApi;
+use common_meta_api::WatchKVStream;
 use common_meta_raft_store::state_machine::AppliedState;
 use common_meta_types::Cmd;
 use common_meta_types::GetKVActionReply;
@@ -24,8 +25,11 @@ use common_meta_types::LogEntry;
 use common_meta_types::MGetKVActionReply;
 use common_meta_types::MGetKVReq;
 use common_meta_types::PrefixListReply;
+use common_meta_types::TransactionReply;
+use common_meta_types::TransactionReq;
 use common_meta_types::UpsertKVAction;
 use common_meta_types::UpsertKVActionReply;
+use common_meta_types::WatchKVReq;
 use common_tracing::tracing;
 
 use crate::meta_service::MetaNode;
@@ -35,6 +39,7 @@ use crate::meta_service::MetaNode;
 /// Write through raft-log.
 /// Read through local state machine, which may not be consistent.
 /// E.g. Read is not guaranteed to see a write.
+/// Watch the changes applied to the local state machine, which may lag behind the leader.
 #[async_trait]
 impl KVApi for MetaNode {
     async fn upsert_kv(
@@ -93,4 +98,26 @@ impl KVApi for MetaNode {
 
         Ok(res)
     }
+
+    async fn transaction(&self, req: TransactionReq) -> common_exception::Result<TransactionReply> {
+        let ent = LogEntry {
+            txid: None,
+            cmd: Cmd::Transaction(req),
+        };
+        let rst = self
+            .write(ent)
+            .await
+            .map_err(|e| ErrorCode::MetaNodeInternalError(e.to_string()))?;
+
+        match rst {
+            AppliedState::Transaction(x) => Ok(x),
+            _ => Err(ErrorCode::MetaNodeInternalError("not a Transaction result")),
+        }
+    }
+
+    #[tracing::instrument(level = "debug", skip(self))]
+    async fn watch_kv(&self, req: WatchKVReq) -> common_exception::Result<WatchKVStream> {
+        let sm = self.sto.state_machine.read().await;
+        sm.watch_kv(req).await
+    }
 }
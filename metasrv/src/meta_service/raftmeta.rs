@@ -15,6 +15,9 @@
 use std::collections::BTreeSet;
 use std::fmt::Debug;
 use std::sync::Arc;
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
 
 use async_raft::config::Config;
 use async_raft::Raft;
@@ -84,6 +87,7 @@ pub struct MetaNodeBuilder {
     raft_config: Option<Config>,
     sto: Option<Arc<MetaRaftStore>>,
     monitor_metrics: bool,
+    purge_expired_kv_interval: u64,
     addr: Option<String>,
 }
 
@@ -124,6 +128,12 @@ impl MetaNodeBuilder {
             MetaNode::subscribe_metrics(mn.clone(), metrics_rx).await;
         }
 
+        if self.purge_expired_kv_interval > 0 {
+            let interval = Duration::from_millis(self.purge_expired_kv_interval);
+            tracing::info!("about to purge expired kv every {:?}", interval);
+            MetaNode::purge_expired_kv_periodically(mn.clone(), interval).await;
+        }
+
         let addr = if let Some(a) = self.addr.take() {
             a
         } else {
@@ -152,6 +162,10 @@ impl MetaNodeBuilder {
         self.monitor_metrics = b;
         self
     }
+    pub fn purge_expired_kv_interval(mut self, ms: u64) -> Self {
+        self.purge_expired_kv_interval = ms;
+        self
+    }
 }
 
 impl MetaNode {
@@ -163,6 +177,7 @@ impl MetaNode {
             raft_config: Some(raft_config),
             sto: None,
             monitor_metrics: true,
+            purge_expired_kv_interval: config.purge_expired_kv_interval,
             addr: None,
         }
     }
@@ -306,6 +321,66 @@ impl MetaNode {
         Ok(joined)
     }
 
+    /// Spawn a task that periodically submits a `PurgeExpiredKV` log if this node is the leader.
+    ///
+    /// The timestamp is assigned by the leader, thus every node removes exactly the same expired entries.
+    pub async fn purge_expired_kv_periodically(mn: Arc<Self>, interval: Duration) {
+        let mut running_rx = mn.running_rx.clone();
+        let mut jh = mn.join_handles.lock().await;
+
+        let mn = mn.clone();
+
+        let span = tracing::span!(tracing::Level::INFO, "purge-expired-kv");
+
+        let h = tokio::task::spawn(
+            async move {
+                loop {
+                    tokio::select! {
+                        _ = running_rx.changed() => {
+                           return Ok::<(), ErrorCode>(());
+                        }
+                        _ = tokio::time::sleep(interval) => {}
+                    }
+
+                    match mn.purge_expired_kv().await {
+                        Ok(Some(AppliedState::PurgedKV(purged))) => {
+                            tracing::debug!("purged {} expired kv", purged.len());
+                        }
+                        Ok(_) => {}
+                        Err(e) => {
+                            tracing::warn!("fail to purge expired kv: {:?}", e);
+                        }
+                    }
+                }
+            }
+            .instrument(span),
+        );
+        jh.push(h);
+    }
+
+    /// Submit a `PurgeExpiredKV` log with the current time through raft.
+    /// It does nothing and returns `None` if this node is not the leader.
+    pub async fn purge_expired_kv(&self) -> Result<Option<AppliedState>, MetaError> {
+        let leader = match self.as_leader().await {
+            Ok(leader) => leader,
+            Err(_) => return Ok(None),
+        };
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+
+        let res = leader
+            .write(LogEntry {
+                txid: None,
+                cmd: Cmd::PurgeExpiredKV { now },
+            })
+            .await?;
+
+        Ok(Some(res))
+    }
+
     // spawn a monitor to watch raft state changes such as leader changes,
     // and manually add non-voter to cluster so that non-voter receives raft logs.
     pub async fn subscribe_metrics(mn: Arc<Self>, mut metrics_rx: watch::Receiver<RaftMetrics>) {
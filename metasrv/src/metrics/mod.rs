@@ -15,3 +15,5 @@
 mod metric_service;
 
 pub use metric_service::MetricService;
+
+pub static METRIC_META_PURGED_EXPIRED_KV: &str = "meta.purged_expired_kv";
@@ -41,8 +41,10 @@ use common_meta_types::LogEntry;
 use common_meta_types::Node;
 use common_meta_types::NodeId;
 use common_tracing::tracing;
+use metrics::counter;
 
 use crate::errors::ShutdownError;
+use crate::metrics::METRIC_META_PURGED_EXPIRED_KV;
 use crate::Opened;
 
 /// An storage implementing the `async_raft::RaftStorage` trait.
@@ -176,7 +178,10 @@ impl MetaRaftStore {
             .write_state_machine_id(&(sm_id, new_sm_id))
             .await?;
 
-        let new_sm = StateMachine::open(&self.config, new_sm_id).await?;
+        let mut new_sm = StateMachine::open(&self.config, new_sm_id).await?;
+        // The watchers keep watching the new state machine.
+        // NOTE: the changes made by installing a snapshot are not pushed to the watchers.
+        new_sm.kv_watcher = sm.kv_watcher.clone();
         tracing::info!(
             "insert all key-value into new state machine, n={}",
             snap.kvs.len()
@@ -225,6 +230,13 @@ impl MetaRaftStore {
         Ok(())
     }
 
+    /// Reports the metrics of an applied log.
+    fn observe_applied(resp: &AppliedState) {
+        if let AppliedState::PurgedKV(purged) = resp {
+            counter!(METRIC_META_PURGED_EXPIRED_KV, purged.len() as u64);
+        }
+    }
+
     /// Go backwards through the log to find the most recent membership config <= `upto_index`.
     #[tracing::instrument(level = "debug", skip(self))]
     pub async fn get_membership_from_log(
@@ -358,6 +370,7 @@ impl RaftStorage<LogEntry, AppliedState> for MetaRaftStore {
     ) -> anyhow::Result<AppliedState> {
         let sm = self.state_machine.write().await;
         let resp = sm.apply(entry).await?;
+        Self::observe_applied(&resp);
         Ok(resp)
     }
 
@@ -365,7 +378,8 @@ impl RaftStorage<LogEntry, AppliedState> for MetaRaftStore {
     async fn replicate_to_state_machine(&self, entries: &[&Entry<LogEntry>]) -> anyhow::Result<()> {
         let sm = self.state_machine.write().await;
         for entry in entries {
-            sm.apply(*entry).await?;
+            let resp = sm.apply(*entry).await?;
+            Self::observe_applied(&resp);
         }
         Ok(())
     }
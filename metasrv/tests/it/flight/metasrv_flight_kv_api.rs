@@ -103,3 +103,27 @@ async fn test_kv_api_write_read() -> anyhow::Result<()> {
 
     KVApiTestSuite {}.kv_write_read(&client).await
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_kv_api_transaction() -> anyhow::Result<()> {
+    let (_log_guards, ut_span) = init_meta_ut!();
+    let _ent = ut_span.enter();
+
+    let (_tc, addr) = crate::tests::start_metasrv().await?;
+
+    let client = MetaFlightClient::try_create(addr.as_str(), "root", "xxx").await?;
+
+    KVApiTestSuite {}.kv_transaction(&client).await
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_kv_api_watch() -> anyhow::Result<()> {
+    let (_log_guards, ut_span) = init_meta_ut!();
+    let _ent = ut_span.enter();
+
+    let (_tc, addr) = crate::tests::start_metasrv().await?;
+
+    let client = MetaFlightClient::try_create(addr.as_str(), "root", "xxx").await?;
+
+    KVApiTestSuite {}.kv_watch(&client).await
+}
@@ -69,6 +69,20 @@ async fn test_meta_api_table_list() -> anyhow::Result<()> {
     MetaApiTestSuite {}.table_list(&client).await
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_meta_api_table_rename_and_update_schema() -> anyhow::Result<()> {
+    let (_log_guards, ut_span) = init_meta_ut!();
+    let _ent = ut_span.enter();
+
+    let (_tc, addr) = start_metasrv().await?;
+
+    let client = MetaFlightClient::try_create(addr.as_str(), "root", "xxx").await?;
+
+    MetaApiTestSuite {}
+        .table_rename_and_update_schema(&client)
+        .await
+}
+
 // TODO(xp): uncomment following tests when the function is ready
 // ------------------------------------------------------------
 
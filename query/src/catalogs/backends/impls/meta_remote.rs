@@ -32,9 +32,13 @@ use common_meta_types::GetTableReq;
 use common_meta_types::ListDatabaseReq;
 use common_meta_types::ListTableReq;
 use common_meta_types::MetaId;
+use common_meta_types::RenameTableReply;
+use common_meta_types::RenameTableReq;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
+use common_meta_types::UpdateTableSchemaReply;
+use common_meta_types::UpdateTableSchemaReq;
 use common_meta_types::UpsertTableOptionReply;
 use common_meta_types::UpsertTableOptionReq;
 
@@ -108,6 +112,11 @@ impl MetaApi for MetaRemote {
             .await
     }
 
+    async fn rename_table(&self, req: RenameTableReq) -> Result<RenameTableReply> {
+        self.query_backend(move |cli| async move { cli.rename_table(req).await })
+            .await
+    }
+
     async fn get_table(&self, req: GetTableReq) -> Result<Arc<TableInfo>> {
         self.query_backend(move |cli| async move { cli.get_table(req).await })
             .await
@@ -131,6 +140,14 @@ impl MetaApi for MetaRemote {
             .await
     }
 
+    async fn update_table_schema(
+        &self,
+        req: UpdateTableSchemaReq,
+    ) -> Result<UpdateTableSchemaReply> {
+        self.query_backend(move |cli| async move { cli.update_table_schema(req).await })
+            .await
+    }
+
     fn name(&self) -> String {
         "meta-remote".to_owned()
     }
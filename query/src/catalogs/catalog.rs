@@ -23,9 +23,13 @@ use common_meta_types::DropDatabaseReq;
 use common_meta_types::DropTableReply;
 use common_meta_types::DropTableReq;
 use common_meta_types::MetaId;
+use common_meta_types::RenameTableReply;
+use common_meta_types::RenameTableReq;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
+use common_meta_types::UpdateTableSchemaReply;
+use common_meta_types::UpdateTableSchemaReq;
 use common_meta_types::UpsertTableOptionReply;
 use common_meta_types::UpsertTableOptionReq;
 use dyn_clone::DynClone;
@@ -84,6 +88,8 @@ pub trait Catalog: DynClone + Send + Sync {
 
     async fn drop_table(&self, req: DropTableReq) -> Result<DropTableReply>;
 
+    async fn rename_table(&self, req: RenameTableReq) -> Result<RenameTableReply>;
+
     // Check a db.table is exists or not.
     async fn exists_table(&self, db_name: &str, table_name: &str) -> Result<bool> {
         match self.get_table(db_name, table_name).await {
@@ -103,6 +109,11 @@ pub trait Catalog: DynClone + Send + Sync {
         req: UpsertTableOptionReq,
     ) -> Result<UpsertTableOptionReply>;
 
+    async fn update_table_schema(
+        &self,
+        req: UpdateTableSchemaReq,
+    ) -> Result<UpdateTableSchemaReply>;
+
     ///
     /// Table function
     ///
@@ -24,9 +24,13 @@ use common_meta_types::DropDatabaseReq;
 use common_meta_types::DropTableReply;
 use common_meta_types::DropTableReq;
 use common_meta_types::MetaId;
+use common_meta_types::RenameTableReply;
+use common_meta_types::RenameTableReq;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
+use common_meta_types::UpdateTableSchemaReply;
+use common_meta_types::UpdateTableSchemaReq;
 use common_meta_types::UpsertTableOptionReply;
 use common_meta_types::UpsertTableOptionReq;
 
@@ -195,6 +199,20 @@ impl Catalog for DatabaseCatalog {
         }
     }
 
+    async fn rename_table(&self, req: RenameTableReq) -> Result<RenameTableReply> {
+        let r = self.immutable_catalog.rename_table(req.clone()).await;
+        match r {
+            Err(e) => {
+                if e.code() == ErrorCode::UnknownTableCode() {
+                    self.mutable_catalog.rename_table(req).await
+                } else {
+                    Err(e)
+                }
+            }
+            Ok(x) => Ok(x),
+        }
+    }
+
     async fn upsert_table_option(
         &self,
         req: UpsertTableOptionReq,
@@ -203,6 +221,14 @@ impl Catalog for DatabaseCatalog {
         self.mutable_catalog.upsert_table_option(req).await
     }
 
+    async fn update_table_schema(
+        &self,
+        req: UpdateTableSchemaReq,
+    ) -> Result<UpdateTableSchemaReply> {
+        // update table schema in BOTTOM layer only
+        self.mutable_catalog.update_table_schema(req).await
+    }
+
     fn get_table_function(
         &self,
         func_name: &str,
@@ -24,9 +24,13 @@ use common_meta_types::DropDatabaseReq;
 use common_meta_types::DropTableReply;
 use common_meta_types::DropTableReq;
 use common_meta_types::MetaId;
+use common_meta_types::RenameTableReply;
+use common_meta_types::RenameTableReq;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
+use common_meta_types::UpdateTableSchemaReply;
+use common_meta_types::UpdateTableSchemaReq;
 use common_meta_types::UpsertTableOptionReply;
 use common_meta_types::UpsertTableOptionReq;
 
@@ -142,6 +146,18 @@ impl Catalog for ImmutableCatalog {
         )));
     }
 
+    async fn rename_table(&self, req: RenameTableReq) -> Result<RenameTableReply> {
+        if req.db == "system" {
+            return Err(ErrorCode::UnImplement(
+                "Cannot rename table in system database",
+            ));
+        }
+        Err(ErrorCode::UnknownTable(format!(
+            "Unknown table: '{}'",
+            req.table
+        )))
+    }
+
     async fn upsert_table_option(
         &self,
         req: UpsertTableOptionReq,
@@ -151,4 +167,14 @@ impl Catalog for ImmutableCatalog {
             req
         )))
     }
+
+    async fn update_table_schema(
+        &self,
+        req: UpdateTableSchemaReq,
+    ) -> Result<UpdateTableSchemaReply> {
+        Err(ErrorCode::UnImplement(format!(
+            "Alter table not allowed for system database {:?}",
+            req
+        )))
+    }
 }
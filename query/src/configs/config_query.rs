@@ -28,6 +28,7 @@ pub const QUERY_CLICKHOUSE_HANDLER_HOST: &str = "QUERY_CLICKHOUSE_HANDLER_HOST";
 pub const QUERY_CLICKHOUSE_HANDLER_PORT: &str = "QUERY_CLICKHOUSE_HANDLER_PORT";
 pub const QUERY_HTTP_HANDLER_HOST: &str = "QUERY_HTTP_HANDLER_HOST";
 pub const QUERY_HTTP_HANDLER_PORT: &str = "QUERY_HTTP_HANDLER_PORT";
+pub const QUERY_HTTP_HANDLER_JWT_SECRET: &str = "QUERY_HTTP_HANDLER_JWT_SECRET";
 pub const QUERY_FLIGHT_API_ADDRESS: &str = "QUERY_FLIGHT_API_ADDRESS";
 pub const QUERY_HTTP_API_ADDRESS: &str = "QUERY_HTTP_API_ADDRESS";
 pub const QUERY_METRICS_API_ADDRESS: &str = "QUERY_METRIC_API_ADDRESS";
@@ -117,6 +118,15 @@ pub struct QueryConfig {
     #[serde(default)]
     pub http_handler_port: u16,
 
+    #[structopt(
+    long,
+    env = QUERY_HTTP_HANDLER_JWT_SECRET,
+    default_value = "",
+    help = "Secret to verify the HS256 bearer tokens of http handler, empty to disable bearer auth"
+    )]
+    #[serde(default)]
+    pub http_handler_jwt_secret: String,
+
     #[structopt(
     long,
     env = QUERY_FLIGHT_API_ADDRESS,
@@ -245,6 +255,7 @@ impl QueryConfig {
             clickhouse_handler_port: 9000,
             http_handler_host: "127.0.0.1".to_string(),
             http_handler_port: 8000,
+            http_handler_jwt_secret: "".to_string(),
             flight_api_address: "127.0.0.1:9090".to_string(),
             http_api_address: "127.0.0.1:8080".to_string(),
             metric_api_address: "127.0.0.1:7070".to_string(),
@@ -303,6 +314,13 @@ impl QueryConfig {
             u16,
             QUERY_CLICKHOUSE_HANDLER_PORT
         );
+        env_helper!(
+            mut_config,
+            query,
+            http_handler_jwt_secret,
+            String,
+            QUERY_HTTP_HANDLER_JWT_SECRET
+        );
         env_helper!(
             mut_config,
             query,
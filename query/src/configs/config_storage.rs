@@ -40,6 +40,12 @@ const AZURE_STORAGE_ACCOUNT: &str = "AZURE_STORAGE_ACCOUNT";
 const AZURE_BLOB_MASTER_KEY: &str = "AZURE_BLOB_MASTER_KEY";
 const AZURE_BLOB_CONTAINER: &str = "AZURE_BLOB_CONTAINER";
 
+// Storage cache env.
+const STORAGE_CACHE_ENABLED: &str = "STORAGE_CACHE_ENABLED";
+const STORAGE_CACHE_MEMORY_SIZE: &str = "STORAGE_CACHE_MEMORY_SIZE";
+const STORAGE_CACHE_DISK_PATH: &str = "STORAGE_CACHE_DISK_PATH";
+const STORAGE_CACHE_DISK_SIZE: &str = "STORAGE_CACHE_DISK_SIZE";
+
 #[derive(Clone, serde::Serialize, serde::Deserialize, PartialEq)]
 pub enum StorageType {
     Disk,
@@ -165,6 +171,38 @@ impl fmt::Debug for AzureStorageBlobConfig {
     }
 }
 
+#[derive(
+    Clone, Debug, serde::Serialize, serde::Deserialize, PartialEq, StructOpt, StructOptToml,
+)]
+pub struct StorageCacheConfig {
+    #[structopt(long, env = STORAGE_CACHE_ENABLED, help = "Cache the fuse table snapshots, segments and blocks locally")]
+    #[serde(default)]
+    pub cache_enabled: bool,
+
+    #[structopt(long, env = STORAGE_CACHE_MEMORY_SIZE, default_value = "268435456", help = "Memory cache size in bytes for the snapshots and segments")]
+    #[serde(default)]
+    pub cache_memory_size: u64,
+
+    #[structopt(long, env = STORAGE_CACHE_DISK_PATH, default_value = "_cache", help = "Disk cache path for the blocks")]
+    #[serde(default)]
+    pub cache_disk_path: String,
+
+    #[structopt(long, env = STORAGE_CACHE_DISK_SIZE, default_value = "10737418240", help = "Disk cache size in bytes for the blocks, 0 disables the disk cache")]
+    #[serde(default)]
+    pub cache_disk_size: u64,
+}
+
+impl StorageCacheConfig {
+    pub fn default() -> Self {
+        StorageCacheConfig {
+            cache_enabled: false,
+            cache_memory_size: 256 * 1024 * 1024,
+            cache_disk_path: "_cache".to_string(),
+            cache_disk_size: 10 * 1024 * 1024 * 1024,
+        }
+    }
+}
+
 /// Storage config group.
 /// serde(default) make the toml de to default working.
 #[derive(
@@ -186,6 +224,10 @@ pub struct StorageConfig {
     // azure storage blob config.
     #[structopt(flatten)]
     pub azure_storage_blob: AzureStorageBlobConfig,
+
+    // Local cache config.
+    #[structopt(flatten)]
+    pub cache: StorageCacheConfig,
 }
 
 impl StorageConfig {
@@ -195,6 +237,7 @@ impl StorageConfig {
             disk: DiskStorageConfig::default(),
             s3: S3StorageConfig::default(),
             azure_storage_blob: AzureStorageBlobConfig::default(),
+            cache: StorageCacheConfig::default(),
         }
     }
 
@@ -258,5 +301,35 @@ impl StorageConfig {
             String,
             AZURE_BLOB_MASTER_KEY
         );
+
+        // Cache.
+        env_helper!(
+            mut_config.storage,
+            cache,
+            cache_enabled,
+            bool,
+            STORAGE_CACHE_ENABLED
+        );
+        env_helper!(
+            mut_config.storage,
+            cache,
+            cache_memory_size,
+            u64,
+            STORAGE_CACHE_MEMORY_SIZE
+        );
+        env_helper!(
+            mut_config.storage,
+            cache,
+            cache_disk_path,
+            String,
+            STORAGE_CACHE_DISK_PATH
+        );
+        env_helper!(
+            mut_config.storage,
+            cache,
+            cache_disk_size,
+            u64,
+            STORAGE_CACHE_DISK_SIZE
+        );
     }
 }
@@ -29,4 +29,5 @@ pub use config_query::QueryConfig;
 pub use config_storage::AzureStorageBlobConfig;
 pub use config_storage::DiskStorageConfig;
 pub use config_storage::S3StorageConfig;
+pub use config_storage::StorageCacheConfig;
 pub use config_storage::StorageConfig;
@@ -0,0 +1,359 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::GrantObject;
+use common_meta_types::UserGrantSet;
+use common_meta_types::UserPrivilegeType;
+use common_planners::AlterTablePlan;
+use common_planners::AlterUserPlan;
+use common_planners::CopyPlan;
+use common_planners::CreateDatabasePlan;
+use common_planners::CreateRolePlan;
+use common_planners::CreateTablePlan;
+use common_planners::CreateUserPlan;
+use common_planners::CreateUserStagePlan;
+use common_planners::CreateViewPlan;
+use common_planners::DeletePlan;
+use common_planners::DescribeTablePlan;
+use common_planners::DropDatabasePlan;
+use common_planners::DropRolePlan;
+use common_planners::DropTablePlan;
+use common_planners::DropUserPlan;
+use common_planners::DropViewPlan;
+use common_planners::Expression;
+use common_planners::GrantPrivilegePlan;
+use common_planners::GrantRolePlan;
+use common_planners::InsertInputSource;
+use common_planners::InsertPlan;
+use common_planners::KillPlan;
+use common_planners::PlanNode;
+use common_planners::PlanVisitor;
+use common_planners::ReadDataSourcePlan;
+use common_planners::RevokePrivilegePlan;
+use common_planners::RevokeRolePlan;
+use common_planners::RewriteHelper;
+use common_planners::SettingPlan;
+use common_planners::ShowCreateTablePlan;
+use common_planners::ShowGrantsPlan;
+use common_planners::TruncateTablePlan;
+use common_planners::UpdatePlan;
+use common_planners::VacuumTablePlan;
+
+use crate::sessions::QueryContext;
+
+/// The system database is always readable, `SHOW` statements and constant
+/// selects are rewritten into queries on it.
+const SYSTEM_DATABASE: &str = "system";
+
+/// The grants of a role are keyed by the role name and this host pattern.
+const ROLE_HOST: &str = "%";
+
+/// Checks the privileges of the current user against every object a plan touches.
+pub struct PrivilegeAccess {
+    ctx: Arc<QueryContext>,
+}
+
+impl PrivilegeAccess {
+    pub fn create(ctx: Arc<QueryContext>) -> PrivilegeAccess {
+        PrivilegeAccess { ctx }
+    }
+
+    pub fn check(&self, plan: &PlanNode) -> Result<()> {
+        let mut collector = RequiredPrivileges::create(self.ctx.clone());
+        collector.visit_plan_node(plan)?;
+
+        if collector.required.is_empty() {
+            return Ok(());
+        }
+
+        let user = self.ctx.get_current_user()?;
+        let roles = self.ctx.get_current_roles();
+        for (object, privilege) in collector.required {
+            let granted =
+                Self::verify(&user.grants, &user.name, &user.hostname, &object, privilege)
+                    || roles.iter().any(|role| {
+                        Self::verify(&role.grants, &role.name, ROLE_HOST, &object, privilege)
+                    });
+            if !granted {
+                return Err(ErrorCode::PermissionDenied(format!(
+                    "Permission denied, user '{}'@'{}' requires {} privilege on {}",
+                    user.name, user.hostname, privilege, object
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn verify(
+        grants: &UserGrantSet,
+        name: &str,
+        host: &str,
+        object: &GrantObject,
+        privilege: UserPrivilegeType,
+    ) -> bool {
+        match object {
+            GrantObject::Global => grants.verify_global_privilege(name, host, privilege),
+            GrantObject::Database(db) => {
+                grants.verify_database_privilege(name, host, db, privilege)
+            }
+            GrantObject::Table(db, table) => {
+                grants.verify_table_privilege(name, host, db, table, privilege)
+            }
+        }
+    }
+}
+
+struct RequiredPrivileges {
+    ctx: Arc<QueryContext>,
+    required: Vec<(GrantObject, UserPrivilegeType)>,
+}
+
+impl RequiredPrivileges {
+    fn create(ctx: Arc<QueryContext>) -> RequiredPrivileges {
+        RequiredPrivileges {
+            ctx,
+            required: vec![],
+        }
+    }
+
+    fn require(&mut self, object: GrantObject, privilege: UserPrivilegeType) {
+        self.required.push((object, privilege));
+    }
+
+    fn require_table(&mut self, db: &str, table: &str, privilege: UserPrivilegeType) {
+        self.require(
+            GrantObject::Table(db.to_string(), table.to_string()),
+            privilege,
+        );
+    }
+
+    // The subqueries nested in the expressions read their tables as selects do.
+    fn visit_nested_subqueries<'a>(
+        &mut self,
+        exprs: impl Iterator<Item = &'a Expression>,
+    ) -> Result<()> {
+        let exprs = exprs.cloned().collect::<Vec<_>>();
+        let subqueries = RewriteHelper::collect_exprs_sub_queries(&exprs)?;
+        self.visit_exprs(&subqueries)
+    }
+}
+
+impl PlanVisitor for RequiredPrivileges {
+    fn visit_read_data_source(&mut self, plan: &ReadDataSourcePlan) -> Result<()> {
+        // Table functions have no grantable object.
+        if let Some((db, table)) = &plan.source_table {
+            if db != SYSTEM_DATABASE {
+                self.require_table(db, table, UserPrivilegeType::Select);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn visit_create_database(&mut self, plan: &CreateDatabasePlan) -> Result<()> {
+        self.require(
+            GrantObject::Database(plan.db.clone()),
+            UserPrivilegeType::Create,
+        );
+        Ok(())
+    }
+
+    fn visit_drop_database(&mut self, plan: &DropDatabasePlan) -> Result<()> {
+        self.require(
+            GrantObject::Database(plan.db.clone()),
+            UserPrivilegeType::Drop,
+        );
+        Ok(())
+    }
+
+    fn visit_create_table(&mut self, plan: &CreateTablePlan) -> Result<()> {
+        self.require(
+            GrantObject::Database(plan.db.clone()),
+            UserPrivilegeType::Create,
+        );
+
+        match &plan.as_select {
+            None => Ok(()),
+            Some(select) => self.visit_plan_node(select.as_ref()),
+        }
+    }
+
+    fn visit_create_user(&mut self, _: &CreateUserPlan) -> Result<()> {
+        self.require(GrantObject::Global, UserPrivilegeType::Grant);
+        Ok(())
+    }
+
+    fn visit_alter_user(&mut self, _: &AlterUserPlan) -> Result<()> {
+        self.require(GrantObject::Global, UserPrivilegeType::Grant);
+        Ok(())
+    }
+
+    fn visit_drop_user(&mut self, _: &DropUserPlan) -> Result<()> {
+        self.require(GrantObject::Global, UserPrivilegeType::Grant);
+        Ok(())
+    }
+
+    fn visit_grant_privilege(&mut self, _: &GrantPrivilegePlan) -> Result<()> {
+        self.require(GrantObject::Global, UserPrivilegeType::Grant);
+        Ok(())
+    }
+
+    fn visit_revoke_privilege(&mut self, _: &RevokePrivilegePlan) -> Result<()> {
+        self.require(GrantObject::Global, UserPrivilegeType::Grant);
+        Ok(())
+    }
+
+    fn visit_create_role(&mut self, _: &CreateRolePlan) -> Result<()> {
+        self.require(GrantObject::Global, UserPrivilegeType::Grant);
+        Ok(())
+    }
+
+    fn visit_drop_role(&mut self, _: &DropRolePlan) -> Result<()> {
+        self.require(GrantObject::Global, UserPrivilegeType::Grant);
+        Ok(())
+    }
+
+    fn visit_grant_role(&mut self, _: &GrantRolePlan) -> Result<()> {
+        self.require(GrantObject::Global, UserPrivilegeType::Grant);
+        Ok(())
+    }
+
+    fn visit_revoke_role(&mut self, _: &RevokeRolePlan) -> Result<()> {
+        self.require(GrantObject::Global, UserPrivilegeType::Grant);
+        Ok(())
+    }
+
+    fn visit_describe_table(&mut self, plan: &DescribeTablePlan) -> Result<()> {
+        if plan.db != SYSTEM_DATABASE {
+            self.require_table(&plan.db, &plan.table, UserPrivilegeType::Select);
+        }
+        Ok(())
+    }
+
+    fn visit_drop_table(&mut self, plan: &DropTablePlan) -> Result<()> {
+        self.require_table(&plan.db, &plan.table, UserPrivilegeType::Drop);
+        Ok(())
+    }
+
+    fn visit_set_variable(&mut self, _: &SettingPlan) -> Result<()> {
+        self.require(GrantObject::Global, UserPrivilegeType::Set);
+        Ok(())
+    }
+
+    fn visit_insert_into(&mut self, plan: &InsertPlan) -> Result<()> {
+        self.require_table(
+            &plan.database_name,
+            &plan.table_name,
+            UserPrivilegeType::Insert,
+        );
+
+        match &plan.source {
+            InsertInputSource::SelectPlan(select) => self.visit_plan_node(select.as_ref()),
+            _ => Ok(()),
+        }
+    }
+
+    fn visit_copy(&mut self, plan: &CopyPlan) -> Result<()> {
+        self.require_table(&plan.db_name, &plan.tbl_name, UserPrivilegeType::Insert);
+        Ok(())
+    }
+
+    fn visit_show_create_table(&mut self, plan: &ShowCreateTablePlan) -> Result<()> {
+        if plan.db != SYSTEM_DATABASE {
+            self.require_table(&plan.db, &plan.table, UserPrivilegeType::Select);
+        }
+        Ok(())
+    }
+
+    fn visit_alter_table(&mut self, plan: &AlterTablePlan) -> Result<()> {
+        // Altering a table changes its definition, as creating it does.
+        self.require(
+            GrantObject::Database(plan.db.clone()),
+            UserPrivilegeType::Create,
+        );
+        Ok(())
+    }
+
+    fn visit_create_view(&mut self, plan: &CreateViewPlan) -> Result<()> {
+        self.require(
+            GrantObject::Database(plan.db.clone()),
+            UserPrivilegeType::Create,
+        );
+        Ok(())
+    }
+
+    fn visit_drop_view(&mut self, plan: &DropViewPlan) -> Result<()> {
+        self.require_table(&plan.db, &plan.viewname, UserPrivilegeType::Drop);
+        Ok(())
+    }
+
+    fn visit_truncate_table(&mut self, plan: &TruncateTablePlan) -> Result<()> {
+        self.require_table(&plan.db, &plan.table, UserPrivilegeType::Drop);
+        Ok(())
+    }
+
+    fn visit_delete(&mut self, plan: &DeletePlan) -> Result<()> {
+        // Deleting rewrites the table, and the selection reveals its rows.
+        self.require_table(&plan.db, &plan.table, UserPrivilegeType::Insert);
+        self.require_table(&plan.db, &plan.table, UserPrivilegeType::Select);
+        self.visit_nested_subqueries(plan.selection.iter())
+    }
+
+    fn visit_update(&mut self, plan: &UpdatePlan) -> Result<()> {
+        // Updating rewrites the table, and the selection and new values reveal its rows.
+        self.require_table(&plan.db, &plan.table, UserPrivilegeType::Insert);
+        self.require_table(&plan.db, &plan.table, UserPrivilegeType::Select);
+        let assignments = plan.assignments.iter().map(|(_, expr)| expr);
+        self.visit_nested_subqueries(assignments.chain(plan.selection.iter()))
+    }
+
+    fn visit_vacuum_table(&mut self, plan: &VacuumTablePlan) -> Result<()> {
+        self.require_table(&plan.db, &plan.table, UserPrivilegeType::Drop);
+        Ok(())
+    }
+
+    fn visit_kill_query(&mut self, plan: &KillPlan) -> Result<()> {
+        // Killing the sessions of another user requires the grant privilege.
+        let current_user = self.ctx.get_current_user()?;
+        let owned = match self.ctx.get_sessions_manager().get_session(&plan.id) {
+            None => false,
+            Some(session) => session.get_current_user().map_or(false, |user| {
+                user.name == current_user.name && user.hostname == current_user.hostname
+            }),
+        };
+
+        if !owned {
+            self.require(GrantObject::Global, UserPrivilegeType::Grant);
+        }
+        Ok(())
+    }
+
+    fn visit_create_stage(&mut self, _: &CreateUserStagePlan) -> Result<()> {
+        self.require(GrantObject::Global, UserPrivilegeType::Create);
+        Ok(())
+    }
+
+    fn visit_show_grants(&mut self, plan: &ShowGrantsPlan) -> Result<()> {
+        // Reading the grants of another user requires the grant privilege.
+        if plan.user_identity.is_some() {
+            self.require(GrantObject::Global, UserPrivilegeType::Grant);
+        }
+        Ok(())
+    }
+}
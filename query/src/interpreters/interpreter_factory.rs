@@ -19,35 +19,49 @@ use common_exception::Result;
 use common_planners::PlanNode;
 
 use super::DescribeStageInterpreter;
+use crate::interpreters::AlterTableInterpreter;
 use crate::interpreters::AlterUserInterpreter;
 use crate::interpreters::CopyInterpreter;
 use crate::interpreters::CreatStageInterpreter;
 use crate::interpreters::CreatUserInterpreter;
 use crate::interpreters::CreateDatabaseInterpreter;
+use crate::interpreters::CreateRoleInterpreter;
 use crate::interpreters::CreateTableInterpreter;
+use crate::interpreters::CreateViewInterpreter;
+use crate::interpreters::DeleteInterpreter;
 use crate::interpreters::DescribeTableInterpreter;
 use crate::interpreters::DropDatabaseInterpreter;
+use crate::interpreters::DropRoleInterpreter;
 use crate::interpreters::DropTableInterpreter;
 use crate::interpreters::DropUserInterpreter;
+use crate::interpreters::DropViewInterpreter;
 use crate::interpreters::ExplainInterpreter;
 use crate::interpreters::GrantPrivilegeInterpreter;
+use crate::interpreters::GrantRoleInterpreter;
 use crate::interpreters::InsertInterpreter;
 use crate::interpreters::InterceptorInterpreter;
 use crate::interpreters::Interpreter;
 use crate::interpreters::KillInterpreter;
+use crate::interpreters::PrivilegeAccess;
 use crate::interpreters::RevokePrivilegeInterpreter;
+use crate::interpreters::RevokeRoleInterpreter;
 use crate::interpreters::SelectInterpreter;
+use crate::interpreters::SetRoleInterpreter;
 use crate::interpreters::SettingInterpreter;
 use crate::interpreters::ShowCreateTableInterpreter;
 use crate::interpreters::ShowGrantsInterpreter;
 use crate::interpreters::TruncateTableInterpreter;
+use crate::interpreters::UpdateInterpreter;
 use crate::interpreters::UseDatabaseInterpreter;
+use crate::interpreters::VacuumTableInterpreter;
 use crate::sessions::QueryContext;
 
 pub struct InterpreterFactory;
 
 impl InterpreterFactory {
     pub fn get(ctx: Arc<QueryContext>, plan: PlanNode) -> Result<Arc<dyn Interpreter>> {
+        PrivilegeAccess::create(ctx.clone()).check(&plan)?;
+
         let ctx_clone = ctx.clone();
         let inner = match plan.clone() {
             PlanNode::Select(v) => SelectInterpreter::try_create(ctx_clone, v),
@@ -56,8 +70,14 @@ impl InterpreterFactory {
             PlanNode::DropDatabase(v) => DropDatabaseInterpreter::try_create(ctx_clone, v),
             PlanNode::CreateTable(v) => CreateTableInterpreter::try_create(ctx_clone, v),
             PlanNode::DropTable(v) => DropTableInterpreter::try_create(ctx_clone, v),
+            PlanNode::AlterTable(v) => AlterTableInterpreter::try_create(ctx_clone, v),
+            PlanNode::CreateView(v) => CreateViewInterpreter::try_create(ctx_clone, v),
+            PlanNode::DropView(v) => DropViewInterpreter::try_create(ctx_clone, v),
             PlanNode::DescribeTable(v) => DescribeTableInterpreter::try_create(ctx_clone, v),
             PlanNode::TruncateTable(v) => TruncateTableInterpreter::try_create(ctx_clone, v),
+            PlanNode::Delete(v) => DeleteInterpreter::try_create(ctx_clone, v),
+            PlanNode::Update(v) => UpdateInterpreter::try_create(ctx_clone, v),
+            PlanNode::VacuumTable(v) => VacuumTableInterpreter::try_create(ctx_clone, v),
             PlanNode::UseDatabase(v) => UseDatabaseInterpreter::try_create(ctx_clone, v),
             PlanNode::SetVariable(v) => SettingInterpreter::try_create(ctx_clone, v),
             PlanNode::Insert(v) => InsertInterpreter::try_create(ctx_clone, v),
@@ -72,6 +92,11 @@ impl InterpreterFactory {
             PlanNode::CreateUserStage(v) => CreatStageInterpreter::try_create(ctx_clone, v),
             PlanNode::ShowGrants(v) => ShowGrantsInterpreter::try_create(ctx_clone, v),
             PlanNode::DescribeStage(v) => DescribeStageInterpreter::try_create(ctx_clone, v),
+            PlanNode::CreateRole(v) => CreateRoleInterpreter::try_create(ctx_clone, v),
+            PlanNode::DropRole(v) => DropRoleInterpreter::try_create(ctx_clone, v),
+            PlanNode::GrantRole(v) => GrantRoleInterpreter::try_create(ctx_clone, v),
+            PlanNode::RevokeRole(v) => RevokeRoleInterpreter::try_create(ctx_clone, v),
+            PlanNode::SetRole(v) => SetRoleInterpreter::try_create(ctx_clone, v),
             _ => Result::Err(ErrorCode::UnknownTypeOfQuery(format!(
                 "Can't get the interpreter by plan:{}",
                 plan.name()
@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use common_exception::Result;
+use common_meta_types::PrincipalIdentity;
 use common_planners::RevokePrivilegePlan;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
@@ -56,9 +57,23 @@ impl Interpreter for RevokePrivilegeInterpreter {
         // TODO: check privilege on granting on the grant object
 
         let user_mgr = self.ctx.get_sessions_manager().get_user_manager();
-        user_mgr
-            .revoke_user_privileges(&plan.username, &plan.hostname, plan.on, plan.priv_types)
-            .await?;
+        match &plan.principal {
+            PrincipalIdentity::User(user) => {
+                user_mgr
+                    .revoke_user_privileges(
+                        &user.username,
+                        &user.hostname,
+                        plan.on,
+                        plan.priv_types,
+                    )
+                    .await?;
+            }
+            PrincipalIdentity::Role(role) => {
+                user_mgr
+                    .revoke_role_privileges(role, plan.on, plan.priv_types)
+                    .await?;
+            }
+        }
 
         Ok(Box::pin(DataBlockStream::create(
             self.plan.schema(),
@@ -0,0 +1,80 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::SetRolePlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+use common_tracing::tracing;
+
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::sessions::QueryContext;
+
+#[derive(Debug)]
+pub struct SetRoleInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: SetRolePlan,
+}
+
+impl SetRoleInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: SetRolePlan) -> Result<InterpreterPtr> {
+        Ok(Arc::new(SetRoleInterpreter { ctx, plan }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for SetRoleInterpreter {
+    fn name(&self) -> &str {
+        "SetRoleInterpreter"
+    }
+
+    #[tracing::instrument(level = "info", skip(self, _input_stream), fields(ctx.id = self.ctx.get_id().as_str()))]
+    async fn execute(
+        &self,
+        _input_stream: Option<SendableDataBlockStream>,
+    ) -> Result<SendableDataBlockStream> {
+        let plan = self.plan.clone();
+        let current_user = self.ctx.get_current_user()?;
+        let user_mgr = self.ctx.get_sessions_manager().get_user_manager();
+
+        // Reload the user, the granted roles may have changed since login.
+        let user_info = user_mgr
+            .get_user(&current_user.name, &current_user.hostname)
+            .await?;
+
+        let roles = match plan.role {
+            None => user_mgr.get_granted_roles(&user_info.roles).await?,
+            Some(role) => {
+                if !user_info.roles.contains(&role) {
+                    return Err(ErrorCode::PermissionDenied(format!(
+                        "Permission denied, role '{}' is not granted to user '{}'@'{}'",
+                        role, user_info.name, user_info.hostname
+                    )));
+                }
+                vec![user_mgr.get_role(&role).await?]
+            }
+        };
+        self.ctx.set_current_roles(roles);
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.plan.schema(),
+            None,
+            vec![],
+        )))
+    }
+}
@@ -30,6 +30,8 @@ use crate::catalogs::Catalog;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
 use crate::sessions::QueryContext;
+use crate::storages::view::QUERY;
+use crate::storages::view::VIEW_ENGINE;
 
 pub struct ShowCreateTableInterpreter {
     ctx: Arc<QueryContext>,
@@ -60,22 +62,28 @@ impl Interpreter for ShowCreateTableInterpreter {
         let engine = table.engine();
         let schema = table.schema();
 
-        let mut table_info = format!("CREATE TABLE `{}` (\n", name);
-        for field in schema.fields().iter() {
-            let column = format!("  `{}` {},\n", field.name(), field.data_type());
-            table_info.push_str(column.as_str());
-        }
-        let table_engine = format!(") ENGINE={}", engine);
-        table_info.push_str(table_engine.as_str());
-        table_info.push_str(
-            table
-                .options()
-                .iter()
-                .map(|(k, v)| format!(" {}='{}'", k.to_uppercase(), v))
-                .collect::<Vec<_>>()
-                .join("")
-                .as_str(),
-        );
+        let table_info = match engine.eq_ignore_ascii_case(VIEW_ENGINE) {
+            true => format!("CREATE VIEW `{}` AS {}", name, table.options()[QUERY]),
+            false => {
+                let mut table_info = format!("CREATE TABLE `{}` (\n", name);
+                for field in schema.fields().iter() {
+                    let column = format!("  `{}` {},\n", field.name(), field.data_type());
+                    table_info.push_str(column.as_str());
+                }
+                let table_engine = format!(") ENGINE={}", engine);
+                table_info.push_str(table_engine.as_str());
+                table_info.push_str(
+                    table
+                        .options()
+                        .iter()
+                        .map(|(k, v)| format!(" {}='{}'", k.to_uppercase(), v))
+                        .collect::<Vec<_>>()
+                        .join("")
+                        .as_str(),
+                );
+                table_info
+            }
+        };
 
         let show_fields = vec![
             DataField::new("Table", DataType::String, false),
@@ -50,20 +50,32 @@ impl Interpreter for ShowGrantsInterpreter {
         let schema =
             DataSchemaRefExt::create(vec![DataField::new("Grants", DataType::String, false)]);
 
-        // TODO: add permission check on reading user grants
-        let user_info = match self.plan.user_identity {
-            None => self.ctx.get_current_user()?,
+        let user_mgr = self.ctx.get_sessions_manager().get_user_manager();
+        let (user_info, roles) = match self.plan.user_identity {
+            None => (self.ctx.get_current_user()?, self.ctx.get_current_roles()),
             Some(ref user_identity) => {
-                self.ctx
-                    .get_sessions_manager()
-                    .get_user_manager()
+                let user_info = user_mgr
                     .get_user(&user_identity.username, &user_identity.hostname)
-                    .await?
+                    .await?;
+                let roles = user_mgr.get_granted_roles(&user_info.roles).await?;
+                (user_info, roles)
             }
         };
 
-        let grant_list = user_info
-            .grants
+        // The effective grants are the union of the user's own grants and its roles' grants.
+        let mut grants = user_info.grants.clone();
+        for role in roles.iter() {
+            for entry in role.grants.entries() {
+                grants.grant_privileges(
+                    &user_info.name,
+                    &user_info.hostname,
+                    entry.object(),
+                    (*entry.privileges()).into(),
+                );
+            }
+        }
+
+        let grant_list = grants
             .entries()
             .iter()
             .map(|e| e.to_string().into_bytes())
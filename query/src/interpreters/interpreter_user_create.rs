@@ -59,6 +59,7 @@ impl Interpreter for CreatUserInterpreter {
             auth_type: plan.auth_type,
             grants: UserGrantSet::empty(),
             quota: UserQuota::no_limit(),
+            roles: vec![],
         };
         user_mgr.add_user(user_info).await?;
 
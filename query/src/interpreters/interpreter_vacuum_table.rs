@@ -0,0 +1,66 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::series::Series;
+use common_exception::Result;
+use common_planners::VacuumTablePlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::sessions::QueryContext;
+
+pub struct VacuumTableInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: VacuumTablePlan,
+}
+
+impl VacuumTableInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: VacuumTablePlan) -> Result<InterpreterPtr> {
+        Ok(Arc::new(VacuumTableInterpreter { ctx, plan }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for VacuumTableInterpreter {
+    fn name(&self) -> &str {
+        "VacuumTableInterpreter"
+    }
+
+    async fn execute(
+        &self,
+        _input_stream: Option<SendableDataBlockStream>,
+    ) -> Result<SendableDataBlockStream> {
+        let database = self.plan.db.as_str();
+        let table = self.plan.table.as_str();
+        let vacuum_table = self.ctx.get_table(database, table).await?;
+
+        let stats = vacuum_table
+            .vacuum(self.ctx.clone(), self.plan.clone())
+            .await?;
+        let block = DataBlock::create_by_array(self.plan.schema(), vec![
+            Series::new(vec![stats.removed_files]),
+            Series::new(vec![stats.removed_bytes]),
+        ]);
+        Ok(Box::pin(DataBlockStream::create(
+            self.plan.schema(),
+            None,
+            vec![block],
+        )))
+    }
+}
@@ -0,0 +1,77 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_meta_types::CreateTableReq;
+use common_meta_types::TableMeta;
+use common_planners::CreateViewPlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::catalogs::Catalog;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::sessions::QueryContext;
+use crate::storages::view::QUERY;
+use crate::storages::view::VIEW_ENGINE;
+
+pub struct CreateViewInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateViewPlan,
+}
+
+impl CreateViewInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateViewPlan) -> Result<InterpreterPtr> {
+        Ok(Arc::new(CreateViewInterpreter { ctx, plan }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateViewInterpreter {
+    fn name(&self) -> &str {
+        "CreateViewInterpreter"
+    }
+
+    async fn execute(
+        &self,
+        _input_stream: Option<SendableDataBlockStream>,
+    ) -> Result<SendableDataBlockStream> {
+        // The view is stored as a table of the VIEW engine, with the defining query in its options.
+        let mut options = HashMap::new();
+        options.insert(QUERY.to_string(), self.plan.subquery.clone());
+
+        let plan = CreateTableReq {
+            if_not_exists: self.plan.if_not_exists,
+            db: self.plan.db.clone(),
+            table: self.plan.viewname.clone(),
+            table_meta: TableMeta {
+                schema: self.plan.view_schema.clone(),
+                engine: VIEW_ENGINE.to_string(),
+                options,
+            },
+        };
+
+        let catalog = self.ctx.get_catalog();
+        catalog.create_table(plan).await?;
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.plan.schema(),
+            None,
+            vec![],
+        )))
+    }
+}
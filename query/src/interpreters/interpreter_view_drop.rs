@@ -0,0 +1,79 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::DropTableReq;
+use common_planners::DropViewPlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::catalogs::Catalog;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::sessions::QueryContext;
+use crate::storages::view::VIEW_ENGINE;
+
+pub struct DropViewInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DropViewPlan,
+}
+
+impl DropViewInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DropViewPlan) -> Result<InterpreterPtr> {
+        Ok(Arc::new(DropViewInterpreter { ctx, plan }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DropViewInterpreter {
+    fn name(&self) -> &str {
+        "DropViewInterpreter"
+    }
+
+    async fn execute(
+        &self,
+        _input_stream: Option<SendableDataBlockStream>,
+    ) -> Result<SendableDataBlockStream> {
+        let catalog = self.ctx.get_catalog();
+        let db = &self.plan.db;
+        let viewname = &self.plan.viewname;
+
+        match catalog.get_table(db, viewname).await {
+            Ok(table) if !table.engine().eq_ignore_ascii_case(VIEW_ENGINE) => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "{}.{} is not a VIEW, please use DROP TABLE {}.{}",
+                    db, viewname, db, viewname
+                )));
+            }
+            Err(e) if !self.plan.if_exists => return Err(e),
+            _ => {}
+        }
+
+        let plan = DropTableReq {
+            if_exists: self.plan.if_exists,
+            db: db.clone(),
+            table: viewname.clone(),
+        };
+        catalog.drop_table(plan).await?;
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.plan.schema(),
+            None,
+            vec![],
+        )))
+    }
+}
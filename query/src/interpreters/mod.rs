@@ -12,11 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod access;
 mod interpreter;
 mod interpreter_common;
 mod interpreter_copy;
 mod interpreter_database_create;
 mod interpreter_database_drop;
+mod interpreter_delete;
 mod interpreter_describe_stage;
 mod interpreter_describe_table;
 mod interpreter_explain;
@@ -29,26 +31,38 @@ mod interpreter_interceptor;
 mod interpreter_kill;
 mod interpreter_query_log;
 mod interpreter_revoke_privilege;
+mod interpreter_role_create;
+mod interpreter_role_drop;
+mod interpreter_role_grant;
+mod interpreter_role_revoke;
+mod interpreter_role_set;
 mod interpreter_select;
 mod interpreter_setting;
 mod interpreter_show_create_table;
 mod interpreter_show_grants;
 mod interpreter_stage_create;
+mod interpreter_table_alter;
 mod interpreter_table_create;
 mod interpreter_table_drop;
 mod interpreter_truncate_table;
+mod interpreter_update;
 mod interpreter_use_database;
 mod interpreter_user_alter;
 mod interpreter_user_create;
 mod interpreter_user_drop;
+mod interpreter_vacuum_table;
+mod interpreter_view_create;
+mod interpreter_view_drop;
 mod plan_schedulers;
 mod stream_addon;
 
+pub use access::PrivilegeAccess;
 pub use interpreter::Interpreter;
 pub use interpreter::InterpreterPtr;
 pub use interpreter_copy::CopyInterpreter;
 pub use interpreter_database_create::CreateDatabaseInterpreter;
 pub use interpreter_database_drop::DropDatabaseInterpreter;
+pub use interpreter_delete::DeleteInterpreter;
 pub use interpreter_describe_stage::DescribeStageInterpreter;
 pub use interpreter_describe_table::DescribeTableInterpreter;
 pub use interpreter_explain::ExplainInterpreter;
@@ -61,17 +75,27 @@ pub use interpreter_query_log::InterpreterQueryLog;
 pub use interpreter_query_log::LogEvent;
 pub use interpreter_query_log::LogType;
 pub use interpreter_revoke_privilege::RevokePrivilegeInterpreter;
+pub use interpreter_role_create::CreateRoleInterpreter;
+pub use interpreter_role_drop::DropRoleInterpreter;
+pub use interpreter_role_grant::GrantRoleInterpreter;
+pub use interpreter_role_revoke::RevokeRoleInterpreter;
+pub use interpreter_role_set::SetRoleInterpreter;
 pub use interpreter_select::SelectInterpreter;
 pub use interpreter_setting::SettingInterpreter;
 pub use interpreter_show_create_table::ShowCreateTableInterpreter;
 pub use interpreter_show_grants::ShowGrantsInterpreter;
 pub use interpreter_stage_create::CreatStageInterpreter;
+pub use interpreter_table_alter::AlterTableInterpreter;
 pub use interpreter_table_create::CreateTableInterpreter;
 pub use interpreter_table_drop::DropTableInterpreter;
 pub use interpreter_truncate_table::TruncateTableInterpreter;
+pub use interpreter_update::UpdateInterpreter;
 pub use interpreter_use_database::UseDatabaseInterpreter;
 pub use interpreter_user_alter::AlterUserInterpreter;
 pub use interpreter_user_create::CreatUserInterpreter;
 pub use interpreter_user_drop::DropUserInterpreter;
+pub use interpreter_vacuum_table::VacuumTableInterpreter;
+pub use interpreter_view_create::CreateViewInterpreter;
+pub use interpreter_view_drop::DropViewInterpreter;
 pub use plan_schedulers::PlanScheduler;
 pub use stream_addon::AddOnStream;
@@ -29,6 +29,7 @@ use common_planners::ExpressionPlan;
 use common_planners::Expressions;
 use common_planners::FilterPlan;
 use common_planners::HavingPlan;
+use common_planners::JoinPlan;
 use common_planners::LimitByPlan;
 use common_planners::LimitPlan;
 use common_planners::Partitions;
@@ -42,6 +43,7 @@ use common_planners::SortPlan;
 use common_planners::StageKind;
 use common_planners::StagePlan;
 use common_planners::SubQueriesSetPlan;
+use common_planners::WindowPlan;
 use common_tracing::tracing;
 
 use crate::api::BroadcastAction;
@@ -300,6 +302,7 @@ impl PlanScheduler {
             PlanNode::Projection(plan) => self.visit_projection(plan, tasks),
             PlanNode::Filter(plan) => self.visit_filter(plan, tasks),
             PlanNode::Sort(plan) => self.visit_sort(plan, tasks),
+            PlanNode::Window(plan) => self.visit_window(plan, tasks),
             PlanNode::Limit(plan) => self.visit_limit(plan, tasks),
             PlanNode::LimitBy(plan) => self.visit_limit_by(plan, tasks),
             PlanNode::ReadSource(plan) => self.visit_data_source(plan, tasks),
@@ -308,6 +311,7 @@ impl PlanScheduler {
             PlanNode::Stage(plan) => self.visit_stage(plan, tasks),
             PlanNode::Broadcast(plan) => self.visit_broadcast(plan, tasks),
             PlanNode::Having(plan) => self.visit_having(plan, tasks),
+            PlanNode::Join(plan) => self.visit_join(plan, tasks),
             PlanNode::Expression(plan) => self.visit_expression(plan, tasks),
             PlanNode::SubQueryExpression(plan) => self.visit_subqueries_set(plan, tasks),
             _ => Err(ErrorCode::UnImplement("")),
@@ -645,6 +649,54 @@ impl PlanScheduler {
         Ok(subquery_scheduler.nodes_plan)
     }
 
+    fn visit_join(&mut self, plan: &JoinPlan, tasks: &mut Tasks) -> Result<()> {
+        self.visit_plan_node(plan.left.as_ref(), tasks)?;
+
+        // The build side is scheduled as a subquery, it must be in the same mode as the probe side.
+        let right_context = QueryContext::new(self.query_context.clone());
+        let mut right_scheduler = PlanScheduler::try_create(right_context)?;
+        right_scheduler.visit_plan_node(plan.right.as_ref(), tasks)?;
+
+        if right_scheduler.running_mode != self.running_mode {
+            return Err(ErrorCode::LogicalError(
+                "Join build side running mode miss match probe side",
+            ));
+        }
+
+        let right_nodes_plan = right_scheduler.nodes_plan;
+        match self.running_mode {
+            RunningMode::Cluster => self.visit_cluster_join(plan, &right_nodes_plan),
+            RunningMode::Standalone => self.visit_local_join(plan, &right_nodes_plan),
+        };
+        Ok(())
+    }
+
+    fn visit_local_join(&mut self, plan: &JoinPlan, right_nodes_plan: &[PlanNode]) {
+        self.nodes_plan[self.local_pos] = PlanNode::Join(JoinPlan {
+            join_type: plan.join_type,
+            left_keys: plan.left_keys.clone(),
+            right_keys: plan.right_keys.clone(),
+            other_condition: plan.other_condition.clone(),
+            schema: plan.schema.clone(),
+            left: Arc::new(self.nodes_plan[self.local_pos].clone()),
+            right: Arc::new(right_nodes_plan[self.local_pos].clone()),
+        });
+    }
+
+    fn visit_cluster_join(&mut self, plan: &JoinPlan, right_nodes_plan: &[PlanNode]) {
+        for index in 0..self.nodes_plan.len() {
+            self.nodes_plan[index] = PlanNode::Join(JoinPlan {
+                join_type: plan.join_type,
+                left_keys: plan.left_keys.clone(),
+                right_keys: plan.right_keys.clone(),
+                other_condition: plan.other_condition.clone(),
+                schema: plan.schema.clone(),
+                left: Arc::new(self.nodes_plan[index].clone()),
+                right: Arc::new(right_nodes_plan[index].clone()),
+            });
+        }
+    }
+
     fn visit_filter(&mut self, plan: &FilterPlan, tasks: &mut Tasks) -> Result<()> {
         self.visit_plan_node(plan.input.as_ref(), tasks)?;
         match self.running_mode {
@@ -726,6 +778,33 @@ impl PlanScheduler {
         }
     }
 
+    fn visit_window(&mut self, plan: &WindowPlan, tasks: &mut Tasks) -> Result<()> {
+        self.visit_plan_node(plan.input.as_ref(), tasks)?;
+        match self.running_mode {
+            RunningMode::Cluster => self.visit_cluster_window(plan),
+            RunningMode::Standalone => self.visit_local_window(plan),
+        };
+        Ok(())
+    }
+
+    fn visit_local_window(&mut self, plan: &WindowPlan) {
+        self.nodes_plan[self.local_pos] = PlanNode::Window(WindowPlan {
+            window_func: plan.window_func.clone(),
+            schema: plan.schema.clone(),
+            input: Arc::new(self.nodes_plan[self.local_pos].clone()),
+        });
+    }
+
+    fn visit_cluster_window(&mut self, plan: &WindowPlan) {
+        for index in 0..self.nodes_plan.len() {
+            self.nodes_plan[index] = PlanNode::Window(WindowPlan {
+                window_func: plan.window_func.clone(),
+                schema: plan.schema.clone(),
+                input: Arc::new(self.nodes_plan[index].clone()),
+            });
+        }
+    }
+
     fn visit_limit(&mut self, plan: &LimitPlan, tasks: &mut Tasks) -> Result<()> {
         self.visit_plan_node(plan.input.as_ref(), tasks)?;
         match self.running_mode {
@@ -256,6 +256,7 @@ impl PlanRewriter for ExprTransformImpl {
                 description: format!("(Read from {} table)", plan.table_info.desc),
                 tbl_args: plan.tbl_args.clone(),
                 push_downs: plan.push_downs.clone(),
+                source_table: plan.source_table.clone(),
             });
             return Ok(node);
         }
@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use common_datavalues::DataSchemaRef;
+use common_datavalues::DataType;
 use common_datavalues::DataValue;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -22,6 +23,7 @@ use common_planners::AggregatorFinalPlan;
 use common_planners::AggregatorPartialPlan;
 use common_planners::BroadcastPlan;
 use common_planners::Expression;
+use common_planners::JoinPlan;
 use common_planners::LimitByPlan;
 use common_planners::LimitPlan;
 use common_planners::PlanBuilder;
@@ -31,6 +33,7 @@ use common_planners::ReadDataSourcePlan;
 use common_planners::SortPlan;
 use common_planners::StageKind;
 use common_planners::StagePlan;
+use common_planners::WindowPlan;
 
 use crate::optimizers::Optimizer;
 use crate::sessions::QueryContext;
@@ -130,6 +133,27 @@ impl ScattersOptimizerImpl {
         }
     }
 
+    fn cluster_window(&mut self, plan: &WindowPlan) -> Result<PlanNode> {
+        // Window we convergent it in local node
+        self.running_mode = RunningMode::Standalone;
+
+        match self.input.take() {
+            None => Err(ErrorCode::LogicalError("Cluster window input is None")),
+            Some(input) => Self::convergent_shuffle_stage_builder(input)
+                .window(plan.window_func.clone())?
+                .build(),
+        }
+    }
+
+    fn standalone_window(&mut self, plan: &WindowPlan) -> Result<PlanNode> {
+        match self.input.take() {
+            None => Err(ErrorCode::LogicalError("Standalone window input is None")),
+            Some(input) => PlanBuilder::from(input.as_ref())
+                .window(plan.window_func.clone())?
+                .build(),
+        }
+    }
+
     fn cluster_limit(&mut self, plan: &LimitPlan) -> Result<PlanNode> {
         // Limit we convergent it in local node
         self.running_mode = RunningMode::Standalone;
@@ -174,6 +198,133 @@ impl ScattersOptimizerImpl {
         }
     }
 
+    fn cluster_join(
+        &mut self,
+        plan: &JoinPlan,
+        (left, left_running_mode): (PlanNode, RunningMode),
+        (right, right_running_mode): (PlanNode, RunningMode),
+    ) -> Result<PlanNode> {
+        let settings = self.ctx.get_settings();
+        let max_broadcast_bytes = settings.get_max_broadcast_join_bytes()? as usize;
+        let broadcast_right = matches!(left_running_mode, RunningMode::Cluster)
+            && !plan.join_type.preserves_right()
+            && Self::estimated_read_bytes(&right) <= max_broadcast_bytes;
+
+        if broadcast_right {
+            // Each node joins its own part of the left side with the whole right side.
+            self.running_mode = RunningMode::Cluster;
+            let right = PlanNode::Broadcast(BroadcastPlan {
+                input: Arc::new(right),
+            });
+
+            return Self::join(plan, &left, &right);
+        }
+
+        if Self::is_shuffle_keys(&plan.left_keys, &left.schema())? {
+            // Both sides are repartitioned by the join keys, the same keys meet on the same node.
+            self.running_mode = RunningMode::Cluster;
+            let left = Self::join_keys_shuffle_stage(&plan.left_keys[0], left, &left_running_mode)?;
+            let right =
+                Self::join_keys_shuffle_stage(&plan.right_keys[0], right, &right_running_mode)?;
+
+            return Self::join(plan, &left, &right);
+        }
+
+        self.running_mode = RunningMode::Standalone;
+        let left = match left_running_mode {
+            RunningMode::Cluster => Self::convergent_shuffle_stage(left)?,
+            RunningMode::Standalone => left,
+        };
+
+        let right = match right_running_mode {
+            RunningMode::Cluster => Self::convergent_shuffle_stage(right)?,
+            RunningMode::Standalone => right,
+        };
+
+        Self::join(plan, &left, &right)
+    }
+
+    fn standalone_join(
+        &mut self,
+        plan: &JoinPlan,
+        left: PlanNode,
+        right: PlanNode,
+    ) -> Result<PlanNode> {
+        self.running_mode = RunningMode::Standalone;
+        Self::join(plan, &left, &right)
+    }
+
+    fn join(plan: &JoinPlan, left: &PlanNode, right: &PlanNode) -> Result<PlanNode> {
+        PlanBuilder::from(left)
+            .join(
+                right,
+                plan.join_type,
+                &plan.left_keys,
+                &plan.right_keys,
+                plan.other_condition.clone(),
+            )?
+            .build()
+    }
+
+    fn join_keys_shuffle_stage(
+        key: &Expression,
+        input: PlanNode,
+        running_mode: &RunningMode,
+    ) -> Result<PlanNode> {
+        // Rows with the same keys always have the same first key.
+        let scatters_expr = Expression::ScalarFunction {
+            op: String::from("sipHash"),
+            args: vec![key.clone()],
+        };
+
+        let kind = match running_mode {
+            RunningMode::Cluster => StageKind::Normal,
+            RunningMode::Standalone => StageKind::Expansive,
+        };
+
+        Ok(PlanNode::Stage(StagePlan {
+            kind,
+            scatters_expr,
+            input: Arc::new(input),
+        }))
+    }
+
+    fn is_shuffle_keys(keys: &[Expression], schema: &DataSchemaRef) -> Result<bool> {
+        match keys.first() {
+            None => Ok(false),
+            Some(key) => Ok(matches!(
+                key.to_data_type(schema)?,
+                DataType::Int8
+                    | DataType::Int16
+                    | DataType::Int32
+                    | DataType::Int64
+                    | DataType::UInt8
+                    | DataType::UInt16
+                    | DataType::UInt32
+                    | DataType::UInt64
+                    | DataType::Float32
+                    | DataType::Float64
+                    | DataType::Date16
+                    | DataType::Date32
+                    | DataType::DateTime32(_)
+                    | DataType::DateTime64(_, _)
+                    | DataType::String
+            )),
+        }
+    }
+
+    /// The estimated bytes to read from the statistics of read_partitions.
+    fn estimated_read_bytes(plan: &PlanNode) -> usize {
+        match plan {
+            PlanNode::ReadSource(plan) => plan.statistics.read_bytes,
+            plan => plan
+                .inputs()
+                .iter()
+                .map(|input| Self::estimated_read_bytes(input.as_ref()))
+                .sum(),
+        }
+    }
+
     fn convergent_shuffle_stage_builder(input: Arc<PlanNode>) -> PlanBuilder {
         PlanBuilder::from(&PlanNode::Stage(StagePlan {
             kind: StageKind::Convergent,
@@ -228,6 +379,28 @@ impl PlanRewriter for ScattersOptimizerImpl {
         }
     }
 
+    fn rewrite_join(&mut self, plan: &JoinPlan) -> Result<PlanNode> {
+        let new_left = self.rewrite_plan_node(plan.left.as_ref())?;
+        let left_running_mode = self.running_mode.clone();
+
+        // The build side is pulled by a separate pipeline, like the subquery.
+        let right_ctx = QueryContext::new(self.ctx.clone());
+        let mut right_optimizer = ScattersOptimizerImpl::create(right_ctx);
+        let new_right = right_optimizer.rewrite_plan_node(plan.right.as_ref())?;
+        let right_running_mode = right_optimizer.running_mode;
+
+        match (&left_running_mode, &right_running_mode) {
+            (RunningMode::Standalone, RunningMode::Standalone) => {
+                self.standalone_join(plan, new_left, new_right)
+            }
+            _ => self.cluster_join(
+                plan,
+                (new_left, left_running_mode),
+                (new_right, right_running_mode),
+            ),
+        }
+    }
+
     fn rewrite_aggregate_partial(&mut self, plan: &AggregatorPartialPlan) -> Result<PlanNode> {
         let new_input = Arc::new(self.rewrite_plan_node(&plan.input)?);
 
@@ -260,6 +433,15 @@ impl PlanRewriter for ScattersOptimizerImpl {
         }
     }
 
+    fn rewrite_window(&mut self, plan: &WindowPlan) -> Result<PlanNode> {
+        self.input = Some(Arc::new(self.rewrite_plan_node(plan.input.as_ref())?));
+
+        match self.running_mode {
+            RunningMode::Cluster => self.cluster_window(plan),
+            RunningMode::Standalone => self.standalone_window(plan),
+        }
+    }
+
     fn rewrite_limit(&mut self, plan: &LimitPlan) -> Result<PlanNode> {
         self.input = Some(Arc::new(self.rewrite_plan_node(plan.input.as_ref())?));
 
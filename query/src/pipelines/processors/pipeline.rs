@@ -16,6 +16,7 @@ use std::sync::Arc;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_streams::MemoryLimitStream;
 use common_streams::SendableDataBlockStream;
 
 use super::MixedProcessor;
@@ -154,6 +155,10 @@ impl Pipeline {
         if self.last_pipe()?.nums() > 1 {
             self.merge_processor()?;
         }
-        self.last_pipe()?.first().execute().await
+        let stream = self.last_pipe()?.first().execute().await?;
+
+        // Fails the query whose memory limit is exceeded after the sources are drained.
+        let tracker = self.ctx.get_shared_runtime()?.get_tracker();
+        Ok(Box::pin(MemoryLimitStream::try_create(stream, tracker)?))
     }
 }
@@ -21,6 +21,7 @@ use common_planners::BroadcastPlan;
 use common_planners::ExpressionPlan;
 use common_planners::FilterPlan;
 use common_planners::HavingPlan;
+use common_planners::JoinPlan;
 use common_planners::LimitByPlan;
 use common_planners::LimitPlan;
 use common_planners::PlanNode;
@@ -32,17 +33,21 @@ use common_planners::SinkPlan;
 use common_planners::SortPlan;
 use common_planners::StagePlan;
 use common_planners::SubQueriesSetPlan;
+use common_planners::WindowPlan;
 use common_tracing::tracing;
 
 use crate::api::FlightTicket;
 use crate::pipelines::processors::Pipeline;
+use crate::pipelines::transforms::window_sort_exprs;
 use crate::pipelines::transforms::AggregatorFinalTransform;
 use crate::pipelines::transforms::AggregatorPartialTransform;
 use crate::pipelines::transforms::CreateSetsTransform;
 use crate::pipelines::transforms::ExpressionTransform;
 use crate::pipelines::transforms::GroupByFinalTransform;
 use crate::pipelines::transforms::GroupByPartialTransform;
+use crate::pipelines::transforms::HashJoinTransform;
 use crate::pipelines::transforms::HavingTransform;
+use crate::pipelines::transforms::JoinBuildSidePuller;
 use crate::pipelines::transforms::LimitByTransform;
 use crate::pipelines::transforms::LimitTransform;
 use crate::pipelines::transforms::ProjectionTransform;
@@ -53,6 +58,7 @@ use crate::pipelines::transforms::SortPartialTransform;
 use crate::pipelines::transforms::SourceTransform;
 use crate::pipelines::transforms::SubQueriesPuller;
 use crate::pipelines::transforms::WhereTransform;
+use crate::pipelines::transforms::WindowTransform;
 use crate::sessions::QueryContext;
 
 pub struct PipelineBuilder {
@@ -91,7 +97,9 @@ impl PipelineBuilder {
             PlanNode::AggregatorFinal(node) => self.visit_aggregator_final(node),
             PlanNode::Filter(node) => self.visit_filter(node),
             PlanNode::Having(node) => self.visit_having(node),
+            PlanNode::Join(node) => self.visit_join(node),
             PlanNode::Sort(node) => self.visit_sort(node),
+            PlanNode::Window(node) => self.visit_window(node),
             PlanNode::Limit(node) => self.visit_limit(node),
             PlanNode::LimitBy(node) => self.visit_limit_by(node),
             PlanNode::ReadSource(node) => self.visit_read_data_source(node),
@@ -284,6 +292,59 @@ impl PipelineBuilder {
         Ok(pipeline)
     }
 
+    fn visit_window(&mut self, plan: &WindowPlan) -> Result<Pipeline> {
+        // The limit is applied after the window, every row of the input is required.
+        let (limit, offset) = (self.limit.take(), self.offset);
+        self.offset = 0;
+        let mut pipeline = self.visit(&*plan.input)?;
+        self.limit = limit;
+        self.offset = offset;
+
+        let input_schema = plan.input.schema();
+        let sort_exprs = window_sort_exprs(&plan.window_func)?;
+
+        // Sort by the partition keys and the order keys, then merge to one sorted block
+        if !sort_exprs.is_empty() {
+            pipeline.add_simple_transform(|| {
+                Ok(Box::new(SortPartialTransform::try_create(
+                    input_schema.clone(),
+                    sort_exprs.clone(),
+                    None,
+                )?))
+            })?;
+
+            pipeline.add_simple_transform(|| {
+                Ok(Box::new(SortMergeTransform::try_create(
+                    input_schema.clone(),
+                    sort_exprs.clone(),
+                    None,
+                )?))
+            })?;
+        }
+
+        if pipeline.last_pipe()?.nums() > 1 {
+            pipeline.merge_processor()?;
+            if !sort_exprs.is_empty() {
+                pipeline.add_simple_transform(|| {
+                    Ok(Box::new(SortMergeTransform::try_create(
+                        input_schema.clone(),
+                        sort_exprs.clone(),
+                        None,
+                    )?))
+                })?;
+            }
+        }
+
+        pipeline.add_simple_transform(|| {
+            Ok(Box::new(WindowTransform::try_create(
+                plan.window_func.clone(),
+                plan.schema(),
+                input_schema.clone(),
+            )?))
+        })?;
+        Ok(pipeline)
+    }
+
     fn visit_limit(&mut self, node: &LimitPlan) -> Result<Pipeline> {
         self.limit = node.n;
         self.offset = node.offset;
@@ -353,4 +414,39 @@ impl PipelineBuilder {
 
         Ok(pipeline)
     }
+
+    fn visit_join(&mut self, plan: &JoinPlan) -> Result<Pipeline> {
+        let mut pipeline = self.visit(&*plan.left)?;
+
+        // The unmatched rows of the build side can only be found after all the probe rows.
+        if plan.join_type.preserves_right() && pipeline.last_pipe()?.nums() > 1 {
+            pipeline.merge_processor()?;
+        }
+
+        let schema = plan.schema();
+        let left_schema = plan.left.schema();
+        let context = self.ctx.clone();
+        let join_type = plan.join_type;
+        let left_keys = plan.left_keys.clone();
+        let other_condition = plan.other_condition.clone();
+        let build_side_puller = JoinBuildSidePuller::create(
+            context.clone(),
+            plan.right.as_ref().clone(),
+            plan.right_keys.clone(),
+        );
+
+        pipeline.add_simple_transform(move || {
+            Ok(Box::new(HashJoinTransform::try_create(
+                context.clone(),
+                join_type,
+                left_keys.clone(),
+                other_condition.clone(),
+                schema.clone(),
+                left_schema.clone(),
+                build_side_puller.clone(),
+            )?))
+        })?;
+
+        Ok(pipeline)
+    }
 }
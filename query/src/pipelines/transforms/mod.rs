@@ -22,6 +22,8 @@ pub use transform_filter::HavingTransform;
 pub use transform_filter::WhereTransform;
 pub use transform_group_by_final::GroupByFinalTransform;
 pub use transform_group_by_partial::GroupByPartialTransform;
+pub use transform_hash_join::HashJoinTransform;
+pub use transform_hash_join::JoinBuildSidePuller;
 pub use transform_limit::LimitTransform;
 pub use transform_limit_by::LimitByTransform;
 pub use transform_projection::ProjectionTransform;
@@ -31,6 +33,8 @@ pub use transform_sort_merge::SortMergeTransform;
 pub use transform_sort_partial::get_sort_descriptions;
 pub use transform_sort_partial::SortPartialTransform;
 pub use transform_source::SourceTransform;
+pub use transform_window::window_sort_exprs;
+pub use transform_window::WindowTransform;
 
 mod transform_aggregator_final;
 mod transform_aggregator_partial;
@@ -40,6 +44,7 @@ mod transform_expression_executor;
 mod transform_filter;
 mod transform_group_by_final;
 mod transform_group_by_partial;
+mod transform_hash_join;
 mod transform_limit;
 mod transform_limit_by;
 mod transform_projection;
@@ -47,6 +52,7 @@ mod transform_remote;
 mod transform_sort_merge;
 mod transform_sort_partial;
 mod transform_source;
+mod transform_window;
 
 mod group_by;
 mod transform_sink;
@@ -22,6 +22,7 @@ use common_datavalues::DataField;
 use common_datavalues::DataSchemaRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_functions::scalars::ArrayJoinFunction;
 use common_planners::Expression;
 use common_planners::ExpressionAction;
 use common_planners::ExpressionChain;
@@ -87,7 +88,7 @@ impl ExpressionExecutor {
             column_map.insert(f.name(), column);
         }
 
-        let rows = block.num_rows();
+        let mut rows = block.num_rows();
         for action in self.chain.actions.iter() {
             if let ExpressionAction::Alias(alias) = action {
                 if let Some(v) = alias_action_map.get_mut(alias.arg_name.as_str()) {
@@ -126,6 +127,16 @@ impl ExpressionExecutor {
                     let func = f.to_function()?;
                     let column = func.eval(&arg_columns, rows)?;
 
+                    // arrayJoin expands the rows, so the evaluated columns are replicated to the expanded rows.
+                    if f.is_array_join() {
+                        let indices =
+                            ArrayJoinFunction::replicate_indices(arg_columns[0].column())?;
+                        for column in column_map.values_mut() {
+                            *column = Self::replicate_column(column, &indices)?;
+                        }
+                        rows = indices.len();
+                    }
+
                     let column = DataColumnWithField::new(
                         column,
                         DataField::new(&f.name, f.return_type.clone(), f.is_nullable),
@@ -189,4 +200,15 @@ impl ExpressionExecutor {
             project_columns,
         ))
     }
+
+    fn replicate_column(
+        column: &DataColumnWithField,
+        indices: &[usize],
+    ) -> Result<DataColumnWithField> {
+        let replicated = match column.column() {
+            DataColumn::Constant(value, _) => DataColumn::Constant(value.clone(), indices.len()),
+            DataColumn::Array(array) => array.take_iter(&mut indices.iter().copied())?.into(),
+        };
+        Ok(DataColumnWithField::new(replicated, column.field().clone()))
+    }
 }
@@ -0,0 +1,568 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Instant;
+
+use bumpalo::Bump;
+use common_base::TrySpawn;
+use common_datablocks::DataBlock;
+use common_datablocks::HashMethod;
+use common_datablocks::HashMethodSerializer;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_infallible::Mutex;
+use common_planners::Expression;
+use common_planners::JoinType;
+use common_planners::PlanNode;
+use common_streams::CorrectWithSchemaStream;
+use common_streams::SendableDataBlockStream;
+use common_tracing::tracing;
+use futures::future::BoxFuture;
+use futures::future::Shared;
+use futures::Future;
+use futures::FutureExt;
+use tokio_stream::StreamExt;
+
+use crate::common::HashMap;
+use crate::common::HashTableEntity;
+use crate::pipelines::processors::EmptyProcessor;
+use crate::pipelines::processors::PipelineBuilder;
+use crate::pipelines::processors::Processor;
+use crate::pipelines::transforms::group_by::KeysRef;
+use crate::pipelines::transforms::ExpressionExecutor;
+use crate::sessions::QueryContext;
+
+/// Hash join, the right input is the build side and the left input is the probe side.
+///
+/// The build side is pulled once and shared by all the probe processors:
+///     - Build: concat all the right blocks, and hash the serialized keys of each row
+///     - Probe: for each left row, find the right rows with the same keys, then apply
+///       the other join condition on the candidate pairs
+///     - For LEFT/FULL join, the left rows without match are padded with NULLs
+///     - For RIGHT/FULL join, the right rows never matched are padded with NULLs and
+///       returned after the probe side is finished, so the probe side must be merged
+///       into one processor.
+pub struct HashJoinTransform {
+    ctx: Arc<QueryContext>,
+    join_type: JoinType,
+    left_keys: Vec<Expression>,
+    other_condition: Option<Expression>,
+    schema: DataSchemaRef,
+    left_schema: DataSchemaRef,
+    input: Arc<dyn Processor>,
+    build_side_puller: Arc<Mutex<JoinBuildSidePuller<'static>>>,
+}
+
+impl HashJoinTransform {
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_create(
+        ctx: Arc<QueryContext>,
+        join_type: JoinType,
+        left_keys: Vec<Expression>,
+        other_condition: Option<Expression>,
+        schema: DataSchemaRef,
+        left_schema: DataSchemaRef,
+        build_side_puller: Arc<Mutex<JoinBuildSidePuller<'static>>>,
+    ) -> Result<HashJoinTransform> {
+        Ok(HashJoinTransform {
+            ctx,
+            join_type,
+            left_keys,
+            other_condition,
+            schema,
+            left_schema,
+            build_side_puller,
+            input: Arc::new(EmptyProcessor::create()),
+        })
+    }
+
+    async fn pull_build_side(&self) -> Result<Arc<JoinHashTable>> {
+        let future = self.build_side_puller.lock().take_hash_table()?;
+
+        match self.ctx.try_spawn(future)?.await {
+            Ok(hash_table) => hash_table,
+            Err(error) => Err(ErrorCode::TokioError(format!(
+                "Cannot pull the build side of join. cause: {}",
+                error
+            ))),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for HashJoinTransform {
+    fn name(&self) -> &str {
+        "HashJoinTransform"
+    }
+
+    fn connect_to(&mut self, input: Arc<dyn Processor>) -> Result<()> {
+        self.input = input;
+        Ok(())
+    }
+
+    fn inputs(&self) -> Vec<Arc<dyn Processor>> {
+        vec![self.input.clone()]
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        tracing::debug!("execute...");
+
+        let start = Instant::now();
+        let hash_table = self.pull_build_side().await?;
+        tracing::debug!("Join build side cost: {:?}", start.elapsed());
+
+        let probe = Arc::new(JoinProbe::try_create(
+            self.join_type,
+            &self.left_keys,
+            &self.other_condition,
+            self.schema.clone(),
+            self.left_schema.clone(),
+            hash_table,
+        )?);
+
+        let input_stream = self.input.execute().await?;
+        let probe_side = probe.clone();
+        let stream = input_stream.filter_map(move |data_block| match data_block {
+            Err(fail) => Some(Err(fail)),
+            Ok(data_block) if data_block.is_empty() => None,
+            Ok(data_block) => match probe_side.probe(&data_block) {
+                Err(fail) => Some(Err(fail)),
+                Ok(data_block) if data_block.is_empty() => None,
+                Ok(data_block) => Some(Ok(data_block)),
+            },
+        });
+
+        match self.join_type.preserves_right() {
+            false => Ok(Box::pin(CorrectWithSchemaStream::new(
+                Box::pin(stream),
+                self.schema.clone(),
+            ))),
+            true => {
+                // It's polled only after the probe side is finished.
+                let unmatched = futures::stream::once(async move { probe.unmatched_build_rows() })
+                    .filter(|data_block| !matches!(data_block, Ok(block) if block.is_empty()));
+
+                Ok(Box::pin(CorrectWithSchemaStream::new(
+                    Box::pin(stream.chain(unmatched)),
+                    self.schema.clone(),
+                )))
+            }
+        }
+    }
+}
+
+type JoinHashTableData = Result<Arc<JoinHashTable>>;
+type SharedFuture<'a> = Shared<BoxFuture<'a, JoinHashTableData>>;
+
+pub struct JoinBuildSidePuller<'a> {
+    ctx: Arc<QueryContext>,
+    plan: PlanNode,
+    keys: Vec<Expression>,
+    hash_table: Option<SharedFuture<'a>>,
+}
+
+impl<'a> JoinBuildSidePuller<'a> {
+    pub fn create(
+        ctx: Arc<QueryContext>,
+        plan: PlanNode,
+        keys: Vec<Expression>,
+    ) -> Arc<Mutex<JoinBuildSidePuller<'a>>> {
+        Arc::new(Mutex::new(JoinBuildSidePuller {
+            ctx,
+            plan,
+            keys,
+            hash_table: None,
+        }))
+    }
+
+    pub fn take_hash_table(&mut self) -> Result<impl Future<Output = JoinHashTableData> + 'a> {
+        if self.hash_table.is_none() {
+            self.init()?;
+        }
+
+        Ok(self.hash_table.clone().unwrap())
+    }
+
+    fn init(&mut self) -> Result<()> {
+        // The build side is a separate pipeline, it needs its own partitions.
+        let build_side_ctx = QueryContext::new(self.ctx.clone());
+        let builder = PipelineBuilder::create(build_side_ctx);
+        let mut pipeline = builder.build(&self.plan)?;
+
+        let keys = self.keys.clone();
+        let schema = self.plan.schema();
+        let build_future = async move {
+            let mut stream = pipeline.execute().await?;
+
+            let mut blocks = vec![];
+            while let Some(data_block) = stream.next().await {
+                let data_block = data_block?;
+                if !data_block.is_empty() {
+                    let columns = data_block.columns().to_vec();
+                    blocks.push(DataBlock::create(schema.clone(), columns));
+                }
+            }
+
+            let hash_table = JoinHashTable::try_create(schema, &keys, blocks)?;
+            Ok(Arc::new(hash_table))
+        };
+
+        self.hash_table = Some(build_future.boxed().shared());
+        Ok(())
+    }
+}
+
+pub struct JoinHashTable {
+    block: DataBlock,
+    keys_area: Bump,
+    // The serialized keys to the position of rows_of_keys.
+    keys_map: HashMap<KeysRef, usize>,
+    rows_of_keys: Vec<Vec<u32>>,
+    // Whether the build row has been matched, only used by RIGHT/FULL join.
+    visited: Vec<AtomicBool>,
+}
+
+// TODO:(Winter) Hack:
+// The *mut KeyValueEntity needs to be used externally, but we can ensure that *mut KeyValueEntity
+// will not be modified after the hash table is built, so JoinHashTable is Send
+#[allow(clippy::non_send_fields_in_send_ty)]
+unsafe impl Send for JoinHashTable {}
+
+// TODO:(Winter) Hack:
+// The *mut KeyValueEntity needs to be used externally, but we can ensure that &*mut KeyValueEntity
+// will not be modified after the hash table is built, so JoinHashTable is Sync
+unsafe impl Sync for JoinHashTable {}
+
+impl JoinHashTable {
+    pub fn try_create(
+        schema: DataSchemaRef,
+        keys: &[Expression],
+        blocks: Vec<DataBlock>,
+    ) -> Result<JoinHashTable> {
+        let block = match blocks.is_empty() {
+            true => DataBlock::empty_with_schema(schema.clone()),
+            false => DataBlock::concat_blocks(&blocks)?,
+        };
+
+        let num_rows = block.num_rows();
+        let mut hash_table = JoinHashTable {
+            keys_area: Bump::new(),
+            keys_map: HashMap::create(),
+            rows_of_keys: vec![],
+            visited: (0..num_rows).map(|_| AtomicBool::new(false)).collect(),
+            block,
+        };
+
+        if !keys.is_empty() && num_rows != 0 {
+            let keys_executor = keys_executor("join build keys executor", &schema, keys)?;
+            let (serialized_keys, has_null) =
+                serialize_keys(&keys_executor, keys, &hash_table.block)?;
+
+            for (row, row_keys) in serialized_keys.iter().enumerate() {
+                // NULL never equals to anything.
+                if !has_null[row] {
+                    hash_table.insert(row_keys, row as u32);
+                }
+            }
+        }
+
+        Ok(hash_table)
+    }
+
+    fn insert(&mut self, keys: &[u8], row: u32) {
+        let mut inserted = false;
+        let mut keys_ref = KeysRef::create(keys.as_ptr() as usize, keys.len());
+        let entity = self.keys_map.insert_key(&keys_ref, &mut inserted);
+
+        match inserted {
+            true => {
+                unsafe {
+                    // Keys will be destroyed after build, we need copy the keys to the memory pool.
+                    let global_keys = self.keys_area.alloc_slice_copy(keys);
+                    let inserted_hash = entity.get_hash();
+                    keys_ref.address = global_keys.as_ptr() as usize;
+                    entity.set_key_and_hash(&keys_ref, inserted_hash);
+                }
+
+                entity.set_value(self.rows_of_keys.len());
+                self.rows_of_keys.push(vec![row]);
+            }
+            false => {
+                let position = *entity.get_value();
+                self.rows_of_keys[position].push(row);
+            }
+        }
+    }
+
+    fn find(&self, keys: &[u8]) -> Option<&[u32]> {
+        let keys_ref = KeysRef::create(keys.as_ptr() as usize, keys.len());
+        self.keys_map
+            .find_key(&keys_ref)
+            .map(|entity| self.rows_of_keys[*entity.get_value()].as_slice())
+    }
+
+    fn num_rows(&self) -> usize {
+        self.block.num_rows()
+    }
+}
+
+struct JoinProbe {
+    join_type: JoinType,
+    keys: Vec<Expression>,
+    keys_executor: Option<ExpressionExecutor>,
+    condition_executor: Option<(String, ExpressionExecutor)>,
+    schema: DataSchemaRef,
+    left_schema: DataSchemaRef,
+    hash_table: Arc<JoinHashTable>,
+}
+
+impl JoinProbe {
+    fn try_create(
+        join_type: JoinType,
+        keys: &[Expression],
+        condition: &Option<Expression>,
+        schema: DataSchemaRef,
+        left_schema: DataSchemaRef,
+        hash_table: Arc<JoinHashTable>,
+    ) -> Result<JoinProbe> {
+        let keys_executor = match keys.is_empty() {
+            true => None,
+            false => Some(keys_executor(
+                "join probe keys executor",
+                &left_schema,
+                keys,
+            )?),
+        };
+
+        let condition_executor = match condition {
+            None => None,
+            Some(condition) => {
+                let condition_field = condition.to_data_field(&schema)?;
+                let executor = ExpressionExecutor::try_create(
+                    "join condition executor",
+                    schema.clone(),
+                    DataSchemaRefExt::create(vec![condition_field]),
+                    vec![condition.clone()],
+                    false,
+                )?;
+
+                executor.validate()?;
+                Some((condition.column_name(), executor))
+            }
+        };
+
+        Ok(JoinProbe {
+            join_type,
+            keys: keys.to_vec(),
+            keys_executor,
+            condition_executor,
+            schema,
+            left_schema,
+            hash_table,
+        })
+    }
+
+    fn probe(&self, block: &DataBlock) -> Result<DataBlock> {
+        let num_rows = block.num_rows();
+        let mut left_indices = vec![];
+        let mut right_indices = vec![];
+
+        match &self.keys_executor {
+            None => {
+                // Without keys, each row matches all rows of the build side.
+                for row in 0..num_rows {
+                    for build_row in 0..self.hash_table.num_rows() {
+                        left_indices.push(row as u32);
+                        right_indices.push(build_row as u32);
+                    }
+                }
+            }
+            Some(executor) => {
+                let (serialized_keys, has_null) = serialize_keys(executor, &self.keys, block)?;
+
+                for (row, row_keys) in serialized_keys.iter().enumerate() {
+                    if has_null[row] {
+                        continue;
+                    }
+
+                    if let Some(build_rows) = self.hash_table.find(row_keys) {
+                        for build_row in build_rows {
+                            left_indices.push(row as u32);
+                            right_indices.push(*build_row);
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut matched_block = self.joined_block(block, &left_indices, &right_indices)?;
+
+        if let Some((column_name, executor)) = &self.condition_executor {
+            if !matched_block.is_empty() {
+                let condition_block = executor.execute(&matched_block)?;
+                let condition_column = condition_block.try_column_by_name(column_name)?;
+                let condition_array = condition_column
+                    .to_array()?
+                    .cast_with_type(&DataType::Boolean)?;
+
+                let condition_values = condition_array.bool()?.collect_values();
+                let mut filtered_left_indices = Vec::with_capacity(left_indices.len());
+                let mut filtered_right_indices = Vec::with_capacity(right_indices.len());
+                for (index, value) in condition_values.iter().enumerate() {
+                    if let Some(true) = value {
+                        filtered_left_indices.push(left_indices[index]);
+                        filtered_right_indices.push(right_indices[index]);
+                    }
+                }
+
+                matched_block = DataBlock::filter_block(&matched_block, condition_array)?;
+                left_indices = filtered_left_indices;
+                right_indices = filtered_right_indices;
+            }
+        }
+
+        if self.join_type.preserves_right() {
+            for build_row in &right_indices {
+                self.hash_table.visited[*build_row as usize].store(true, Ordering::Relaxed);
+            }
+        }
+
+        if !self.join_type.preserves_left() {
+            return Ok(matched_block);
+        }
+
+        let mut left_matched = vec![false; num_rows];
+        for row in &left_indices {
+            left_matched[*row as usize] = true;
+        }
+
+        let unmatched_rows = (0..num_rows as u32)
+            .filter(|row| !left_matched[*row as usize])
+            .collect::<Vec<_>>();
+
+        if unmatched_rows.is_empty() {
+            return Ok(matched_block);
+        }
+
+        let unmatched_block = DataBlock::block_take_by_indices(block, &[], &unmatched_rows)?;
+        let right_schema = self.hash_table.block.schema();
+        let mut columns = unmatched_block.columns().to_vec();
+        columns.extend(null_columns(right_schema, unmatched_rows.len())?);
+        let unmatched_block = DataBlock::create(self.schema.clone(), columns);
+
+        match matched_block.is_empty() {
+            true => Ok(unmatched_block),
+            false => DataBlock::concat_blocks(&[matched_block, unmatched_block]),
+        }
+    }
+
+    fn joined_block(&self, block: &DataBlock, left: &[u32], right: &[u32]) -> Result<DataBlock> {
+        if left.is_empty() {
+            return Ok(DataBlock::empty_with_schema(self.schema.clone()));
+        }
+
+        let left_block = DataBlock::block_take_by_indices(block, &[], left)?;
+        let right_block = DataBlock::block_take_by_indices(&self.hash_table.block, &[], right)?;
+
+        let mut columns = left_block.columns().to_vec();
+        columns.extend_from_slice(right_block.columns());
+        Ok(DataBlock::create(self.schema.clone(), columns))
+    }
+
+    fn unmatched_build_rows(&self) -> Result<DataBlock> {
+        let unmatched_rows = (0..self.hash_table.num_rows() as u32)
+            .filter(|row| !self.hash_table.visited[*row as usize].load(Ordering::Relaxed))
+            .collect::<Vec<_>>();
+
+        if unmatched_rows.is_empty() {
+            return Ok(DataBlock::empty_with_schema(self.schema.clone()));
+        }
+
+        let build_block = &self.hash_table.block;
+        let unmatched_block = DataBlock::block_take_by_indices(build_block, &[], &unmatched_rows)?;
+
+        let mut columns = null_columns(&self.left_schema, unmatched_rows.len())?;
+        columns.extend_from_slice(unmatched_block.columns());
+        Ok(DataBlock::create(self.schema.clone(), columns))
+    }
+}
+
+fn keys_executor(
+    description: &str,
+    schema: &DataSchemaRef,
+    keys: &[Expression],
+) -> Result<ExpressionExecutor> {
+    let mut fields = Vec::with_capacity(keys.len());
+    for key in keys {
+        fields.push(key.to_data_field(schema)?);
+    }
+
+    let executor = ExpressionExecutor::try_create(
+        description,
+        schema.clone(),
+        DataSchemaRefExt::create(fields),
+        keys.to_vec(),
+        false,
+    )?;
+
+    executor.validate()?;
+    Ok(executor)
+}
+
+/// Serialize the keys of each row, and mark the rows that have NULL keys.
+fn serialize_keys(
+    executor: &ExpressionExecutor,
+    keys: &[Expression],
+    block: &DataBlock,
+) -> Result<(Vec<Vec<u8>>, Vec<bool>)> {
+    let num_rows = block.num_rows();
+    let keys_block = executor.execute(block)?;
+
+    let mut has_null = vec![false; num_rows];
+    let mut keys_columns = Vec::with_capacity(keys.len());
+    for key in keys {
+        let column = keys_block.try_column_by_name(&key.column_name())?;
+        let series = column.to_array()?;
+        if series.null_count() > 0 {
+            for (row, row_has_null) in has_null.iter_mut().enumerate() {
+                *row_has_null = *row_has_null || series.is_null(row);
+            }
+        }
+
+        keys_columns.push(column);
+    }
+
+    let serialized_keys = HashMethodSerializer::default().build_keys(&keys_columns, num_rows)?;
+    Ok((serialized_keys, has_null))
+}
+
+fn null_columns(schema: &DataSchemaRef, num_rows: usize) -> Result<Vec<DataColumn>> {
+    let mut columns = Vec::with_capacity(schema.fields().len());
+    for field in schema.fields() {
+        let data_type = field.data_type();
+        let null_series = DataValue::from(data_type).to_series_with_size(num_rows)?;
+        columns.push(DataColumn::Array(null_series.cast_with_type(data_type)?));
+    }
+
+    Ok(columns)
+}
@@ -19,6 +19,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_planners::ReadDataSourcePlan;
 use common_streams::CorrectWithSchemaStream;
+use common_streams::MemoryLimitStream;
 use common_streams::ProgressStream;
 use common_streams::SendableDataBlockStream;
 use common_tracing::tracing;
@@ -44,8 +45,13 @@ impl SourceTransform {
         let progress_stream =
             ProgressStream::try_create(table_stream.await?, self.ctx.progress_callback()?)?;
 
+        let tracker = self.ctx.get_shared_runtime()?.get_tracker();
+        let memory_limit_stream =
+            MemoryLimitStream::try_create(Box::pin(progress_stream), tracker)?;
+
         Ok(Box::pin(
-            self.ctx.try_create_abortable(Box::pin(progress_stream))?,
+            self.ctx
+                .try_create_abortable(Box::pin(memory_limit_stream))?,
         ))
     }
 }
@@ -0,0 +1,557 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::ops::Range;
+use std::sync::Arc;
+use std::time::Instant;
+
+use bumpalo::Bump;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_functions::aggregates::AggregateFunctionRef;
+use common_functions::aggregates::StateAddr;
+use common_planners::Expression;
+use common_planners::WindowFrame;
+use common_planners::WindowFrameBound;
+use common_planners::WindowFrameUnits;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+use common_tracing::tracing;
+use futures::StreamExt;
+
+use crate::pipelines::processors::EmptyProcessor;
+use crate::pipelines::processors::Processor;
+
+/// Evaluate a window function partition by partition, the input must be sorted by
+/// `window_sort_exprs` and merged to one stream.
+pub struct WindowTransform {
+    window_func: Expression,
+    schema: DataSchemaRef,
+    input_schema: DataSchemaRef,
+    input: Arc<dyn Processor>,
+}
+
+impl WindowTransform {
+    pub fn try_create(
+        window_func: Expression,
+        schema: DataSchemaRef,
+        input_schema: DataSchemaRef,
+    ) -> Result<Self> {
+        match window_func {
+            Expression::WindowFunction { .. } => Ok(WindowTransform {
+                window_func,
+                schema,
+                input_schema,
+                input: Arc::new(EmptyProcessor::create()),
+            }),
+            _ => Err(ErrorCode::BadTransformType(format!(
+                "Window expression must be Expression::WindowFunction, but got: {:?}",
+                window_func
+            ))),
+        }
+    }
+
+    fn evaluate(&self, block: &DataBlock) -> Result<DataColumn> {
+        let (op, args, partition_by, order_by, window_frame) = match &self.window_func {
+            Expression::WindowFunction {
+                op,
+                args,
+                partition_by,
+                order_by,
+                window_frame,
+                ..
+            } => (op, args, partition_by, order_by, window_frame),
+            _ => unreachable!(),
+        };
+
+        let partition_changes = Self::key_changes(block, partition_by)?;
+        let order_changes = Self::key_changes(block, order_by)?;
+
+        let rows = block.num_rows();
+        let partitions = Self::split_by_changes(&partition_changes, 0..rows);
+
+        let op = op.to_lowercase();
+        let data_type = self.window_func.to_data_type(&self.input_schema)?;
+        let mut values = Vec::with_capacity(rows);
+        match op.as_str() {
+            "row_number" => {
+                for partition in partitions {
+                    for row in partition.clone() {
+                        values.push(DataValue::UInt64(Some((row - partition.start + 1) as u64)));
+                    }
+                }
+            }
+            "rank" | "dense_rank" => {
+                for partition in partitions {
+                    let peers = Self::split_by_changes(&order_changes, partition.clone());
+                    for (index, peer) in peers.iter().enumerate() {
+                        let rank = match op.as_str() {
+                            "rank" => peer.start - partition.start + 1,
+                            _ => index + 1,
+                        };
+                        values.extend(peer.clone().map(|_| DataValue::UInt64(Some(rank as u64))));
+                    }
+                }
+            }
+            "lag" | "lead" => {
+                let arg_values = Self::arg_array(block, &args[0])?.to_values()?;
+                let offset = match args.get(1) {
+                    Some(Expression::Literal { value, .. }) => value.as_u64()? as usize,
+                    _ => 1,
+                };
+                let default = match args.get(2) {
+                    Some(Expression::Literal { value, .. }) => value
+                        .to_series_with_size(1)?
+                        .cast_with_type(&data_type)?
+                        .try_get(0)?,
+                    _ => DataValue::from(&data_type),
+                };
+
+                for partition in partitions {
+                    for row in partition.clone() {
+                        let target = match op.as_str() {
+                            "lag" => row.checked_sub(offset),
+                            _ => row.checked_add(offset),
+                        };
+
+                        match target {
+                            Some(target) if partition.contains(&target) => {
+                                values.push(arg_values[target].clone())
+                            }
+                            _ => values.push(default.clone()),
+                        }
+                    }
+                }
+            }
+            _ => {
+                let frame = Self::window_frame(window_frame, order_by);
+                let frames = partitions
+                    .into_iter()
+                    .flat_map(|partition| {
+                        let peers = Self::split_by_changes(&order_changes, partition.clone());
+                        Self::frame_ranges(&frame, &partition, &peers)
+                    })
+                    .collect::<Vec<_>>();
+
+                match op.as_str() {
+                    "first_value" | "last_value" => {
+                        let arg_values = Self::arg_array(block, &args[0])?.to_values()?;
+                        for frame in frames {
+                            let row = match op.as_str() {
+                                "first_value" => Some(frame.start),
+                                _ => frame.end.checked_sub(1),
+                            };
+
+                            match row {
+                                Some(row) if frame.contains(&row) => {
+                                    values.push(arg_values[row].clone())
+                                }
+                                _ => values.push(DataValue::from(&data_type)),
+                            }
+                        }
+                    }
+                    _ => {
+                        let func = self.window_func.to_aggregate_function(&self.input_schema)?;
+                        let arg_arrays = args
+                            .iter()
+                            .map(|arg| Self::arg_array(block, arg))
+                            .collect::<Result<Vec<_>>>()?;
+                        values = Self::aggregate(func, &arg_arrays, rows, &frame, &frames)?;
+                    }
+                }
+            }
+        }
+
+        Ok(DataValue::try_into_data_array(&values, &data_type)?.into())
+    }
+
+    fn aggregate(
+        func: AggregateFunctionRef,
+        arrays: &[Series],
+        rows: usize,
+        frame: &WindowFrame,
+        frames: &[Range<usize>],
+    ) -> Result<Vec<DataValue>> {
+        let arena = bumpalo::Bump::new();
+        match frame.start_bound {
+            WindowFrameBound::Preceding(None) => {
+                Self::aggregate_running(&arena, &func, arrays, frames)
+            }
+            _ => Self::aggregate_sliding(&arena, &func, arrays, rows, frames),
+        }
+    }
+
+    /// The frames start with their partitions and only grow within them, the rows are
+    /// accumulated once into the state of the partition.
+    fn aggregate_running(
+        arena: &Bump,
+        func: &AggregateFunctionRef,
+        arrays: &[Series],
+        frames: &[Range<usize>],
+    ) -> Result<Vec<DataValue>> {
+        let mut values = Vec::with_capacity(frames.len());
+        // (frame start, accumulated end, state) of the current partition
+        let mut running: Option<(usize, usize, StateAddr)> = None;
+        for frame in frames {
+            let (place, end) = match running {
+                Some((start, end, place)) if start == frame.start && end <= frame.end => {
+                    (place, end)
+                }
+                _ => (Self::new_state(arena, func), frame.start),
+            };
+
+            Self::accumulate_rows(func, place, arrays, end..frame.end)?;
+            running = Some((frame.start, frame.end.max(end), place));
+            values.push(func.merge_result(place)?);
+        }
+
+        Ok(values)
+    }
+
+    /// The frames slide within the partitions, they are merged from the states of a segment
+    /// tree over the rows, so that every row is accumulated once.
+    fn aggregate_sliding(
+        arena: &Bump,
+        func: &AggregateFunctionRef,
+        arrays: &[Series],
+        rows: usize,
+        frames: &[Range<usize>],
+    ) -> Result<Vec<DataValue>> {
+        // tree[rows + i] is the state of row i, tree[i] merges tree[2 * i] and tree[2 * i + 1]
+        let mut tree = Vec::with_capacity(2 * rows);
+        tree.resize_with(rows, || Self::new_state(arena, func));
+        for row in 0..rows {
+            let place = Self::new_state(arena, func);
+            Self::accumulate_rows(func, place, arrays, row..row + 1)?;
+            tree.push(place);
+        }
+        for node in (1..rows).rev() {
+            func.merge(tree[node], tree[2 * node])?;
+            func.merge(tree[node], tree[2 * node + 1])?;
+        }
+
+        let mut values = Vec::with_capacity(frames.len());
+        for frame in frames {
+            let place = Self::new_state(arena, func);
+            let (mut left, mut right) = (frame.start + rows, frame.end + rows);
+            while left < right {
+                if left & 1 == 1 {
+                    func.merge(place, tree[left])?;
+                    left += 1;
+                }
+                if right & 1 == 1 {
+                    right -= 1;
+                    func.merge(place, tree[right])?;
+                }
+                left >>= 1;
+                right >>= 1;
+            }
+            values.push(func.merge_result(place)?);
+        }
+
+        Ok(values)
+    }
+
+    fn new_state(arena: &Bump, func: &AggregateFunctionRef) -> StateAddr {
+        let place: StateAddr = arena.alloc_layout(func.state_layout()).into();
+        func.init_state(place);
+        place
+    }
+
+    fn accumulate_rows(
+        func: &AggregateFunctionRef,
+        place: StateAddr,
+        arrays: &[Series],
+        rows: Range<usize>,
+    ) -> Result<()> {
+        let len = rows.end.saturating_sub(rows.start);
+        if len > 0 {
+            let rows_arrays = arrays
+                .iter()
+                .map(|array| array.slice(rows.start, len))
+                .collect::<Vec<_>>();
+            func.accumulate(place, &rows_arrays, len)?;
+        }
+        Ok(())
+    }
+
+    fn arg_array(block: &DataBlock, arg: &Expression) -> Result<Series> {
+        match arg {
+            // Constant arguments are not projected by the expression before window
+            Expression::Literal { value, .. } => value.to_series_with_size(block.num_rows()),
+            _ => block.try_array_by_name(&arg.column_name()),
+        }
+    }
+
+    fn key_column_name(expr: &Expression) -> String {
+        match expr {
+            Expression::Sort { expr, .. } => expr.column_name(),
+            _ => expr.column_name(),
+        }
+    }
+
+    /// Marks the rows whose keys differ from the previous row, the first row is always marked.
+    fn key_changes(block: &DataBlock, exprs: &[Expression]) -> Result<Vec<bool>> {
+        let rows = block.num_rows();
+        let mut changes = vec![false; rows];
+        if let Some(first) = changes.first_mut() {
+            *first = true;
+        }
+
+        for expr in exprs {
+            let array = block.try_array_by_name(&Self::key_column_name(expr))?;
+            Self::mark_changes(&array, &mut changes)?;
+        }
+        Ok(changes)
+    }
+
+    fn mark_changes(array: &Series, changes: &mut [bool]) -> Result<()> {
+        let rows = array.len();
+        if rows < 2 {
+            return Ok(());
+        }
+
+        let data_type = array.data_type();
+        let comparable = data_type.is_numeric()
+            || matches!(
+                data_type,
+                DataType::Boolean
+                    | DataType::String
+                    | DataType::Date16
+                    | DataType::Date32
+                    | DataType::Decimal128(_, _)
+            );
+        if !comparable {
+            // the types without comparison kernels are compared by values
+            let values = array.to_values()?;
+            for row in 1..rows {
+                changes[row] |= values[row] != values[row - 1];
+            }
+            return Ok(());
+        }
+
+        // compare every row with the previous one, NULLs are equal to each other
+        let equal = array.slice(1, rows - 1).eq(&array.slice(0, rows - 1))?;
+        let equal = equal.inner();
+        for row in 1..rows {
+            let (is_null, prev_is_null) = (array.is_null(row), array.is_null(row - 1));
+            changes[row] |= match is_null || prev_is_null {
+                true => is_null != prev_is_null,
+                false => !equal.value(row - 1),
+            };
+        }
+        Ok(())
+    }
+
+    /// Split the rows into ranges of consecutive rows with the same keys.
+    fn split_by_changes(changes: &[bool], rows: Range<usize>) -> Vec<Range<usize>> {
+        let mut ranges = vec![];
+        let mut start = rows.start;
+        for row in rows.clone() {
+            if row > start && changes[row] {
+                ranges.push(start..row);
+                start = row;
+            }
+        }
+
+        if start < rows.end {
+            ranges.push(start..rows.end);
+        }
+        ranges
+    }
+
+    fn window_frame(window_frame: &Option<WindowFrame>, order_by: &[Expression]) -> WindowFrame {
+        match window_frame {
+            Some(window_frame) => *window_frame,
+            // Without ORDER BY the frame is the whole partition, otherwise the frame
+            // ends with the last peer of the current row.
+            None => WindowFrame {
+                units: WindowFrameUnits::Range,
+                start_bound: WindowFrameBound::Preceding(None),
+                end_bound: match order_by.is_empty() {
+                    true => WindowFrameBound::Following(None),
+                    false => WindowFrameBound::CurrentRow,
+                },
+            },
+        }
+    }
+
+    /// Compute the frame of every row in the partition.
+    fn frame_ranges(
+        frame: &WindowFrame,
+        partition: &Range<usize>,
+        peers: &[Range<usize>],
+    ) -> Vec<Range<usize>> {
+        let mut frames = Vec::with_capacity(partition.len());
+        for peer in peers {
+            for row in peer.clone() {
+                let current = match frame.units {
+                    WindowFrameUnits::Rows => row..row + 1,
+                    WindowFrameUnits::Range => peer.clone(),
+                };
+
+                let start = match frame.start_bound {
+                    WindowFrameBound::Preceding(None) => partition.start,
+                    WindowFrameBound::Preceding(Some(n)) => row.saturating_sub(n as usize),
+                    WindowFrameBound::CurrentRow => current.start,
+                    WindowFrameBound::Following(n) => row.saturating_add(n.unwrap_or(0) as usize),
+                };
+
+                let end = match frame.end_bound {
+                    WindowFrameBound::Following(None) => partition.end,
+                    WindowFrameBound::Following(Some(n)) => row.saturating_add(n as usize + 1),
+                    WindowFrameBound::CurrentRow => current.end,
+                    WindowFrameBound::Preceding(n) => {
+                        (row + 1).saturating_sub(n.unwrap_or(0) as usize)
+                    }
+                };
+
+                let start = start.max(partition.start).min(partition.end);
+                let end = end.max(start).min(partition.end);
+                frames.push(start..end);
+            }
+        }
+
+        frames
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for WindowTransform {
+    fn name(&self) -> &str {
+        "WindowTransform"
+    }
+
+    fn connect_to(&mut self, input: Arc<dyn Processor>) -> Result<()> {
+        self.input = input;
+        Ok(())
+    }
+
+    fn inputs(&self) -> Vec<Arc<dyn Processor>> {
+        vec![self.input.clone()]
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        tracing::debug!("execute...");
+
+        let partition_by = match &self.window_func {
+            Expression::WindowFunction { partition_by, .. } => partition_by,
+            _ => unreachable!(),
+        };
+
+        // The rows of the last partition seen so far, it may continue in the next block.
+        let mut pending: Vec<DataBlock> = vec![];
+        let mut results = vec![];
+        let mut stream = self.input.execute().await?;
+        while let Some(block) = stream.next().await {
+            let block = block?;
+            if block.is_empty() {
+                continue;
+            }
+
+            let mut changes = Self::key_changes(&block, partition_by)?;
+            if let Some(last) = pending.last() {
+                changes[0] = Self::keys_differ(last, &block, partition_by)?;
+            }
+
+            // the partitions before the last one of the block are complete
+            match changes.iter().rposition(|changed| *changed) {
+                None => pending.push(block),
+                Some(start) => {
+                    if start > 0 {
+                        pending.push(block.slice(0, start));
+                    }
+                    if !pending.is_empty() {
+                        results.push(self.evaluate_partitions(&pending)?);
+                    }
+                    pending = vec![block.slice(start, block.num_rows() - start)];
+                }
+            }
+        }
+
+        if !pending.is_empty() {
+            results.push(self.evaluate_partitions(&pending)?);
+        }
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.schema.clone(),
+            None,
+            results,
+        )))
+    }
+}
+
+impl WindowTransform {
+    /// Evaluate the window function over the blocks of complete partitions.
+    fn evaluate_partitions(&self, blocks: &[DataBlock]) -> Result<DataBlock> {
+        let start = Instant::now();
+        let block = DataBlock::concat_blocks(blocks)?;
+        let column = self.evaluate(&block)?;
+        let field = self.window_func.to_data_field(&self.input_schema)?;
+        let result = block
+            .add_column(column, field)?
+            .resort(self.schema.clone())?;
+        tracing::debug!("Window cost: {:?}", start.elapsed());
+        Ok(result)
+    }
+
+    /// Whether the keys of the last row of `prev` differ from the first row of `next`.
+    fn keys_differ(prev: &DataBlock, next: &DataBlock, exprs: &[Expression]) -> Result<bool> {
+        for expr in exprs {
+            let name = Self::key_column_name(expr);
+            let prev_value = prev
+                .try_column_by_name(&name)?
+                .try_get(prev.num_rows() - 1)?;
+            let next_value = next.try_column_by_name(&name)?.try_get(0)?;
+            if prev_value != next_value {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+}
+
+/// The partition keys and the order keys that the input of the window must be sorted by.
+pub fn window_sort_exprs(window_func: &Expression) -> Result<Vec<Expression>> {
+    match window_func {
+        Expression::WindowFunction {
+            partition_by,
+            order_by,
+            ..
+        } => {
+            let mut sort_exprs = partition_by
+                .iter()
+                .map(|expr| Expression::Sort {
+                    expr: Box::new(expr.clone()),
+                    asc: true,
+                    nulls_first: true,
+                    origin_expr: Box::new(expr.clone()),
+                })
+                .collect::<Vec<_>>();
+            sort_exprs.extend_from_slice(order_by);
+            Ok(sort_exprs)
+        }
+        _ => Err(ErrorCode::BadTransformType(format!(
+            "Window expression must be Expression::WindowFunction, but got: {:?}",
+            window_func
+        ))),
+    }
+}
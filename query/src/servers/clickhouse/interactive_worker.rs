@@ -104,21 +104,18 @@ impl ClickHouseSession for InteractiveWorker {
         let user_manager = self.session.get_user_manager();
         // TODO: push async up to clickhouse server lib
         futures::executor::block_on(async move {
-            // TODO: use get_users and check client address
-            let (authed, user_info) = match user_manager.get_user(user, "%").await {
-                Ok(user_info) => (
-                    user_manager.auth_user(user_info.clone(), info).await,
-                    Some(user_info),
-                ),
-                Err(err) => (Err(err), None),
+            let authed = match user_manager
+                .get_user_by_client_address(user, client_addr)
+                .await
+            {
+                Ok(user_info) => match user_manager.auth_user(user_info.clone(), info).await {
+                    Ok(true) => self.session.set_authed_user(user_info).await.map(|_| true),
+                    other => other,
+                },
+                Err(err) => Err(err),
             };
             match authed {
-                Ok(res) => {
-                    if res {
-                        self.session.set_current_user(user_info.unwrap());
-                    }
-                    res
-                }
+                Ok(res) => res,
                 Err(failure) => {
                     tracing::error!(
                         "ClickHouse handler authenticate failed, \
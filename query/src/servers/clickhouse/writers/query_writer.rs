@@ -22,6 +22,7 @@ use common_clickhouse_srv::connection::Connection;
 use common_clickhouse_srv::errors::Error as CHError;
 use common_clickhouse_srv::errors::Result as CHResult;
 use common_clickhouse_srv::errors::ServerError;
+use common_clickhouse_srv::types::column::datetime64;
 use common_clickhouse_srv::types::column::ArcColumnData;
 use common_clickhouse_srv::types::column::ArcColumnWrapper;
 use common_clickhouse_srv::types::column::ColumnFrom;
@@ -31,6 +32,7 @@ use common_clickhouse_srv::types::DateTimeType;
 use common_clickhouse_srv::types::SqlType;
 use common_datablocks::DataBlock;
 use common_datavalues::prelude::*;
+use common_exception::exception::PERMISSION_DENIED;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_tracing::tracing;
@@ -144,9 +146,17 @@ impl<'a> QueryWriter<'a> {
     }
 }
 
+// The ACCESS_DENIED error code of ClickHouse.
+const CLICKHOUSE_ACCESS_DENIED: u32 = 497;
+
 pub fn to_clickhouse_err(res: ErrorCode) -> common_clickhouse_srv::errors::Error {
+    let code = match res.code() {
+        PERMISSION_DENIED => CLICKHOUSE_ACCESS_DENIED,
+        code => code as u32,
+    };
+
     common_clickhouse_srv::errors::Error::Server(ServerError {
-        code: res.code() as u32,
+        code,
         name: "DB:Exception".to_string(),
         message: res.message(),
         stack_trace: res.backtrace_str(),
@@ -261,6 +271,18 @@ pub fn from_clickhouse_block(schema: DataSchemaRef, block: Block) -> Result<Data
             SqlType::Nullable(SqlType::FixedString(_)) => {
                 Ok(DFStringArray::new_from_opt_iter(col.iter::<Option<&[u8]>>()?).into_series())
             }
+            SqlType::DateTime(DateTimeType::DateTime64(precision, _)) => {
+                let it = col
+                    .iter::<DateTime<Tz>>()?
+                    .map(|v| datetime64_to_ticks(&v, precision));
+                Ok(DFInt64Array::new_from_iter(it).into_series())
+            }
+            SqlType::Nullable(SqlType::DateTime(DateTimeType::DateTime64(precision, _))) => {
+                let it = col
+                    .iter::<Option<DateTime<Tz>>>()?
+                    .map(|v| v.map(|v| datetime64_to_ticks(&v, *precision)));
+                Ok(DFInt64Array::new_from_opt_iter(it).into_series())
+            }
 
             other => Err(CHError::Other(Cow::from(format!(
                 "Unsupported type: {:?}",
@@ -323,6 +345,14 @@ fn to_clickhouse_column(field: &DataField, column: &Series) -> Result<ArcColumnD
 
                 Vec::column_from::<ArcColumnWrapper>(c)
             }
+            DataType::DateTime64(precision, tz) => {
+                let tz = timezone_or_utc(tz)?;
+                datetime64::nullable_column_from_ticks::<ArcColumnWrapper>(
+                    column.i64()?.collect_values(),
+                    *precision,
+                    tz,
+                )
+            }
             DataType::UInt64 => {
                 Vec::column_from::<ArcColumnWrapper>(column.u64()?.collect_values())
             }
@@ -344,6 +374,24 @@ fn to_clickhouse_column(field: &DataField, column: &Series) -> Result<ArcColumnD
 
                 Vec::column_from::<ArcColumnWrapper>(v)
             }
+            // Decimals are written as strings, the native decimal of clickhouse only supports precision up to 18
+            DataType::Decimal128(_, scale) => {
+                let vs: Vec<Option<Vec<u8>>> = column
+                    .decimal()?
+                    .inner()
+                    .iter()
+                    .map(|x| x.map(|v| decimal_to_string(*v, *scale).into_bytes()))
+                    .collect();
+                Vec::column_from::<ArcColumnWrapper>(vs)
+            }
+            DataType::Decimal128(_, scale) => {
+                let vs: Vec<String> = column
+                    .decimal()?
+                    .into_no_null_iter()
+                    .map(|v| decimal_to_string(*v, *scale))
+                    .collect();
+                Vec::column_from::<ArcColumnWrapper>(vs)
+            }
             DataType::Struct(fields) => Vec::column_from::<ArcColumnWrapper>(
                 fields
                     .iter()
@@ -414,6 +462,14 @@ fn to_clickhouse_column(field: &DataField, column: &Series) -> Result<ArcColumnD
 
                 Vec::column_from::<ArcColumnWrapper>(c)
             }
+            DataType::DateTime64(precision, tz) => {
+                let tz = timezone_or_utc(tz)?;
+                datetime64::column_from_ticks::<ArcColumnWrapper>(
+                    column.i64()?.inner().values().as_slice().to_vec(),
+                    *precision,
+                    tz,
+                )
+            }
 
             DataType::UInt64 => Vec::column_from::<ArcColumnWrapper>(
                 column.u64()?.inner().values().as_slice().to_vec(),
@@ -0,0 +1,131 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::AuthType;
+use common_meta_types::UserInfo;
+use headers::authorization::Basic;
+use headers::authorization::Bearer;
+use headers::Authorization;
+use headers::HeaderMapExt;
+use jwt_simple::prelude::*;
+use poem::Request;
+
+use crate::sessions::SessionManager;
+use crate::users::CertifiedInfo;
+use crate::users::UserApiProvider;
+
+/// Authenticate the user of an http request.
+///
+/// Accepts `Authorization: Basic` with the user password, or `Authorization: Bearer`
+/// with a HS256 JWT whose subject is the user name, if `http_handler_jwt_secret` is set.
+/// The users without password are only accepted from the local host, and the JWT must expire.
+pub async fn authenticate_user(
+    req: &Request,
+    session_manager: &Arc<SessionManager>,
+) -> Result<UserInfo> {
+    let headers = req.headers();
+    let client_address = req
+        .remote_addr()
+        .as_socket_addr()
+        .map(|addr| addr.to_string())
+        .unwrap_or_default();
+
+    if let Some(Authorization(basic)) = headers.typed_get::<Authorization<Basic>>() {
+        let (user_name, password) = (basic.username(), basic.password());
+        return auth_by_password(session_manager, user_name, password, &client_address).await;
+    }
+
+    if let Some(Authorization(bearer)) = headers.typed_get::<Authorization<Bearer>>() {
+        return auth_by_jwt(session_manager, bearer.token(), &client_address).await;
+    }
+
+    Err(ErrorCode::AuthenticateFailure(
+        "No authorization header, expect basic or bearer authorization",
+    ))
+}
+
+async fn auth_by_password(
+    session_manager: &Arc<SessionManager>,
+    user_name: &str,
+    password: &str,
+    client_address: &str,
+) -> Result<UserInfo> {
+    let user_manager = session_manager.get_user_manager();
+    let user_info = user_manager
+        .get_user_by_client_address(user_name, client_address)
+        .await?;
+
+    // The users without password can only login from the local host.
+    if user_info.auth_type == AuthType::None
+        && !UserApiProvider::is_local_client_address(client_address)
+    {
+        return Err(ErrorCode::AuthenticateFailure(format!(
+            "Authenticate failed, user '{}' without password can not login from '{}'",
+            user_name, client_address
+        )));
+    }
+
+    let info = CertifiedInfo::create(user_name, password, client_address);
+
+    match user_manager.auth_user(user_info.clone(), info).await? {
+        true => Ok(user_info),
+        false => Err(ErrorCode::AuthenticateFailure(format!(
+            "Authenticate failed, wrong password for user '{}'",
+            user_name
+        ))),
+    }
+}
+
+async fn auth_by_jwt(
+    session_manager: &Arc<SessionManager>,
+    token: &str,
+    client_address: &str,
+) -> Result<UserInfo> {
+    let secret = &session_manager.get_conf().query.http_handler_jwt_secret;
+    if secret.is_empty() {
+        return Err(ErrorCode::AuthenticateFailure(
+            "Bearer authorization is not enabled, http_handler_jwt_secret is empty",
+        ));
+    }
+
+    let key = HS256Key::from_bytes(secret.as_bytes());
+    let claims = key
+        .verify_token::<NoCustomClaims>(token, None)
+        .map_err(|cause| {
+            ErrorCode::AuthenticateFailure(format!("Invalid bearer token: {}", cause))
+        })?;
+
+    // The expiration is validated by verify_token, but a token without it never expires.
+    if claims.expires_at.is_none() {
+        return Err(ErrorCode::AuthenticateFailure(
+            "Invalid bearer token, missing expiration claim",
+        ));
+    }
+
+    match claims.subject {
+        None => Err(ErrorCode::AuthenticateFailure(
+            "Invalid bearer token, missing subject claim",
+        )),
+        Some(user_name) => {
+            let user_manager = session_manager.get_user_manager();
+            user_manager
+                .get_user_by_client_address(&user_name, client_address)
+                .await
+        }
+    }
+}
@@ -12,7 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod http_auth;
 mod http_services;
 pub mod v1;
 
+pub use http_auth::authenticate_user;
 pub use http_services::HttpHandler;
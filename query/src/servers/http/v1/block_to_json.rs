@@ -15,11 +15,14 @@
 use std::sync::Arc;
 
 use common_datablocks::DataBlock;
+use common_datavalues::arrays::DFDecimalArray;
 use common_datavalues::arrays::DFPrimitiveArray;
 use common_datavalues::chrono::TimeZone;
 use common_datavalues::chrono::Utc;
+use common_datavalues::decimal_to_string;
 use common_datavalues::DFPrimitiveType;
 use common_datavalues::DataType;
+use common_datavalues::DataValue;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use serde::Serialize;
@@ -92,6 +95,55 @@ where
         .collect()
 }
 
+// decimals are written as strings to keep the precision
+fn decimal_array_to_string_array(array: &DFDecimalArray) -> Vec<JsonValue> {
+    array
+        .inner()
+        .iter()
+        .map(|o| o.map(|x| decimal_to_string(*x, array.scale())))
+        .map(to_json_value)
+        .collect()
+}
+
+fn decimal_array_to_string_array_not_null(array: &DFDecimalArray) -> Vec<JsonValue> {
+    array
+        .into_no_null_iter()
+        .map(|x| decimal_to_string(*x, array.scale()))
+        .map(to_json_value)
+        .collect()
+}
+
+// datetime64 values are formatted with the precision and the timezone of the type
+fn datetime64_array_to_string_array(
+    array: &DFPrimitiveArray<i64>,
+    data_type: &DataType,
+) -> Result<Vec<JsonValue>> {
+    let serializer = data_type.create_serializer();
+    array
+        .into_iter()
+        .map(|o| match o {
+            Some(x) => Ok(to_json_value(
+                serializer.serialize_value(&DataValue::Int64(Some(*x)))?,
+            )),
+            None => Ok(JsonValue::Null),
+        })
+        .collect()
+}
+
+fn datetime64_array_to_string_array_not_null(
+    array: &DFPrimitiveArray<i64>,
+    data_type: &DataType,
+) -> Result<Vec<JsonValue>> {
+    let serializer = data_type.create_serializer();
+    array
+        .into_no_null_iter()
+        .map(|x| {
+            let value = serializer.serialize_value(&DataValue::Int64(Some(*x)))?;
+            Ok(to_json_value(value))
+        })
+        .collect()
+}
+
 fn bad_type(data_type: &DataType) -> ErrorCode {
     ErrorCode::BadDataValueType(format!("Unsupported column type:{:?}", data_type))
 }
@@ -129,6 +181,10 @@ pub fn block_to_json(block: &DataBlock) -> Result<Vec<Vec<JsonValue>>> {
                 DataType::Date32 => date_array_to_string_array(series.i32()?, DATE_FMT),
                 // TODO(youngsofun): add time zone?
                 DataType::DateTime32(_) => date_array_to_string_array(series.i32()?, TIME_FMT),
+                DataType::DateTime64(_, _) => {
+                    datetime64_array_to_string_array(series.i64()?, data_type)?
+                }
+                DataType::Decimal128(_, _) => decimal_array_to_string_array(series.decimal()?),
                 // TODO(youngsofun): support other DataType
                 _ => return Err(bad_type(data_type)),
             },
@@ -159,6 +215,12 @@ pub fn block_to_json(block: &DataBlock) -> Result<Vec<Vec<JsonValue>>> {
                 DataType::DateTime32(_) => {
                     date_array_to_string_array_not_null(series.i32()?, TIME_FMT)
                 }
+                DataType::DateTime64(_, _) => {
+                    datetime64_array_to_string_array_not_null(series.i64()?, data_type)?
+                }
+                DataType::Decimal128(_, _) => {
+                    decimal_array_to_string_array_not_null(series.decimal()?)
+                }
                 _ => return Err(bad_type(data_type)),
             },
         };
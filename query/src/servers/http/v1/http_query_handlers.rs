@@ -19,11 +19,15 @@ use std::time::Instant;
 
 use common_base::ProgressValues;
 use common_datavalues::DataSchemaRef;
+use common_exception::exception::PERMISSION_DENIED;
 use common_exception::ErrorCode;
+use common_meta_types::UserInfo;
 use common_tracing::tracing;
 use poem::error::Error as PoemError;
+use poem::error::Forbidden;
 use poem::error::NotFound;
 use poem::error::Result as PoemResult;
+use poem::error::Unauthorized;
 use poem::get;
 use poem::http::StatusCode;
 use poem::post;
@@ -31,11 +35,12 @@ use poem::web::Data;
 use poem::web::Json;
 use poem::web::Path;
 use poem::web::Query;
-use poem::IntoResponse;
+use poem::Request;
 use poem::Route;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::servers::http::authenticate_user;
 use crate::servers::http::v1::query::ExecuteStateName;
 use crate::servers::http::v1::query::HttpQuery;
 use crate::servers::http::v1::query::HttpQueryRequest;
@@ -142,35 +147,61 @@ pub(crate) struct CancelParams {
     delete: Option<bool>,
 }
 
+async fn authenticate(
+    request: &Request,
+    session_manager: &Arc<SessionManager>,
+) -> PoemResult<UserInfo> {
+    authenticate_user(request, session_manager)
+        .await
+        .map_err(|err| Unauthorized(err.message()))
+}
+
+// Only the user who started the query can access it.
+fn check_query_user(query: &HttpQuery, user_info: &UserInfo) -> PoemResult<()> {
+    match query.is_owned_by(user_info) {
+        true => Ok(()),
+        false => Err(Forbidden(format!(
+            "query {} belongs to another user",
+            query.id
+        ))),
+    }
+}
+
 #[poem::handler]
 async fn query_cancel_handler(
+    request: &Request,
     sessions_extension: Data<&Arc<SessionManager>>,
     Query(params): Query<CancelParams>,
     Path(query_id): Path<String>,
-) -> impl IntoResponse {
+) -> PoemResult<StatusCode> {
     let session_manager = sessions_extension.0;
+    let user_info = authenticate(request, session_manager).await?;
     let http_query_manager = session_manager.get_http_query_manager();
     match http_query_manager.get_query_by_id(&query_id).await {
         Some(query) => {
+            check_query_user(&query, &user_info)?;
             query.kill().await;
             if params.delete.unwrap_or(false) {
                 http_query_manager.remove_query_by_id(&query_id).await;
             }
-            StatusCode::OK
+            Ok(StatusCode::OK)
         }
-        None => StatusCode::NOT_FOUND,
+        None => Ok(StatusCode::NOT_FOUND),
     }
 }
 
 #[poem::handler]
 async fn query_state_handler(
+    request: &Request,
     sessions_extension: Data<&Arc<SessionManager>>,
     Path(query_id): Path<String>,
 ) -> PoemResult<Json<QueryResponse>> {
     let session_manager = sessions_extension.0;
+    let user_info = authenticate(request, session_manager).await?;
     let http_query_manager = session_manager.get_http_query_manager();
     match http_query_manager.get_query_by_id(&query_id).await {
         Some(query) => {
+            check_query_user(&query, &user_info)?;
             let response = query.get_response_state_only().await;
             Ok(Json(QueryResponse::from_internal(query_id, response)))
         }
@@ -197,14 +228,17 @@ impl PageParams {
 
 #[poem::handler]
 async fn query_page_handler(
+    request: &Request,
     sessions_extension: Data<&Arc<SessionManager>>,
     Query(params): Query<PageParams>,
     Path((query_id, page_no)): Path<(String, usize)>,
 ) -> PoemResult<Json<QueryResponse>> {
     let session_manager = sessions_extension.0;
+    let user_info = authenticate(request, session_manager).await?;
     let http_query_manager = session_manager.get_http_query_manager();
     match http_query_manager.get_query_by_id(&query_id).await {
         Some(query) => {
+            check_query_user(&query, &user_info)?;
             let wait_type = params.get_wait_type();
             let resp = query
                 .get_response_page(page_no, &wait_type, false)
@@ -218,15 +252,18 @@ async fn query_page_handler(
 
 #[poem::handler]
 pub(crate) async fn query_handler(
+    request: &Request,
     sessions_extension: Data<&Arc<SessionManager>>,
     Query(params): Query<PageParams>,
     Json(req): Json<HttpQueryRequest>,
 ) -> PoemResult<Json<QueryResponse>> {
     tracing::info!("receive http query: {:?} {:?}", req, params);
     let session_manager = sessions_extension.0;
+    let user_info = authenticate(request, session_manager).await?;
+
     let http_query_manager = session_manager.get_http_query_manager();
     let query_id = http_query_manager.next_query_id();
-    let query = HttpQuery::try_create(query_id.clone(), req, session_manager).await;
+    let query = HttpQuery::try_create(query_id.clone(), req, session_manager, user_info).await;
 
     match query {
         Ok(query) => {
@@ -246,6 +283,7 @@ pub(crate) async fn query_handler(
                 resp,
             )))
         }
+        Err(e) if e.code() == PERMISSION_DENIED => Err(Forbidden(e.message())),
         Err(e) => Ok(Json(QueryResponse::fail_to_start_sql(query_id, &e))),
     }
 }
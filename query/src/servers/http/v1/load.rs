@@ -17,6 +17,7 @@ use std::sync::Arc;
 use async_compat::CompatExt;
 use async_stream::stream;
 use common_base::ProgressValues;
+use common_exception::exception::PERMISSION_DENIED;
 use common_planners::InsertInputSource;
 use common_planners::PlanNode;
 use common_streams::CsvSource;
@@ -24,7 +25,9 @@ use common_streams::Source;
 use common_tracing::tracing;
 use futures::StreamExt;
 use poem::error::BadRequest;
+use poem::error::Forbidden;
 use poem::error::Result as PoemResult;
+use poem::error::Unauthorized;
 use poem::web::Data;
 use poem::web::Json;
 use poem::web::Multipart;
@@ -33,6 +36,7 @@ use serde::Deserialize;
 use serde::Serialize;
 
 use crate::interpreters::InterpreterFactory;
+use crate::servers::http::authenticate_user;
 use crate::sessions::SessionManager;
 use crate::sql::PlanParser;
 
@@ -51,13 +55,12 @@ pub async fn streaming_load(
     sessions_extension: Data<&Arc<SessionManager>>,
 ) -> PoemResult<Json<LoadResponse>> {
     let session_manager = sessions_extension.0;
+    let user_info = authenticate_user(req, session_manager)
+        .await
+        .map_err(|err| Unauthorized(err.message()))?;
+
     let session = session_manager.create_session("Streaming load")?;
-    // Auth.
-    let user_name = "root";
-    let user_manager = session.get_user_manager();
-    // TODO: list user's grant list and check client address
-    let user_info = user_manager.get_user(user_name, "%").await?;
-    session.set_current_user(user_info);
+    session.set_authed_user(user_info).await?;
 
     let context = session.create_context().await?;
     let insert_sql = req
@@ -100,7 +103,11 @@ pub async fn streaming_load(
     }?;
 
     let max_block_size = context.get_settings().get_max_block_size()? as usize;
-    let interpreter = InterpreterFactory::get(context.clone(), plan.clone())?;
+    let interpreter =
+        InterpreterFactory::get(context.clone(), plan.clone()).map_err(|e| match e.code() {
+            PERMISSION_DENIED => Forbidden(e.message()),
+            _ => poem::Error::from(e),
+        })?;
     // Write Start to query log table.
     let _ = interpreter
         .start()
@@ -20,6 +20,7 @@ use common_base::ProgressValues;
 use common_datavalues::DataSchemaRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_types::UserInfo;
 
 use crate::servers::http::v1::query::ExecuteState;
 use crate::servers::http::v1::query::ExecuteStateName;
@@ -52,6 +53,8 @@ pub struct HttpQuery {
     pub(crate) id: String,
     #[allow(dead_code)]
     request: HttpQueryRequest,
+    // The user who started the query, only this user can access it.
+    user: (String, String),
     state: ExecutorRef,
     data: Arc<TokioMutex<ResultDataManager>>,
 }
@@ -63,15 +66,19 @@ impl HttpQuery {
         id: String,
         request: HttpQueryRequest,
         session_manager: &Arc<SessionManager>,
+        user_info: UserInfo,
     ) -> Result<HttpQueryRef> {
         //TODO(youngsofun): support config/set channel size
         let (block_tx, block_rx) = mpsc::channel(10);
+        let user = (user_info.name.clone(), user_info.hostname.clone());
 
-        let (state, schema) = ExecuteState::try_create(&request, session_manager, block_tx).await?;
+        let (state, schema) =
+            ExecuteState::try_create(&request, session_manager, user_info, block_tx).await?;
         let data = Arc::new(TokioMutex::new(ResultDataManager::new(schema, block_rx)));
         let query = HttpQuery {
             id,
             request,
+            user,
             state,
             data,
         };
@@ -79,6 +86,10 @@ impl HttpQuery {
         Ok(query)
     }
 
+    pub fn is_owned_by(&self, user_info: &UserInfo) -> bool {
+        self.user.0 == user_info.name && self.user.1 == user_info.hostname
+    }
+
     pub async fn get_response_page(
         &self,
         page_no: usize,
@@ -14,16 +14,21 @@
 
 use std::sync::Arc;
 
+use common_exception::exception::PERMISSION_DENIED;
+use poem::error::Forbidden;
 use poem::error::NotFound;
 use poem::error::Result as PoemResult;
+use poem::error::Unauthorized;
 use poem::post;
 use poem::web::Data;
 use poem::web::Json;
 use poem::web::Query;
 use poem::Endpoint;
+use poem::Request;
 use poem::Route;
 use serde::Deserialize;
 
+use crate::servers::http::authenticate_user;
 use crate::servers::http::v1::query::HttpQuery;
 use crate::servers::http::v1::query::HttpQueryRequest;
 use crate::servers::http::v1::query::HttpSessionConf;
@@ -34,24 +39,27 @@ use crate::sessions::SessionManager;
 #[derive(Deserialize)]
 pub struct StatementHandlerParams {
     db: Option<String>,
-    user: Option<String>,
 }
 
 #[poem::handler]
 pub async fn statement_handler(
+    request: &Request,
     sessions_extension: Data<&Arc<SessionManager>>,
     sql: String,
     Query(params): Query<StatementHandlerParams>,
 ) -> PoemResult<Json<QueryResponse>> {
     let session_manager = sessions_extension.0;
+    let user_info = authenticate_user(request, session_manager)
+        .await
+        .map_err(|err| Unauthorized(err.message()))?;
+
     let http_query_manager = session_manager.get_http_query_manager();
     let query_id = http_query_manager.next_query_id();
     let session = HttpSessionConf {
         database: params.db.filter(|x| !x.is_empty()),
-        user: params.user,
     };
     let req = HttpQueryRequest { sql, session };
-    let query = HttpQuery::try_create(query_id.clone(), req, session_manager).await;
+    let query = HttpQuery::try_create(query_id.clone(), req, session_manager, user_info).await;
 
     match query {
         Ok(query) => {
@@ -61,6 +69,7 @@ pub async fn statement_handler(
                 .map_err(|err| NotFound(err.message()))?;
             Ok(Json(QueryResponse::from_internal(query_id, resp)))
         }
+        Err(e) if e.code() == PERMISSION_DENIED => Err(Forbidden(e.message())),
         Err(e) => Ok(Json(QueryResponse::fail_to_start_sql(query_id, &e))),
     }
 }
@@ -16,6 +16,7 @@ use std::marker::PhantomData;
 use std::sync::Arc;
 use std::time::Instant;
 
+use chrono::NaiveDateTime;
 use common_base::tokio;
 use common_datablocks::DataBlock;
 use common_exception::ErrorCode;
@@ -24,18 +25,25 @@ use common_io::prelude::*;
 use common_planners::PlanNode;
 use common_tracing::tracing;
 use metrics::histogram;
+use msql_srv::Column;
+use msql_srv::ColumnFlags;
+use msql_srv::ColumnType;
 use msql_srv::ErrorKind;
 use msql_srv::InitWriter;
 use msql_srv::MysqlShim;
 use msql_srv::ParamParser;
+use msql_srv::ParamValue;
 use msql_srv::QueryResultWriter;
 use msql_srv::StatementMetaWriter;
+use msql_srv::ValueInner;
 use rand::RngCore;
 use tokio_stream::StreamExt;
 
 use crate::interpreters::InterpreterFactory;
+use crate::servers::mysql::writers::make_column_from_field;
 use crate::servers::mysql::writers::DFInitResultWriter;
 use crate::servers::mysql::writers::DFQueryResultWriter;
+use crate::sessions::PreparedStatement;
 use crate::sessions::QueryContext;
 use crate::sessions::SessionRef;
 use crate::sql::PlanParser;
@@ -210,8 +218,9 @@ impl<W: std::io::Write> InteractiveWorkerBase<W> {
         let address = &info.user_client_address;
 
         let user_manager = self.session.get_user_manager();
-        // TODO: list user's grant list and check client address
-        let user_info = user_manager.get_user(user_name, "%").await?;
+        let user_info = user_manager
+            .get_user_by_client_address(user_name, address)
+            .await?;
 
         let input = &info.user_password;
         let saved = &user_info.password;
@@ -224,7 +233,7 @@ impl<W: std::io::Write> InteractiveWorkerBase<W> {
             )
             .await?;
         if authed {
-            self.session.set_current_user(user_info);
+            self.session.set_authed_user(user_info).await?;
         }
 
         Ok(authed)
@@ -258,28 +267,130 @@ impl<W: std::io::Write> InteractiveWorkerBase<W> {
         }
     }
 
-    fn do_prepare(&mut self, _: &str, writer: StatementMetaWriter<'_, W>) -> Result<()> {
-        writer.error(
-            ErrorKind::ER_UNKNOWN_ERROR,
-            "Prepare is not support in Databend.".as_bytes(),
-        )?;
+    fn do_prepare(&mut self, query: &str, writer: StatementMetaWriter<'_, W>) -> Result<()> {
+        let statement = PreparedStatement::create(query);
+
+        let columns = match Self::build_runtime() {
+            Ok(runtime) => runtime.block_on(self.do_describe(&statement)),
+            Err(error) => Err(error),
+        };
+
+        match columns {
+            Ok(columns) => {
+                // The types of parameters are unknown until they are bound.
+                let params = (0..statement.num_params())
+                    .map(|_| Column {
+                        table: "".to_string(),
+                        column: "?".to_string(),
+                        coltype: ColumnType::MYSQL_TYPE_VAR_STRING,
+                        colflags: ColumnFlags::empty(),
+                    })
+                    .collect::<Vec<_>>();
+
+                let id = self.session.add_prepared_statement(statement);
+                writer.reply(id, &params, &columns)?;
+            }
+            Err(error) => {
+                writer.error(ErrorKind::ER_UNKNOWN_ERROR, format!("{}", error).as_bytes())?;
+            }
+        }
         Ok(())
     }
 
     fn do_execute(
         &mut self,
-        _: u32,
-        _: ParamParser<'_>,
+        id: u32,
+        params: ParamParser<'_>,
         writer: QueryResultWriter<'_, W>,
     ) -> Result<()> {
-        writer.error(
-            ErrorKind::ER_UNKNOWN_ERROR,
-            "Execute is not support in Databend.".as_bytes(),
-        )?;
-        Ok(())
+        let mut writer = DFQueryResultWriter::create(writer);
+
+        let query = match self.session.get_prepared_statement(id) {
+            Some(statement) => Self::bind_params(&statement, params),
+            None => Err(ErrorCode::BadArguments(format!(
+                "Unknown prepared statement: {}",
+                id
+            ))),
+        };
+
+        let query = match query {
+            Ok(query) => query,
+            Err(error) => return writer.write(Err(error)),
+        };
+
+        match Self::build_runtime() {
+            Ok(runtime) => {
+                let blocks = runtime.block_on(self.do_query(&query));
+
+                let mut write_result = writer.write(blocks);
+
+                if let Err(cause) = write_result {
+                    let suffix = format!("(while in query {})", query);
+                    write_result = Err(cause.add_message_back(suffix));
+                }
+
+                write_result
+            }
+            Err(error) => writer.write(Err(error)),
+        }
+    }
+
+    fn do_close(&mut self, id: u32) {
+        self.session.remove_prepared_statement(id);
     }
 
-    fn do_close(&mut self, _: u32) {}
+    // Plan the statement with NULL parameters to find out the columns of its result.
+    async fn do_describe(&mut self, statement: &PreparedStatement) -> Result<Vec<Column>> {
+        let nulls = vec!["NULL".to_string(); statement.num_params()];
+        let query = statement.bind(&nulls)?;
+
+        let context = self.session.create_context().await?;
+        context.attach_query_str(&query);
+
+        match PlanParser::parse(&query, context).await? {
+            plan @ (PlanNode::Select(_) | PlanNode::Explain(_)) => plan
+                .schema()
+                .fields()
+                .iter()
+                .map(make_column_from_field)
+                .collect(),
+            _ => Ok(vec![]),
+        }
+    }
+
+    fn bind_params(statement: &PreparedStatement, params: ParamParser<'_>) -> Result<String> {
+        let literals = params
+            .into_iter()
+            .map(Self::param_literal)
+            .collect::<Result<Vec<_>>>()?;
+
+        statement.bind(&literals)
+    }
+
+    // Convert a bound parameter into a SQL literal of the same type.
+    fn param_literal(param: ParamValue<'_>) -> Result<String> {
+        let value = param.value;
+        match value.into_inner() {
+            ValueInner::NULL => Ok("NULL".to_string()),
+            ValueInner::Int(v) => Ok(v.to_string()),
+            ValueInner::UInt(v) => Ok(v.to_string()),
+            ValueInner::Double(v) => Ok(format!("{:?}", v)),
+            ValueInner::Bytes(v) => match std::str::from_utf8(v) {
+                Ok(v) => Ok(PreparedStatement::string_literal(v)),
+                Err(_) => Ok(PreparedStatement::binary_literal(v)),
+            },
+            ValueInner::Date(_) => {
+                let v = NaiveDateTime::from(value);
+                match param.coltype {
+                    ColumnType::MYSQL_TYPE_DATE => Ok(format!("'{}'", v.format("%Y-%m-%d"))),
+                    _ => Ok(format!("'{}'", v.format("%Y-%m-%d %H:%M:%S"))),
+                }
+            }
+            ValueInner::Time(_) => Err(ErrorCode::UnImplement(
+                "Unsupported TIME parameter in prepared statement",
+            )),
+        }
+    }
 
     async fn do_query(&mut self, query: &str) -> Result<(Vec<DataBlock>, String)> {
         tracing::debug!("{}", query);
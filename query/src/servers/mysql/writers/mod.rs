@@ -16,4 +16,5 @@ mod init_result_writer;
 mod query_result_writer;
 
 pub use self::init_result_writer::DFInitResultWriter;
+pub use self::query_result_writer::make_column_from_field;
 pub use self::query_result_writer::DFQueryResultWriter;
@@ -21,11 +21,49 @@ use common_datavalues::DataValue;
 use common_datavalues::DateConverter;
 use common_exception::exception::ABORT_QUERY;
 use common_exception::exception::ABORT_SESSION;
+use common_exception::exception::PERMISSION_DENIED;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_tracing::tracing;
 use msql_srv::*;
 
+pub fn convert_field_type(field: &DataField) -> Result<ColumnType> {
+    match field.data_type() {
+        DataType::Int8 => Ok(ColumnType::MYSQL_TYPE_LONG),
+        DataType::Int16 => Ok(ColumnType::MYSQL_TYPE_LONG),
+        DataType::Int32 => Ok(ColumnType::MYSQL_TYPE_LONG),
+        DataType::Int64 => Ok(ColumnType::MYSQL_TYPE_LONG),
+        DataType::UInt8 => Ok(ColumnType::MYSQL_TYPE_LONG),
+        DataType::UInt16 => Ok(ColumnType::MYSQL_TYPE_LONG),
+        DataType::UInt32 => Ok(ColumnType::MYSQL_TYPE_LONG),
+        DataType::UInt64 => Ok(ColumnType::MYSQL_TYPE_LONG),
+        DataType::Float32 => Ok(ColumnType::MYSQL_TYPE_FLOAT),
+        DataType::Float64 => Ok(ColumnType::MYSQL_TYPE_DOUBLE),
+        DataType::String => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+        DataType::Boolean => Ok(ColumnType::MYSQL_TYPE_SHORT),
+        DataType::Date16 | DataType::Date32 => Ok(ColumnType::MYSQL_TYPE_DATE),
+        DataType::DateTime32(_) | DataType::DateTime64(_, _) => Ok(ColumnType::MYSQL_TYPE_DATETIME),
+        DataType::Null => Ok(ColumnType::MYSQL_TYPE_NULL),
+        DataType::Interval(_) => Ok(ColumnType::MYSQL_TYPE_LONG),
+        DataType::Struct(_) => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+        DataType::List(_) => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+        DataType::Decimal128(_, _) => Ok(ColumnType::MYSQL_TYPE_NEWDECIMAL),
+        _ => Err(ErrorCode::UnImplement(format!(
+            "Unsupported column type:{:?}",
+            field.data_type()
+        ))),
+    }
+}
+
+pub fn make_column_from_field(field: &DataField) -> Result<Column> {
+    convert_field_type(field).map(|column_type| Column {
+        table: "".to_string(),
+        column: field.name().to_string(),
+        coltype: column_type,
+        colflags: ColumnFlags::empty(),
+    })
+}
+
 pub struct DFQueryResultWriter<'a, W: std::io::Write> {
     inner: Option<QueryResultWriter<'a, W>>,
 }
@@ -61,41 +99,6 @@ impl<'a, W: std::io::Write> DFQueryResultWriter<'a, W> {
             return Ok(());
         }
 
-        fn convert_field_type(field: &DataField) -> Result<ColumnType> {
-            match field.data_type() {
-                DataType::Int8 => Ok(ColumnType::MYSQL_TYPE_LONG),
-                DataType::Int16 => Ok(ColumnType::MYSQL_TYPE_LONG),
-                DataType::Int32 => Ok(ColumnType::MYSQL_TYPE_LONG),
-                DataType::Int64 => Ok(ColumnType::MYSQL_TYPE_LONG),
-                DataType::UInt8 => Ok(ColumnType::MYSQL_TYPE_LONG),
-                DataType::UInt16 => Ok(ColumnType::MYSQL_TYPE_LONG),
-                DataType::UInt32 => Ok(ColumnType::MYSQL_TYPE_LONG),
-                DataType::UInt64 => Ok(ColumnType::MYSQL_TYPE_LONG),
-                DataType::Float32 => Ok(ColumnType::MYSQL_TYPE_FLOAT),
-                DataType::Float64 => Ok(ColumnType::MYSQL_TYPE_FLOAT),
-                DataType::String => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
-                DataType::Boolean => Ok(ColumnType::MYSQL_TYPE_SHORT),
-                DataType::Date16 | DataType::Date32 => Ok(ColumnType::MYSQL_TYPE_DATE),
-                DataType::DateTime32(_) => Ok(ColumnType::MYSQL_TYPE_DATETIME),
-                DataType::Null => Ok(ColumnType::MYSQL_TYPE_NULL),
-                DataType::Interval(_) => Ok(ColumnType::MYSQL_TYPE_LONG),
-                DataType::Struct(_) => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
-                _ => Err(ErrorCode::UnImplement(format!(
-                    "Unsupported column type:{:?}",
-                    field.data_type()
-                ))),
-            }
-        }
-
-        fn make_column_from_field(field: &DataField) -> Result<Column> {
-            convert_field_type(field).map(|column_type| Column {
-                table: "".to_string(),
-                column: field.name().to_string(),
-                coltype: column_type,
-                colflags: ColumnFlags::empty(),
-            })
-        }
-
         fn convert_schema(schema: &DataSchemaRef) -> Result<Vec<Column>> {
             schema.fields().iter().map(make_column_from_field).collect()
         }
@@ -164,13 +167,28 @@ impl<'a, W: std::io::Write> DFQueryResultWriter<'a, W> {
                                     let tz: Tz = tz.parse().unwrap();
                                     row_writer.write_col(v.to_date_time(&tz).naive_local())?
                                 }
+                                (DataType::DateTime64(_, _), DataValue::Int64(Some(_))) => {
+                                    let serializer = data_type.create_serializer();
+                                    row_writer.write_col(serializer.serialize_value(&val)?)?
+                                }
                                 (DataType::String, DataValue::String(Some(v))) => {
                                     row_writer.write_col(v)?
                                 }
+                                (
+                                    DataType::Decimal128(_, _),
+                                    DataValue::Decimal128(Some(_), _, _),
+                                ) => {
+                                    let serializer = data_type.create_serializer();
+                                    row_writer.write_col(serializer.serialize_value(&val)?)?
+                                }
                                 (DataType::Struct(_), DataValue::Struct(_)) => {
                                     let serializer = data_type.create_serializer();
                                     row_writer.write_col(serializer.serialize_value(&val)?)?
                                 }
+                                (DataType::List(_), DataValue::List(Some(_), _)) => {
+                                    let serializer = data_type.create_serializer();
+                                    row_writer.write_col(serializer.serialize_value(&val)?)?
+                                }
                                 (_, v) => {
                                     return Err(ErrorCode::BadDataValueType(format!(
                                         "Unsupported column type:{:?}, expected type in schema: {:?}",
@@ -190,7 +208,12 @@ impl<'a, W: std::io::Write> DFQueryResultWriter<'a, W> {
     }
 
     fn err(error: &ErrorCode, writer: QueryResultWriter<'a, W>) -> Result<()> {
-        if error.code() != ABORT_QUERY && error.code() != ABORT_SESSION {
+        if error.code() == PERMISSION_DENIED {
+            writer.error(
+                ErrorKind::ER_SPECIFIC_ACCESS_DENIED_ERROR,
+                format!("{}", error).as_bytes(),
+            )?;
+        } else if error.code() != ABORT_QUERY && error.code() != ABORT_SESSION {
             tracing::error!("OnQuery Error: {:?}", error);
             writer.error(ErrorKind::ER_UNKNOWN_ERROR, format!("{}", error).as_bytes())?;
         } else {
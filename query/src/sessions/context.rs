@@ -26,6 +26,7 @@ use common_base::ProgressValues;
 use common_base::Runtime;
 use common_base::TrySpawn;
 use common_dal::AzureBlobAccessor;
+use common_dal::CachedDataAccessor;
 use common_dal::DalMetrics;
 use common_dal::DataAccessor;
 use common_dal::DataAccessorInterceptor;
@@ -35,6 +36,7 @@ use common_dal::S3;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_infallible::RwLock;
+use common_meta_types::RoleInfo;
 use common_meta_types::UserInfo;
 use common_planners::Part;
 use common_planners::Partitions;
@@ -55,6 +57,9 @@ use crate::sessions::QueryContextShared;
 use crate::sessions::Session;
 use crate::sessions::SessionManager;
 use crate::sessions::Settings;
+use crate::storages::fuse::FUSE_TBL_BLOCK_PREFIX;
+use crate::storages::fuse::FUSE_TBL_SEGMENT_PREFIX;
+use crate::storages::fuse::FUSE_TBL_SNAPSHOT_PREFIX;
 use crate::storages::Table;
 
 pub struct QueryContext {
@@ -204,6 +209,14 @@ impl QueryContext {
         self.shared.get_current_user()
     }
 
+    pub fn get_current_roles(&self) -> Vec<RoleInfo> {
+        self.shared.get_current_roles()
+    }
+
+    pub fn set_current_roles(&self, roles: Vec<RoleInfo>) {
+        self.shared.set_current_roles(roles)
+    }
+
     pub async fn set_current_database(&self, new_database_name: String) -> Result<()> {
         let catalog = self.get_catalog();
         match catalog.get_database(&new_database_name).await {
@@ -275,10 +288,40 @@ impl QueryContext {
             StorageScheme::LocalFs => Arc::new(Local::new(storage_conf.disk.data_path.as_str())),
         };
 
-        Ok(Arc::new(DataAccessorInterceptor::new(
+        // The interceptor is behind the cache, the cache hits are not counted as reads.
+        let da: Arc<dyn DataAccessor> = Arc::new(DataAccessorInterceptor::new(
             self.shared.dal_ctx.clone(),
             da,
-        )))
+        ));
+
+        // Only the fuse table objects are immutable and can be cached.
+        match self.shared.session.get_sessions_manager().get_dal_cache() {
+            Some(cache) => Ok(Arc::new(CachedDataAccessor::new(
+                cache,
+                da,
+                &[FUSE_TBL_SNAPSHOT_PREFIX, FUSE_TBL_SEGMENT_PREFIX],
+                &[FUSE_TBL_BLOCK_PREFIX],
+            ))),
+            None => Ok(da),
+        }
+    }
+
+    /// Marks the view as being expanded, fails if it is expanded inside of itself.
+    pub fn begin_expand_view(&self, database: &str, view: &str) -> Result<()> {
+        let view = (database.to_string(), view.to_string());
+        let mut expanding_views = self.shared.expanding_views.lock();
+        if expanding_views.contains(&view) {
+            return Err(ErrorCode::BadArguments(format!(
+                "View {}.{} is defined recursively",
+                view.0, view.1
+            )));
+        }
+        expanding_views.push(view);
+        Ok(())
+    }
+
+    pub fn end_expand_view(&self) {
+        self.shared.expanding_views.lock().pop();
     }
 
     /// Get the data accessor metrics.
@@ -24,6 +24,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_infallible::Mutex;
 use common_infallible::RwLock;
+use common_meta_types::RoleInfo;
 use common_meta_types::UserInfo;
 use common_planners::PlanNode;
 use futures::future::AbortHandle;
@@ -64,6 +65,7 @@ pub struct QueryContextShared {
     pub(in crate::sessions) running_plan: Arc<RwLock<Option<PlanNode>>>,
     pub(in crate::sessions) tables_refs: Arc<Mutex<HashMap<DatabaseAndTable, Arc<dyn Table>>>>,
     pub(in crate::sessions) dal_ctx: Arc<DalContext>,
+    pub(in crate::sessions) expanding_views: Arc<Mutex<Vec<DatabaseAndTable>>>,
 }
 
 impl QueryContextShared {
@@ -87,6 +89,7 @@ impl QueryContextShared {
             running_plan: Arc::new(RwLock::new(None)),
             tables_refs: Arc::new(Mutex::new(HashMap::new())),
             dal_ctx: Arc::new(Default::default()),
+            expanding_views: Arc::new(Mutex::new(Vec::new())),
         })
     }
 
@@ -121,6 +124,14 @@ impl QueryContextShared {
         self.session.get_current_user()
     }
 
+    pub fn get_current_roles(&self) -> Vec<RoleInfo> {
+        self.session.get_current_roles()
+    }
+
+    pub fn set_current_roles(&self, roles: Vec<RoleInfo>) {
+        self.session.set_current_roles(roles)
+    }
+
     pub fn get_settings(&self) -> Arc<Settings> {
         self.session.get_settings()
     }
@@ -167,8 +178,27 @@ impl QueryContextShared {
             Some(query_runtime) => Ok(query_runtime.clone()),
             None => {
                 let settings = self.get_settings();
-                let max_threads = settings.get_max_threads()? as usize;
+                let mut max_threads = settings.get_max_threads()? as usize;
+
+                // Apply the user quota, 0 means no limit.
+                let quota = self.get_current_user().ok().map(|user| user.quota);
+                if let Some(quota) = &quota {
+                    if quota.max_cpu > 0 {
+                        max_threads = std::cmp::min(max_threads, quota.max_cpu as usize);
+                    }
+                }
+
                 let runtime = Arc::new(Runtime::with_worker_threads(max_threads)?);
+                if let Some(quota) = &quota {
+                    if quota.max_memory_in_bytes > 0 {
+                        let memory_tracker = runtime.get_tracker();
+                        let memory_limit = quota.max_memory_in_bytes as i64;
+                        memory_tracker
+                            .get_memory_tracker()
+                            .set_memory_limit(memory_limit);
+                    }
+                }
+
                 *query_runtime = Some(runtime.clone());
                 Ok(runtime)
             }
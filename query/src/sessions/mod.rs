@@ -18,6 +18,7 @@ mod macros;
 mod context;
 mod context_shared;
 mod metrics;
+mod prepared_statement;
 mod session;
 mod session_info;
 mod session_ref;
@@ -29,6 +30,7 @@ mod settings;
 
 pub use context::QueryContext;
 pub use context_shared::QueryContextShared;
+pub use prepared_statement::PreparedStatement;
 pub use session::Session;
 pub use session_info::ProcessInfo;
 pub use session_ref::SessionRef;
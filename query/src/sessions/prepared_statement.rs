@@ -0,0 +1,141 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+/// A statement prepared by a client, in which every `?` outside of quotes and comments is a
+/// parameter placeholder.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PreparedStatement {
+    query: String,
+    // The query split by placeholders, there is always one more part than placeholders.
+    parts: Vec<String>,
+}
+
+#[derive(Clone, Copy)]
+enum ScanState {
+    Code,
+    Quoted(char),
+    LineComment,
+    BlockComment,
+}
+
+impl PreparedStatement {
+    pub fn create(query: &str) -> PreparedStatement {
+        let mut parts = vec![];
+        let mut part = String::new();
+        let mut state = ScanState::Code;
+
+        // A doubled quote inside a quoted string closes and reopens it, thus needs no special care.
+        let mut chars = query.chars().peekable();
+        while let Some(c) = chars.next() {
+            part.push(c);
+            match state {
+                ScanState::Quoted(q) => match c {
+                    // The escaped character never closes the string, identifiers have no escapes.
+                    '\\' if q != '`' => part.extend(chars.next()),
+                    _ if c == q => state = ScanState::Code,
+                    _ => {}
+                },
+                ScanState::LineComment => {
+                    if c == '\n' {
+                        state = ScanState::Code;
+                    }
+                }
+                ScanState::BlockComment => {
+                    if c == '*' && chars.peek() == Some(&'/') {
+                        part.extend(chars.next());
+                        state = ScanState::Code;
+                    }
+                }
+                ScanState::Code => match c {
+                    '\'' | '"' | '`' => state = ScanState::Quoted(c),
+                    '#' => state = ScanState::LineComment,
+                    // `--` starts a comment only if followed by a whitespace or the end.
+                    '-' if Self::is_dash_comment(chars.clone()) => state = ScanState::LineComment,
+                    '/' if chars.peek() == Some(&'*') => {
+                        part.extend(chars.next());
+                        state = ScanState::BlockComment;
+                    }
+                    '?' => {
+                        part.pop();
+                        parts.push(std::mem::take(&mut part));
+                    }
+                    _ => {}
+                },
+            }
+        }
+        parts.push(part);
+
+        PreparedStatement {
+            query: query.to_string(),
+            parts,
+        }
+    }
+
+    fn is_dash_comment(mut rest: impl Iterator<Item = char>) -> bool {
+        rest.next() == Some('-') && rest.next().map_or(true, char::is_whitespace)
+    }
+
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    /// Quote the value as a string literal, backslashes are escape characters in string
+    /// literals so they are escaped as well as the quotes.
+    pub fn string_literal(value: &str) -> String {
+        let mut literal = String::with_capacity(value.len() + 2);
+        literal.push('\'');
+        for c in value.chars() {
+            if matches!(c, '\\' | '\'') {
+                literal.push('\\');
+            }
+            literal.push(c);
+        }
+        literal.push('\'');
+        literal
+    }
+
+    /// Quote the bytes as a hex literal decoded by `unhex`, they may not be valid UTF-8.
+    pub fn binary_literal(value: &[u8]) -> String {
+        let hex = value
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect::<String>();
+        format!("unhex('{}')", hex)
+    }
+
+    pub fn num_params(&self) -> usize {
+        self.parts.len() - 1
+    }
+
+    /// Build the query to run by replacing the placeholders with the literals, in order.
+    pub fn bind(&self, literals: &[String]) -> Result<String> {
+        if literals.len() != self.num_params() {
+            return Err(ErrorCode::BadArguments(format!(
+                "Prepared statement expects {} parameters, but got {}",
+                self.num_params(),
+                literals.len()
+            )));
+        }
+
+        let mut query = self.parts[0].clone();
+        for (literal, part) in literals.iter().zip(self.parts[1..].iter()) {
+            query.push_str(literal);
+            query.push_str(part);
+        }
+        Ok(query)
+    }
+}
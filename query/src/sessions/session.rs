@@ -20,6 +20,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_macros::MallocSizeOf;
 use common_mem_allocator::malloc_size;
+use common_meta_types::RoleInfo;
 use common_meta_types::UserInfo;
 use futures::channel::*;
 
@@ -27,6 +28,7 @@ use crate::catalogs::DatabaseCatalog;
 use crate::configs::Config;
 use crate::sessions::context_shared::QueryContextShared;
 use crate::sessions::MutableStatus;
+use crate::sessions::PreparedStatement;
 use crate::sessions::QueryContext;
 use crate::sessions::SessionManager;
 use crate::sessions::Settings;
@@ -104,6 +106,7 @@ impl Session {
     /// For a query, execution environment(e.g cluster) should be immutable.
     /// We can bind the environment to the context in create_context method.
     pub async fn create_context(self: &Arc<Self>) -> Result<Arc<QueryContext>> {
+        self.refresh_current_roles().await?;
         let context_shared = self.mutable_state.get_context_shared();
 
         Ok(match context_shared.as_ref() {
@@ -160,6 +163,47 @@ impl Session {
         self.mutable_state.set_current_user(user)
     }
 
+    // Set the authenticated user and activate all the roles granted to the user.
+    pub async fn set_authed_user(self: &Arc<Self>, user: UserInfo) -> Result<()> {
+        let user_manager = self.get_user_manager();
+        let roles = user_manager.get_granted_roles(&user.roles).await?;
+        self.mutable_state.set_current_user(user);
+        self.mutable_state.set_current_roles(roles);
+        Ok(())
+    }
+
+    // Reload the activated roles before every query, the roles may have been revoked from
+    // the user and their grants may have changed since they were activated.
+    async fn refresh_current_roles(self: &Arc<Self>) -> Result<()> {
+        let roles = self.mutable_state.get_current_roles();
+        let current_user = match self.mutable_state.get_current_user() {
+            Some(user) if !roles.is_empty() => user,
+            _ => return Ok(()),
+        };
+
+        let user_manager = self.get_user_manager();
+        let user = user_manager
+            .get_user(&current_user.name, &current_user.hostname)
+            .await?;
+        let names = roles
+            .into_iter()
+            .map(|role| role.name)
+            .filter(|name| user.roles.contains(name))
+            .collect::<Vec<_>>();
+
+        let roles = user_manager.get_granted_roles(&names).await?;
+        self.mutable_state.set_current_roles(roles);
+        Ok(())
+    }
+
+    pub fn get_current_roles(self: &Arc<Self>) -> Vec<RoleInfo> {
+        self.mutable_state.get_current_roles()
+    }
+
+    pub fn set_current_roles(self: &Arc<Self>, roles: Vec<RoleInfo>) {
+        self.mutable_state.set_current_roles(roles)
+    }
+
     pub fn get_settings(self: &Arc<Self>) -> Arc<Settings> {
         self.mutable_state.get_settings()
     }
@@ -176,6 +220,18 @@ impl Session {
         self.sessions.get_user_manager()
     }
 
+    pub fn add_prepared_statement(self: &Arc<Self>, statement: PreparedStatement) -> u32 {
+        self.mutable_state.add_prepared_statement(statement)
+    }
+
+    pub fn get_prepared_statement(self: &Arc<Self>, id: u32) -> Option<Arc<PreparedStatement>> {
+        self.mutable_state.get_prepared_statement(id)
+    }
+
+    pub fn remove_prepared_statement(self: &Arc<Self>, id: u32) -> Option<Arc<PreparedStatement>> {
+        self.mutable_state.remove_prepared_statement(id)
+    }
+
     pub fn get_memory_usage(self: &Arc<Self>) -> usize {
         malloc_size(self)
     }
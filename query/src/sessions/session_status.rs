@@ -12,18 +12,22 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::net::SocketAddr;
 use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicU32;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
 use common_exception::Result;
 use common_infallible::RwLock;
 use common_macros::MallocSizeOf;
+use common_meta_types::RoleInfo;
 use common_meta_types::UserInfo;
 use futures::channel::oneshot::Sender;
 
 use crate::sessions::context_shared::QueryContextShared;
+use crate::sessions::PreparedStatement;
 use crate::sessions::Settings;
 
 #[derive(MallocSizeOf)]
@@ -34,11 +38,17 @@ pub struct MutableStatus {
     #[ignore_malloc_size_of = "insignificant"]
     current_user: RwLock<Option<UserInfo>>,
     #[ignore_malloc_size_of = "insignificant"]
+    current_roles: RwLock<Vec<RoleInfo>>,
+    #[ignore_malloc_size_of = "insignificant"]
     client_host: RwLock<Option<SocketAddr>>,
     #[ignore_malloc_size_of = "insignificant"]
     io_shutdown_tx: RwLock<Option<Sender<Sender<()>>>>,
     #[ignore_malloc_size_of = "insignificant"]
     context_shared: RwLock<Option<Arc<QueryContextShared>>>,
+    #[ignore_malloc_size_of = "insignificant"]
+    next_statement_id: AtomicU32,
+    #[ignore_malloc_size_of = "insignificant"]
+    prepared_statements: RwLock<HashMap<u32, Arc<PreparedStatement>>>,
 }
 
 impl MutableStatus {
@@ -46,11 +56,14 @@ impl MutableStatus {
         Ok(MutableStatus {
             abort: Default::default(),
             current_user: Default::default(),
+            current_roles: Default::default(),
             client_host: Default::default(),
             current_database: RwLock::new("default".to_string()),
             session_settings: RwLock::new(Settings::try_create()?.as_ref().clone()),
             io_shutdown_tx: Default::default(),
             context_shared: Default::default(),
+            next_statement_id: AtomicU32::new(1),
+            prepared_statements: Default::default(),
         })
     }
 
@@ -88,6 +101,18 @@ impl MutableStatus {
         *lock = Some(user);
     }
 
+    // Get the roles activated in the current session
+    pub fn get_current_roles(&self) -> Vec<RoleInfo> {
+        let lock = self.current_roles.read();
+        lock.clone()
+    }
+
+    // Set the roles activated in the current session
+    pub fn set_current_roles(&self, roles: Vec<RoleInfo>) {
+        let mut lock = self.current_roles.write();
+        *lock = roles;
+    }
+
     pub fn get_settings(&self) -> Arc<Settings> {
         let lock = self.session_settings.read();
         Arc::new(lock.clone())
@@ -134,4 +159,23 @@ impl MutableStatus {
         let mut lock = self.context_shared.write();
         lock.take()
     }
+
+    // Cache a prepared statement and return the id assigned to it.
+    pub fn add_prepared_statement(&self, statement: PreparedStatement) -> u32 {
+        let id = self.next_statement_id.fetch_add(1, Ordering::Relaxed);
+        let mut lock = self.prepared_statements.write();
+        lock.insert(id, Arc::new(statement));
+        id
+    }
+
+    pub fn get_prepared_statement(&self, id: u32) -> Option<Arc<PreparedStatement>> {
+        let lock = self.prepared_statements.read();
+        lock.get(&id).cloned()
+    }
+
+    // Remove a prepared statement, return it if it exists.
+    pub fn remove_prepared_statement(&self, id: u32) -> Option<Arc<PreparedStatement>> {
+        let mut lock = self.prepared_statements.write();
+        lock.remove(&id)
+    }
 }
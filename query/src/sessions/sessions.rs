@@ -21,6 +21,7 @@ use std::time::Duration;
 
 use common_base::tokio;
 use common_base::SignalStream;
+use common_dal::DalCache;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_infallible::RwLock;
@@ -43,6 +44,7 @@ pub struct SessionManager {
     pub(in crate::sessions) catalog: Arc<DatabaseCatalog>,
     pub(in crate::sessions) user: Arc<UserApiProvider>,
     pub(in crate::sessions) http_query_manager: Arc<HttpQueryManager>,
+    pub(in crate::sessions) dal_cache: Option<Arc<DalCache>>,
 
     pub(in crate::sessions) max_sessions: usize,
     pub(in crate::sessions) active_sessions: Arc<RwLock<HashMap<String, Arc<Session>>>>,
@@ -60,6 +62,17 @@ impl SessionManager {
 
         let http_query_manager = HttpQueryManager::create_global(conf.clone()).await?;
 
+        // Local cache of the fuse table objects, shared by all the sessions.
+        let cache_conf = &conf.storage.cache;
+        let dal_cache = match cache_conf.cache_enabled {
+            false => None,
+            true => Some(DalCache::create(
+                cache_conf.cache_memory_size,
+                &cache_conf.cache_disk_path,
+                cache_conf.cache_disk_size,
+            )?),
+        };
+
         let max_active_sessions = conf.query.max_active_sessions as usize;
         Ok(Arc::new(SessionManager {
             catalog,
@@ -67,6 +80,7 @@ impl SessionManager {
             discovery,
             user,
             http_query_manager,
+            dal_cache,
             max_sessions: max_active_sessions,
             active_sessions: Arc::new(RwLock::new(HashMap::with_capacity(max_active_sessions))),
         }))
@@ -84,6 +98,10 @@ impl SessionManager {
         self.http_query_manager.clone()
     }
 
+    pub fn get_dal_cache(self: &Arc<Self>) -> Option<Arc<DalCache>> {
+        self.dal_cache.clone()
+    }
+
     // Get the user api provider.
     pub fn get_user_manager(self: &Arc<Self>) -> Arc<UserApiProvider> {
         self.user.clone()
@@ -31,7 +31,10 @@ impl Settings {
         ("max_threads", u64, 16, "The maximum number of threads to execute the request. By default, it is determined automatically."),
         ("flight_client_timeout", u64, 60, "Max duration the flight client request is allowed to take in seconds. By default, it is 60 seconds"),
         ("min_distributed_rows", u64, 100000000, "Minimum distributed read rows. In cluster mode, when read rows exceeds this value, the local table converted to distributed query."),
-        ("min_distributed_bytes", u64, 500 * 1024 * 1024, "Minimum distributed read bytes. In cluster mode, when read bytes exceeds this value, the local table converted to distributed query.")
+        ("min_distributed_bytes", u64, 500 * 1024 * 1024, "Minimum distributed read bytes. In cluster mode, when read bytes exceeds this value, the local table converted to distributed query."),
+        ("max_broadcast_join_bytes", u64, 10 * 1024 * 1024, "Maximum broadcast join read bytes. In cluster mode, when the right side of join reads less than this value, it is broadcast to all nodes instead of shuffled by the join keys."),
+        ("fuse_commit_max_retries", u64, 10, "Maximum number of retries when the commit of a fuse table conflicts with a concurrent commit."),
+        ("fuse_commit_retry_backoff_ms", u64, 10, "Initial backoff between the retries of a conflicting fuse table commit in milliseconds, doubled after each retry.")
     }
 
     pub fn try_create() -> Result<Arc<Settings>> {
@@ -145,6 +145,10 @@ pub enum BinaryOperator {
     Or,
     Like,
     NotLike,
+    ILike,
+    NotILike,
+    Regexp,
+    NotRegexp,
     BitwiseOr,
     BitwiseAnd,
     BitwiseXor,
@@ -227,6 +231,18 @@ impl Display for BinaryOperator {
             BinaryOperator::NotLike => {
                 write!(f, "NOT LIKE")
             }
+            BinaryOperator::ILike => {
+                write!(f, "ILIKE")
+            }
+            BinaryOperator::NotILike => {
+                write!(f, "NOT ILIKE")
+            }
+            BinaryOperator::Regexp => {
+                write!(f, "REGEXP")
+            }
+            BinaryOperator::NotRegexp => {
+                write!(f, "NOT REGEXP")
+            }
             BinaryOperator::BitwiseOr => {
                 write!(f, "|")
             }
@@ -774,6 +774,8 @@ impl TransformerSqlparser {
             SqlparserBinaryOperator::Or => Ok(BinaryOperator::Or),
             SqlparserBinaryOperator::Like => Ok(BinaryOperator::Like),
             SqlparserBinaryOperator::NotLike => Ok(BinaryOperator::NotLike),
+            SqlparserBinaryOperator::ILike => Ok(BinaryOperator::ILike),
+            SqlparserBinaryOperator::NotILike => Ok(BinaryOperator::NotILike),
             SqlparserBinaryOperator::BitwiseOr => Ok(BinaryOperator::BitwiseOr),
             SqlparserBinaryOperator::BitwiseAnd => Ok(BinaryOperator::BitwiseAnd),
             SqlparserBinaryOperator::BitwiseXor => Ok(BinaryOperator::BitwiseXor),
@@ -12,19 +12,26 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::sync::Arc;
 
+use common_datavalues::compare_coercion;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataType;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_planners::ExplainPlan;
 use common_planners::Expression;
 use common_planners::PlanBuilder;
 use common_planners::PlanNode;
+use common_planners::RewriteHelper;
 use common_planners::SelectPlan;
 
 use crate::sessions::QueryContext;
 use crate::sql::statements::AnalyzableStatement;
 use crate::sql::statements::AnalyzedResult;
+use crate::sql::statements::JoinRelation;
+use crate::sql::statements::JoinRelationInput;
 use crate::sql::statements::QueryAnalyzeState;
 use crate::sql::statements::QueryRelation;
 use crate::sql::DfHint;
@@ -74,7 +81,8 @@ impl PlanParser {
         let from = Self::build_from_plan(data)?;
         let filter = Self::build_filter_plan(from, data)?;
         let group_by = Self::build_group_by_plan(filter, data)?;
-        let before_order = Self::build_before_order(group_by, data)?;
+        let window = Self::build_window_plan(group_by, data)?;
+        let before_order = Self::build_before_order(window, data)?;
         let having = Self::build_having_plan(before_order, data)?;
         let order_by = Self::build_order_by_plan(having, data)?;
         let projection = Self::build_projection_plan(order_by, data)?;
@@ -86,10 +94,143 @@ impl PlanParser {
     }
 
     fn build_from_plan(data: &QueryAnalyzeState) -> Result<PlanNode> {
-        match &data.relation {
+        Self::build_relation_plan(&data.relation)
+    }
+
+    fn build_relation_plan(relation: &QueryRelation) -> Result<PlanNode> {
+        match relation {
             QueryRelation::None => Err(ErrorCode::LogicalError("Not from in select query")),
             QueryRelation::Nested(data) => Self::build_query_plan(data),
             QueryRelation::FromTable(plan) => Ok(PlanNode::ReadSource(plan.as_ref().clone())),
+            QueryRelation::Join(join) => Self::build_join_plan(join),
+        }
+    }
+
+    fn build_join_plan(join: &JoinRelation) -> Result<PlanNode> {
+        let left = Self::build_join_input_plan(&join.left)?;
+        let right = Self::build_join_input_plan(&join.right)?;
+
+        let left_schema = left.schema();
+        let right_schema = right.schema();
+        let mut left_keys = vec![];
+        let mut right_keys = vec![];
+        let mut other_conditions = vec![];
+
+        if let Some(condition) = &join.condition {
+            let mut conjunctions = vec![];
+            Self::split_conjunctions(condition, &mut conjunctions);
+
+            for conjunction in conjunctions {
+                match Self::extract_join_keys(&conjunction, &left_schema, &right_schema)? {
+                    None => other_conditions.push(conjunction),
+                    Some((left_key, right_key)) => {
+                        left_keys.push(left_key);
+                        right_keys.push(right_key);
+                    }
+                }
+            }
+        }
+
+        let other_condition = other_conditions
+            .into_iter()
+            .reduce(|condition, conjunction| condition.and(conjunction));
+
+        PlanBuilder::from(&left)
+            .join(
+                &right,
+                join.join_type,
+                &left_keys,
+                &right_keys,
+                other_condition,
+            )?
+            .build()
+    }
+
+    /// Rename the columns of the join input to the names in the joined schema.
+    fn build_join_input_plan(input: &JoinRelationInput) -> Result<PlanNode> {
+        let plan = Self::build_relation_plan(&input.relation)?;
+
+        let schema = plan.schema();
+        let mut need_rename = false;
+        let mut projection_exprs = Vec::with_capacity(schema.fields().len());
+        for field in schema.fields() {
+            match input.aliases.get(field.name()) {
+                Some(alias) if alias != field.name() => {
+                    need_rename = true;
+                    projection_exprs.push(Expression::Column(field.name().clone()).alias(alias));
+                }
+                _ => projection_exprs.push(Expression::Column(field.name().clone())),
+            }
+        }
+
+        match need_rename {
+            false => Ok(plan),
+            true => PlanBuilder::from(&plan).project(&projection_exprs)?.build(),
+        }
+    }
+
+    fn split_conjunctions(expr: &Expression, conjunctions: &mut Vec<Expression>) {
+        match expr {
+            Expression::BinaryExpression { op, left, right } if op.to_lowercase() == "and" => {
+                Self::split_conjunctions(left, conjunctions);
+                Self::split_conjunctions(right, conjunctions);
+            }
+            _ => conjunctions.push(expr.clone()),
+        }
+    }
+
+    /// Extract the equi-join keys if the expression is `left_expr = right_expr`,
+    /// each side of the equation only refers to columns of one join input.
+    fn extract_join_keys(
+        expr: &Expression,
+        left_schema: &DataSchemaRef,
+        right_schema: &DataSchemaRef,
+    ) -> Result<Option<(Expression, Expression)>> {
+        let (lhs, rhs) = match expr {
+            Expression::BinaryExpression { op, left, right } if op == "=" => (left, right),
+            _ => return Ok(None),
+        };
+
+        let refer_to = |expr: &Expression, schema: &DataSchemaRef| -> Result<bool> {
+            let columns = RewriteHelper::expression_plan_columns(expr)?;
+            let names = columns
+                .iter()
+                .map(|column| column.column_name())
+                .collect::<HashSet<_>>();
+
+            Ok(!names.is_empty() && names.iter().all(|name| schema.has_field(name)))
+        };
+
+        let (left_key, right_key) = if refer_to(lhs, left_schema)? && refer_to(rhs, right_schema)? {
+            (lhs.as_ref().clone(), rhs.as_ref().clone())
+        } else if refer_to(lhs, right_schema)? && refer_to(rhs, left_schema)? {
+            (rhs.as_ref().clone(), lhs.as_ref().clone())
+        } else {
+            return Ok(None);
+        };
+
+        // Both sides of the keys must be the same type, so that they can be hashed in the same way.
+        let left_type = left_key.to_data_type(left_schema)?;
+        let right_type = right_key.to_data_type(right_schema)?;
+        match left_type == right_type {
+            true => Ok(Some((left_key, right_key))),
+            false => {
+                let data_type = compare_coercion(&left_type, &right_type)?;
+                Ok(Some((
+                    Self::cast_join_key(left_key, &left_type, &data_type),
+                    Self::cast_join_key(right_key, &right_type, &data_type),
+                )))
+            }
+        }
+    }
+
+    fn cast_join_key(key: Expression, key_type: &DataType, data_type: &DataType) -> Expression {
+        match key_type == data_type {
+            true => key,
+            false => Expression::Cast {
+                expr: Box::new(key),
+                data_type: data_type.clone(),
+            },
         }
     }
 
@@ -142,6 +283,30 @@ impl PlanParser {
         }
     }
 
+    fn build_window_plan(plan: PlanNode, data: &QueryAnalyzeState) -> Result<PlanNode> {
+        match data.window_expressions.is_empty() {
+            true => Ok(plan),
+            false => {
+                let before_window_exprs = &data.before_window_expressions;
+                let mut builder = match before_window_exprs
+                    .iter()
+                    .all(|expr| matches!(expr, Expression::Column(_)))
+                {
+                    true => PlanBuilder::from(&plan),
+                    false => {
+                        PlanBuilder::from(&plan).expression(before_window_exprs, "Before Window")?
+                    }
+                };
+
+                for window_expr in &data.window_expressions {
+                    builder = builder.window(window_expr.clone())?;
+                }
+
+                builder.build()
+            }
+        }
+    }
+
     fn build_having_plan(plan: PlanNode, data: &QueryAnalyzeState) -> Result<PlanNode> {
         match &data.having {
             None => Ok(plan),
@@ -31,7 +31,11 @@ impl SQLCommon {
             SQLDataType::Varchar(_) => Ok(DataType::String),
             SQLDataType::String => Ok(DataType::String),
             SQLDataType::Text => Ok(DataType::String),
-            SQLDataType::Decimal(_, _) => Ok(DataType::Float64),
+            // DECIMAL is equivalent to DECIMAL(10, 0) like MySQL
+            SQLDataType::Decimal(precision, scale) => DataType::decimal(
+                precision.unwrap_or(10) as usize,
+                scale.unwrap_or(0) as usize,
+            ),
             SQLDataType::Float(_) => Ok(DataType::Float32),
             SQLDataType::Real | SQLDataType::Double => Ok(DataType::Float64),
             SQLDataType::Boolean => Ok(DataType::Boolean),
@@ -58,6 +62,11 @@ impl SQLCommon {
                     "DATE32" => Ok(DataType::Date32),
                     "DATETIME" => Ok(DataType::DateTime32(None)),
                     "DATETIME32" => Ok(DataType::DateTime32(None)),
+                    // DATETIME64 is equivalent to DATETIME64(3) like ClickHouse
+                    "DATETIME64" => Ok(DataType::DateTime64(3, None)),
+                    name if name.starts_with("DATETIME64(") => {
+                        Self::make_datetime64_type(&obj.0[0].value)
+                    }
                     "SIGNED" => Ok(DataType::Int64),
                     "UNSIGNED" => Ok(DataType::UInt64),
 
@@ -73,4 +82,40 @@ impl SQLCommon {
             ))),
         }
     }
+
+    /// Parse the quoted custom type such as `DateTime64(6, 'Asia/Shanghai')`,
+    /// the parser does not accept arguments for custom types.
+    fn make_datetime64_type(name: &str) -> Result<DataType> {
+        let error = || {
+            ErrorCode::IllegalDataType(format!(
+                "The SQL data type {} is invalid, it should be like DateTime64(precision[, 'timezone'])",
+                name
+            ))
+        };
+
+        let args = name
+            .trim_end()
+            .get("DATETIME64(".len()..)
+            .and_then(|args| args.strip_suffix(')'))
+            .ok_or_else(error)?;
+
+        let mut args = args.splitn(2, ',');
+        let precision = args
+            .next()
+            .and_then(|precision| precision.trim().parse::<u32>().ok())
+            .ok_or_else(error)?;
+        let tz = match args.next() {
+            None => None,
+            Some(tz) => {
+                let tz = tz.trim();
+                let tz = tz
+                    .strip_prefix('\'')
+                    .and_then(|tz| tz.strip_suffix('\''))
+                    .ok_or_else(error)?;
+                Some(tz.to_string())
+            }
+        };
+
+        DataType::datetime64(precision, tz)
+    }
 }
@@ -24,11 +24,13 @@ use common_io::prelude::OptionsDeserializer;
 use common_meta_types::AuthType;
 use common_meta_types::Credentials;
 use common_meta_types::FileFormat;
+use common_meta_types::PrincipalIdentity;
 use common_meta_types::StageParams;
 use common_meta_types::UserIdentity;
 use common_meta_types::UserPrivilegeSet;
 use common_meta_types::UserPrivilegeType;
 use common_planners::ExplainType;
+use common_planners::DEFAULT_VACUUM_RETAIN_HOURS;
 use metrics::histogram;
 use serde::Deserialize;
 use sqlparser::ast::BinaryOperator;
@@ -52,23 +54,33 @@ use sqlparser::tokenizer::Word;
 
 use super::statements::DfCopy;
 use super::statements::DfDescribeStage;
+use crate::sql::statements::DfAlterTable;
+use crate::sql::statements::DfAlterTableAction;
 use crate::sql::statements::DfAlterUser;
 use crate::sql::statements::DfCompactTable;
 use crate::sql::statements::DfCreateDatabase;
+use crate::sql::statements::DfCreateRole;
 use crate::sql::statements::DfCreateStage;
 use crate::sql::statements::DfCreateTable;
 use crate::sql::statements::DfCreateUser;
+use crate::sql::statements::DfCreateView;
+use crate::sql::statements::DfDeleteStatement;
 use crate::sql::statements::DfDescribeTable;
 use crate::sql::statements::DfDropDatabase;
+use crate::sql::statements::DfDropRole;
 use crate::sql::statements::DfDropTable;
 use crate::sql::statements::DfDropUser;
+use crate::sql::statements::DfDropView;
 use crate::sql::statements::DfExplain;
 use crate::sql::statements::DfGrantObject;
+use crate::sql::statements::DfGrantRole;
 use crate::sql::statements::DfGrantStatement;
 use crate::sql::statements::DfInsertStatement;
 use crate::sql::statements::DfKillStatement;
 use crate::sql::statements::DfQueryStatement;
+use crate::sql::statements::DfRevokeRole;
 use crate::sql::statements::DfRevokeStatement;
+use crate::sql::statements::DfSetRole;
 use crate::sql::statements::DfSetVariable;
 use crate::sql::statements::DfShowCreateTable;
 use crate::sql::statements::DfShowDatabases;
@@ -80,7 +92,9 @@ use crate::sql::statements::DfShowSettings;
 use crate::sql::statements::DfShowTables;
 use crate::sql::statements::DfShowUsers;
 use crate::sql::statements::DfTruncateTable;
+use crate::sql::statements::DfUpdateStatement;
 use crate::sql::statements::DfUseDatabase;
+use crate::sql::statements::DfVacuumTable;
 use crate::sql::DfHint;
 use crate::sql::DfStatement;
 
@@ -226,6 +240,8 @@ impl<'a> DfParser<'a> {
                     Keyword::TRUNCATE => self.parse_truncate(),
                     Keyword::SET => self.parse_set(),
                     Keyword::INSERT => self.parse_insert(),
+                    Keyword::DELETE => self.parse_delete(),
+                    Keyword::UPDATE => self.parse_update(),
                     Keyword::SELECT | Keyword::WITH | Keyword::VALUES => self.parse_query(),
                     Keyword::GRANT => {
                         self.parser.next_token();
@@ -244,6 +260,7 @@ impl<'a> DfParser<'a> {
                         "USE" => self.parse_use_database(),
                         "KILL" => self.parse_kill_query(),
                         "COMPACT" => self.parse_compact(),
+                        "VACUUM" => self.parse_vacuum(),
                         _ => self.expected("Keyword", self.parser.peek_token()),
                     },
                     _ => self.expected("an SQL statement", Token::Word(w)),
@@ -264,6 +281,10 @@ impl<'a> DfParser<'a> {
 
     fn parse_set(&mut self) -> Result<DfStatement, ParserError> {
         self.parser.next_token();
+        if self.consume_token("ROLE") {
+            return self.parse_set_role();
+        }
+
         match self.parser.parse_set()? {
             Statement::SetVariable {
                 local,
@@ -308,6 +329,53 @@ impl<'a> DfParser<'a> {
         }
     }
 
+    // DELETE FROM [db.]table [WHERE selection]
+    fn parse_delete(&mut self) -> Result<DfStatement, ParserError> {
+        self.parser.next_token();
+        self.parser.expect_keyword(Keyword::FROM)?;
+        let table_name = self.parser.parse_object_name()?;
+        let selection = if self.parser.parse_keyword(Keyword::WHERE) {
+            Some(self.parser.parse_expr()?)
+        } else {
+            None
+        };
+
+        Ok(DfStatement::Delete(DfDeleteStatement {
+            name: table_name,
+            selection,
+        }))
+    }
+
+    // UPDATE [db.]table SET column = expr [, column = expr ...] [WHERE selection]
+    fn parse_update(&mut self) -> Result<DfStatement, ParserError> {
+        self.parser.next_token();
+        let table_name = self.parser.parse_object_name()?;
+        self.parser.expect_keyword(Keyword::SET)?;
+
+        let mut assignments = vec![];
+        loop {
+            let column = self.parser.parse_identifier()?;
+            self.parser.expect_token(&Token::Eq)?;
+            let value = self.parser.parse_expr()?;
+            assignments.push((column, value));
+            if !self.parser.consume_token(&Token::Comma) {
+                break;
+            }
+        }
+
+        let selection = if self.parser.parse_keyword(Keyword::WHERE) {
+            Some(self.parser.parse_expr()?)
+        } else {
+            None
+        };
+
+        Ok(DfStatement::Update(DfUpdateStatement {
+            name: table_name,
+            assignments,
+            selection,
+        }))
+    }
+
     /// Parse an SQL EXPLAIN statement.
     pub fn parse_explain(&mut self) -> Result<DfStatement, ParserError> {
         // Parser is at the token immediately after EXPLAIN
@@ -526,11 +594,14 @@ impl<'a> DfParser<'a> {
                 //TODO:make stage to sql parser keyword
                 if w.value.to_uppercase() == "STAGE" {
                     self.parse_create_stage()
+                } else if w.value.to_uppercase() == "ROLE" {
+                    self.parse_create_role()
                 } else {
                     match w.keyword {
                         Keyword::TABLE => self.parse_create_table(),
                         Keyword::DATABASE => self.parse_create_database(),
                         Keyword::USER => self.parse_create_user(),
+                        Keyword::VIEW => self.parse_create_view(),
                         _ => self.expected("create statement", Token::Word(w)),
                     }
                 }
@@ -543,6 +614,7 @@ impl<'a> DfParser<'a> {
         match self.parser.next_token() {
             Token::Word(w) => match w.keyword {
                 Keyword::USER => self.parse_alter_user(),
+                Keyword::TABLE => self.parse_alter_table(),
                 _ => self.expected("alter statement", Token::Word(w)),
             },
             unexpected => self.expected("alter statement", unexpected),
@@ -586,6 +658,8 @@ impl<'a> DfParser<'a> {
                 Keyword::DATABASE => self.parse_drop_database(),
                 Keyword::TABLE => self.parse_drop_table(),
                 Keyword::USER => self.parse_drop_user(),
+                Keyword::VIEW => self.parse_drop_view(),
+                _ if w.value.to_uppercase() == "ROLE" => self.parse_drop_role(),
                 _ => self.expected("drop statement", Token::Word(w)),
             },
             unexpected => self.expected("drop statement", unexpected),
@@ -618,6 +692,19 @@ impl<'a> DfParser<'a> {
         Ok(DfStatement::DropTable(drop))
     }
 
+    /// Drop view.
+    fn parse_drop_view(&mut self) -> Result<DfStatement, ParserError> {
+        let if_exists = self.parser.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
+        let view_name = self.parser.parse_object_name()?;
+
+        let drop = DfDropView {
+            if_exists,
+            name: view_name,
+        };
+
+        Ok(DfStatement::DropView(drop))
+    }
+
     // Parse 'use database' db name.
     fn parse_use_database(&mut self) -> Result<DfStatement, ParserError> {
         if !self.consume_token("USE") {
@@ -702,6 +789,59 @@ impl<'a> DfParser<'a> {
         Ok(DfStatement::AlterUser(alter))
     }
 
+    fn parse_alter_table(&mut self) -> Result<DfStatement, ParserError> {
+        let name = self.parser.parse_object_name()?;
+        let action = if self.parser.parse_keyword(Keyword::ADD) {
+            // ALTER TABLE t ADD [COLUMN] c T [DEFAULT expr]
+            self.parser.parse_keyword(Keyword::COLUMN);
+            DfAlterTableAction::AddColumn(self.parse_column_def()?)
+        } else if self.parser.parse_keyword(Keyword::DROP) {
+            // ALTER TABLE t DROP [COLUMN] c
+            self.parser.parse_keyword(Keyword::COLUMN);
+            DfAlterTableAction::DropColumn(self.parser.parse_identifier()?)
+        } else if self.parser.parse_keyword(Keyword::RENAME) {
+            if self.parser.parse_keyword(Keyword::COLUMN) {
+                // ALTER TABLE t RENAME COLUMN c1 TO c2
+                let old_column = self.parser.parse_identifier()?;
+                self.parser.expect_keyword(Keyword::TO)?;
+                let new_column = self.parser.parse_identifier()?;
+                DfAlterTableAction::RenameColumn {
+                    old_column,
+                    new_column,
+                }
+            } else {
+                // ALTER TABLE t RENAME TO t2
+                self.parser.expect_keyword(Keyword::TO)?;
+                DfAlterTableAction::RenameTable(self.parser.parse_object_name()?)
+            }
+        } else if self.parser.parse_keyword(Keyword::SET) {
+            // ALTER TABLE t SET OPTIONS (k1 = v1, k2 = v2)
+            if !self.consume_token("OPTIONS") {
+                return self.expected("OPTIONS", self.parser.peek_token());
+            }
+            self.parser.expect_token(&Token::LParen)?;
+            let mut options = HashMap::new();
+            loop {
+                let name = self.parser.parse_identifier()?;
+                self.parser.expect_token(&Token::Eq)?;
+                let value = self.parse_value_or_ident()?;
+                options.insert(name.to_string(), value);
+                if !self.parser.consume_token(&Token::Comma) {
+                    break;
+                }
+            }
+            self.parser.expect_token(&Token::RParen)?;
+            DfAlterTableAction::SetOptions(options)
+        } else {
+            return self.expected(
+                "ADD, DROP, RENAME or SET after ALTER TABLE",
+                self.parser.peek_token(),
+            );
+        };
+
+        Ok(DfStatement::AlterTable(DfAlterTable { name, action }))
+    }
+
     fn parse_drop_user(&mut self) -> Result<DfStatement, ParserError> {
         let if_exists = self.parser.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
         let name = self.parser.parse_literal_string()?;
@@ -718,6 +858,35 @@ impl<'a> DfParser<'a> {
         Ok(DfStatement::DropUser(drop))
     }
 
+    fn parse_create_role(&mut self) -> Result<DfStatement, ParserError> {
+        let if_not_exists =
+            self.parser
+                .parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+        let name = self.parser.parse_literal_string()?;
+        let create = DfCreateRole {
+            if_not_exists,
+            name,
+        };
+        Ok(DfStatement::CreateRole(create))
+    }
+
+    fn parse_drop_role(&mut self) -> Result<DfStatement, ParserError> {
+        let if_exists = self.parser.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
+        let name = self.parser.parse_literal_string()?;
+        let drop = DfDropRole { if_exists, name };
+        Ok(DfStatement::DropRole(drop))
+    }
+
+    // SET ROLE 'role' | ALL | DEFAULT
+    fn parse_set_role(&mut self) -> Result<DfStatement, ParserError> {
+        let role = if self.parser.parse_keyword(Keyword::ALL) || self.consume_token("DEFAULT") {
+            None
+        } else {
+            Some(self.parser.parse_literal_string()?)
+        };
+        Ok(DfStatement::SetRole(DfSetRole { role }))
+    }
+
     fn get_auth_option(&mut self) -> Result<(AuthType, String), ParserError> {
         let exist_not_identified = self.parser.parse_keyword(Keyword::NOT);
         let exist_identified = self.consume_token("IDENTIFIED");
@@ -873,6 +1042,26 @@ impl<'a> DfParser<'a> {
         Ok(DfStatement::CreateTable(create))
     }
 
+    fn parse_create_view(&mut self) -> Result<DfStatement, ParserError> {
+        let if_not_exists =
+            self.parser
+                .parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+        let view_name = self.parser.parse_object_name()?;
+        self.parser.expect_keyword(Keyword::AS)?;
+
+        // Validate the defining query early, the view keeps the original AST.
+        let query = self.parser.parse_query()?;
+        DfQueryStatement::try_from(query.clone())?;
+
+        let create = DfCreateView {
+            if_not_exists,
+            name: view_name,
+            query: Box::new(query),
+        };
+
+        Ok(DfStatement::CreateView(create))
+    }
+
     fn parse_database_engine(&mut self) -> Result<String, ParserError> {
         // TODO make ENGINE as a keyword
         if !self.consume_token("ENGINE") {
@@ -949,6 +1138,8 @@ impl<'a> DfParser<'a> {
                     Keyword::SELECT => privileges.set_privilege(UserPrivilegeType::Select),
                     Keyword::INSERT => privileges.set_privilege(UserPrivilegeType::Insert),
                     Keyword::SET => privileges.set_privilege(UserPrivilegeType::Set),
+                    Keyword::DROP => privileges.set_privilege(UserPrivilegeType::Drop),
+                    Keyword::GRANT => privileges.set_privilege(UserPrivilegeType::Grant),
                     Keyword::ALL => {
                         privileges.set_all_privileges();
                         // GRANT ALL [PRIVILEGES]
@@ -967,6 +1158,20 @@ impl<'a> DfParser<'a> {
     }
 
     fn parse_grant(&mut self) -> Result<DfStatement, ParserError> {
+        if self.consume_token("ROLE") {
+            let role = self.parser.parse_literal_string()?;
+            if !self.parser.parse_keyword(Keyword::TO) {
+                return self.expected("keyword TO", self.parser.peek_token());
+            }
+            let (username, hostname) = self.parse_user_identity()?;
+            let grant = DfGrantRole {
+                role,
+                username,
+                hostname,
+            };
+            return Ok(DfStatement::GrantRole(grant));
+        }
+
         let privileges = self.parse_privileges()?;
         if !self.parser.parse_keyword(Keyword::ON) {
             return self.expected("keyword ON", self.parser.peek_token());
@@ -975,10 +1180,9 @@ impl<'a> DfParser<'a> {
         if !self.parser.parse_keyword(Keyword::TO) {
             return self.expected("keyword TO", self.parser.peek_token());
         }
-        let (name, hostname) = self.parse_user_identity()?;
+        let principal = self.parse_principal_identity()?;
         let grant = DfGrantStatement {
-            name,
-            hostname,
+            principal,
             on,
             priv_types: privileges,
         };
@@ -986,6 +1190,20 @@ impl<'a> DfParser<'a> {
     }
 
     fn parse_revoke(&mut self) -> Result<DfStatement, ParserError> {
+        if self.consume_token("ROLE") {
+            let role = self.parser.parse_literal_string()?;
+            if !self.parser.parse_keyword(Keyword::FROM) {
+                return self.expected("keyword FROM", self.parser.peek_token());
+            }
+            let (username, hostname) = self.parse_user_identity()?;
+            let revoke = DfRevokeRole {
+                role,
+                username,
+                hostname,
+            };
+            return Ok(DfStatement::RevokeRole(revoke));
+        }
+
         let privileges = self.parse_privileges()?;
         if !self.parser.parse_keyword(Keyword::ON) {
             return self.expected("keyword ON", self.parser.peek_token());
@@ -994,10 +1212,9 @@ impl<'a> DfParser<'a> {
         if !self.parser.parse_keyword(Keyword::FROM) {
             return self.expected("keyword FROM", self.parser.peek_token());
         }
-        let (username, hostname) = self.parse_user_identity()?;
+        let principal = self.parse_principal_identity()?;
         let revoke = DfRevokeStatement {
-            username,
-            hostname,
+            principal,
             on,
             priv_types: privileges,
         };
@@ -1014,6 +1231,16 @@ impl<'a> DfParser<'a> {
         Ok((username, hostname))
     }
 
+    // 'user'@'host' or ROLE 'role'
+    fn parse_principal_identity(&mut self) -> Result<PrincipalIdentity, ParserError> {
+        if self.consume_token("ROLE") {
+            let role = self.parser.parse_literal_string()?;
+            return Ok(PrincipalIdentity::Role(role));
+        }
+        let (username, hostname) = self.parse_user_identity()?;
+        Ok(PrincipalIdentity::User(UserIdentity { username, hostname }))
+    }
+
     /// Parse a possibly qualified, possibly quoted identifier or wild card, e.g.
     /// `*` or `myschema`.*. The sub string pattern like "db0%" is not in planned.
     fn parse_grant_object(&mut self) -> Result<DfGrantObject, ParserError> {
@@ -1115,6 +1342,43 @@ impl<'a> DfParser<'a> {
         }
     }
 
+    // VACUUM TABLE [db.]table [RETAIN n HOURS] [DRY RUN]
+    fn parse_vacuum(&mut self) -> Result<DfStatement, ParserError> {
+        self.parser.next_token();
+        match self.parser.next_token() {
+            Token::Word(w) => match w.keyword {
+                Keyword::TABLE => {
+                    let table_name = self.parser.parse_object_name()?;
+
+                    let mut retain_hours = DEFAULT_VACUUM_RETAIN_HOURS;
+                    if self.consume_token("RETAIN") {
+                        retain_hours = self.parser.parse_literal_uint()?;
+                        if !self.consume_token("HOURS") {
+                            return self.expected("HOURS", self.parser.peek_token());
+                        }
+                    }
+
+                    let mut dry_run = false;
+                    if self.consume_token("DRY") {
+                        if !self.consume_token("RUN") {
+                            return self.expected("RUN", self.parser.peek_token());
+                        }
+                        dry_run = true;
+                    }
+
+                    let vacuum = DfVacuumTable {
+                        name: table_name,
+                        retain_hours,
+                        dry_run,
+                    };
+                    Ok(DfStatement::VacuumTable(vacuum))
+                }
+                _ => self.expected("TABLE", Token::Word(w)),
+            },
+            unexpected => self.expected("vacuum statement", unexpected),
+        }
+    }
+
     fn consume_token(&mut self, expected: &str) -> bool {
         if self.parser.peek_token().to_string().to_uppercase() == *expected.to_uppercase() {
             self.parser.next_token();
@@ -21,22 +21,31 @@ use nom::IResult;
 
 use super::statements::DfCopy;
 use super::statements::DfDescribeStage;
+use crate::sql::statements::DfAlterTable;
 use crate::sql::statements::DfAlterUser;
 use crate::sql::statements::DfCompactTable;
 use crate::sql::statements::DfCreateDatabase;
+use crate::sql::statements::DfCreateRole;
 use crate::sql::statements::DfCreateStage;
 use crate::sql::statements::DfCreateTable;
 use crate::sql::statements::DfCreateUser;
+use crate::sql::statements::DfCreateView;
+use crate::sql::statements::DfDeleteStatement;
 use crate::sql::statements::DfDescribeTable;
 use crate::sql::statements::DfDropDatabase;
+use crate::sql::statements::DfDropRole;
 use crate::sql::statements::DfDropTable;
 use crate::sql::statements::DfDropUser;
+use crate::sql::statements::DfDropView;
 use crate::sql::statements::DfExplain;
+use crate::sql::statements::DfGrantRole;
 use crate::sql::statements::DfGrantStatement;
 use crate::sql::statements::DfInsertStatement;
 use crate::sql::statements::DfKillStatement;
 use crate::sql::statements::DfQueryStatement;
+use crate::sql::statements::DfRevokeRole;
 use crate::sql::statements::DfRevokeStatement;
+use crate::sql::statements::DfSetRole;
 use crate::sql::statements::DfSetVariable;
 use crate::sql::statements::DfShowCreateTable;
 use crate::sql::statements::DfShowDatabases;
@@ -48,7 +57,9 @@ use crate::sql::statements::DfShowSettings;
 use crate::sql::statements::DfShowTables;
 use crate::sql::statements::DfShowUsers;
 use crate::sql::statements::DfTruncateTable;
+use crate::sql::statements::DfUpdateStatement;
 use crate::sql::statements::DfUseDatabase;
+use crate::sql::statements::DfVacuumTable;
 
 /// Tokens parsed by `DFParser` are converted into these values.
 #[derive(Debug, Clone, PartialEq)]
@@ -70,8 +81,12 @@ pub enum DfStatement {
     DescribeTable(DfDescribeTable),
     DescribeStage(DfDescribeStage),
     DropTable(DfDropTable),
+    AlterTable(DfAlterTable),
+    CreateView(DfCreateView),
+    DropView(DfDropView),
     TruncateTable(DfTruncateTable),
     CompactTable(DfCompactTable),
+    VacuumTable(DfVacuumTable),
 
     // Settings.
     ShowSettings(DfShowSettings),
@@ -94,12 +109,25 @@ pub enum DfStatement {
     // Insert
     InsertQuery(DfInsertStatement),
 
+    // Delete
+    Delete(DfDeleteStatement),
+
+    // Update
+    Update(DfUpdateStatement),
+
     // User
     CreateUser(DfCreateUser),
     AlterUser(DfAlterUser),
     ShowUsers(DfShowUsers),
     DropUser(DfDropUser),
 
+    // Role
+    CreateRole(DfCreateRole),
+    DropRole(DfDropRole),
+    GrantRole(DfGrantRole),
+    RevokeRole(DfRevokeRole),
+    SetRole(DfSetRole),
+
     // Copy
     Copy(DfCopy),
 
@@ -18,9 +18,15 @@ use std::sync::Arc;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_functions::aggregates::AggregateFunctionFactory;
+use common_planners::find_array_join_exprs_in_expr;
+use common_planners::is_window_function;
 use common_planners::Expression;
+use common_planners::WindowFrame;
+use common_planners::WindowFrameBound;
+use common_planners::WindowFrameUnits;
 use sqlparser::ast::BinaryOperator;
 use sqlparser::ast::DataType;
+use sqlparser::ast::DateTimeField;
 use sqlparser::ast::Expr;
 use sqlparser::ast::Function;
 use sqlparser::ast::FunctionArg;
@@ -28,6 +34,9 @@ use sqlparser::ast::Ident;
 use sqlparser::ast::Query;
 use sqlparser::ast::UnaryOperator;
 use sqlparser::ast::Value;
+use sqlparser::ast::WindowFrameBound as SQLWindowFrameBound;
+use sqlparser::ast::WindowFrameUnits as SQLWindowFrameUnits;
+use sqlparser::ast::WindowSpec;
 
 use crate::functions::ContextFunction;
 use crate::sessions::QueryContext;
@@ -57,9 +66,11 @@ impl ExpressionAnalyzer {
                 ExprRPNItem::Identifier(v) => self.analyze_identifier(v, &mut stack)?,
                 ExprRPNItem::QualifiedIdentifier(v) => self.analyze_identifiers(v, &mut stack)?,
                 ExprRPNItem::Function(v) => self.analyze_function(v, &mut stack)?,
+                ExprRPNItem::Window(v) => self.analyze_window(v, &mut stack)?,
                 ExprRPNItem::Wildcard => self.analyze_wildcard(&mut stack)?,
                 ExprRPNItem::Exists(v) => self.analyze_exists(v, &mut stack).await?,
                 ExprRPNItem::Subquery(v) => self.analyze_scalar_subquery(v, &mut stack).await?,
+                ExprRPNItem::SetSubquery(v) => self.analyze_set_subquery(v, &mut stack).await?,
                 ExprRPNItem::Cast(v) => self.analyze_cast(v, &mut stack)?,
                 ExprRPNItem::Between(negated) => self.analyze_between(*negated, &mut stack)?,
             }
@@ -73,6 +84,17 @@ impl ExpressionAnalyzer {
         }
     }
 
+    /// arrayJoin expands the rows, which is only supported in the SELECT list.
+    pub fn verify_no_array_join(expr: &Expression, info: &str) -> Result<()> {
+        match find_array_join_exprs_in_expr(expr).is_empty() {
+            true => Ok(()),
+            false => Err(ErrorCode::SyntaxException(format!(
+                "{} cannot contain arrayJoin functions, they are only supported in the SELECT list",
+                info
+            ))),
+        }
+    }
+
     fn analyze_value(value: &Value, args: &mut Vec<Expression>) -> Result<()> {
         args.push(ValueExprAnalyzer::analyze(value)?);
         Ok(())
@@ -104,6 +126,70 @@ impl ExpressionAnalyzer {
         Ok(())
     }
 
+    fn analyze_window(&self, info: &WindowExprInfo, args: &mut Vec<Expression>) -> Result<()> {
+        let function = &info.function;
+        let exprs_count = function.args_count + info.partition_by_count + info.order_by.len();
+        if args.len() < exprs_count {
+            return Err(ErrorCode::LogicalError("It's a bug."));
+        }
+
+        let mut exprs = args.split_off(args.len() - exprs_count);
+        let order_by_exprs = exprs.split_off(function.args_count + info.partition_by_count);
+        let partition_by = exprs.split_off(function.args_count);
+
+        let order_by = order_by_exprs
+            .into_iter()
+            .zip(&info.order_by)
+            .map(|(expr, asc)| Expression::Sort {
+                expr: Box::new(expr.clone()),
+                asc: asc.unwrap_or(true),
+                nulls_first: asc.unwrap_or(true),
+                origin_expr: Box::new(expr),
+            })
+            .collect::<Vec<_>>();
+
+        let window_function = match is_window_function(&function.name) {
+            true => Expression::WindowFunction {
+                op: function.name.clone(),
+                params: vec![],
+                args: exprs,
+                partition_by,
+                order_by,
+                window_frame: info.window_frame,
+            },
+            false if AggregateFunctionFactory::instance().check(&function.name) => {
+                if function.distinct {
+                    return Err(ErrorCode::UnImplement(
+                        "DISTINCT is not supported for window functions",
+                    ));
+                }
+
+                match self.aggr_function(function, &exprs)? {
+                    Expression::AggregateFunction {
+                        op, params, args, ..
+                    } => Expression::WindowFunction {
+                        op,
+                        params,
+                        args,
+                        partition_by,
+                        order_by,
+                        window_frame: info.window_frame,
+                    },
+                    _ => return Err(ErrorCode::LogicalError("It's a bug.")),
+                }
+            }
+            false => {
+                return Err(ErrorCode::UnknownFunction(format!(
+                    "Unsupported window function: {}",
+                    function.name
+                )));
+            }
+        };
+
+        args.push(window_function);
+        Ok(())
+    }
+
     fn unary_function(info: &FunctionExprInfo, args: &[Expression]) -> Result<Expression> {
         match args.is_empty() {
             true => Err(ErrorCode::LogicalError(
@@ -216,6 +302,15 @@ impl ExpressionAnalyzer {
         Ok(())
     }
 
+    async fn analyze_set_subquery(
+        &self,
+        subquery: &Query,
+        args: &mut Vec<Expression>,
+    ) -> Result<()> {
+        args.push(self.analyze_subquery(subquery).await?);
+        Ok(())
+    }
+
     async fn analyze_subquery(&self, subquery: &Query) -> Result<Expression> {
         let statement = DfQueryStatement::try_from(subquery.clone())?;
 
@@ -325,14 +420,25 @@ struct FunctionExprInfo {
     parameters: Vec<Value>,
 }
 
+struct WindowExprInfo {
+    function: FunctionExprInfo,
+    partition_by_count: usize,
+    // The `asc` of ORDER BY expressions
+    order_by: Vec<Option<bool>>,
+    window_frame: Option<WindowFrame>,
+}
+
 enum ExprRPNItem {
     Value(Value),
     Identifier(Ident),
     QualifiedIdentifier(Vec<Ident>),
     Function(FunctionExprInfo),
+    Window(WindowExprInfo),
     Wildcard,
     Exists(Box<Query>),
     Subquery(Box<Query>),
+    // The subquery of `IN`, which is evaluated into a set of values.
+    SetSubquery(Box<Query>),
     Cast(common_datavalues::DataType),
     Between(bool),
 }
@@ -415,6 +521,25 @@ impl ExprRPNBuilder {
                 high,
             } => self.visit_between(expr, negated, low, high),
             Expr::Tuple(exprs) => self.visit_tuple(exprs),
+            Expr::Array(exprs) => self.visit_array(exprs),
+            Expr::MapAccess { column, keys } => self.visit_map_access(column, keys),
+            Expr::Case {
+                operand,
+                conditions,
+                results,
+                else_result,
+            } => self.visit_case(operand, conditions, results, else_result),
+            Expr::InList {
+                expr,
+                list,
+                negated,
+            } => self.visit_in_list(expr, list, negated),
+            Expr::InSubquery {
+                expr,
+                subquery,
+                negated,
+            } => self.visit_in_subquery(expr, subquery, negated),
+            Expr::Extract { field, expr } => self.visit_extract(field, expr),
             other => Result::Err(ErrorCode::SyntaxException(format!(
                 "Unsupported expression: {}, type: {:?}",
                 expr, other
@@ -440,6 +565,34 @@ impl ExprRPNBuilder {
         }
     }
 
+    fn visit_array(&mut self, exprs: &[Expr]) -> Result<()> {
+        match exprs.len() {
+            0 => Err(ErrorCode::SyntaxException(
+                "Array must have at least one element.",
+            )),
+            len => {
+                for expr in exprs {
+                    self.visit(expr)?;
+                }
+
+                self.rpn
+                    .push(ExprRPNItem::function(String::from("array"), len));
+                Ok(())
+            }
+        }
+    }
+
+    fn visit_map_access(&mut self, column: &Expr, keys: &[Value]) -> Result<()> {
+        // arr[1][2] is arrayElement(arrayElement(arr, 1), 2)
+        self.visit(column)?;
+        for key in keys {
+            self.visit_value(key)?;
+            self.rpn
+                .push(ExprRPNItem::function(String::from("arrayElement"), 2));
+        }
+        Ok(())
+    }
+
     fn visit_wildcard(&mut self) -> Result<()> {
         self.rpn.push(ExprRPNItem::Wildcard);
         Ok(())
@@ -482,17 +635,68 @@ impl ExprRPNBuilder {
             };
         }
 
-        self.rpn.push(ExprRPNItem::Function(FunctionExprInfo {
+        let function_info = FunctionExprInfo {
             name: function.name.to_string(),
             distinct: function.distinct,
             args_count: function.args.len(),
             unary_operator: false,
             binary_operator: false,
             parameters: function.params.to_owned(),
+        };
+
+        match &function.over {
+            None => self.rpn.push(ExprRPNItem::Function(function_info)),
+            Some(window_spec) => self.visit_window(function_info, window_spec)?,
+        };
+        Ok(())
+    }
+
+    fn visit_window(&mut self, function: FunctionExprInfo, spec: &WindowSpec) -> Result<()> {
+        for expr in &spec.partition_by {
+            self.visit(expr)?;
+        }
+
+        for order_by_expr in &spec.order_by {
+            self.visit(&order_by_expr.expr)?;
+        }
+
+        let window_frame = match &spec.window_frame {
+            None => None,
+            Some(frame) => {
+                let units = match frame.units {
+                    SQLWindowFrameUnits::Rows => WindowFrameUnits::Rows,
+                    SQLWindowFrameUnits::Range => WindowFrameUnits::Range,
+                    SQLWindowFrameUnits::Groups => {
+                        return Err(ErrorCode::UnImplement("GROUPS window frame is unsupported"));
+                    }
+                };
+
+                let end_bound = frame.end_bound.as_ref().map(Self::window_frame_bound);
+                Some(WindowFrame::try_create(
+                    units,
+                    Self::window_frame_bound(&frame.start_bound),
+                    end_bound,
+                )?)
+            }
+        };
+
+        self.rpn.push(ExprRPNItem::Window(WindowExprInfo {
+            function,
+            partition_by_count: spec.partition_by.len(),
+            order_by: spec.order_by.iter().map(|expr| expr.asc).collect(),
+            window_frame,
         }));
         Ok(())
     }
 
+    fn window_frame_bound(bound: &SQLWindowFrameBound) -> WindowFrameBound {
+        match bound {
+            SQLWindowFrameBound::CurrentRow => WindowFrameBound::CurrentRow,
+            SQLWindowFrameBound::Preceding(n) => WindowFrameBound::Preceding(*n),
+            SQLWindowFrameBound::Following(n) => WindowFrameBound::Following(*n),
+        }
+    }
+
     fn visit_cast(&mut self, expr: &Expr, data_type: &DataType) -> Result<()> {
         self.visit(expr)?;
         self.rpn
@@ -576,4 +780,76 @@ impl ExprRPNBuilder {
 
         Ok(())
     }
+
+    fn visit_case(
+        &mut self,
+        operand: &Option<Box<Expr>>,
+        conditions: &[Expr],
+        results: &[Expr],
+        else_result: &Option<Box<Expr>>,
+    ) -> Result<()> {
+        // CASE [operand] WHEN cond THEN result ... [ELSE else_result] END
+        // is lowered into multiIf([operand =] cond, result, ..., else_result)
+        for (condition, result) in conditions.iter().zip(results.iter()) {
+            match operand {
+                None => self.visit(condition)?,
+                Some(operand) => {
+                    self.visit(operand)?;
+                    self.visit(condition)?;
+                    self.rpn
+                        .push(ExprRPNItem::binary_operator(String::from("=")));
+                }
+            }
+            self.visit(result)?;
+        }
+
+        match else_result {
+            None => self.rpn.push(ExprRPNItem::Value(Value::Null)),
+            Some(else_result) => self.visit(else_result)?,
+        };
+
+        let name = String::from("multiIf");
+        let args_count = conditions.len() * 2 + 1;
+        self.rpn.push(ExprRPNItem::function(name, args_count));
+        Ok(())
+    }
+
+    fn visit_in_list(&mut self, expr: &Expr, list: &[Expr], negated: &bool) -> Result<()> {
+        self.visit(expr)?;
+        for value in list {
+            self.visit(value)?;
+        }
+
+        let name = match negated {
+            true => String::from("not in"),
+            false => String::from("in"),
+        };
+        self.rpn.push(ExprRPNItem::function(name, list.len() + 1));
+        Ok(())
+    }
+
+    fn visit_in_subquery(&mut self, expr: &Expr, subquery: &Query, negated: &bool) -> Result<()> {
+        self.visit(expr)?;
+        self.rpn
+            .push(ExprRPNItem::SetSubquery(Box::new(subquery.clone())));
+
+        let name = match negated {
+            true => String::from("not in"),
+            false => String::from("in"),
+        };
+        self.rpn.push(ExprRPNItem::function(name, 2));
+        Ok(())
+    }
+
+    fn visit_extract(&mut self, field: &DateTimeField, expr: &Expr) -> Result<()> {
+        let name = match field {
+            DateTimeField::Year => "toYear",
+            DateTimeField::Month => "toMonth",
+            DateTimeField::Day => "toDayOfMonth",
+            DateTimeField::Hour => "toHour",
+            DateTimeField::Minute => "toMinute",
+            DateTimeField::Second => "toSecond",
+        };
+        self.visit_simple_function(expr, name)
+    }
 }
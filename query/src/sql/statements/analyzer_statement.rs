@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::fmt::Debug;
 use std::sync::Arc;
 
@@ -21,6 +22,7 @@ use common_datavalues::DataSchemaRef;
 use common_exception::Result;
 use common_planners::ExplainType;
 use common_planners::Expression;
+use common_planners::JoinType;
 use common_planners::PlanNode;
 use common_planners::ReadDataSourcePlan;
 
@@ -39,6 +41,22 @@ pub enum QueryRelation {
     None,
     FromTable(Box<ReadDataSourcePlan>),
     Nested(Box<QueryAnalyzeState>),
+    Join(Box<JoinRelation>),
+}
+
+#[derive(Clone)]
+pub struct JoinRelation {
+    pub join_type: JoinType,
+    pub left: JoinRelationInput,
+    pub right: JoinRelationInput,
+    pub condition: Option<Expression>,
+}
+
+#[derive(Clone)]
+pub struct JoinRelationInput {
+    pub relation: QueryRelation,
+    // Rename the columns of the input to the names in the joined schema, input name -> joined name.
+    pub aliases: HashMap<String, String>,
 }
 
 #[derive(Clone)]
@@ -54,6 +72,9 @@ pub struct QueryAnalyzeState {
     pub aggregate_expressions: Vec<Expression>,
     pub before_group_by_expressions: Vec<Expression>,
 
+    pub window_expressions: Vec<Expression>,
+    pub before_window_expressions: Vec<Expression>,
+
     pub limit: Option<usize>,
     pub offset: Option<usize>,
 
@@ -73,6 +94,12 @@ impl QueryAnalyzeState {
             self.before_group_by_expressions.push(expr.clone());
         }
     }
+
+    pub fn add_before_window_expression(&mut self, expr: &Expression) {
+        if !self.before_window_expressions.contains(expr) {
+            self.before_window_expressions.push(expr.clone());
+        }
+    }
 }
 
 impl Default for QueryAnalyzeState {
@@ -86,6 +113,8 @@ impl Default for QueryAnalyzeState {
             group_by_expressions: vec![],
             aggregate_expressions: vec![],
             before_group_by_expressions: vec![],
+            window_expressions: vec![],
+            before_window_expressions: vec![],
             limit: None,
             offset: None,
             relation: QueryRelation::None,
@@ -114,6 +143,14 @@ impl Debug for QueryAnalyzeState {
             debug_struct.field("aggregate", &self.aggregate_expressions);
         }
 
+        if !self.before_window_expressions.is_empty() {
+            debug_struct.field("before_window", &self.before_window_expressions);
+        }
+
+        if !self.window_expressions.is_empty() {
+            debug_struct.field("window", &self.window_expressions);
+        }
+
         if !self.expressions.is_empty() {
             match self.order_by_expressions.is_empty() {
                 true => debug_struct.field("before_projection", &self.expressions),
@@ -155,8 +192,12 @@ impl AnalyzableStatement for DfStatement {
             DfStatement::DescribeTable(v) => v.analyze(ctx).await,
             DfStatement::DescribeStage(v) => v.analyze(ctx).await,
             DfStatement::DropTable(v) => v.analyze(ctx).await,
+            DfStatement::AlterTable(v) => v.analyze(ctx).await,
+            DfStatement::CreateView(v) => v.analyze(ctx).await,
+            DfStatement::DropView(v) => v.analyze(ctx).await,
             DfStatement::TruncateTable(v) => v.analyze(ctx).await,
             DfStatement::CompactTable(v) => v.analyze(ctx).await,
+            DfStatement::VacuumTable(v) => v.analyze(ctx).await,
             DfStatement::UseDatabase(v) => v.analyze(ctx).await,
             DfStatement::ShowCreateTable(v) => v.analyze(ctx).await,
             DfStatement::ShowTables(v) => v.analyze(ctx).await,
@@ -166,6 +207,8 @@ impl AnalyzableStatement for DfStatement {
             DfStatement::ShowGrants(v) => v.analyze(ctx).await,
             DfStatement::KillStatement(v) => v.analyze(ctx).await,
             DfStatement::InsertQuery(v) => v.analyze(ctx).await,
+            DfStatement::Delete(v) => v.analyze(ctx).await,
+            DfStatement::Update(v) => v.analyze(ctx).await,
             DfStatement::SetVariable(v) => v.analyze(ctx).await,
             DfStatement::CreateUser(v) => v.analyze(ctx).await,
             DfStatement::AlterUser(v) => v.analyze(ctx).await,
@@ -173,6 +216,11 @@ impl AnalyzableStatement for DfStatement {
             DfStatement::GrantPrivilege(v) => v.analyze(ctx).await,
             DfStatement::RevokePrivilege(v) => v.analyze(ctx).await,
             DfStatement::DropUser(v) => v.analyze(ctx).await,
+            DfStatement::CreateRole(v) => v.analyze(ctx).await,
+            DfStatement::DropRole(v) => v.analyze(ctx).await,
+            DfStatement::GrantRole(v) => v.analyze(ctx).await,
+            DfStatement::RevokeRole(v) => v.analyze(ctx).await,
+            DfStatement::SetRole(v) => v.analyze(ctx).await,
             DfStatement::Copy(v) => v.analyze(ctx).await,
             DfStatement::CreateStage(v) => v.analyze(ctx).await,
             DfStatement::ShowFunctions(v) => v.analyze(ctx).await,
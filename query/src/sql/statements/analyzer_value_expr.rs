@@ -73,7 +73,6 @@ impl ValueExprAnalyzer {
     }
 
     fn unsupported_interval(interval: &Value) -> Result<Expression> {
-        //TODO: support parsing literal interval like '1 hour'
         Err(ErrorCode::SyntaxException(format!(
             "Unsupported interval expression: {}.",
             interval
@@ -81,20 +80,53 @@ impl ValueExprAnalyzer {
     }
 
     fn analyze_interval(value: &str, unit: &Option<DateTimeField>) -> Result<Expression> {
-        // We only accept i32 for number in "interval [num] [year|month|day|hour|minute|second]"
-        let num = value.parse::<i32>()?;
+        match unit {
+            None => Self::analyze_literal_interval(value),
+            Some(unit) => {
+                // We only accept i32 for number in "interval [num] [year|month|day|hour|minute|second]"
+                let num = value.parse::<i32>()?;
+                Self::interval(num, unit)
+            }
+        }
+    }
 
+    // Parse the unit from the literal, e.g: interval '1 hour'
+    fn analyze_literal_interval(value: &str) -> Result<Expression> {
         //TODO: support default unit for interval
-        match unit {
-            None => Err(ErrorCode::SyntaxException(
+        let parts = value.split_whitespace().collect::<Vec<_>>();
+        if parts.len() != 2 {
+            return Err(ErrorCode::SyntaxException(
                 "Interval must have unit, e.g: '1 HOUR'",
-            )),
-            Some(DateTimeField::Year) => Self::year_month_interval(num * 12),
-            Some(DateTimeField::Month) => Self::year_month_interval(num),
-            Some(DateTimeField::Day) => Self::day_time_interval(num, 0),
-            Some(DateTimeField::Hour) => Self::day_time_interval(0, num * 3600 * 1000),
-            Some(DateTimeField::Minute) => Self::day_time_interval(0, num * 60 * 1000),
-            Some(DateTimeField::Second) => Self::day_time_interval(0, num * 1000),
+            ));
+        }
+
+        let num = parts[0].parse::<i32>()?;
+        let unit = match parts[1].to_lowercase().as_str() {
+            "year" | "years" => DateTimeField::Year,
+            "month" | "months" => DateTimeField::Month,
+            "day" | "days" => DateTimeField::Day,
+            "hour" | "hours" => DateTimeField::Hour,
+            "minute" | "minutes" => DateTimeField::Minute,
+            "second" | "seconds" => DateTimeField::Second,
+            unit => {
+                return Err(ErrorCode::SyntaxException(format!(
+                    "Unsupported interval unit: {}",
+                    unit
+                )))
+            }
+        };
+
+        Self::interval(num, &unit)
+    }
+
+    fn interval(num: i32, unit: &DateTimeField) -> Result<Expression> {
+        match unit {
+            DateTimeField::Year => Self::year_month_interval(num * 12),
+            DateTimeField::Month => Self::year_month_interval(num),
+            DateTimeField::Day => Self::day_time_interval(num, 0),
+            DateTimeField::Hour => Self::day_time_interval(0, num * 3600 * 1000),
+            DateTimeField::Minute => Self::day_time_interval(0, num * 60 * 1000),
+            DateTimeField::Second => Self::day_time_interval(0, num * 1000),
         }
     }
 
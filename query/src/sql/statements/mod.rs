@@ -17,25 +17,34 @@ pub mod query;
 mod analyzer_expr;
 mod analyzer_statement;
 mod analyzer_value_expr;
+mod statement_alter_table;
 mod statement_alter_user;
 mod statement_compact_table;
 mod statement_copy;
 mod statement_create_database;
+mod statement_create_role;
 mod statement_create_stage;
 mod statement_create_table;
 mod statement_create_user;
+mod statement_create_view;
+mod statement_delete;
 mod statement_describe_stage;
 mod statement_describe_table;
 mod statement_drop_database;
+mod statement_drop_role;
 mod statement_drop_table;
 mod statement_drop_user;
+mod statement_drop_view;
 mod statement_explain;
 mod statement_grant;
+mod statement_grant_role;
 mod statement_insert;
 mod statement_kill;
 mod statement_revoke;
+mod statement_revoke_role;
 mod statement_select;
 mod statement_select_convert;
+mod statement_set_role;
 mod statement_set_variable;
 mod statement_show_create_table;
 mod statement_show_databases;
@@ -47,32 +56,46 @@ mod statement_show_settings;
 mod statement_show_tables;
 mod statement_show_users;
 mod statement_truncate_table;
+mod statement_update;
 mod statement_use_database;
+mod statement_vacuum_table;
 
 pub use analyzer_statement::AnalyzableStatement;
 pub use analyzer_statement::AnalyzedResult;
+pub use analyzer_statement::JoinRelation;
+pub use analyzer_statement::JoinRelationInput;
 pub use analyzer_statement::QueryAnalyzeState;
 pub use analyzer_statement::QueryRelation;
 pub use query::QueryASTIR;
+pub use statement_alter_table::DfAlterTable;
+pub use statement_alter_table::DfAlterTableAction;
 pub use statement_alter_user::DfAlterUser;
 pub use statement_compact_table::DfCompactTable;
 pub use statement_copy::DfCopy;
 pub use statement_create_database::DfCreateDatabase;
+pub use statement_create_role::DfCreateRole;
 pub use statement_create_stage::DfCreateStage;
 pub use statement_create_table::DfCreateTable;
 pub use statement_create_user::DfCreateUser;
+pub use statement_create_view::DfCreateView;
+pub use statement_delete::DfDeleteStatement;
 pub use statement_describe_stage::DfDescribeStage;
 pub use statement_describe_table::DfDescribeTable;
 pub use statement_drop_database::DfDropDatabase;
+pub use statement_drop_role::DfDropRole;
 pub use statement_drop_table::DfDropTable;
 pub use statement_drop_user::DfDropUser;
+pub use statement_drop_view::DfDropView;
 pub use statement_explain::DfExplain;
 pub use statement_grant::DfGrantObject;
 pub use statement_grant::DfGrantStatement;
+pub use statement_grant_role::DfGrantRole;
 pub use statement_insert::DfInsertStatement;
 pub use statement_kill::DfKillStatement;
 pub use statement_revoke::DfRevokeStatement;
+pub use statement_revoke_role::DfRevokeRole;
 pub use statement_select::DfQueryStatement;
+pub use statement_set_role::DfSetRole;
 pub use statement_set_variable::DfSetVariable;
 pub use statement_show_create_table::DfShowCreateTable;
 pub use statement_show_databases::DfShowDatabases;
@@ -84,4 +107,6 @@ pub use statement_show_settings::DfShowSettings;
 pub use statement_show_tables::DfShowTables;
 pub use statement_show_users::DfShowUsers;
 pub use statement_truncate_table::DfTruncateTable;
+pub use statement_update::DfUpdateStatement;
 pub use statement_use_database::DfUseDatabase;
+pub use statement_vacuum_table::DfVacuumTable;
@@ -17,8 +17,10 @@ use std::collections::HashSet;
 use common_exception::Result;
 use common_planners::Expression;
 use common_planners::Extras;
+use common_planners::RewriteHelper;
 
 use crate::sql::statements::query::query_ast_ir::QueryASTIRVisitor;
+use crate::sql::statements::query::JoinedRelationDesc;
 use crate::sql::statements::query::JoinedSchema;
 use crate::sql::statements::query::JoinedTableDesc;
 use crate::sql::statements::QueryASTIR;
@@ -48,9 +50,27 @@ impl QueryCollectPushDowns {
             require_columns: HashSet::new(),
         };
         QueryCollectPushDowns::visit(ir, &mut push_downs_data)?;
+        push_downs_data.collect_join_columns(schema.get_relation())?;
         push_downs_data.collect_push_downs(schema)
     }
 
+    fn collect_join_columns(&mut self, relation: &JoinedRelationDesc) -> Result<()> {
+        if let JoinedRelationDesc::Join(join) = relation {
+            self.collect_join_columns(&join.left)?;
+            self.collect_join_columns(&join.right)?;
+
+            if let Some(condition) = &join.condition {
+                for column in RewriteHelper::expression_plan_columns(condition)? {
+                    if let Expression::Column(name) = column {
+                        self.require_columns.insert(name);
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     fn collect_push_downs(mut self, schema: &mut JoinedSchema) -> Result<()> {
         for index in 0..schema.get_tables_desc().len() {
             let table_desc = &schema.get_tables_desc()[index];
@@ -71,7 +91,11 @@ impl QueryCollectPushDowns {
     fn collect_table_require_columns(&mut self, table_desc: &JoinedTableDesc) -> Vec<usize> {
         match self.require_columns.is_empty() {
             true => Self::collect_table_smallest_column(table_desc),
-            false => self.collect_table_projection_columns(table_desc),
+            false => match self.collect_table_projection_columns(table_desc) {
+                // The table is joined but none of its columns is required.
+                columns if columns.is_empty() => Self::collect_table_smallest_column(table_desc),
+                columns => columns,
+            },
         }
     }
 
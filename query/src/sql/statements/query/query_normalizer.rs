@@ -19,6 +19,8 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_planners::extract_aliases;
 use common_planners::find_aggregate_exprs_in_expr;
+use common_planners::find_array_join_exprs_in_expr;
+use common_planners::find_window_exprs_in_expr;
 use common_planners::resolve_aliases_to_exprs;
 use common_planners::Expression;
 use sqlparser::ast::Expr;
@@ -47,6 +49,7 @@ impl QueryNormalizer {
                 group_by_expressions: vec![],
                 having_predicate: None,
                 aggregate_expressions: vec![],
+                window_expressions: vec![],
                 order_by_expressions: vec![],
                 projection_expressions: vec![],
                 limit: None,
@@ -91,7 +94,11 @@ impl QueryNormalizer {
     async fn visit_filter(&mut self, query: &DfQueryStatement) -> Result<()> {
         if let Some(predicate) = &query.selection {
             let analyzer = &self.expression_analyzer;
-            self.query_ast_ir.filter_predicate = Some(analyzer.analyze(predicate).await?);
+            let expression = analyzer.analyze(predicate).await?;
+
+            Self::verify_no_window_function(&expression, "WHERE")?;
+            ExpressionAnalyzer::verify_no_array_join(&expression, "WHERE")?;
+            self.query_ast_ir.filter_predicate = Some(expression);
         }
 
         Ok(())
@@ -103,6 +110,7 @@ impl QueryNormalizer {
 
         for projection_expression in &projection_expressions {
             self.add_aggregate_function(projection_expression)?;
+            self.add_window_function(projection_expression)?;
         }
 
         self.query_ast_ir.projection_expressions = projection_expressions;
@@ -112,6 +120,9 @@ impl QueryNormalizer {
     async fn analyze_group_by(&mut self, query: &DfQueryStatement) -> Result<()> {
         for group_by_expr in &query.group_by {
             let expression = self.resolve_aliases(group_by_expr).await?;
+
+            Self::verify_no_window_function(&expression, "GROUP BY")?;
+            ExpressionAnalyzer::verify_no_array_join(&expression, "GROUP BY")?;
             self.query_ast_ir.group_by_expressions.push(expression);
         }
 
@@ -122,6 +133,8 @@ impl QueryNormalizer {
         if let Some(predicate) = &query.having {
             let expression = self.resolve_aliases(predicate).await?;
 
+            Self::verify_no_window_function(&expression, "HAVING")?;
+            ExpressionAnalyzer::verify_no_array_join(&expression, "HAVING")?;
             self.add_aggregate_function(&expression)?;
             self.query_ast_ir.having_predicate = Some(expression);
         }
@@ -129,10 +142,25 @@ impl QueryNormalizer {
     }
 
     async fn analyze_order_by(&mut self, query: &DfQueryStatement) -> Result<()> {
+        // The rows expanded by arrayJoin of the SELECT list can be sorted by it.
+        let projected_array_joins = self
+            .query_ast_ir
+            .projection_expressions
+            .iter()
+            .flat_map(find_array_join_exprs_in_expr)
+            .collect::<Vec<_>>();
+
         for order_by_expr in &query.order_by {
             let expression = self.resolve_aliases(&order_by_expr.expr).await?;
 
+            for array_join in find_array_join_exprs_in_expr(&expression) {
+                if !projected_array_joins.contains(&array_join) {
+                    ExpressionAnalyzer::verify_no_array_join(&array_join, "ORDER BY")?;
+                }
+            }
+
             self.add_aggregate_function(&expression)?;
+            self.add_window_function(&expression)?;
             self.query_ast_ir
                 .order_by_expressions
                 .push(Expression::Sort {
@@ -226,4 +254,37 @@ impl QueryNormalizer {
 
         Ok(())
     }
+
+    fn add_window_function(&mut self, expr: &Expression) -> Result<()> {
+        for window_expr in find_window_exprs_in_expr(expr) {
+            if let Expression::WindowFunction {
+                args,
+                partition_by,
+                order_by,
+                ..
+            } = &window_expr
+            {
+                for expr in args.iter().chain(partition_by).chain(order_by) {
+                    Self::verify_no_window_function(expr, "Window function")?;
+                    ExpressionAnalyzer::verify_no_array_join(expr, "Window function")?;
+                }
+            }
+
+            if !self.query_ast_ir.window_expressions.contains(&window_expr) {
+                self.query_ast_ir.window_expressions.push(window_expr);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn verify_no_window_function(expr: &Expression, info: &str) -> Result<()> {
+        match find_window_exprs_in_expr(expr).is_empty() {
+            true => Ok(()),
+            false => Err(ErrorCode::SyntaxException(format!(
+                "{} cannot contain window functions",
+                info
+            ))),
+        }
+    }
 }
@@ -24,7 +24,9 @@ use common_datavalues::DataSchemaRef;
 use common_datavalues::DataType;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_planners::Expression;
 use common_planners::Extras;
+use common_planners::JoinType;
 
 use crate::sql::statements::QueryAnalyzeState;
 use crate::storages::Table;
@@ -35,6 +37,8 @@ pub struct JoinedSchema {
     short_name_columns: HashMap<String, JoinedColumnDesc>,
     // Reference by full name, short name may be ambiguous.
     tables_long_name_columns: Vec<JoinedTableDesc>,
+    // The join tree of tables, the leaves are the positions of tables_long_name_columns.
+    relation: JoinedRelationDesc,
 }
 
 impl JoinedSchema {
@@ -42,11 +46,16 @@ impl JoinedSchema {
         JoinedSchema {
             short_name_columns: HashMap::new(),
             tables_long_name_columns: Vec::new(),
+            relation: JoinedRelationDesc::Table(0),
         }
     }
 
-    pub fn from_table(table: Arc<dyn Table>, prefix: Vec<String>) -> Result<JoinedSchema> {
-        let table_desc = JoinedTableDesc::from_table(table, prefix);
+    pub fn from_table(
+        table: Arc<dyn Table>,
+        source_table: Option<(String, String)>,
+        prefix: Vec<String>,
+    ) -> Result<JoinedSchema> {
+        let table_desc = JoinedTableDesc::from_table(table, source_table, prefix);
         Self::from_table_desc(table_desc)
     }
 
@@ -78,6 +87,7 @@ impl JoinedSchema {
         Ok(JoinedSchema {
             short_name_columns,
             tables_long_name_columns: vec![table_desc],
+            relation: JoinedRelationDesc::Table(0),
         })
     }
 
@@ -100,6 +110,18 @@ impl JoinedSchema {
         self.tables_long_name_columns
     }
 
+    pub fn get_relation(&self) -> &JoinedRelationDesc {
+        &self.relation
+    }
+
+    pub fn get_relation_mut(&mut self) -> &mut JoinedRelationDesc {
+        &mut self.relation
+    }
+
+    pub fn take_tables_and_relation(self) -> (Vec<JoinedTableDesc>, JoinedRelationDesc) {
+        (self.tables_long_name_columns, self.relation)
+    }
+
     pub fn to_data_schema(&self) -> DataSchemaRef {
         let mut fields = Vec::with_capacity(self.short_name_columns.len());
 
@@ -129,8 +151,118 @@ impl JoinedSchema {
         Arc::new(DataSchema::new(fields))
     }
 
-    pub fn join(&self, _joined_schema: &JoinedSchema) -> Result<Arc<JoinedSchema>> {
-        unimplemented!("")
+    /// Join two schemas, the tables of `right` are placed after the tables of `self`.
+    pub fn join(
+        &self,
+        right: &JoinedSchema,
+        join_type: JoinType,
+        constraint: JoinedConstraint,
+    ) -> Result<JoinedSchema> {
+        let mut tables_desc = Vec::with_capacity(
+            self.tables_long_name_columns.len() + right.tables_long_name_columns.len(),
+        );
+
+        for table_desc in &self.tables_long_name_columns {
+            let mut table_desc = table_desc.clone();
+            if join_type.preserves_right() {
+                table_desc.set_columns_nullable();
+            }
+
+            tables_desc.push(table_desc);
+        }
+
+        for table_desc in &right.tables_long_name_columns {
+            let mut table_desc = table_desc.clone();
+            if join_type.preserves_left() {
+                table_desc.set_columns_nullable();
+            }
+
+            tables_desc.push(table_desc);
+        }
+
+        for (index, table_desc) in tables_desc.iter().enumerate() {
+            let name_parts = table_desc.get_name_parts();
+            let same_name = |desc: &JoinedTableDesc| desc.get_name_parts() == name_parts;
+            if !name_parts.is_empty() && tables_desc[index + 1..].iter().any(same_name) {
+                return Err(ErrorCode::SyntaxException(format!(
+                    "Not unique table/alias: '{}'",
+                    name_parts.join(".")
+                )));
+            }
+        }
+
+        // The short name is ambiguous if it appears in more than one table.
+        let mut short_names_count = HashMap::new();
+        for table_desc in &tables_desc {
+            for column_desc in table_desc.get_columns_desc() {
+                *short_names_count
+                    .entry(column_desc.short_name.clone())
+                    .or_insert(0_usize) += 1;
+            }
+        }
+
+        let mut short_name_columns = HashMap::new();
+        for table_desc in tables_desc.iter_mut() {
+            for column_desc in table_desc.get_columns_desc_mut() {
+                column_desc.is_ambiguity = short_names_count[&column_desc.short_name] > 1;
+
+                if !column_desc.is_ambiguity {
+                    short_name_columns.insert(column_desc.short_name.clone(), column_desc.clone());
+                }
+            }
+        }
+
+        let offset = self.tables_long_name_columns.len();
+        Ok(JoinedSchema {
+            short_name_columns,
+            tables_long_name_columns: tables_desc,
+            relation: JoinedRelationDesc::Join(Box::new(JoinedJoinDesc {
+                join_type,
+                left: self.relation.clone(),
+                right: right.relation.with_offset(offset),
+                constraint,
+                condition: None,
+            })),
+        })
+    }
+
+    /// Returns the column name in the schema of the column `name` in the table at `table_pos`.
+    pub fn get_column_name(&self, table_pos: usize, name: &str) -> Result<String> {
+        let table_desc = &self.tables_long_name_columns[table_pos];
+        for column_desc in table_desc.get_columns_desc() {
+            if column_desc.short_name == name {
+                return Ok(table_desc.get_column_name(column_desc));
+            }
+        }
+
+        Err(ErrorCode::UnknownColumn(format!(
+            "Unknown column: {}.{}",
+            table_desc.get_name_parts().join("."),
+            name
+        )))
+    }
+
+    /// Find the position of the table that contains the column in the given tables.
+    pub fn find_table_of_column(&self, tables_pos: &[usize], name: &str) -> Result<usize> {
+        let mut found = None;
+        for table_pos in tables_pos {
+            let table_desc = &self.tables_long_name_columns[*table_pos];
+            let columns_desc = table_desc.get_columns_desc();
+            if columns_desc.iter().any(|desc| desc.short_name == name) {
+                if found.is_some() {
+                    return Err(ErrorCode::SyntaxException(format!(
+                        "Column '{}' in USING clause is ambiguous",
+                        name
+                    )));
+                }
+
+                found = Some(*table_pos);
+            }
+        }
+
+        found.ok_or_else(|| {
+            ErrorCode::UnknownColumn(format!("Unknown column '{}' in USING clause", name))
+        })
     }
 }
 
@@ -170,6 +302,8 @@ impl Debug for JoinedSchema {
 pub enum JoinedTableDesc {
     Table {
         table: Arc<dyn Table>,
+        // The database and the name of the table, None for a table function.
+        source_table: Option<(String, String)>,
         name_parts: Vec<String>,
         columns_desc: Vec<JoinedColumnDesc>,
         push_downs: Option<Extras>,
@@ -182,7 +316,11 @@ pub enum JoinedTableDesc {
 }
 
 impl JoinedTableDesc {
-    pub fn from_table(table: Arc<dyn Table>, prefix: Vec<String>) -> JoinedTableDesc {
+    pub fn from_table(
+        table: Arc<dyn Table>,
+        source_table: Option<(String, String)>,
+        prefix: Vec<String>,
+    ) -> JoinedTableDesc {
         let schema = table.schema();
         let mut columns_desc = Vec::with_capacity(schema.fields().len());
 
@@ -192,6 +330,7 @@ impl JoinedTableDesc {
 
         JoinedTableDesc::Table {
             table,
+            source_table,
             columns_desc,
             name_parts: prefix,
             push_downs: None,
@@ -226,6 +365,31 @@ impl JoinedTableDesc {
             JoinedTableDesc::Subquery { columns_desc, .. } => columns_desc,
         }
     }
+
+    fn get_columns_desc_mut(&mut self) -> &mut [JoinedColumnDesc] {
+        match self {
+            JoinedTableDesc::Table { columns_desc, .. } => columns_desc,
+            JoinedTableDesc::Subquery { columns_desc, .. } => columns_desc,
+        }
+    }
+
+    fn set_columns_nullable(&mut self) {
+        for column_desc in self.get_columns_desc_mut() {
+            column_desc.nullable = true;
+        }
+    }
+
+    /// The column name in the joined schema, it's the full name if the short name is ambiguous.
+    pub fn get_column_name(&self, column_desc: &JoinedColumnDesc) -> String {
+        match column_desc.is_ambiguity {
+            true => format!(
+                "{}.{}",
+                self.get_name_parts().join("."),
+                column_desc.short_name
+            ),
+            false => column_desc.short_name.clone(),
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -255,3 +419,53 @@ impl JoinedColumnDesc {
         }
     }
 }
+
+#[derive(Clone)]
+pub enum JoinedRelationDesc {
+    Table(usize),
+    Join(Box<JoinedJoinDesc>),
+}
+
+impl JoinedRelationDesc {
+    fn with_offset(&self, offset: usize) -> JoinedRelationDesc {
+        match self {
+            JoinedRelationDesc::Table(pos) => JoinedRelationDesc::Table(pos + offset),
+            JoinedRelationDesc::Join(join) => JoinedRelationDesc::Join(Box::new(JoinedJoinDesc {
+                join_type: join.join_type,
+                left: join.left.with_offset(offset),
+                right: join.right.with_offset(offset),
+                constraint: join.constraint.clone(),
+                condition: join.condition.clone(),
+            })),
+        }
+    }
+
+    /// The positions of all tables under this relation.
+    pub fn tables_pos(&self) -> Vec<usize> {
+        match self {
+            JoinedRelationDesc::Table(pos) => vec![*pos],
+            JoinedRelationDesc::Join(join) => {
+                let mut tables_pos = join.left.tables_pos();
+                tables_pos.extend(join.right.tables_pos());
+                tables_pos
+            }
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct JoinedJoinDesc {
+    pub join_type: JoinType,
+    pub left: JoinedRelationDesc,
+    pub right: JoinedRelationDesc,
+    pub constraint: JoinedConstraint,
+    // The join condition resolved with the whole joined schema.
+    pub condition: Option<Expression>,
+}
+
+#[derive(Clone)]
+pub enum JoinedConstraint {
+    None,
+    On(Expression),
+    Using(Vec<String>),
+}
@@ -12,12 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_planners::Expression;
+use common_planners::JoinType;
 use sqlparser::ast::FunctionArg;
 use sqlparser::ast::Ident;
+use sqlparser::ast::JoinConstraint;
 use sqlparser::ast::JoinOperator;
 use sqlparser::ast::ObjectName;
 use sqlparser::ast::Query;
@@ -28,10 +32,18 @@ use sqlparser::ast::TableWithJoins;
 use crate::catalogs::Catalog;
 use crate::sessions::QueryContext;
 use crate::sql::statements::analyzer_expr::ExpressionAnalyzer;
+use crate::sql::statements::query::query_schema_joined::JoinedConstraint;
+use crate::sql::statements::query::query_schema_joined::JoinedRelationDesc;
 use crate::sql::statements::query::query_schema_joined::JoinedSchema;
+use crate::sql::statements::query::QualifiedRewriter;
 use crate::sql::statements::AnalyzableStatement;
 use crate::sql::statements::AnalyzedResult;
 use crate::sql::statements::DfQueryStatement;
+use crate::sql::DfParser;
+use crate::sql::DfStatement;
+use crate::storages::view::QUERY;
+use crate::storages::view::VIEW_ENGINE;
+use crate::storages::Table;
 
 pub struct JoinedSchemaAnalyzer {
     ctx: Arc<QueryContext>,
@@ -49,8 +61,18 @@ impl JoinedSchemaAnalyzer {
         let rpn = RelationRPNBuilder::build(&query.from)?;
         for rpn_item in &rpn {
             match rpn_item {
-                RelationRPNItem::Join(_) => {
-                    return Err(ErrorCode::UnImplement("Unimplemented SELECT JOIN yet."));
+                RelationRPNItem::Join(join_operator) => {
+                    if analyzed_tables.len() < 2 {
+                        return Err(ErrorCode::LogicalError(
+                            "Logical error: this is relation rpn bug.",
+                        ));
+                    }
+
+                    let right = analyzed_tables.pop().unwrap();
+                    let left = analyzed_tables.pop().unwrap();
+                    let (join_type, constraint) =
+                        self.join_operator(join_operator, &left, &right).await?;
+                    analyzed_tables.push(left.join(&right, join_type, constraint)?);
                 }
                 RelationRPNItem::Table(v) => {
                     let schema = self.table(v);
@@ -73,20 +95,137 @@ impl JoinedSchemaAnalyzer {
             ));
         }
 
-        Ok(analyzed_tables.remove(0))
+        let mut joined_schema = analyzed_tables.remove(0);
+        let mut relation = joined_schema.get_relation().clone();
+        self.resolve_join_condition(&joined_schema, &mut relation)?;
+        *joined_schema.get_relation_mut() = relation;
+        Ok(joined_schema)
+    }
+
+    async fn join_operator(
+        &self,
+        join_operator: &JoinOperator,
+        left: &JoinedSchema,
+        right: &JoinedSchema,
+    ) -> Result<(JoinType, JoinedConstraint)> {
+        let (join_type, constraint) = match join_operator {
+            JoinOperator::CrossJoin => return Ok((JoinType::Cross, JoinedConstraint::None)),
+            JoinOperator::Inner(JoinConstraint::None) => {
+                return Ok((JoinType::Cross, JoinedConstraint::None));
+            }
+            JoinOperator::Inner(constraint) => (JoinType::Inner, constraint),
+            JoinOperator::LeftOuter(constraint) => (JoinType::Left, constraint),
+            JoinOperator::RightOuter(constraint) => (JoinType::Right, constraint),
+            JoinOperator::FullOuter(constraint) => (JoinType::Full, constraint),
+            _ => {
+                return Err(ErrorCode::UnImplement(format!(
+                    "Unsupported join operator {:?}",
+                    join_operator
+                )));
+            }
+        };
+
+        match constraint {
+            JoinConstraint::On(expr) => {
+                let analyzer = ExpressionAnalyzer::create(self.ctx.clone());
+                let expr = analyzer.analyze(expr).await?;
+                Ok((join_type, JoinedConstraint::On(expr)))
+            }
+            JoinConstraint::Using(idents) => {
+                let columns = idents.iter().map(|ident| ident.value.clone()).collect();
+                Ok((join_type, JoinedConstraint::Using(columns)))
+            }
+            JoinConstraint::Natural => {
+                let columns = Self::natural_join_columns(left, right);
+                Ok((join_type, JoinedConstraint::Using(columns)))
+            }
+            JoinConstraint::None => Err(ErrorCode::SyntaxException(format!(
+                "{} JOIN requires a join condition",
+                join_type
+            ))),
+        }
+    }
+
+    fn natural_join_columns(left: &JoinedSchema, right: &JoinedSchema) -> Vec<String> {
+        let mut right_columns = HashSet::new();
+        for table_desc in right.get_tables_desc() {
+            for column_desc in table_desc.get_columns_desc() {
+                right_columns.insert(column_desc.short_name.clone());
+            }
+        }
+
+        let mut columns = Vec::new();
+        for table_desc in left.get_tables_desc() {
+            for column_desc in table_desc.get_columns_desc() {
+                let name = &column_desc.short_name;
+                if right_columns.contains(name) && !columns.contains(name) {
+                    columns.push(name.clone());
+                }
+            }
+        }
+
+        columns
+    }
+
+    // The join conditions can only be resolved after all tables are joined,
+    // because the ambiguity of the column names depends on the whole schema.
+    fn resolve_join_condition(
+        &self,
+        schema: &JoinedSchema,
+        relation: &mut JoinedRelationDesc,
+    ) -> Result<()> {
+        if let JoinedRelationDesc::Join(join) = relation {
+            self.resolve_join_condition(schema, &mut join.left)?;
+            self.resolve_join_condition(schema, &mut join.right)?;
+
+            join.condition = match &join.constraint {
+                JoinedConstraint::None => None,
+                JoinedConstraint::On(expr) => {
+                    let ctx = self.ctx.clone();
+                    Some(QualifiedRewriter::rewrite_expr(schema, ctx, expr)?)
+                }
+                JoinedConstraint::Using(columns) => {
+                    let left_tables = join.left.tables_pos();
+                    let right_tables = join.right.tables_pos();
+
+                    let mut condition: Option<Expression> = None;
+                    for column in columns {
+                        let left_pos = schema.find_table_of_column(&left_tables, column)?;
+                        let right_pos = schema.find_table_of_column(&right_tables, column)?;
+                        let left_column = schema.get_column_name(left_pos, column)?;
+                        let right_column = schema.get_column_name(right_pos, column)?;
+                        let equal =
+                            Expression::Column(left_column).eq(Expression::Column(right_column));
+
+                        condition = Some(match condition {
+                            None => equal,
+                            Some(condition) => condition.and(equal),
+                        });
+                    }
+
+                    condition
+                }
+            };
+        }
+
+        Ok(())
     }
 
     async fn subquery(&self, v: &DerivedRPNItem) -> Result<JoinedSchema> {
         let subquery = &(*v.subquery);
         let subquery = DfQueryStatement::try_from(subquery.clone())?;
-        match subquery.analyze(self.ctx.clone()).await? {
-            AnalyzedResult::SelectQuery(state) => match &v.alias {
-                None => JoinedSchema::from_subquery(state, Vec::new()),
-                Some(alias) => {
-                    let name_prefix = vec![alias.name.value.clone()];
-                    JoinedSchema::from_subquery(state, name_prefix)
-                }
-            },
+        match &v.alias {
+            None => self.derived(&subquery, Vec::new()).await,
+            Some(alias) => {
+                let name_prefix = vec![alias.name.value.clone()];
+                self.derived(&subquery, name_prefix).await
+            }
+        }
+    }
+
+    async fn derived(&self, query: &DfQueryStatement, prefix: Vec<String>) -> Result<JoinedSchema> {
+        match query.analyze(self.ctx.clone()).await? {
+            AnalyzedResult::SelectQuery(state) => JoinedSchema::from_subquery(state, prefix),
             _ => Err(ErrorCode::LogicalError(
                 "Logical error, subquery analyzed data must be SelectQuery, it's a bug.",
             )),
@@ -98,15 +237,36 @@ impl JoinedSchemaAnalyzer {
         let (database, table) = self.resolve_table(&item.name)?;
         let read_table = self.ctx.get_table(&database, &table).await?;
 
-        match &item.alias {
-            None => {
-                let name_prefix = vec![database, table];
-                JoinedSchema::from_table(read_table, name_prefix)
-            }
-            Some(table_alias) => {
-                let name_prefix = vec![table_alias.name.value.clone()];
-                JoinedSchema::from_table(read_table, name_prefix)
+        let name_prefix = match &item.alias {
+            None => vec![database.clone(), table.clone()],
+            Some(table_alias) => vec![table_alias.name.value.clone()],
+        };
+
+        match read_table.engine().eq_ignore_ascii_case(VIEW_ENGINE) {
+            true => {
+                // The views are expanded recursively, a view must not refer to itself.
+                self.ctx.begin_expand_view(&database, &table)?;
+                let schema = self.view(read_table, name_prefix).await;
+                self.ctx.end_expand_view();
+                schema
             }
+            false => JoinedSchema::from_table(read_table, Some((database, table)), name_prefix),
+        }
+    }
+
+    // The view is expanded as a derived table of its defining query.
+    async fn view(&self, view: Arc<dyn Table>, prefix: Vec<String>) -> Result<JoinedSchema> {
+        let query = view.options().get(QUERY).ok_or_else(|| {
+            ErrorCode::LogicalError(format!("Need `{}` option for view {}", QUERY, view.name()))
+        })?;
+
+        let (mut statements, _) = DfParser::parse_sql(query)?;
+        match (statements.len(), statements.pop()) {
+            (1, Some(DfStatement::Query(query))) => self.derived(&query, prefix).await,
+            _ => Err(ErrorCode::LogicalError(format!(
+                "Logical error: the query of view {} must be a SELECT statement.",
+                view.name()
+            ))),
         }
     }
 
@@ -131,10 +291,10 @@ impl JoinedSchemaAnalyzer {
         let catalog = self.ctx.get_catalog();
         let table_function = catalog.get_table_function(&table_name, Some(table_args))?;
         match &item.alias {
-            None => JoinedSchema::from_table(table_function.as_table(), Vec::new()),
+            None => JoinedSchema::from_table(table_function.as_table(), None, Vec::new()),
             Some(table_alias) => {
                 let name_prefix = vec![table_alias.name.value.clone()];
-                JoinedSchema::from_table(table_function.as_table(), name_prefix)
+                JoinedSchema::from_table(table_function.as_table(), None, name_prefix)
             }
         }
     }
@@ -0,0 +1,110 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::AlterTableOperation;
+use common_planners::AlterTablePlan;
+use common_planners::PlanNode;
+use common_tracing::tracing;
+use sqlparser::ast::ColumnDef;
+use sqlparser::ast::Ident;
+use sqlparser::ast::ObjectName;
+
+use crate::sessions::QueryContext;
+use crate::sql::statements::analyzer_expr::ExpressionAnalyzer;
+use crate::sql::statements::AnalyzableStatement;
+use crate::sql::statements::AnalyzedResult;
+use crate::sql::statements::DfCreateTable;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DfAlterTableAction {
+    AddColumn(ColumnDef),
+    DropColumn(Ident),
+    RenameColumn {
+        old_column: Ident,
+        new_column: Ident,
+    },
+    RenameTable(ObjectName),
+    SetOptions(HashMap<String, String>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfAlterTable {
+    pub name: ObjectName,
+    pub action: DfAlterTableAction,
+}
+
+#[async_trait::async_trait]
+impl AnalyzableStatement for DfAlterTable {
+    #[tracing::instrument(level = "info", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
+    async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
+        let (db, table) = Self::resolve_table(ctx.clone(), &self.name)?;
+
+        let operation = match &self.action {
+            DfAlterTableAction::AddColumn(column) => {
+                let expr_analyzer = ExpressionAnalyzer::create(ctx);
+                let field = DfCreateTable::column_field(&expr_analyzer, column).await?;
+                AlterTableOperation::AddColumn { field }
+            }
+            DfAlterTableAction::DropColumn(column) => AlterTableOperation::DropColumn {
+                column: column.value.clone(),
+            },
+            DfAlterTableAction::RenameColumn {
+                old_column,
+                new_column,
+            } => AlterTableOperation::RenameColumn {
+                old_column: old_column.value.clone(),
+                new_column: new_column.value.clone(),
+            },
+            DfAlterTableAction::RenameTable(new_name) => {
+                let (new_db, new_table) = Self::resolve_table(ctx, new_name)?;
+                if new_db != db {
+                    return Err(ErrorCode::SyntaxException(
+                        "Rename table to another database is not supported",
+                    ));
+                }
+                AlterTableOperation::RenameTable { new_table }
+            }
+            DfAlterTableAction::SetOptions(options) => AlterTableOperation::SetOptions {
+                options: options.clone(),
+            },
+        };
+
+        Ok(AnalyzedResult::SimpleQuery(Box::new(PlanNode::AlterTable(
+            AlterTablePlan {
+                db,
+                table,
+                operation,
+            },
+        ))))
+    }
+}
+
+impl DfAlterTable {
+    fn resolve_table(ctx: Arc<QueryContext>, table_name: &ObjectName) -> Result<(String, String)> {
+        let idents = &table_name.0;
+        match idents.len() {
+            0 => Err(ErrorCode::SyntaxException("Alter table name is empty")),
+            1 => Ok((ctx.get_current_database(), idents[0].value.clone())),
+            2 => Ok((idents[0].value.clone(), idents[1].value.clone())),
+            _ => Err(ErrorCode::SyntaxException(
+                "Alter table name must be [`db`].`table`",
+            )),
+        }
+    }
+}
@@ -36,6 +36,7 @@ use crate::sql::statements::DfQueryStatement;
 use crate::sql::DfStatement;
 use crate::sql::PlanParser;
 use crate::sql::SQLCommon;
+use crate::storages::view::VIEW_ENGINE;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct DfCreateTable {
@@ -105,6 +106,12 @@ impl DfCreateTable {
 
     async fn table_meta(&self, ctx: Arc<QueryContext>) -> Result<TableMeta> {
         let engine = self.engine.clone();
+        if engine.eq_ignore_ascii_case(VIEW_ENGINE) {
+            return Err(ErrorCode::BadOption(
+                "Can not create table with VIEW engine, please use CREATE VIEW",
+            ));
+        }
+
         let schema = self.table_schema(ctx).await?;
         Ok(TableMeta {
             schema,
@@ -131,28 +138,33 @@ impl DfCreateTable {
                 let mut fields = Vec::with_capacity(self.columns.len());
 
                 for column in &self.columns {
-                    let mut nullable = true;
-                    let mut default_expr = None;
-                    for opt in &column.options {
-                        match &opt.option {
-                            ColumnOption::NotNull => {
-                                nullable = false;
-                            }
-                            ColumnOption::Default(expr) => {
-                                let expr = expr_analyzer.analyze(expr).await?;
-                                default_expr = Some(serde_json::to_vec(&expr)?);
-                            }
-                            _ => {}
-                        }
-                    }
-                    let field = SQLCommon::make_data_type(&column.data_type).map(|data_type| {
-                        DataField::new(&column.name.value, data_type, nullable)
-                            .with_default_expr(default_expr)
-                    })?;
-                    fields.push(field);
+                    fields.push(Self::column_field(&expr_analyzer, column).await?);
                 }
                 Ok(DataSchemaRefExt::create(fields))
             }
         }
     }
+
+    pub(crate) async fn column_field(
+        expr_analyzer: &ExpressionAnalyzer,
+        column: &ColumnDef,
+    ) -> Result<DataField> {
+        let mut nullable = true;
+        let mut default_expr = None;
+        for opt in &column.options {
+            match &opt.option {
+                ColumnOption::NotNull => {
+                    nullable = false;
+                }
+                ColumnOption::Default(expr) => {
+                    let expr = expr_analyzer.analyze(expr).await?;
+                    default_expr = Some(serde_json::to_vec(&expr)?);
+                }
+                _ => {}
+            }
+        }
+        SQLCommon::make_data_type(&column.data_type).map(|data_type| {
+            DataField::new(&column.name.value, data_type, nullable).with_default_expr(default_expr)
+        })
+    }
 }
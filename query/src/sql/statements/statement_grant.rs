@@ -16,6 +16,7 @@ use std::sync::Arc;
 
 use common_exception::Result;
 use common_meta_types::GrantObject;
+use common_meta_types::PrincipalIdentity;
 use common_meta_types::UserPrivilegeSet;
 use common_planners::GrantPrivilegePlan;
 use common_planners::PlanNode;
@@ -27,8 +28,7 @@ use crate::sql::statements::AnalyzedResult;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct DfGrantStatement {
-    pub name: String,
-    pub hostname: String,
+    pub principal: PrincipalIdentity,
     pub priv_types: UserPrivilegeSet,
     pub on: DfGrantObject,
 }
@@ -66,8 +66,7 @@ impl AnalyzableStatement for DfGrantStatement {
     async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
         Ok(AnalyzedResult::SimpleQuery(Box::new(
             PlanNode::GrantPrivilege(GrantPrivilegePlan {
-                name: self.name.clone(),
-                hostname: self.hostname.clone(),
+                principal: self.principal.clone(),
                 on: self.on.convert_to_grant_object(ctx),
                 priv_types: self.priv_types,
             }),
@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use common_exception::Result;
+use common_meta_types::PrincipalIdentity;
 use common_meta_types::UserPrivilegeSet;
 use common_planners::PlanNode;
 use common_planners::RevokePrivilegePlan;
@@ -27,8 +28,7 @@ use crate::sql::statements::DfGrantObject;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct DfRevokeStatement {
-    pub username: String,
-    pub hostname: String,
+    pub principal: PrincipalIdentity,
     pub priv_types: UserPrivilegeSet,
     pub on: DfGrantObject,
 }
@@ -39,8 +39,7 @@ impl AnalyzableStatement for DfRevokeStatement {
     async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
         Ok(AnalyzedResult::SimpleQuery(Box::new(
             PlanNode::RevokePrivilege(RevokePrivilegePlan {
-                username: self.username.clone(),
-                hostname: self.hostname.clone(),
+                principal: self.principal.clone(),
                 on: self.on.convert_to_grant_object(ctx),
                 priv_types: self.priv_types,
             }),
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use common_datablocks::DataBlock;
@@ -21,7 +22,9 @@ use common_exception::Result;
 use common_planners::expand_aggregate_arg_exprs;
 use common_planners::find_aggregate_exprs;
 use common_planners::find_aggregate_exprs_in_expr;
+use common_planners::find_window_exprs_in_expr;
 use common_planners::rebase_expr;
+use common_planners::sort_to_inner_expr;
 use common_planners::Expression;
 use common_tracing::tracing;
 use sqlparser::ast::Expr;
@@ -32,6 +35,7 @@ use sqlparser::ast::TableWithJoins;
 
 use crate::sessions::QueryContext;
 use crate::sql::statements::analyzer_statement::QueryAnalyzeState;
+use crate::sql::statements::query::JoinedRelationDesc;
 use crate::sql::statements::query::JoinedSchema;
 use crate::sql::statements::query::JoinedSchemaAnalyzer;
 use crate::sql::statements::query::JoinedTableDesc;
@@ -41,6 +45,8 @@ use crate::sql::statements::query::QueryCollectPushDowns;
 use crate::sql::statements::query::QueryNormalizer;
 use crate::sql::statements::AnalyzableStatement;
 use crate::sql::statements::AnalyzedResult;
+use crate::sql::statements::JoinRelation;
+use crate::sql::statements::JoinRelationInput;
 use crate::sql::statements::QueryRelation;
 use crate::storages::ToReadDataSourcePlan;
 
@@ -124,7 +130,28 @@ impl DfQueryStatement {
             }
         }
 
+        let mut window_expressions = ir.window_expressions.clone();
+        if !window_expressions.is_empty() {
+            if ir.having_predicate.is_some() {
+                return Err(ErrorCode::UnImplement(
+                    "HAVING with window functions is unsupported",
+                ));
+            }
+
+            // Rebase expressions using window expressions
+            let mut expressions = Vec::with_capacity(analyze_state.expressions.len());
+            for expression in &analyze_state.expressions {
+                expressions.push(rebase_expr(expression, &window_expressions)?);
+            }
+
+            analyze_state.expressions = expressions;
+        }
+
         if !ir.aggregate_expressions.is_empty() || !ir.group_by_expressions.is_empty() {
+            for aggregate_expression in &ir.aggregate_expressions {
+                Self::verify_no_window(aggregate_expression, "Aggregate function")?;
+            }
+
             // Rebase expressions using aggregate expressions and group by expressions
             let mut expressions = Vec::with_capacity(analyze_state.expressions.len());
             for expression in &analyze_state.expressions {
@@ -134,6 +161,14 @@ impl DfQueryStatement {
 
             analyze_state.expressions = expressions;
 
+            let mut expressions = Vec::with_capacity(window_expressions.len());
+            for expression in &window_expressions {
+                let expression = rebase_expr(expression, &ir.aggregate_expressions)?;
+                expressions.push(rebase_expr(&expression, &ir.group_by_expressions)?);
+            }
+
+            window_expressions = expressions;
+
             for group_expression in &ir.group_by_expressions {
                 analyze_state.add_before_group_expression(group_expression);
                 let base_exprs = &analyze_state.before_group_by_expressions;
@@ -145,9 +180,44 @@ impl DfQueryStatement {
             Self::analyze_aggregate(&ir.aggregate_expressions, &mut analyze_state)?;
         }
 
+        Self::analyze_window(&window_expressions, &mut analyze_state)?;
         Ok(analyze_state)
     }
 
+    fn analyze_window(exprs: &[Expression], state: &mut QueryAnalyzeState) -> Result<()> {
+        for window_expression in exprs {
+            if let Expression::WindowFunction {
+                args,
+                partition_by,
+                order_by,
+                ..
+            } = window_expression
+            {
+                // The constant arguments(such as the offset of lag) are kept in the window function
+                let args = args
+                    .iter()
+                    .filter(|arg| !matches!(arg, Expression::Literal { .. }));
+
+                for expr in args.chain(partition_by) {
+                    state.add_before_window_expression(expr);
+                }
+
+                for expr in order_by {
+                    state.add_before_window_expression(&sort_to_inner_expr(expr));
+                }
+            }
+        }
+
+        for window_expression in exprs {
+            let base_exprs = &state.before_window_expressions;
+            state
+                .window_expressions
+                .push(rebase_expr(window_expression, base_exprs)?);
+        }
+
+        Ok(())
+    }
+
     fn analyze_aggregate(exprs: &[Expression], state: &mut QueryAnalyzeState) -> Result<()> {
         let aggregate_functions = find_aggregate_exprs(exprs);
         let aggregate_functions_args = expand_aggregate_arg_exprs(&aggregate_functions);
@@ -180,6 +250,16 @@ impl DfQueryStatement {
         Ok(())
     }
 
+    fn verify_no_window(expr: &Expression, info: &str) -> Result<()> {
+        match find_window_exprs_in_expr(expr).is_empty() {
+            true => Ok(()),
+            false => Err(ErrorCode::SyntaxException(format!(
+                "{} cannot contain window functions",
+                info
+            ))),
+        }
+    }
+
     fn verify_no_aggregate(expr: &Expression, info: &str) -> Result<()> {
         match find_aggregate_exprs_in_expr(expr).is_empty() {
             true => Ok(()),
@@ -201,29 +281,82 @@ impl DfQueryStatement {
         let dry_run_res = Self::verify_with_dry_run(&schema, &state)?;
         state.finalize_schema = dry_run_res.schema().clone();
 
-        let mut tables_desc = schema.take_tables_desc();
+        let (tables_desc, relation_desc) = schema.take_tables_and_relation();
 
-        if tables_desc.len() != 1 {
-            return Err(ErrorCode::UnImplement("Select join unimplemented yet."));
+        let mut tables_relation = Vec::with_capacity(tables_desc.len());
+        for table_desc in &tables_desc {
+            tables_relation.push(Some(Self::table_relation(table_desc, &ctx).await?));
         }
 
-        match tables_desc.remove(0) {
+        state.relation = match tables_desc.len() {
+            1 => tables_relation.remove(0).unwrap(),
+            _ => Self::join_relation(&tables_desc, &mut tables_relation, &relation_desc)?.relation,
+        };
+
+        Ok(AnalyzedResult::SelectQuery(Box::new(state)))
+    }
+
+    async fn table_relation(
+        table_desc: &JoinedTableDesc,
+        ctx: &Arc<QueryContext>,
+    ) -> Result<QueryRelation> {
+        match table_desc {
             JoinedTableDesc::Table {
-                table, push_downs, ..
+                table,
+                source_table,
+                push_downs,
+                ..
             } => {
-                let source_plan = table.read_plan(ctx.clone(), push_downs).await?;
-                state.relation = QueryRelation::FromTable(Box::new(source_plan));
+                let mut source_plan = table.read_plan(ctx.clone(), push_downs.clone()).await?;
+                source_plan.source_table = source_table.clone();
+                Ok(QueryRelation::FromTable(Box::new(source_plan)))
             }
             JoinedTableDesc::Subquery {
                 state: subquery_state,
                 ..
             } => {
                 // TODO: maybe need reanalyze subquery.
-                state.relation = QueryRelation::Nested(subquery_state);
+                Ok(QueryRelation::Nested(subquery_state.clone()))
             }
         }
+    }
 
-        Ok(AnalyzedResult::SelectQuery(Box::new(state)))
+    fn join_relation(
+        tables_desc: &[JoinedTableDesc],
+        tables_relation: &mut Vec<Option<QueryRelation>>,
+        relation_desc: &JoinedRelationDesc,
+    ) -> Result<JoinRelationInput> {
+        match relation_desc {
+            JoinedRelationDesc::Table(pos) => {
+                let table_desc = &tables_desc[*pos];
+                let mut aliases = HashMap::new();
+                for column_desc in table_desc.get_columns_desc() {
+                    let joined_name = table_desc.get_column_name(column_desc);
+                    aliases.insert(column_desc.short_name.clone(), joined_name);
+                }
+
+                match tables_relation[*pos].take() {
+                    Some(relation) => Ok(JoinRelationInput { relation, aliases }),
+                    None => Err(ErrorCode::LogicalError(
+                        "Logical error: table is joined more than once, it's a bug.",
+                    )),
+                }
+            }
+            JoinedRelationDesc::Join(join_desc) => {
+                let left = Self::join_relation(tables_desc, tables_relation, &join_desc.left)?;
+                let right = Self::join_relation(tables_desc, tables_relation, &join_desc.right)?;
+
+                Ok(JoinRelationInput {
+                    relation: QueryRelation::Join(Box::new(JoinRelation {
+                        join_type: join_desc.join_type,
+                        left,
+                        right,
+                        condition: join_desc.condition.clone(),
+                    })),
+                    aliases: HashMap::new(),
+                })
+            }
+        }
     }
 
     fn verify_with_dry_run(schema: &JoinedSchema, state: &QueryAnalyzeState) -> Result<DataBlock> {
@@ -268,6 +401,17 @@ impl DfQueryStatement {
             }
         }
 
+        if !state.window_expressions.is_empty() {
+            match Self::dry_run_window(state, &data_block) {
+                Ok(res) => {
+                    data_block = res;
+                }
+                Err(cause) => {
+                    return Err(cause.add_message_back(" (while in select window)"));
+                }
+            }
+        }
+
         if !state.expressions.is_empty() {
             match Self::dry_run_exprs(&state.expressions, &data_block) {
                 Ok(res) => {
@@ -329,6 +473,27 @@ impl DfQueryStatement {
         )))
     }
 
+    fn dry_run_window(state: &QueryAnalyzeState, data: &DataBlock) -> Result<DataBlock> {
+        // The window plans keep the input columns and append the results.
+        let mut data_fields = data.schema().fields().clone();
+        let exprs = state
+            .before_window_expressions
+            .iter()
+            .chain(&state.window_expressions);
+
+        for expr in exprs {
+            let schema = DataSchemaRefExt::create(data_fields.clone());
+            let data_field = expr.to_data_field(&schema)?;
+            if !data_fields.iter().any(|f| f.name() == data_field.name()) {
+                data_fields.push(data_field);
+            }
+        }
+
+        Ok(DataBlock::empty_with_schema(DataSchemaRefExt::create(
+            data_fields,
+        )))
+    }
+
     fn dry_run_exprs_ref(exprs: &[&Expression], data: &DataBlock) -> Result<DataBlock> {
         let schema = data.schema();
         let mut new_data_fields = Vec::with_capacity(exprs.len());
@@ -0,0 +1,108 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::Expression;
+use common_planners::PlanNode;
+use common_planners::UpdatePlan;
+use common_tracing::tracing;
+use sqlparser::ast::Expr;
+use sqlparser::ast::Ident;
+use sqlparser::ast::ObjectName;
+
+use crate::sessions::QueryContext;
+use crate::sql::statements::analyzer_expr::ExpressionAnalyzer;
+use crate::sql::statements::AnalyzableStatement;
+use crate::sql::statements::AnalyzedResult;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfUpdateStatement {
+    pub name: ObjectName,
+    pub assignments: Vec<(Ident, Expr)>,
+    pub selection: Option<Expr>,
+}
+
+#[async_trait::async_trait]
+impl AnalyzableStatement for DfUpdateStatement {
+    #[tracing::instrument(level = "info", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
+    async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
+        let (db, table) = self.resolve_table(ctx.clone())?;
+        let schema = ctx.get_table(&db, &table).await?.schema();
+        let expression_analyzer = ExpressionAnalyzer::create(ctx);
+
+        let mut assignments: Vec<(String, Expression)> = Vec::with_capacity(self.assignments.len());
+        for (column, value) in &self.assignments {
+            let name = column.value.clone();
+            if assignments.iter().any(|(assigned, _)| assigned == &name) {
+                return Err(ErrorCode::SyntaxException(format!(
+                    "Column {} is assigned more than once",
+                    name
+                )));
+            }
+
+            let field = schema.field_with_name(&name)?;
+            let expr = expression_analyzer.analyze(value).await?;
+            ExpressionAnalyzer::verify_no_array_join(&expr, "UPDATE")?;
+            let expr = if &expr.to_data_type(&schema)? != field.data_type() {
+                Expression::Cast {
+                    expr: Box::new(expr),
+                    data_type: field.data_type().clone(),
+                }
+            } else {
+                expr
+            };
+            assignments.push((name, expr));
+        }
+
+        let selection = match &self.selection {
+            None => None,
+            Some(expr) => {
+                let expression = expression_analyzer.analyze(expr).await?;
+                ExpressionAnalyzer::verify_no_array_join(&expression, "UPDATE")?;
+                // make sure the selection can be evaluated against the table
+                expression.to_data_field(&schema)?;
+                Some(expression)
+            }
+        };
+
+        Ok(AnalyzedResult::SimpleQuery(Box::new(PlanNode::Update(
+            UpdatePlan {
+                db,
+                table,
+                assignments,
+                selection,
+            },
+        ))))
+    }
+}
+
+impl DfUpdateStatement {
+    fn resolve_table(&self, ctx: Arc<QueryContext>) -> Result<(String, String)> {
+        let DfUpdateStatement {
+            name: ObjectName(idents),
+            ..
+        } = self;
+        match idents.len() {
+            0 => Err(ErrorCode::SyntaxException("Update table name is empty")),
+            1 => Ok((ctx.get_current_database(), idents[0].value.clone())),
+            2 => Ok((idents[0].value.clone(), idents[1].value.clone())),
+            _ => Err(ErrorCode::SyntaxException(
+                "Update table name must be [`db`].`table`",
+            )),
+        }
+    }
+}
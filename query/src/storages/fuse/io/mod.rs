@@ -19,6 +19,8 @@ mod readers;
 
 pub use block_stream_writer::BlockStreamWriter;
 pub use block_stream_writer::SegmentInfoStream;
+pub use block_writer::write_block;
+pub use locations::gen_block_location;
 pub use locations::gen_segment_info_location;
 pub use locations::snapshot_location;
 pub use readers::read_obj;
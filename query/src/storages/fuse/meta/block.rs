@@ -27,6 +27,9 @@ pub struct BlockMeta {
     pub block_size: u64,
     pub col_stats: HashMap<ColumnId, ColumnStatistics>,
     pub location: BlockLocation,
+    /// Size of the block file, 0 for the blocks written by older versions
+    #[serde(default)]
+    pub file_size: u64,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
@@ -14,6 +14,8 @@
 
 use std::collections::HashMap;
 
+use chrono::DateTime;
+use chrono::Utc;
 use common_base::uuid;
 use common_datavalues::DataSchema;
 use serde::Deserialize;
@@ -35,6 +37,10 @@ pub struct TableSnapshot {
 
     pub prev_snapshot_id: Option<SnapshotId>,
 
+    /// Creation time of snapshot, absent in snapshots written by older versions
+    #[serde(default)]
+    pub timestamp: Option<DateTime<Utc>>,
+
     /// For each snapshot, we keep a schema for it (in case of schema evolution)
     pub schema: DataSchema,
 
@@ -0,0 +1,174 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::MatchSeq;
+use common_meta_types::UpdateTableSchemaReq;
+use common_meta_types::UpsertTableOptionReq;
+use common_planners::AlterTableOperation;
+use common_planners::AlterTablePlan;
+use futures::FutureExt;
+
+use crate::sessions::QueryContext;
+use crate::storages::fuse::operations::mutation::BlockMutation;
+use crate::storages::fuse::FuseTable;
+use crate::storages::fuse::TBL_OPT_KEY_SNAPSHOT_LOC;
+
+impl FuseTable {
+    /// Columns can only be appended to the schema, since the blocks are read by column index:
+    /// - adding a column only changes the schema, the blocks written before are read with
+    ///   the default value of the column.
+    /// - renaming a column only changes the schema.
+    /// - dropping a column rewrites all the blocks, the new snapshot is committed along with
+    ///   the new schema. If it conflicts with a concurrent commit, the blocks are rewritten
+    ///   again on the new head snapshot.
+    #[inline]
+    pub async fn do_alter(&self, ctx: Arc<QueryContext>, alter_plan: AlterTablePlan) -> Result<()> {
+        let schema = self.table_info.schema();
+        let ident = &self.table_info.ident;
+        let catalog = ctx.get_catalog();
+
+        match alter_plan.operation {
+            AlterTableOperation::AddColumn { field } => {
+                Self::check_column_absent(&schema, field.name())?;
+                let mut fields = schema.fields().clone();
+                fields.push(field);
+                let new_schema = Arc::new(DataSchema::new_from(fields, schema.meta().clone()));
+                catalog
+                    .update_table_schema(UpdateTableSchemaReq::new(ident, new_schema))
+                    .await?;
+            }
+            AlterTableOperation::RenameColumn {
+                old_column,
+                new_column,
+            } => {
+                let idx = Self::column_index(&schema, &old_column)?;
+                Self::check_column_absent(&schema, &new_column)?;
+                let mut fields = schema.fields().clone();
+                let old_field = &fields[idx];
+                fields[idx] = DataField::new(
+                    &new_column,
+                    old_field.data_type().clone(),
+                    old_field.is_nullable(),
+                )
+                .with_default_expr(old_field.default_expr().clone());
+                let new_schema = Arc::new(DataSchema::new_from(fields, schema.meta().clone()));
+                catalog
+                    .update_table_schema(UpdateTableSchemaReq::new(ident, new_schema))
+                    .await?;
+            }
+            AlterTableOperation::DropColumn { column } => {
+                let idx = Self::column_index(&schema, &column)?;
+                if schema.fields().len() == 1 {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Can not drop column {}, the table {} has only one column",
+                        column,
+                        self.name()
+                    )));
+                }
+                let mut fields = schema.fields().clone();
+                fields.remove(idx);
+                let new_schema = Arc::new(DataSchema::new_from(fields, schema.meta().clone()));
+
+                let mutator = |block: &DataBlock| {
+                    let mut columns = block.columns().to_vec();
+                    columns.remove(idx);
+                    Ok(BlockMutation::Replaced(DataBlock::create(
+                        new_schema.clone(),
+                        columns,
+                    )))
+                };
+                let mutator = &mutator;
+                let new_schema = &new_schema;
+                self.commit_with_retry(ctx.as_ref(), |table| {
+                    let ctx = ctx.clone();
+                    async move {
+                        let mut written = vec![];
+                        let result = async {
+                            let snapshot_loc = table
+                                .write_mutated_snapshot(
+                                    ctx.as_ref(),
+                                    new_schema,
+                                    None,
+                                    mutator,
+                                    &mut written,
+                                )
+                                .await?;
+
+                            let ident = &table.table_info.ident;
+                            let mut req = UpdateTableSchemaReq::new(ident, new_schema.clone());
+                            if let Some(snapshot_loc) = snapshot_loc {
+                                req = req.with_option(TBL_OPT_KEY_SNAPSHOT_LOC, snapshot_loc);
+                            }
+                            ctx.get_catalog().update_table_schema(req).await?;
+                            Ok::<_, ErrorCode>(())
+                        }
+                        .await;
+                        (written, result)
+                    }
+                    .boxed()
+                })
+                .await?;
+            }
+            AlterTableOperation::SetOptions { options } => {
+                if options.contains_key(TBL_OPT_KEY_SNAPSHOT_LOC) {
+                    return Err(ErrorCode::BadOption(format!(
+                        "Option {} is maintained by the table engine and can not be set",
+                        TBL_OPT_KEY_SNAPSHOT_LOC
+                    )));
+                }
+                let options = options
+                    .into_iter()
+                    .map(|(k, v)| (k, Some(v)))
+                    .collect::<HashMap<_, _>>();
+                catalog
+                    .upsert_table_option(UpsertTableOptionReq {
+                        table_id: ident.table_id,
+                        seq: MatchSeq::Exact(ident.version),
+                        options,
+                    })
+                    .await?;
+            }
+            AlterTableOperation::RenameTable { .. } => {
+                return Err(ErrorCode::LogicalError(
+                    "Renaming a table should be done by the catalog",
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    fn column_index(schema: &DataSchema, column: &str) -> Result<usize> {
+        schema
+            .column_with_name(column)
+            .map(|(idx, _)| idx)
+            .ok_or_else(|| ErrorCode::UnknownColumn(format!("Unknown column {}", column)))
+    }
+
+    fn check_column_absent(schema: &DataSchema, column: &str) -> Result<()> {
+        if schema.has_field(column) {
+            return Err(ErrorCode::BadArguments(format!(
+                "Column {} already exists",
+                column
+            )));
+        }
+        Ok(())
+    }
+}
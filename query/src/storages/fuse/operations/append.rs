@@ -49,6 +49,16 @@ impl FuseTable {
 
         let da = ctx.get_data_accessor()?;
 
+        let storage_quota = Self::storage_quota(ctx.as_ref());
+        let mut storage_usage = match storage_quota {
+            0 => 0,
+            _ => self
+                .table_snapshot(ctx.as_ref())
+                .await?
+                .map(|snapshot| snapshot.summary.compressed_byte_size)
+                .unwrap_or(0),
+        };
+
         let mut segment_stream = BlockStreamWriter::write_block_stream(
             da.clone(),
             stream,
@@ -62,6 +72,8 @@ impl FuseTable {
             while let Some(segment) = segment_stream.next().await {
                 let log_entry_res = match segment {
                     Ok(seg) => {
+                        storage_usage += seg.summary.compressed_byte_size;
+                        Self::check_storage_quota(storage_quota, storage_usage)?;
                         let seg_loc = io::gen_segment_info_location();
                         let bytes = serde_json::to_vec(&seg)?;
                         da.put(&seg_loc, bytes).await?;
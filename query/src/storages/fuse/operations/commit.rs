@@ -14,13 +14,19 @@
 //
 
 use std::sync::Arc;
+use std::time::Duration;
 
+use chrono::Utc;
+use common_base::tokio;
 use common_datavalues::DataSchema;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_types::TableIdent;
 use common_meta_types::UpsertTableOptionReply;
 use common_meta_types::UpsertTableOptionReq;
+use common_tracing::tracing;
+use futures::future::BoxFuture;
+use futures::FutureExt;
 use uuid::Uuid;
 
 use crate::catalogs::Catalog;
@@ -42,8 +48,111 @@ impl FuseTable {
         operation_log: TableOperationLog,
         overwrite: bool,
     ) -> Result<()> {
-        // TODO OCC retry & resolves conflicts if applicable
+        let operation_log = &operation_log;
+        let result = self
+            .commit_with_retry(ctx.as_ref(), |table| {
+                let ctx = ctx.clone();
+                async move {
+                    let mut written = vec![];
+                    let result = table
+                        .try_commit(ctx, operation_log, overwrite, &mut written)
+                        .await;
+                    (written, result)
+                }
+                .boxed()
+            })
+            .await;
 
+        if result.is_err() {
+            // the appended segments and blocks are not referenced by any snapshot
+            let appended = operation_log
+                .iter()
+                .flat_map(|entry| {
+                    let blocks = entry.segment_info.blocks.iter();
+                    blocks
+                        .map(|block_meta| block_meta.location.location.clone())
+                        .chain(std::iter::once(entry.segment_location.clone()))
+                })
+                .collect::<Vec<_>>();
+            Self::remove_uncommitted(ctx.as_ref(), &appended).await;
+        }
+        result
+    }
+
+    /// Runs `try_commit` on this version of the table. If it conflicts with a concurrent
+    /// commit, runs it again on the latest version of the table, with backoff.
+    ///
+    /// `try_commit` returns the locations of the files written by the attempt along with
+    /// its result. If the attempt fails, the files are not referenced by any snapshot, and
+    /// are removed before the next attempt.
+    pub(crate) async fn commit_with_retry<'a, F>(
+        &self,
+        ctx: &QueryContext,
+        try_commit: F,
+    ) -> Result<()>
+    where
+        F: Fn(FuseTable) -> BoxFuture<'a, (Vec<String>, Result<()>)>,
+    {
+        let settings = ctx.get_settings();
+        let max_retries = settings.get_fuse_commit_max_retries()?;
+        let backoff = Duration::from_millis(settings.get_fuse_commit_retry_backoff_ms()?);
+
+        // The table that the commit is based on, refreshed after each conflict.
+        let mut table_info = self.table_info.clone();
+        let mut retries = 0;
+        loop {
+            let table = FuseTable {
+                table_info: table_info.clone(),
+            };
+            let (written, result) = try_commit(table).await;
+            if result.is_err() {
+                Self::remove_uncommitted(ctx, &written).await;
+            }
+
+            match result {
+                Err(e) if e.code() == ErrorCode::TableVersionMissMatch("").code() => {
+                    if retries >= max_retries {
+                        return Err(ErrorCode::OCCRetryFailure(format!(
+                            "Can not commit to table {} after {} retries, the last conflict: {}",
+                            self.table_info.desc,
+                            retries,
+                            e.message()
+                        )));
+                    }
+
+                    tracing::warn!(
+                        "Commit to table {} conflicts with a concurrent commit, retrying({}): {}",
+                        self.table_info.desc,
+                        retries + 1,
+                        e.message()
+                    );
+                    tokio::time::sleep(backoff * 2u32.pow(retries.min(10) as u32)).await;
+                    retries += 1;
+
+                    // rebase onto the new head snapshot
+                    let refreshed = self.refresh(ctx).await?;
+                    if refreshed.table_info.meta.schema != self.table_info.meta.schema {
+                        return Err(ErrorCode::OCCRetryFailure(format!(
+                            "Can not commit to table {}, its schema is changed by a concurrent commit",
+                            self.table_info.desc
+                        )));
+                    }
+                    table_info = refreshed.table_info;
+                }
+                r => return r,
+            }
+        }
+    }
+
+    /// Writes a new snapshot on top of the head snapshot of this version of the table, and
+    /// commits it to the meta server, conditioned on the version of the table.
+    async fn try_commit(
+        &self,
+        ctx: Arc<QueryContext>,
+        operation_log: &[AppendOperationLogEntry],
+        overwrite: bool,
+        written: &mut Vec<String>,
+    ) -> Result<()> {
         let prev = self.table_snapshot(ctx.as_ref()).await?;
         let new_snapshot = if overwrite {
             let schema = self.table_info.meta.schema.as_ref().clone();
@@ -51,6 +160,7 @@ impl FuseTable {
             TableSnapshot {
                 snapshot_id: Uuid::new_v4(),
                 prev_snapshot_id: prev.as_ref().map(|v| v.snapshot_id),
+                timestamp: Some(Utc::now()),
                 schema,
                 summary,
                 segments,
@@ -59,20 +169,52 @@ impl FuseTable {
             Self::merge_table_operations(self.table_info.meta.schema.as_ref(), prev, operation_log)?
         };
 
+        let storage_usage = new_snapshot.summary.compressed_byte_size;
+        Self::check_storage_quota(Self::storage_quota(ctx.as_ref()), storage_usage)?;
+
         let uuid = new_snapshot.snapshot_id;
         let snapshot_loc = io::snapshot_location(uuid.to_simple().to_string().as_str());
         let bytes = serde_json::to_vec(&new_snapshot)?;
         let da = ctx.get_data_accessor()?;
         da.put(&snapshot_loc, bytes).await?;
+        written.push(snapshot_loc.clone());
 
         self.commit_to_meta_server(ctx, snapshot_loc).await?;
         Ok(())
     }
 
+    /// Removes the files that are not referenced by any snapshot, best effort cleanup.
+    pub(crate) async fn remove_uncommitted(ctx: &QueryContext, locations: &[String]) {
+        let da = match ctx.get_data_accessor() {
+            Ok(da) => da,
+            Err(cause) => {
+                tracing::warn!("Failed to remove uncommitted files: {}", cause);
+                return;
+            }
+        };
+        for location in locations {
+            if let Err(cause) = da.delete(location).await {
+                tracing::warn!("Failed to remove uncommitted file {}: {}", location, cause);
+            }
+        }
+    }
+
+    /// Loads the latest version of the table from the meta server.
+    async fn refresh(&self, ctx: &QueryContext) -> Result<FuseTable> {
+        let catalog = ctx.get_catalog();
+        let (ident, meta) = catalog
+            .get_table_meta_by_id(self.table_info.ident.table_id)
+            .await?;
+        let mut table_info = self.table_info.clone();
+        table_info.ident = ident;
+        table_info.meta = meta.as_ref().clone();
+        Ok(FuseTable { table_info })
+    }
+
     fn merge_table_operations(
         schema: &DataSchema,
         prev: Option<TableSnapshot>,
-        ops: TableOperationLog,
+        ops: &[AppendOperationLogEntry],
     ) -> Result<TableSnapshot> {
         // 1. merge operations(appends, currently)
         let (mut segs, stats) = Self::merge_append_operations(schema, ops)?;
@@ -93,6 +235,7 @@ impl FuseTable {
         let new_snapshot = TableSnapshot {
             snapshot_id: Uuid::new_v4(),
             prev_snapshot_id,
+            timestamp: Some(Utc::now()),
             schema: schema.clone(),
             summary: stats,
             segments: segs,
@@ -100,7 +243,7 @@ impl FuseTable {
         Ok(new_snapshot)
     }
 
-    async fn commit_to_meta_server(
+    pub(crate) async fn commit_to_meta_server(
         &self,
         ctx: Arc<QueryContext>,
         new_snapshot_location: String,
@@ -122,7 +265,7 @@ impl FuseTable {
 
     pub fn merge_append_operations(
         schema: &DataSchema,
-        append_log_entries: Vec<AppendOperationLogEntry>,
+        append_log_entries: &[AppendOperationLogEntry],
     ) -> Result<(Vec<String>, Statistics)> {
         let (s, seg_locs) = append_log_entries.iter().try_fold(
             (
@@ -0,0 +1,62 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_planners::DeletePlan;
+use common_planners::TruncateTablePlan;
+
+use crate::sessions::QueryContext;
+use crate::storages::fuse::operations::mutation::BlockMutation;
+use crate::storages::fuse::FuseTable;
+
+impl FuseTable {
+    #[inline]
+    pub async fn do_delete(&self, ctx: Arc<QueryContext>, delete_plan: DeletePlan) -> Result<()> {
+        let selection = match &delete_plan.selection {
+            Some(selection) => selection,
+            None => {
+                // without a selection, all the rows are deleted
+                let truncate_plan = TruncateTablePlan {
+                    db: delete_plan.db,
+                    table: delete_plan.table,
+                };
+                return self.do_truncate(ctx, truncate_plan).await;
+            }
+        };
+
+        let executor = Self::selection_executor(&self.table_info.schema(), selection)?;
+        let mutator = |block: &DataBlock| {
+            let selected = Self::selected_rows(&executor, block)?;
+            let deleted_rows = selected.iter().filter(|v| **v).count();
+            if deleted_rows == 0 {
+                return Ok(BlockMutation::Untouched);
+            }
+            if deleted_rows == block.num_rows() {
+                return Ok(BlockMutation::Removed);
+            }
+
+            let remains = Series::new(selected.into_iter().map(|v| !v).collect::<Vec<_>>());
+            Ok(BlockMutation::Replaced(DataBlock::filter_block(
+                block, remains,
+            )?))
+        };
+
+        self.mutate_blocks(ctx, Some(selection), mutator).await
+    }
+}
@@ -12,12 +12,18 @@
 //  See the License for the specific language governing permissions and
 //  limitations under the License.
 
+mod alter;
 mod append;
 mod commit;
+mod delete;
+mod mutation;
 mod operation_log;
+mod quota;
 mod read;
 mod read_plan;
 mod truncate;
+mod update;
+mod vacuum;
 
 pub use operation_log::AppendOperationLogEntry;
 pub use operation_log::TableOperationLog;
@@ -0,0 +1,292 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_dal::DataAccessor;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::Expression;
+use common_streams::ParquetSource;
+use common_streams::Source;
+use futures::FutureExt;
+use uuid::Uuid;
+
+use crate::pipelines::transforms::ExpressionExecutor;
+use crate::sessions::QueryContext;
+use crate::storages::fuse::io;
+use crate::storages::fuse::meta::BlockMeta;
+use crate::storages::fuse::meta::SegmentInfo;
+use crate::storages::fuse::meta::Statistics;
+use crate::storages::fuse::meta::TableSnapshot;
+use crate::storages::fuse::statistics;
+use crate::storages::fuse::statistics::StatisticsAccumulator;
+use crate::storages::fuse::FuseTable;
+use crate::storages::index::BlockStatistics;
+use crate::storages::index::RangeFilter;
+
+/// The outcome of mutating a single block
+pub enum BlockMutation {
+    /// the block is kept as it is
+    Untouched,
+    /// all the rows of the block are removed
+    Removed,
+    /// the block is replaced by a new one
+    Replaced(DataBlock),
+}
+
+impl FuseTable {
+    /// Applies `mutator` to the blocks that may contain rows matching the `selection`
+    /// (all the blocks if there is no selection), and commits a new snapshot in which
+    /// the mutated blocks are replaced, if any of them is changed.
+    ///
+    /// Blocks and segments are copy-on-write: the untouched ones are shared with the
+    /// previous snapshot. If the commit conflicts with a concurrent one, the mutation is
+    /// applied again on the new head snapshot.
+    pub(crate) async fn mutate_blocks<F>(
+        &self,
+        ctx: Arc<QueryContext>,
+        selection: Option<&Expression>,
+        mutator: F,
+    ) -> Result<()>
+    where
+        F: Fn(&DataBlock) -> Result<BlockMutation> + Send + Sync,
+    {
+        let mutator = &mutator;
+        self.commit_with_retry(ctx.as_ref(), |table| {
+            let ctx = ctx.clone();
+            async move {
+                let mut written = vec![];
+                let result = async {
+                    let schema = table.table_info.schema();
+                    let snapshot_loc = table
+                        .write_mutated_snapshot(
+                            ctx.as_ref(),
+                            &schema,
+                            selection,
+                            mutator,
+                            &mut written,
+                        )
+                        .await?;
+                    if let Some(snapshot_loc) = snapshot_loc {
+                        table.commit_to_meta_server(ctx, snapshot_loc).await?;
+                    }
+                    Ok::<_, ErrorCode>(())
+                }
+                .await;
+                (written, result)
+            }
+            .boxed()
+        })
+        .await
+    }
+
+    /// Writes the snapshot of the mutated blocks, without committing it. Returns the location
+    /// of the new snapshot, or None if nothing is changed.
+    ///
+    /// `schema` is the schema of the mutated blocks. If it is not the schema of the table, the
+    /// mutator must replace all the blocks. The locations of the written blocks, segments and
+    /// snapshot are appended to `written`.
+    pub(crate) async fn write_mutated_snapshot<F>(
+        &self,
+        ctx: &QueryContext,
+        schema: &DataSchemaRef,
+        selection: Option<&Expression>,
+        mutator: F,
+        written: &mut Vec<String>,
+    ) -> Result<Option<String>>
+    where
+        F: Fn(&DataBlock) -> Result<BlockMutation> + Send + Sync,
+    {
+        let snapshot = match self.table_snapshot(ctx).await? {
+            Some(snapshot) => snapshot,
+            None => return Ok(None),
+        };
+
+        let da = ctx.get_data_accessor()?;
+        let table_schema = self.table_info.schema();
+        let default_values = Self::column_default_values(&table_schema)?;
+        let range_filter = match selection {
+            Some(selection) => Some(RangeFilter::try_create(selection, table_schema.clone())?),
+            None => None,
+        };
+        let may_match = |stats: &BlockStatistics| match &range_filter {
+            Some(range_filter) => range_filter.eval(stats),
+            None => Ok(true),
+        };
+
+        let mut segments = Vec::with_capacity(snapshot.segments.len());
+        let mut summary = Statistics::default();
+        let mut modified = false;
+        for seg_loc in &snapshot.segments {
+            let segment: SegmentInfo = io::read_obj(da.as_ref(), seg_loc).await?;
+
+            // segments and blocks that can not contain any matching rows are kept as they are
+            let mut segment_modified = false;
+            let mut compressed_byte_size = segment.summary.compressed_byte_size;
+            let mut blocks = Vec::with_capacity(segment.blocks.len());
+            if may_match(&segment.summary.col_stats)? {
+                for block_meta in &segment.blocks {
+                    if !may_match(&block_meta.col_stats)? {
+                        blocks.push(block_meta.clone());
+                        continue;
+                    }
+
+                    let block =
+                        Self::read_block(da.clone(), &table_schema, &default_values, block_meta)
+                            .await?;
+                    let new_block = match mutator(&block)? {
+                        BlockMutation::Untouched => {
+                            blocks.push(block_meta.clone());
+                            continue;
+                        }
+                        BlockMutation::Removed => None,
+                        BlockMutation::Replaced(new_block) => Some(new_block),
+                    };
+
+                    segment_modified = true;
+                    let prev_file_size = match block_meta.file_size {
+                        // written by older versions
+                        0 => da.stat(&block_meta.location.location).await?.size,
+                        file_size => file_size,
+                    };
+                    compressed_byte_size = compressed_byte_size.saturating_sub(prev_file_size);
+                    if let Some(new_block) = new_block {
+                        let new_block_meta = Self::write_block(da.clone(), new_block).await?;
+                        written.push(new_block_meta.location.location.clone());
+                        compressed_byte_size += new_block_meta.file_size;
+                        blocks.push(new_block_meta);
+                    }
+                }
+            }
+
+            if !segment_modified {
+                summary = statistics::merge_statistics(schema, &summary, &segment.summary)?;
+                segments.push(seg_loc.clone());
+                continue;
+            }
+
+            modified = true;
+            if blocks.is_empty() {
+                continue;
+            }
+
+            let new_segment = SegmentInfo {
+                summary: Self::blocks_summary(schema, &blocks, compressed_byte_size)?,
+                blocks,
+            };
+            let new_seg_loc = io::gen_segment_info_location();
+            da.put(&new_seg_loc, serde_json::to_vec(&new_segment)?)
+                .await?;
+            written.push(new_seg_loc.clone());
+            summary = statistics::merge_statistics(schema, &summary, &new_segment.summary)?;
+            segments.push(new_seg_loc);
+        }
+
+        if !modified {
+            return Ok(None);
+        }
+
+        let new_snapshot = TableSnapshot {
+            snapshot_id: Uuid::new_v4(),
+            prev_snapshot_id: Some(snapshot.snapshot_id),
+            timestamp: Some(Utc::now()),
+            schema: schema.as_ref().clone(),
+            summary,
+            segments,
+        };
+        let snapshot_loc =
+            io::snapshot_location(new_snapshot.snapshot_id.to_simple().to_string().as_str());
+        da.put(&snapshot_loc, serde_json::to_vec(&new_snapshot)?)
+            .await?;
+        written.push(snapshot_loc.clone());
+        Ok(Some(snapshot_loc))
+    }
+
+    pub(crate) fn selection_executor(
+        schema: &DataSchemaRef,
+        selection: &Expression,
+    ) -> Result<ExpressionExecutor> {
+        let expr_field = selection.to_data_field(schema)?;
+        let expr_schema = DataSchemaRefExt::create(vec![expr_field]);
+
+        ExpressionExecutor::try_create(
+            "mutation selection executor",
+            schema.clone(),
+            expr_schema,
+            vec![selection.clone()],
+            false,
+        )
+    }
+
+    /// Rows that the selection evaluates to NULL are not selected
+    pub(crate) fn selected_rows(
+        executor: &ExpressionExecutor,
+        block: &DataBlock,
+    ) -> Result<Vec<bool>> {
+        let selected = executor.execute(block)?.column(0).to_array()?;
+        let selected = selected.cast_with_type(&DataType::Boolean)?;
+        Ok(selected
+            .bool()?
+            .collect_values()
+            .into_iter()
+            .map(|v| matches!(v, Some(true)))
+            .collect())
+    }
+
+    async fn read_block(
+        da: Arc<dyn DataAccessor>,
+        schema: &DataSchemaRef,
+        default_values: &[DataValue],
+        block_meta: &BlockMeta,
+    ) -> Result<DataBlock> {
+        let location = block_meta.location.location.clone();
+        let projection = (0..schema.fields().len()).collect::<Vec<usize>>();
+        let mut source = ParquetSource::new(da, location, schema.clone(), projection)
+            .with_default_values(default_values.to_vec());
+        let mut blocks = vec![];
+        while let Some(block) = source.read().await? {
+            blocks.push(block);
+        }
+        DataBlock::concat_blocks(&blocks)
+    }
+
+    async fn write_block(da: Arc<dyn DataAccessor>, block: DataBlock) -> Result<BlockMeta> {
+        let partial_acc = StatisticsAccumulator::new().begin(&block)?;
+        let arrow_schema = block.schema().to_arrow();
+        let location = io::gen_block_location();
+        let file_size = io::write_block(&arrow_schema, block, &da, &location).await?;
+        let mut acc = partial_acc.end(file_size, location);
+        Ok(acc.blocks_metas.remove(0))
+    }
+
+    fn blocks_summary(
+        schema: &DataSchema,
+        blocks: &[BlockMeta],
+        compressed_byte_size: u64,
+    ) -> Result<Statistics> {
+        let col_stats = blocks.iter().map(|b| &b.col_stats).collect::<Vec<_>>();
+        Ok(Statistics {
+            row_count: blocks.iter().map(|b| b.row_count).sum(),
+            block_count: blocks.len() as u64,
+            uncompressed_byte_size: blocks.iter().map(|b| b.block_size).sum(),
+            compressed_byte_size,
+            col_stats: statistics::reduce_block_stats(&col_stats, schema)?,
+        })
+    }
+}
@@ -0,0 +1,40 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::sessions::QueryContext;
+use crate::storages::fuse::FuseTable;
+
+impl FuseTable {
+    /// The storage quota of the current user in bytes, 0 means no limit.
+    pub(crate) fn storage_quota(ctx: &QueryContext) -> u64 {
+        ctx.get_current_user()
+            .map(|user| user.quota.max_storage_in_bytes)
+            .unwrap_or(0)
+    }
+
+    pub(crate) fn check_storage_quota(quota: u64, usage: u64) -> Result<()> {
+        if quota > 0 && usage > quota {
+            return Err(ErrorCode::QuotaExceeded(format!(
+                "Storage quota exceeded, usage {} bytes, quota {} bytes",
+                usage, quota
+            )));
+        }
+
+        Ok(())
+    }
+}
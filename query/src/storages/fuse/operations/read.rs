@@ -16,14 +16,17 @@
 use std::sync::Arc;
 
 use async_stream::stream;
-use common_datavalues::DataSchema;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
 use common_exception::Result;
+use common_planners::Expression;
 use common_planners::Extras;
 use common_streams::ParquetSource;
 use common_streams::SendableDataBlockStream;
 use common_streams::Source;
 use futures::StreamExt;
 
+use crate::pipelines::transforms::ExpressionExecutor;
 use crate::sessions::QueryContext;
 use crate::storages::fuse::FuseTable;
 
@@ -65,6 +68,7 @@ impl FuseTable {
         let da = ctx.get_data_accessor()?;
         let arrow_schema = self.table_info.schema().to_arrow();
         let table_schema = Arc::new(DataSchema::from(arrow_schema));
+        let default_values = Self::column_default_values(&self.table_info.schema())?;
 
         let mut iter = futures::stream::iter(iter);
         let stream = stream! {
@@ -74,7 +78,8 @@ impl FuseTable {
                     part.name.clone(),
                     table_schema.clone(),
                     projection.clone(),
-                );
+                )
+                .with_default_values(default_values.clone());
                 loop {
                     let block = source.read().await;
                     match block {
@@ -87,4 +92,41 @@ impl FuseTable {
         };
         Ok(Box::pin(stream))
     }
+
+    /// Values of the columns for the blocks written before the columns are added to the table:
+    /// the evaluated default expressions, or the default values of the column data types.
+    pub(crate) fn column_default_values(schema: &DataSchemaRef) -> Result<Vec<DataValue>> {
+        let input_fields = vec![DataField::new("_dummy", DataType::UInt8, false)];
+        let input_schema = Arc::new(DataSchema::new(input_fields));
+        let dummy_columns = vec![DataColumn::Constant(DataValue::UInt8(Some(1)), 1)];
+        let dummy_block = DataBlock::create(input_schema.clone(), dummy_columns);
+
+        schema
+            .fields()
+            .iter()
+            .map(|f| match f.default_expr() {
+                None => Ok(DataValue::new_from_data_type(
+                    f.data_type(),
+                    f.is_nullable(),
+                )),
+                Some(expr) => {
+                    let expr = serde_json::from_slice::<Expression>(expr)?;
+                    let expr = Expression::Cast {
+                        expr: Box::new(expr),
+                        data_type: f.data_type().clone(),
+                    };
+                    let output_schema =
+                        DataSchemaRefExt::create(vec![expr.to_data_field(&input_schema)?]);
+                    let executor = ExpressionExecutor::try_create(
+                        "column default value executor",
+                        input_schema.clone(),
+                        output_schema,
+                        vec![expr],
+                        false,
+                    )?;
+                    executor.execute(&dummy_block)?.column(0).try_get(0)
+                }
+            })
+            .collect()
+    }
 }
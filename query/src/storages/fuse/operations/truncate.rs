@@ -15,6 +15,7 @@
 
 use std::sync::Arc;
 
+use chrono::Utc;
 use common_exception::Result;
 use common_meta_types::UpsertTableOptionReq;
 use common_planners::TruncateTablePlan;
@@ -38,6 +39,7 @@ impl FuseTable {
             let mut new_snapshot = prev_snapshot;
             new_snapshot.segments = vec![];
             new_snapshot.prev_snapshot_id = Some(prev_id);
+            new_snapshot.timestamp = Some(Utc::now());
             new_snapshot.summary = Default::default();
             new_snapshot.snapshot_id = Uuid::new_v4();
             let new_snapshot_loc =
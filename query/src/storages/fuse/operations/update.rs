@@ -0,0 +1,90 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_planners::UpdatePlan;
+
+use crate::pipelines::transforms::ExpressionExecutor;
+use crate::sessions::QueryContext;
+use crate::storages::fuse::operations::mutation::BlockMutation;
+use crate::storages::fuse::FuseTable;
+
+impl FuseTable {
+    #[inline]
+    pub async fn do_update(&self, ctx: Arc<QueryContext>, update_plan: UpdatePlan) -> Result<()> {
+        let schema = self.table_info.schema();
+        let selection_executor = match &update_plan.selection {
+            Some(selection) => Some(Self::selection_executor(&schema, selection)?),
+            None => None,
+        };
+
+        // evaluates the new values of all the assigned columns in one pass
+        let exprs = update_plan
+            .assignments
+            .iter()
+            .map(|(_, expr)| expr.clone())
+            .collect::<Vec<_>>();
+        let fields = exprs
+            .iter()
+            .map(|expr| expr.to_data_field(&schema))
+            .collect::<Result<Vec<_>>>()?;
+        let update_executor = ExpressionExecutor::try_create(
+            "update executor",
+            schema.clone(),
+            DataSchemaRefExt::create(fields),
+            exprs,
+            false,
+        )?;
+        let column_indices = update_plan
+            .assignments
+            .iter()
+            .map(|(name, _)| schema.index_of(name))
+            .collect::<Result<Vec<_>>>()?;
+
+        let mutator = |block: &DataBlock| {
+            let selected = match &selection_executor {
+                Some(executor) => Self::selected_rows(executor, block)?,
+                None => vec![true; block.num_rows()],
+            };
+            if !selected.iter().any(|v| *v) {
+                return Ok(BlockMutation::Untouched);
+            }
+
+            let updated = update_executor.execute(block)?;
+            let selected: DataColumn = DFBooleanArray::new_from_slice(&selected).into();
+            let mut columns = block.columns().to_vec();
+            for (i, idx) in column_indices.iter().enumerate() {
+                let column = selected.if_then_else(updated.column(i), block.column(*idx))?;
+                let data_type = schema.field(*idx).data_type();
+                columns[*idx] = match column.data_type() == *data_type {
+                    true => column,
+                    false => column.cast_with_type(data_type)?,
+                };
+            }
+
+            Ok(BlockMutation::Replaced(DataBlock::create(
+                block.schema().clone(),
+                columns,
+            )))
+        };
+
+        self.mutate_blocks(ctx, update_plan.selection.as_ref(), mutator)
+            .await
+    }
+}
@@ -0,0 +1,166 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use chrono::Duration;
+use chrono::Utc;
+use common_dal::DataAccessor;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::VacuumTablePlan;
+use serde::de::DeserializeOwned;
+
+use crate::sessions::QueryContext;
+use crate::storages::fuse::io;
+use crate::storages::fuse::meta::Location;
+use crate::storages::fuse::meta::SegmentInfo;
+use crate::storages::fuse::meta::TableSnapshot;
+use crate::storages::fuse::FuseTable;
+use crate::storages::VacuumStats;
+
+impl FuseTable {
+    #[inline]
+    pub async fn do_vacuum(
+        &self,
+        ctx: Arc<QueryContext>,
+        vacuum_plan: VacuumTablePlan,
+    ) -> Result<VacuumStats> {
+        let retain_from = i64::try_from(vacuum_plan.retain_hours)
+            .ok()
+            .and_then(|hours| hours.checked_mul(60 * 60 * 1000))
+            .and_then(|millis| Utc::now().checked_sub_signed(Duration::milliseconds(millis)))
+            .ok_or_else(|| {
+                ErrorCode::BadArguments(format!(
+                    "Vacuum retain hours is out of range: {}",
+                    vacuum_plan.retain_hours
+                ))
+            })?;
+
+        let current = match self.table_snapshot(ctx.as_ref()).await? {
+            Some(snapshot) => snapshot,
+            None => return Ok(VacuumStats::default()),
+        };
+
+        let da = ctx.get_data_accessor()?;
+
+        // 1. walk the snapshot chain, the current snapshot is always retained.
+        //
+        // Once a snapshot is expired, all the snapshots before it are expired as well,
+        // so that the retained part of the chain stays contiguous.
+        let mut prev = current.prev_snapshot_id;
+        let mut retained = vec![current];
+        let mut expired_snapshots = vec![];
+        let mut expired_segments = HashSet::new();
+        let mut expired = false;
+        while let Some(id) = prev {
+            let loc = io::snapshot_location(id.to_simple().to_string().as_str());
+            // the tail of the chain may have been removed by a previous vacuum
+            let snapshot: TableSnapshot = match Self::read_existing(da.as_ref(), &loc).await? {
+                Some(snapshot) => snapshot,
+                None => break,
+            };
+            prev = snapshot.prev_snapshot_id;
+            // snapshots without timestamp (written by older versions) are retained
+            expired = expired || matches!(snapshot.timestamp, Some(ts) if ts < retain_from);
+            if expired {
+                expired_segments.extend(snapshot.segments);
+                expired_snapshots.push(loc);
+            } else {
+                retained.push(snapshot);
+            }
+        }
+
+        // 2. collect the segments and blocks that are still referenced
+        let mut referenced_segments = HashSet::new();
+        let mut referenced_blocks = HashSet::new();
+        for snapshot in &retained {
+            for seg_loc in &snapshot.segments {
+                if referenced_segments.insert(seg_loc.clone()) {
+                    let segment: SegmentInfo = io::read_obj(da.as_ref(), seg_loc).await?;
+                    referenced_blocks
+                        .extend(segment.blocks.into_iter().map(|b| b.location.location));
+                }
+            }
+        }
+
+        // 3. the unreferenced segments of expired snapshots, and their unreferenced blocks
+        let mut garbage_blocks = HashSet::new();
+        let mut garbage_segments = vec![];
+        for seg_loc in expired_segments {
+            if referenced_segments.contains(&seg_loc) {
+                continue;
+            }
+            // the segment may have been removed partially by a previous vacuum
+            let segment = Self::read_existing::<SegmentInfo>(da.as_ref(), &seg_loc).await?;
+            if let Some(segment) = segment {
+                garbage_blocks.extend(
+                    segment
+                        .blocks
+                        .into_iter()
+                        .map(|b| b.location.location)
+                        .filter(|loc| !referenced_blocks.contains(loc)),
+                );
+            }
+            garbage_segments.push(seg_loc);
+        }
+        let garbage_blocks = garbage_blocks.into_iter().collect::<Vec<_>>();
+
+        // 4. remove them, blocks first, snapshots last
+        let mut stats = VacuumStats::default();
+        for garbage in [&garbage_blocks, &garbage_segments, &expired_snapshots] {
+            let existing = Self::stat_objects(da.as_ref(), garbage, &mut stats).await?;
+            if !vacuum_plan.dry_run && !existing.is_empty() {
+                da.batch_delete(&existing).await?;
+            }
+        }
+
+        Ok(stats)
+    }
+
+    /// Reads the object, None if it does not exist
+    async fn read_existing<T: DeserializeOwned>(
+        da: &dyn DataAccessor,
+        loc: &str,
+    ) -> Result<Option<T>> {
+        match da.stat(loc).await {
+            Ok(_) => Ok(Some(io::read_obj(da, loc).await?)),
+            Err(e) if e.code() == ErrorCode::DALPathNotFound("").code() => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Accumulates the sizes of the given objects, returns the ones that still exist
+    async fn stat_objects(
+        da: &dyn DataAccessor,
+        locations: &[Location],
+        stats: &mut VacuumStats,
+    ) -> Result<Vec<Location>> {
+        let mut existing = Vec::with_capacity(locations.len());
+        for loc in locations {
+            match da.stat(loc).await {
+                Ok(meta) => {
+                    stats.removed_files += 1;
+                    stats.removed_bytes += meta.size;
+                    existing.push(loc.clone());
+                }
+                Err(e) if e.code() == ErrorCode::DALPathNotFound("").code() => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(existing)
+    }
+}
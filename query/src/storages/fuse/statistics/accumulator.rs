@@ -121,6 +121,7 @@ impl PartiallyAccumulated {
             row_count: self.block_row_count,
             block_size: self.block_size,
             col_stats: self.block_column_statistics,
+            file_size,
         };
         stats.blocks_metas.push(block_meta);
         self.accumulator
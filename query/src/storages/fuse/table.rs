@@ -20,11 +20,15 @@ use std::sync::Arc;
 use common_datablocks::DataBlock;
 use common_exception::Result;
 use common_meta_types::TableInfo;
+use common_planners::AlterTablePlan;
+use common_planners::DeletePlan;
 use common_planners::Extras;
 use common_planners::Partitions;
 use common_planners::ReadDataSourcePlan;
 use common_planners::Statistics;
 use common_planners::TruncateTablePlan;
+use common_planners::UpdatePlan;
+use common_planners::VacuumTablePlan;
 use common_streams::SendableDataBlockStream;
 use futures::StreamExt;
 
@@ -35,6 +39,7 @@ use crate::storages::fuse::operations::AppendOperationLogEntry;
 use crate::storages::fuse::TBL_OPT_KEY_SNAPSHOT_LOC;
 use crate::storages::StorageContext;
 use crate::storages::Table;
+use crate::storages::VacuumStats;
 
 pub struct FuseTable {
     pub(crate) table_info: TableInfo,
@@ -115,6 +120,26 @@ impl Table for FuseTable {
     ) -> Result<()> {
         self.do_truncate(ctx, truncate_plan).await
     }
+
+    async fn delete(&self, ctx: Arc<QueryContext>, delete_plan: DeletePlan) -> Result<()> {
+        self.do_delete(ctx, delete_plan).await
+    }
+
+    async fn update(&self, ctx: Arc<QueryContext>, update_plan: UpdatePlan) -> Result<()> {
+        self.do_update(ctx, update_plan).await
+    }
+
+    async fn alter(&self, ctx: Arc<QueryContext>, alter_plan: AlterTablePlan) -> Result<()> {
+        self.do_alter(ctx, alter_plan).await
+    }
+
+    async fn vacuum(
+        &self,
+        ctx: Arc<QueryContext>,
+        vacuum_plan: VacuumTablePlan,
+    ) -> Result<VacuumStats> {
+        self.do_vacuum(ctx, vacuum_plan).await
+    }
 }
 
 impl FuseTable {
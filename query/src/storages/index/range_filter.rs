@@ -75,6 +75,16 @@ impl RangeFilter {
     }
 
     pub fn eval(&self, stats: &BlockStatistics) -> Result<bool> {
+        // blocks written before a column is added to the table have no statistics of it,
+        // they can not be pruned by the filter.
+        if self
+            .stat_columns
+            .iter()
+            .any(|c| !stats.contains_key(&c.column_id))
+        {
+            return Ok(true);
+        }
+
         let columns = self
             .stat_columns
             .iter()
@@ -106,7 +116,11 @@ pub fn build_verifiable_expr(
 
     let (exprs, op) = match expr {
         Expression::Literal { .. } => return expr.clone(),
-        Expression::ScalarFunction { op, args } => (args.clone(), op.clone()),
+        Expression::ScalarFunction { op, args } => match op.to_lowercase().as_str() {
+            "in" => return build_in_list_expr(args, false, schema, stat_columns),
+            "not in" => return build_in_list_expr(args, true, schema, stat_columns),
+            _ => (args.clone(), op.clone()),
+        },
         Expression::BinaryExpression { left, op, right } => match op.to_lowercase().as_str() {
             "and" => {
                 let left = build_verifiable_expr(left, schema, stat_columns);
@@ -130,6 +144,46 @@ pub fn build_verifiable_expr(
         .map_or(unhandled.clone(), |mut v| v.build().unwrap_or(unhandled))
 }
 
+// `a in (x, y)` is verified as `a = x or a = y`, and `a not in (x, y)` as `a <> x and a <> y`.
+// Only the lists of constant values are handled, null values never match thus are skipped.
+fn build_in_list_expr(
+    args: &[Expression],
+    negated: bool,
+    schema: &DataSchemaRef,
+    stat_columns: &mut StatColumns,
+) -> Expression {
+    let unhandled = lit(true);
+
+    let (expr, values) = match args.split_first() {
+        Some((Expression::Literal { .. }, _)) | None => return unhandled,
+        Some((expr, values)) => (expr, values),
+    };
+
+    let mut result: Option<Expression> = None;
+    for value in values {
+        match value {
+            Expression::Literal { value, .. } if value.is_null() => continue,
+            Expression::Literal { .. } => {
+                let verifiable_expr = match negated {
+                    true => {
+                        build_verifiable_expr(&expr.not_eq(value.clone()), schema, stat_columns)
+                    }
+                    false => build_verifiable_expr(&expr.eq(value.clone()), schema, stat_columns),
+                };
+
+                result = Some(match (result, negated) {
+                    (None, _) => verifiable_expr,
+                    (Some(result), true) => result.and(verifiable_expr),
+                    (Some(result), false) => result.or(verifiable_expr),
+                });
+            }
+            _ => return unhandled,
+        }
+    }
+
+    result.unwrap_or(unhandled)
+}
+
 fn inverse_operator(op: &str) -> Result<&str> {
     match op {
         "<" => Ok(">"),
@@ -329,7 +383,6 @@ impl<'a> VerifiableExprBuilder<'a> {
     }
 
     fn build(&mut self) -> Result<Expression> {
-        // TODO: support in/not in.
         match self.op {
             "isnull" => {
                 let nulls_expr = self.nulls_column_expr()?;
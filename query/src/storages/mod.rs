@@ -20,6 +20,7 @@ pub mod memory;
 pub mod null;
 pub mod parquet;
 pub mod system;
+pub mod view;
 
 mod storage_context;
 mod storage_factory;
@@ -32,5 +33,6 @@ pub use storage_context::StorageContext;
 pub use storage_factory::StorageCreator;
 pub use storage_factory::StorageFactory;
 pub use storage_table::Table;
+pub use storage_table::VacuumStats;
 pub use storage_table_read_plan::ToReadDataSourcePlan;
 pub use system::SystemDatabase;
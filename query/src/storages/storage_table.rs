@@ -22,6 +22,8 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_types::MetaId;
 use common_meta_types::TableInfo;
+use common_planners::AlterTablePlan;
+use common_planners::DeletePlan;
 use common_planners::Expression;
 use common_planners::Extras;
 use common_planners::Part;
@@ -29,6 +31,8 @@ use common_planners::Partitions;
 use common_planners::ReadDataSourcePlan;
 use common_planners::Statistics;
 use common_planners::TruncateTablePlan;
+use common_planners::UpdatePlan;
+use common_planners::VacuumTablePlan;
 use common_streams::SendableDataBlockStream;
 
 use crate::sessions::QueryContext;
@@ -122,4 +126,48 @@ pub trait Table: Sync + Send {
             self.name()
         )))
     }
+
+    async fn delete(&self, _ctx: Arc<QueryContext>, _delete_plan: DeletePlan) -> Result<()> {
+        Err(ErrorCode::UnImplement(format!(
+            "delete for table {} is not implemented, table engine is {}",
+            self.name(),
+            self.get_table_info().meta.engine
+        )))
+    }
+
+    async fn update(&self, _ctx: Arc<QueryContext>, _update_plan: UpdatePlan) -> Result<()> {
+        Err(ErrorCode::UnImplement(format!(
+            "update for table {} is not implemented, table engine is {}",
+            self.name(),
+            self.get_table_info().meta.engine
+        )))
+    }
+
+    /// Alters the columns or the options of the table, renaming a table is done by the catalog
+    async fn alter(&self, _ctx: Arc<QueryContext>, _alter_plan: AlterTablePlan) -> Result<()> {
+        Err(ErrorCode::UnImplement(format!(
+            "alter for table {} is not implemented, table engine is {}",
+            self.name(),
+            self.get_table_info().meta.engine
+        )))
+    }
+
+    async fn vacuum(
+        &self,
+        _ctx: Arc<QueryContext>,
+        _vacuum_plan: VacuumTablePlan,
+    ) -> Result<VacuumStats> {
+        Err(ErrorCode::UnImplement(format!(
+            "vacuum for table {} is not implemented, table engine is {}",
+            self.name(),
+            self.get_table_info().meta.engine
+        )))
+    }
+}
+
+/// Objects removed (or to be removed, in dry run mode) by a vacuum operation
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct VacuumStats {
+    pub removed_files: u64,
+    pub removed_bytes: u64,
 }
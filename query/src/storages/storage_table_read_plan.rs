@@ -67,6 +67,7 @@ impl ToReadDataSourcePlan for dyn Table {
             description,
             tbl_args: self.table_args(),
             push_downs,
+            source_table: None,
         })
     }
 }
@@ -0,0 +1,103 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_meta_types::TableIdent;
+use common_meta_types::TableInfo;
+use common_meta_types::TableMeta;
+use common_planners::ReadDataSourcePlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::sessions::QueryContext;
+use crate::storages::Table;
+
+pub struct CachesTable {
+    table_info: TableInfo,
+}
+
+impl CachesTable {
+    pub fn create(table_id: u64) -> Self {
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("name", DataType::String, false),
+            DataField::new("capacity", DataType::UInt64, false),
+            DataField::new("size", DataType::UInt64, false),
+            DataField::new("items", DataType::UInt64, false),
+            DataField::new("hits", DataType::UInt64, false),
+            DataField::new("misses", DataType::UInt64, false),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'caches'".to_string(),
+            name: "caches".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemCaches".to_string(),
+                ..Default::default()
+            },
+        };
+
+        CachesTable { table_info }
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for CachesTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn read(
+        &self,
+        ctx: Arc<QueryContext>,
+        _plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
+        let caches = match ctx.get_sessions_manager().get_dal_cache() {
+            Some(dal_cache) => dal_cache.get_status(),
+            None => vec![],
+        };
+
+        let names: Vec<&str> = caches.iter().map(|x| x.name.as_str()).collect();
+        let capacities: Vec<u64> = caches.iter().map(|x| x.capacity).collect();
+        let sizes: Vec<u64> = caches.iter().map(|x| x.size).collect();
+        let items: Vec<u64> = caches.iter().map(|x| x.items).collect();
+        let hits: Vec<u64> = caches.iter().map(|x| x.hits).collect();
+        let misses: Vec<u64> = caches.iter().map(|x| x.misses).collect();
+
+        let block = DataBlock::create_by_array(self.table_info.schema(), vec![
+            Series::new(names),
+            Series::new(capacities),
+            Series::new(sizes),
+            Series::new(items),
+            Series::new(hits),
+            Series::new(misses),
+        ]);
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.table_info.schema(),
+            None,
+            vec![block],
+        )))
+    }
+}
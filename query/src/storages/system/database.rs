@@ -41,6 +41,7 @@ impl SystemDatabase {
             Arc::new(system::ColumnsTable::create(sys_db_meta.next_id())),
             Arc::new(system::UsersTable::create(sys_db_meta.next_id())),
             Arc::new(system::QueryLogTable::create(sys_db_meta.next_id())),
+            Arc::new(system::CachesTable::create(sys_db_meta.next_id())),
         ];
 
         for tbl in table_list.into_iter() {
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod caches_table;
 mod clusters_table;
 mod columns_table;
 mod configs_table;
@@ -30,6 +31,7 @@ mod tracing_table;
 mod tracing_table_stream;
 mod users_table;
 
+pub use caches_table::CachesTable;
 pub use clusters_table::ClustersTable;
 pub use columns_table::ColumnsTable;
 pub use configs_table::ConfigsTable;
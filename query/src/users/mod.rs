@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod role_mgr;
 mod user;
 mod user_api;
 mod user_mgr;
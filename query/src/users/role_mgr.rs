@@ -0,0 +1,146 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::GrantObject;
+use common_meta_types::RoleInfo;
+use common_meta_types::UserPrivilegeSet;
+
+use crate::users::UserApiProvider;
+
+/// role operations.
+impl UserApiProvider {
+    // Add a new role.
+    pub async fn add_role(&self, role_info: RoleInfo) -> Result<u64> {
+        let client = self.get_role_api_client();
+        let add_role = client.add_role(role_info);
+        match add_role.await {
+            Ok(res) => Ok(res),
+            Err(failure) => Err(failure.add_message_back("(while add role).")),
+        }
+    }
+
+    // Get one role by name.
+    pub async fn get_role(&self, role: &str) -> Result<RoleInfo> {
+        let client = self.get_role_api_client();
+        let get_role = client.get_role(role.to_string(), None);
+        Ok(get_role.await?.data)
+    }
+
+    // Get the tenant all roles list.
+    pub async fn get_roles(&self) -> Result<Vec<RoleInfo>> {
+        let client = self.get_role_api_client();
+        let get_roles = client.get_roles();
+
+        match get_roles.await {
+            Err(failure) => Err(failure.add_message_back("(while get roles).")),
+            Ok(seq_roles_info) => Ok(seq_roles_info.into_iter().map(|r| r.data).collect()),
+        }
+    }
+
+    // Get the roles granted to the user, the roles which no longer exist are skipped.
+    pub async fn get_granted_roles(&self, roles: &[String]) -> Result<Vec<RoleInfo>> {
+        let mut res = Vec::with_capacity(roles.len());
+        for role in roles {
+            match self.get_role(role).await {
+                Ok(role_info) => res.push(role_info),
+                Err(failure) if failure.code() == ErrorCode::UnknownRoleCode() => continue,
+                Err(failure) => return Err(failure),
+            }
+        }
+        Ok(res)
+    }
+
+    pub async fn grant_role_privileges(
+        &self,
+        role: &str,
+        object: GrantObject,
+        privileges: UserPrivilegeSet,
+    ) -> Result<Option<u64>> {
+        let client = self.get_role_api_client();
+        client
+            .grant_role_privileges(role.to_string(), object, privileges, None)
+            .await
+            .map_err(|failure| failure.add_message_back("(while set role privileges)"))
+    }
+
+    pub async fn revoke_role_privileges(
+        &self,
+        role: &str,
+        object: GrantObject,
+        privileges: UserPrivilegeSet,
+    ) -> Result<Option<u64>> {
+        let client = self.get_role_api_client();
+        client
+            .revoke_role_privileges(role.to_string(), object, privileges, None)
+            .await
+            .map_err(|failure| failure.add_message_back("(while revoke role privileges)"))
+    }
+
+    // Grant a role to the user, the role must exist.
+    pub async fn grant_user_role(
+        &self,
+        username: &str,
+        hostname: &str,
+        role: &str,
+    ) -> Result<Option<u64>> {
+        self.get_role(role).await?;
+
+        let client = self.get_user_api_client();
+        client
+            .grant_user_role(
+                username.to_string(),
+                hostname.to_string(),
+                role.to_string(),
+                None,
+            )
+            .await
+            .map_err(|failure| failure.add_message_back("(while grant role to user)"))
+    }
+
+    pub async fn revoke_user_role(
+        &self,
+        username: &str,
+        hostname: &str,
+        role: &str,
+    ) -> Result<Option<u64>> {
+        let client = self.get_user_api_client();
+        client
+            .revoke_user_role(
+                username.to_string(),
+                hostname.to_string(),
+                role.to_string(),
+                None,
+            )
+            .await
+            .map_err(|failure| failure.add_message_back("(while revoke role from user)"))
+    }
+
+    // Drop a role by name.
+    pub async fn drop_role(&self, role: &str, if_exists: bool) -> Result<()> {
+        let client = self.get_role_api_client();
+        let drop_role = client.drop_role(role.to_string(), None);
+        match drop_role.await {
+            Ok(res) => Ok(res),
+            Err(failure) => {
+                if if_exists && failure.code() == ErrorCode::UnknownRoleCode() {
+                    Ok(())
+                } else {
+                    Err(failure.add_message_back("(while drop role)"))
+                }
+            }
+        }
+    }
+}
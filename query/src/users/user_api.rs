@@ -15,6 +15,8 @@
 use std::sync::Arc;
 
 use common_exception::Result;
+use common_management::RoleMgr;
+use common_management::RoleMgrApi;
 use common_management::StageMgr;
 use common_management::StageMgrApi;
 use common_management::UserMgr;
@@ -27,6 +29,7 @@ use crate::configs::Config;
 pub struct UserApiProvider {
     user_api_provider: Arc<dyn UserMgrApi>,
     stage_api_provider: Arc<dyn StageMgrApi>,
+    role_api_provider: Arc<dyn RoleMgrApi>,
 }
 
 impl UserApiProvider {
@@ -46,7 +49,8 @@ impl UserApiProvider {
 
         Ok(Arc::new(UserApiProvider {
             user_api_provider: Arc::new(UserMgr::new(client.clone(), tenant_id)),
-            stage_api_provider: Arc::new(StageMgr::new(client, tenant_id)),
+            stage_api_provider: Arc::new(StageMgr::new(client.clone(), tenant_id)),
+            role_api_provider: Arc::new(RoleMgr::new(client, tenant_id)),
         }))
     }
 
@@ -57,4 +61,8 @@ impl UserApiProvider {
     pub fn get_stage_api_client(&self) -> Arc<dyn StageMgrApi> {
         self.stage_api_provider.clone()
     }
+
+    pub fn get_role_api_client(&self) -> Arc<dyn RoleMgrApi> {
+        self.role_api_provider.clone()
+    }
 }
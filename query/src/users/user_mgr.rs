@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::net::IpAddr;
+use std::net::SocketAddr;
+
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_types::AuthType;
@@ -32,7 +35,7 @@ impl UserApiProvider {
             "default" | "" | "root" => {
                 let mut user_info: UserInfo =
                     User::new(username, hostname, "", AuthType::None).into();
-                if hostname == "127.0.0.1" || &hostname.to_lowercase() == "localhost" {
+                if Self::is_local_host(hostname) {
                     user_info.grants.grant_privileges(
                         username,
                         hostname,
@@ -50,6 +53,47 @@ impl UserApiProvider {
         }
     }
 
+    // Get the user logging in from the client address.
+    pub async fn get_user_by_client_address(
+        &self,
+        username: &str,
+        client_address: &str,
+    ) -> Result<UserInfo> {
+        match username {
+            // The built-in users get their privileges only from the local host.
+            "default" | "" | "root" => {
+                self.get_user(username, &Self::client_host(client_address))
+                    .await
+            }
+            // TODO: list user's grant list and check client address
+            _ => self.get_user(username, "%").await,
+        }
+    }
+
+    // Whether the client address, with or without the port, is on the local host.
+    pub fn is_local_client_address(client_address: &str) -> bool {
+        Self::is_local_host(&Self::client_host(client_address))
+    }
+
+    fn client_host(client_address: &str) -> String {
+        let ip = match client_address.parse::<SocketAddr>() {
+            Ok(addr) => addr.ip(),
+            Err(_) => match client_address.parse::<IpAddr>() {
+                Ok(ip) => ip,
+                Err(_) => return client_address.to_string(),
+            },
+        };
+
+        match ip.is_loopback() {
+            true => "localhost".to_string(),
+            false => ip.to_string(),
+        }
+    }
+
+    fn is_local_host(hostname: &str) -> bool {
+        hostname == "127.0.0.1" || hostname.to_lowercase() == "localhost"
+    }
+
     // Auth the user and password for different Auth type.
     pub async fn auth_user(&self, user: UserInfo, info: CertifiedInfo) -> Result<bool> {
         match user.auth_type {
@@ -46,6 +46,7 @@ clickhouse_handler_host = \"127.0.0.1\"
 clickhouse_handler_port = 9000
 http_handler_host = \"127.0.0.1\"
 http_handler_port = 8000
+http_handler_jwt_secret = \"\"
 flight_api_address = \"127.0.0.1:9090\"
 http_api_address = \"127.0.0.1:8080\"
 metric_api_address = \"127.0.0.1:7070\"
@@ -95,6 +96,12 @@ bucket = \"\"
 account = \"\"
 master_key = \"\"
 container = \"\"
+
+[storage.cache]
+cache_enabled = false
+cache_memory_size = 268435456
+cache_disk_path = \"_cache\"
+cache_disk_size = 10737418240
 ";
 
     let tom_actual = toml::to_string(&actual).unwrap();
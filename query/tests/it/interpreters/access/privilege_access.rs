@@ -0,0 +1,184 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::AuthType;
+use common_meta_types::GrantObject;
+use common_meta_types::RoleInfo;
+use common_meta_types::UserInfo;
+use common_meta_types::UserPrivilegeSet;
+use common_meta_types::UserPrivilegeType;
+use databend_query::interpreters::*;
+use futures::TryStreamExt;
+use pretty_assertions::assert_eq;
+
+use crate::tests::parse_query;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_privilege_access() -> Result<()> {
+    common_tracing::init_default_ut_tracing();
+    let ctx = crate::tests::create_query_context()?;
+
+    // Prepare the tables with the privileged test user.
+    {
+        for query in [
+            "create table default.a(a bigint) Engine = Memory",
+            "create database secret",
+            "create table secret.b(a bigint) Engine = Memory",
+        ] {
+            let plan = parse_query(query, &ctx)?;
+            let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+            let _ = interpreter.execute(None).await?;
+        }
+    }
+
+    let mut user_info = UserInfo::new(
+        "access_user".to_string(),
+        "%".to_string(),
+        Vec::from("pass"),
+        AuthType::PlainText,
+    );
+    let mut privileges = UserPrivilegeSet::empty();
+    privileges.set_privilege(UserPrivilegeType::Select);
+    user_info.grants.grant_privileges(
+        "access_user",
+        "%",
+        &GrantObject::Table("default".to_string(), "a".to_string()),
+        privileges,
+    );
+    ctx.get_session().set_current_user(user_info.clone());
+
+    // Granted select on the table, system tables and table functions.
+    {
+        for query in [
+            "select * from default.a",
+            "select * from system.databases",
+            "select number from numbers(10)",
+            "show tables",
+        ] {
+            let plan = parse_query(query, &ctx)?;
+            let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+            let stream = interpreter.execute(None).await?;
+            stream.try_collect::<Vec<_>>().await?;
+        }
+    }
+
+    // Not granted.
+    {
+        for (query, expect) in [
+            (
+                "insert into default.a values(1)",
+                "Code: 3003, displayText = Permission denied, user 'access_user'@'%' requires INSERT privilege on 'default'.'a'.",
+            ),
+            (
+                "insert into default.a select * from default.a",
+                "Code: 3003, displayText = Permission denied, user 'access_user'@'%' requires INSERT privilege on 'default'.'a'.",
+            ),
+            (
+                "delete from default.a where a = 1",
+                "Code: 3003, displayText = Permission denied, user 'access_user'@'%' requires INSERT privilege on 'default'.'a'.",
+            ),
+            (
+                "kill query nobody",
+                "Code: 3003, displayText = Permission denied, user 'access_user'@'%' requires GRANT privilege on *.*.",
+            ),
+            (
+                "drop table default.a",
+                "Code: 3003, displayText = Permission denied, user 'access_user'@'%' requires DROP privilege on 'default'.'a'.",
+            ),
+            (
+                "create database db1",
+                "Code: 3003, displayText = Permission denied, user 'access_user'@'%' requires CREATE privilege on 'db1'.*.",
+            ),
+            (
+                "SET max_threads = 1",
+                "Code: 3003, displayText = Permission denied, user 'access_user'@'%' requires SET privilege on *.*.",
+            ),
+            (
+                "CREATE USER 'test'@'localhost' IDENTIFIED BY 'password'",
+                "Code: 3003, displayText = Permission denied, user 'access_user'@'%' requires GRANT privilege on *.*.",
+            ),
+        ] {
+            let plan = parse_query(query, &ctx)?;
+            let res = InterpreterFactory::get(ctx.clone(), plan);
+            assert!(res.is_err());
+            let err = res.err().unwrap();
+            assert_eq!(err.code(), ErrorCode::PermissionDenied("").code());
+            assert_eq!(err.to_string(), expect);
+        }
+    }
+
+    // Grant the database privileges.
+    {
+        user_info.grants.grant_privileges(
+            "access_user",
+            "%",
+            &GrantObject::Database("default".to_string()),
+            UserPrivilegeSet::all_privileges(),
+        );
+        ctx.get_session().set_current_user(user_info);
+
+        let plan = parse_query("insert into default.a select * from default.a", &ctx)?;
+        InterpreterFactory::get(ctx.clone(), plan)?;
+
+        let plan = parse_query("drop table default.a", &ctx)?;
+        InterpreterFactory::get(ctx.clone(), plan)?;
+
+        let plan = parse_query("create database db1", &ctx)?;
+        let res = InterpreterFactory::get(ctx.clone(), plan);
+        assert!(res.is_err());
+
+        // Killing its own session.
+        let query = format!("kill query `{}`", ctx.get_session().get_id());
+        let plan = parse_query(&query, &ctx)?;
+        InterpreterFactory::get(ctx.clone(), plan)?;
+
+        // The subqueries of the selection and the new values.
+        for query in [
+            "delete from default.a where a in (select a from secret.b)",
+            "update default.a set a = (select max(a) from secret.b)",
+            "update default.a set a = 1 where exists (select a from secret.b)",
+        ] {
+            let plan = parse_query(query, &ctx)?;
+            let res = InterpreterFactory::get(ctx.clone(), plan);
+            assert!(res.is_err());
+            assert_eq!(
+                res.err().unwrap().to_string(),
+                "Code: 3003, displayText = Permission denied, user 'access_user'@'%' requires SELECT privilege on 'secret'.'b'."
+            );
+        }
+    }
+
+    // The privileges granted to the current roles.
+    {
+        let mut role_info = RoleInfo::new("role1".to_string());
+        let mut privileges = UserPrivilegeSet::empty();
+        privileges.set_privilege(UserPrivilegeType::Create);
+        role_info
+            .grants
+            .grant_privileges("role1", "%", &GrantObject::Global, privileges);
+        ctx.get_session().set_current_roles(vec![role_info]);
+
+        let plan = parse_query("create database db1", &ctx)?;
+        InterpreterFactory::get(ctx.clone(), plan)?;
+
+        let plan = parse_query("SET max_threads = 1", &ctx)?;
+        let res = InterpreterFactory::get(ctx.clone(), plan);
+        assert!(res.is_err());
+    }
+
+    Ok(())
+}
@@ -63,12 +63,12 @@ async fn test_interpreter_interceptor() -> Result<()> {
         let result = stream.try_collect::<Vec<_>>().await?;
 
         let expected = vec![
-            "+----------+--------------+-----------+--------------+-----------+------------+--------------+---------------+-------------+--------------+------------+------------------------------------------------------+-----------+-----------------------------+---------------------------------------------------------------------------+",
-            "| log_type | handler_type | cpu_usage | memory_usage | read_rows | read_bytes | written_rows | written_bytes | result_rows | result_bytes | query_kind | query_text                                           | sql_user  | sql_user_quota              | sql_user_privileges                                                       |",
-            "+----------+--------------+-----------+--------------+-----------+------------+--------------+---------------+-------------+--------------+------------+------------------------------------------------------+-----------+-----------------------------+---------------------------------------------------------------------------+",
-            "| 1        | TestSession  | 8         | 3421         | 0         | 0          | 0            | 0             | 0           | 0            | SelectPlan | select number from numbers_mt(100) where number > 90 | test_user | UserGrantSet { grants: [] } | UserQuota { max_cpu: 0, max_memory_in_bytes: 0, max_storage_in_bytes: 0 } |",
-            "| 2        | TestSession  | 8         | 3421         | 100       | 800        | 0            | 0             | 9           | 72           | SelectPlan | select number from numbers_mt(100) where number > 90 | test_user | UserGrantSet { grants: [] } | UserQuota { max_cpu: 0, max_memory_in_bytes: 0, max_storage_in_bytes: 0 } |",
-            "+----------+--------------+-----------+--------------+-----------+------------+--------------+---------------+-------------+--------------+------------+------------------------------------------------------+-----------+-----------------------------+---------------------------------------------------------------------------+",
+            "+----------+--------------+-----------+--------------+-----------+------------+--------------+---------------+-------------+--------------+------------+------------------------------------------------------+-----------+---------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------+---------------------------------------------------------------------------+",
+            "| log_type | handler_type | cpu_usage | memory_usage | read_rows | read_bytes | written_rows | written_bytes | result_rows | result_bytes | query_kind | query_text                                           | sql_user  | sql_user_quota                                                                                                                                                                                                | sql_user_privileges                                                       |",
+            "+----------+--------------+-----------+--------------+-----------+------------+--------------+---------------+-------------+--------------+------------+------------------------------------------------------+-----------+---------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------+---------------------------------------------------------------------------+",
+            "| 1        | TestSession  | 8         | 3421         | 0         | 0          | 0            | 0             | 0           | 0            | SelectPlan | select number from numbers_mt(100) where number > 90 | test_user | UserGrantSet { grants: [GrantEntry { user: \"test_user\", host_pattern: \"%\", object: Global, privileges: BitFlags<UserPrivilegeType>(0b1111110, Create | Select | Insert | Set | Drop | Grant) }], version: 1 } | UserQuota { max_cpu: 0, max_memory_in_bytes: 0, max_storage_in_bytes: 0 } |",
+            "| 2        | TestSession  | 8         | 3421         | 100       | 800        | 0            | 0             | 9           | 72           | SelectPlan | select number from numbers_mt(100) where number > 90 | test_user | UserGrantSet { grants: [GrantEntry { user: \"test_user\", host_pattern: \"%\", object: Global, privileges: BitFlags<UserPrivilegeType>(0b1111110, Create | Select | Insert | Set | Drop | Grant) }], version: 1 } | UserQuota { max_cpu: 0, max_memory_in_bytes: 0, max_storage_in_bytes: 0 } |",
+            "+----------+--------------+-----------+--------------+-----------+------------+--------------+---------------+-------------+--------------+------------+------------------------------------------------------+-----------+---------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------+---------------------------------------------------------------------------+",
         ];
 
         common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
@@ -0,0 +1,66 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_exception::Result;
+use common_planners::*;
+use databend_query::interpreters::*;
+use databend_query::sql::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_create_role_interpreter() -> Result<()> {
+    common_tracing::init_default_ut_tracing();
+
+    let ctx = crate::tests::create_query_context()?;
+
+    {
+        static TEST_QUERY: &str = "CREATE ROLE 'role1'";
+        if let PlanNode::CreateRole(plan) = PlanParser::parse(TEST_QUERY, ctx.clone()).await? {
+            let executor = CreateRoleInterpreter::try_create(ctx.clone(), plan.clone())?;
+            assert_eq!(executor.name(), "CreateRoleInterpreter");
+            executor.execute(None).await?;
+        } else {
+            panic!()
+        }
+    }
+
+    // Create the same role again.
+    {
+        static TEST_QUERY: &str = "CREATE ROLE 'role1'";
+        if let PlanNode::CreateRole(plan) = PlanParser::parse(TEST_QUERY, ctx.clone()).await? {
+            let executor = CreateRoleInterpreter::try_create(ctx.clone(), plan.clone())?;
+            let ret = executor.execute(None).await;
+            assert!(ret.is_err())
+        } else {
+            panic!()
+        }
+    }
+
+    {
+        static TEST_QUERY: &str = "CREATE ROLE IF NOT EXISTS 'role1'";
+        if let PlanNode::CreateRole(plan) = PlanParser::parse(TEST_QUERY, ctx.clone()).await? {
+            let executor = CreateRoleInterpreter::try_create(ctx.clone(), plan.clone())?;
+            executor.execute(None).await?;
+        } else {
+            panic!()
+        }
+    }
+
+    let user_mgr = ctx.get_sessions_manager().get_user_manager();
+    let role = user_mgr.get_role("role1").await?;
+    assert_eq!(role.name, "role1");
+
+    Ok(())
+}
@@ -0,0 +1,105 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_exception::Result;
+use common_meta_types::AuthType;
+use common_meta_types::RoleInfo;
+use common_meta_types::UserInfo;
+use common_meta_types::UserPrivilegeType;
+use common_planners::*;
+use databend_query::interpreters::*;
+use databend_query::sql::*;
+use futures::stream::StreamExt;
+use pretty_assertions::assert_eq;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_grant_role_interpreter() -> Result<()> {
+    common_tracing::init_default_ut_tracing();
+
+    let ctx = crate::tests::create_query_context()?;
+    let user_mgr = ctx.get_sessions_manager().get_user_manager();
+    user_mgr
+        .add_role(RoleInfo::new("role1".to_string()))
+        .await?;
+    user_mgr
+        .add_user(UserInfo::new(
+            "test".to_string(),
+            "localhost".to_string(),
+            Vec::from("test"),
+            AuthType::PlainText,
+        ))
+        .await?;
+
+    // Grant privileges to the role.
+    {
+        static TEST_QUERY: &str = "GRANT SELECT ON db1.* TO ROLE 'role1'";
+        if let PlanNode::GrantPrivilege(plan) = PlanParser::parse(TEST_QUERY, ctx.clone()).await? {
+            let executor = GrantPrivilegeInterpreter::try_create(ctx.clone(), plan.clone())?;
+            let mut stream = executor.execute(None).await?;
+            while let Some(_block) = stream.next().await {}
+            let role = user_mgr.get_role("role1").await?;
+            assert!(role.grants.verify_database_privilege(
+                "role1",
+                "%",
+                "db1",
+                UserPrivilegeType::Select
+            ));
+        } else {
+            panic!()
+        }
+    }
+
+    // Grant the role to the user.
+    {
+        static TEST_QUERY: &str = "GRANT ROLE 'role1' TO 'test'@'localhost'";
+        if let PlanNode::GrantRole(plan) = PlanParser::parse(TEST_QUERY, ctx.clone()).await? {
+            let executor = GrantRoleInterpreter::try_create(ctx.clone(), plan.clone())?;
+            assert_eq!(executor.name(), "GrantRoleInterpreter");
+            executor.execute(None).await?;
+            let user = user_mgr.get_user("test", "localhost").await?;
+            assert_eq!(user.roles, vec!["role1".to_string()]);
+        } else {
+            panic!()
+        }
+    }
+
+    // Grant an unknown role.
+    {
+        static TEST_QUERY: &str = "GRANT ROLE 'role2' TO 'test'@'localhost'";
+        if let PlanNode::GrantRole(plan) = PlanParser::parse(TEST_QUERY, ctx.clone()).await? {
+            let executor = GrantRoleInterpreter::try_create(ctx.clone(), plan.clone())?;
+            let ret = executor.execute(None).await;
+            assert!(ret.is_err())
+        } else {
+            panic!()
+        }
+    }
+
+    // Revoke the role from the user.
+    {
+        static TEST_QUERY: &str = "REVOKE ROLE 'role1' FROM 'test'@'localhost'";
+        if let PlanNode::RevokeRole(plan) = PlanParser::parse(TEST_QUERY, ctx.clone()).await? {
+            let executor = RevokeRoleInterpreter::try_create(ctx.clone(), plan.clone())?;
+            assert_eq!(executor.name(), "RevokeRoleInterpreter");
+            executor.execute(None).await?;
+            let user = user_mgr.get_user("test", "localhost").await?;
+            assert!(user.roles.is_empty());
+        } else {
+            panic!()
+        }
+    }
+
+    Ok(())
+}
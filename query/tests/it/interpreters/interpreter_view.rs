@@ -0,0 +1,163 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::*;
+use databend_query::interpreters::*;
+use futures::TryStreamExt;
+use pretty_assertions::assert_eq;
+
+use crate::tests::parse_query;
+
+#[tokio::test]
+async fn test_create_view_interpreter() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    // Create view.
+    {
+        static TEST_CREATE_QUERY: &str =
+            "CREATE VIEW default.v AS SELECT number FROM numbers(5) WHERE number > 2";
+
+        if let PlanNode::CreateView(plan) = parse_query(TEST_CREATE_QUERY, &ctx)? {
+            let executor = CreateViewInterpreter::try_create(ctx.clone(), plan.clone())?;
+            assert_eq!(executor.name(), "CreateViewInterpreter");
+            let stream = executor.execute(None).await?;
+            let result = stream.try_collect::<Vec<_>>().await?;
+            let expected = vec!["++", "++"];
+            common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+        } else {
+            panic!()
+        }
+    }
+
+    // Select from view.
+    {
+        let plan = parse_query("SELECT v.number FROM v ORDER BY number", &ctx)?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let expected = vec![
+            "+--------+",
+            "| number |",
+            "+--------+",
+            "| 3      |",
+            "| 4      |",
+            "+--------+",
+        ];
+        common_datablocks::assert_blocks_eq(expected, result.as_slice());
+    }
+
+    // Show create view.
+    {
+        if let PlanNode::ShowCreateTable(plan) = parse_query("SHOW CREATE TABLE v", &ctx)? {
+            let executor = ShowCreateTableInterpreter::try_create(ctx.clone(), plan.clone())?;
+            let stream = executor.execute(None).await?;
+            let result = stream.try_collect::<Vec<_>>().await?;
+            let expected = vec![
+                "+-------+-------------------------------------------------------------------+",
+                "| Table | Create Table                                                      |",
+                "+-------+-------------------------------------------------------------------+",
+                "| v     | CREATE VIEW `v` AS SELECT number FROM numbers(5) WHERE number > 2 |",
+                "+-------+-------------------------------------------------------------------+",
+            ];
+            common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+        } else {
+            panic!()
+        }
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_drop_view_interpreter() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    // Create table and view.
+    for query in [
+        "CREATE TABLE default.a(a bigint) Engine = Null",
+        "CREATE VIEW default.v AS SELECT a FROM a",
+    ] {
+        let plan = parse_query(query, &ctx)?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        let _ = executor.execute(None).await?;
+    }
+
+    // Drop view on a table.
+    {
+        if let PlanNode::DropView(plan) = parse_query("DROP VIEW a", &ctx)? {
+            let executor = DropViewInterpreter::try_create(ctx.clone(), plan.clone())?;
+            let res = executor.execute(None).await;
+            assert_eq!(
+                res.err().unwrap().code(),
+                ErrorCode::BadArguments("").code()
+            );
+        } else {
+            panic!()
+        }
+    }
+
+    // Drop view.
+    {
+        if let PlanNode::DropView(plan) = parse_query("DROP VIEW v", &ctx)? {
+            let executor = DropViewInterpreter::try_create(ctx.clone(), plan.clone())?;
+            assert_eq!(executor.name(), "DropViewInterpreter");
+            let stream = executor.execute(None).await?;
+            let result = stream.try_collect::<Vec<_>>().await?;
+            let expected = vec!["++", "++"];
+            common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+        } else {
+            panic!()
+        }
+    }
+
+    // Drop view if exists.
+    {
+        if let PlanNode::DropView(plan) = parse_query("DROP VIEW IF EXISTS v", &ctx)? {
+            let executor = DropViewInterpreter::try_create(ctx.clone(), plan.clone())?;
+            let _ = executor.execute(None).await?;
+        } else {
+            panic!()
+        }
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_recursive_view_interpreter() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    // The defining query is not validated here, the view refers to itself.
+    let plan = CreateViewPlan {
+        if_not_exists: false,
+        db: "default".to_string(),
+        viewname: "v".to_string(),
+        view_schema: DataSchemaRefExt::create(vec![DataField::new("a", DataType::Int64, false)]),
+        subquery: "SELECT a FROM default.v".to_string(),
+    };
+    let executor = CreateViewInterpreter::try_create(ctx.clone(), plan)?;
+    let _ = executor.execute(None).await?;
+
+    let res = parse_query("SELECT a FROM v", &ctx);
+    assert_eq!(
+        res.err().unwrap().code(),
+        ErrorCode::BadArguments("").code()
+    );
+
+    Ok(())
+}
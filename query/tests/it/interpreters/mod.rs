@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod access;
 mod interpreter_database_create;
 mod interpreter_database_drop;
 mod interpreter_describe_stage;
@@ -21,6 +22,8 @@ mod interpreter_grant_privilege;
 mod interpreter_insert;
 mod interpreter_interceptor;
 mod interpreter_revoke_previlege;
+mod interpreter_role_create;
+mod interpreter_role_grant;
 mod interpreter_select;
 mod interpreter_setting;
 mod interpreter_show_create_table;
@@ -32,4 +35,5 @@ mod interpreter_use_database;
 mod interpreter_user_alter;
 mod interpreter_user_create;
 mod interpreter_user_drop;
+mod interpreter_view;
 mod plan_scheduler;
@@ -200,6 +200,71 @@ async fn test_scatter_optimizer() -> Result<()> {
             \n            ReadDataSource: scan partitions: [1], scan schema: [number:UInt64], statistics: [read_rows: 1, read_bytes: 8], push_downs: [projections: [0]]\
             \n        ReadDataSource: scan partitions: [1], scan schema: [number:UInt64], statistics: [read_rows: 1, read_bytes: 8], push_downs: [projections: [0]]",
         },
+        Test {
+            name: "Standalone join query",
+            query: "SELECT a.number FROM numbers_local(1) a JOIN numbers_local(1) b ON a.number = b.number",
+            expect: "\
+            Projection: a.number:UInt64\
+            \n  Join: INNER, keys=[[a.number]] = [[b.number]]\
+            \n    Projection: number as a.number:UInt64\
+            \n      ReadDataSource: scan partitions: [1], scan schema: [number:UInt64], statistics: [read_rows: 1, read_bytes: 8], push_downs: [projections: [0]]\
+            \n    Projection: number as b.number:UInt64\
+            \n      ReadDataSource: scan partitions: [1], scan schema: [number:UInt64], statistics: [read_rows: 1, read_bytes: 8], push_downs: [projections: [0]]",
+        },
+        Test {
+            name: "Cluster join query with small right table",
+            query: "SELECT a.number FROM numbers(100000000) a JOIN numbers(1) b ON a.number = b.number",
+            expect: "\
+            RedistributeStage[expr: 0]\
+            \n  Projection: a.number:UInt64\
+            \n    Join: INNER, keys=[[a.number]] = [[b.number]]\
+            \n      Projection: number as a.number:UInt64\
+            \n        ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 100000000, read_bytes: 800000000], push_downs: [projections: [0]]\
+            \n      Broadcast in cluster\
+            \n        Projection: number as b.number:UInt64\
+            \n          ReadDataSource: scan partitions: [1], scan schema: [number:UInt64], statistics: [read_rows: 1, read_bytes: 8], push_downs: [projections: [0]]",
+        },
+        Test {
+            name: "Cluster join query with large right table",
+            query: "SELECT a.number FROM numbers(100000000) a JOIN numbers(100000000) b ON a.number = b.number",
+            expect: "\
+            RedistributeStage[expr: 0]\
+            \n  Projection: a.number:UInt64\
+            \n    Join: INNER, keys=[[a.number]] = [[b.number]]\
+            \n      RedistributeStage[expr: sipHash(a.number)]\
+            \n        Projection: number as a.number:UInt64\
+            \n          ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 100000000, read_bytes: 800000000], push_downs: [projections: [0]]\
+            \n      RedistributeStage[expr: sipHash(b.number)]\
+            \n        Projection: number as b.number:UInt64\
+            \n          ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 100000000, read_bytes: 800000000], push_downs: [projections: [0]]",
+        },
+        Test {
+            name: "Cluster right join query with small right table",
+            query: "SELECT a.number FROM numbers(100000000) a RIGHT JOIN numbers(1) b ON a.number = b.number",
+            expect: "\
+            RedistributeStage[expr: 0]\
+            \n  Projection: a.number:UInt64\
+            \n    Join: RIGHT OUTER, keys=[[a.number]] = [[b.number]]\
+            \n      RedistributeStage[expr: sipHash(a.number)]\
+            \n        Projection: number as a.number:UInt64\
+            \n          ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 100000000, read_bytes: 800000000], push_downs: [projections: [0]]\
+            \n      RedistributeStage[expr: sipHash(b.number)]\
+            \n        Projection: number as b.number:UInt64\
+            \n          ReadDataSource: scan partitions: [1], scan schema: [number:UInt64], statistics: [read_rows: 1, read_bytes: 8], push_downs: [projections: [0]]",
+        },
+        Test {
+            name: "Cluster cross join query",
+            query: "SELECT a.number FROM numbers(100000000) a CROSS JOIN numbers(100000000) b",
+            expect: "\
+            Projection: a.number:UInt64\
+            \n  Join: CROSS\
+            \n    RedistributeStage[expr: 0]\
+            \n      Projection: number as a.number:UInt64\
+            \n        ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 100000000, read_bytes: 800000000], push_downs: [projections: [0]]\
+            \n    RedistributeStage[expr: 0]\
+            \n      Projection: number as b.number:UInt64\
+            \n        ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 100000000, read_bytes: 800000000], push_downs: [projections: [0]]",
+        },
     ];
 
     for test in tests {
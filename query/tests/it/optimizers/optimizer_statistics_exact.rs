@@ -50,6 +50,7 @@ fn test_statistics_exact_optimizer() -> Result<()> {
         ),
         tbl_args: None,
         push_downs: None,
+        source_table: None,
     });
 
     let aggr_expr = Expression::AggregateFunction {
@@ -44,6 +44,18 @@ fn create_u8(d: u8) -> Option<DataColumnWithField> {
     Some(DataColumnWithField::new(data_column, data_field))
 }
 
+fn create_u32(d: u32) -> Option<DataColumnWithField> {
+    let data_field = DataField::new("x", DataType::UInt32, false);
+    let data_column = DataColumn::Constant(DataValue::UInt32(Some(d)), 1);
+    Some(DataColumnWithField::new(data_column, data_field))
+}
+
+fn create_i64(d: i64) -> Option<DataColumnWithField> {
+    let data_field = DataField::new("x", DataType::Int64, false);
+    let data_column = DataColumn::Constant(DataValue::Int64(Some(d)), 1);
+    Some(DataColumnWithField::new(data_column, data_field))
+}
+
 fn create_datetime(d: u32) -> Option<DataColumnWithField> {
     let data_field = DataField::new("x", DataType::DateTime32(None), false);
     let data_column = DataColumn::Constant(DataValue::UInt32(Some(d)), 1);
@@ -610,6 +622,151 @@ fn test_dates_function() -> Result<()> {
             },
             error: "",
         },
+        Test {
+            name: "f(x) = toUnixTimestamp(x)",
+            expr: Expression::create_scalar_function("toUnixTimestamp", vec![col("x")]),
+            column: "x",
+            left: create_datetime(1638288000),
+            right: create_datetime(1638288059),
+            expect_mono: Monotonicity {
+                is_monotonic: true,
+                is_positive: true,
+                is_constant: false,
+                left: create_u32(1638288000),
+                right: create_u32(1638288059),
+            },
+            error: "",
+        },
+        Test {
+            name: "f(x) = addDays(x, 3)",
+            expr: Expression::create_scalar_function("addDays", vec![col("x"), lit(3i32)]),
+            column: "x",
+            left: create_datetime(1638288000),
+            right: create_datetime(1638288059),
+            expect_mono: Monotonicity {
+                is_monotonic: true,
+                is_positive: true,
+                is_constant: false,
+                left: create_datetime(1638547200),
+                right: create_datetime(1638547259),
+            },
+            error: "",
+        },
+        Test {
+            name: "f(x) = subtractDays(x, 3)",
+            expr: Expression::create_scalar_function("subtractDays", vec![col("x"), lit(3i32)]),
+            column: "x",
+            left: create_datetime(1638288000),
+            right: create_datetime(1638288059),
+            expect_mono: Monotonicity {
+                is_monotonic: true,
+                is_positive: true,
+                is_constant: false,
+                left: create_datetime(1638028800),
+                right: create_datetime(1638028859),
+            },
+            error: "",
+        },
+        Test {
+            name: "f(x) = addDays(1638288000, x)",
+            expr: Expression::create_scalar_function("addDays", vec![
+                Expression::create_literal_with_type(
+                    DataValue::UInt32(Some(1638288000)),
+                    DataType::DateTime32(None),
+                ),
+                col("x"),
+            ]),
+            column: "x",
+            left: create_i64(1),
+            right: create_i64(3),
+            expect_mono: Monotonicity {
+                is_monotonic: true,
+                is_positive: true,
+                is_constant: false,
+                left: create_datetime(1638374400),
+                right: create_datetime(1638547200),
+            },
+            error: "",
+        },
+        Test {
+            name: "f(x) = subtractDays(1638288000, x)",
+            expr: Expression::create_scalar_function("subtractDays", vec![
+                Expression::create_literal_with_type(
+                    DataValue::UInt32(Some(1638288000)),
+                    DataType::DateTime32(None),
+                ),
+                col("x"),
+            ]),
+            column: "x",
+            left: create_i64(1),
+            right: create_i64(3),
+            expect_mono: Monotonicity {
+                is_monotonic: true,
+                is_positive: false,
+                is_constant: false,
+                left: create_datetime(1638201600),
+                right: create_datetime(1638028800),
+            },
+            error: "",
+        },
+        Test {
+            name: "f(x) = date_trunc('month', x)",
+            expr: Expression::create_scalar_function("date_trunc", vec![
+                lit("month".as_bytes()),
+                col("x"),
+            ]),
+            column: "x",
+            left: create_datetime(1636502400),
+            right: create_datetime(1639008000),
+            expect_mono: Monotonicity {
+                is_monotonic: true,
+                is_positive: true,
+                is_constant: false,
+                left: create_datetime(1635724800),
+                right: create_datetime(1638316800),
+            },
+            error: "",
+        },
+        Test {
+            name: "f(x) = dateDiff('day', x, 1638288000)",
+            expr: Expression::create_scalar_function("dateDiff", vec![
+                lit("day".as_bytes()),
+                col("x"),
+                Expression::create_literal_with_type(
+                    DataValue::UInt32(Some(1638288000)),
+                    DataType::DateTime32(None),
+                ),
+            ]),
+            column: "x",
+            left: create_datetime(1638028800),
+            right: create_datetime(1638288000),
+            expect_mono: Monotonicity {
+                is_monotonic: true,
+                is_positive: false,
+                is_constant: false,
+                left: create_i64(3),
+                right: create_i64(0),
+            },
+            error: "",
+        },
+        Test {
+            name: "f(x) = formatDateTime(x, '%Y')",
+            expr: Expression::create_scalar_function("formatDateTime", vec![
+                col("x"),
+                lit("%Y".as_bytes()),
+            ]),
+            column: "x",
+            left: None,
+            right: None,
+            expect_mono: Monotonicity {
+                is_monotonic: false,
+                is_positive: true,
+                is_constant: false,
+                left: None,
+                right: None,
+            },
+            error: "",
+        },
     ];
 
     for t in test_suite.into_iter() {
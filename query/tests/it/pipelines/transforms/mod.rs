@@ -23,3 +23,4 @@ mod transform_limit_by;
 mod transform_projection;
 mod transform_sort;
 mod transform_source;
+mod transform_window;
@@ -0,0 +1,111 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_exception::Result;
+use common_planners::*;
+use databend_query::pipelines::processors::*;
+use databend_query::pipelines::transforms::*;
+use futures::TryStreamExt;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_transform_window() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+    let test_source = crate::tests::NumberTestData::create(ctx.clone());
+
+    // Pipeline.
+    let mut pipeline = Pipeline::create(ctx.clone());
+    let a = test_source.number_source_transform_for_test(8)?;
+    pipeline.add_source(Arc::new(a))?;
+
+    let window_func = Expression::WindowFunction {
+        op: "sum".to_string(),
+        params: vec![],
+        args: vec![col("number")],
+        partition_by: vec![],
+        order_by: vec![sort("number", true, false)],
+        window_frame: Some(WindowFrame::try_create(
+            WindowFrameUnits::Rows,
+            WindowFrameBound::Preceding(Some(1)),
+            Some(WindowFrameBound::CurrentRow),
+        )?),
+    };
+
+    let input_schema = test_source.number_schema_for_test()?;
+    let plan = PlanBuilder::create(input_schema.clone())
+        .window(window_func.clone())?
+        .build()?;
+    let sort_exprs = window_sort_exprs(&window_func)?;
+
+    pipeline.add_simple_transform(|| {
+        Ok(Box::new(SortPartialTransform::try_create(
+            input_schema.clone(),
+            sort_exprs.clone(),
+            None,
+        )?))
+    })?;
+
+    pipeline.add_simple_transform(|| {
+        Ok(Box::new(SortMergeTransform::try_create(
+            input_schema.clone(),
+            sort_exprs.clone(),
+            None,
+        )?))
+    })?;
+
+    if pipeline.last_pipe()?.nums() > 1 {
+        pipeline.merge_processor()?;
+        pipeline.add_simple_transform(|| {
+            Ok(Box::new(SortMergeTransform::try_create(
+                input_schema.clone(),
+                sort_exprs.clone(),
+                None,
+            )?))
+        })?;
+    }
+
+    pipeline.add_simple_transform(|| {
+        Ok(Box::new(WindowTransform::try_create(
+            window_func.clone(),
+            plan.schema(),
+            input_schema.clone(),
+        )?))
+    })?;
+
+    // Result.
+    let stream = pipeline.execute().await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let block = &result[0];
+    assert_eq!(block.num_columns(), 2);
+
+    let expected = vec![
+        "+--------+-----------------------------------------------------------------------------+",
+        "| number | sum(number) OVER (ORDER BY number ROWS BETWEEN 1 PRECEDING AND CURRENT ROW) |",
+        "+--------+-----------------------------------------------------------------------------+",
+        "| 0      | 0                                                                           |",
+        "| 1      | 1                                                                           |",
+        "| 2      | 3                                                                           |",
+        "| 3      | 5                                                                           |",
+        "| 4      | 7                                                                           |",
+        "| 5      | 9                                                                           |",
+        "| 6      | 11                                                                          |",
+        "| 7      | 13                                                                          |",
+        "+--------+-----------------------------------------------------------------------------+",
+    ];
+    common_datablocks::assert_blocks_eq(expected, result.as_slice());
+
+    Ok(())
+}
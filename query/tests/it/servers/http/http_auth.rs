@@ -0,0 +1,159 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_exception::Result;
+use common_meta_types::AuthType;
+use databend_query::servers::http::v1::statement_handler;
+use databend_query::sessions::SessionManager;
+use databend_query::users::User;
+use hyper::header;
+use jwt_simple::prelude::*;
+use poem::http::Method;
+use poem::http::StatusCode;
+use poem::post;
+use poem::Endpoint;
+use poem::EndpointExt;
+use poem::Request;
+use poem::Route;
+use pretty_assertions::assert_eq;
+
+use crate::tests::SessionManagerBuilder;
+
+#[tokio::test]
+async fn test_http_basic_auth() -> Result<()> {
+    let sessions = SessionManagerBuilder::create().build()?;
+
+    // No authorization header.
+    {
+        let status = test_auth(&sessions, None).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+
+    // root: without password from a non-local host.
+    {
+        let status = test_auth(&sessions, Some("Basic cm9vdDo=")).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+
+    // user1:pass
+    {
+        let user_info = User::new("user1", "%", "pass", AuthType::PlainText);
+        sessions
+            .get_user_manager()
+            .add_user(user_info.into())
+            .await?;
+        let status = test_auth(&sessions, Some("Basic dXNlcjE6cGFzcw==")).await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    // user1:wrong
+    {
+        let status = test_auth(&sessions, Some("Basic dXNlcjE6d3Jvbmc=")).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+
+    // unknown:pass
+    {
+        let status = test_auth(&sessions, Some("Basic dW5rbm93bjpwYXNz")).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+
+    // Bearer authorization is disabled without jwt secret.
+    {
+        let key = HS256Key::from_bytes(b"jwt-secret");
+        let token = create_token(&key, "root")?;
+        let authorization = format!("Bearer {}", token);
+        let status = test_auth(&sessions, Some(&authorization)).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_http_jwt_auth() -> Result<()> {
+    let sessions = SessionManagerBuilder::create()
+        .http_handler_jwt_secret("jwt-secret")
+        .build()?;
+
+    {
+        let key = HS256Key::from_bytes(b"jwt-secret");
+        let token = create_token(&key, "root")?;
+        let authorization = format!("Bearer {}", token);
+        let status = test_auth(&sessions, Some(&authorization)).await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    // Signed by another key.
+    {
+        let key = HS256Key::from_bytes(b"other-secret");
+        let token = create_token(&key, "root")?;
+        let authorization = format!("Bearer {}", token);
+        let status = test_auth(&sessions, Some(&authorization)).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+
+    // Bad token.
+    {
+        let status = test_auth(&sessions, Some("Bearer xxx")).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+
+    // Token without expiration.
+    {
+        let key = HS256Key::from_bytes(b"jwt-secret");
+        let mut claims = Claims::create(Duration::from_hours(1)).with_subject("root");
+        claims.expires_at = None;
+        let authorization = format!("Bearer {}", key.authenticate(claims)?);
+        let status = test_auth(&sessions, Some(&authorization)).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+
+    // Expired token.
+    {
+        let key = HS256Key::from_bytes(b"jwt-secret");
+        let mut claims = Claims::create(Duration::from_hours(1)).with_subject("root");
+        claims.expires_at = Some(Clock::now_since_epoch() - Duration::from_hours(1));
+        let authorization = format!("Bearer {}", key.authenticate(claims)?);
+        let status = test_auth(&sessions, Some(&authorization)).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+
+    Ok(())
+}
+
+fn create_token(key: &HS256Key, user: &str) -> Result<String> {
+    let claims = Claims::create(Duration::from_hours(1)).with_subject(user);
+    Ok(key.authenticate(claims)?)
+}
+
+async fn test_auth(sessions: &Arc<SessionManager>, authorization: Option<&str>) -> StatusCode {
+    let path = "/v1/statement";
+    let route = Route::new()
+        .at(path, post(statement_handler))
+        .data(sessions.clone());
+
+    let mut request = Request::builder()
+        .uri(path.parse().unwrap())
+        .method(Method::POST);
+    if let Some(authorization) = authorization {
+        request = request.header(header::AUTHORIZATION, authorization);
+    }
+
+    let response = route.call(request.body("select 1")).await;
+    response.status()
+}
@@ -16,6 +16,7 @@ use std::sync::Arc;
 
 use common_base::tokio;
 use common_exception::Result;
+use common_meta_types::AuthType;
 use databend_query::servers::http::v1::make_final_uri;
 use databend_query::servers::http::v1::make_page_uri;
 use databend_query::servers::http::v1::make_state_uri;
@@ -23,6 +24,7 @@ use databend_query::servers::http::v1::query_route;
 use databend_query::servers::http::v1::ExecuteStateName;
 use databend_query::servers::http::v1::QueryResponse;
 use databend_query::sessions::SessionManager;
+use databend_query::users::User;
 use hyper::header;
 use poem::http::Method;
 use poem::http::StatusCode;
@@ -192,6 +194,44 @@ async fn test_insert() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_query_owner() -> Result<()> {
+    let sessions = SessionManagerBuilder::create().build()?;
+    let user_info = User::new("user1", "%", "pass", AuthType::PlainText);
+    sessions
+        .get_user_manager()
+        .add_user(user_info.into())
+        .await?;
+    let route = Route::new().nest("/v1/query", query_route()).data(sessions);
+
+    let json = serde_json::json!({"sql": "select * from numbers(10)"});
+    let (status, result) = post_json_to_router(&route, &json, 1).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    let query_id = result.id;
+
+    let uris = [
+        make_state_uri(&query_id),
+        get_page_uri(&query_id, 0, 1),
+        make_final_uri(&query_id),
+    ];
+    for uri in uris.iter() {
+        // Not authenticated.
+        let response = get_uri_with_auth(&route, uri, None).await;
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED, "{}", uri);
+
+        // user1:pass is not the owner of the query.
+        let response = get_uri_with_auth(&route, uri, Some("Basic dXNlcjE6cGFzcw==")).await;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN, "{}", uri);
+    }
+
+    // The owner can still read and delete the query.
+    let (status, result) = get_uri_checked(&route, &make_state_uri(&query_id)).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert_eq!(delete_query(&route, query_id).await, StatusCode::OK);
+
+    Ok(())
+}
+
 async fn delete_query(route: &RouteWithData, query_id: String) -> StatusCode {
     let uri = make_final_uri(&query_id);
     let resp = get_uri(route, &uri).await;
@@ -212,14 +252,21 @@ async fn check_response(response: Response) -> Result<(StatusCode, QueryResponse
 }
 
 async fn get_uri(route: &RouteWithData, uri: &str) -> Response {
-    route
-        .call(
-            Request::builder()
-                .uri(uri.parse().unwrap())
-                .method(Method::GET)
-                .finish(),
-        )
-        .await
+    get_uri_with_auth(route, uri, Some("Basic cm9vdDo=")).await
+}
+
+async fn get_uri_with_auth(
+    route: &RouteWithData,
+    uri: &str,
+    authorization: Option<&str>,
+) -> Response {
+    let mut request = Request::builder()
+        .uri(uri.parse().unwrap())
+        .method(Method::GET);
+    if let Some(authorization) = authorization {
+        request = request.header(header::AUTHORIZATION, authorization);
+    }
+    route.call(request.finish()).await
 }
 async fn get_uri_checked(route: &RouteWithData, uri: &str) -> Result<(StatusCode, QueryResponse)> {
     let response = get_uri(route, uri).await;
@@ -260,6 +307,7 @@ async fn post_json_to_router(
                 .uri(uri.parse().unwrap())
                 .method(Method::POST)
                 .header(header::CONTENT_TYPE, content_type)
+                .header(header::AUTHORIZATION, "Basic cm9vdDo=")
                 .body(body),
         )
         .await;
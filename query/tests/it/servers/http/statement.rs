@@ -16,6 +16,7 @@ use common_base::tokio;
 use common_exception::Result;
 use databend_query::servers::http::v1::statement_handler;
 use databend_query::servers::http::v1::QueryResponse;
+use hyper::header;
 use poem::http::Method;
 use poem::http::StatusCode;
 use poem::post;
@@ -80,6 +81,7 @@ async fn test_sql(
             Request::builder()
                 .uri(uri.parse().unwrap())
                 .method(Method::POST)
+                .header(header::AUTHORIZATION, "Basic cm9vdDo=")
                 .body(sql),
         )
         .await;
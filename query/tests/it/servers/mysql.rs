@@ -48,6 +48,46 @@ async fn test_use_database_with_on_query() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_prepared_statement_with_on_execute() -> Result<()> {
+    let mut handler =
+        MySQLHandler::create(SessionManagerBuilder::create().max_sessions(1).build()?);
+
+    let listening = "0.0.0.0:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+    let mut connection = create_connection(runnable_server.port())?;
+
+    let statement = connection
+        .prep("SELECT ? + 1, ?, '?'")
+        .map_err_to_code(ErrorCode::UnknownException, || "Prepare error")?;
+    assert_eq!(statement.num_params(), 2);
+    assert_eq!(statement.num_columns(), 3);
+
+    let received_data: Vec<(u64, String, String)> = connection
+        .exec(&statement, (1, "databend"))
+        .map_err_to_code(ErrorCode::UnknownException, || "Execute error")?;
+    assert_eq!(received_data, vec![(
+        2,
+        "databend".to_string(),
+        "?".to_string()
+    )]);
+
+    let received_data: Vec<(u64, String, String)> = connection
+        .exec(&statement, (2, "it's"))
+        .map_err_to_code(ErrorCode::UnknownException, || "Execute error")?;
+    assert_eq!(received_data, vec![(
+        3,
+        "it's".to_string(),
+        "?".to_string()
+    )]);
+
+    connection
+        .close(statement)
+        .map_err_to_code(ErrorCode::UnknownException, || "Close error")?;
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_rejected_session_with_sequence() -> Result<()> {
     let mut handler =
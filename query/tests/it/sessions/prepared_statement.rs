@@ -0,0 +1,159 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_datavalues::DataValue;
+use common_exception::Result;
+use databend_query::interpreters::InterpreterFactory;
+use databend_query::sessions::PreparedStatement;
+use databend_query::sql::PlanParser;
+use futures::TryStreamExt;
+
+#[test]
+fn test_prepared_statement_bind() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        query: &'static str,
+        params: Vec<&'static str>,
+        expect: &'static str,
+    }
+
+    let tests = vec![
+        Test {
+            name: "no-params",
+            query: "SELECT 1",
+            params: vec![],
+            expect: "SELECT 1",
+        },
+        Test {
+            name: "params",
+            query: "SELECT ? + ? FROM t WHERE a = ?",
+            params: vec!["1", "2.5", "'x'"],
+            expect: "SELECT 1 + 2.5 FROM t WHERE a = 'x'",
+        },
+        Test {
+            name: "quoted-placeholders",
+            query: "SELECT '?', `?`, \"?\", 'it''s?', ?",
+            params: vec!["NULL"],
+            expect: "SELECT '?', `?`, \"?\", 'it''s?', NULL",
+        },
+        Test {
+            name: "escaped-quotes",
+            query: "SELECT 'it\\'s?', \"a\\\"?\", ?",
+            params: vec!["1"],
+            expect: "SELECT 'it\\'s?', \"a\\\"?\", 1",
+        },
+        Test {
+            name: "comments",
+            query: "SELECT ? -- why?\n, /* what? */ ? # how?",
+            params: vec!["1", "2"],
+            expect: "SELECT 1 -- why?\n, /* what? */ 2 # how?",
+        },
+        Test {
+            name: "double-minus",
+            query: "SELECT ?--?",
+            params: vec!["1", "2"],
+            expect: "SELECT 1--2",
+        },
+        Test {
+            name: "double-minus-negative",
+            query: "SELECT 5--?",
+            params: vec!["1"],
+            expect: "SELECT 5--1",
+        },
+        Test {
+            name: "double-minus-comment",
+            query: "SELECT 5-- ?\n, ?",
+            params: vec!["1"],
+            expect: "SELECT 5-- ?\n, 1",
+        },
+        Test {
+            name: "trailing-placeholder",
+            query: "SELECT * FROM t LIMIT ?",
+            params: vec!["3"],
+            expect: "SELECT * FROM t LIMIT 3",
+        },
+    ];
+
+    for t in tests {
+        let statement = PreparedStatement::create(t.query);
+        assert_eq!(t.params.len(), statement.num_params(), "{}", t.name);
+
+        let params = t.params.iter().map(|x| x.to_string()).collect::<Vec<_>>();
+        let actual = statement.bind(&params)?;
+        assert_eq!(t.expect, actual, "{}", t.name);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_prepared_statement_bind_mismatch() -> Result<()> {
+    let statement = PreparedStatement::create("SELECT ?, ?");
+
+    let result = statement.bind(&["1".to_string()]);
+    assert!(result.is_err());
+    assert_eq!(
+        "Code: 6, displayText = Prepared statement expects 2 parameters, but got 1.",
+        result.unwrap_err().to_string()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_prepared_statement_bind_string() -> Result<()> {
+    let value = "it\\'s 'quoted'\\";
+    let literal = PreparedStatement::string_literal(value);
+    assert_eq!("'it\\\\\\'s \\'quoted\\'\\\\'", literal);
+
+    // The bound value is parsed back as it is.
+    let statement = PreparedStatement::create("SELECT ?");
+    let query = statement.bind(&[literal])?;
+
+    let ctx = crate::tests::create_query_context()?;
+    let plan = PlanParser::parse(&query, ctx.clone()).await?;
+    let executor = InterpreterFactory::get(ctx, plan)?;
+    let stream = executor.execute(None).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    assert_eq!(
+        DataValue::String(Some(value.as_bytes().to_vec())),
+        result[0].column(0).try_get(0)?
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_prepared_statement_bind_binary() -> Result<()> {
+    let value = vec![0u8, 0xff, b'\'', 0xc3];
+    let literal = PreparedStatement::binary_literal(&value);
+    assert_eq!("unhex('00ff27c3')", literal);
+
+    // The bound value is parsed back as it is.
+    let statement = PreparedStatement::create("SELECT ?");
+    let query = statement.bind(&[literal])?;
+
+    let ctx = crate::tests::create_query_context()?;
+    let plan = PlanParser::parse(&query, ctx.clone()).await?;
+    let executor = InterpreterFactory::get(ctx, plan)?;
+    let stream = executor.execute(None).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    assert_eq!(
+        DataValue::String(Some(value)),
+        result[0].column(0).try_get(0)?
+    );
+
+    Ok(())
+}
@@ -13,10 +13,20 @@
 // limitations under the License.
 
 use common_base::tokio;
+use common_exception::Result;
 use common_mem_allocator::malloc_size;
+use common_meta_types::AuthType;
+use common_meta_types::GrantObject;
+use common_meta_types::RoleInfo;
+use common_meta_types::UserPrivilegeSet;
+use common_meta_types::UserPrivilegeType;
 use databend_query::configs::Config;
 use databend_query::sessions::Session;
 use databend_query::sessions::SessionManager;
+use databend_query::users::User;
+
+use crate::tests::create_query_context;
+use crate::tests::SessionManagerBuilder;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_session_mem_usage() {
@@ -37,3 +47,65 @@ async fn test_session_mem_usage() {
     assert!(session_size > 3000);
     assert_eq!(session_size, session.get_memory_usage());
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_session_user_quota() -> Result<()> {
+    let ctx = create_query_context()?;
+
+    let mut user_info = ctx.get_current_user()?;
+    user_info.quota.max_memory_in_bytes = 1024 * 1024 * 1024;
+    ctx.get_session().set_current_user(user_info);
+
+    let runtime = ctx.get_shared_runtime()?;
+    let memory_tracker = runtime.get_tracker();
+    assert_eq!(
+        memory_tracker.get_memory_tracker().get_memory_limit(),
+        1024 * 1024 * 1024
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_session_reload_current_roles() -> Result<()> {
+    let sessions = SessionManagerBuilder::create().build()?;
+    let user_mgr = sessions.get_user_manager();
+    user_mgr
+        .add_role(RoleInfo::new("role1".to_string()))
+        .await?;
+    user_mgr
+        .add_user(User::new("user1", "%", "pass", AuthType::PlainText).into())
+        .await?;
+    user_mgr.grant_user_role("user1", "%", "role1").await?;
+
+    let session = sessions.create_session("TestSession")?;
+    session
+        .set_authed_user(user_mgr.get_user("user1", "%").await?)
+        .await?;
+
+    // grant privileges to the activated role.
+    {
+        let mut privileges = UserPrivilegeSet::empty();
+        privileges.set_privilege(UserPrivilegeType::Create);
+        user_mgr
+            .grant_role_privileges("role1", GrantObject::Global, privileges)
+            .await?;
+
+        let ctx = session.create_context().await?;
+        let roles = ctx.get_current_roles();
+        assert_eq!(roles.len(), 1);
+        assert!(roles[0]
+            .grants
+            .verify_global_privilege("role1", "%", UserPrivilegeType::Create));
+    }
+
+    // revoke the activated role from the user.
+    {
+        user_mgr.revoke_user_role("user1", "%", "role1").await?;
+
+        let ctx = session.create_context().await?;
+        assert!(ctx.get_current_roles().is_empty());
+    }
+
+    Ok(())
+}
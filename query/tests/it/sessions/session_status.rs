@@ -21,6 +21,7 @@ use common_meta_types::AuthType;
 use common_meta_types::UserInfo;
 use databend_query::clusters::Cluster;
 use databend_query::sessions::MutableStatus;
+use databend_query::sessions::PreparedStatement;
 use databend_query::sessions::QueryContextShared;
 
 use crate::tests::SessionManagerBuilder;
@@ -101,5 +102,24 @@ fn test_session_status() -> Result<()> {
         assert!(val.is_none());
     }
 
+    // Prepared statements.
+    {
+        let id1 = mutable_status.add_prepared_statement(PreparedStatement::create("SELECT ?"));
+        let id2 = mutable_status.add_prepared_statement(PreparedStatement::create("SELECT 1"));
+        assert_ne!(id1, id2);
+
+        let val = mutable_status.get_prepared_statement(id1).unwrap();
+        assert_eq!("SELECT ?", val.query());
+
+        let val = mutable_status.remove_prepared_statement(id1);
+        assert!(val.is_some());
+
+        let val = mutable_status.get_prepared_statement(id1);
+        assert!(val.is_none());
+
+        let val = mutable_status.get_prepared_statement(id2);
+        assert!(val.is_some());
+    }
+
     Ok(())
 }
@@ -137,6 +137,18 @@ fn test_plan_parser() -> Result<()> {
             expect: "Projection: 12:Interval(YearMonth), 1:Interval(YearMonth), 86400000:Interval(DayTime), 3600000:Interval(DayTime), 60000:Interval(DayTime), 1000:Interval(DayTime)\n  Expression: 12:Interval(YearMonth), 1:Interval(YearMonth), 86400000:Interval(DayTime), 3600000:Interval(DayTime), 60000:Interval(DayTime), 1000:Interval(DayTime) (Before Projection)\n    ReadDataSource: scan partitions: [1], scan schema: [dummy:UInt8], statistics: [read_rows: 1, read_bytes: 1], push_downs: [projections: [0]]",
             error: "",
         },
+        Test {
+            name: "interval-literal-passed",
+            sql: "SELECT INTERVAL '2 days', INTERVAL '1 HOUR'",
+            expect: "Projection: 172800000:Interval(DayTime), 3600000:Interval(DayTime)\n  Expression: 172800000:Interval(DayTime), 3600000:Interval(DayTime) (Before Projection)\n    ReadDataSource: scan partitions: [1], scan schema: [dummy:UInt8], statistics: [read_rows: 1, read_bytes: 1], push_downs: [projections: [0]]",
+            error: "",
+        },
+        Test {
+            name: "interval-literal-unsupported-unit",
+            sql: "SELECT INTERVAL '1 week'",
+            expect: "",
+            error: "Code: 5, displayText = Unsupported interval unit: week (while in analyze select projection).",
+        },
         // Test {
         //     name: "interval-unsupported",
         //     sql: "SELECT INTERVAL '1 year 1 day'",
@@ -20,29 +20,42 @@ use common_meta_types::Compression;
 use common_meta_types::Credentials;
 use common_meta_types::FileFormat;
 use common_meta_types::Format;
+use common_meta_types::PrincipalIdentity;
 use common_meta_types::StageParams;
 use common_meta_types::UserIdentity;
 use common_meta_types::UserPrivilegeSet;
 use common_meta_types::UserPrivilegeType;
+use databend_query::sql::statements::DfAlterTable;
+use databend_query::sql::statements::DfAlterTableAction;
 use databend_query::sql::statements::DfAlterUser;
 use databend_query::sql::statements::DfCopy;
 use databend_query::sql::statements::DfCreateDatabase;
+use databend_query::sql::statements::DfCreateRole;
 use databend_query::sql::statements::DfCreateStage;
 use databend_query::sql::statements::DfCreateTable;
 use databend_query::sql::statements::DfCreateUser;
+use databend_query::sql::statements::DfCreateView;
+use databend_query::sql::statements::DfDeleteStatement;
 use databend_query::sql::statements::DfDescribeTable;
 use databend_query::sql::statements::DfDropDatabase;
+use databend_query::sql::statements::DfDropRole;
 use databend_query::sql::statements::DfDropTable;
 use databend_query::sql::statements::DfDropUser;
+use databend_query::sql::statements::DfDropView;
 use databend_query::sql::statements::DfGrantObject;
+use databend_query::sql::statements::DfGrantRole;
 use databend_query::sql::statements::DfGrantStatement;
 use databend_query::sql::statements::DfQueryStatement;
+use databend_query::sql::statements::DfRevokeRole;
 use databend_query::sql::statements::DfRevokeStatement;
+use databend_query::sql::statements::DfSetRole;
 use databend_query::sql::statements::DfShowDatabases;
 use databend_query::sql::statements::DfShowGrants;
 use databend_query::sql::statements::DfShowTables;
 use databend_query::sql::statements::DfTruncateTable;
+use databend_query::sql::statements::DfUpdateStatement;
 use databend_query::sql::statements::DfUseDatabase;
+use databend_query::sql::statements::DfVacuumTable;
 use databend_query::sql::*;
 use sqlparser::ast::*;
 use sqlparser::dialect::GenericDialect;
@@ -271,6 +284,63 @@ fn drop_table() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn create_view() -> Result<()> {
+    let native_query = |sql: &str| {
+        let dialect = GenericDialect {};
+        let tokens = Tokenizer::new(&dialect, sql).tokenize().unwrap();
+        Box::new(Parser::new(tokens, &dialect).parse_query().unwrap())
+    };
+
+    {
+        let sql = "CREATE VIEW v1 AS SELECT a, b FROM t1 WHERE a > 1";
+        let expected = DfStatement::CreateView(DfCreateView {
+            if_not_exists: false,
+            name: ObjectName(vec![Ident::new("v1")]),
+            query: native_query("SELECT a, b FROM t1 WHERE a > 1"),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+    {
+        let sql = "CREATE VIEW IF NOT EXISTS db1.v1 AS SELECT count(*) FROM db2.t1";
+        let expected = DfStatement::CreateView(DfCreateView {
+            if_not_exists: true,
+            name: ObjectName(vec![Ident::new("db1"), Ident::new("v1")]),
+            query: native_query("SELECT count(*) FROM db2.t1"),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    expect_parse_err_contains(
+        "CREATE VIEW v1 SELECT a FROM t1",
+        "Expected AS, found: SELECT".to_string(),
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn drop_view() -> Result<()> {
+    {
+        let sql = "DROP VIEW v1";
+        let expected = DfStatement::DropView(DfDropView {
+            if_exists: false,
+            name: ObjectName(vec![Ident::new("v1")]),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+    {
+        let sql = "DROP VIEW IF EXISTS db1.v1";
+        let expected = DfStatement::DropView(DfDropView {
+            if_exists: true,
+            name: ObjectName(vec![Ident::new("db1"), Ident::new("v1")]),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    Ok(())
+}
+
 #[test]
 fn describe_table() -> Result<()> {
     {
@@ -456,6 +526,189 @@ fn truncate_table() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn delete_from() -> Result<()> {
+    expect_parse_ok(
+        "DELETE FROM t1",
+        DfStatement::Delete(DfDeleteStatement {
+            name: ObjectName(vec![Ident::new("t1")]),
+            selection: None,
+        }),
+    )?;
+
+    expect_parse_ok(
+        "DELETE FROM db1.t1 WHERE a > 1",
+        DfStatement::Delete(DfDeleteStatement {
+            name: ObjectName(vec![Ident::new("db1"), Ident::new("t1")]),
+            selection: Some(Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Ident::new("a"))),
+                op: BinaryOperator::Gt,
+                right: Box::new(Expr::Value(Value::Number("1".to_string(), false))),
+            }),
+        }),
+    )?;
+
+    expect_parse_err_contains("DELETE t1", "Expected FROM, found: t1".to_string())?;
+
+    Ok(())
+}
+
+#[test]
+fn update() -> Result<()> {
+    expect_parse_ok(
+        "UPDATE t1 SET a = 1",
+        DfStatement::Update(DfUpdateStatement {
+            name: ObjectName(vec![Ident::new("t1")]),
+            assignments: vec![(
+                Ident::new("a"),
+                Expr::Value(Value::Number("1".to_string(), false)),
+            )],
+            selection: None,
+        }),
+    )?;
+
+    expect_parse_ok(
+        "UPDATE db1.t1 SET a = 1, b = 'DE' WHERE a > 1",
+        DfStatement::Update(DfUpdateStatement {
+            name: ObjectName(vec![Ident::new("db1"), Ident::new("t1")]),
+            assignments: vec![
+                (
+                    Ident::new("a"),
+                    Expr::Value(Value::Number("1".to_string(), false)),
+                ),
+                (
+                    Ident::new("b"),
+                    Expr::Value(Value::SingleQuotedString("DE".to_string())),
+                ),
+            ],
+            selection: Some(Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Ident::new("a"))),
+                op: BinaryOperator::Gt,
+                right: Box::new(Expr::Value(Value::Number("1".to_string(), false))),
+            }),
+        }),
+    )?;
+
+    expect_parse_err_contains("UPDATE t1 a = 1", "Expected SET, found: a".to_string())?;
+
+    Ok(())
+}
+
+#[test]
+fn alter_table() -> Result<()> {
+    expect_parse_ok(
+        "ALTER TABLE t1 ADD COLUMN c2 INT",
+        DfStatement::AlterTable(DfAlterTable {
+            name: ObjectName(vec![Ident::new("t1")]),
+            action: DfAlterTableAction::AddColumn(make_column_def("c2", DataType::Int(None))),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "ALTER TABLE db1.t1 ADD c2 INT DEFAULT 1",
+        DfStatement::AlterTable(DfAlterTable {
+            name: ObjectName(vec![Ident::new("db1"), Ident::new("t1")]),
+            action: DfAlterTableAction::AddColumn(ColumnDef {
+                options: vec![ColumnOptionDef {
+                    name: None,
+                    option: ColumnOption::Default(Expr::Value(Value::Number(
+                        "1".to_string(),
+                        false,
+                    ))),
+                }],
+                ..make_column_def("c2", DataType::Int(None))
+            }),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "ALTER TABLE t1 DROP COLUMN c2",
+        DfStatement::AlterTable(DfAlterTable {
+            name: ObjectName(vec![Ident::new("t1")]),
+            action: DfAlterTableAction::DropColumn(Ident::new("c2")),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "ALTER TABLE t1 RENAME COLUMN c1 TO c2",
+        DfStatement::AlterTable(DfAlterTable {
+            name: ObjectName(vec![Ident::new("t1")]),
+            action: DfAlterTableAction::RenameColumn {
+                old_column: Ident::new("c1"),
+                new_column: Ident::new("c2"),
+            },
+        }),
+    )?;
+
+    expect_parse_ok(
+        "ALTER TABLE t1 RENAME TO t2",
+        DfStatement::AlterTable(DfAlterTable {
+            name: ObjectName(vec![Ident::new("t1")]),
+            action: DfAlterTableAction::RenameTable(ObjectName(vec![Ident::new("t2")])),
+        }),
+    )?;
+
+    let mut options = HashMap::new();
+    options.insert("comment".to_string(), "ab".to_string());
+    options.insert("location".to_string(), "/tmp".to_string());
+    expect_parse_ok(
+        "ALTER TABLE t1 SET OPTIONS (comment = 'ab', location = '/tmp')",
+        DfStatement::AlterTable(DfAlterTable {
+            name: ObjectName(vec![Ident::new("t1")]),
+            action: DfAlterTableAction::SetOptions(options),
+        }),
+    )?;
+
+    expect_parse_err_contains(
+        "ALTER TABLE t1 RENAME COLUMN c1 c2",
+        "Expected TO, found: c2".to_string(),
+    )?;
+
+    expect_parse_err_contains(
+        "ALTER TABLE t1 MODIFY c1 INT",
+        "Expected ADD, DROP, RENAME or SET after ALTER TABLE, found: MODIFY".to_string(),
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn vacuum_table() -> Result<()> {
+    expect_parse_ok(
+        "VACUUM TABLE t1",
+        DfStatement::VacuumTable(DfVacuumTable {
+            name: ObjectName(vec![Ident::new("t1")]),
+            retain_hours: 12,
+            dry_run: false,
+        }),
+    )?;
+
+    expect_parse_ok(
+        "VACUUM TABLE db1.t1 RETAIN 24 HOURS",
+        DfStatement::VacuumTable(DfVacuumTable {
+            name: ObjectName(vec![Ident::new("db1"), Ident::new("t1")]),
+            retain_hours: 24,
+            dry_run: false,
+        }),
+    )?;
+
+    expect_parse_ok(
+        "vacuum table t1 retain 0 hours dry run",
+        DfStatement::VacuumTable(DfVacuumTable {
+            name: ObjectName(vec![Ident::new("t1")]),
+            retain_hours: 0,
+            dry_run: true,
+        }),
+    )?;
+
+    expect_parse_err_contains(
+        "VACUUM TABLE t1 RETAIN 24",
+        "Expected HOURS, found: EOF".to_string(),
+    )?;
+
+    Ok(())
+}
+
 #[test]
 fn hint_test() -> Result<()> {
     {
@@ -914,8 +1167,10 @@ fn grant_privilege_test() -> Result<()> {
     expect_parse_ok(
         "GRANT ALL ON * TO 'test'@'localhost'",
         DfStatement::GrantPrivilege(DfGrantStatement {
-            name: String::from("test"),
-            hostname: String::from("localhost"),
+            principal: PrincipalIdentity::User(UserIdentity {
+                username: String::from("test"),
+                hostname: String::from("localhost"),
+            }),
             on: DfGrantObject::Database(None),
             priv_types: UserPrivilegeSet::all_privileges(),
         }),
@@ -924,8 +1179,10 @@ fn grant_privilege_test() -> Result<()> {
     expect_parse_ok(
         "GRANT ALL PRIVILEGES ON * TO 'test'@'localhost'",
         DfStatement::GrantPrivilege(DfGrantStatement {
-            name: String::from("test"),
-            hostname: String::from("localhost"),
+            principal: PrincipalIdentity::User(UserIdentity {
+                username: String::from("test"),
+                hostname: String::from("localhost"),
+            }),
             on: DfGrantObject::Database(None),
             priv_types: UserPrivilegeSet::all_privileges(),
         }),
@@ -934,8 +1191,10 @@ fn grant_privilege_test() -> Result<()> {
     expect_parse_ok(
         "GRANT INSERT ON `db1`.`tb1` TO 'test'@'localhost'",
         DfStatement::GrantPrivilege(DfGrantStatement {
-            name: String::from("test"),
-            hostname: String::from("localhost"),
+            principal: PrincipalIdentity::User(UserIdentity {
+                username: String::from("test"),
+                hostname: String::from("localhost"),
+            }),
             on: DfGrantObject::Table(Some("db1".into()), "tb1".into()),
             priv_types: {
                 let mut privileges = UserPrivilegeSet::empty();
@@ -948,8 +1207,10 @@ fn grant_privilege_test() -> Result<()> {
     expect_parse_ok(
         "GRANT INSERT ON `tb1` TO 'test'@'localhost'",
         DfStatement::GrantPrivilege(DfGrantStatement {
-            name: String::from("test"),
-            hostname: String::from("localhost"),
+            principal: PrincipalIdentity::User(UserIdentity {
+                username: String::from("test"),
+                hostname: String::from("localhost"),
+            }),
             on: DfGrantObject::Table(None, "tb1".into()),
             priv_types: {
                 let mut privileges = UserPrivilegeSet::empty();
@@ -962,8 +1223,10 @@ fn grant_privilege_test() -> Result<()> {
     expect_parse_ok(
         "GRANT INSERT ON `db1`.'*' TO 'test'@'localhost'",
         DfStatement::GrantPrivilege(DfGrantStatement {
-            name: String::from("test"),
-            hostname: String::from("localhost"),
+            principal: PrincipalIdentity::User(UserIdentity {
+                username: String::from("test"),
+                hostname: String::from("localhost"),
+            }),
             on: DfGrantObject::Database(Some("db1".into())),
             priv_types: {
                 let mut privileges = UserPrivilegeSet::empty();
@@ -976,8 +1239,10 @@ fn grant_privilege_test() -> Result<()> {
     expect_parse_ok(
         "GRANT CREATE, SELECT ON * TO 'test'@'localhost'",
         DfStatement::GrantPrivilege(DfGrantStatement {
-            name: String::from("test"),
-            hostname: String::from("localhost"),
+            principal: PrincipalIdentity::User(UserIdentity {
+                username: String::from("test"),
+                hostname: String::from("localhost"),
+            }),
             on: DfGrantObject::Database(None),
             priv_types: {
                 let mut privileges = UserPrivilegeSet::empty();
@@ -988,6 +1253,36 @@ fn grant_privilege_test() -> Result<()> {
         }),
     )?;
 
+    expect_parse_ok(
+        "GRANT DROP, GRANT ON *.* TO 'test'@'localhost'",
+        DfStatement::GrantPrivilege(DfGrantStatement {
+            principal: PrincipalIdentity::User(UserIdentity {
+                username: String::from("test"),
+                hostname: String::from("localhost"),
+            }),
+            on: DfGrantObject::Global,
+            priv_types: {
+                let mut privileges = UserPrivilegeSet::empty();
+                privileges.set_privilege(UserPrivilegeType::Drop);
+                privileges.set_privilege(UserPrivilegeType::Grant);
+                privileges
+            },
+        }),
+    )?;
+
+    expect_parse_ok(
+        "GRANT SELECT ON `db1`.* TO ROLE 'role1'",
+        DfStatement::GrantPrivilege(DfGrantStatement {
+            principal: PrincipalIdentity::Role(String::from("role1")),
+            on: DfGrantObject::Database(Some("db1".into())),
+            priv_types: {
+                let mut privileges = UserPrivilegeSet::empty();
+                privileges.set_privilege(UserPrivilegeType::Select);
+                privileges
+            },
+        }),
+    )?;
+
     expect_parse_err(
         "GRANT TEST, ON * TO 'test'@'localhost'",
         String::from("sql parser error: Expected privilege type, found: TEST"),
@@ -1021,8 +1316,19 @@ fn revoke_privilege_test() -> Result<()> {
     expect_parse_ok(
         "REVOKE ALL ON * FROM 'test'@'localhost'",
         DfStatement::RevokePrivilege(DfRevokeStatement {
-            username: String::from("test"),
-            hostname: String::from("localhost"),
+            principal: PrincipalIdentity::User(UserIdentity {
+                username: String::from("test"),
+                hostname: String::from("localhost"),
+            }),
+            on: DfGrantObject::Database(None),
+            priv_types: UserPrivilegeSet::all_privileges(),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "REVOKE ALL ON * FROM ROLE 'role1'",
+        DfStatement::RevokePrivilege(DfRevokeStatement {
+            principal: PrincipalIdentity::Role(String::from("role1")),
             on: DfGrantObject::Database(None),
             priv_types: UserPrivilegeSet::all_privileges(),
         }),
@@ -1036,6 +1342,70 @@ fn revoke_privilege_test() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn role_test() -> Result<()> {
+    expect_parse_ok(
+        "CREATE ROLE 'role1'",
+        DfStatement::CreateRole(DfCreateRole {
+            if_not_exists: false,
+            name: String::from("role1"),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "CREATE ROLE IF NOT EXISTS 'role1'",
+        DfStatement::CreateRole(DfCreateRole {
+            if_not_exists: true,
+            name: String::from("role1"),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "DROP ROLE IF EXISTS 'role1'",
+        DfStatement::DropRole(DfDropRole {
+            if_exists: true,
+            name: String::from("role1"),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "GRANT ROLE 'role1' TO 'test'@'localhost'",
+        DfStatement::GrantRole(DfGrantRole {
+            role: String::from("role1"),
+            username: String::from("test"),
+            hostname: String::from("localhost"),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "REVOKE ROLE 'role1' FROM 'test'",
+        DfStatement::RevokeRole(DfRevokeRole {
+            role: String::from("role1"),
+            username: String::from("test"),
+            hostname: String::from("%"),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "SET ROLE 'role1'",
+        DfStatement::SetRole(DfSetRole {
+            role: Some(String::from("role1")),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "SET ROLE ALL",
+        DfStatement::SetRole(DfSetRole { role: None }),
+    )?;
+
+    expect_parse_err(
+        "GRANT ROLE 'role1' 'test'@'localhost'",
+        String::from("sql parser error: Expected keyword TO, found: 'test'"),
+    )?;
+
+    Ok(())
+}
+
 #[test]
 fn create_stage_test() -> Result<()> {
     expect_parse_ok(
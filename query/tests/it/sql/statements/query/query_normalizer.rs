@@ -139,3 +139,57 @@ async fn test_query_normalizer() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_query_normalizer_array_join() -> Result<()> {
+    struct TestCase {
+        name: &'static str,
+        query: &'static str,
+        error: &'static str,
+    }
+
+    let tests = vec![
+        TestCase {
+            name: "arrayJoin in projection",
+            query: "SELECT arrayJoin([1, 2]) AS a FROM numbers(2) ORDER BY a",
+            error: "",
+        },
+        TestCase {
+            name: "arrayJoin in filter",
+            query: "SELECT number FROM numbers(2) WHERE arrayJoin([1, 2]) = 1",
+            error: "Code: 5, displayText = WHERE cannot contain arrayJoin functions, they are only supported in the SELECT list (while in analyze select filter).",
+        },
+        TestCase {
+            name: "unnest in group by",
+            query: "SELECT count() FROM numbers(2) GROUP BY unnest([1, 2])",
+            error: "Code: 5, displayText = GROUP BY cannot contain arrayJoin functions, they are only supported in the SELECT list (while in analyze select group by).",
+        },
+        TestCase {
+            name: "arrayJoin in having",
+            query: "SELECT number FROM numbers(2) HAVING arrayJoin([1, 2]) = 1",
+            error: "Code: 5, displayText = HAVING cannot contain arrayJoin functions, they are only supported in the SELECT list (while in analyze select having).",
+        },
+        TestCase {
+            name: "arrayJoin in order by",
+            query: "SELECT number FROM numbers(2) ORDER BY arrayJoin([1, 2])",
+            error: "Code: 5, displayText = ORDER BY cannot contain arrayJoin functions, they are only supported in the SELECT list (while in analyze select order by).",
+        },
+    ];
+
+    for test_case in &tests {
+        let ctx = create_query_context()?;
+        let (mut statements, _) = DfParser::parse_sql(test_case.query)?;
+
+        match statements.remove(0) {
+            DfStatement::Query(query) => match QueryNormalizer::normalize(ctx, &query).await {
+                Ok(_) => assert_eq!("", test_case.error, "{:#?}", test_case.name),
+                Err(e) => assert_eq!(test_case.error, e.to_string(), "{:#?}", test_case.name),
+            },
+            _ => {
+                return Err(ErrorCode::LogicalError("Cannot get analyze query state."));
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -49,6 +49,21 @@ async fn test_joined_schema_analyzer() -> Result<()> {
             query: "SELECT * FROM (SELECT * FROM system.databases)",
             expect: "QuerySchema { short_names: [\"name\"] }",
         },
+        TestCase {
+            name: "Cross join query",
+            query: "SELECT * FROM system.databases, system.one",
+            expect: "QuerySchema { short_names: [\"name\", \"dummy\"] }",
+        },
+        TestCase {
+            name: "Join query with alias",
+            query: "SELECT * FROM system.databases a JOIN system.databases b ON a.name = b.name",
+            expect: "QuerySchema { ambiguity_names: [[\"a\", \"name\"], [\"b\", \"name\"]] }",
+        },
+        TestCase {
+            name: "Left join query with using",
+            query: "SELECT * FROM system.databases LEFT JOIN system.tables USING(name)",
+            expect: "QuerySchema { short_names: [\"database\", \"engine\"], ambiguity_names: [[\"system\", \"databases\", \"name\"], [\"system\", \"tables\", \"name\"]] }",
+        },
     ];
 
     for test_case in &tests {
@@ -52,6 +52,7 @@ fn test_to_partitions() -> Result<()> {
             location: "".to_string(),
             meta_size: 0,
         },
+        file_size: 0,
     };
 
     let blocks_metas = (0..num_of_block)
@@ -14,12 +14,20 @@
 //
 
 use common_base::tokio;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_planners::col;
+use common_planners::lit;
+use common_planners::DeletePlan;
+use common_planners::Extras;
 use common_planners::ReadDataSourcePlan;
 use common_planners::TruncateTablePlan;
+use common_planners::VacuumTablePlan;
 use databend_query::catalogs::Catalog;
 use databend_query::interpreters::InterpreterFactory;
 use databend_query::sql::PlanParser;
+use databend_query::storages::fuse::meta::TableSnapshot;
+use databend_query::storages::fuse::FUSE_TBL_SNAPSHOT_PREFIX;
 use databend_query::storages::fuse::TBL_OPT_KEY_CHUNK_BLOCK_NUM;
 use databend_query::storages::ToReadDataSourcePlan;
 use futures::TryStreamExt;
@@ -69,6 +77,7 @@ async fn test_fuse_table_simple_case() -> Result<()> {
             description: "".to_string(),
             tbl_args: None,
             push_downs: None,
+            source_table: None,
         })
         .await?;
     let blocks = stream.try_collect::<Vec<_>>().await?;
@@ -130,6 +139,7 @@ async fn test_fuse_table_simple_case() -> Result<()> {
             description: "".to_string(),
             tbl_args: None,
             push_downs: None,
+            source_table: None,
         })
         .await?;
     let blocks = stream.try_collect::<Vec<_>>().await?;
@@ -225,6 +235,117 @@ async fn test_fuse_table_truncate() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_fuse_table_storage_quota() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+
+    let create_table_plan = fixture.default_crate_table_plan();
+    let catalog = ctx.get_catalog();
+    catalog.create_table(create_table_plan.into()).await?;
+    let table = fixture.latest_default_table().await?;
+
+    // 1. commit exceeds the quota
+    let stream = TestFixture::gen_sample_blocks_stream(1, 1);
+    let r = table.append_data(ctx.clone(), stream).await?;
+    let operation_log = r.try_collect().await?;
+
+    let mut user_info = ctx.get_current_user()?;
+    user_info.quota.max_storage_in_bytes = 1;
+    ctx.get_session().set_current_user(user_info);
+
+    let r = table.commit(ctx.clone(), operation_log, false).await;
+    assert!(r.is_err());
+    assert_eq!(r.unwrap_err().code(), ErrorCode::QuotaExceededCode());
+
+    // 2. append exceeds the quota
+    let stream = TestFixture::gen_sample_blocks_stream(1, 1);
+    let r = table.append_data(ctx.clone(), stream).await?;
+    let r = r.try_collect::<Vec<_>>().await;
+    assert!(r.is_err());
+    assert_eq!(r.unwrap_err().code(), ErrorCode::QuotaExceededCode());
+
+    // nothing committed
+    let table = fixture.latest_default_table().await?;
+    let source_plan = table.read_plan(ctx.clone(), None).await?;
+    let (stats, parts) = table
+        .read_partitions(ctx.clone(), source_plan.push_downs.clone())
+        .await?;
+    assert_eq!(parts.len(), 0);
+    assert_eq!(stats.read_rows, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fuse_table_concurrent_commit() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+
+    let create_table_plan = fixture.default_crate_table_plan();
+    let catalog = ctx.get_catalog();
+    catalog.create_table(create_table_plan.into()).await?;
+
+    // two writers based on the same version of the table
+    let table_a = fixture.latest_default_table().await?;
+    let table_b = fixture.latest_default_table().await?;
+
+    let stream = TestFixture::gen_sample_blocks_stream(2, 1);
+    let r = table_a.append_data(ctx.clone(), stream).await?;
+    let operation_log_a = r.try_collect().await?;
+
+    let stream = TestFixture::gen_sample_blocks_stream(3, 1);
+    let r = table_b.append_data(ctx.clone(), stream).await?;
+    let operation_log_b = r.try_collect().await?;
+
+    // 1. the commit of b is rebased onto the snapshot committed by a
+    table_a.commit(ctx.clone(), operation_log_a, false).await?;
+    table_b.commit(ctx.clone(), operation_log_b, false).await?;
+
+    let table = fixture.latest_default_table().await?;
+    let (stats, parts) = table.read_partitions(ctx.clone(), None).await?;
+    assert_eq!(parts.len(), 5);
+    assert_eq!(stats.read_rows, 5 * 3);
+
+    // 2. conflicts are not resolved if retries are exhausted
+    ctx.get_settings().set_fuse_commit_max_retries(0)?;
+    let file_count = fixture.storage_file_count();
+    let stream = TestFixture::gen_sample_blocks_stream(1, 1);
+    let r = table_a.append_data(ctx.clone(), stream).await?;
+    let r = table_a
+        .commit(ctx.clone(), r.try_collect().await?, false)
+        .await;
+    assert!(r.is_err());
+    assert_eq!(r.unwrap_err().code(), ErrorCode::OCCRetryFailureCode());
+
+    // nothing committed, the appended blocks and segments are removed
+    let table = fixture.latest_default_table().await?;
+    let (_, parts) = table.read_partitions(ctx.clone(), None).await?;
+    assert_eq!(parts.len(), 5);
+    assert_eq!(fixture.storage_file_count(), file_count);
+
+    // 3. the blocks and segments written by a conflicting mutation are removed
+    let stale_table = fixture.latest_default_table().await?;
+    let stream = TestFixture::gen_sample_blocks_stream(1, 1);
+    let r = table.append_data(ctx.clone(), stream).await?;
+    table
+        .commit(ctx.clone(), r.try_collect().await?, false)
+        .await?;
+
+    let file_count = fixture.storage_file_count();
+    let delete_plan = DeletePlan {
+        db: "".to_string(),
+        table: "".to_string(),
+        selection: Some(col("id").eq(lit(1))),
+    };
+    let r = stale_table.delete(ctx.clone(), delete_plan).await;
+    assert!(r.is_err());
+    assert_eq!(r.unwrap_err().code(), ErrorCode::OCCRetryFailureCode());
+    assert_eq!(fixture.storage_file_count(), file_count);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_fuse_table_compact() -> Result<()> {
     let fixture = TestFixture::new().await;
@@ -283,3 +404,446 @@ async fn test_fuse_table_compact() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_fuse_table_vacuum() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+
+    let create_table_plan = fixture.default_crate_table_plan();
+    let catalog = ctx.get_catalog();
+    catalog.create_table(create_table_plan.into()).await?;
+
+    let vacuum_plan = |retain_hours: u64, dry_run: bool| VacuumTablePlan {
+        db: "".to_string(),
+        table: "".to_string(),
+        retain_hours,
+        dry_run,
+    };
+
+    // 1. vacuum empty table
+    let table = fixture.latest_default_table().await?;
+    let stats = table.vacuum(ctx.clone(), vacuum_plan(0, false)).await?;
+    assert_eq!(stats.removed_files, 0);
+
+    // 2. insert twice, then truncate
+    for _ in 0..2 {
+        let table = fixture.latest_default_table().await?;
+        let stream = TestFixture::gen_sample_blocks_stream(1, 1);
+        let r = table.append_data(ctx.clone(), stream).await?;
+        table
+            .commit(ctx.clone(), r.try_collect().await?, false)
+            .await?;
+    }
+    let table = fixture.latest_default_table().await?;
+    let truncate_plan = TruncateTablePlan {
+        db: "".to_string(),
+        table: "".to_string(),
+    };
+    table.truncate(ctx.clone(), truncate_plan).await?;
+
+    // 3. nothing expired within the default retention period
+    let table = fixture.latest_default_table().await?;
+    let stats = table.vacuum(ctx.clone(), vacuum_plan(12, false)).await?;
+    assert_eq!(stats.removed_files, 0);
+    assert_eq!(stats.removed_bytes, 0);
+
+    // 4. dry run reports, but removes nothing
+    let dry_run_stats = table.vacuum(ctx.clone(), vacuum_plan(0, true)).await?;
+    assert!(dry_run_stats.removed_files > 0);
+    assert!(dry_run_stats.removed_bytes > 0);
+    let stats = table.vacuum(ctx.clone(), vacuum_plan(0, true)).await?;
+    assert_eq!(stats, dry_run_stats);
+
+    // 5. vacuum removes what the dry run reported
+    let stats = table.vacuum(ctx.clone(), vacuum_plan(0, false)).await?;
+    assert_eq!(stats, dry_run_stats);
+    let stats = table.vacuum(ctx.clone(), vacuum_plan(0, false)).await?;
+    assert_eq!(stats.removed_files, 0);
+
+    // the current snapshot is still readable
+    let (stats, parts) = table.read_partitions(ctx.clone(), None).await?;
+    assert_eq!(parts.len(), 0);
+    assert_eq!(stats.read_rows, 0);
+
+    // 6. retain hours out of range
+    for retain_hours in [u64::MAX, i64::MAX as u64, i64::MAX as u64 / 3600] {
+        let res = table
+            .vacuum(ctx.clone(), vacuum_plan(retain_hours, false))
+            .await;
+        assert_eq!(
+            res.err().unwrap().code(),
+            ErrorCode::BadArguments("").code()
+        );
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fuse_table_vacuum_retains_snapshots_without_timestamp() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+
+    let create_table_plan = fixture.default_crate_table_plan();
+    let catalog = ctx.get_catalog();
+    catalog.create_table(create_table_plan.into()).await?;
+
+    for _ in 0..2 {
+        let table = fixture.latest_default_table().await?;
+        let stream = TestFixture::gen_sample_blocks_stream(1, 1);
+        let r = table.append_data(ctx.clone(), stream).await?;
+        table
+            .commit(ctx.clone(), r.try_collect().await?, false)
+            .await?;
+    }
+
+    // snapshots written by older versions have no timestamp
+    let da = ctx.get_data_accessor()?;
+    for object in da.list(FUSE_TBL_SNAPSHOT_PREFIX).await? {
+        let bytes = da.read(&object.path).await?;
+        let mut snapshot = serde_json::from_slice::<TableSnapshot>(&bytes)?;
+        snapshot.timestamp = None;
+        da.put(&object.path, serde_json::to_vec(&snapshot)?).await?;
+    }
+
+    // they are not known to be expired, nothing is removed
+    let table = fixture.latest_default_table().await?;
+    let vacuum_plan = VacuumTablePlan {
+        db: "".to_string(),
+        table: "".to_string(),
+        retain_hours: 0,
+        dry_run: false,
+    };
+    let stats = table.vacuum(ctx.clone(), vacuum_plan).await?;
+    assert_eq!(stats.removed_files, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fuse_table_vacuum_keeps_referenced_blocks() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+
+    let create_table_plan = fixture.default_crate_table_plan();
+    let tbl_name = create_table_plan.table.clone();
+    let db_name = create_table_plan.db.clone();
+    let catalog = ctx.get_catalog();
+    catalog.create_table(create_table_plan.into()).await?;
+
+    for _ in 0..3 {
+        let table = fixture.latest_default_table().await?;
+        let stream = TestFixture::gen_sample_blocks_stream(1, 1);
+        let r = table.append_data(ctx.clone(), stream).await?;
+        table
+            .commit(ctx.clone(), r.try_collect().await?, false)
+            .await?;
+    }
+
+    // only the expired snapshots can be removed, their segments and blocks are
+    // still referenced by the current snapshot
+    let query = format!("vacuum table {}.{} retain 0 hours", db_name, tbl_name);
+    let plan = PlanParser::parse(&query, ctx.clone()).await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+    let stream = interpreter.execute(None).await?;
+    let blocks = stream.try_collect::<Vec<_>>().await?;
+    assert_eq!(blocks.len(), 1);
+    // the first two snapshots
+    let removed_files = blocks[0].column(0).try_get(0)?.as_u64()?;
+    assert_eq!(removed_files, 2);
+
+    let table = fixture.latest_default_table().await?;
+    let (stats, parts) = table.read_partitions(ctx.clone(), None).await?;
+    assert_eq!(parts.len(), 3);
+    assert_eq!(stats.read_rows, 3 * 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fuse_table_delete() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+
+    let create_table_plan = fixture.default_crate_table_plan();
+    let tbl_name = create_table_plan.table.clone();
+    let db_name = create_table_plan.db.clone();
+    let catalog = ctx.get_catalog();
+    catalog.create_table(create_table_plan.into()).await?;
+
+    // 3 blocks: [1, 2, 3], [4, 5, 6], [7, 8, 9]
+    for start in [1, 4, 7] {
+        let table = fixture.latest_default_table().await?;
+        let stream = TestFixture::gen_sample_blocks_stream(1, start);
+        let r = table.append_data(ctx.clone(), stream).await?;
+        table
+            .commit(ctx.clone(), r.try_collect().await?, false)
+            .await?;
+    }
+
+    let execute = |query: String| {
+        let ctx = ctx.clone();
+        async move {
+            let plan = PlanParser::parse(&query, ctx.clone()).await?;
+            let interpreter = InterpreterFactory::get(ctx, plan)?;
+            interpreter
+                .execute(None)
+                .await?
+                .try_collect::<Vec<_>>()
+                .await
+        }
+    };
+
+    // 1. the first block is untouched, the second one is rewritten, the last one is removed
+    execute(format!("delete from {}.{} where id > 5", db_name, tbl_name)).await?;
+    let table = fixture.latest_default_table().await?;
+    let (stats, parts) = table.read_partitions(ctx.clone(), None).await?;
+    assert_eq!(parts.len(), 2);
+    assert_eq!(stats.read_rows, 5);
+
+    let query = format!("select * from {}.{} order by id", db_name, tbl_name);
+    let blocks = execute(query).await?;
+    let expected = vec![
+        "+----+", //
+        "| id |", //
+        "+----+", //
+        "| 1  |", //
+        "| 2  |", //
+        "| 3  |", //
+        "| 4  |", //
+        "| 5  |", //
+        "+----+", //
+    ];
+    common_datablocks::assert_blocks_eq(expected, blocks.as_slice());
+
+    // 2. nothing matches, no new snapshot is committed
+    let prev_version = table.get_table_info().ident.version;
+    execute(format!(
+        "delete from {}.{} where id = 100",
+        db_name, tbl_name
+    ))
+    .await?;
+    let table = fixture.latest_default_table().await?;
+    assert_eq!(prev_version, table.get_table_info().ident.version);
+
+    // 3. delete all
+    execute(format!("delete from {}.{}", db_name, tbl_name)).await?;
+    let table = fixture.latest_default_table().await?;
+    let (stats, parts) = table.read_partitions(ctx.clone(), None).await?;
+    assert_eq!(parts.len(), 0);
+    assert_eq!(stats.read_rows, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fuse_table_update() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+
+    let create_table_plan = fixture.default_crate_table_plan();
+    let tbl_name = create_table_plan.table.clone();
+    let db_name = create_table_plan.db.clone();
+    let catalog = ctx.get_catalog();
+    catalog.create_table(create_table_plan.into()).await?;
+
+    // 3 blocks: [1, 2, 3], [4, 5, 6], [7, 8, 9]
+    for start in [1, 4, 7] {
+        let table = fixture.latest_default_table().await?;
+        let stream = TestFixture::gen_sample_blocks_stream(1, start);
+        let r = table.append_data(ctx.clone(), stream).await?;
+        table
+            .commit(ctx.clone(), r.try_collect().await?, false)
+            .await?;
+    }
+
+    let execute = |query: String| {
+        let ctx = ctx.clone();
+        async move {
+            let plan = PlanParser::parse(&query, ctx.clone()).await?;
+            let interpreter = InterpreterFactory::get(ctx, plan)?;
+            interpreter
+                .execute(None)
+                .await?
+                .try_collect::<Vec<_>>()
+                .await
+        }
+    };
+
+    // 1. the first block is untouched, the other two are rewritten
+    execute(format!(
+        "update {}.{} set id = id + 10 where id > 5",
+        db_name, tbl_name
+    ))
+    .await?;
+    let table = fixture.latest_default_table().await?;
+    let (stats, parts) = table.read_partitions(ctx.clone(), None).await?;
+    assert_eq!(parts.len(), 3);
+    assert_eq!(stats.read_rows, 9);
+
+    let query = format!("select * from {}.{} order by id", db_name, tbl_name);
+    let blocks = execute(query).await?;
+    let expected = vec![
+        "+----+", //
+        "| id |", //
+        "+----+", //
+        "| 1  |", //
+        "| 2  |", //
+        "| 3  |", //
+        "| 4  |", //
+        "| 5  |", //
+        "| 16 |", //
+        "| 17 |", //
+        "| 18 |", //
+        "| 19 |", //
+        "+----+", //
+    ];
+    common_datablocks::assert_blocks_eq(expected, blocks.as_slice());
+
+    // 2. the statistics of the rewritten blocks are recomputed, so that they can be pruned
+    let extra = Extras {
+        filters: vec![col("id").gt(lit(15))],
+        ..Default::default()
+    };
+    let (_, parts) = table.read_partitions(ctx.clone(), Some(extra)).await?;
+    assert_eq!(parts.len(), 2);
+
+    // 3. nothing matches, no new snapshot is committed
+    let prev_version = table.get_table_info().ident.version;
+    execute(format!(
+        "update {}.{} set id = 0 where id = 100",
+        db_name, tbl_name
+    ))
+    .await?;
+    let table = fixture.latest_default_table().await?;
+    assert_eq!(prev_version, table.get_table_info().ident.version);
+
+    // 4. update all
+    execute(format!("update {}.{} set id = 0", db_name, tbl_name)).await?;
+    let blocks = execute(format!("select sum(id) as s from {}.{}", db_name, tbl_name)).await?;
+    let expected = vec![
+        "+---+", //
+        "| s |", //
+        "+---+", //
+        "| 0 |", //
+        "+---+", //
+    ];
+    common_datablocks::assert_blocks_eq(expected, blocks.as_slice());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fuse_table_alter() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+
+    let create_table_plan = fixture.default_crate_table_plan();
+    let tbl_name = create_table_plan.table.clone();
+    let db_name = create_table_plan.db.clone();
+    let catalog = ctx.get_catalog();
+    catalog.create_table(create_table_plan.into()).await?;
+
+    // 3 blocks: [1, 2, 3], [4, 5, 6], [7, 8, 9]
+    for start in [1, 4, 7] {
+        let table = fixture.latest_default_table().await?;
+        let stream = TestFixture::gen_sample_blocks_stream(1, start);
+        let r = table.append_data(ctx.clone(), stream).await?;
+        table
+            .commit(ctx.clone(), r.try_collect().await?, false)
+            .await?;
+    }
+
+    let execute = |query: String| {
+        let ctx = ctx.clone();
+        async move {
+            let plan = PlanParser::parse(&query, ctx.clone()).await?;
+            let interpreter = InterpreterFactory::get(ctx, plan)?;
+            interpreter
+                .execute(None)
+                .await?
+                .try_collect::<Vec<_>>()
+                .await
+        }
+    };
+
+    // 1. add column: existing blocks are not rewritten, the default value is filled while reading
+    execute(format!(
+        "alter table {}.{} add column c Int32 default 7",
+        db_name, tbl_name
+    ))
+    .await?;
+    let table = fixture.latest_default_table().await?;
+    assert_eq!(table.schema().fields().len(), 2);
+    let (stats, parts) = table.read_partitions(ctx.clone(), None).await?;
+    assert_eq!(parts.len(), 3);
+    assert_eq!(stats.read_rows, 9);
+
+    let query = format!(
+        "select sum(id) as s, sum(c) as t from {}.{}",
+        db_name, tbl_name
+    );
+    let blocks = execute(query).await?;
+    let expected = vec![
+        "+----+----+", //
+        "| s  | t  |", //
+        "+----+----+", //
+        "| 45 | 63 |", //
+        "+----+----+", //
+    ];
+    common_datablocks::assert_blocks_eq(expected, blocks.as_slice());
+
+    // 2. rename column
+    execute(format!(
+        "alter table {}.{} rename column id to k",
+        db_name, tbl_name
+    ))
+    .await?;
+    let blocks = execute(format!("select max(k) as m from {}.{}", db_name, tbl_name)).await?;
+    let expected = vec![
+        "+---+", //
+        "| m |", //
+        "+---+", //
+        "| 9 |", //
+        "+---+", //
+    ];
+    common_datablocks::assert_blocks_eq(expected, blocks.as_slice());
+
+    // 3. drop column: all the blocks are rewritten without the dropped column
+    execute(format!(
+        "alter table {}.{} drop column k",
+        db_name, tbl_name
+    ))
+    .await?;
+    let table = fixture.latest_default_table().await?;
+    assert_eq!(table.schema().fields().len(), 1);
+    assert_eq!(table.schema().field(0).name(), "c");
+    let blocks = execute(format!("select sum(c) as t from {}.{}", db_name, tbl_name)).await?;
+    let expected = vec![
+        "+----+", //
+        "| t  |", //
+        "+----+", //
+        "| 63 |", //
+        "+----+", //
+    ];
+    common_datablocks::assert_blocks_eq(expected, blocks.as_slice());
+
+    // 4. the only column left can not be dropped
+    let r = execute(format!(
+        "alter table {}.{} drop column c",
+        db_name, tbl_name
+    ))
+    .await;
+    assert!(r.is_err());
+
+    // 5. unknown column
+    let r = execute(format!(
+        "alter table {}.{} rename column x to y",
+        db_name, tbl_name
+    ))
+    .await;
+    assert_eq!(r.unwrap_err().code(), ErrorCode::UnknownColumn("").code());
+
+    Ok(())
+}
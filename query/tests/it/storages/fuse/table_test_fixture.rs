@@ -13,6 +13,7 @@
 //  limitations under the License.
 //
 
+use std::path::Path;
 use std::sync::Arc;
 
 use common_datablocks::DataBlock;
@@ -36,7 +37,7 @@ use tempfile::TempDir;
 use uuid::Uuid;
 
 pub struct TestFixture {
-    _tmp_dir: TempDir,
+    tmp_dir: TempDir,
     ctx: Arc<QueryContext>,
     prefix: String,
 }
@@ -67,7 +68,7 @@ impl TestFixture {
             .unwrap();
 
         Self {
-            _tmp_dir: tmp_dir,
+            tmp_dir,
             ctx,
             prefix: random_prefix,
         }
@@ -133,6 +134,24 @@ impl TestFixture {
             )
             .await
     }
+
+    /// Number of the files in the storage, the blocks, segments and snapshots of all the tables
+    pub fn storage_file_count(&self) -> usize {
+        fn count(dir: &Path) -> usize {
+            std::fs::read_dir(dir)
+                .unwrap()
+                .map(|entry| {
+                    let path = entry.unwrap().path();
+                    if path.is_dir() {
+                        count(&path)
+                    } else {
+                        1
+                    }
+                })
+                .sum()
+        }
+        count(self.tmp_dir.path())
+    }
 }
 
 fn gen_db_name(prefix: &str) -> String {
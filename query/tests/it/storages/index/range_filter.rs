@@ -125,6 +125,25 @@ fn test_range_filter() -> Result<()> {
             ]),
             expect: true,
         },
+        Test {
+            name: "b in (1, 12)",
+            expr: Expression::create_scalar_function("in", vec![col("b"), lit(1), lit(12)]),
+            expect: false,
+        },
+        Test {
+            name: "a in (5, null)",
+            expr: Expression::create_scalar_function("in", vec![
+                col("a"),
+                lit(5),
+                Expression::create_literal(DataValue::Null),
+            ]),
+            expect: true,
+        },
+        Test {
+            name: "b not in (3, 4)",
+            expr: Expression::create_scalar_function("not in", vec![col("b"), lit(3), lit(4)]),
+            expect: true,
+        },
     ];
 
     for test in tests {
@@ -234,6 +253,25 @@ fn test_build_verifiable_function() -> Result<()> {
             ]),
             expect: "((min_c < sys) or (max_c >= syt))",
         },
+        Test {
+            name: "a in (1, 3)",
+            expr: Expression::create_scalar_function("in", vec![col("a"), lit(1), lit(3)]),
+            expect: "(((min_a <= 1) and (max_a >= 1)) or ((min_a <= 3) and (max_a >= 3)))",
+        },
+        Test {
+            name: "b not in (3, null)",
+            expr: Expression::create_scalar_function("not in", vec![
+                col("b"),
+                lit(3),
+                Expression::create_literal(DataValue::Null),
+            ]),
+            expect: "((min_b != 3) or (max_b != 3))",
+        },
+        Test {
+            name: "a in (b, 3)",
+            expr: Expression::create_scalar_function("in", vec![col("a"), col("b"), lit(3)]),
+            expect: "true",
+        },
         Test {
             name: "c not like 'sys%a'",
             expr: Expression::create_binary_expression("not like", vec![
@@ -38,7 +38,7 @@ async fn test_configs_table() -> Result<()> {
     let result = stream.try_collect::<Vec<_>>().await?;
     let block = &result[0];
     assert_eq!(block.num_columns(), 4);
-    assert_eq!(block.num_rows(), 35);
+    assert_eq!(block.num_rows(), 36);
 
     let expected = vec![
         "+-----------------------------------+------------------+-------+-------------+",
@@ -53,6 +53,7 @@ async fn test_configs_table() -> Result<()> {
         "| flight_api_address                | 127.0.0.1:9090   | query |             |",
         "| http_api_address                  | 127.0.0.1:8080   | query |             |",
         "| http_handler_host                 | 127.0.0.1        | query |             |",
+        "| http_handler_jwt_secret           |                  | query |             |",
         "| http_handler_port                 | 8000             | query |             |",
         "| log_dir                           | ./_logs          | log   |             |",
         "| log_level                         | INFO             | log   |             |",
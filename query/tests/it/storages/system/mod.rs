@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod caches_table;
 mod clusters_table;
 mod columns_table;
 mod configs_table;
@@ -36,6 +36,7 @@ async fn test_tables_table() -> Result<()> {
         "+----------+--------------+--------------------+",
         "| database | name         | engine             |",
         "+----------+--------------+--------------------+",
+        "| system   | caches       | SystemCaches       |",
         "| system   | clusters     | SystemClusters     |",
         "| system   | columns      | SystemColumns      |",
         "| system   | configs      | SystemConfigs      |",
@@ -39,6 +39,7 @@ async fn test_users_table() -> Result<()> {
             auth_type: AuthType::None,
             grants: UserGrantSet::empty(),
             quota: UserQuota::no_limit(),
+            roles: vec![],
         })
         .await?;
     ctx.get_sessions_manager()
@@ -50,6 +51,7 @@ async fn test_users_table() -> Result<()> {
             auth_type: AuthType::PlainText,
             grants: UserGrantSet::empty(),
             quota: UserQuota::no_limit(),
+            roles: vec![],
         })
         .await?;
 
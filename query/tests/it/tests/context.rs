@@ -17,8 +17,10 @@ use std::sync::Arc;
 use common_exception::Result;
 use common_meta_embedded::MetaEmbedded;
 use common_meta_types::AuthType;
+use common_meta_types::GrantObject;
 use common_meta_types::NodeInfo;
 use common_meta_types::UserInfo;
+use common_meta_types::UserPrivilegeSet;
 use databend_query::catalogs::CatalogContext;
 use databend_query::clusters::Cluster;
 use databend_query::configs::Config;
@@ -30,18 +32,28 @@ use databend_query::storages::StorageFactory;
 
 use crate::tests::SessionManagerBuilder;
 
-pub fn create_query_context() -> Result<Arc<QueryContext>> {
-    let sessions = SessionManagerBuilder::create().build()?;
-    let dummy_session = sessions.create_session("TestSession")?;
-
-    // Set user.
-    let user_info = UserInfo::new(
+// The test user holds all privileges, so that plans pass the privilege check.
+fn create_test_user() -> UserInfo {
+    let mut user_info = UserInfo::new(
         "test_user".to_string(),
         "%".to_string(),
         Vec::from("pass"),
         AuthType::Sha256,
     );
-    dummy_session.set_current_user(user_info);
+    user_info.grants.grant_privileges(
+        "test_user",
+        "%",
+        &GrantObject::Global,
+        UserPrivilegeSet::all_privileges(),
+    );
+    user_info
+}
+
+pub fn create_query_context() -> Result<Arc<QueryContext>> {
+    let sessions = SessionManagerBuilder::create().build()?;
+    let dummy_session = sessions.create_session("TestSession")?;
+
+    dummy_session.set_current_user(create_test_user());
 
     let context = QueryContext::from_shared(QueryContextShared::try_create(
         sessions.get_conf().clone(),
@@ -56,6 +68,7 @@ pub fn create_query_context() -> Result<Arc<QueryContext>> {
 pub fn create_query_context_with_config(config: Config) -> Result<Arc<QueryContext>> {
     let sessions = SessionManagerBuilder::create().build()?;
     let dummy_session = sessions.create_session("TestSession")?;
+    dummy_session.set_current_user(create_test_user());
 
     let context = QueryContext::from_shared(QueryContextShared::try_create(
         config,
@@ -130,6 +143,7 @@ impl Default for ClusterDescriptor {
 pub fn create_query_context_with_cluster(desc: ClusterDescriptor) -> Result<Arc<QueryContext>> {
     let sessions = SessionManagerBuilder::create().build()?;
     let dummy_session = sessions.create_session("TestSession")?;
+    dummy_session.set_current_user(create_test_user());
 
     let local_id = desc.local_node_id;
     let nodes = desc.cluster_nodes_list;
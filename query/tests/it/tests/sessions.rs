@@ -84,6 +84,12 @@ impl SessionManagerBuilder {
         SessionManagerBuilder::inner_create(new_config)
     }
 
+    pub fn http_handler_jwt_secret(self, value: impl Into<String>) -> SessionManagerBuilder {
+        let mut new_config = self.config;
+        new_config.query.http_handler_jwt_secret = value.into();
+        SessionManagerBuilder::inner_create(new_config)
+    }
+
     pub fn disk_storage_path(self, path: String) -> SessionManagerBuilder {
         let mut new_config = self.config;
         new_config.storage.disk.data_path = path;
@@ -12,5 +12,6 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod role_mgr;
 mod user_mgr;
 mod user_stage;
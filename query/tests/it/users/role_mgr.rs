@@ -0,0 +1,103 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_exception::Result;
+use common_meta_types::AuthType;
+use common_meta_types::GrantObject;
+use common_meta_types::RoleInfo;
+use common_meta_types::UserPrivilegeSet;
+use common_meta_types::UserPrivilegeType;
+use databend_query::configs::Config;
+use databend_query::users::User;
+use databend_query::users::UserApiProvider;
+use pretty_assertions::assert_eq;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_role_manager() -> Result<()> {
+    let mut config = Config::default();
+    config.query.tenant_id = "tenant1".to_string();
+
+    let role = "role1";
+    let user = "test-user1";
+    let hostname = "localhost";
+    let user_mgr = UserApiProvider::create_global(config).await?;
+
+    // add role.
+    {
+        user_mgr.add_role(RoleInfo::new(role.to_string())).await?;
+        let roles = user_mgr.get_roles().await?;
+        assert_eq!(1, roles.len());
+        assert_eq!(role, roles[0].name);
+    }
+
+    // add the same role again.
+    {
+        let res = user_mgr.add_role(RoleInfo::new(role.to_string())).await;
+        // ErrorCode::RoleAlreadyExists
+        assert_eq!(res.err().unwrap().code(), 3005);
+    }
+
+    // grant role privileges.
+    {
+        let mut add_priv = UserPrivilegeSet::empty();
+        add_priv.set_privilege(UserPrivilegeType::Select);
+        user_mgr
+            .grant_role_privileges(role, GrantObject::Database("db1".to_string()), add_priv)
+            .await?;
+        let role_info = user_mgr.get_role(role).await?;
+        assert!(role_info.grants.verify_database_privilege(
+            role,
+            "%",
+            "db1",
+            UserPrivilegeType::Select
+        ));
+    }
+
+    // grant role to user.
+    {
+        let user_info = User::new(user, hostname, "", AuthType::None);
+        user_mgr.add_user(user_info.into()).await?;
+        user_mgr.grant_user_role(user, hostname, role).await?;
+        let user_info = user_mgr.get_user(user, hostname).await?;
+        assert_eq!(user_info.roles, vec![role.to_string()]);
+
+        // unknown role.
+        let res = user_mgr.grant_user_role(user, hostname, "role2").await;
+        // ErrorCode::UnknownRole
+        assert_eq!(res.err().unwrap().code(), 3004);
+    }
+
+    // revoke role from user.
+    {
+        user_mgr.revoke_user_role(user, hostname, role).await?;
+        let user_info = user_mgr.get_user(user, hostname).await?;
+        assert!(user_info.roles.is_empty());
+    }
+
+    // drop role.
+    {
+        user_mgr.drop_role(role, false).await?;
+        let roles = user_mgr.get_roles().await?;
+        assert!(roles.is_empty());
+
+        let res = user_mgr.drop_role(role, false).await;
+        assert!(res.is_err());
+
+        let res = user_mgr.drop_role(role, true).await;
+        assert!(res.is_ok());
+    }
+
+    Ok(())
+}
@@ -319,5 +319,38 @@ async fn test_user_manager_with_root_user() -> Result<()> {
         assert!(user.grants.entries().is_empty());
     }
 
+    // Get user via username `root` and the '%' host pattern.
+    {
+        let user = user_mgr.get_user(username2, "%").await?;
+        assert!(user.grants.entries().is_empty());
+    }
+
+    // Get user via username `root` from the local client address.
+    {
+        let user = user_mgr
+            .get_user_by_client_address(username2, "127.0.0.1:3307")
+            .await?;
+        assert_eq!(user.hostname, hostname2);
+        assert!(user.grants.verify_global_privilege(
+            username2,
+            hostname2,
+            UserPrivilegeType::Grant
+        ));
+
+        let user = user_mgr
+            .get_user_by_client_address(username2, "[::1]:3307")
+            .await?;
+        assert_eq!(user.hostname, hostname2);
+    }
+
+    // Get user via username `root` from the remote client address.
+    {
+        let user = user_mgr
+            .get_user_by_client_address(username2, "10.0.0.1:3307")
+            .await?;
+        assert_eq!(user.hostname, "10.0.0.1");
+        assert!(user.grants.entries().is_empty());
+    }
+
     Ok(())
 }
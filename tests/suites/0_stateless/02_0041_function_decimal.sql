@@ -0,0 +1,14 @@
+SELECT CAST('-1.25' AS DECIMAL(10, 2));
+SELECT CAST(CAST('3.14159' AS DECIMAL(10, 5)) AS DECIMAL(10, 2));
+SELECT CAST('1.23' AS DECIMAL(10, 2)) + CAST('2.5' AS DECIMAL(5, 1));
+SELECT CAST('1.23' AS DECIMAL(10, 2)) - 2;
+SELECT CAST('1.5' AS DECIMAL(10, 2)) * 2;
+SELECT CAST('10' AS DECIMAL(10, 2)) / CAST('4' AS DECIMAL(10, 2));
+SELECT CAST('10.5' AS DECIMAL(10, 2)) DIV 4;
+SELECT CAST('1.23' AS DECIMAL(10, 2)) > 1, CAST('1.23' AS DECIMAL(10, 2)) = CAST('1.230' AS DECIMAL(10, 3));
+SELECT toTypeName(CAST('1.23' AS DECIMAL(10, 2))), toTypeName(CAST('1.23' AS DECIMAL(38, 2)));
+SELECT toTypeName(CAST('1.23' AS DECIMAL(10, 2)) * CAST('1.23' AS DECIMAL(10, 2)));
+SELECT sum(CAST(number AS DECIMAL(10, 2))), avg(CAST(number AS DECIMAL(10, 2))) FROM numbers(5);
+SELECT CAST('99999999999999999999999999999999999999' AS DECIMAL(38, 0)) * 10; -- {ErrorCode 49}
+SELECT CAST('1.23' AS DECIMAL(10, 2)) / 0; -- {ErrorCode 6}
+SELECT CAST('1.23' AS DECIMAL(39, 2)); -- {ErrorCode 6}
@@ -0,0 +1,31 @@
+SELECT CAST('2021-08-30 10:47:42.123' AS DateTime64);
+SELECT toTypeName(CAST('2021-08-30 10:47:42.123' AS DateTime64));
+SELECT CAST('2021-08-30 10:47:42.123456' AS `DateTime64(6)`);
+SELECT CAST('2021-08-30 10:47:42.5' AS `DateTime64(0)`);
+SELECT toDateTime64('2021-08-30 10:47:42');
+
+SELECT '===timezone===';
+SELECT CAST('2021-08-30 10:47:42.123' AS `DateTime64(3, 'Asia/Shanghai')`);
+SELECT toHour(CAST('2021-08-30 10:47:42.123' AS `DateTime64(3, 'Asia/Shanghai')`));
+SELECT toUInt64(CAST('2021-08-30 10:47:42.123' AS `DateTime64(3, 'Asia/Shanghai')`));
+SELECT toYYYYMMDD(CAST('2021-08-30 23:00:00' AS `DateTime64(3, 'Asia/Shanghai')`));
+SELECT toStartOfHour(CAST('2021-08-30 10:47:42.123' AS `DateTime64(3, 'Asia/Kolkata')`));
+SELECT today('Asia/Shanghai') - today() <= 1;
+
+SELECT '===arithmetic===';
+SELECT CAST('2021-08-30 10:47:42.123' AS DateTime64) + 1;
+SELECT CAST('2021-08-30 10:47:42.123' AS DateTime64) - 60;
+SELECT CAST('2021-08-30 10:47:42.123' AS DateTime64) - CAST('2021-08-30 10:47:41' AS DateTime64);
+SELECT CAST('2021-08-30 10:47:42.123456' AS `DateTime64(6)`) - CAST('2021-08-30 10:47:41.1' AS DateTime64);
+SELECT addMonths(CAST('2021-01-31 10:47:42.123' AS DateTime64), 1);
+SELECT addHours(CAST('2021-08-30 10:47:42.123' AS DateTime64), 2);
+
+SELECT '===cast===';
+SELECT toDate(CAST('2021-08-30 10:47:42.123' AS DateTime64));
+SELECT toDateTime(CAST('2021-08-30 10:47:42.123' AS DateTime64));
+SELECT CAST(CAST('2021-08-30 10:47:42.123' AS DateTime64) AS `DateTime64(6)`);
+SELECT CAST(toDateTime(1630320462) AS DateTime64);
+SELECT CAST('2021-08-30 10:47:42' AS DateTime64) = CAST('2021-08-30 10:47:42.000' AS `DateTime64(6)`);
+
+SELECT CAST('2021-08-30 10:47:42' AS `DateTime64(10)`); -- {ErrorCode 6}
+SELECT CAST('2021-08-30 10:47:42' AS `DateTime64(3, 'Mars/Olympus')`); -- {ErrorCode 6}
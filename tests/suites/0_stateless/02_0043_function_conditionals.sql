@@ -0,0 +1,12 @@
+select case when number = 0 then 'zero' when number = 1 then 'one' else 'many' end from numbers(3) order by number;
+select case number when 1 then 10 end from numbers(3) order by number;
+select number in (1, 3) from numbers(4) order by number;
+select count(*) from numbers(10) where number not in (1, 2, 3);
+select number from numbers(5) where number in (select number + 2 from numbers(2)) order by number;
+select 1 in (2, null), 1 not in (2, null), 1 in (1, null), 1 not in (1, null);
+select count(*) from numbers(10) where number not in (1, null);
+select null in (select number from numbers(0)), null not in (select number from numbers(0));
+select coalesce(null, number, 1) from numbers(2) order by number;
+select nullIf(number, 1) from numbers(3) order by number;
+select ifNull(nullIf(number, 1), 100) from numbers(3) order by number;
+select extract(year from toDateTime(1633081817)), extract(month from toDateTime(1633081817)), extract(day from toDateTime(1633081817));
@@ -0,0 +1,8 @@
+SELECT lower('Hello, World!'), upper('Hello, World!'), lcase('ABC'), ucase('abc');
+SELECT regexp_like('Michael!', '.*'), regexp_like('a', '^[a-d]'), regexp_like('abc', 'ABC'), regexp_like('abc', 'ABC', 'i');
+SELECT regexp_instr('dog cat dog', 'dog'), regexp_instr('dog cat dog', 'dog', 2), regexp_instr('aa aaa aaaa', 'a{4}', 1, 1, 1);
+SELECT regexp_substr('abc def ghi', '[a-z]+', 1, 3), regexp_substr('abc', 'x') IS NULL;
+SELECT regexp_replace('a b c', 'b', 'X'), regexp_replace('abc def ghi', '[a-z]+', 'X', 1, 3), regexp_replace('a1b22', '([0-9]+)', '<$1>');
+SELECT split_part('a,b,c', ',', 2), split_part('a,b,c', ',', -1), split_part('a,b,c', ',', 4) = '';
+SELECT count(*) FROM numbers(20) WHERE regexp_like(toString(number), '^1');
+SELECT regexp_like(NULL, 'a'), regexp_replace('abc', NULL, 'x');
@@ -0,0 +1,47 @@
+select '===format===';
+select date_format(toDateTime(1630833797), '%Y-%m-%d %H:%i:%s');
+select date_format(toDate(18875), '%W, %M %D %Y');
+select date_format(toDateTime(1630833797), '%c/%e/%y %l:%i %p');
+select formatDateTime(toDateTime(1630833797), '%F %T Q%Q');
+select formatDateTime(toDate(18875), '%d/%m/%Y %u %j');
+
+select '===parse===';
+select str_to_date('05/09/2021 09:23', '%d/%m/%Y %H:%i');
+select str_to_date('September 5 2021', '%M %e %Y');
+select str_to_date('2021-09-05', '%d/%m/%Y') is null;
+select parseDateTimeBestEffort('2021-09-05 09:23:17');
+select parseDateTimeBestEffort('2021-09-05T11:23:17+02:00');
+select parseDateTimeBestEffort('Sun, 05 Sep 2021 09:23:17 GMT');
+select parseDateTimeBestEffort('20210905');
+select parseDateTimeBestEffort('1630833797');
+select parseDateTimeBestEffortOrNull('yesterday') is null;
+
+select '===diff===';
+select dateDiff('second', toDateTime(1630833797), toDateTime(1630833857));
+select dateDiff('minute', toDateTime(1630833797), toDateTime(1630833857));
+select dateDiff('day', toDate(18875), toDate(18880));
+select dateDiff('week', toDate(18875), toDate(18876));
+select dateDiff('month', toDateTime(1630833797), toDateTime(1640995200));
+select dateDiff('year', toDateTime(1640995200), toDateTime(1630833797));
+select dateDiff(toDate(18880), toDate(18875));
+
+select '===trunc===';
+select date_trunc('hour', toDateTime(1630833797));
+select date_trunc('day', toDateTime(1630833797));
+select date_trunc('week', toDate(18875));
+select date_trunc('month', toDateTime(1630833797));
+select date_trunc('quarter', toDate(18875));
+select date_trunc('year', toDateTime(1630833797));
+
+select '===unix===';
+select toUnixTimestamp(toDateTime(1630833797));
+select toUnixTimestamp(toDate(18875));
+select fromUnixTimestamp(1630833797);
+select from_unixtime(1630833797, '%Y%m%d');
+select toUnixTimestamp(fromUnixTimestamp(1630833797)) = 1630833797;
+
+select '===interval===';
+select addWeeks(toDate(18875), 2);
+select subtractWeeks(toDateTime(1630833797), 1);
+select addQuarters(toDate(18875), 2);
+select subtractQuarters(toDateTime(1630833797), 1);
@@ -0,0 +1,12 @@
+select [1, 2, 3], ['a', 'b'];
+select [1, 2, 3][2], [1, 2, 3][-1], [1, 2, 3][4];
+select length([1, 2, 3]), length('databend');
+select length(toString(number * 100)) from numbers(3) order by number;
+select has([1, 2, 3], 2), has([1, 2, 3], 4), indexOf(['a', 'b'], 'b');
+select arrayConcat([1, 2], [3]), arraySlice([1, 2, 3, 4, 5], 2, 3), arrayDistinct([1, 2, 1, 3]);
+select number, arrayJoin([1, 2]) from numbers(2) order by number;
+select count(*) from (select unnest([number, number + 1]) from numbers(3));
+select number from numbers(2) where arrayJoin([1, 2]) = 1; -- {ErrorCode 5}
+select number from numbers(2) order by unnest([1, 2]); -- {ErrorCode 5}
+select length(groupArray(number)), has(groupArray(number), 2) from numbers(3);
+select number % 2 as k, length(groupUniqArray(number % 3)) from numbers(10) group by k order by k;
@@ -0,0 +1,10 @@
+SELECT a.number, b.number FROM numbers(3) a JOIN numbers(4) b ON a.number = b.number ORDER BY a.number;
+SELECT a.number, b.number FROM numbers(4) a LEFT JOIN numbers(2) b ON a.number = b.number ORDER BY a.number;
+SELECT a.number, b.number FROM numbers(2) a RIGHT JOIN numbers(4) b ON a.number = b.number ORDER BY b.number;
+SELECT count(*), sum(a.number), sum(b.number) FROM numbers(3) a FULL JOIN (SELECT number + 2 AS number FROM numbers(3)) b ON a.number = b.number;
+SELECT count(*) FROM numbers(3) a, numbers(4) b;
+SELECT count(*) FROM numbers(3) a CROSS JOIN numbers(4) b;
+SELECT a.number FROM numbers(3) a JOIN numbers(5) b USING(number) ORDER BY a.number;
+SELECT a.number, b.number FROM numbers(3) a JOIN numbers(3) b ON a.number = b.number AND b.number > 0 ORDER BY a.number;
+SELECT count(*) FROM numbers(3) a JOIN numbers(3) b ON a.number < b.number;
+SELECT * FROM numbers(3) a JOIN numbers(3) a ON a.number = a.number; -- {ErrorCode 5}
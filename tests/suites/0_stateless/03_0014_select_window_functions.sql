@@ -0,0 +1,17 @@
+SELECT number, row_number() OVER (ORDER BY number DESC) FROM numbers(4) ORDER BY number;
+SELECT number, rank() OVER (ORDER BY number % 3), dense_rank() OVER (ORDER BY number % 3) FROM numbers(6) ORDER BY number;
+SELECT number, rank() OVER (PARTITION BY number % 2 ORDER BY number) FROM numbers(6) ORDER BY number;
+SELECT number, lag(number) OVER (ORDER BY number), lead(number, 2, 100) OVER (ORDER BY number) FROM numbers(4) ORDER BY number;
+SELECT number, sum(number) OVER (ORDER BY number) FROM numbers(5) ORDER BY number;
+SELECT number, sum(number) OVER (PARTITION BY number % 2) FROM numbers(4) ORDER BY number;
+SELECT number, sum(number) OVER (ORDER BY number ROWS BETWEEN 1 PRECEDING AND 1 FOLLOWING) FROM numbers(5) ORDER BY number;
+SELECT number, first_value(number) OVER (PARTITION BY number % 2 ORDER BY number), last_value(number) OVER (PARTITION BY number % 2 ORDER BY number ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING) FROM numbers(4) ORDER BY number;
+SELECT count(*) OVER () FROM numbers(3);
+-- partitions span several blocks
+SET max_block_size = 2;
+SELECT number % 3, number, sum(number) OVER (PARTITION BY number % 3 ORDER BY number) FROM numbers(9) ORDER BY number;
+SELECT number, sum(number) OVER (ORDER BY number ROWS BETWEEN 2 PRECEDING AND CURRENT ROW) FROM numbers(5) ORDER BY number;
+SET max_block_size = 10000;
+SELECT number FROM numbers(3) WHERE row_number() OVER (ORDER BY number) > 1; -- {ErrorCode 5}
+SELECT row_number(number) OVER (ORDER BY number) FROM numbers(3); -- {ErrorCode 28}
+SELECT sum(number) OVER (ORDER BY number GROUPS BETWEEN 1 PRECEDING AND CURRENT ROW) FROM numbers(3); -- {ErrorCode 2}
@@ -0,0 +1,25 @@
+CREATE DATABASE IF NOT EXISTS `db01`;
+CREATE TABLE IF NOT EXISTS `db01`.`tb1`;
+
+CREATE ROLE 'test-role';
+CREATE ROLE 'test-role'; -- {ErrorCode 3005}
+CREATE ROLE IF NOT EXISTS 'test-role';
+
+GRANT SELECT ON db01.* TO ROLE 'test-role';
+GRANT SELECT ON db01.* TO ROLE 'test-role-notexists'; -- {ErrorCode 3004}
+
+CREATE USER 'test-user'@'localhost' IDENTIFIED BY 'password';
+GRANT INSERT ON db01.tb1 TO 'test-user'@'localhost';
+GRANT ROLE 'test-role' TO 'test-user'@'localhost';
+GRANT ROLE 'test-role-notexists' TO 'test-user'@'localhost'; -- {ErrorCode 3004}
+SHOW GRANTS FOR 'test-user'@'localhost';
+
+REVOKE ROLE 'test-role' FROM 'test-user'@'localhost';
+SHOW GRANTS FOR 'test-user'@'localhost';
+
+DROP ROLE 'test-role';
+DROP ROLE 'test-role'; -- {ErrorCode 3004}
+DROP ROLE IF EXISTS 'test-role';
+
+DROP USER 'test-user'@'localhost';
+DROP DATABASE `db01`;
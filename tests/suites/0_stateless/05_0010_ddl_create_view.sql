@@ -0,0 +1,34 @@
+DROP DATABASE IF EXISTS db1;
+CREATE DATABASE db1;
+USE db1;
+
+CREATE TABLE IF NOT EXISTS t(a Int32, b varchar) Engine = Memory;
+INSERT INTO t(a,b) VALUES(1, 'v1'),(2, 'v2'),(3, 'v3');
+
+CREATE VIEW v AS SELECT a, b FROM t WHERE a > 1;
+CREATE VIEW v AS SELECT a FROM t; -- {ErrorCode 4003}
+CREATE VIEW IF NOT EXISTS v AS SELECT a FROM t;
+CREATE VIEW v2 AS SELECT x FROM t; -- {ErrorCode 58}
+CREATE TABLE v3(a int) Engine = View; -- {ErrorCode 22}
+
+SELECT * FROM v ORDER BY a;
+SELECT name, engine FROM system.tables WHERE database = 'db1' ORDER BY name;
+
+CREATE VIEW v4 AS SELECT a FROM t WHERE a IN (SELECT max(a) FROM t) AND a > (SELECT min(a) FROM t);
+
+-- the tables of the view are bound to the database where it is created
+USE default;
+SELECT count(*) FROM db1.v;
+SELECT x.b FROM db1.v AS x WHERE x.a = 3;
+SELECT a FROM db1.v4;
+
+USE db1;
+INSERT INTO t(a,b) VALUES(4, 'v4');
+SELECT sum(a) FROM v;
+
+DROP VIEW t; -- {ErrorCode 6}
+DROP VIEW v;
+DROP VIEW v; -- {ErrorCode 25}
+DROP VIEW IF EXISTS v;
+
+DROP DATABASE db1;
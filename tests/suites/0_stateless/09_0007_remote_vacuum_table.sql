@@ -0,0 +1,18 @@
+DROP DATABASE IF EXISTS db1;
+CREATE DATABASE db1;
+USE db1;
+
+CREATE TABLE IF NOT EXISTS t(a varchar, b varchar) Engine = fuse;
+INSERT INTO t(a,b) VALUES('1', 'v1'),('2','v2');
+INSERT INTO t(a,b) VALUES('3', 'v3');
+VACUUM TABLE t;
+VACUUM TABLE t RETAIN 1 HOURS DRY RUN;
+SELECT * FROM t ORDER BY a;
+
+CREATE TABLE m(a int) Engine = Memory;
+VACUUM TABLE m; -- {ErrorCode 2}
+
+DROP TABLE t;
+VACUUM TABLE t; -- {ErrorCode 25}
+
+DROP DATABASE db1;
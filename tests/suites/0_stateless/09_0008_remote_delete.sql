@@ -0,0 +1,22 @@
+DROP DATABASE IF EXISTS db1;
+CREATE DATABASE db1;
+USE db1;
+
+CREATE TABLE IF NOT EXISTS t(a Int32, b varchar) Engine = fuse;
+INSERT INTO t(a,b) VALUES(1, 'v1'),(2, 'v2'),(3, 'v3');
+INSERT INTO t(a,b) VALUES(4, 'v4'),(5, 'v5');
+DELETE FROM t WHERE a > 4;
+DELETE FROM t WHERE b = 'v2' OR a = 3;
+DELETE FROM t WHERE a = 100;
+SELECT * FROM t ORDER BY a;
+DELETE FROM t WHERE c = 1; -- {ErrorCode 6}
+DELETE FROM t;
+SELECT count(*) FROM t;
+
+CREATE TABLE m(a int) Engine = Memory;
+DELETE FROM m WHERE a = 1; -- {ErrorCode 2}
+
+DROP TABLE t;
+DELETE FROM t; -- {ErrorCode 25}
+
+DROP DATABASE db1;
@@ -0,0 +1,21 @@
+DROP DATABASE IF EXISTS db1;
+CREATE DATABASE db1;
+USE db1;
+
+CREATE TABLE IF NOT EXISTS t(a Int32, b varchar) Engine = fuse;
+INSERT INTO t(a,b) VALUES(1, 'v1'),(2, 'v2'),(3, 'v3');
+INSERT INTO t(a,b) VALUES(4, 'v4'),(5, 'v5');
+UPDATE t SET b = 'DE' WHERE a > 3;
+UPDATE t SET a = a * 10, b = concat(b, '!') WHERE a = 2;
+UPDATE t SET b = 'x' WHERE a = 100;
+SELECT * FROM t ORDER BY a;
+UPDATE t SET c = 1; -- {ErrorCode 6}
+UPDATE t SET a = 1, a = 2; -- {ErrorCode 5}
+
+CREATE TABLE m(a int) Engine = Memory;
+UPDATE m SET a = 1; -- {ErrorCode 2}
+
+DROP TABLE t;
+UPDATE t SET a = 1; -- {ErrorCode 25}
+
+DROP DATABASE db1;
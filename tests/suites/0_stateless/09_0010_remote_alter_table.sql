@@ -0,0 +1,25 @@
+DROP DATABASE IF EXISTS db1;
+CREATE DATABASE db1;
+USE db1;
+
+CREATE TABLE IF NOT EXISTS t(a Int32, b varchar) Engine = fuse;
+INSERT INTO t(a,b) VALUES(1, 'v1'),(2, 'v2');
+ALTER TABLE t ADD COLUMN c Int32 DEFAULT 10;
+INSERT INTO t(a,b,c) VALUES(3, 'v3', 30);
+SELECT * FROM t ORDER BY a;
+ALTER TABLE t RENAME COLUMN b TO d;
+SELECT d FROM t ORDER BY a;
+ALTER TABLE t DROP COLUMN a;
+SELECT * FROM t ORDER BY c;
+ALTER TABLE t ADD COLUMN c Int32; -- {ErrorCode 6}
+ALTER TABLE t DROP COLUMN x; -- {ErrorCode 58}
+ALTER TABLE t RENAME TO t1;
+SELECT count(*) FROM t1;
+SELECT count(*) FROM t; -- {ErrorCode 25}
+
+CREATE TABLE m(a int) Engine = Memory;
+ALTER TABLE m ADD COLUMN b int; -- {ErrorCode 2}
+
+DROP TABLE t1;
+DROP TABLE m;
+DROP DATABASE db1;